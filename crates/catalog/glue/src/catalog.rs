@@ -24,10 +24,12 @@ use async_trait::async_trait;
 use aws_sdk_glue::operation::create_table::CreateTableError;
 use aws_sdk_glue::operation::update_table::UpdateTableError;
 use aws_sdk_glue::types::TableInput;
+use iceberg::encryption::KeyManagementClient;
 use iceberg::io::{
     FileIO, FileIOBuilder, S3_ACCESS_KEY_ID, S3_ENDPOINT, S3_REGION, S3_SECRET_ACCESS_KEY,
     S3_SESSION_TOKEN, StorageFactory,
 };
+use iceberg::metrics::MetricsReporter;
 use iceberg::spec::{TableMetadata, TableMetadataBuilder};
 use iceberg::table::Table;
 use iceberg::{
@@ -57,6 +59,8 @@ pub const GLUE_CATALOG_PROP_WAREHOUSE: &str = "warehouse";
 pub struct GlueCatalogBuilder {
     config: GlueCatalogConfig,
     storage_factory: Option<Arc<dyn StorageFactory>>,
+    key_management_client: Option<Arc<dyn KeyManagementClient>>,
+    metrics_reporter: Option<Arc<dyn MetricsReporter>>,
 }
 
 impl Default for GlueCatalogBuilder {
@@ -70,10 +74,26 @@ impl Default for GlueCatalogBuilder {
                 props: HashMap::new(),
             },
             storage_factory: None,
+            key_management_client: None,
+            metrics_reporter: None,
         }
     }
 }
 
+impl GlueCatalogBuilder {
+    /// Set the client of the KMS holding the master keys of encrypted tables.
+    pub fn with_key_management_client(mut self, client: Arc<dyn KeyManagementClient>) -> Self {
+        self.key_management_client = Some(client);
+        self
+    }
+
+    /// Set the [`MetricsReporter`] the scans and commits of the catalog's tables report to.
+    pub fn with_metrics_reporter(mut self, metrics_reporter: Arc<dyn MetricsReporter>) -> Self {
+        self.metrics_reporter = Some(metrics_reporter);
+        self
+    }
+}
+
 impl CatalogBuilder for GlueCatalogBuilder {
     type C = GlueCatalog;
 
@@ -128,7 +148,13 @@ impl CatalogBuilder for GlueCatalogBuilder {
                 ));
             }
 
-            GlueCatalog::new(self.config, self.storage_factory).await
+            GlueCatalog::new(
+                self.config,
+                self.storage_factory,
+                self.key_management_client,
+                self.metrics_reporter,
+            )
+            .await
         }
     }
 }
@@ -150,6 +176,7 @@ pub struct GlueCatalog {
     config: GlueCatalogConfig,
     client: GlueClient,
     file_io: FileIO,
+    metrics_reporter: Option<Arc<dyn MetricsReporter>>,
 }
 
 impl Debug for GlueCatalog {
@@ -165,6 +192,8 @@ impl GlueCatalog {
     async fn new(
         config: GlueCatalogConfig,
         storage_factory: Option<Arc<dyn StorageFactory>>,
+        key_management_client: Option<Arc<dyn KeyManagementClient>>,
+        metrics_reporter: Option<Arc<dyn MetricsReporter>>,
     ) -> Result<Self> {
         let sdk_config = create_sdk_config(&config.props, config.uri.as_ref()).await;
         let mut file_io_props = config.props.clone();
@@ -206,14 +235,16 @@ impl GlueCatalog {
                 customized_credential_load: None,
             })
         });
-        let file_io = FileIOBuilder::new(factory)
-            .with_props(file_io_props)
-            .build();
+        let mut file_io = FileIOBuilder::new(factory).with_props(file_io_props);
+        if let Some(client) = key_management_client {
+            file_io = file_io.with_key_management_client(client);
+        }
 
         Ok(GlueCatalog {
             config,
             client: GlueClient(client),
-            file_io,
+            file_io: file_io.build(),
+            metrics_reporter,
         })
     }
     /// Get the catalogs `FileIO`
@@ -272,6 +303,7 @@ impl GlueCatalog {
                 NamespaceIdent::new(db_name),
                 table_name.to_owned(),
             ))
+            .metrics_reporter(self.metrics_reporter.clone())
             .build()?;
 
         Ok((table, version_id))
@@ -578,6 +610,7 @@ impl Catalog for GlueCatalog {
             .metadata_location(metadata_location)
             .metadata(metadata)
             .identifier(TableIdent::new(NamespaceIdent::new(db_name), table_name))
+            .metrics_reporter(self.metrics_reporter.clone())
             .build()
     }
 
@@ -801,6 +834,7 @@ impl Catalog for GlueCatalog {
             .metadata_location(metadata_location)
             .metadata(metadata)
             .file_io(self.file_io())
+            .metrics_reporter(self.metrics_reporter.clone())
             .build()?)
     }
 
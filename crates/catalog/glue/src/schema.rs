@@ -174,7 +174,10 @@ impl SchemaVisitor for GlueSchemaBuilder {
             PrimitiveType::Time | PrimitiveType::String | PrimitiveType::Uuid => {
                 "string".to_string()
             }
-            PrimitiveType::Binary | PrimitiveType::Fixed(_) => "binary".to_string(),
+            PrimitiveType::Binary
+            | PrimitiveType::Fixed(_)
+            | PrimitiveType::Geometry { .. }
+            | PrimitiveType::Geography { .. } => "binary".to_string(),
             PrimitiveType::Decimal { precision, scale } => {
                 format!("decimal({precision},{scale})")
             }
@@ -182,12 +185,17 @@ impl SchemaVisitor for GlueSchemaBuilder {
 
         Ok(glue_type)
     }
+
+    /// Glue has no variant type, so variants are described by their binary metadata and value.
+    fn variant(&mut self) -> iceberg::Result<String> {
+        Ok("struct<metadata:binary,value:binary>".to_string())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use iceberg::TableCreation;
-    use iceberg::spec::{Schema, TableMetadataBuilder};
+    use iceberg::spec::{FormatVersion, Schema, TableMetadataBuilder};
 
     use super::*;
 
@@ -489,6 +497,59 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_schema_with_variant() -> Result<()> {
+        let record = r#"{
+            "type": "struct",
+            "schema-id": 1,
+            "fields": [
+                {
+                    "id": 1,
+                    "name": "v",
+                    "required": false,
+                    "type": "variant"
+                },
+                {
+                    "id": 2,
+                    "name": "l",
+                    "required": true,
+                    "type": {
+                        "type": "list",
+                        "element-id": 3,
+                        "element-required": false,
+                        "element": "variant"
+                    }
+                }
+            ]
+        }"#;
+
+        let schema = serde_json::from_str::<Schema>(record)?;
+        let table_creation = TableCreation::builder()
+            .name("my_table".to_string())
+            .location("my_location".to_string())
+            .schema(schema)
+            .format_version(FormatVersion::V3)
+            .build();
+        let metadata = TableMetadataBuilder::from_table_creation(table_creation)?
+            .build()?
+            .metadata;
+
+        let result = GlueSchemaBuilder::from_iceberg(&metadata)?.build();
+
+        let expected = vec![
+            create_column("v", "struct<metadata:binary,value:binary>", "1", true)?,
+            create_column(
+                "l",
+                "array<struct<metadata:binary,value:binary>>",
+                "2",
+                false,
+            )?,
+        ];
+
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
     #[test]
     fn test_schema_with_optional_fields() -> Result<()> {
         let record = r#"{
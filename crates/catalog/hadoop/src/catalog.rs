@@ -0,0 +1,1100 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use iceberg::encryption::KeyManagementClient;
+use iceberg::io::{FileIO, FileIOBuilder, StorageFactory};
+use iceberg::metrics::MetricsReporter;
+use iceberg::spec::{TableMetadata, TableMetadataBuilder};
+use iceberg::table::Table;
+use iceberg::{
+    Catalog, CatalogBuilder, Error, ErrorKind, Namespace, NamespaceIdent, Result, TableCommit,
+    TableCreation, TableIdent,
+};
+
+/// Hadoop catalog warehouse location
+pub const HADOOP_CATALOG_PROP_WAREHOUSE: &str = "warehouse";
+
+/// namespace `location` property
+const LOCATION: &str = "location";
+/// Directory of the metadata files of a table
+const METADATA_DIR: &str = "metadata";
+/// File recording the latest metadata version of a table
+const VERSION_HINT_FILE: &str = "version-hint.text";
+const METADATA_FILE_SUFFIX: &str = ".metadata.json";
+const GZIP_METADATA_FILE_SUFFIX: &str = ".gz.metadata.json";
+
+/// Builder for [`HadoopCatalog`].
+#[derive(Debug)]
+pub struct HadoopCatalogBuilder {
+    config: HadoopCatalogConfig,
+    storage_factory: Option<Arc<dyn StorageFactory>>,
+    key_management_client: Option<Arc<dyn KeyManagementClient>>,
+    metrics_reporter: Option<Arc<dyn MetricsReporter>>,
+}
+
+impl Default for HadoopCatalogBuilder {
+    fn default() -> Self {
+        Self {
+            config: HadoopCatalogConfig {
+                name: None,
+                warehouse: "".to_string(),
+                props: HashMap::new(),
+            },
+            storage_factory: None,
+            key_management_client: None,
+            metrics_reporter: None,
+        }
+    }
+}
+
+impl HadoopCatalogBuilder {
+    /// Set the client of the KMS holding the master keys of encrypted tables.
+    pub fn with_key_management_client(mut self, client: Arc<dyn KeyManagementClient>) -> Self {
+        self.key_management_client = Some(client);
+        self
+    }
+
+    /// Set the [`MetricsReporter`] the scans and commits of the catalog's tables report to.
+    pub fn with_metrics_reporter(mut self, metrics_reporter: Arc<dyn MetricsReporter>) -> Self {
+        self.metrics_reporter = Some(metrics_reporter);
+        self
+    }
+}
+
+impl CatalogBuilder for HadoopCatalogBuilder {
+    type C = HadoopCatalog;
+
+    fn with_storage_factory(mut self, storage_factory: Arc<dyn StorageFactory>) -> Self {
+        self.storage_factory = Some(storage_factory);
+        self
+    }
+
+    fn load(
+        mut self,
+        name: impl Into<String>,
+        props: HashMap<String, String>,
+    ) -> impl Future<Output = Result<Self::C>> + Send {
+        self.config.name = Some(name.into());
+
+        if let Some(warehouse) = props.get(HADOOP_CATALOG_PROP_WAREHOUSE) {
+            self.config.warehouse = warehouse.trim_end_matches('/').to_string();
+        }
+
+        // Collect other remaining properties
+        self.config.props = props
+            .into_iter()
+            .filter(|(k, _)| k != HADOOP_CATALOG_PROP_WAREHOUSE)
+            .collect();
+
+        let result = {
+            if self.config.name.is_none() {
+                Err(Error::new(
+                    ErrorKind::DataInvalid,
+                    "Catalog name is required",
+                ))
+            } else if self.config.warehouse.is_empty() {
+                Err(Error::new(
+                    ErrorKind::DataInvalid,
+                    "Catalog warehouse is required",
+                ))
+            } else {
+                HadoopCatalog::new(
+                    self.config,
+                    self.storage_factory,
+                    self.key_management_client,
+                    self.metrics_reporter,
+                )
+            }
+        };
+
+        std::future::ready(result)
+    }
+}
+
+#[derive(Clone, Debug)]
+struct HadoopCatalogConfig {
+    name: Option<String>,
+    warehouse: String,
+    props: HashMap<String, String>,
+}
+
+/// Hadoop catalog implementation.
+///
+/// Tables are stored under `<warehouse>/<namespace>/<table>`, and the catalog state is only
+/// made of the files of the warehouse, so it can be shared with the Java `HadoopCatalog`.
+///
+/// Commits are safe against concurrent writers only if the storage creates files atomically,
+/// as the local filesystem and most object stores with conditional writes do.
+#[derive(Debug)]
+pub struct HadoopCatalog {
+    file_io: FileIO,
+    warehouse: String,
+    metrics_reporter: Option<Arc<dyn MetricsReporter>>,
+}
+
+impl HadoopCatalog {
+    /// Creates a hadoop catalog.
+    fn new(
+        config: HadoopCatalogConfig,
+        storage_factory: Option<Arc<dyn StorageFactory>>,
+        key_management_client: Option<Arc<dyn KeyManagementClient>>,
+        metrics_reporter: Option<Arc<dyn MetricsReporter>>,
+    ) -> Result<Self> {
+        let factory = storage_factory.ok_or_else(|| {
+            Error::new(
+                ErrorKind::Unexpected,
+                "StorageFactory must be provided for HadoopCatalog. Use `with_storage_factory` to configure it.",
+            )
+        })?;
+        let mut file_io = FileIOBuilder::new(factory).with_props(config.props);
+        if let Some(client) = key_management_client {
+            file_io = file_io.with_key_management_client(client);
+        }
+
+        Ok(Self {
+            file_io: file_io.build(),
+            warehouse: config.warehouse,
+            metrics_reporter,
+        })
+    }
+
+    /// Get the catalogs `FileIO`
+    pub fn file_io(&self) -> FileIO {
+        self.file_io.clone()
+    }
+
+    fn namespace_location(&self, namespace: &NamespaceIdent) -> Result<String> {
+        for name in namespace.iter() {
+            validate_name(name)?;
+        }
+        Ok(format!("{}/{}", self.warehouse, namespace.join("/")))
+    }
+
+    fn table_location(&self, table_ident: &TableIdent) -> Result<String> {
+        validate_name(table_ident.name())?;
+        Ok(format!(
+            "{}/{}",
+            self.namespace_location(table_ident.namespace())?,
+            table_ident.name()
+        ))
+    }
+
+    /// Whether a directory holds table metadata files.
+    async fn is_table_dir(&self, location: &str) -> Result<bool> {
+        Ok(self
+            .file_io
+            .list(format!("{location}/{METADATA_DIR}"))
+            .await?
+            .iter()
+            .any(|entry| !entry.is_dir && entry.name().ends_with(METADATA_FILE_SUFFIX)))
+    }
+
+    /// Lists the directories of namespaces or tables under a location.
+    async fn list_dirs(&self, location: &str, tables: bool) -> Result<Vec<String>> {
+        let mut names = vec![];
+        for entry in self.file_io.list(location).await? {
+            if entry.is_dir && self.is_table_dir(&entry.path).await? == tables {
+                names.push(entry.name().to_string());
+            }
+        }
+        Ok(names)
+    }
+
+    async fn ensure_namespace_exists(&self, namespace: &NamespaceIdent) -> Result<String> {
+        if !self.namespace_exists(namespace).await? {
+            return Err(Error::new(
+                ErrorKind::NamespaceNotFound,
+                format!("No such namespace: {namespace:?}"),
+            ));
+        }
+        self.namespace_location(namespace)
+    }
+
+    /// Finds the current version of a table and the location of its metadata file.
+    ///
+    /// The version hint is only a starting point: it may lag behind if a writer failed to
+    /// update it, so newer versions are looked for as well.
+    async fn current_version(&self, table_location: &str) -> Result<Option<(u64, String)>> {
+        let metadata_dir = format!("{table_location}/{METADATA_DIR}");
+
+        let Some(mut version) = self.find_version(&metadata_dir).await? else {
+            return Ok(None);
+        };
+        let mut metadata_location = self
+            .metadata_file(&metadata_dir, version)
+            .await?
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::DataInvalid,
+                    format!("Metadata file for version {version} is missing in {metadata_dir}"),
+                )
+            })?;
+
+        while let Some(next_location) = self.metadata_file(&metadata_dir, version + 1).await? {
+            version += 1;
+            metadata_location = next_location;
+        }
+
+        Ok(Some((version, metadata_location)))
+    }
+
+    /// Reads the version hint, falling back to the highest version of the metadata files.
+    async fn find_version(&self, metadata_dir: &str) -> Result<Option<u64>> {
+        let hint_location = format!("{metadata_dir}/{VERSION_HINT_FILE}");
+        if self.file_io.exists(&hint_location).await? {
+            let hint = self.file_io.new_input(&hint_location)?.read().await?;
+            match std::str::from_utf8(&hint)
+                .ok()
+                .and_then(|hint| hint.trim().parse().ok())
+            {
+                Some(version) => return Ok(Some(version)),
+                None => tracing::warn!("Ignoring invalid version hint in {hint_location}"),
+            }
+        }
+
+        Ok(self
+            .file_io
+            .list(metadata_dir)
+            .await?
+            .iter()
+            .filter(|entry| !entry.is_dir)
+            .filter_map(|entry| parse_metadata_version(entry.name()))
+            .max())
+    }
+
+    /// Finds the metadata file of a version, which may be compressed.
+    async fn metadata_file(&self, metadata_dir: &str, version: u64) -> Result<Option<String>> {
+        for suffix in [METADATA_FILE_SUFFIX, GZIP_METADATA_FILE_SUFFIX] {
+            let location = format!("{metadata_dir}/v{version}{suffix}");
+            if self.file_io.exists(&location).await? {
+                return Ok(Some(location));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Creates the metadata file of a version, failing if it already exists, then updates
+    /// the version hint.
+    ///
+    /// Returns the location of the metadata file, or an error of kind
+    /// [`ErrorKind::PreconditionFailed`] if the version was committed by someone else. Storages
+    /// which can't create files atomically are rejected with [`ErrorKind::FeatureUnsupported`].
+    async fn write_version(
+        &self,
+        table_location: &str,
+        version: u64,
+        metadata: &TableMetadata,
+    ) -> Result<String> {
+        let metadata_dir = format!("{table_location}/{METADATA_DIR}");
+        let extension = metadata.metadata_file_extension()?;
+        let metadata_location = format!("{metadata_dir}/v{version}{extension}");
+        metadata
+            .write_to_if_not_exists(&self.file_io, &metadata_location)
+            .await?;
+
+        // A concurrent commit with another compression codec creates the version under the
+        // other extension, so the version is only ours if that file doesn't exist.
+        let other_extension = if extension == METADATA_FILE_SUFFIX {
+            GZIP_METADATA_FILE_SUFFIX
+        } else {
+            METADATA_FILE_SUFFIX
+        };
+        if self
+            .file_io
+            .exists(format!("{metadata_dir}/v{version}{other_extension}"))
+            .await?
+        {
+            if let Err(e) = self.file_io.delete(&metadata_location).await {
+                tracing::warn!(
+                    "Failed to delete the conflicting metadata {metadata_location}: {e}"
+                );
+            }
+            return Err(Error::new(
+                ErrorKind::PreconditionFailed,
+                format!("Version {version} already exists: {metadata_dir}"),
+            ));
+        }
+
+        // Readers look for newer versions than the hint, so a stale hint is not fatal.
+        let hint_location = format!("{metadata_dir}/{VERSION_HINT_FILE}");
+        if let Err(e) = self
+            .file_io
+            .new_output(&hint_location)?
+            .write(version.to_string().into())
+            .await
+        {
+            tracing::warn!("Failed to update the version hint {hint_location}: {e}");
+        }
+
+        Ok(metadata_location)
+    }
+
+    async fn load_table_at(&self, table_ident: &TableIdent) -> Result<(u64, Table)> {
+        let table_location = self.table_location(table_ident)?;
+        let (version, metadata_location) = self
+            .current_version(&table_location)
+            .await?
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::TableNotFound,
+                    format!("No such table: {table_ident}"),
+                )
+            })?;
+        let metadata = TableMetadata::read_from(&self.file_io, &metadata_location).await?;
+
+        let table = Table::builder()
+            .identifier(table_ident.clone())
+            .metadata(metadata)
+            .metadata_location(metadata_location)
+            .file_io(self.file_io.clone())
+            .metrics_reporter(self.metrics_reporter.clone())
+            .build()?;
+        Ok((version, table))
+    }
+}
+
+/// Rejects names which can't be used as directory names.
+fn validate_name(name: &str) -> Result<()> {
+    if name.is_empty() || name == "." || name == ".." || name.contains('/') {
+        return Err(Error::new(
+            ErrorKind::DataInvalid,
+            format!("Invalid name for a hadoop catalog directory: {name:?}"),
+        ));
+    }
+    Ok(())
+}
+
+/// Parses the version of metadata files named `v<N>.metadata.json` or `v<N>.gz.metadata.json`.
+fn parse_metadata_version(file_name: &str) -> Option<u64> {
+    let name = file_name.strip_prefix('v')?;
+    let version = name
+        .strip_suffix(GZIP_METADATA_FILE_SUFFIX)
+        .or_else(|| name.strip_suffix(METADATA_FILE_SUFFIX))?;
+    version.parse().ok()
+}
+
+#[async_trait]
+impl Catalog for HadoopCatalog {
+    /// List namespaces inside the catalog.
+    ///
+    /// Namespaces are the directories which are not tables.
+    async fn list_namespaces(
+        &self,
+        parent: Option<&NamespaceIdent>,
+    ) -> Result<Vec<NamespaceIdent>> {
+        let location = match parent {
+            Some(parent) => self.ensure_namespace_exists(parent).await?,
+            None => self.warehouse.clone(),
+        };
+
+        self.list_dirs(&location, false)
+            .await?
+            .into_iter()
+            .map(|name| {
+                let mut names = parent.map(|p| p.to_vec()).unwrap_or_default();
+                names.push(name);
+                NamespaceIdent::from_vec(names)
+            })
+            .collect()
+    }
+
+    /// Create a new namespace inside the catalog, with its missing parents.
+    ///
+    /// # Behavior
+    ///
+    /// Namespace properties can't be stored, so they must be empty.
+    async fn create_namespace(
+        &self,
+        namespace: &NamespaceIdent,
+        properties: HashMap<String, String>,
+    ) -> Result<Namespace> {
+        if !properties.is_empty() {
+            return Err(Error::new(
+                ErrorKind::FeatureUnsupported,
+                "Namespace properties are not supported by the hadoop catalog",
+            ));
+        }
+        if self.namespace_exists(namespace).await? {
+            return Err(Error::new(
+                ErrorKind::NamespaceAlreadyExists,
+                format!("Namespace {namespace:?} already exists"),
+            ));
+        }
+
+        self.file_io
+            .create_dir(self.namespace_location(namespace)?)
+            .await?;
+        Ok(Namespace::new(namespace.clone()))
+    }
+
+    /// Get a namespace information from the catalog.
+    ///
+    /// The only property of namespaces is their `location`.
+    async fn get_namespace(&self, namespace: &NamespaceIdent) -> Result<Namespace> {
+        let location = self.ensure_namespace_exists(namespace).await?;
+        Ok(Namespace::with_properties(
+            namespace.clone(),
+            HashMap::from([(LOCATION.to_string(), location)]),
+        ))
+    }
+
+    /// Check if namespace exists in catalog.
+    async fn namespace_exists(&self, namespace: &NamespaceIdent) -> Result<bool> {
+        let location = self.namespace_location(namespace)?;
+        let (parent_location, name) = location
+            .rsplit_once('/')
+            .expect("namespace location is under the warehouse");
+
+        for entry in self.file_io.list(parent_location).await? {
+            if entry.is_dir && entry.name() == name {
+                return Ok(!self.is_table_dir(&location).await?);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Namespace properties can't be stored, so updating them is not supported.
+    async fn update_namespace(
+        &self,
+        _namespace: &NamespaceIdent,
+        _properties: HashMap<String, String>,
+    ) -> Result<()> {
+        Err(Error::new(
+            ErrorKind::FeatureUnsupported,
+            "Namespace properties are not supported by the hadoop catalog",
+        ))
+    }
+
+    /// Drop an empty namespace from the catalog.
+    async fn drop_namespace(&self, namespace: &NamespaceIdent) -> Result<()> {
+        let location = self.ensure_namespace_exists(namespace).await?;
+        if !self.file_io.list(&location).await?.is_empty() {
+            return Err(Error::new(
+                ErrorKind::PreconditionFailed,
+                format!("Namespace {namespace:?} is not empty"),
+            ));
+        }
+        self.file_io.delete_prefix(&location).await
+    }
+
+    /// List tables from namespace.
+    async fn list_tables(&self, namespace: &NamespaceIdent) -> Result<Vec<TableIdent>> {
+        let location = self.ensure_namespace_exists(namespace).await?;
+        Ok(self
+            .list_dirs(&location, true)
+            .await?
+            .into_iter()
+            .map(|name| TableIdent::new(namespace.clone(), name))
+            .collect())
+    }
+
+    /// Create a new table inside the namespace.
+    ///
+    /// # Behavior
+    ///
+    /// Tables are identified by their location, so a custom location can't be set.
+    async fn create_table(
+        &self,
+        namespace: &NamespaceIdent,
+        creation: TableCreation,
+    ) -> Result<Table> {
+        self.ensure_namespace_exists(namespace).await?;
+        let table_ident = TableIdent::new(namespace.clone(), creation.name.clone());
+        let location = self.table_location(&table_ident)?;
+
+        if creation
+            .location
+            .as_ref()
+            .is_some_and(|custom| custom.trim_end_matches('/') != location)
+        {
+            return Err(Error::new(
+                ErrorKind::DataInvalid,
+                format!("Cannot set a custom location for hadoop table {table_ident}"),
+            ));
+        }
+        if self.is_table_dir(&location).await? {
+            return Err(Error::new(
+                ErrorKind::TableAlreadyExists,
+                format!("Table {table_ident} already exists"),
+            ));
+        }
+
+        let creation = TableCreation {
+            location: Some(location.clone()),
+            ..creation
+        };
+        let metadata = TableMetadataBuilder::from_table_creation(creation)?
+            .build()?
+            .metadata;
+
+        let metadata_location = self
+            .write_version(&location, 1, &metadata)
+            .await
+            .map_err(|e| match e.kind() {
+                ErrorKind::PreconditionFailed => Error::new(
+                    ErrorKind::TableAlreadyExists,
+                    format!("Table {table_ident} already exists"),
+                )
+                .with_source(e),
+                _ => e,
+            })?;
+
+        Table::builder()
+            .file_io(self.file_io.clone())
+            .metadata_location(metadata_location)
+            .metadata(metadata)
+            .identifier(table_ident)
+            .metrics_reporter(self.metrics_reporter.clone())
+            .build()
+    }
+
+    /// Load table from the catalog.
+    async fn load_table(&self, table_ident: &TableIdent) -> Result<Table> {
+        Ok(self.load_table_at(table_ident).await?.1)
+    }
+
+    /// Drop a table from the catalog.
+    ///
+    /// # Behavior
+    ///
+    /// The table directory is deleted, including data files stored in it.
+    async fn drop_table(&self, table_ident: &TableIdent) -> Result<()> {
+        let location = self.table_location(table_ident)?;
+        if !self.is_table_dir(&location).await? {
+            return Err(Error::new(
+                ErrorKind::TableNotFound,
+                format!("No such table: {table_ident}"),
+            ));
+        }
+        self.file_io.delete_prefix(&location).await
+    }
+
+    /// Check if a table exists in the catalog.
+    async fn table_exists(&self, table_ident: &TableIdent) -> Result<bool> {
+        self.is_table_dir(&self.table_location(table_ident)?).await
+    }
+
+    /// Tables are identified by their location, so renaming them is not supported.
+    async fn rename_table(&self, _src: &TableIdent, _dest: &TableIdent) -> Result<()> {
+        Err(Error::new(
+            ErrorKind::FeatureUnsupported,
+            "Renaming tables is not supported by the hadoop catalog",
+        ))
+    }
+
+    /// Tables are identified by their location, so registering them is not supported.
+    async fn register_table(
+        &self,
+        _table_ident: &TableIdent,
+        _metadata_location: String,
+    ) -> Result<Table> {
+        Err(Error::new(
+            ErrorKind::FeatureUnsupported,
+            "Registering tables is not supported by the hadoop catalog",
+        ))
+    }
+
+    /// Update a table in the catalog.
+    ///
+    /// # Errors
+    ///
+    /// Returns a retryable [`ErrorKind::CatalogCommitConflicts`] error if another writer
+    /// committed the next version of the table first.
+    async fn update_table(&self, mut commit: TableCommit) -> Result<Table> {
+        let table_ident = commit.identifier().clone();
+        let (version, current_table) = self.load_table_at(&table_ident).await?;
+        let current_metadata_location = current_table.metadata_location_result()?.to_string();
+
+        for requirement in commit.take_requirements() {
+            requirement.check(Some(current_table.metadata()))?;
+        }
+        let mut metadata_builder = current_table
+            .metadata()
+            .clone()
+            .into_builder(Some(current_metadata_location));
+        for update in commit.take_updates() {
+            metadata_builder = update.apply(metadata_builder)?;
+        }
+        let metadata = metadata_builder.build()?.metadata;
+
+        let table_location = self.table_location(&table_ident)?;
+        let metadata_location = self
+            .write_version(&table_location, version + 1, &metadata)
+            .await
+            .map_err(|e| match e.kind() {
+                ErrorKind::PreconditionFailed => Error::new(
+                    ErrorKind::CatalogCommitConflicts,
+                    format!(
+                        "Version {} of table {table_ident} was committed concurrently",
+                        version + 1
+                    ),
+                )
+                .with_retryable(true)
+                .with_source(e),
+                _ => e,
+            })?;
+
+        Table::builder()
+            .file_io(self.file_io.clone())
+            .metadata_location(metadata_location)
+            .metadata(metadata)
+            .identifier(table_ident)
+            .metrics_reporter(self.metrics_reporter.clone())
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use iceberg::encryption::{EncryptionManager, InMemoryKeyManagementClient};
+    use iceberg::io::LocalFsStorageFactory;
+    use iceberg::spec::{FormatVersion, NestedField, PrimitiveType, Schema, TableProperties, Type};
+    use iceberg::transaction::{ApplyTransactionAction, Transaction};
+    use iceberg::{
+        Catalog, CatalogBuilder, ErrorKind, NamespaceIdent, TableCommit, TableCreation, TableIdent,
+        TableUpdate,
+    };
+    use tempfile::TempDir;
+
+    use super::*;
+
+    async fn new_catalog(warehouse: &TempDir) -> HadoopCatalog {
+        HadoopCatalogBuilder::default()
+            .with_storage_factory(Arc::new(LocalFsStorageFactory))
+            .load(
+                "hadoop",
+                HashMap::from([(
+                    HADOOP_CATALOG_PROP_WAREHOUSE.to_string(),
+                    format!("file://{}", warehouse.path().to_str().unwrap()),
+                )]),
+            )
+            .await
+            .unwrap()
+    }
+
+    fn table_creation(name: &str) -> TableCreation {
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+            ])
+            .build()
+            .unwrap();
+
+        TableCreation::builder()
+            .name(name.to_string())
+            .schema(schema)
+            .build()
+    }
+
+    fn set_property(table_ident: &TableIdent, value: &str) -> TableCommit {
+        TableCommit::new(table_ident.clone(), vec![], vec![
+            TableUpdate::SetProperties {
+                updates: HashMap::from([("key".to_string(), value.to_string())]),
+            },
+        ])
+    }
+
+    #[test]
+    fn test_parse_metadata_version() {
+        assert_eq!(parse_metadata_version("v1.metadata.json"), Some(1));
+        assert_eq!(parse_metadata_version("v12.gz.metadata.json"), Some(12));
+        assert_eq!(
+            parse_metadata_version("00001-2cd22b57-5127-4198-92ba-e4e67c79821b.metadata.json"),
+            None
+        );
+        assert_eq!(parse_metadata_version("version-hint.text"), None);
+    }
+
+    #[tokio::test]
+    async fn test_namespaces() {
+        let warehouse = TempDir::new().unwrap();
+        let catalog = new_catalog(&warehouse).await;
+        let parent = NamespaceIdent::new("parent".to_string());
+        let child = NamespaceIdent::from_strs(["parent", "child"]).unwrap();
+
+        catalog
+            .create_namespace(&child, HashMap::new())
+            .await
+            .unwrap();
+        assert!(catalog.namespace_exists(&parent).await.unwrap());
+        assert_eq!(catalog.list_namespaces(None).await.unwrap(), vec![
+            parent.clone()
+        ]);
+        assert_eq!(catalog.list_namespaces(Some(&parent)).await.unwrap(), vec![
+            child.clone()
+        ]);
+        assert_eq!(
+            catalog
+                .create_namespace(&child, HashMap::new())
+                .await
+                .unwrap_err()
+                .kind(),
+            ErrorKind::NamespaceAlreadyExists
+        );
+        assert_eq!(
+            catalog
+                .get_namespace(&child)
+                .await
+                .unwrap()
+                .properties()
+                .get(LOCATION),
+            Some(&format!(
+                "file://{}/parent/child",
+                warehouse.path().to_str().unwrap()
+            ))
+        );
+
+        assert!(catalog.drop_namespace(&parent).await.is_err());
+        catalog.drop_namespace(&child).await.unwrap();
+        catalog.drop_namespace(&parent).await.unwrap();
+        assert!(!catalog.namespace_exists(&parent).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_create_namespace_with_properties_is_unsupported() {
+        let warehouse = TempDir::new().unwrap();
+        let catalog = new_catalog(&warehouse).await;
+
+        let err = catalog
+            .create_namespace(
+                &NamespaceIdent::new("ns".to_string()),
+                HashMap::from([("owner".to_string(), "alice".to_string())]),
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::FeatureUnsupported);
+    }
+
+    #[tokio::test]
+    async fn test_create_table_layout() {
+        let warehouse = TempDir::new().unwrap();
+        let catalog = new_catalog(&warehouse).await;
+        let namespace = NamespaceIdent::new("ns".to_string());
+        catalog
+            .create_namespace(&namespace, HashMap::new())
+            .await
+            .unwrap();
+
+        let table = catalog
+            .create_table(&namespace, table_creation("tbl"))
+            .await
+            .unwrap();
+
+        let metadata_dir = warehouse.path().join("ns").join("tbl").join("metadata");
+        assert_eq!(
+            table.metadata_location(),
+            Some(format!("file://{}/v1.metadata.json", metadata_dir.to_str().unwrap()).as_str())
+        );
+        assert_eq!(
+            std::fs::read_to_string(metadata_dir.join("version-hint.text")).unwrap(),
+            "1"
+        );
+        assert_eq!(
+            table.metadata().location(),
+            format!("file://{}/ns/tbl", warehouse.path().to_str().unwrap())
+        );
+
+        let table_ident = TableIdent::new(namespace.clone(), "tbl".to_string());
+        assert!(catalog.table_exists(&table_ident).await.unwrap());
+        assert_eq!(catalog.list_tables(&namespace).await.unwrap(), vec![
+            table_ident.clone()
+        ]);
+        // Table directories are not namespaces.
+        assert!(
+            catalog
+                .list_namespaces(Some(&namespace))
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(
+            catalog
+                .create_table(&namespace, table_creation("tbl"))
+                .await
+                .unwrap_err()
+                .kind(),
+            ErrorKind::TableAlreadyExists
+        );
+
+        catalog.drop_table(&table_ident).await.unwrap();
+        assert!(!catalog.table_exists(&table_ident).await.unwrap());
+        assert!(!metadata_dir.exists());
+    }
+
+    #[tokio::test]
+    async fn test_create_table_with_custom_location_is_rejected() {
+        let warehouse = TempDir::new().unwrap();
+        let catalog = new_catalog(&warehouse).await;
+        let namespace = NamespaceIdent::new("ns".to_string());
+        catalog
+            .create_namespace(&namespace, HashMap::new())
+            .await
+            .unwrap();
+
+        let creation = TableCreation {
+            location: Some("file:///elsewhere/tbl".to_string()),
+            ..table_creation("tbl")
+        };
+        let err = catalog
+            .create_table(&namespace, creation)
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::DataInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_update_table() {
+        let warehouse = TempDir::new().unwrap();
+        let catalog = new_catalog(&warehouse).await;
+        let namespace = NamespaceIdent::new("ns".to_string());
+        catalog
+            .create_namespace(&namespace, HashMap::new())
+            .await
+            .unwrap();
+        let table = catalog
+            .create_table(&namespace, table_creation("tbl"))
+            .await
+            .unwrap();
+
+        let tx = Transaction::new(&table);
+        let updated = tx
+            .update_table_properties()
+            .set("key".to_string(), "value".to_string())
+            .apply(tx)
+            .unwrap()
+            .commit(&catalog)
+            .await
+            .unwrap();
+
+        assert!(
+            updated
+                .metadata_location()
+                .unwrap()
+                .ends_with("/metadata/v2.metadata.json")
+        );
+        assert_eq!(
+            updated.metadata().metadata_log()[0].metadata_file,
+            table.metadata_location().unwrap()
+        );
+
+        let loaded = catalog.load_table(table.identifier()).await.unwrap();
+        assert_eq!(loaded.metadata_location(), updated.metadata_location());
+        assert_eq!(loaded.metadata(), updated.metadata());
+    }
+
+    #[tokio::test]
+    async fn test_load_table_with_stale_version_hint() {
+        let warehouse = TempDir::new().unwrap();
+        let catalog = new_catalog(&warehouse).await;
+        let namespace = NamespaceIdent::new("ns".to_string());
+        catalog
+            .create_namespace(&namespace, HashMap::new())
+            .await
+            .unwrap();
+        let table = catalog
+            .create_table(&namespace, table_creation("tbl"))
+            .await
+            .unwrap();
+        catalog
+            .update_table(set_property(table.identifier(), "v2"))
+            .await
+            .unwrap();
+
+        let hint = warehouse
+            .path()
+            .join("ns/tbl/metadata")
+            .join(VERSION_HINT_FILE);
+        std::fs::write(&hint, "1").unwrap();
+        let loaded = catalog.load_table(table.identifier()).await.unwrap();
+        assert!(
+            loaded
+                .metadata_location()
+                .unwrap()
+                .ends_with("/v2.metadata.json")
+        );
+
+        std::fs::remove_file(&hint).unwrap();
+        let loaded = catalog.load_table(table.identifier()).await.unwrap();
+        assert!(
+            loaded
+                .metadata_location()
+                .unwrap()
+                .ends_with("/v2.metadata.json")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_table_conflict() {
+        let warehouse = TempDir::new().unwrap();
+        let catalog = new_catalog(&warehouse).await;
+        let namespace = NamespaceIdent::new("ns".to_string());
+        catalog
+            .create_namespace(&namespace, HashMap::new())
+            .await
+            .unwrap();
+        let table = catalog
+            .create_table(&namespace, table_creation("tbl"))
+            .await
+            .unwrap();
+
+        // Another writer commits version 2 without updating the version hint.
+        let concurrent = table.metadata().clone();
+        catalog
+            .file_io()
+            .new_output(format!(
+                "{}/metadata/v2.metadata.json",
+                table.metadata().location()
+            ))
+            .unwrap()
+            .write(serde_json::to_vec(&concurrent).unwrap().into())
+            .await
+            .unwrap();
+        let hint = warehouse
+            .path()
+            .join("ns/tbl/metadata")
+            .join(VERSION_HINT_FILE);
+
+        // Versions newer than the hint are found, so the commit writes version 3.
+        let updated = catalog
+            .update_table(set_property(table.identifier(), "v3"))
+            .await
+            .unwrap();
+        assert!(
+            updated
+                .metadata_location()
+                .unwrap()
+                .ends_with("/v3.metadata.json")
+        );
+        assert_eq!(std::fs::read_to_string(&hint).unwrap(), "3");
+
+        // Creating a version which exists is a conflict.
+        let err = catalog
+            .write_version(table.metadata().location(), 3, &concurrent)
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::PreconditionFailed);
+    }
+
+    #[tokio::test]
+    async fn test_write_compressed_version() {
+        let warehouse = TempDir::new().unwrap();
+        let catalog = new_catalog(&warehouse).await;
+        let namespace = NamespaceIdent::new("ns".to_string());
+        catalog
+            .create_namespace(&namespace, HashMap::new())
+            .await
+            .unwrap();
+        let table = catalog
+            .create_table(&namespace, table_creation("tbl"))
+            .await
+            .unwrap();
+
+        let compressed = TableMetadataBuilder::new_from_metadata(table.metadata().clone(), None)
+            .set_properties(HashMap::from([(
+                TableProperties::PROPERTY_METADATA_COMPRESSION_CODEC.to_string(),
+                "gzip".to_string(),
+            )]))
+            .unwrap()
+            .build()
+            .unwrap()
+            .metadata;
+        let location = catalog
+            .write_version(table.metadata().location(), 2, &compressed)
+            .await
+            .unwrap();
+        assert!(location.ends_with("/v2.gz.metadata.json"));
+        let loaded = catalog.load_table(table.identifier()).await.unwrap();
+        assert_eq!(loaded.metadata_location(), Some(location.as_str()));
+
+        // The version exists under the other extension, so writing it uncompressed conflicts.
+        let err = catalog
+            .write_version(table.metadata().location(), 2, table.metadata())
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::PreconditionFailed);
+        assert!(
+            !warehouse
+                .path()
+                .join("ns/tbl/metadata/v2.metadata.json")
+                .exists()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_key_management_client() {
+        let warehouse = TempDir::new().unwrap();
+        let kms =
+            Arc::new(InMemoryKeyManagementClient::new().with_master_key("master", vec![3; 32]));
+        let catalog = HadoopCatalogBuilder::default()
+            .with_storage_factory(Arc::new(LocalFsStorageFactory))
+            .with_key_management_client(kms)
+            .load(
+                "hadoop",
+                HashMap::from([(
+                    HADOOP_CATALOG_PROP_WAREHOUSE.to_string(),
+                    format!("file://{}", warehouse.path().to_str().unwrap()),
+                )]),
+            )
+            .await
+            .unwrap();
+        let namespace = NamespaceIdent::new("ns".to_string());
+        catalog
+            .create_namespace(&namespace, HashMap::new())
+            .await
+            .unwrap();
+        let mut creation = table_creation("tbl");
+        creation.format_version = FormatVersion::V3;
+        creation.properties = HashMap::from([(
+            TableProperties::PROPERTY_ENCRYPTION_TABLE_KEY.to_string(),
+            "master".to_string(),
+        )]);
+        let table = catalog.create_table(&namespace, creation).await.unwrap();
+
+        let encryption_manager =
+            EncryptionManager::try_from_table(table.metadata(), table.file_io()).unwrap();
+        assert_eq!(encryption_manager.unwrap().table_key_id(), "master");
+    }
+
+    #[tokio::test]
+    async fn test_unsupported_operations() {
+        let warehouse = TempDir::new().unwrap();
+        let catalog = new_catalog(&warehouse).await;
+        let namespace = NamespaceIdent::new("ns".to_string());
+        let src = TableIdent::new(namespace.clone(), "a".to_string());
+        let dest = TableIdent::new(namespace.clone(), "b".to_string());
+
+        assert_eq!(
+            catalog.rename_table(&src, &dest).await.unwrap_err().kind(),
+            ErrorKind::FeatureUnsupported
+        );
+        assert_eq!(
+            catalog
+                .update_namespace(&namespace, HashMap::new())
+                .await
+                .unwrap_err()
+                .kind(),
+            ErrorKind::FeatureUnsupported
+        );
+    }
+}
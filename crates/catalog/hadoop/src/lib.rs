@@ -0,0 +1,59 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Iceberg Hadoop catalog implementation.
+//!
+//! The catalog keeps tables directly in a warehouse directory, in the layout of the Java
+//! `HadoopCatalog`, so that no external metastore is needed:
+//!
+//! - namespaces are directories under the warehouse,
+//! - a table is a directory holding `metadata/v<N>.metadata.json` files, the latest version
+//!   being recorded in `metadata/version-hint.text`.
+//!
+//! Commits are made by creating the metadata file of the next version, which only one writer
+//! can do when the storage supports atomic creation of files.
+//!
+//! # Example
+//!
+//! ```rust, no_run
+//! use std::collections::HashMap;
+//! use std::sync::Arc;
+//!
+//! use iceberg::CatalogBuilder;
+//! use iceberg::io::LocalFsStorageFactory;
+//! use iceberg_catalog_hadoop::{HADOOP_CATALOG_PROP_WAREHOUSE, HadoopCatalogBuilder};
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let catalog = HadoopCatalogBuilder::default()
+//!         .with_storage_factory(Arc::new(LocalFsStorageFactory))
+//!         .load(
+//!             "hadoop",
+//!             HashMap::from([(
+//!                 HADOOP_CATALOG_PROP_WAREHOUSE.to_string(),
+//!                 "file:///tmp/warehouse".to_string(),
+//!             )]),
+//!         )
+//!         .await
+//!         .unwrap();
+//! }
+//! ```
+
+#![deny(missing_docs)]
+
+mod catalog;
+pub use catalog::*;
@@ -26,7 +26,9 @@ use hive_metastore::{
     ThriftHiveMetastoreClient, ThriftHiveMetastoreClientBuilder,
     ThriftHiveMetastoreGetDatabaseException, ThriftHiveMetastoreGetTableException,
 };
+use iceberg::encryption::KeyManagementClient;
 use iceberg::io::{FileIO, FileIOBuilder, StorageFactory};
+use iceberg::metrics::MetricsReporter;
 use iceberg::spec::{TableMetadata, TableMetadataBuilder};
 use iceberg::table::Table;
 use iceberg::{
@@ -37,6 +39,7 @@ use volo_thrift::MaybeException;
 
 use super::utils::*;
 use crate::error::{from_io_error, from_thrift_error, from_thrift_exception};
+use crate::lock::{HmsLock, HmsLockConfig};
 
 /// HMS catalog address
 pub const HMS_CATALOG_PROP_URI: &str = "uri";
@@ -51,11 +54,23 @@ pub const THRIFT_TRANSPORT_BUFFERED: &str = "buffered";
 /// HMS Catalog warehouse location
 pub const HMS_CATALOG_PROP_WAREHOUSE: &str = "warehouse";
 
+/// Lock tables in HMS while committing to them (defaults to true).
+///
+/// When disabled, commits rely on HMS checking that the metadata location of the table did
+/// not change, which requires HMS 4.0 or later.
+pub const HMS_CATALOG_PROP_LOCK_ENABLED: &str = "lock-enabled";
+/// Maximum time in milliseconds a commit waits for the lock of a table (defaults to 3 minutes)
+pub const HMS_CATALOG_PROP_LOCK_TIMEOUT_MS: &str = "lock-timeout-ms";
+/// Interval in milliseconds of the heartbeats keeping table locks alive (defaults to 4 minutes)
+pub const HMS_CATALOG_PROP_LOCK_HEARTBEAT_INTERVAL_MS: &str = "lock-heartbeat-interval-ms";
+
 /// Builder for [`HmsCatalog`].
 #[derive(Debug)]
 pub struct HmsCatalogBuilder {
     config: HmsCatalogConfig,
     storage_factory: Option<Arc<dyn StorageFactory>>,
+    key_management_client: Option<Arc<dyn KeyManagementClient>>,
+    metrics_reporter: Option<Arc<dyn MetricsReporter>>,
 }
 
 impl Default for HmsCatalogBuilder {
@@ -66,13 +81,30 @@ impl Default for HmsCatalogBuilder {
                 address: "".to_string(),
                 thrift_transport: HmsThriftTransport::default(),
                 warehouse: "".to_string(),
+                lock: HmsLockConfig::default(),
                 props: HashMap::new(),
             },
             storage_factory: None,
+            key_management_client: None,
+            metrics_reporter: None,
         }
     }
 }
 
+impl HmsCatalogBuilder {
+    /// Set the client of the KMS holding the master keys of encrypted tables.
+    pub fn with_key_management_client(mut self, client: Arc<dyn KeyManagementClient>) -> Self {
+        self.key_management_client = Some(client);
+        self
+    }
+
+    /// Set the [`MetricsReporter`] the scans and commits of the catalog's tables report to.
+    pub fn with_metrics_reporter(mut self, metrics_reporter: Arc<dyn MetricsReporter>) -> Self {
+        self.metrics_reporter = Some(metrics_reporter);
+        self
+    }
+}
+
 impl CatalogBuilder for HmsCatalogBuilder {
     type C = HmsCatalog;
 
@@ -107,16 +139,28 @@ impl CatalogBuilder for HmsCatalogBuilder {
                 .unwrap_or_default();
         }
 
+        let lock = HmsLockConfig::from_props(&props);
+
         self.config.props = props
             .into_iter()
             .filter(|(k, _)| {
                 k != HMS_CATALOG_PROP_URI
                     && k != HMS_CATALOG_PROP_THRIFT_TRANSPORT
                     && k != HMS_CATALOG_PROP_WAREHOUSE
+                    && k != HMS_CATALOG_PROP_LOCK_ENABLED
+                    && k != HMS_CATALOG_PROP_LOCK_TIMEOUT_MS
+                    && k != HMS_CATALOG_PROP_LOCK_HEARTBEAT_INTERVAL_MS
             })
             .collect();
 
-        let result = {
+        let result = match lock {
+            Err(e) => Err(e),
+            Ok(lock) => {
+                self.config.lock = lock;
+                Ok(())
+            }
+        }
+        .and_then(|_| {
             if self.config.name.is_none() {
                 Err(Error::new(
                     ErrorKind::DataInvalid,
@@ -133,9 +177,14 @@ impl CatalogBuilder for HmsCatalogBuilder {
                     "Catalog warehouse is required",
                 ))
             } else {
-                HmsCatalog::new(self.config, self.storage_factory)
+                HmsCatalog::new(
+                    self.config,
+                    self.storage_factory,
+                    self.key_management_client,
+                    self.metrics_reporter,
+                )
             }
-        };
+        });
 
         std::future::ready(result)
     }
@@ -159,6 +208,7 @@ pub(crate) struct HmsCatalogConfig {
     address: String,
     thrift_transport: HmsThriftTransport,
     warehouse: String,
+    lock: HmsLockConfig,
     props: HashMap<String, String>,
 }
 
@@ -169,6 +219,7 @@ pub struct HmsCatalog {
     config: HmsCatalogConfig,
     client: HmsClient,
     file_io: FileIO,
+    metrics_reporter: Option<Arc<dyn MetricsReporter>>,
 }
 
 impl Debug for HmsCatalog {
@@ -184,6 +235,8 @@ impl HmsCatalog {
     fn new(
         config: HmsCatalogConfig,
         storage_factory: Option<Arc<dyn StorageFactory>>,
+        key_management_client: Option<Arc<dyn KeyManagementClient>>,
+        metrics_reporter: Option<Arc<dyn MetricsReporter>>,
     ) -> Result<Self> {
         let address = config
             .address
@@ -215,20 +268,104 @@ impl HmsCatalog {
                 "StorageFactory must be provided for HmsCatalog. Use `with_storage_factory` to configure it.",
             )
         })?;
-        let file_io = FileIOBuilder::new(factory)
-            .with_props(&config.props)
-            .build();
+        let mut file_io = FileIOBuilder::new(factory).with_props(&config.props);
+        if let Some(client) = key_management_client {
+            file_io = file_io.with_key_management_client(client);
+        }
 
         Ok(Self {
             config,
             client: HmsClient(client),
-            file_io,
+            file_io: file_io.build(),
+            metrics_reporter,
         })
     }
     /// Get the catalogs `FileIO`
     pub fn file_io(&self) -> FileIO {
         self.file_io.clone()
     }
+
+    /// Writes the metadata of a commit and swaps the metadata location of the table to it.
+    ///
+    /// The table is loaded again, so that the requirements of the commit are checked against
+    /// its latest metadata. HMS is asked to alter the table only if its metadata location is
+    /// still the loaded one, which guards commits made without a lock.
+    async fn commit_table(
+        &self,
+        db_name: &str,
+        table_ident: &TableIdent,
+        commit: TableCommit,
+    ) -> Result<Table> {
+        let hive_table = self
+            .client
+            .0
+            .get_table(db_name.to_string().into(), table_ident.name.clone().into())
+            .await
+            .map(from_thrift_exception)
+            .map_err(from_thrift_error)??;
+
+        let current_metadata_location = get_metadata_location(&hive_table.parameters)?;
+        let metadata = TableMetadata::read_from(&self.file_io, &current_metadata_location).await?;
+        let current_table = Table::builder()
+            .file_io(self.file_io())
+            .metadata_location(current_metadata_location.clone())
+            .metadata(metadata)
+            .identifier(table_ident.clone())
+            .metrics_reporter(self.metrics_reporter.clone())
+            .build()?;
+
+        let staged_table = commit.apply(current_table)?;
+        let staged_metadata_location = staged_table.metadata_location_result()?.to_string();
+        staged_table
+            .metadata()
+            .write_to(&self.file_io, &staged_metadata_location)
+            .await?;
+
+        let hive_table = update_hive_table(
+            hive_table,
+            staged_table.metadata(),
+            staged_metadata_location.clone(),
+            current_metadata_location.clone(),
+        )?;
+
+        let result = self
+            .client
+            .0
+            .alter_table_with_environment_context(
+                db_name.to_string().into(),
+                table_ident.name.clone().into(),
+                hive_table,
+                expected_metadata_location_context(&current_metadata_location),
+            )
+            .await;
+
+        let error = match result {
+            Ok(MaybeException::Ok(_)) => return Ok(staged_table),
+            // HMS rejects the change if the metadata location was changed concurrently.
+            Ok(MaybeException::Exception(exception))
+                if is_metadata_location_conflict(&exception) =>
+            {
+                Error::new(
+                    ErrorKind::CatalogCommitConflicts,
+                    format!("Commit conflicted for table: {table_ident}"),
+                )
+                .with_retryable(true)
+            }
+            Ok(exception) => match from_thrift_exception(exception) {
+                Ok(_) => return Ok(staged_table),
+                Err(e) => e,
+            },
+            // The table may or may not have been altered, so the metadata file is kept.
+            Err(e) => return Err(from_thrift_error(e)),
+        };
+
+        if let Err(e) = self.file_io.delete(&staged_metadata_location).await {
+            tracing::warn!(
+                "Failed to delete the metadata file {staged_metadata_location} of a failed commit: {e}"
+            );
+        }
+        Err(error)
+    }
 }
 
 #[async_trait]
@@ -488,6 +625,7 @@ impl Catalog for HmsCatalog {
             .metadata_location(metadata_location)
             .metadata(metadata)
             .identifier(TableIdent::new(NamespaceIdent::new(db_name), table_name))
+            .metrics_reporter(self.metrics_reporter.clone())
             .build()
     }
 
@@ -526,6 +664,7 @@ impl Catalog for HmsCatalog {
                 NamespaceIdent::new(db_name),
                 table.name.clone(),
             ))
+            .metrics_reporter(self.metrics_reporter.clone())
             .build()
     }
 
@@ -624,10 +763,38 @@ impl Catalog for HmsCatalog {
         ))
     }
 
-    async fn update_table(&self, _commit: TableCommit) -> Result<Table> {
-        Err(Error::new(
-            ErrorKind::FeatureUnsupported,
-            "Updating a table is not supported yet",
-        ))
+    /// Commits updates to a table by writing its new metadata and swapping the
+    /// `metadata_location` parameter of the table in HMS.
+    ///
+    /// Unless disabled with [`HMS_CATALOG_PROP_LOCK_ENABLED`], the table is locked in HMS
+    /// during the commit, and the lock is kept alive by heartbeats.
+    ///
+    /// # Errors
+    /// Returns a retryable [`ErrorKind::CatalogCommitConflicts`] error if the lock could not be
+    /// acquired in time or if the table was changed concurrently.
+    async fn update_table(&self, commit: TableCommit) -> Result<Table> {
+        let table_ident = commit.identifier().clone();
+        let db_name = validate_namespace(table_ident.namespace())?;
+
+        let lock = if self.config.lock.enabled {
+            Some(
+                HmsLock::acquire(
+                    &self.client.0,
+                    &self.config.lock,
+                    &db_name,
+                    &table_ident.name,
+                )
+                .await?,
+            )
+        } else {
+            None
+        };
+
+        let result = self.commit_table(&db_name, &table_ident, commit).await;
+
+        if let Some(lock) = lock {
+            lock.release().await;
+        }
+        result
     }
 }
@@ -52,5 +52,6 @@ mod catalog;
 pub use catalog::*;
 
 mod error;
+mod lock;
 mod schema;
 mod utils;
@@ -0,0 +1,311 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use hive_metastore::{
+    CheckLockRequest, DataOperationType, HeartbeatRequest, LockComponent, LockLevel, LockRequest,
+    LockState, LockType, ThriftHiveMetastoreClient, UnlockRequest,
+};
+use iceberg::{Error, ErrorKind, Result};
+use tokio::task::JoinHandle;
+use tokio::time::{Instant, sleep};
+
+use crate::catalog::{
+    HMS_CATALOG_PROP_LOCK_ENABLED, HMS_CATALOG_PROP_LOCK_HEARTBEAT_INTERVAL_MS,
+    HMS_CATALOG_PROP_LOCK_TIMEOUT_MS,
+};
+use crate::error::{from_thrift_error, from_thrift_exception};
+
+/// Default maximum time waited for a table lock.
+const DEFAULT_LOCK_TIMEOUT: Duration = Duration::from_secs(3 * 60);
+/// Default interval of the heartbeats keeping a table lock alive.
+const DEFAULT_LOCK_HEARTBEAT_INTERVAL: Duration = Duration::from_secs(4 * 60);
+/// First wait before checking a waiting lock again, doubled after each check.
+const LOCK_CHECK_MIN_WAIT: Duration = Duration::from_millis(50);
+/// Maximum wait between checks of a waiting lock.
+const LOCK_CHECK_MAX_WAIT: Duration = Duration::from_secs(5);
+
+/// Configuration of the table locks taken by commits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct HmsLockConfig {
+    pub(crate) enabled: bool,
+    pub(crate) timeout: Duration,
+    pub(crate) heartbeat_interval: Duration,
+}
+
+impl Default for HmsLockConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            timeout: DEFAULT_LOCK_TIMEOUT,
+            heartbeat_interval: DEFAULT_LOCK_HEARTBEAT_INTERVAL,
+        }
+    }
+}
+
+impl HmsLockConfig {
+    /// Parses the lock configuration from the catalog properties.
+    pub(crate) fn from_props(props: &HashMap<String, String>) -> Result<Self> {
+        let mut config = Self::default();
+
+        if let Some(enabled) = props.get(HMS_CATALOG_PROP_LOCK_ENABLED) {
+            config.enabled = enabled.parse().map_err(|e| {
+                Error::new(
+                    ErrorKind::DataInvalid,
+                    format!("Invalid value for {HMS_CATALOG_PROP_LOCK_ENABLED}: {enabled}"),
+                )
+                .with_source(e)
+            })?;
+        }
+        if let Some(timeout) = parse_millis(props, HMS_CATALOG_PROP_LOCK_TIMEOUT_MS)? {
+            config.timeout = timeout;
+        }
+        if let Some(interval) = parse_millis(props, HMS_CATALOG_PROP_LOCK_HEARTBEAT_INTERVAL_MS)? {
+            config.heartbeat_interval = interval;
+        }
+
+        Ok(config)
+    }
+}
+
+fn parse_millis(props: &HashMap<String, String>, key: &str) -> Result<Option<Duration>> {
+    props
+        .get(key)
+        .map(|value| {
+            value.parse().map(Duration::from_millis).map_err(|e| {
+                Error::new(
+                    ErrorKind::DataInvalid,
+                    format!("Invalid value for {key}: {value}"),
+                )
+                .with_source(e)
+            })
+        })
+        .transpose()
+}
+
+/// An exclusive HMS lock on a table, kept alive by heartbeats until it is released.
+///
+/// A lock dropped without being released, e.g. because the commit holding it was cancelled, is
+/// released in the background.
+pub(crate) struct HmsLock {
+    client: ThriftHiveMetastoreClient,
+    lock_id: i64,
+    heartbeat: Option<JoinHandle<()>>,
+    released: bool,
+}
+
+impl HmsLock {
+    /// Acquires an exclusive lock on a table, waiting up to the configured timeout while the
+    /// lock is held by others.
+    ///
+    /// Failing to acquire the lock in time is reported as a retryable commit conflict.
+    pub(crate) async fn acquire(
+        client: &ThriftHiveMetastoreClient,
+        config: &HmsLockConfig,
+        db_name: &str,
+        table_name: &str,
+    ) -> Result<Self> {
+        let request = LockRequest {
+            component: vec![LockComponent {
+                r#type: LockType::EXCLUSIVE,
+                level: LockLevel::TABLE,
+                dbname: db_name.to_string().into(),
+                tablename: Some(table_name.to_string().into()),
+                operation_type: Some(DataOperationType::NO_TXN),
+                ..Default::default()
+            }],
+            user: lock_user().into(),
+            hostname: lock_hostname().into(),
+            ..Default::default()
+        };
+
+        let response = client
+            .lock(request)
+            .await
+            .map(from_thrift_exception)
+            .map_err(from_thrift_error)??;
+        // Guards the lock request from here on, so that it is released if acquiring it is
+        // cancelled while waiting.
+        let mut lock = Self {
+            client: client.clone(),
+            lock_id: response.lockid,
+            heartbeat: None,
+            released: false,
+        };
+        let mut state = response.state;
+
+        let deadline = Instant::now() + config.timeout;
+        let mut wait = LOCK_CHECK_MIN_WAIT;
+        while state == LockState::WAITING && Instant::now() < deadline {
+            sleep(wait.min(deadline - Instant::now())).await;
+            wait = (wait * 2).min(LOCK_CHECK_MAX_WAIT);
+
+            state = client
+                .check_lock(CheckLockRequest {
+                    lockid: lock.lock_id,
+                    ..Default::default()
+                })
+                .await
+                .map(from_thrift_exception)
+                .map_err(from_thrift_error)??
+                .state;
+        }
+
+        if state != LockState::ACQUIRED {
+            lock.release().await;
+
+            let reason = if state == LockState::WAITING {
+                format!("timed out after {:?}", config.timeout)
+            } else {
+                format!("lock state is {state:?}")
+            };
+            return Err(Error::new(
+                ErrorKind::CatalogCommitConflicts,
+                format!("Failed to acquire the lock of table {db_name}.{table_name}: {reason}"),
+            )
+            .with_retryable(true));
+        }
+
+        lock.heartbeat = Some(tokio::spawn(heartbeat(
+            client.clone(),
+            lock.lock_id,
+            config.heartbeat_interval,
+        )));
+        Ok(lock)
+    }
+
+    /// Releases the lock. Failures are logged, as HMS expires locks which are not kept alive.
+    pub(crate) async fn release(mut self) {
+        self.stop_heartbeat();
+        self.released = true;
+        if let Err(e) = unlock(&self.client, self.lock_id).await {
+            tracing::warn!("Failed to release HMS lock {}: {e}", self.lock_id);
+        }
+    }
+
+    fn stop_heartbeat(&mut self) {
+        if let Some(heartbeat) = self.heartbeat.take() {
+            heartbeat.abort();
+        }
+    }
+}
+
+impl Drop for HmsLock {
+    fn drop(&mut self) {
+        self.stop_heartbeat();
+        if self.released {
+            return;
+        }
+
+        let client = self.client.clone();
+        let lock_id = self.lock_id;
+        match tokio::runtime::Handle::try_current() {
+            Ok(handle) => {
+                handle.spawn(async move {
+                    if let Err(e) = unlock(&client, lock_id).await {
+                        tracing::warn!("Failed to release dropped HMS lock {lock_id}: {e}");
+                    }
+                });
+            }
+            Err(_) => tracing::warn!(
+                "HMS lock {lock_id} dropped outside of a runtime, it is released once it expires"
+            ),
+        }
+    }
+}
+
+/// Keeps a lock alive until the task is aborted.
+async fn heartbeat(client: ThriftHiveMetastoreClient, lock_id: i64, interval: Duration) {
+    let mut ticker = tokio::time::interval_at(Instant::now() + interval, interval);
+    loop {
+        ticker.tick().await;
+
+        let result = client
+            .heartbeat(HeartbeatRequest {
+                lockid: Some(lock_id),
+                ..Default::default()
+            })
+            .await
+            .map(from_thrift_exception)
+            .map_err(from_thrift_error);
+        if let Err(e) | Ok(Err(e)) = result {
+            tracing::warn!("Failed to send a heartbeat for HMS lock {lock_id}: {e}");
+        }
+    }
+}
+
+async fn unlock(client: &ThriftHiveMetastoreClient, lock_id: i64) -> Result<()> {
+    client
+        .unlock(UnlockRequest { lockid: lock_id })
+        .await
+        .map(from_thrift_exception)
+        .map_err(from_thrift_error)?
+}
+
+fn lock_user() -> String {
+    std::env::var("USER").unwrap_or_else(|_| "iceberg".to_string())
+}
+
+fn lock_hostname() -> String {
+    std::env::var("HOSTNAME").unwrap_or_else(|_| "localhost".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_lock_config_from_props() -> Result<()> {
+        assert_eq!(
+            HmsLockConfig::from_props(&HashMap::new())?,
+            HmsLockConfig::default()
+        );
+
+        let props = HashMap::from([
+            (
+                HMS_CATALOG_PROP_LOCK_ENABLED.to_string(),
+                "false".to_string(),
+            ),
+            (
+                HMS_CATALOG_PROP_LOCK_TIMEOUT_MS.to_string(),
+                "1000".to_string(),
+            ),
+            (
+                HMS_CATALOG_PROP_LOCK_HEARTBEAT_INTERVAL_MS.to_string(),
+                "2000".to_string(),
+            ),
+        ]);
+        assert_eq!(HmsLockConfig::from_props(&props)?, HmsLockConfig {
+            enabled: false,
+            timeout: Duration::from_secs(1),
+            heartbeat_interval: Duration::from_secs(2),
+        });
+
+        let invalid = HashMap::from([(
+            HMS_CATALOG_PROP_LOCK_TIMEOUT_MS.to_string(),
+            "soon".to_string(),
+        )]);
+        assert!(HmsLockConfig::from_props(&invalid).is_err());
+
+        Ok(())
+    }
+}
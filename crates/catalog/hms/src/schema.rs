@@ -131,7 +131,10 @@ impl SchemaVisitor for HiveSchemaBuilder {
             PrimitiveType::Time | PrimitiveType::String | PrimitiveType::Uuid => {
                 "string".to_string()
             }
-            PrimitiveType::Binary | PrimitiveType::Fixed(_) => "binary".to_string(),
+            PrimitiveType::Binary
+            | PrimitiveType::Fixed(_)
+            | PrimitiveType::Geometry { .. }
+            | PrimitiveType::Geography { .. } => "binary".to_string(),
             PrimitiveType::Decimal { precision, scale } => {
                 format!("decimal({precision},{scale})")
             }
@@ -139,6 +142,11 @@ impl SchemaVisitor for HiveSchemaBuilder {
 
         Ok(hive_type)
     }
+
+    /// Hive has no variant type, so a variant is a struct of its binary metadata and value.
+    fn variant(&mut self) -> iceberg::Result<String> {
+        Ok("struct<metadata:binary,value:binary>".to_string())
+    }
 }
 
 #[cfg(test)]
@@ -456,4 +464,56 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_schema_with_variant() -> Result<()> {
+        let record = r#"
+            {
+                "schema-id": 1,
+                "type": "struct",
+                "fields": [
+                    {
+                        "id": 1,
+                        "name": "v",
+                        "required": false,
+                        "type": "variant"
+                    },
+                    {
+                        "id": 2,
+                        "name": "m",
+                        "required": true,
+                        "type": {
+                            "type": "map",
+                            "key-id": 3,
+                            "key": "string",
+                            "value-id": 4,
+                            "value-required": false,
+                            "value": "variant"
+                        }
+                    }
+                ]
+            }
+        "#;
+
+        let schema = serde_json::from_str::<Schema>(record)?;
+
+        let result = HiveSchemaBuilder::from_iceberg(&schema)?.build();
+
+        let expected = vec![
+            FieldSchema {
+                name: Some("v".into()),
+                r#type: Some("struct<metadata:binary,value:binary>".into()),
+                comment: None,
+            },
+            FieldSchema {
+                name: Some("m".into()),
+                r#type: Some("map<string,struct<metadata:binary,value:binary>>".into()),
+                comment: None,
+            },
+        ];
+
+        assert_eq!(result, expected);
+
+        Ok(())
+    }
 }
@@ -18,8 +18,11 @@
 use std::collections::HashMap;
 
 use chrono::Utc;
-use hive_metastore::{Database, PrincipalType, SerDeInfo, StorageDescriptor};
-use iceberg::spec::Schema;
+use hive_metastore::{
+    Database, EnvironmentContext, MetaException, PrincipalType, SerDeInfo, StorageDescriptor,
+    ThriftHiveMetastoreAlterTableWithEnvironmentContextException,
+};
+use iceberg::spec::{Schema, TableMetadata};
 use iceberg::{Error, ErrorKind, Namespace, NamespaceIdent, Result};
 use pilota::{AHashMap, FastStr};
 
@@ -39,6 +42,12 @@ const COMMENT: &str = "comment";
 const LOCATION: &str = "location";
 /// hive metatore `metadata_location` property
 const METADATA_LOCATION: &str = "metadata_location";
+/// hive metatore `previous_metadata_location` property
+const PREVIOUS_METADATA_LOCATION: &str = "previous_metadata_location";
+/// Environment context key of the table parameter HMS checks before altering a table
+const EXPECTED_PARAMETER_KEY: &str = "expected_parameter_key";
+/// Environment context key of the value the checked table parameter must have
+const EXPECTED_PARAMETER_VALUE: &str = "expected_parameter_value";
 /// hive metatore `external` property
 const EXTERNAL: &str = "EXTERNAL";
 /// hive metatore `external_table` property
@@ -206,6 +215,69 @@ pub(crate) fn convert_to_hive_table(
     })
 }
 
+/// Updates a `hive_metastore::Table` to point to the metadata committed at `metadata_location`,
+/// keeping the replaced location as `previous_metadata_location`.
+pub(crate) fn update_hive_table(
+    mut table: hive_metastore::Table,
+    metadata: &TableMetadata,
+    metadata_location: String,
+    previous_metadata_location: String,
+) -> Result<hive_metastore::Table> {
+    let hive_schema = HiveSchemaBuilder::from_iceberg(metadata.current_schema())?.build();
+    let storage_descriptor = table.sd.get_or_insert_with(Default::default);
+    storage_descriptor.location = Some(metadata.location().to_string().into());
+    storage_descriptor.cols = Some(hive_schema);
+
+    let parameters = table.parameters.get_or_insert_with(AHashMap::new);
+    parameters.insert(
+        FastStr::from(METADATA_LOCATION),
+        FastStr::from(metadata_location),
+    );
+    parameters.insert(
+        FastStr::from(PREVIOUS_METADATA_LOCATION),
+        FastStr::from(previous_metadata_location),
+    );
+
+    Ok(table)
+}
+
+/// Returns the environment context making HMS (4.0 and later) alter a table only if its
+/// metadata location is still `metadata_location`.
+///
+/// Older HMS versions ignore the context.
+pub(crate) fn expected_metadata_location_context(metadata_location: &str) -> EnvironmentContext {
+    EnvironmentContext {
+        properties: Some(AHashMap::from([
+            (
+                FastStr::from(EXPECTED_PARAMETER_KEY),
+                FastStr::from(METADATA_LOCATION),
+            ),
+            (
+                FastStr::from(EXPECTED_PARAMETER_VALUE),
+                FastStr::from(metadata_location.to_string()),
+            ),
+        ])),
+    }
+}
+
+/// Returns whether HMS rejected an `alter_table_with_environment_context` call because the
+/// metadata location was no longer the one expected by [`expected_metadata_location_context`].
+///
+/// HMS reports this as a `MetaException` without a dedicated error code, so its message is
+/// matched the same way the Java `HiveTableOperations` does.
+pub(crate) fn is_metadata_location_conflict(
+    exception: &ThriftHiveMetastoreAlterTableWithEnvironmentContextException,
+) -> bool {
+    match exception {
+        ThriftHiveMetastoreAlterTableWithEnvironmentContextException::O2(MetaException {
+            message: Some(message),
+        }) => message.starts_with(&format!(
+            "The table has been modified. The parameter value for key '{METADATA_LOCATION}' is"
+        )),
+        _ => false,
+    }
+}
+
 /// Checks if provided `NamespaceIdent` is valid.
 pub(crate) fn validate_namespace(namespace: &NamespaceIdent) -> Result<String> {
     let name = namespace.as_ref();
@@ -311,8 +383,8 @@ fn get_current_time() -> Result<i32> {
 
 #[cfg(test)]
 mod tests {
-    use iceberg::spec::{NestedField, PrimitiveType, Type};
-    use iceberg::{MetadataLocation, Namespace, NamespaceIdent};
+    use iceberg::spec::{NestedField, PrimitiveType, TableMetadataBuilder, Type};
+    use iceberg::{MetadataLocation, Namespace, NamespaceIdent, TableCreation};
 
     use super::*;
 
@@ -388,6 +460,86 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_update_hive_table() -> Result<()> {
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "foo", Type::Primitive(PrimitiveType::Int)).into(),
+            ])
+            .build()?;
+        let metadata = TableMetadataBuilder::from_table_creation(
+            TableCreation::builder()
+                .name("my_table".to_string())
+                .location("s3a://warehouse/hms/my_table".to_string())
+                .schema(schema.clone())
+                .build(),
+        )?
+        .build()?
+        .metadata;
+        let table = convert_to_hive_table(
+            "my_db".to_string(),
+            &schema,
+            "my_table".to_string(),
+            "s3a://warehouse/hms/my_table".to_string(),
+            "old_location".to_string(),
+            &HashMap::new(),
+        )?;
+
+        let result = update_hive_table(
+            table,
+            &metadata,
+            "new_location".to_string(),
+            "old_location".to_string(),
+        )?;
+
+        assert_eq!(get_metadata_location(&result.parameters)?, "new_location");
+        let parameters = result.parameters.unwrap();
+        assert_eq!(
+            parameters.get(PREVIOUS_METADATA_LOCATION),
+            Some(&FastStr::from("old_location"))
+        );
+        assert_eq!(parameters.get(TABLE_TYPE), Some(&FastStr::from("ICEBERG")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_expected_metadata_location_context() {
+        let context = expected_metadata_location_context("old_location");
+        let properties = context.properties.unwrap();
+
+        assert_eq!(
+            properties.get(EXPECTED_PARAMETER_KEY),
+            Some(&FastStr::from(METADATA_LOCATION))
+        );
+        assert_eq!(
+            properties.get(EXPECTED_PARAMETER_VALUE),
+            Some(&FastStr::from("old_location"))
+        );
+    }
+
+    #[test]
+    fn test_is_metadata_location_conflict() {
+        let exception = |message: &str| {
+            ThriftHiveMetastoreAlterTableWithEnvironmentContextException::O2(MetaException {
+                message: Some(FastStr::from(message.to_string())),
+            })
+        };
+
+        assert!(is_metadata_location_conflict(&exception(
+            "The table has been modified. The parameter value for key 'metadata_location' is \
+             'new_location'. The expected was value was 'old_location'"
+        )));
+        assert!(!is_metadata_location_conflict(&exception(
+            "Unable to alter table"
+        )));
+        assert!(!is_metadata_location_conflict(
+            &ThriftHiveMetastoreAlterTableWithEnvironmentContextException::O2(MetaException {
+                message: None,
+            })
+        ));
+    }
+
     #[test]
     fn test_get_default_table_location() -> Result<()> {
         let properties = HashMap::from([(LOCATION.to_string(), "db_location".to_string())]);
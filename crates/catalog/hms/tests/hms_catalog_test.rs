@@ -25,7 +25,11 @@ use std::sync::Arc;
 
 use iceberg::io::{FileIOBuilder, S3_ACCESS_KEY_ID, S3_ENDPOINT, S3_REGION, S3_SECRET_ACCESS_KEY};
 use iceberg::spec::{NestedField, PrimitiveType, Schema, Type};
-use iceberg::{Catalog, CatalogBuilder, Namespace, NamespaceIdent, TableCreation, TableIdent};
+use iceberg::transaction::{ApplyTransactionAction, Transaction};
+use iceberg::{
+    Catalog, CatalogBuilder, Namespace, NamespaceIdent, TableCommit, TableCreation, TableIdent,
+    TableRequirement, TableUpdate,
+};
 use iceberg_catalog_hms::{
     HMS_CATALOG_PROP_THRIFT_TRANSPORT, HMS_CATALOG_PROP_URI, HMS_CATALOG_PROP_WAREHOUSE,
     HmsCatalog, HmsCatalogBuilder, THRIFT_TRANSPORT_BUFFERED,
@@ -210,6 +214,54 @@ async fn test_load_table() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_update_table() -> Result<()> {
+    let catalog = get_catalog().await;
+    let creation = set_table_creation(None, "my_table")?;
+    // Use unique namespace to avoid conflicts
+    let namespace = Namespace::new(NamespaceIdent::new(normalize_test_name_with_parts!(
+        "test_update_table"
+    )));
+    cleanup_namespace(&catalog, namespace.name()).await;
+    set_test_namespace(&catalog, namespace.name()).await?;
+
+    let table = catalog.create_table(namespace.name(), creation).await?;
+
+    let tx = Transaction::new(&table);
+    let updated = tx
+        .update_table_properties()
+        .set("prop1".to_string(), "v1".to_string())
+        .apply(tx)?
+        .commit(&catalog)
+        .await?;
+
+    assert_eq!(
+        updated.metadata().properties().get("prop1"),
+        Some(&"v1".to_string())
+    );
+    assert_ne!(updated.metadata_location(), table.metadata_location());
+
+    let loaded = catalog.load_table(table.identifier()).await?;
+    assert_eq!(loaded.metadata_location(), updated.metadata_location());
+    assert_eq!(loaded.metadata(), updated.metadata());
+
+    // Commits whose requirements don't hold leave the table unchanged.
+    let commit = TableCommit::new(
+        table.identifier().clone(),
+        vec![TableRequirement::CurrentSchemaIdMatch {
+            current_schema_id: 42,
+        }],
+        vec![TableUpdate::SetProperties {
+            updates: HashMap::from([("prop2".to_string(), "v2".to_string())]),
+        }],
+    );
+    assert!(catalog.update_table(commit).await.is_err());
+    let loaded = catalog.load_table(table.identifier()).await?;
+    assert_eq!(loaded.metadata_location(), updated.metadata_location());
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_create_table() -> Result<()> {
     let catalog = get_catalog().await;
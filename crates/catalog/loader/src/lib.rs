@@ -17,17 +17,30 @@
 
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 use async_trait::async_trait;
 use iceberg::io::StorageFactory;
-use iceberg::{Catalog, CatalogBuilder, Error, ErrorKind, Result};
+use iceberg::{
+    CachingCatalog, Catalog, CatalogBuilder, DEFAULT_CATALOG_CACHE_EXPIRATION,
+    DEFAULT_CATALOG_CACHE_MAX_ENTRIES, Error, ErrorKind, Result,
+};
 use iceberg_catalog_glue::GlueCatalogBuilder;
+use iceberg_catalog_hadoop::HadoopCatalogBuilder;
 use iceberg_catalog_hms::HmsCatalogBuilder;
 use iceberg_catalog_rest::RestCatalogBuilder;
 use iceberg_catalog_s3tables::S3TablesCatalogBuilder;
 use iceberg_catalog_sql::SqlCatalogBuilder;
 
 /// A CatalogBuilderFactory creating a new catalog builder.
+/// Wrap loaded catalogs in a [`CachingCatalog`] (defaults to false)
+pub const CATALOG_PROP_CACHE_ENABLED: &str = "cache-enabled";
+/// Time in milliseconds after which cached tables and namespace listings expire
+/// (defaults to 30 seconds)
+pub const CATALOG_PROP_CACHE_EXPIRATION_INTERVAL_MS: &str = "cache.expiration-interval-ms";
+/// Maximum number of cached tables, and of cached namespace listings (defaults to 1000)
+pub const CATALOG_PROP_CACHE_MAX_ENTRIES: &str = "cache.max-entries";
+
 type CatalogBuilderFactory = fn() -> Box<dyn BoxedCatalogBuilder>;
 
 /// A registry of catalog builders.
@@ -37,6 +50,7 @@ static CATALOG_REGISTRY: &[(&str, CatalogBuilderFactory)] = &[
     ("s3tables", || Box::new(S3TablesCatalogBuilder::default())),
     ("hms", || Box::new(HmsCatalogBuilder::default())),
     ("sql", || Box::new(SqlCatalogBuilder::default())),
+    ("hadoop", || Box::new(HadoopCatalogBuilder::default())),
 ];
 
 /// Return the list of supported catalog types.
@@ -70,11 +84,61 @@ impl<T: CatalogBuilder + 'static> BoxedCatalogBuilder for T {
     async fn load(
         self: Box<Self>,
         name: String,
-        props: HashMap<String, String>,
+        mut props: HashMap<String, String>,
     ) -> Result<Arc<dyn Catalog>> {
+        let cache_config = take_cache_config(&mut props)?;
         let builder = *self;
-        Ok(Arc::new(builder.load(name, props).await?) as Arc<dyn Catalog>)
+        let catalog = Arc::new(builder.load(name, props).await?) as Arc<dyn Catalog>;
+
+        Ok(match cache_config {
+            Some((expiration, max_entries)) => Arc::new(CachingCatalog::with_expiration(
+                catalog,
+                expiration,
+                max_entries,
+            )),
+            None => catalog,
+        })
+    }
+}
+
+/// Removes the cache properties from `props`, returning the expiration and maximum number of
+/// entries of the cache if caching is enabled.
+fn take_cache_config(props: &mut HashMap<String, String>) -> Result<Option<(Duration, u64)>> {
+    let enabled = props.remove(CATALOG_PROP_CACHE_ENABLED);
+    let expiration = props.remove(CATALOG_PROP_CACHE_EXPIRATION_INTERVAL_MS);
+    let max_entries = props.remove(CATALOG_PROP_CACHE_MAX_ENTRIES);
+
+    let parse_error = |key: &str, value: &str| {
+        Error::new(
+            ErrorKind::DataInvalid,
+            format!("Invalid value for {key}: {value}"),
+        )
+    };
+
+    let enabled = match enabled {
+        Some(value) => value
+            .parse::<bool>()
+            .map_err(|e| parse_error(CATALOG_PROP_CACHE_ENABLED, &value).with_source(e))?,
+        None => false,
+    };
+    if !enabled {
+        return Ok(None);
     }
+
+    let expiration = match expiration {
+        Some(value) => Duration::from_millis(value.parse().map_err(|e| {
+            parse_error(CATALOG_PROP_CACHE_EXPIRATION_INTERVAL_MS, &value).with_source(e)
+        })?),
+        None => DEFAULT_CATALOG_CACHE_EXPIRATION,
+    };
+    let max_entries = match max_entries {
+        Some(value) => value
+            .parse()
+            .map_err(|e| parse_error(CATALOG_PROP_CACHE_MAX_ENTRIES, &value).with_source(e))?,
+        None => DEFAULT_CATALOG_CACHE_MAX_ENTRIES,
+    };
+
+    Ok(Some((expiration, max_entries)))
 }
 
 /// Load a catalog from a string.
@@ -128,7 +192,40 @@ mod tests {
     use sqlx::migrate::MigrateDatabase;
     use tempfile::TempDir;
 
-    use crate::{CatalogLoader, load};
+    use crate::{
+        CATALOG_PROP_CACHE_ENABLED, CATALOG_PROP_CACHE_EXPIRATION_INTERVAL_MS, CatalogLoader, load,
+        take_cache_config,
+    };
+
+    #[test]
+    fn test_take_cache_config() {
+        let mut props = HashMap::from([("key".to_string(), "value".to_string())]);
+        assert_eq!(take_cache_config(&mut props).unwrap(), None);
+
+        let mut props = HashMap::from([
+            (CATALOG_PROP_CACHE_ENABLED.to_string(), "true".to_string()),
+            (
+                CATALOG_PROP_CACHE_EXPIRATION_INTERVAL_MS.to_string(),
+                "5000".to_string(),
+            ),
+            ("key".to_string(), "value".to_string()),
+        ]);
+        assert_eq!(
+            take_cache_config(&mut props).unwrap(),
+            Some((
+                std::time::Duration::from_secs(5),
+                iceberg::DEFAULT_CATALOG_CACHE_MAX_ENTRIES
+            ))
+        );
+        assert_eq!(
+            props,
+            HashMap::from([("key".to_string(), "value".to_string())])
+        );
+
+        let mut props =
+            HashMap::from([(CATALOG_PROP_CACHE_ENABLED.to_string(), "yes".to_string())]);
+        assert!(take_cache_config(&mut props).is_err());
+    }
 
     #[tokio::test]
     async fn test_load_unsupported_catalog() {
@@ -271,6 +368,48 @@ mod tests {
         assert!(catalog.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_catalog_loader_pattern_hadoop_catalog() {
+        use iceberg_catalog_hadoop::HADOOP_CATALOG_PROP_WAREHOUSE;
+
+        let catalog_loader = load("hadoop").unwrap();
+        let catalog = catalog_loader
+            .with_storage_factory(Arc::new(LocalFsStorageFactory))
+            .load(
+                "hadoop".to_string(),
+                HashMap::from([(
+                    HADOOP_CATALOG_PROP_WAREHOUSE.to_string(),
+                    format!("file://{}", temp_path()),
+                )]),
+            )
+            .await;
+
+        assert!(catalog.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_catalog_loader_with_cache() {
+        use iceberg_catalog_hadoop::HADOOP_CATALOG_PROP_WAREHOUSE;
+
+        let catalog = load("hadoop")
+            .unwrap()
+            .with_storage_factory(Arc::new(LocalFsStorageFactory))
+            .load(
+                "hadoop".to_string(),
+                HashMap::from([
+                    (
+                        HADOOP_CATALOG_PROP_WAREHOUSE.to_string(),
+                        format!("file://{}", temp_path()),
+                    ),
+                    (CATALOG_PROP_CACHE_ENABLED.to_string(), "true".to_string()),
+                ]),
+            )
+            .await
+            .unwrap();
+
+        assert!(format!("{catalog:?}").starts_with("CachingCatalog"));
+    }
+
     #[tokio::test]
     async fn test_error_message_includes_supported_types() {
         let err = match load("does-not-exist") {
@@ -0,0 +1,579 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! This module contains the routing of REST requests to the catalog.
+
+use std::collections::HashMap;
+
+use bytes::Bytes;
+use http_body_util::{BodyExt, Full};
+use hyper::body::Incoming;
+use hyper::header::{CONTENT_TYPE, HeaderValue};
+use hyper::{Request, Response, StatusCode};
+use iceberg::table::Table;
+use iceberg::view::View;
+use iceberg::{
+    Catalog, Error, ErrorKind, NamespaceIdent, Result, TableCommit, TableCreation, TableIdent,
+    ViewCommit, ViewCreation,
+};
+use iceberg_catalog_rest::{
+    CatalogConfig, CommitTableRequest, CommitTableResponse, CommitTransactionRequest,
+    CommitViewRequest, CreateNamespaceRequest, CreateTableRequest, CreateViewRequest, ErrorModel,
+    ErrorResponse, ListNamespaceResponse, ListTablesResponse, LoadTableResult, LoadViewResult,
+    NamespaceResponse, RegisterTableRequest, RenameTableRequest, UpdateNamespacePropertiesRequest,
+    UpdateNamespacePropertiesResponse,
+};
+use percent_encoding::percent_decode_str;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+type HttpResponse = Response<Full<Bytes>>;
+
+const PATH_V1: &str = "/v1/";
+
+/// Endpoints advertised to clients in the config response.
+const ENDPOINTS: &[&str] = &[
+    "GET /v1/{prefix}/namespaces",
+    "POST /v1/{prefix}/namespaces",
+    "GET /v1/{prefix}/namespaces/{namespace}",
+    "HEAD /v1/{prefix}/namespaces/{namespace}",
+    "DELETE /v1/{prefix}/namespaces/{namespace}",
+    "POST /v1/{prefix}/namespaces/{namespace}/properties",
+    "GET /v1/{prefix}/namespaces/{namespace}/tables",
+    "POST /v1/{prefix}/namespaces/{namespace}/tables",
+    "GET /v1/{prefix}/namespaces/{namespace}/tables/{table}",
+    "HEAD /v1/{prefix}/namespaces/{namespace}/tables/{table}",
+    "POST /v1/{prefix}/namespaces/{namespace}/tables/{table}",
+    "DELETE /v1/{prefix}/namespaces/{namespace}/tables/{table}",
+    "POST /v1/{prefix}/namespaces/{namespace}/register",
+    "POST /v1/{prefix}/namespaces/{namespace}/tables/{table}/metrics",
+    "POST /v1/{prefix}/tables/rename",
+    "POST /v1/{prefix}/transactions/commit",
+    "GET /v1/{prefix}/namespaces/{namespace}/views",
+    "POST /v1/{prefix}/namespaces/{namespace}/views",
+    "GET /v1/{prefix}/namespaces/{namespace}/views/{view}",
+    "HEAD /v1/{prefix}/namespaces/{namespace}/views/{view}",
+    "POST /v1/{prefix}/namespaces/{namespace}/views/{view}",
+    "DELETE /v1/{prefix}/namespaces/{namespace}/views/{view}",
+    "POST /v1/{prefix}/views/rename",
+];
+
+/// Handles a request of a REST client, turning errors into error responses.
+pub(crate) async fn handle(catalog: &dyn Catalog, request: Request<Incoming>) -> HttpResponse {
+    let (parts, body) = request.into_parts();
+    let body = match body.collect().await {
+        Ok(body) => body.to_bytes(),
+        Err(e) => {
+            return error_response(
+                &Error::new(ErrorKind::DataInvalid, "Failed to read the request body")
+                    .with_source(e),
+            );
+        }
+    };
+
+    match route(
+        catalog,
+        parts.method.as_str(),
+        parts.uri.path(),
+        parts.uri.query(),
+        body,
+    )
+    .await
+    {
+        Ok(response) => response,
+        Err(e) => error_response(&e),
+    }
+}
+
+async fn route(
+    catalog: &dyn Catalog,
+    method: &str,
+    path: &str,
+    query: Option<&str>,
+    body: Bytes,
+) -> Result<HttpResponse> {
+    let Some(segments) = path_segments(path)? else {
+        return Ok(endpoint_not_found(method, path));
+    };
+    let segments = segments.iter().map(String::as_str).collect::<Vec<_>>();
+
+    match (method, segments.as_slice()) {
+        ("GET", ["config"]) => json_response(&CatalogConfig {
+            overrides: HashMap::new(),
+            defaults: HashMap::new(),
+            endpoints: Some(ENDPOINTS.iter().map(|e| e.to_string()).collect()),
+        }),
+
+        ("GET", ["namespaces"]) => {
+            let parent = query_params(query)
+                .get("parent")
+                .map(|parent| namespace_ident(parent))
+                .transpose()?;
+            let namespaces = catalog.list_namespaces(parent.as_ref()).await?;
+            json_response(&ListNamespaceResponse {
+                namespaces,
+                next_page_token: None,
+            })
+        }
+        ("POST", ["namespaces"]) => {
+            let request: CreateNamespaceRequest = parse_body(&body)?;
+            let namespace = catalog
+                .create_namespace(&request.namespace, request.properties)
+                .await?;
+            json_response(&NamespaceResponse::from(&namespace))
+        }
+        ("GET", ["namespaces", namespace]) => {
+            let namespace = catalog.get_namespace(&namespace_ident(namespace)?).await?;
+            json_response(&NamespaceResponse::from(&namespace))
+        }
+        ("HEAD", ["namespaces", namespace]) => exists_response(
+            catalog
+                .namespace_exists(&namespace_ident(namespace)?)
+                .await?,
+        ),
+        ("DELETE", ["namespaces", namespace]) => {
+            catalog.drop_namespace(&namespace_ident(namespace)?).await?;
+            Ok(empty_response(StatusCode::NO_CONTENT))
+        }
+        ("POST", ["namespaces", namespace, "properties"]) => {
+            update_namespace_properties(catalog, &namespace_ident(namespace)?, &body).await
+        }
+
+        ("GET", ["namespaces", namespace, "tables"]) => {
+            let identifiers = catalog.list_tables(&namespace_ident(namespace)?).await?;
+            json_response(&ListTablesResponse {
+                identifiers,
+                next_page_token: None,
+            })
+        }
+        ("POST", ["namespaces", namespace, "tables"]) => {
+            create_table(catalog, namespace_ident(namespace)?, &body).await
+        }
+        ("POST", ["namespaces", namespace, "register"]) => {
+            let request: RegisterTableRequest = parse_body(&body)?;
+            if request.overwrite.unwrap_or(false) {
+                return Err(Error::new(
+                    ErrorKind::FeatureUnsupported,
+                    "Registering a table overwriting an existing table is not supported",
+                ));
+            }
+            let table_ident = TableIdent::new(namespace_ident(namespace)?, request.name);
+            let table = catalog
+                .register_table(&table_ident, request.metadata_location)
+                .await?;
+            json_response(&load_table_result(&table))
+        }
+        ("GET", ["namespaces", namespace, "tables", table]) => {
+            let table = catalog.load_table(&table_ident(namespace, table)?).await?;
+            json_response(&load_table_result(&table))
+        }
+        ("HEAD", ["namespaces", namespace, "tables", table]) => exists_response(
+            catalog
+                .table_exists(&table_ident(namespace, table)?)
+                .await?,
+        ),
+        ("POST", ["namespaces", namespace, "tables", table]) => {
+            let request: CommitTableRequest = parse_body(&body)?;
+            let commit = TableCommit::new(
+                table_ident(namespace, table)?,
+                request.requirements,
+                request.updates,
+            );
+            let table = catalog.update_table(commit).await?;
+            json_response(&CommitTableResponse {
+                metadata_location: table.metadata_location_result()?.to_string(),
+                metadata: table.metadata().clone(),
+            })
+        }
+        ("DELETE", ["namespaces", namespace, "tables", table]) => {
+            let table_ident = table_ident(namespace, table)?;
+            let purge = query_params(query)
+                .get("purgeRequested")
+                .is_some_and(|purge| purge == "true");
+            if purge {
+                catalog.purge_table(&table_ident).await?;
+            } else {
+                catalog.drop_table(&table_ident).await?;
+            }
+            Ok(empty_response(StatusCode::NO_CONTENT))
+        }
+        // Metrics reports are accepted so that clients report without errors, and dropped.
+        ("POST", ["namespaces", _, "tables", _, "metrics"]) => {
+            Ok(empty_response(StatusCode::NO_CONTENT))
+        }
+        ("POST", ["tables", "rename"]) => {
+            let request: RenameTableRequest = parse_body(&body)?;
+            catalog
+                .rename_table(&request.source, &request.destination)
+                .await?;
+            Ok(empty_response(StatusCode::NO_CONTENT))
+        }
+        ("POST", ["transactions", "commit"]) => {
+            let request: CommitTransactionRequest = parse_body(&body)?;
+            let commits = request
+                .table_changes
+                .into_iter()
+                .map(|change| {
+                    let identifier = change.identifier.ok_or_else(|| {
+                        Error::new(
+                            ErrorKind::DataInvalid,
+                            "The table changes of a transaction must have an identifier",
+                        )
+                    })?;
+                    Ok(TableCommit::new(
+                        identifier,
+                        change.requirements,
+                        change.updates,
+                    ))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            catalog.commit_transaction(commits).await?;
+            Ok(empty_response(StatusCode::NO_CONTENT))
+        }
+
+        ("GET", ["namespaces", namespace, "views"]) => {
+            let identifiers = catalog.list_views(&namespace_ident(namespace)?).await?;
+            json_response(&ListTablesResponse {
+                identifiers,
+                next_page_token: None,
+            })
+        }
+        ("POST", ["namespaces", namespace, "views"]) => {
+            create_view(catalog, &namespace_ident(namespace)?, &body).await
+        }
+        ("GET", ["namespaces", namespace, "views", view]) => {
+            let view = catalog.load_view(&table_ident(namespace, view)?).await?;
+            json_response(&load_view_result(&view)?)
+        }
+        ("HEAD", ["namespaces", namespace, "views", view]) => {
+            exists_response(catalog.view_exists(&table_ident(namespace, view)?).await?)
+        }
+        ("POST", ["namespaces", namespace, "views", view]) => {
+            let request: CommitViewRequest = parse_body(&body)?;
+            let commit = ViewCommit::new(
+                table_ident(namespace, view)?,
+                request.requirements,
+                request.updates,
+            );
+            let view = catalog.update_view(commit).await?;
+            json_response(&load_view_result(&view)?)
+        }
+        ("DELETE", ["namespaces", namespace, "views", view]) => {
+            catalog.drop_view(&table_ident(namespace, view)?).await?;
+            Ok(empty_response(StatusCode::NO_CONTENT))
+        }
+        ("POST", ["views", "rename"]) => {
+            let request: RenameTableRequest = parse_body(&body)?;
+            catalog
+                .rename_view(&request.source, &request.destination)
+                .await?;
+            Ok(empty_response(StatusCode::NO_CONTENT))
+        }
+
+        _ => Ok(endpoint_not_found(method, path)),
+    }
+}
+
+async fn update_namespace_properties(
+    catalog: &dyn Catalog,
+    namespace: &NamespaceIdent,
+    body: &Bytes,
+) -> Result<HttpResponse> {
+    let request: UpdateNamespacePropertiesRequest = parse_body(body)?;
+    let removals = request.removals.unwrap_or_default();
+    if let Some(key) = removals.iter().find(|k| request.updates.contains_key(*k)) {
+        return Err(Error::new(
+            ErrorKind::DataInvalid,
+            format!("Property {key} can not be both updated and removed"),
+        ));
+    }
+
+    let mut properties = catalog.get_namespace(namespace).await?.properties().clone();
+    let (removed, missing): (Vec<_>, Vec<_>) = removals
+        .into_iter()
+        .partition(|key| properties.remove(key).is_some());
+    let updated = request.updates.keys().cloned().collect();
+    properties.extend(request.updates);
+
+    catalog.update_namespace(namespace, properties).await?;
+
+    json_response(&UpdateNamespacePropertiesResponse {
+        updated,
+        removed,
+        missing: (!missing.is_empty()).then_some(missing),
+    })
+}
+
+async fn create_table(
+    catalog: &dyn Catalog,
+    namespace: NamespaceIdent,
+    body: &Bytes,
+) -> Result<HttpResponse> {
+    let request: CreateTableRequest = parse_body(body)?;
+    let creation = TableCreation::builder()
+        .name(request.name)
+        .location_opt(request.location)
+        .schema(request.schema)
+        .partition_spec_opt(request.partition_spec)
+        .sort_order_opt(request.write_order)
+        .properties(request.properties)
+        .build();
+
+    let table = if request.stage_create.unwrap_or(false) {
+        catalog.stage_create_table(&namespace, creation).await?
+    } else {
+        catalog.create_table(&namespace, creation).await?
+    };
+    json_response(&load_table_result(&table))
+}
+
+async fn create_view(
+    catalog: &dyn Catalog,
+    namespace: &NamespaceIdent,
+    body: &Bytes,
+) -> Result<HttpResponse> {
+    let request: CreateViewRequest = parse_body(body)?;
+    let location = request.location.ok_or_else(|| {
+        Error::new(
+            ErrorKind::DataInvalid,
+            "Creating a view requires its location",
+        )
+    })?;
+    let version = request.view_version;
+    let creation = ViewCreation::builder()
+        .name(request.name)
+        .location(location)
+        .representations(version.representations().clone())
+        .schema(request.schema)
+        .properties(request.properties)
+        .default_namespace(version.default_namespace().clone())
+        .default_catalog(version.default_catalog().cloned())
+        .summary(version.summary().clone())
+        .build();
+
+    let view = catalog.create_view(namespace, creation).await?;
+    json_response(&load_view_result(&view)?)
+}
+
+fn load_table_result(table: &Table) -> LoadTableResult {
+    LoadTableResult {
+        metadata_location: table.metadata_location().map(str::to_string),
+        metadata: table.metadata().clone(),
+        config: HashMap::new(),
+        storage_credentials: None,
+    }
+}
+
+fn load_view_result(view: &View) -> Result<LoadViewResult> {
+    Ok(LoadViewResult {
+        metadata_location: view.metadata_location_result()?.to_string(),
+        metadata: view.metadata().clone(),
+        config: HashMap::new(),
+    })
+}
+
+/// Splits the path of a request after `/v1/` into percent-decoded segments.
+/// Returns the decoded segments of a path under `/v1/`, or `None` for other paths.
+fn path_segments(path: &str) -> Result<Option<Vec<String>>> {
+    let Some(path) = path.strip_prefix(PATH_V1) else {
+        return Ok(None);
+    };
+
+    path.split('/')
+        .map(|segment| {
+            percent_decode_str(segment)
+                .decode_utf8()
+                .map(|segment| segment.into_owned())
+                .map_err(|e| {
+                    Error::new(
+                        ErrorKind::DataInvalid,
+                        format!("Invalid path segment: {segment}"),
+                    )
+                    .with_source(e)
+                })
+        })
+        .collect::<Result<_>>()
+        .map(Some)
+}
+
+fn query_params(query: Option<&str>) -> HashMap<String, String> {
+    url::form_urlencoded::parse(query.unwrap_or_default().as_bytes())
+        .into_owned()
+        .collect()
+}
+
+/// Parses a namespace, whose levels are separated by the unit separator in paths.
+fn namespace_ident(namespace: &str) -> Result<NamespaceIdent> {
+    NamespaceIdent::from_strs(namespace.split('\u{1f}'))
+}
+
+fn table_ident(namespace: &str, name: &str) -> Result<TableIdent> {
+    Ok(TableIdent::new(
+        namespace_ident(namespace)?,
+        name.to_string(),
+    ))
+}
+
+fn parse_body<T: DeserializeOwned>(body: &Bytes) -> Result<T> {
+    serde_json::from_slice(body).map_err(|e| {
+        Error::new(ErrorKind::DataInvalid, "Failed to parse the request body").with_source(e)
+    })
+}
+
+fn json_response<T: Serialize>(value: &T) -> Result<HttpResponse> {
+    let body = serde_json::to_vec(value).map_err(|e| {
+        Error::new(
+            ErrorKind::Unexpected,
+            "Failed to serialize the response body",
+        )
+        .with_source(e)
+    })?;
+    Ok(with_json_content_type(Response::new(Full::new(
+        Bytes::from(body),
+    ))))
+}
+
+fn exists_response(exists: bool) -> Result<HttpResponse> {
+    Ok(empty_response(if exists {
+        StatusCode::NO_CONTENT
+    } else {
+        StatusCode::NOT_FOUND
+    }))
+}
+
+fn empty_response(status: StatusCode) -> HttpResponse {
+    let mut response = Response::new(Full::new(Bytes::new()));
+    *response.status_mut() = status;
+    response
+}
+
+fn with_json_content_type(mut response: HttpResponse) -> HttpResponse {
+    response
+        .headers_mut()
+        .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+    response
+}
+
+/// Maps an error of the catalog to the status and error type of the REST API.
+fn error_status(kind: ErrorKind) -> (StatusCode, &'static str) {
+    match kind {
+        ErrorKind::NamespaceNotFound => (StatusCode::NOT_FOUND, "NoSuchNamespaceException"),
+        ErrorKind::TableNotFound => (StatusCode::NOT_FOUND, "NoSuchTableException"),
+        ErrorKind::ViewNotFound => (StatusCode::NOT_FOUND, "NoSuchViewException"),
+        ErrorKind::NamespaceAlreadyExists
+        | ErrorKind::TableAlreadyExists
+        | ErrorKind::ViewAlreadyExists => (StatusCode::CONFLICT, "AlreadyExistsException"),
+        ErrorKind::CatalogCommitConflicts => (StatusCode::CONFLICT, "CommitFailedException"),
+        // Conflicts found by validations can't be resolved by retrying the commit
+        ErrorKind::CommitConflict => (StatusCode::BAD_REQUEST, "ValidationException"),
+        ErrorKind::FeatureUnsupported => {
+            (StatusCode::NOT_ACCEPTABLE, "UnsupportedOperationException")
+        }
+        ErrorKind::DataInvalid | ErrorKind::PreconditionFailed => {
+            (StatusCode::BAD_REQUEST, "BadRequestException")
+        }
+        _ => (StatusCode::INTERNAL_SERVER_ERROR, "ServiceFailureException"),
+    }
+}
+
+fn error_response(error: &Error) -> HttpResponse {
+    let (status, r#type) = error_status(error.kind());
+    error_model_response(status, r#type, error.to_string())
+}
+
+/// Response to requests for endpoints the server doesn't implement.
+fn endpoint_not_found(method: &str, path: &str) -> HttpResponse {
+    error_model_response(
+        StatusCode::NOT_FOUND,
+        "NoSuchEndpointException",
+        format!("No endpoint {method} {path}"),
+    )
+}
+
+fn error_model_response(status: StatusCode, r#type: &str, message: String) -> HttpResponse {
+    let body = ErrorResponse {
+        error: ErrorModel {
+            message,
+            r#type: r#type.to_string(),
+            code: status.as_u16(),
+            stack: None,
+        },
+    };
+
+    let mut response = match json_response(&body) {
+        Ok(response) => response,
+        Err(e) => {
+            tracing::warn!("Failed to serialize an error response: {e}");
+            empty_response(status)
+        }
+    };
+    *response.status_mut() = status;
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use hyper::StatusCode;
+    use iceberg::{ErrorKind, NamespaceIdent};
+
+    use super::{error_status, namespace_ident, path_segments, query_params};
+
+    #[test]
+    fn test_path_segments() {
+        assert_eq!(
+            path_segments("/v1/namespaces/a%1Fb/tables/my%20table").unwrap(),
+            Some(vec![
+                "namespaces".to_string(),
+                "a\u{1f}b".to_string(),
+                "tables".to_string(),
+                "my table".to_string()
+            ])
+        );
+        assert_eq!(path_segments("/v2/namespaces").unwrap(), None);
+    }
+
+    #[test]
+    fn test_namespace_ident() {
+        assert_eq!(
+            namespace_ident("a\u{1f}b").unwrap(),
+            NamespaceIdent::from_strs(["a", "b"]).unwrap()
+        );
+
+        let params = query_params(Some("parent=a%1Fb&pageToken=1"));
+        assert_eq!(params.get("parent").unwrap(), "a\u{1f}b");
+    }
+
+    #[test]
+    fn test_error_status() {
+        assert_eq!(
+            error_status(ErrorKind::TableNotFound),
+            (StatusCode::NOT_FOUND, "NoSuchTableException")
+        );
+        assert_eq!(
+            error_status(ErrorKind::CatalogCommitConflicts),
+            (StatusCode::CONFLICT, "CommitFailedException")
+        );
+        assert_eq!(
+            error_status(ErrorKind::CommitConflict),
+            (StatusCode::BAD_REQUEST, "ValidationException")
+        );
+        assert_eq!(
+            error_status(ErrorKind::Unexpected),
+            (StatusCode::INTERNAL_SERVER_ERROR, "ServiceFailureException")
+        );
+    }
+}
@@ -0,0 +1,59 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Iceberg REST catalog server.
+//!
+//! Serves the routes of the Iceberg REST API on top of any [`Catalog`](iceberg::Catalog), so
+//! that REST clients can be run against an in-process catalog.
+//!
+//! # Example
+//!
+//! ```rust, no_run
+//! use std::collections::HashMap;
+//! use std::sync::Arc;
+//!
+//! use iceberg::CatalogBuilder;
+//! use iceberg::memory::{MEMORY_CATALOG_WAREHOUSE, MemoryCatalogBuilder};
+//! use iceberg_catalog_rest_server::RestCatalogServer;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let catalog = MemoryCatalogBuilder::default()
+//!         .load(
+//!             "memory",
+//!             HashMap::from([(
+//!                 MEMORY_CATALOG_WAREHOUSE.to_string(),
+//!                 "/tmp/warehouse".to_string(),
+//!             )]),
+//!         )
+//!         .await
+//!         .unwrap();
+//!
+//!     let server = RestCatalogServer::new(Arc::new(catalog))
+//!         .start("127.0.0.1:8181")
+//!         .await
+//!         .unwrap();
+//!     println!("Serving the REST catalog at {}", server.uri());
+//! }
+//! ```
+
+#![deny(missing_docs)]
+
+mod handler;
+mod server;
+
+pub use server::*;
@@ -0,0 +1,125 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! This module contains the server accepting the connections of REST clients.
+
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper_util::rt::TokioIo;
+use iceberg::{Catalog, Error, ErrorKind, Result};
+use tokio::net::{TcpListener, ToSocketAddrs};
+use tokio::task::JoinHandle;
+
+use crate::handler::handle;
+
+/// Serves the Iceberg REST catalog API on top of a [`Catalog`].
+///
+/// Requests are forwarded to the wrapped catalog, so the operations supported by the server
+/// are the ones supported by the catalog. The server does not authenticate clients.
+#[derive(Debug, Clone)]
+pub struct RestCatalogServer {
+    catalog: Arc<dyn Catalog>,
+}
+
+impl RestCatalogServer {
+    /// Creates a server forwarding requests to `catalog`.
+    pub fn new(catalog: Arc<dyn Catalog>) -> Self {
+        Self { catalog }
+    }
+
+    /// Serves the connections accepted by `listener` until the returned future is dropped.
+    pub async fn serve(self, listener: TcpListener) -> Result<()> {
+        loop {
+            let (stream, _) = listener.accept().await.map_err(|e| {
+                Error::new(ErrorKind::Unexpected, "Failed to accept a connection").with_source(e)
+            })?;
+
+            let catalog = self.catalog.clone();
+            tokio::spawn(async move {
+                let service = service_fn(move |request| {
+                    let catalog = catalog.clone();
+                    async move { Ok::<_, Infallible>(handle(catalog.as_ref(), request).await) }
+                });
+
+                if let Err(e) = http1::Builder::new()
+                    .serve_connection(TokioIo::new(stream), service)
+                    .await
+                {
+                    tracing::warn!("Failed to serve a REST catalog connection: {e}");
+                }
+            });
+        }
+    }
+
+    /// Binds to `addr` and serves connections in a background task.
+    ///
+    /// Binding to port 0 picks a free port, which can be read from
+    /// [`RunningRestCatalogServer::local_addr`].
+    pub async fn start(self, addr: impl ToSocketAddrs) -> Result<RunningRestCatalogServer> {
+        let listener = TcpListener::bind(addr).await.map_err(|e| {
+            Error::new(
+                ErrorKind::Unexpected,
+                "Failed to bind the REST catalog server",
+            )
+            .with_source(e)
+        })?;
+        let local_addr = listener.local_addr().map_err(|e| {
+            Error::new(
+                ErrorKind::Unexpected,
+                "Failed to get the address of the REST catalog server",
+            )
+            .with_source(e)
+        })?;
+
+        Ok(RunningRestCatalogServer {
+            local_addr,
+            task: tokio::spawn(self.serve(listener)),
+        })
+    }
+}
+
+/// A [`RestCatalogServer`] serving connections in a background task.
+///
+/// The server is stopped when this is dropped.
+#[derive(Debug)]
+pub struct RunningRestCatalogServer {
+    local_addr: SocketAddr,
+    task: JoinHandle<Result<()>>,
+}
+
+impl RunningRestCatalogServer {
+    /// The address the server is listening on.
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// The URI REST clients connect to, to be used as
+    /// `REST_CATALOG_PROP_URI` of a REST catalog.
+    pub fn uri(&self) -> String {
+        format!("http://{}", self.local_addr)
+    }
+}
+
+impl Drop for RunningRestCatalogServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
@@ -0,0 +1,342 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! End to end tests of the rest catalog against a rest catalog server backed by a memory
+//! catalog.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use iceberg::io::LocalFsStorageFactory;
+use iceberg::memory::{MEMORY_CATALOG_WAREHOUSE, MemoryCatalogBuilder};
+use iceberg::spec::{NestedField, PrimitiveType, Schema, Type};
+use iceberg::table::Table;
+use iceberg::transaction::{ApplyTransactionAction, Transaction};
+use iceberg::{
+    Catalog, CatalogBuilder, Error, ErrorKind, MemoryCatalog, Namespace, NamespaceIdent, Result,
+    TableCommit, TableCreation, TableIdent, TableRequirement, TableUpdate,
+};
+use iceberg_catalog_rest::{REST_CATALOG_PROP_URI, RestCatalog, RestCatalogBuilder};
+use iceberg_catalog_rest_server::{RestCatalogServer, RunningRestCatalogServer};
+use tempfile::TempDir;
+use uuid::Uuid;
+
+struct TestCatalog {
+    catalog: RestCatalog,
+    _server: RunningRestCatalogServer,
+    _warehouse: TempDir,
+}
+
+async fn get_catalog() -> TestCatalog {
+    get_catalog_with(|catalog| Arc::new(catalog)).await
+}
+
+/// Starts a server for the memory catalog wrapped by `backend`.
+async fn get_catalog_with(backend: impl FnOnce(MemoryCatalog) -> Arc<dyn Catalog>) -> TestCatalog {
+    let warehouse = TempDir::new().unwrap();
+    let memory_catalog = MemoryCatalogBuilder::default()
+        .with_storage_factory(Arc::new(LocalFsStorageFactory))
+        .load(
+            "memory",
+            HashMap::from([(
+                MEMORY_CATALOG_WAREHOUSE.to_string(),
+                warehouse.path().to_str().unwrap().to_string(),
+            )]),
+        )
+        .await
+        .unwrap();
+
+    let server = RestCatalogServer::new(backend(memory_catalog))
+        .start("127.0.0.1:0")
+        .await
+        .unwrap();
+
+    let catalog = RestCatalogBuilder::default()
+        .with_storage_factory(Arc::new(LocalFsStorageFactory))
+        .load(
+            "rest",
+            HashMap::from([(REST_CATALOG_PROP_URI.to_string(), server.uri())]),
+        )
+        .await
+        .unwrap();
+
+    TestCatalog {
+        catalog,
+        _server: server,
+        _warehouse: warehouse,
+    }
+}
+
+/// A catalog failing every table commit with a conflict found by validations, e.g. of the
+/// files added concurrently to the table.
+#[derive(Debug)]
+struct ValidationFailingCatalog(MemoryCatalog);
+
+#[async_trait]
+impl Catalog for ValidationFailingCatalog {
+    async fn list_namespaces(
+        &self,
+        parent: Option<&NamespaceIdent>,
+    ) -> Result<Vec<NamespaceIdent>> {
+        self.0.list_namespaces(parent).await
+    }
+
+    async fn create_namespace(
+        &self,
+        namespace: &NamespaceIdent,
+        properties: HashMap<String, String>,
+    ) -> Result<Namespace> {
+        self.0.create_namespace(namespace, properties).await
+    }
+
+    async fn get_namespace(&self, namespace: &NamespaceIdent) -> Result<Namespace> {
+        self.0.get_namespace(namespace).await
+    }
+
+    async fn namespace_exists(&self, namespace: &NamespaceIdent) -> Result<bool> {
+        self.0.namespace_exists(namespace).await
+    }
+
+    async fn update_namespace(
+        &self,
+        namespace: &NamespaceIdent,
+        properties: HashMap<String, String>,
+    ) -> Result<()> {
+        self.0.update_namespace(namespace, properties).await
+    }
+
+    async fn drop_namespace(&self, namespace: &NamespaceIdent) -> Result<()> {
+        self.0.drop_namespace(namespace).await
+    }
+
+    async fn list_tables(&self, namespace: &NamespaceIdent) -> Result<Vec<TableIdent>> {
+        self.0.list_tables(namespace).await
+    }
+
+    async fn create_table(
+        &self,
+        namespace: &NamespaceIdent,
+        creation: TableCreation,
+    ) -> Result<Table> {
+        self.0.create_table(namespace, creation).await
+    }
+
+    async fn load_table(&self, table: &TableIdent) -> Result<Table> {
+        self.0.load_table(table).await
+    }
+
+    async fn drop_table(&self, table: &TableIdent) -> Result<()> {
+        self.0.drop_table(table).await
+    }
+
+    async fn table_exists(&self, table: &TableIdent) -> Result<bool> {
+        self.0.table_exists(table).await
+    }
+
+    async fn rename_table(&self, src: &TableIdent, dest: &TableIdent) -> Result<()> {
+        self.0.rename_table(src, dest).await
+    }
+
+    async fn register_table(&self, table: &TableIdent, metadata_location: String) -> Result<Table> {
+        self.0.register_table(table, metadata_location).await
+    }
+
+    async fn update_table(&self, _commit: TableCommit) -> Result<Table> {
+        Err(Error::new(
+            ErrorKind::CommitConflict,
+            "Found conflicting files added concurrently",
+        ))
+    }
+}
+
+fn table_creation(name: &str) -> TableCreation {
+    let schema = Schema::builder()
+        .with_fields(vec![
+            NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+            NestedField::optional(2, "data", Type::Primitive(PrimitiveType::String)).into(),
+        ])
+        .build()
+        .unwrap();
+
+    TableCreation::builder()
+        .name(name.to_string())
+        .schema(schema)
+        .build()
+}
+
+#[tokio::test]
+async fn test_namespaces() {
+    let test_catalog = get_catalog().await;
+    let catalog = &test_catalog.catalog;
+    let parent = NamespaceIdent::new("parent".to_string());
+    let child = NamespaceIdent::from_strs(["parent", "child"]).unwrap();
+
+    catalog
+        .create_namespace(
+            &parent,
+            HashMap::from([("owner".to_string(), "alice".to_string())]),
+        )
+        .await
+        .unwrap();
+    catalog
+        .create_namespace(&child, HashMap::new())
+        .await
+        .unwrap();
+
+    assert_eq!(catalog.list_namespaces(None).await.unwrap(), vec![
+        parent.clone()
+    ]);
+    assert_eq!(catalog.list_namespaces(Some(&parent)).await.unwrap(), vec![
+        child.clone()
+    ]);
+    assert!(catalog.namespace_exists(&child).await.unwrap());
+    assert_eq!(
+        catalog.get_namespace(&parent).await.unwrap().properties(),
+        &HashMap::from([("owner".to_string(), "alice".to_string())])
+    );
+    assert!(
+        catalog
+            .create_namespace(&parent, HashMap::new())
+            .await
+            .is_err()
+    );
+
+    catalog.drop_namespace(&child).await.unwrap();
+    assert!(!catalog.namespace_exists(&child).await.unwrap());
+}
+
+#[tokio::test]
+async fn test_tables() {
+    let test_catalog = get_catalog().await;
+    let catalog = &test_catalog.catalog;
+    let namespace = NamespaceIdent::new("ns".to_string());
+    catalog
+        .create_namespace(&namespace, HashMap::new())
+        .await
+        .unwrap();
+
+    let table = catalog
+        .create_table(&namespace, table_creation("t1"))
+        .await
+        .unwrap();
+    let table_ident = TableIdent::new(namespace.clone(), "t1".to_string());
+    assert_eq!(table.identifier(), &table_ident);
+    assert!(catalog.table_exists(&table_ident).await.unwrap());
+    assert_eq!(catalog.list_tables(&namespace).await.unwrap(), vec![
+        table_ident.clone()
+    ]);
+
+    let tx = Transaction::new(&table);
+    let updated = tx
+        .update_table_properties()
+        .set("prop1".to_string(), "v1".to_string())
+        .apply(tx)
+        .unwrap()
+        .commit(catalog)
+        .await
+        .unwrap();
+    assert_eq!(
+        updated.metadata().properties().get("prop1"),
+        Some(&"v1".to_string())
+    );
+
+    let loaded = catalog.load_table(&table_ident).await.unwrap();
+    assert_eq!(loaded.metadata(), updated.metadata());
+    assert_eq!(loaded.metadata_location(), updated.metadata_location());
+
+    let renamed_ident = TableIdent::new(namespace.clone(), "t2".to_string());
+    catalog
+        .rename_table(&table_ident, &renamed_ident)
+        .await
+        .unwrap();
+    assert!(!catalog.table_exists(&table_ident).await.unwrap());
+    assert!(catalog.load_table(&table_ident).await.is_err());
+
+    catalog.drop_table(&renamed_ident).await.unwrap();
+    assert!(catalog.list_tables(&namespace).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_commit_conflict() {
+    let test_catalog = get_catalog().await;
+    let catalog = &test_catalog.catalog;
+    let namespace = NamespaceIdent::new("ns".to_string());
+    catalog
+        .create_namespace(&namespace, HashMap::new())
+        .await
+        .unwrap();
+    let table = catalog
+        .create_table(&namespace, table_creation("t1"))
+        .await
+        .unwrap();
+
+    let commit = TableCommit::new(
+        table.identifier().clone(),
+        vec![TableRequirement::UuidMatch {
+            uuid: Uuid::new_v4(),
+        }],
+        vec![TableUpdate::SetProperties {
+            updates: HashMap::from([("prop1".to_string(), "v1".to_string())]),
+        }],
+    );
+    let err = catalog.update_table(commit).await.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::CatalogCommitConflicts);
+
+    let loaded = catalog.load_table(table.identifier()).await.unwrap();
+    assert!(loaded.metadata().properties().get("prop1").is_none());
+}
+
+#[tokio::test]
+async fn test_validation_failure_is_not_retried() {
+    let test_catalog =
+        get_catalog_with(|catalog| Arc::new(ValidationFailingCatalog(catalog))).await;
+    let catalog = &test_catalog.catalog;
+    let namespace = NamespaceIdent::new("ns".to_string());
+    catalog
+        .create_namespace(&namespace, HashMap::new())
+        .await
+        .unwrap();
+    let table = catalog
+        .create_table(&namespace, table_creation("t1"))
+        .await
+        .unwrap();
+
+    let commit = TableCommit::new(table.identifier().clone(), vec![], vec![
+        TableUpdate::SetProperties {
+            updates: HashMap::from([("prop1".to_string(), "v1".to_string())]),
+        },
+    ]);
+    let err = catalog.update_table(commit).await.unwrap_err();
+    assert_ne!(err.kind(), ErrorKind::CatalogCommitConflicts);
+    assert!(!err.retryable());
+    assert!(err.to_string().contains("ValidationException"));
+    assert!(err.to_string().contains("400"));
+}
+
+#[tokio::test]
+async fn test_unknown_endpoint_is_not_found() {
+    let test_catalog = get_catalog().await;
+
+    let response = reqwest::get(format!("{}/v1/unknown", test_catalog._server.uri()))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), reqwest::StatusCode::NOT_FOUND);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body["error"]["type"], "NoSuchEndpointException");
+}
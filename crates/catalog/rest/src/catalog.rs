@@ -17,19 +17,31 @@
 
 //! This module contains the iceberg REST catalog implementation.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::future::Future;
 use std::str::FromStr;
 use std::sync::Arc;
+use std::time::Duration;
 
 use async_trait::async_trait;
-use iceberg::io::{FileIO, FileIOBuilder, StorageFactory};
-use iceberg::table::Table;
+#[cfg(feature = "sigv4")]
+use aws_credential_types::Credentials;
+use chrono::Utc;
+use iceberg::encryption::KeyManagementClient;
+use iceberg::io::{
+    FileIO, FileIOBuilder, StorageCredential as FileIOStorageCredential, StorageFactory,
+};
+use iceberg::metrics::MetricsReporter;
+use iceberg::scan::ScanPlanner;
+use iceberg::spec::ViewVersion;
+use iceberg::table::{Table, TableBuilder};
+use iceberg::view::View;
 use iceberg::{
     Catalog, CatalogBuilder, Error, ErrorKind, Namespace, NamespaceIdent, Result, TableCommit,
-    TableCreation, TableIdent,
+    TableCreation, TableIdent, ViewCommit, ViewCreation,
 };
 use itertools::Itertools;
+use moka::sync::Cache;
 use reqwest::header::{
     HeaderMap, HeaderName, HeaderValue, {self},
 };
@@ -40,10 +52,18 @@ use typed_builder::TypedBuilder;
 use crate::client::{
     HttpClient, deserialize_catalog_response, deserialize_unexpected_catalog_error,
 };
+use crate::credentials::{
+    LOAD_CREDENTIALS_ENDPOINT, RestCredentialsLoader, to_storage_credentials,
+};
+use crate::metrics::{REPORT_METRICS_ENDPOINT, RestMetricsReporter};
+use crate::scan::{CANCEL_PLANNING_ENDPOINT, PLAN_TABLE_SCAN_ENDPOINT, RestScanPlanner};
+#[cfg(feature = "sigv4")]
+use crate::sigv4::SigV4Signer;
 use crate::types::{
-    CatalogConfig, CommitTableRequest, CommitTableResponse, CreateNamespaceRequest,
-    CreateTableRequest, ListNamespaceResponse, ListTablesResponse, LoadTableResult,
-    NamespaceResponse, RegisterTableRequest, RenameTableRequest,
+    CatalogConfig, CommitTableRequest, CommitTableResponse, CommitTransactionRequest,
+    CommitViewRequest, CreateNamespaceRequest, CreateTableRequest, CreateViewRequest,
+    ListNamespaceResponse, ListTablesResponse, LoadTableResult, LoadViewResult, NamespaceResponse,
+    RegisterTableRequest, RenameTableRequest, StorageCredential,
 };
 
 /// REST catalog URI
@@ -52,6 +72,36 @@ pub const REST_CATALOG_PROP_URI: &str = "uri";
 pub const REST_CATALOG_PROP_WAREHOUSE: &str = "warehouse";
 /// Disable header redaction in error logs (defaults to false for security)
 pub const REST_CATALOG_PROP_DISABLE_HEADER_REDACTION: &str = "disable-header-redaction";
+/// Report the metrics of the catalog's tables to the server (defaults to true)
+pub const REST_CATALOG_PROP_METRICS_REPORTING_ENABLED: &str = "rest-metrics-reporting-enabled";
+/// Sign requests with AWS Signature Version 4 (defaults to false, requires the `sigv4` feature)
+pub const REST_CATALOG_PROP_SIGV4_ENABLED: &str = "rest.sigv4-enabled";
+/// AWS region requests are signed for (defaults to the `AWS_REGION` environment variable)
+pub const REST_CATALOG_PROP_SIGNING_REGION: &str = "rest.signing-region";
+/// AWS service requests are signed for (defaults to `execute-api`)
+pub const REST_CATALOG_PROP_SIGNING_NAME: &str = "rest.signing-name";
+/// AWS access key id requests are signed with (defaults to the standard AWS credentials)
+pub const REST_CATALOG_PROP_ACCESS_KEY_ID: &str = "rest.access-key-id";
+/// AWS secret access key requests are signed with
+pub const REST_CATALOG_PROP_SECRET_ACCESS_KEY: &str = "rest.secret-access-key";
+/// AWS session token requests are signed with
+pub const REST_CATALOG_PROP_SESSION_TOKEN: &str = "rest.session-token";
+/// Snapshots returned when loading tables, either `all` (the default) or `refs` to only
+/// return the snapshots referenced by branches and tags
+pub const REST_CATALOG_PROP_SNAPSHOT_LOADING_MODE: &str = "snapshot-loading-mode";
+/// Milliseconds to wait for the server to complete a submitted scan plan before the plan is
+/// cancelled and planning fails (defaults to 5 minutes)
+pub const REST_CATALOG_PROP_SCAN_PLANNING_TIMEOUT_MS: &str = "rest.scan-planning-timeout-ms";
+
+/// Maximum number of loaded tables kept to skip reloading the tables that have not changed.
+const TABLE_CACHE_CAPACITY: u64 = 1000;
+/// Duration loaded tables are kept to skip reloading the tables that have not changed.
+const TABLE_CACHE_TTL: Duration = Duration::from_secs(5 * 60);
+/// Loaded tables whose storage credentials expire within this duration are reloaded, to get
+/// fresh credentials, rather than reused.
+const TABLE_CACHE_CREDENTIALS_EXPIRY_WINDOW: Duration = Duration::from_secs(5 * 60);
+/// Duration to wait for the server to complete a submitted scan plan.
+const DEFAULT_SCAN_PLANNING_TIMEOUT: Duration = Duration::from_secs(5 * 60);
 
 const ICEBERG_REST_SPEC_VERSION: &str = "0.14.1";
 const CARGO_PKG_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -73,6 +123,7 @@ impl Default for RestCatalogBuilder {
                 warehouse: None,
                 props: HashMap::new(),
                 client: None,
+                key_management_client: None,
             },
             storage_factory: None,
         }
@@ -137,6 +188,12 @@ impl RestCatalogBuilder {
         self.config.client = Some(client);
         self
     }
+
+    /// Set the client of the KMS holding the master keys of encrypted tables.
+    pub fn with_key_management_client(mut self, client: Arc<dyn KeyManagementClient>) -> Self {
+        self.config.key_management_client = Some(client);
+        self
+    }
 }
 
 /// Rest catalog configuration.
@@ -155,6 +212,9 @@ pub(crate) struct RestCatalogConfig {
 
     #[builder(default)]
     client: Option<Client>,
+
+    #[builder(default)]
+    key_management_client: Option<Arc<dyn KeyManagementClient>>,
 }
 
 impl RestCatalogConfig {
@@ -198,6 +258,10 @@ impl RestCatalogConfig {
         self.url_prefixed(&["namespaces", &ns.to_url_string(), "register"])
     }
 
+    fn transactions_commit_endpoint(&self) -> String {
+        self.url_prefixed(&["transactions", "commit"])
+    }
+
     fn table_endpoint(&self, table: &TableIdent) -> String {
         self.url_prefixed(&[
             "namespaces",
@@ -207,6 +271,74 @@ impl RestCatalogConfig {
         ])
     }
 
+    pub(crate) fn plan_table_scan_endpoint(&self, table: &TableIdent) -> String {
+        self.url_prefixed(&[
+            "namespaces",
+            &table.namespace.to_url_string(),
+            "tables",
+            &table.name,
+            "plan",
+        ])
+    }
+
+    pub(crate) fn plan_endpoint(&self, table: &TableIdent, plan_id: &str) -> String {
+        self.url_prefixed(&[
+            "namespaces",
+            &table.namespace.to_url_string(),
+            "tables",
+            &table.name,
+            "plan",
+            plan_id,
+        ])
+    }
+
+    pub(crate) fn fetch_scan_tasks_endpoint(&self, table: &TableIdent) -> String {
+        self.url_prefixed(&[
+            "namespaces",
+            &table.namespace.to_url_string(),
+            "tables",
+            &table.name,
+            "tasks",
+        ])
+    }
+
+    pub(crate) fn credentials_endpoint(&self, table: &TableIdent) -> String {
+        self.url_prefixed(&[
+            "namespaces",
+            &table.namespace.to_url_string(),
+            "tables",
+            &table.name,
+            "credentials",
+        ])
+    }
+
+    pub(crate) fn metrics_endpoint(&self, table: &TableIdent) -> String {
+        self.url_prefixed(&[
+            "namespaces",
+            &table.namespace.to_url_string(),
+            "tables",
+            &table.name,
+            "metrics",
+        ])
+    }
+
+    fn views_endpoint(&self, ns: &NamespaceIdent) -> String {
+        self.url_prefixed(&["namespaces", &ns.to_url_string(), "views"])
+    }
+
+    fn rename_view_endpoint(&self) -> String {
+        self.url_prefixed(&["views", "rename"])
+    }
+
+    fn view_endpoint(&self, view: &TableIdent) -> String {
+        self.url_prefixed(&[
+            "namespaces",
+            &view.namespace.to_url_string(),
+            "views",
+            &view.name,
+        ])
+    }
+
     /// Get the client from the config.
     pub(crate) fn client(&self) -> Option<Client> {
         self.client.clone()
@@ -238,6 +370,86 @@ impl RestCatalogConfig {
         }
     }
 
+    /// Whether requests are signed with AWS SigV4.
+    fn sigv4_enabled(&self) -> bool {
+        self.props
+            .get(REST_CATALOG_PROP_SIGV4_ENABLED)
+            .is_some_and(|v| v.eq_ignore_ascii_case("true"))
+    }
+
+    /// Returns an error if signing is enabled, as requests can only be signed with the
+    /// `sigv4` feature.
+    #[cfg(not(feature = "sigv4"))]
+    pub(crate) fn check_sigv4_disabled(&self) -> Result<()> {
+        if self.sigv4_enabled() {
+            return Err(Error::new(
+                ErrorKind::FeatureUnsupported,
+                format!(
+                    "{REST_CATALOG_PROP_SIGV4_ENABLED} requires the sigv4 feature of \
+                     iceberg-catalog-rest"
+                ),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Get the SigV4 signer from the config, if signing is enabled.
+    ///
+    /// Requests are signed with the static credentials of the config if set, or else with
+    /// the credentials of the default AWS credentials provider chain.
+    #[cfg(feature = "sigv4")]
+    pub(crate) fn sigv4_signer(&self) -> Result<Option<SigV4Signer>> {
+        if !self.sigv4_enabled() {
+            return Ok(None);
+        }
+
+        let region = self
+            .props
+            .get(REST_CATALOG_PROP_SIGNING_REGION)
+            .cloned()
+            .or_else(|| std::env::var("AWS_REGION").ok())
+            .or_else(|| std::env::var("AWS_DEFAULT_REGION").ok())
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::DataInvalid,
+                    format!(
+                        "Signing region must be set with {REST_CATALOG_PROP_SIGNING_REGION} or \
+                         AWS_REGION to sign requests"
+                    ),
+                )
+            })?;
+        let service = self
+            .props
+            .get(REST_CATALOG_PROP_SIGNING_NAME)
+            .cloned()
+            .unwrap_or_else(|| "execute-api".to_string());
+
+        let credentials = match (
+            self.props.get(REST_CATALOG_PROP_ACCESS_KEY_ID),
+            self.props.get(REST_CATALOG_PROP_SECRET_ACCESS_KEY),
+        ) {
+            (Some(access_key_id), Some(secret_access_key)) => Some(Credentials::new(
+                access_key_id,
+                secret_access_key,
+                self.props.get(REST_CATALOG_PROP_SESSION_TOKEN).cloned(),
+                None,
+                "rest-catalog-properties",
+            )),
+            (None, None) => None,
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::DataInvalid,
+                    format!(
+                        "{REST_CATALOG_PROP_ACCESS_KEY_ID} and \
+                         {REST_CATALOG_PROP_SECRET_ACCESS_KEY} must be set together"
+                    ),
+                ));
+            }
+        };
+
+        Ok(Some(SigV4Signer::new(region, service, credentials)))
+    }
+
     /// Get the extra headers from config, which includes:
     ///
     /// - `content-type`
@@ -317,6 +529,43 @@ impl RestCatalogConfig {
             .unwrap_or(false)
     }
 
+    /// Check if the metrics of tables are reported to the server.
+    ///
+    /// Returns false if the `rest-metrics-reporting-enabled` property is set to "false".
+    pub(crate) fn metrics_reporting_enabled(&self) -> bool {
+        self.props
+            .get(REST_CATALOG_PROP_METRICS_REPORTING_ENABLED)
+            .map(|v| !v.eq_ignore_ascii_case("false"))
+            .unwrap_or(true)
+    }
+
+    /// Check if only the snapshots referenced by branches and tags are loaded.
+    ///
+    /// Returns true if the `snapshot-loading-mode` property is set to "refs".
+    pub(crate) fn load_referenced_snapshots_only(&self) -> bool {
+        self.props
+            .get(REST_CATALOG_PROP_SNAPSHOT_LOADING_MODE)
+            .map(|v| v.eq_ignore_ascii_case("refs"))
+            .unwrap_or(false)
+    }
+
+    /// Get the duration to wait for the server to complete a submitted scan plan.
+    ///
+    /// Read from the `rest.scan-planning-timeout-ms` property, defaults to 5 minutes.
+    pub(crate) fn scan_planning_timeout(&self) -> Result<Duration> {
+        let Some(timeout_ms) = self.props.get(REST_CATALOG_PROP_SCAN_PLANNING_TIMEOUT_MS) else {
+            return Ok(DEFAULT_SCAN_PLANNING_TIMEOUT);
+        };
+
+        timeout_ms.parse().map(Duration::from_millis).map_err(|e| {
+            Error::new(
+                ErrorKind::DataInvalid,
+                format!("Invalid scan planning timeout: {timeout_ms}"),
+            )
+            .with_source(e)
+        })
+    }
+
     /// Merge the `RestCatalogConfig` with the a [`CatalogConfig`] (fetched from the REST server).
     pub(crate) fn merge_with_config(mut self, mut config: CatalogConfig) -> Self {
         if let Some(uri) = config.overrides.remove("uri") {
@@ -334,11 +583,44 @@ impl RestCatalogConfig {
 
 #[derive(Debug)]
 struct RestContext {
-    client: HttpClient,
+    client: Arc<HttpClient>,
     /// Runtime config is fetched from rest server and stored here.
     ///
     /// It's could be different from the user config.
     config: RestCatalogConfig,
+    /// Endpoints advertised by the server in its config response.
+    endpoints: HashSet<String>,
+}
+
+impl RestContext {
+    /// Returns a [`TableBuilder`] delegating the table's scan planning to the server, if it
+    /// supports planning scans, and reporting the table's metrics to the server, if it accepts
+    /// metrics reports.
+    ///
+    /// Servers that don't advertise their endpoints are assumed to accept metrics reports.
+    fn table_builder(&self) -> TableBuilder {
+        let mut table_builder = Table::builder();
+        let config = Arc::new(self.config.clone());
+
+        if self.endpoints.contains(PLAN_TABLE_SCAN_ENDPOINT) {
+            let scan_planner: Arc<dyn ScanPlanner> = Arc::new(RestScanPlanner::new(
+                self.client.clone(),
+                config.clone(),
+                self.endpoints.contains(CANCEL_PLANNING_ENDPOINT),
+            ));
+            table_builder = table_builder.scan_planner(scan_planner);
+        }
+
+        if self.config.metrics_reporting_enabled()
+            && (self.endpoints.is_empty() || self.endpoints.contains(REPORT_METRICS_ENDPOINT))
+        {
+            let metrics_reporter: Arc<dyn MetricsReporter> =
+                Arc::new(RestMetricsReporter::new(self.client.clone(), config));
+            table_builder = table_builder.metrics_reporter(Some(metrics_reporter));
+        }
+
+        table_builder
+    }
 }
 
 /// Rest catalog implementation.
@@ -351,6 +633,31 @@ pub struct RestCatalog {
     ctx: OnceCell<RestContext>,
     /// Storage factory for creating FileIO instances.
     storage_factory: Option<Arc<dyn StorageFactory>>,
+    /// Loaded tables and the ETags the server returned with them, used to skip reloading
+    /// tables that have not changed. Tables are keyed by whether only the snapshots
+    /// referenced by branches and tags were loaded.
+    table_cache: Cache<(TableIdent, bool), CachedTable>,
+}
+
+/// A loaded table and the ETag the server returned with it.
+#[derive(Debug, Clone)]
+struct CachedTable {
+    etag: String,
+    table: Table,
+    /// When the storage credentials the table was loaded with expire, in milliseconds since
+    /// the epoch.
+    credentials_expire_at_ms: Option<i64>,
+}
+
+impl CachedTable {
+    /// Whether the storage credentials of the table are about to expire, in which case the
+    /// table is reloaded with fresh credentials even if it has not changed.
+    fn credentials_expiring(&self) -> bool {
+        self.credentials_expire_at_ms.is_some_and(|expires_at_ms| {
+            expires_at_ms - Utc::now().timestamp_millis()
+                <= TABLE_CACHE_CREDENTIALS_EXPIRY_WINDOW.as_millis() as i64
+        })
+    }
 }
 
 impl RestCatalog {
@@ -360,6 +667,17 @@ impl RestCatalog {
             user_config: config,
             ctx: OnceCell::new(),
             storage_factory,
+            table_cache: Cache::builder()
+                .max_capacity(TABLE_CACHE_CAPACITY)
+                .time_to_live(TABLE_CACHE_TTL)
+                .build(),
+        }
+    }
+
+    fn invalidate_cached_table(&self, table_ident: &TableIdent) {
+        for referenced_snapshots_only in [false, true] {
+            self.table_cache
+                .invalidate(&(table_ident.clone(), referenced_snapshots_only));
         }
     }
 
@@ -369,10 +687,20 @@ impl RestCatalog {
             .get_or_try_init(|| async {
                 let client = HttpClient::new(&self.user_config)?;
                 let catalog_config = RestCatalog::load_config(&client, &self.user_config).await?;
+                let endpoints = catalog_config
+                    .endpoints
+                    .clone()
+                    .unwrap_or_default()
+                    .into_iter()
+                    .collect();
                 let config = self.user_config.clone().merge_with_config(catalog_config);
-                let client = client.update_with(&config)?;
+                let client = Arc::new(client.update_with(&config)?);
 
-                Ok(RestContext { config, client })
+                Ok(RestContext {
+                    config,
+                    client,
+                    endpoints,
+                })
             })
             .await
     }
@@ -404,11 +732,144 @@ impl RestCatalog {
         }
     }
 
+    /// Sends a create table request, staging the creation if `stage_create` is set.
+    async fn create_table_request(
+        &self,
+        namespace: &NamespaceIdent,
+        creation: TableCreation,
+        stage_create: bool,
+    ) -> Result<Table> {
+        let context = self.context().await?;
+
+        let table_ident = TableIdent::new(namespace.clone(), creation.name.clone());
+
+        let request = context
+            .client
+            .request(Method::POST, context.config.tables_endpoint(namespace))
+            .json(&CreateTableRequest {
+                name: creation.name,
+                location: creation.location,
+                schema: creation.schema,
+                partition_spec: creation.partition_spec,
+                write_order: creation.sort_order,
+                stage_create: Some(stage_create),
+                properties: creation.properties,
+            })
+            .build()?;
+
+        let http_response = context.client.query_catalog(request).await?;
+
+        let response = match http_response.status() {
+            StatusCode::OK => {
+                deserialize_catalog_response::<LoadTableResult>(http_response).await?
+            }
+            StatusCode::NOT_FOUND => {
+                return Err(Error::new(
+                    ErrorKind::Unexpected,
+                    "Tried to create a table under a namespace that does not exist",
+                ));
+            }
+            StatusCode::CONFLICT => {
+                return Err(Error::new(
+                    ErrorKind::Unexpected,
+                    "The table already exists",
+                ));
+            }
+            _ => {
+                return Err(deserialize_unexpected_catalog_error(
+                    http_response,
+                    context.client.disable_header_redaction(),
+                )
+                .await);
+            }
+        };
+
+        // Staged tables have no metadata file yet.
+        let location = match (&response.metadata_location, stage_create) {
+            (Some(metadata_location), _) => metadata_location.as_str(),
+            (None, true) => response.metadata.location(),
+            (None, false) => {
+                return Err(Error::new(
+                    ErrorKind::DataInvalid,
+                    "Metadata location missing in `create_table` response!",
+                ));
+            }
+        };
+
+        let config = response
+            .config
+            .into_iter()
+            .chain(self.user_config.props.clone())
+            .collect();
+
+        let file_io = self
+            .load_table_file_io(
+                &table_ident,
+                Some(location),
+                config,
+                response.storage_credentials,
+            )
+            .await?;
+
+        let table_builder = context
+            .table_builder()
+            .identifier(table_ident.clone())
+            .file_io(file_io)
+            .metadata(response.metadata);
+
+        if let Some(metadata_location) = response.metadata_location {
+            table_builder.metadata_location(metadata_location).build()
+        } else {
+            table_builder.build()
+        }
+    }
+
     async fn load_file_io(
         &self,
         metadata_location: Option<&str>,
         extra_config: Option<HashMap<String, String>>,
     ) -> Result<FileIO> {
+        Ok(self
+            .file_io_builder(metadata_location, extra_config)
+            .await?
+            .build())
+    }
+
+    /// Loads the [`FileIO`] of a table, using the storage credentials vended with it.
+    ///
+    /// Vended credentials are refreshed before they expire if the server advertises the
+    /// endpoint loading them.
+    async fn load_table_file_io(
+        &self,
+        table_ident: &TableIdent,
+        location: Option<&str>,
+        config: HashMap<String, String>,
+        storage_credentials: Option<Vec<StorageCredential>>,
+    ) -> Result<FileIO> {
+        let mut builder = self.file_io_builder(location, Some(config)).await?;
+
+        if let Some(credentials) = storage_credentials.filter(|c| !c.is_empty()) {
+            builder = builder.with_storage_credentials(to_storage_credentials(credentials));
+
+            let context = self.context().await?;
+            if context.endpoints.contains(LOAD_CREDENTIALS_ENDPOINT) {
+                builder =
+                    builder.with_storage_credentials_loader(Arc::new(RestCredentialsLoader::new(
+                        context.client.clone(),
+                        Arc::new(context.config.clone()),
+                        table_ident.clone(),
+                    )));
+            }
+        }
+
+        Ok(builder.build())
+    }
+
+    async fn file_io_builder(
+        &self,
+        metadata_location: Option<&str>,
+        extra_config: Option<HashMap<String, String>>,
+    ) -> Result<FileIOBuilder> {
         let mut props = self.context().await?.config.props.clone();
         if let Some(config) = extra_config {
             props.extend(config);
@@ -440,54 +901,195 @@ impl RestCatalog {
                 )
             })?;
 
-        let file_io = FileIOBuilder::new(factory).with_props(props).build();
-
+        let mut file_io = FileIOBuilder::new(factory).with_props(props);
+        if let Some(client) = self.user_config.key_management_client.clone() {
+            file_io = file_io.with_key_management_client(client);
+        }
         Ok(file_io)
     }
 
-    /// Invalidate the current token without generating a new one. On the next request, the client
-    /// will attempt to generate a new token.
-    pub async fn invalidate_token(&self) -> Result<()> {
-        self.context().await?.client.invalidate_token().await
-    }
-
-    /// Invalidate the current token and set a new one. Generates a new token before invalidating
-    /// the current token, meaning the old token will be used until this function acquires the lock
-    /// and overwrites the token.
+    /// Loads a table, with only the snapshots referenced by branches and tags if
+    /// `referenced_snapshots_only` is set.
     ///
-    /// If credential is invalid, or the request fails, this method will return an error and leave
-    /// the current token unchanged.
-    pub async fn regenerate_token(&self) -> Result<()> {
-        self.context().await?.client.regenerate_token().await
-    }
-}
-
-/// All requests and expected responses are derived from the REST catalog API spec:
-/// https://github.com/apache/iceberg/blob/main/open-api/rest-catalog-open-api.yaml
-#[async_trait]
-impl Catalog for RestCatalog {
-    async fn list_namespaces(
+    /// The table is not reloaded if the server reports that it has not changed since it was
+    /// cached, unless its storage credentials are about to expire.
+    async fn load_table_with_snapshots(
         &self,
-        parent: Option<&NamespaceIdent>,
-    ) -> Result<Vec<NamespaceIdent>> {
+        table_ident: &TableIdent,
+        referenced_snapshots_only: bool,
+    ) -> Result<Table> {
         let context = self.context().await?;
-        let endpoint = context.config.namespaces_endpoint();
-        let mut namespaces = Vec::new();
-        let mut next_token = None;
-
-        loop {
-            let mut request = context.client.request(Method::GET, endpoint.clone());
 
-            // Filter on `parent={namespace}` if a parent namespace exists.
-            if let Some(ns) = parent {
-                request = request.query(&[("parent", ns.to_url_string())]);
-            }
+        let cache_key = (table_ident.clone(), referenced_snapshots_only);
+        let cached = self
+            .table_cache
+            .get(&cache_key)
+            .filter(|cached| !cached.credentials_expiring());
 
-            if let Some(token) = next_token {
-                request = request.query(&[("pageToken", token)]);
-            }
+        let mut request = context
+            .client
+            .request(Method::GET, context.config.table_endpoint(table_ident));
+        if referenced_snapshots_only {
+            request = request.query(&[("snapshots", "refs")]);
+        }
+        if let Some(cached) = &cached {
+            request = request.header(header::IF_NONE_MATCH, &cached.etag);
+        }
 
-            let http_response = context.client.query_catalog(request.build()?).await?;
+        let http_response = context.client.query_catalog(request.build()?).await?;
+
+        let (etag, response) = match http_response.status() {
+            StatusCode::NOT_MODIFIED => {
+                return cached.map(|cached| cached.table).ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::Unexpected,
+                        "Received 304 Not Modified for a table that is not cached",
+                    )
+                });
+            }
+            StatusCode::OK => {
+                let etag = http_response
+                    .headers()
+                    .get(header::ETAG)
+                    .and_then(|v| v.to_str().ok())
+                    .map(str::to_string);
+                let response =
+                    deserialize_catalog_response::<LoadTableResult>(http_response).await?;
+                (etag, response)
+            }
+            StatusCode::NOT_FOUND => {
+                return Err(Error::new(
+                    ErrorKind::Unexpected,
+                    "Tried to load a table that does not exist",
+                ));
+            }
+            _ => {
+                return Err(deserialize_unexpected_catalog_error(
+                    http_response,
+                    context.client.disable_header_redaction(),
+                )
+                .await);
+            }
+        };
+
+        let config: HashMap<String, String> = response
+            .config
+            .into_iter()
+            .chain(self.user_config.props.clone())
+            .collect();
+        let credentials_expire_at_ms =
+            to_storage_credentials(response.storage_credentials.clone().unwrap_or_default())
+                .into_iter()
+                .chain([FileIOStorageCredential::new("", config.clone())])
+                .filter_map(|credential| credential.expires_at_ms())
+                .min();
+
+        let file_io = self
+            .load_table_file_io(
+                table_ident,
+                response.metadata_location.as_deref(),
+                config,
+                response.storage_credentials,
+            )
+            .await?;
+
+        let table_builder = context
+            .table_builder()
+            .identifier(table_ident.clone())
+            .file_io(file_io)
+            .metadata(response.metadata);
+
+        let table = if let Some(metadata_location) = response.metadata_location {
+            table_builder.metadata_location(metadata_location).build()?
+        } else {
+            table_builder.build()?
+        };
+
+        match etag {
+            Some(etag) => self.table_cache.insert(cache_key, CachedTable {
+                etag,
+                table: table.clone(),
+                credentials_expire_at_ms,
+            }),
+            None => self.table_cache.invalidate(&cache_key),
+        }
+
+        Ok(table)
+    }
+
+    /// Drops a table, with the `purgeRequested` flag asking the server to also delete the
+    /// files of the table.
+    async fn delete_table(&self, table: &TableIdent, purge: bool) -> Result<()> {
+        let context = self.context().await?;
+        self.invalidate_cached_table(table);
+
+        let mut request = context
+            .client
+            .request(Method::DELETE, context.config.table_endpoint(table));
+        if purge {
+            request = request.query(&[("purgeRequested", "true")]);
+        }
+        let request = request.build()?;
+
+        let http_response = context.client.query_catalog(request).await?;
+
+        match http_response.status() {
+            StatusCode::NO_CONTENT | StatusCode::OK => Ok(()),
+            StatusCode::NOT_FOUND => Err(Error::new(
+                ErrorKind::Unexpected,
+                "Tried to drop a table that does not exist",
+            )),
+            _ => Err(deserialize_unexpected_catalog_error(
+                http_response,
+                context.client.disable_header_redaction(),
+            )
+            .await),
+        }
+    }
+
+    /// Invalidate the current token without generating a new one. On the next request, the client
+    /// will attempt to generate a new token.
+    pub async fn invalidate_token(&self) -> Result<()> {
+        self.context().await?.client.invalidate_token().await
+    }
+
+    /// Invalidate the current token and set a new one. Generates a new token before invalidating
+    /// the current token, meaning the old token will be used until this function acquires the lock
+    /// and overwrites the token.
+    ///
+    /// If credential is invalid, or the request fails, this method will return an error and leave
+    /// the current token unchanged.
+    pub async fn regenerate_token(&self) -> Result<()> {
+        self.context().await?.client.regenerate_token().await
+    }
+}
+
+/// All requests and expected responses are derived from the REST catalog API spec:
+/// https://github.com/apache/iceberg/blob/main/open-api/rest-catalog-open-api.yaml
+#[async_trait]
+impl Catalog for RestCatalog {
+    async fn list_namespaces(
+        &self,
+        parent: Option<&NamespaceIdent>,
+    ) -> Result<Vec<NamespaceIdent>> {
+        let context = self.context().await?;
+        let endpoint = context.config.namespaces_endpoint();
+        let mut namespaces = Vec::new();
+        let mut next_token = None;
+
+        loop {
+            let mut request = context.client.request(Method::GET, endpoint.clone());
+
+            // Filter on `parent={namespace}` if a parent namespace exists.
+            if let Some(ns) = parent {
+                request = request.query(&[("parent", ns.to_url_string())]);
+            }
+
+            if let Some(token) = next_token {
+                request = request.query(&[("pageToken", token)]);
+            }
+
+            let http_response = context.client.query_catalog(request.build()?).await?;
 
             match http_response.status() {
                 StatusCode::OK => {
@@ -698,76 +1300,18 @@ impl Catalog for RestCatalog {
         namespace: &NamespaceIdent,
         creation: TableCreation,
     ) -> Result<Table> {
-        let context = self.context().await?;
-
-        let table_ident = TableIdent::new(namespace.clone(), creation.name.clone());
-
-        let request = context
-            .client
-            .request(Method::POST, context.config.tables_endpoint(namespace))
-            .json(&CreateTableRequest {
-                name: creation.name,
-                location: creation.location,
-                schema: creation.schema,
-                partition_spec: creation.partition_spec,
-                write_order: creation.sort_order,
-                stage_create: Some(false),
-                properties: creation.properties,
-            })
-            .build()?;
-
-        let http_response = context.client.query_catalog(request).await?;
-
-        let response = match http_response.status() {
-            StatusCode::OK => {
-                deserialize_catalog_response::<LoadTableResult>(http_response).await?
-            }
-            StatusCode::NOT_FOUND => {
-                return Err(Error::new(
-                    ErrorKind::Unexpected,
-                    "Tried to create a table under a namespace that does not exist",
-                ));
-            }
-            StatusCode::CONFLICT => {
-                return Err(Error::new(
-                    ErrorKind::Unexpected,
-                    "The table already exists",
-                ));
-            }
-            _ => {
-                return Err(deserialize_unexpected_catalog_error(
-                    http_response,
-                    context.client.disable_header_redaction(),
-                )
-                .await);
-            }
-        };
-
-        let metadata_location = response.metadata_location.as_ref().ok_or(Error::new(
-            ErrorKind::DataInvalid,
-            "Metadata location missing in `create_table` response!",
-        ))?;
-
-        let config = response
-            .config
-            .into_iter()
-            .chain(self.user_config.props.clone())
-            .collect();
-
-        let file_io = self
-            .load_file_io(Some(metadata_location), Some(config))
-            .await?;
-
-        let table_builder = Table::builder()
-            .identifier(table_ident.clone())
-            .file_io(file_io)
-            .metadata(response.metadata);
+        self.create_table_request(namespace, creation, false).await
+    }
 
-        if let Some(metadata_location) = response.metadata_location {
-            table_builder.metadata_location(metadata_location).build()
-        } else {
-            table_builder.build()
-        }
+    /// Stage the creation of a table by sending a create request with `stage-create` set.
+    ///
+    /// The server returns the metadata of the table to create without committing it.
+    async fn stage_create_table(
+        &self,
+        namespace: &NamespaceIdent,
+        creation: TableCreation,
+    ) -> Result<Table> {
+        self.create_table_request(namespace, creation, true).await
     }
 
     /// Load table from the catalog.
@@ -776,79 +1320,29 @@ impl Catalog for RestCatalog {
     /// server and the config provided when creating this `RestCatalog` instance, then the value
     /// provided locally to the `RestCatalog` will take precedence.
     async fn load_table(&self, table_ident: &TableIdent) -> Result<Table> {
-        let context = self.context().await?;
-
-        let request = context
-            .client
-            .request(Method::GET, context.config.table_endpoint(table_ident))
-            .build()?;
-
-        let http_response = context.client.query_catalog(request).await?;
-
-        let response = match http_response.status() {
-            StatusCode::OK | StatusCode::NOT_MODIFIED => {
-                deserialize_catalog_response::<LoadTableResult>(http_response).await?
-            }
-            StatusCode::NOT_FOUND => {
-                return Err(Error::new(
-                    ErrorKind::Unexpected,
-                    "Tried to load a table that does not exist",
-                ));
-            }
-            _ => {
-                return Err(deserialize_unexpected_catalog_error(
-                    http_response,
-                    context.client.disable_header_redaction(),
-                )
-                .await);
-            }
-        };
-
-        let config = response
+        let referenced_snapshots_only = self
+            .context()
+            .await?
             .config
-            .into_iter()
-            .chain(self.user_config.props.clone())
-            .collect();
-
-        let file_io = self
-            .load_file_io(response.metadata_location.as_deref(), Some(config))
-            .await?;
-
-        let table_builder = Table::builder()
-            .identifier(table_ident.clone())
-            .file_io(file_io)
-            .metadata(response.metadata);
+            .load_referenced_snapshots_only();
+        self.load_table_with_snapshots(table_ident, referenced_snapshots_only)
+            .await
+    }
 
-        if let Some(metadata_location) = response.metadata_location {
-            table_builder.metadata_location(metadata_location).build()
-        } else {
-            table_builder.build()
-        }
+    /// Load table from the catalog with all its snapshots, even if the catalog is configured
+    /// to only load the snapshots referenced by branches and tags.
+    async fn load_table_for_commit(&self, table_ident: &TableIdent) -> Result<Table> {
+        self.load_table_with_snapshots(table_ident, false).await
     }
 
     /// Drop a table from the catalog.
     async fn drop_table(&self, table: &TableIdent) -> Result<()> {
-        let context = self.context().await?;
-
-        let request = context
-            .client
-            .request(Method::DELETE, context.config.table_endpoint(table))
-            .build()?;
-
-        let http_response = context.client.query_catalog(request).await?;
+        self.delete_table(table, false).await
+    }
 
-        match http_response.status() {
-            StatusCode::NO_CONTENT | StatusCode::OK => Ok(()),
-            StatusCode::NOT_FOUND => Err(Error::new(
-                ErrorKind::Unexpected,
-                "Tried to drop a table that does not exist",
-            )),
-            _ => Err(deserialize_unexpected_catalog_error(
-                http_response,
-                context.client.disable_header_redaction(),
-            )
-            .await),
-        }
+    /// Drop a table from the catalog, asking the server to delete its files.
+    async fn purge_table(&self, table: &TableIdent) -> Result<()> {
+        self.delete_table(table, true).await
     }
 
     /// Check if a table exists in the catalog.
@@ -876,6 +1370,8 @@ impl Catalog for RestCatalog {
     /// Rename a table in the catalog.
     async fn rename_table(&self, src: &TableIdent, dest: &TableIdent) -> Result<()> {
         let context = self.context().await?;
+        self.invalidate_cached_table(src);
+        self.invalidate_cached_table(dest);
 
         let request = context
             .client
@@ -962,7 +1458,8 @@ impl Catalog for RestCatalog {
 
         let file_io = self.load_file_io(Some(metadata_location), None).await?;
 
-        Table::builder()
+        context
+            .table_builder()
             .identifier(table_ident.clone())
             .file_io(file_io)
             .metadata(response.metadata)
@@ -972,6 +1469,7 @@ impl Catalog for RestCatalog {
 
     async fn update_table(&self, mut commit: TableCommit) -> Result<Table> {
         let context = self.context().await?;
+        self.invalidate_cached_table(commit.identifier());
 
         let request = context
             .client
@@ -1034,50 +1532,1134 @@ impl Catalog for RestCatalog {
             .load_file_io(Some(&response.metadata_location), None)
             .await?;
 
-        Table::builder()
+        context
+            .table_builder()
             .identifier(commit.identifier().clone())
             .file_io(file_io)
             .metadata(response.metadata)
             .metadata_location(response.metadata_location)
             .build()
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use std::fs::File;
-    use std::io::BufReader;
-    use std::sync::Arc;
+    async fn commit_transaction(&self, mut commits: Vec<TableCommit>) -> Result<()> {
+        let context = self.context().await?;
+        for commit in &commits {
+            self.invalidate_cached_table(commit.identifier());
+        }
 
-    use chrono::{TimeZone, Utc};
-    use iceberg::io::LocalFsStorageFactory;
-    use iceberg::spec::{
-        FormatVersion, NestedField, NullOrder, Operation, PrimitiveType, Schema, Snapshot,
-        SnapshotLog, SortDirection, SortField, SortOrder, Summary, Transform, Type,
-        UnboundPartitionField, UnboundPartitionSpec,
-    };
-    use iceberg::transaction::{ApplyTransactionAction, Transaction};
+        let request = context
+            .client
+            .request(Method::POST, context.config.transactions_commit_endpoint())
+            .json(&CommitTransactionRequest {
+                table_changes: commits
+                    .iter_mut()
+                    .map(|commit| CommitTableRequest {
+                        identifier: Some(commit.identifier().clone()),
+                        requirements: commit.take_requirements(),
+                        updates: commit.take_updates(),
+                    })
+                    .collect(),
+            })
+            .build()?;
+
+        let http_response = context.client.query_catalog(request).await?;
+
+        match http_response.status() {
+            StatusCode::NO_CONTENT | StatusCode::OK => Ok(()),
+            StatusCode::NOT_FOUND => Err(Error::new(
+                ErrorKind::TableNotFound,
+                "Tried to update a table that does not exist",
+            )),
+            StatusCode::CONFLICT => Err(Error::new(
+                ErrorKind::CatalogCommitConflicts,
+                "CatalogCommitConflicts, one or more requirements failed. The client may retry.",
+            )
+            .with_retryable(true)),
+            StatusCode::INTERNAL_SERVER_ERROR => Err(Error::new(
+                ErrorKind::Unexpected,
+                "An unknown server-side problem occurred; the commit state is unknown.",
+            )),
+            StatusCode::BAD_GATEWAY => Err(Error::new(
+                ErrorKind::Unexpected,
+                "A gateway or proxy received an invalid response from the upstream server; the commit state is unknown.",
+            )),
+            StatusCode::GATEWAY_TIMEOUT => Err(Error::new(
+                ErrorKind::Unexpected,
+                "A server-side gateway timeout occurred; the commit state is unknown.",
+            )),
+            _ => Err(deserialize_unexpected_catalog_error(
+                http_response,
+                context.client.disable_header_redaction(),
+            )
+            .await),
+        }
+    }
+
+    async fn list_views(&self, namespace: &NamespaceIdent) -> Result<Vec<TableIdent>> {
+        let context = self.context().await?;
+        let endpoint = context.config.views_endpoint(namespace);
+        let mut identifiers = Vec::new();
+        let mut next_token = None;
+
+        loop {
+            let mut request = context.client.request(Method::GET, endpoint.clone());
+
+            if let Some(token) = next_token {
+                request = request.query(&[("pageToken", token)]);
+            }
+
+            let http_response = context.client.query_catalog(request.build()?).await?;
+
+            match http_response.status() {
+                StatusCode::OK => {
+                    let response =
+                        deserialize_catalog_response::<ListTablesResponse>(http_response).await?;
+
+                    identifiers.extend(response.identifiers);
+
+                    match response.next_page_token {
+                        Some(token) => next_token = Some(token),
+                        None => break,
+                    }
+                }
+                StatusCode::NOT_FOUND => {
+                    return Err(Error::new(
+                        ErrorKind::NamespaceNotFound,
+                        "Tried to list views of a namespace that does not exist",
+                    ));
+                }
+                _ => {
+                    return Err(deserialize_unexpected_catalog_error(
+                        http_response,
+                        context.client.disable_header_redaction(),
+                    )
+                    .await);
+                }
+            }
+        }
+
+        Ok(identifiers)
+    }
+
+    async fn create_view(
+        &self,
+        namespace: &NamespaceIdent,
+        creation: ViewCreation,
+    ) -> Result<View> {
+        let context = self.context().await?;
+
+        let view_ident = TableIdent::new(namespace.clone(), creation.name.clone());
+        let view_version = ViewVersion::builder()
+            .with_schema_id(creation.schema.schema_id())
+            .with_timestamp_ms(Utc::now().timestamp_millis())
+            .with_summary(creation.summary)
+            .with_representations(creation.representations)
+            .with_default_catalog(creation.default_catalog)
+            .with_default_namespace(creation.default_namespace)
+            .build();
+
+        let request = context
+            .client
+            .request(Method::POST, context.config.views_endpoint(namespace))
+            .json(&CreateViewRequest {
+                name: creation.name,
+                location: Some(creation.location),
+                schema: creation.schema,
+                view_version,
+                properties: creation.properties,
+            })
+            .build()?;
+
+        let http_response = context.client.query_catalog(request).await?;
+
+        let response = match http_response.status() {
+            StatusCode::OK => deserialize_catalog_response::<LoadViewResult>(http_response).await?,
+            StatusCode::NOT_FOUND => {
+                return Err(Error::new(
+                    ErrorKind::NamespaceNotFound,
+                    "Tried to create a view under a namespace that does not exist",
+                ));
+            }
+            StatusCode::CONFLICT => {
+                return Err(Error::new(
+                    ErrorKind::ViewAlreadyExists,
+                    "The view already exists",
+                ));
+            }
+            _ => {
+                return Err(deserialize_unexpected_catalog_error(
+                    http_response,
+                    context.client.disable_header_redaction(),
+                )
+                .await);
+            }
+        };
+
+        View::builder()
+            .identifier(view_ident)
+            .metadata(response.metadata)
+            .metadata_location(response.metadata_location)
+            .build()
+    }
+
+    async fn load_view(&self, view_ident: &TableIdent) -> Result<View> {
+        let context = self.context().await?;
+
+        let request = context
+            .client
+            .request(Method::GET, context.config.view_endpoint(view_ident))
+            .build()?;
+
+        let http_response = context.client.query_catalog(request).await?;
+
+        let response = match http_response.status() {
+            StatusCode::OK => deserialize_catalog_response::<LoadViewResult>(http_response).await?,
+            StatusCode::NOT_FOUND => {
+                return Err(Error::new(
+                    ErrorKind::ViewNotFound,
+                    "Tried to load a view that does not exist",
+                ));
+            }
+            _ => {
+                return Err(deserialize_unexpected_catalog_error(
+                    http_response,
+                    context.client.disable_header_redaction(),
+                )
+                .await);
+            }
+        };
+
+        View::builder()
+            .identifier(view_ident.clone())
+            .metadata(response.metadata)
+            .metadata_location(response.metadata_location)
+            .build()
+    }
+
+    async fn drop_view(&self, view: &TableIdent) -> Result<()> {
+        let context = self.context().await?;
+
+        let request = context
+            .client
+            .request(Method::DELETE, context.config.view_endpoint(view))
+            .build()?;
+
+        let http_response = context.client.query_catalog(request).await?;
+
+        match http_response.status() {
+            StatusCode::NO_CONTENT | StatusCode::OK => Ok(()),
+            StatusCode::NOT_FOUND => Err(Error::new(
+                ErrorKind::ViewNotFound,
+                "Tried to drop a view that does not exist",
+            )),
+            _ => Err(deserialize_unexpected_catalog_error(
+                http_response,
+                context.client.disable_header_redaction(),
+            )
+            .await),
+        }
+    }
+
+    async fn view_exists(&self, view: &TableIdent) -> Result<bool> {
+        let context = self.context().await?;
+
+        let request = context
+            .client
+            .request(Method::HEAD, context.config.view_endpoint(view))
+            .build()?;
+
+        let http_response = context.client.query_catalog(request).await?;
+
+        match http_response.status() {
+            StatusCode::NO_CONTENT | StatusCode::OK => Ok(true),
+            StatusCode::NOT_FOUND => Ok(false),
+            _ => Err(deserialize_unexpected_catalog_error(
+                http_response,
+                context.client.disable_header_redaction(),
+            )
+            .await),
+        }
+    }
+
+    async fn rename_view(&self, src: &TableIdent, dest: &TableIdent) -> Result<()> {
+        let context = self.context().await?;
+
+        let request = context
+            .client
+            .request(Method::POST, context.config.rename_view_endpoint())
+            .json(&RenameTableRequest {
+                source: src.clone(),
+                destination: dest.clone(),
+            })
+            .build()?;
+
+        let http_response = context.client.query_catalog(request).await?;
+
+        match http_response.status() {
+            StatusCode::NO_CONTENT | StatusCode::OK => Ok(()),
+            StatusCode::NOT_FOUND => Err(Error::new(
+                ErrorKind::ViewNotFound,
+                "Tried to rename a view that does not exist (is the namespace correct?)",
+            )),
+            StatusCode::CONFLICT => Err(Error::new(
+                ErrorKind::ViewAlreadyExists,
+                "Tried to rename a view to a name that already exists",
+            )),
+            _ => Err(deserialize_unexpected_catalog_error(
+                http_response,
+                context.client.disable_header_redaction(),
+            )
+            .await),
+        }
+    }
+
+    async fn update_view(&self, mut commit: ViewCommit) -> Result<View> {
+        let context = self.context().await?;
+
+        let request = context
+            .client
+            .request(
+                Method::POST,
+                context.config.view_endpoint(commit.identifier()),
+            )
+            .json(&CommitViewRequest {
+                identifier: Some(commit.identifier().clone()),
+                requirements: commit.take_requirements(),
+                updates: commit.take_updates(),
+            })
+            .build()?;
+
+        let http_response = context.client.query_catalog(request).await?;
+
+        let response = match http_response.status() {
+            StatusCode::OK => deserialize_catalog_response::<LoadViewResult>(http_response).await?,
+            StatusCode::NOT_FOUND => {
+                return Err(Error::new(
+                    ErrorKind::ViewNotFound,
+                    "Tried to update a view that does not exist",
+                ));
+            }
+            StatusCode::CONFLICT => {
+                return Err(Error::new(
+                    ErrorKind::CatalogCommitConflicts,
+                    "CatalogCommitConflicts, one or more requirements failed. The client may retry.",
+                )
+                .with_retryable(true));
+            }
+            StatusCode::INTERNAL_SERVER_ERROR => {
+                return Err(Error::new(
+                    ErrorKind::Unexpected,
+                    "An unknown server-side problem occurred; the commit state is unknown.",
+                ));
+            }
+            StatusCode::BAD_GATEWAY => {
+                return Err(Error::new(
+                    ErrorKind::Unexpected,
+                    "A gateway or proxy received an invalid response from the upstream server; the commit state is unknown.",
+                ));
+            }
+            StatusCode::GATEWAY_TIMEOUT => {
+                return Err(Error::new(
+                    ErrorKind::Unexpected,
+                    "A server-side gateway timeout occurred; the commit state is unknown.",
+                ));
+            }
+            _ => {
+                return Err(deserialize_unexpected_catalog_error(
+                    http_response,
+                    context.client.disable_header_redaction(),
+                )
+                .await);
+            }
+        };
+
+        View::builder()
+            .identifier(commit.identifier().clone())
+            .metadata(response.metadata)
+            .metadata_location(response.metadata_location)
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+    use std::io::BufReader;
+    use std::sync::Arc;
+
+    use chrono::{TimeZone, Utc};
+    use futures::TryStreamExt;
+    use iceberg::expr::Reference;
+    use iceberg::io::LocalFsStorageFactory;
+    use iceberg::metrics::{CommitMetricsResult, CommitReport, MetricsReport};
+    use iceberg::spec::{
+        Datum, FormatVersion, NestedField, NullOrder, Operation, PrimitiveType, Schema, Snapshot,
+        SnapshotLog, SortDirection, SortField, SortOrder, SqlViewRepresentation, Summary,
+        Transform, Type, UnboundPartitionField, UnboundPartitionSpec, ViewRepresentations,
+    };
+    use iceberg::transaction::{
+        ApplyTransactionAction, MultiTableTransaction, Transaction, ViewTransaction,
+    };
     use mockito::{Mock, Server, ServerGuard};
     use serde_json::json;
     use uuid::uuid;
 
-    use super::*;
+    use super::*;
+    #[cfg(feature = "sigv4")]
+    use crate::sigv4::SigV4Signer;
+    #[cfg(feature = "sigv4")]
+    use crate::sigv4::tests::{TEST_ACCESS_KEY_ID, TEST_SECRET_ACCESS_KEY, test_authorization};
+
+    #[tokio::test]
+    async fn test_update_config() {
+        let mut server = Server::new_async().await;
+
+        let config_mock = server
+            .mock("GET", "/v1/config")
+            .with_status(200)
+            .with_body(
+                r#"{
+                "overrides": {
+                    "warehouse": "s3://iceberg-catalog"
+                },
+                "defaults": {}
+            }"#,
+            )
+            .create_async()
+            .await;
+
+        let catalog = RestCatalog::new(
+            RestCatalogConfig::builder().uri(server.url()).build(),
+            Some(Arc::new(LocalFsStorageFactory)),
+        );
+
+        assert_eq!(
+            catalog
+                .context()
+                .await
+                .unwrap()
+                .config
+                .props
+                .get("warehouse"),
+            Some(&"s3://iceberg-catalog".to_string())
+        );
+
+        config_mock.assert_async().await;
+    }
+
+    async fn create_config_mock(server: &mut ServerGuard) -> Mock {
+        server
+            .mock("GET", "/v1/config")
+            .with_status(200)
+            .with_body(
+                r#"{
+                "overrides": {
+                    "warehouse": "s3://iceberg-catalog"
+                },
+                "defaults": {}
+            }"#,
+            )
+            .create_async()
+            .await
+    }
+
+    async fn create_oauth_mock(server: &mut ServerGuard) -> Mock {
+        create_oauth_mock_with_path(server, "/v1/oauth/tokens", "ey000000000000", 200).await
+    }
+
+    async fn create_oauth_mock_with_path(
+        server: &mut ServerGuard,
+        path: &str,
+        token: &str,
+        status: usize,
+    ) -> Mock {
+        let body = format!(
+            r#"{{
+                "access_token": "{token}",
+                "token_type": "Bearer",
+                "issued_token_type": "urn:ietf:params:oauth:token-type:access_token",
+                "expires_in": 86400
+            }}"#
+        );
+        server
+            .mock("POST", path)
+            .with_status(status)
+            .with_body(body)
+            .expect(1)
+            .create_async()
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_oauth() {
+        let mut server = Server::new_async().await;
+        let oauth_mock = create_oauth_mock(&mut server).await;
+        let config_mock = create_config_mock(&mut server).await;
+
+        let mut props = HashMap::new();
+        props.insert("credential".to_string(), "client1:secret1".to_string());
+
+        let catalog = RestCatalog::new(
+            RestCatalogConfig::builder()
+                .uri(server.url())
+                .props(props)
+                .build(),
+            Some(Arc::new(LocalFsStorageFactory)),
+        );
+
+        let token = catalog.context().await.unwrap().client.token().await;
+        oauth_mock.assert_async().await;
+        config_mock.assert_async().await;
+        assert_eq!(token, Some("ey000000000000".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_oauth_with_optional_param() {
+        let mut props = HashMap::new();
+        props.insert("credential".to_string(), "client1:secret1".to_string());
+        props.insert("scope".to_string(), "custom_scope".to_string());
+        props.insert("audience".to_string(), "custom_audience".to_string());
+        props.insert("resource".to_string(), "custom_resource".to_string());
+
+        let mut server = Server::new_async().await;
+        let oauth_mock = server
+            .mock("POST", "/v1/oauth/tokens")
+            .match_body(mockito::Matcher::Regex("scope=custom_scope".to_string()))
+            .match_body(mockito::Matcher::Regex(
+                "audience=custom_audience".to_string(),
+            ))
+            .match_body(mockito::Matcher::Regex(
+                "resource=custom_resource".to_string(),
+            ))
+            .with_status(200)
+            .with_body(
+                r#"{
+                "access_token": "ey000000000000",
+                "token_type": "Bearer",
+                "issued_token_type": "urn:ietf:params:oauth:token-type:access_token",
+                "expires_in": 86400
+                }"#,
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let config_mock = create_config_mock(&mut server).await;
+
+        let catalog = RestCatalog::new(
+            RestCatalogConfig::builder()
+                .uri(server.url())
+                .props(props)
+                .build(),
+            Some(Arc::new(LocalFsStorageFactory)),
+        );
+
+        let token = catalog.context().await.unwrap().client.token().await;
+
+        oauth_mock.assert_async().await;
+        config_mock.assert_async().await;
+        assert_eq!(token, Some("ey000000000000".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_token() {
+        let mut server = Server::new_async().await;
+        let oauth_mock = create_oauth_mock(&mut server).await;
+        let config_mock = create_config_mock(&mut server).await;
+
+        let mut props = HashMap::new();
+        props.insert("credential".to_string(), "client1:secret1".to_string());
+
+        let catalog = RestCatalog::new(
+            RestCatalogConfig::builder()
+                .uri(server.url())
+                .props(props)
+                .build(),
+            Some(Arc::new(LocalFsStorageFactory)),
+        );
+
+        let token = catalog.context().await.unwrap().client.token().await;
+        oauth_mock.assert_async().await;
+        config_mock.assert_async().await;
+        assert_eq!(token, Some("ey000000000000".to_string()));
+
+        let oauth_mock =
+            create_oauth_mock_with_path(&mut server, "/v1/oauth/tokens", "ey000000000001", 200)
+                .await;
+        catalog.invalidate_token().await.unwrap();
+        let token = catalog.context().await.unwrap().client.token().await;
+        oauth_mock.assert_async().await;
+        assert_eq!(token, Some("ey000000000001".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_token_failing_request() {
+        let mut server = Server::new_async().await;
+        let oauth_mock = create_oauth_mock(&mut server).await;
+        let config_mock = create_config_mock(&mut server).await;
+
+        let mut props = HashMap::new();
+        props.insert("credential".to_string(), "client1:secret1".to_string());
+
+        let catalog = RestCatalog::new(
+            RestCatalogConfig::builder()
+                .uri(server.url())
+                .props(props)
+                .build(),
+            Some(Arc::new(LocalFsStorageFactory)),
+        );
+
+        let token = catalog.context().await.unwrap().client.token().await;
+        oauth_mock.assert_async().await;
+        config_mock.assert_async().await;
+        assert_eq!(token, Some("ey000000000000".to_string()));
+
+        let oauth_mock =
+            create_oauth_mock_with_path(&mut server, "/v1/oauth/tokens", "ey000000000001", 500)
+                .await;
+        catalog.invalidate_token().await.unwrap();
+        let token = catalog.context().await.unwrap().client.token().await;
+        oauth_mock.assert_async().await;
+        assert_eq!(token, None);
+    }
+
+    #[tokio::test]
+    async fn test_regenerate_token() {
+        let mut server = Server::new_async().await;
+        let oauth_mock = create_oauth_mock(&mut server).await;
+        let config_mock = create_config_mock(&mut server).await;
+
+        let mut props = HashMap::new();
+        props.insert("credential".to_string(), "client1:secret1".to_string());
+
+        let catalog = RestCatalog::new(
+            RestCatalogConfig::builder()
+                .uri(server.url())
+                .props(props)
+                .build(),
+            Some(Arc::new(LocalFsStorageFactory)),
+        );
+
+        let token = catalog.context().await.unwrap().client.token().await;
+        oauth_mock.assert_async().await;
+        config_mock.assert_async().await;
+        assert_eq!(token, Some("ey000000000000".to_string()));
+
+        let oauth_mock =
+            create_oauth_mock_with_path(&mut server, "/v1/oauth/tokens", "ey000000000001", 200)
+                .await;
+        catalog.regenerate_token().await.unwrap();
+        oauth_mock.assert_async().await;
+        let token = catalog.context().await.unwrap().client.token().await;
+        assert_eq!(token, Some("ey000000000001".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_regenerate_token_failing_request() {
+        let mut server = Server::new_async().await;
+        let oauth_mock = create_oauth_mock(&mut server).await;
+        let config_mock = create_config_mock(&mut server).await;
+
+        let mut props = HashMap::new();
+        props.insert("credential".to_string(), "client1:secret1".to_string());
+
+        let catalog = RestCatalog::new(
+            RestCatalogConfig::builder()
+                .uri(server.url())
+                .props(props)
+                .build(),
+            Some(Arc::new(LocalFsStorageFactory)),
+        );
+
+        let token = catalog.context().await.unwrap().client.token().await;
+        oauth_mock.assert_async().await;
+        config_mock.assert_async().await;
+        assert_eq!(token, Some("ey000000000000".to_string()));
+
+        let oauth_mock =
+            create_oauth_mock_with_path(&mut server, "/v1/oauth/tokens", "ey000000000001", 500)
+                .await;
+        let invalidate_result = catalog.regenerate_token().await;
+        assert!(invalidate_result.is_err());
+        oauth_mock.assert_async().await;
+        let token = catalog.context().await.unwrap().client.token().await;
+
+        // original token is left intact
+        assert_eq!(token, Some("ey000000000000".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_http_headers() {
+        let server = Server::new_async().await;
+        let mut props = HashMap::new();
+        props.insert("credential".to_string(), "client1:secret1".to_string());
+
+        let config = RestCatalogConfig::builder()
+            .uri(server.url())
+            .props(props)
+            .build();
+        let headers: HeaderMap = config.extra_headers().unwrap();
+
+        let expected_headers = HeaderMap::from_iter([
+            (
+                header::CONTENT_TYPE,
+                HeaderValue::from_static("application/json"),
+            ),
+            (
+                HeaderName::from_static("x-client-version"),
+                HeaderValue::from_static(ICEBERG_REST_SPEC_VERSION),
+            ),
+            (
+                header::USER_AGENT,
+                HeaderValue::from_str(&format!("iceberg-rs/{CARGO_PKG_VERSION}")).unwrap(),
+            ),
+        ]);
+        assert_eq!(headers, expected_headers);
+    }
+
+    #[tokio::test]
+    async fn test_http_headers_with_custom_headers() {
+        let server = Server::new_async().await;
+        let mut props = HashMap::new();
+        props.insert("credential".to_string(), "client1:secret1".to_string());
+        props.insert(
+            "header.content-type".to_string(),
+            "application/yaml".to_string(),
+        );
+        props.insert(
+            "header.customized-header".to_string(),
+            "some/value".to_string(),
+        );
+
+        let config = RestCatalogConfig::builder()
+            .uri(server.url())
+            .props(props)
+            .build();
+        let headers: HeaderMap = config.extra_headers().unwrap();
+
+        let expected_headers = HeaderMap::from_iter([
+            (
+                header::CONTENT_TYPE,
+                HeaderValue::from_static("application/yaml"),
+            ),
+            (
+                HeaderName::from_static("x-client-version"),
+                HeaderValue::from_static(ICEBERG_REST_SPEC_VERSION),
+            ),
+            (
+                header::USER_AGENT,
+                HeaderValue::from_str(&format!("iceberg-rs/{CARGO_PKG_VERSION}")).unwrap(),
+            ),
+            (
+                HeaderName::from_static("customized-header"),
+                HeaderValue::from_static("some/value"),
+            ),
+        ]);
+        assert_eq!(headers, expected_headers);
+    }
+
+    #[tokio::test]
+    async fn test_oauth_with_oauth2_server_uri() {
+        let mut server = Server::new_async().await;
+        let config_mock = create_config_mock(&mut server).await;
+
+        let mut auth_server = Server::new_async().await;
+        let auth_server_path = "/some/path";
+        let oauth_mock =
+            create_oauth_mock_with_path(&mut auth_server, auth_server_path, "ey000000000000", 200)
+                .await;
+
+        let mut props = HashMap::new();
+        props.insert("credential".to_string(), "client1:secret1".to_string());
+        props.insert(
+            "oauth2-server-uri".to_string(),
+            format!("{}{}", auth_server.url(), auth_server_path).to_string(),
+        );
+
+        let catalog = RestCatalog::new(
+            RestCatalogConfig::builder()
+                .uri(server.url())
+                .props(props)
+                .build(),
+            Some(Arc::new(LocalFsStorageFactory)),
+        );
+
+        let token = catalog.context().await.unwrap().client.token().await;
+
+        oauth_mock.assert_async().await;
+        config_mock.assert_async().await;
+        assert_eq!(token, Some("ey000000000000".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_config_override() {
+        let mut server = Server::new_async().await;
+        let mut redirect_server = Server::new_async().await;
+        let new_uri = redirect_server.url();
+
+        let config_mock = server
+            .mock("GET", "/v1/config")
+            .with_status(200)
+            .with_body(
+                json!(
+                    {
+                        "overrides": {
+                            "uri": new_uri,
+                            "warehouse": "s3://iceberg-catalog",
+                            "prefix": "ice/warehouses/my"
+                        },
+                        "defaults": {},
+                    }
+                )
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let list_ns_mock = redirect_server
+            .mock("GET", "/v1/ice/warehouses/my/namespaces")
+            .with_body(
+                r#"{
+                    "namespaces": []
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let catalog = RestCatalog::new(
+            RestCatalogConfig::builder().uri(server.url()).build(),
+            Some(Arc::new(LocalFsStorageFactory)),
+        );
+
+        let _namespaces = catalog.list_namespaces(None).await.unwrap();
+
+        config_mock.assert_async().await;
+        list_ns_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_list_namespace() {
+        let mut server = Server::new_async().await;
+
+        let config_mock = create_config_mock(&mut server).await;
+
+        let list_ns_mock = server
+            .mock("GET", "/v1/namespaces")
+            .with_body(
+                r#"{
+                "namespaces": [
+                    ["ns1", "ns11"],
+                    ["ns2"]
+                ]
+            }"#,
+            )
+            .create_async()
+            .await;
+
+        let catalog = RestCatalog::new(
+            RestCatalogConfig::builder().uri(server.url()).build(),
+            Some(Arc::new(LocalFsStorageFactory)),
+        );
+
+        let namespaces = catalog.list_namespaces(None).await.unwrap();
+
+        let expected_ns = vec![
+            NamespaceIdent::from_vec(vec!["ns1".to_string(), "ns11".to_string()]).unwrap(),
+            NamespaceIdent::from_vec(vec!["ns2".to_string()]).unwrap(),
+        ];
+
+        assert_eq!(expected_ns, namespaces);
+
+        config_mock.assert_async().await;
+        list_ns_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_list_namespace_with_pagination() {
+        let mut server = Server::new_async().await;
+
+        let config_mock = create_config_mock(&mut server).await;
+
+        let list_ns_mock_page1 = server
+            .mock("GET", "/v1/namespaces")
+            .with_body(
+                r#"{
+                "namespaces": [
+                    ["ns1", "ns11"],
+                    ["ns2"]
+                ],
+                "next-page-token": "token123"
+            }"#,
+            )
+            .create_async()
+            .await;
+
+        let list_ns_mock_page2 = server
+            .mock("GET", "/v1/namespaces?pageToken=token123")
+            .with_body(
+                r#"{
+                "namespaces": [
+                    ["ns3"],
+                    ["ns4", "ns41"]
+                ]
+            }"#,
+            )
+            .create_async()
+            .await;
+
+        let catalog = RestCatalog::new(
+            RestCatalogConfig::builder().uri(server.url()).build(),
+            Some(Arc::new(LocalFsStorageFactory)),
+        );
+
+        let namespaces = catalog.list_namespaces(None).await.unwrap();
+
+        let expected_ns = vec![
+            NamespaceIdent::from_vec(vec!["ns1".to_string(), "ns11".to_string()]).unwrap(),
+            NamespaceIdent::from_vec(vec!["ns2".to_string()]).unwrap(),
+            NamespaceIdent::from_vec(vec!["ns3".to_string()]).unwrap(),
+            NamespaceIdent::from_vec(vec!["ns4".to_string(), "ns41".to_string()]).unwrap(),
+        ];
+
+        assert_eq!(expected_ns, namespaces);
+
+        config_mock.assert_async().await;
+        list_ns_mock_page1.assert_async().await;
+        list_ns_mock_page2.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_list_namespace_with_multiple_pages() {
+        let mut server = Server::new_async().await;
+
+        let config_mock = create_config_mock(&mut server).await;
+
+        // Page 1
+        let list_ns_mock_page1 = server
+            .mock("GET", "/v1/namespaces")
+            .with_body(
+                r#"{
+                "namespaces": [
+                    ["ns1", "ns11"],
+                    ["ns2"]
+                ],
+                "next-page-token": "page2"
+            }"#,
+            )
+            .create_async()
+            .await;
+
+        // Page 2
+        let list_ns_mock_page2 = server
+            .mock("GET", "/v1/namespaces?pageToken=page2")
+            .with_body(
+                r#"{
+                "namespaces": [
+                    ["ns3"],
+                    ["ns4", "ns41"]
+                ],
+                "next-page-token": "page3"
+            }"#,
+            )
+            .create_async()
+            .await;
+
+        // Page 3
+        let list_ns_mock_page3 = server
+            .mock("GET", "/v1/namespaces?pageToken=page3")
+            .with_body(
+                r#"{
+                "namespaces": [
+                    ["ns5", "ns51", "ns511"]
+                ],
+                "next-page-token": "page4"
+            }"#,
+            )
+            .create_async()
+            .await;
+
+        // Page 4
+        let list_ns_mock_page4 = server
+            .mock("GET", "/v1/namespaces?pageToken=page4")
+            .with_body(
+                r#"{
+                "namespaces": [
+                    ["ns6"],
+                    ["ns7"]
+                ],
+                "next-page-token": "page5"
+            }"#,
+            )
+            .create_async()
+            .await;
+
+        // Page 5 (final page)
+        let list_ns_mock_page5 = server
+            .mock("GET", "/v1/namespaces?pageToken=page5")
+            .with_body(
+                r#"{
+                "namespaces": [
+                    ["ns8", "ns81"]
+                ]
+            }"#,
+            )
+            .create_async()
+            .await;
+
+        let catalog = RestCatalog::new(
+            RestCatalogConfig::builder().uri(server.url()).build(),
+            Some(Arc::new(LocalFsStorageFactory)),
+        );
+
+        let namespaces = catalog.list_namespaces(None).await.unwrap();
+
+        let expected_ns = vec![
+            NamespaceIdent::from_vec(vec!["ns1".to_string(), "ns11".to_string()]).unwrap(),
+            NamespaceIdent::from_vec(vec!["ns2".to_string()]).unwrap(),
+            NamespaceIdent::from_vec(vec!["ns3".to_string()]).unwrap(),
+            NamespaceIdent::from_vec(vec!["ns4".to_string(), "ns41".to_string()]).unwrap(),
+            NamespaceIdent::from_vec(vec![
+                "ns5".to_string(),
+                "ns51".to_string(),
+                "ns511".to_string(),
+            ])
+            .unwrap(),
+            NamespaceIdent::from_vec(vec!["ns6".to_string()]).unwrap(),
+            NamespaceIdent::from_vec(vec!["ns7".to_string()]).unwrap(),
+            NamespaceIdent::from_vec(vec!["ns8".to_string(), "ns81".to_string()]).unwrap(),
+        ];
+
+        assert_eq!(expected_ns, namespaces);
+
+        // Verify all page requests were made
+        config_mock.assert_async().await;
+        list_ns_mock_page1.assert_async().await;
+        list_ns_mock_page2.assert_async().await;
+        list_ns_mock_page3.assert_async().await;
+        list_ns_mock_page4.assert_async().await;
+        list_ns_mock_page5.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_create_namespace() {
+        let mut server = Server::new_async().await;
+
+        let config_mock = create_config_mock(&mut server).await;
+
+        let create_ns_mock = server
+            .mock("POST", "/v1/namespaces")
+            .with_body(
+                r#"{
+                "namespace": [ "ns1", "ns11"],
+                "properties" : {
+                    "key1": "value1"
+                }
+            }"#,
+            )
+            .create_async()
+            .await;
+
+        let catalog = RestCatalog::new(
+            RestCatalogConfig::builder().uri(server.url()).build(),
+            Some(Arc::new(LocalFsStorageFactory)),
+        );
+
+        let namespaces = catalog
+            .create_namespace(
+                &NamespaceIdent::from_vec(vec!["ns1".to_string(), "ns11".to_string()]).unwrap(),
+                HashMap::from([("key1".to_string(), "value1".to_string())]),
+            )
+            .await
+            .unwrap();
+
+        let expected_ns = Namespace::with_properties(
+            NamespaceIdent::from_vec(vec!["ns1".to_string(), "ns11".to_string()]).unwrap(),
+            HashMap::from([("key1".to_string(), "value1".to_string())]),
+        );
+
+        assert_eq!(expected_ns, namespaces);
+
+        config_mock.assert_async().await;
+        create_ns_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_get_namespace() {
+        let mut server = Server::new_async().await;
+
+        let config_mock = create_config_mock(&mut server).await;
+
+        let get_ns_mock = server
+            .mock("GET", "/v1/namespaces/ns1")
+            .with_body(
+                r#"{
+                "namespace": [ "ns1"],
+                "properties" : {
+                    "key1": "value1"
+                }
+            }"#,
+            )
+            .create_async()
+            .await;
+
+        let catalog = RestCatalog::new(
+            RestCatalogConfig::builder().uri(server.url()).build(),
+            Some(Arc::new(LocalFsStorageFactory)),
+        );
+
+        let namespaces = catalog
+            .get_namespace(&NamespaceIdent::new("ns1".to_string()))
+            .await
+            .unwrap();
+
+        let expected_ns = Namespace::with_properties(
+            NamespaceIdent::new("ns1".to_string()),
+            HashMap::from([("key1".to_string(), "value1".to_string())]),
+        );
+
+        assert_eq!(expected_ns, namespaces);
+
+        config_mock.assert_async().await;
+        get_ns_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn check_namespace_exists() {
+        let mut server = Server::new_async().await;
+
+        let config_mock = create_config_mock(&mut server).await;
+
+        let get_ns_mock = server
+            .mock("HEAD", "/v1/namespaces/ns1")
+            .with_status(204)
+            .create_async()
+            .await;
+
+        let catalog = RestCatalog::new(
+            RestCatalogConfig::builder().uri(server.url()).build(),
+            Some(Arc::new(LocalFsStorageFactory)),
+        );
+
+        assert!(
+            catalog
+                .namespace_exists(&NamespaceIdent::new("ns1".to_string()))
+                .await
+                .unwrap()
+        );
+
+        config_mock.assert_async().await;
+        get_ns_mock.assert_async().await;
+    }
 
     #[tokio::test]
-    async fn test_update_config() {
+    async fn test_drop_namespace() {
         let mut server = Server::new_async().await;
 
-        let config_mock = server
-            .mock("GET", "/v1/config")
-            .with_status(200)
-            .with_body(
-                r#"{
-                "overrides": {
-                    "warehouse": "s3://iceberg-catalog"
-                },
-                "defaults": {}
-            }"#,
-            )
+        let config_mock = create_config_mock(&mut server).await;
+
+        let drop_ns_mock = server
+            .mock("DELETE", "/v1/namespaces/ns1")
+            .with_status(204)
             .create_async()
             .await;
 
@@ -1086,396 +2668,343 @@ mod tests {
             Some(Arc::new(LocalFsStorageFactory)),
         );
 
-        assert_eq!(
-            catalog
-                .context()
-                .await
-                .unwrap()
-                .config
-                .props
-                .get("warehouse"),
-            Some(&"s3://iceberg-catalog".to_string())
-        );
+        catalog
+            .drop_namespace(&NamespaceIdent::new("ns1".to_string()))
+            .await
+            .unwrap();
 
         config_mock.assert_async().await;
+        drop_ns_mock.assert_async().await;
     }
 
-    async fn create_config_mock(server: &mut ServerGuard) -> Mock {
-        server
-            .mock("GET", "/v1/config")
+    #[tokio::test]
+    async fn test_list_tables() {
+        let mut server = Server::new_async().await;
+
+        let config_mock = create_config_mock(&mut server).await;
+
+        let list_tables_mock = server
+            .mock("GET", "/v1/namespaces/ns1/tables")
             .with_status(200)
             .with_body(
                 r#"{
-                "overrides": {
-                    "warehouse": "s3://iceberg-catalog"
-                },
-                "defaults": {}
+                "identifiers": [
+                    {
+                        "namespace": ["ns1"],
+                        "name": "table1"
+                    },
+                    {
+                        "namespace": ["ns1"],
+                        "name": "table2"
+                    }
+                ]
             }"#,
             )
             .create_async()
-            .await
-    }
-
-    async fn create_oauth_mock(server: &mut ServerGuard) -> Mock {
-        create_oauth_mock_with_path(server, "/v1/oauth/tokens", "ey000000000000", 200).await
-    }
+            .await;
 
-    async fn create_oauth_mock_with_path(
-        server: &mut ServerGuard,
-        path: &str,
-        token: &str,
-        status: usize,
-    ) -> Mock {
-        let body = format!(
-            r#"{{
-                "access_token": "{token}",
-                "token_type": "Bearer",
-                "issued_token_type": "urn:ietf:params:oauth:token-type:access_token",
-                "expires_in": 86400
-            }}"#
+        let catalog = RestCatalog::new(
+            RestCatalogConfig::builder().uri(server.url()).build(),
+            Some(Arc::new(LocalFsStorageFactory)),
         );
-        server
-            .mock("POST", path)
-            .with_status(status)
-            .with_body(body)
-            .expect(1)
-            .create_async()
+
+        let tables = catalog
+            .list_tables(&NamespaceIdent::new("ns1".to_string()))
             .await
+            .unwrap();
+
+        let expected_tables = vec![
+            TableIdent::new(NamespaceIdent::new("ns1".to_string()), "table1".to_string()),
+            TableIdent::new(NamespaceIdent::new("ns1".to_string()), "table2".to_string()),
+        ];
+
+        assert_eq!(tables, expected_tables);
+
+        config_mock.assert_async().await;
+        list_tables_mock.assert_async().await;
     }
 
     #[tokio::test]
-    async fn test_oauth() {
+    async fn test_list_tables_with_pagination() {
         let mut server = Server::new_async().await;
-        let oauth_mock = create_oauth_mock(&mut server).await;
+
         let config_mock = create_config_mock(&mut server).await;
 
-        let mut props = HashMap::new();
-        props.insert("credential".to_string(), "client1:secret1".to_string());
+        let list_tables_mock_page1 = server
+            .mock("GET", "/v1/namespaces/ns1/tables")
+            .with_status(200)
+            .with_body(
+                r#"{
+                "identifiers": [
+                    {
+                        "namespace": ["ns1"],
+                        "name": "table1"
+                    },
+                    {
+                        "namespace": ["ns1"],
+                        "name": "table2"
+                    }
+                ],
+                "next-page-token": "token456"
+            }"#,
+            )
+            .create_async()
+            .await;
+
+        let list_tables_mock_page2 = server
+            .mock("GET", "/v1/namespaces/ns1/tables?pageToken=token456")
+            .with_status(200)
+            .with_body(
+                r#"{
+                "identifiers": [
+                    {
+                        "namespace": ["ns1"],
+                        "name": "table3"
+                    },
+                    {
+                        "namespace": ["ns1"],
+                        "name": "table4"
+                    }
+                ]
+            }"#,
+            )
+            .create_async()
+            .await;
 
         let catalog = RestCatalog::new(
-            RestCatalogConfig::builder()
-                .uri(server.url())
-                .props(props)
-                .build(),
+            RestCatalogConfig::builder().uri(server.url()).build(),
             Some(Arc::new(LocalFsStorageFactory)),
         );
 
-        let token = catalog.context().await.unwrap().client.token().await;
-        oauth_mock.assert_async().await;
+        let tables = catalog
+            .list_tables(&NamespaceIdent::new("ns1".to_string()))
+            .await
+            .unwrap();
+
+        let expected_tables = vec![
+            TableIdent::new(NamespaceIdent::new("ns1".to_string()), "table1".to_string()),
+            TableIdent::new(NamespaceIdent::new("ns1".to_string()), "table2".to_string()),
+            TableIdent::new(NamespaceIdent::new("ns1".to_string()), "table3".to_string()),
+            TableIdent::new(NamespaceIdent::new("ns1".to_string()), "table4".to_string()),
+        ];
+
+        assert_eq!(tables, expected_tables);
+
         config_mock.assert_async().await;
-        assert_eq!(token, Some("ey000000000000".to_string()));
+        list_tables_mock_page1.assert_async().await;
+        list_tables_mock_page2.assert_async().await;
     }
 
     #[tokio::test]
-    async fn test_oauth_with_optional_param() {
-        let mut props = HashMap::new();
-        props.insert("credential".to_string(), "client1:secret1".to_string());
-        props.insert("scope".to_string(), "custom_scope".to_string());
-        props.insert("audience".to_string(), "custom_audience".to_string());
-        props.insert("resource".to_string(), "custom_resource".to_string());
-
+    async fn test_list_tables_with_multiple_pages() {
         let mut server = Server::new_async().await;
-        let oauth_mock = server
-            .mock("POST", "/v1/oauth/tokens")
-            .match_body(mockito::Matcher::Regex("scope=custom_scope".to_string()))
-            .match_body(mockito::Matcher::Regex(
-                "audience=custom_audience".to_string(),
-            ))
-            .match_body(mockito::Matcher::Regex(
-                "resource=custom_resource".to_string(),
-            ))
+
+        let config_mock = create_config_mock(&mut server).await;
+
+        // Page 1
+        let list_tables_mock_page1 = server
+            .mock("GET", "/v1/namespaces/ns1/tables")
+            .with_status(200)
+            .with_body(
+                r#"{
+                "identifiers": [
+                    {
+                        "namespace": ["ns1"],
+                        "name": "table1"
+                    },
+                    {
+                        "namespace": ["ns1"],
+                        "name": "table2"
+                    }
+                ],
+                "next-page-token": "page2"
+            }"#,
+            )
+            .create_async()
+            .await;
+
+        // Page 2
+        let list_tables_mock_page2 = server
+            .mock("GET", "/v1/namespaces/ns1/tables?pageToken=page2")
+            .with_status(200)
+            .with_body(
+                r#"{
+                "identifiers": [
+                    {
+                        "namespace": ["ns1"],
+                        "name": "table3"
+                    },
+                    {
+                        "namespace": ["ns1"],
+                        "name": "table4"
+                    }
+                ],
+                "next-page-token": "page3"
+            }"#,
+            )
+            .create_async()
+            .await;
+
+        // Page 3
+        let list_tables_mock_page3 = server
+            .mock("GET", "/v1/namespaces/ns1/tables?pageToken=page3")
+            .with_status(200)
+            .with_body(
+                r#"{
+                "identifiers": [
+                    {
+                        "namespace": ["ns1"],
+                        "name": "table5"
+                    }
+                ],
+                "next-page-token": "page4"
+            }"#,
+            )
+            .create_async()
+            .await;
+
+        // Page 4
+        let list_tables_mock_page4 = server
+            .mock("GET", "/v1/namespaces/ns1/tables?pageToken=page4")
             .with_status(200)
             .with_body(
                 r#"{
-                "access_token": "ey000000000000",
-                "token_type": "Bearer",
-                "issued_token_type": "urn:ietf:params:oauth:token-type:access_token",
-                "expires_in": 86400
-                }"#,
+                "identifiers": [
+                    {
+                        "namespace": ["ns1"],
+                        "name": "table6"
+                    },
+                    {
+                        "namespace": ["ns1"],
+                        "name": "table7"
+                    }
+                ],
+                "next-page-token": "page5"
+            }"#,
             )
-            .expect(1)
             .create_async()
             .await;
 
-        let config_mock = create_config_mock(&mut server).await;
-
-        let catalog = RestCatalog::new(
-            RestCatalogConfig::builder()
-                .uri(server.url())
-                .props(props)
-                .build(),
-            Some(Arc::new(LocalFsStorageFactory)),
-        );
-
-        let token = catalog.context().await.unwrap().client.token().await;
-
-        oauth_mock.assert_async().await;
-        config_mock.assert_async().await;
-        assert_eq!(token, Some("ey000000000000".to_string()));
-    }
-
-    #[tokio::test]
-    async fn test_invalidate_token() {
-        let mut server = Server::new_async().await;
-        let oauth_mock = create_oauth_mock(&mut server).await;
-        let config_mock = create_config_mock(&mut server).await;
-
-        let mut props = HashMap::new();
-        props.insert("credential".to_string(), "client1:secret1".to_string());
+        // Page 5 (final page)
+        let list_tables_mock_page5 = server
+            .mock("GET", "/v1/namespaces/ns1/tables?pageToken=page5")
+            .with_status(200)
+            .with_body(
+                r#"{
+                "identifiers": [
+                    {
+                        "namespace": ["ns1"],
+                        "name": "table8"
+                    }
+                ]
+            }"#,
+            )
+            .create_async()
+            .await;
 
         let catalog = RestCatalog::new(
-            RestCatalogConfig::builder()
-                .uri(server.url())
-                .props(props)
-                .build(),
+            RestCatalogConfig::builder().uri(server.url()).build(),
             Some(Arc::new(LocalFsStorageFactory)),
         );
 
-        let token = catalog.context().await.unwrap().client.token().await;
-        oauth_mock.assert_async().await;
-        config_mock.assert_async().await;
-        assert_eq!(token, Some("ey000000000000".to_string()));
-
-        let oauth_mock =
-            create_oauth_mock_with_path(&mut server, "/v1/oauth/tokens", "ey000000000001", 200)
-                .await;
-        catalog.invalidate_token().await.unwrap();
-        let token = catalog.context().await.unwrap().client.token().await;
-        oauth_mock.assert_async().await;
-        assert_eq!(token, Some("ey000000000001".to_string()));
-    }
-
-    #[tokio::test]
-    async fn test_invalidate_token_failing_request() {
-        let mut server = Server::new_async().await;
-        let oauth_mock = create_oauth_mock(&mut server).await;
-        let config_mock = create_config_mock(&mut server).await;
+        let tables = catalog
+            .list_tables(&NamespaceIdent::new("ns1".to_string()))
+            .await
+            .unwrap();
 
-        let mut props = HashMap::new();
-        props.insert("credential".to_string(), "client1:secret1".to_string());
+        let expected_tables = vec![
+            TableIdent::new(NamespaceIdent::new("ns1".to_string()), "table1".to_string()),
+            TableIdent::new(NamespaceIdent::new("ns1".to_string()), "table2".to_string()),
+            TableIdent::new(NamespaceIdent::new("ns1".to_string()), "table3".to_string()),
+            TableIdent::new(NamespaceIdent::new("ns1".to_string()), "table4".to_string()),
+            TableIdent::new(NamespaceIdent::new("ns1".to_string()), "table5".to_string()),
+            TableIdent::new(NamespaceIdent::new("ns1".to_string()), "table6".to_string()),
+            TableIdent::new(NamespaceIdent::new("ns1".to_string()), "table7".to_string()),
+            TableIdent::new(NamespaceIdent::new("ns1".to_string()), "table8".to_string()),
+        ];
 
-        let catalog = RestCatalog::new(
-            RestCatalogConfig::builder()
-                .uri(server.url())
-                .props(props)
-                .build(),
-            Some(Arc::new(LocalFsStorageFactory)),
-        );
+        assert_eq!(tables, expected_tables);
 
-        let token = catalog.context().await.unwrap().client.token().await;
-        oauth_mock.assert_async().await;
+        // Verify all page requests were made
         config_mock.assert_async().await;
-        assert_eq!(token, Some("ey000000000000".to_string()));
-
-        let oauth_mock =
-            create_oauth_mock_with_path(&mut server, "/v1/oauth/tokens", "ey000000000001", 500)
-                .await;
-        catalog.invalidate_token().await.unwrap();
-        let token = catalog.context().await.unwrap().client.token().await;
-        oauth_mock.assert_async().await;
-        assert_eq!(token, None);
+        list_tables_mock_page1.assert_async().await;
+        list_tables_mock_page2.assert_async().await;
+        list_tables_mock_page3.assert_async().await;
+        list_tables_mock_page4.assert_async().await;
+        list_tables_mock_page5.assert_async().await;
     }
 
     #[tokio::test]
-    async fn test_regenerate_token() {
+    async fn test_drop_tables() {
         let mut server = Server::new_async().await;
-        let oauth_mock = create_oauth_mock(&mut server).await;
-        let config_mock = create_config_mock(&mut server).await;
-
-        let mut props = HashMap::new();
-        props.insert("credential".to_string(), "client1:secret1".to_string());
-
-        let catalog = RestCatalog::new(
-            RestCatalogConfig::builder()
-                .uri(server.url())
-                .props(props)
-                .build(),
-            Some(Arc::new(LocalFsStorageFactory)),
-        );
-
-        let token = catalog.context().await.unwrap().client.token().await;
-        oauth_mock.assert_async().await;
-        config_mock.assert_async().await;
-        assert_eq!(token, Some("ey000000000000".to_string()));
-
-        let oauth_mock =
-            create_oauth_mock_with_path(&mut server, "/v1/oauth/tokens", "ey000000000001", 200)
-                .await;
-        catalog.regenerate_token().await.unwrap();
-        oauth_mock.assert_async().await;
-        let token = catalog.context().await.unwrap().client.token().await;
-        assert_eq!(token, Some("ey000000000001".to_string()));
-    }
 
-    #[tokio::test]
-    async fn test_regenerate_token_failing_request() {
-        let mut server = Server::new_async().await;
-        let oauth_mock = create_oauth_mock(&mut server).await;
         let config_mock = create_config_mock(&mut server).await;
 
-        let mut props = HashMap::new();
-        props.insert("credential".to_string(), "client1:secret1".to_string());
+        let delete_table_mock = server
+            .mock("DELETE", "/v1/namespaces/ns1/tables/table1")
+            .with_status(204)
+            .create_async()
+            .await;
 
         let catalog = RestCatalog::new(
-            RestCatalogConfig::builder()
-                .uri(server.url())
-                .props(props)
-                .build(),
+            RestCatalogConfig::builder().uri(server.url()).build(),
             Some(Arc::new(LocalFsStorageFactory)),
         );
 
-        let token = catalog.context().await.unwrap().client.token().await;
-        oauth_mock.assert_async().await;
-        config_mock.assert_async().await;
-        assert_eq!(token, Some("ey000000000000".to_string()));
-
-        let oauth_mock =
-            create_oauth_mock_with_path(&mut server, "/v1/oauth/tokens", "ey000000000001", 500)
-                .await;
-        let invalidate_result = catalog.regenerate_token().await;
-        assert!(invalidate_result.is_err());
-        oauth_mock.assert_async().await;
-        let token = catalog.context().await.unwrap().client.token().await;
-
-        // original token is left intact
-        assert_eq!(token, Some("ey000000000000".to_string()));
-    }
-
-    #[tokio::test]
-    async fn test_http_headers() {
-        let server = Server::new_async().await;
-        let mut props = HashMap::new();
-        props.insert("credential".to_string(), "client1:secret1".to_string());
-
-        let config = RestCatalogConfig::builder()
-            .uri(server.url())
-            .props(props)
-            .build();
-        let headers: HeaderMap = config.extra_headers().unwrap();
-
-        let expected_headers = HeaderMap::from_iter([
-            (
-                header::CONTENT_TYPE,
-                HeaderValue::from_static("application/json"),
-            ),
-            (
-                HeaderName::from_static("x-client-version"),
-                HeaderValue::from_static(ICEBERG_REST_SPEC_VERSION),
-            ),
-            (
-                header::USER_AGENT,
-                HeaderValue::from_str(&format!("iceberg-rs/{CARGO_PKG_VERSION}")).unwrap(),
-            ),
-        ]);
-        assert_eq!(headers, expected_headers);
-    }
-
-    #[tokio::test]
-    async fn test_http_headers_with_custom_headers() {
-        let server = Server::new_async().await;
-        let mut props = HashMap::new();
-        props.insert("credential".to_string(), "client1:secret1".to_string());
-        props.insert(
-            "header.content-type".to_string(),
-            "application/yaml".to_string(),
-        );
-        props.insert(
-            "header.customized-header".to_string(),
-            "some/value".to_string(),
-        );
-
-        let config = RestCatalogConfig::builder()
-            .uri(server.url())
-            .props(props)
-            .build();
-        let headers: HeaderMap = config.extra_headers().unwrap();
-
-        let expected_headers = HeaderMap::from_iter([
-            (
-                header::CONTENT_TYPE,
-                HeaderValue::from_static("application/yaml"),
-            ),
-            (
-                HeaderName::from_static("x-client-version"),
-                HeaderValue::from_static(ICEBERG_REST_SPEC_VERSION),
-            ),
-            (
-                header::USER_AGENT,
-                HeaderValue::from_str(&format!("iceberg-rs/{CARGO_PKG_VERSION}")).unwrap(),
-            ),
-            (
-                HeaderName::from_static("customized-header"),
-                HeaderValue::from_static("some/value"),
-            ),
-        ]);
-        assert_eq!(headers, expected_headers);
+        catalog
+            .drop_table(&TableIdent::new(
+                NamespaceIdent::new("ns1".to_string()),
+                "table1".to_string(),
+            ))
+            .await
+            .unwrap();
+
+        config_mock.assert_async().await;
+        delete_table_mock.assert_async().await;
     }
 
     #[tokio::test]
-    async fn test_oauth_with_oauth2_server_uri() {
+    async fn test_purge_table() {
         let mut server = Server::new_async().await;
-        let config_mock = create_config_mock(&mut server).await;
 
-        let mut auth_server = Server::new_async().await;
-        let auth_server_path = "/some/path";
-        let oauth_mock =
-            create_oauth_mock_with_path(&mut auth_server, auth_server_path, "ey000000000000", 200)
-                .await;
+        let config_mock = create_config_mock(&mut server).await;
 
-        let mut props = HashMap::new();
-        props.insert("credential".to_string(), "client1:secret1".to_string());
-        props.insert(
-            "oauth2-server-uri".to_string(),
-            format!("{}{}", auth_server.url(), auth_server_path).to_string(),
-        );
+        let purge_table_mock = server
+            .mock("DELETE", "/v1/namespaces/ns1/tables/table1")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "purgeRequested".to_string(),
+                "true".to_string(),
+            ))
+            .with_status(204)
+            .create_async()
+            .await;
 
         let catalog = RestCatalog::new(
-            RestCatalogConfig::builder()
-                .uri(server.url())
-                .props(props)
-                .build(),
+            RestCatalogConfig::builder().uri(server.url()).build(),
             Some(Arc::new(LocalFsStorageFactory)),
         );
 
-        let token = catalog.context().await.unwrap().client.token().await;
+        catalog
+            .purge_table(&TableIdent::new(
+                NamespaceIdent::new("ns1".to_string()),
+                "table1".to_string(),
+            ))
+            .await
+            .unwrap();
 
-        oauth_mock.assert_async().await;
         config_mock.assert_async().await;
-        assert_eq!(token, Some("ey000000000000".to_string()));
+        purge_table_mock.assert_async().await;
     }
 
     #[tokio::test]
-    async fn test_config_override() {
+    async fn test_check_table_exists() {
         let mut server = Server::new_async().await;
-        let mut redirect_server = Server::new_async().await;
-        let new_uri = redirect_server.url();
 
-        let config_mock = server
-            .mock("GET", "/v1/config")
-            .with_status(200)
-            .with_body(
-                json!(
-                    {
-                        "overrides": {
-                            "uri": new_uri,
-                            "warehouse": "s3://iceberg-catalog",
-                            "prefix": "ice/warehouses/my"
-                        },
-                        "defaults": {},
-                    }
-                )
-                .to_string(),
-            )
-            .create_async()
-            .await;
+        let config_mock = create_config_mock(&mut server).await;
 
-        let list_ns_mock = redirect_server
-            .mock("GET", "/v1/ice/warehouses/my/namespaces")
-            .with_body(
-                r#"{
-                    "namespaces": []
-                }"#,
-            )
+        let check_table_exists_mock = server
+            .mock("HEAD", "/v1/namespaces/ns1/tables/table1")
+            .with_status(204)
             .create_async()
             .await;
 
@@ -1484,28 +3013,29 @@ mod tests {
             Some(Arc::new(LocalFsStorageFactory)),
         );
 
-        let _namespaces = catalog.list_namespaces(None).await.unwrap();
+        assert!(
+            catalog
+                .table_exists(&TableIdent::new(
+                    NamespaceIdent::new("ns1".to_string()),
+                    "table1".to_string(),
+                ))
+                .await
+                .unwrap()
+        );
 
         config_mock.assert_async().await;
-        list_ns_mock.assert_async().await;
+        check_table_exists_mock.assert_async().await;
     }
 
     #[tokio::test]
-    async fn test_list_namespace() {
+    async fn test_rename_table() {
         let mut server = Server::new_async().await;
 
         let config_mock = create_config_mock(&mut server).await;
 
-        let list_ns_mock = server
-            .mock("GET", "/v1/namespaces")
-            .with_body(
-                r#"{
-                "namespaces": [
-                    ["ns1", "ns11"],
-                    ["ns2"]
-                ]
-            }"#,
-            )
+        let rename_table_mock = server
+            .mock("POST", "/v1/tables/rename")
+            .with_status(204)
             .create_async()
             .await;
 
@@ -1514,48 +3044,118 @@ mod tests {
             Some(Arc::new(LocalFsStorageFactory)),
         );
 
-        let namespaces = catalog.list_namespaces(None).await.unwrap();
-
-        let expected_ns = vec![
-            NamespaceIdent::from_vec(vec!["ns1".to_string(), "ns11".to_string()]).unwrap(),
-            NamespaceIdent::from_vec(vec!["ns2".to_string()]).unwrap(),
-        ];
-
-        assert_eq!(expected_ns, namespaces);
+        catalog
+            .rename_table(
+                &TableIdent::new(NamespaceIdent::new("ns1".to_string()), "table1".to_string()),
+                &TableIdent::new(NamespaceIdent::new("ns1".to_string()), "table2".to_string()),
+            )
+            .await
+            .unwrap();
 
         config_mock.assert_async().await;
-        list_ns_mock.assert_async().await;
+        rename_table_mock.assert_async().await;
     }
 
     #[tokio::test]
-    async fn test_list_namespace_with_pagination() {
+    async fn test_plan_table_scan() {
         let mut server = Server::new_async().await;
 
-        let config_mock = create_config_mock(&mut server).await;
-
-        let list_ns_mock_page1 = server
-            .mock("GET", "/v1/namespaces")
+        let config_mock = server
+            .mock("GET", "/v1/config")
+            .with_status(200)
             .with_body(
                 r#"{
-                "namespaces": [
-                    ["ns1", "ns11"],
-                    ["ns2"]
-                ],
-                "next-page-token": "token123"
+                "overrides": {},
+                "defaults": {},
+                "endpoints": [
+                    "GET /v1/{prefix}/namespaces/{namespace}/tables/{table}",
+                    "POST /v1/{prefix}/namespaces/{namespace}/tables/{table}/plan",
+                    "GET /v1/{prefix}/namespaces/{namespace}/tables/{table}/plan/{plan-id}",
+                    "POST /v1/{prefix}/namespaces/{namespace}/tables/{table}/tasks"
+                ]
             }"#,
             )
             .create_async()
             .await;
 
-        let list_ns_mock_page2 = server
-            .mock("GET", "/v1/namespaces?pageToken=token123")
+        let load_table_mock = server
+            .mock("GET", "/v1/namespaces/ns1/tables/test1")
+            .with_status(200)
+            .with_body_from_file(format!(
+                "{}/testdata/{}",
+                env!("CARGO_MANIFEST_DIR"),
+                "load_table_response.json"
+            ))
+            .create_async()
+            .await;
+
+        let plan_mock = server
+            .mock("POST", "/v1/namespaces/ns1/tables/test1/plan")
+            .match_body(mockito::Matcher::Json(json!({
+                "snapshot-id": 3497810964824022504i64,
+                "select": ["id"],
+                "filter": {"type": "lt", "term": "id", "value": 10},
+                "case-sensitive": true
+            })))
+            .with_status(200)
+            .with_body(r#"{"status": "submitted", "plan-id": "p1"}"#)
+            .create_async()
+            .await;
+
+        let fetch_planning_result_mock = server
+            .mock("GET", "/v1/namespaces/ns1/tables/test1/plan/p1")
+            .with_status(200)
             .with_body(
-                r#"{
-                "namespaces": [
-                    ["ns3"],
-                    ["ns4", "ns41"]
-                ]
-            }"#,
+                json!({
+                    "status": "completed",
+                    "plan-tasks": ["t1"],
+                    "file-scan-tasks": [{
+                        "data-file": {
+                            "content": "data",
+                            "file-path": "s3://warehouse/database/table/data/1.parquet",
+                            "file-format": "parquet",
+                            "spec-id": 0,
+                            "partition": [],
+                            "file-size-in-bytes": 1024,
+                            "record-count": 10
+                        }
+                    }]
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let fetch_scan_tasks_mock = server
+            .mock("POST", "/v1/namespaces/ns1/tables/test1/tasks")
+            .match_body(mockito::Matcher::Json(json!({"plan-task": "t1"})))
+            .with_status(200)
+            .with_body(
+                json!({
+                    "delete-files": [{
+                        "content": "position-deletes",
+                        "file-path": "s3://warehouse/database/table/data/2-deletes.parquet",
+                        "file-format": "parquet",
+                        "spec-id": 0,
+                        "partition": [],
+                        "file-size-in-bytes": 128,
+                        "record-count": 2
+                    }],
+                    "file-scan-tasks": [{
+                        "data-file": {
+                            "content": "data",
+                            "file-path": "s3://warehouse/database/table/data/2.parquet",
+                            "file-format": "parquet",
+                            "spec-id": 0,
+                            "partition": [],
+                            "file-size-in-bytes": 2048,
+                            "record-count": 20,
+                            "data-sequence-number": 3
+                        },
+                        "delete-file-references": [0]
+                    }]
+                })
+                .to_string(),
             )
             .create_async()
             .await;
@@ -1565,150 +3165,190 @@ mod tests {
             Some(Arc::new(LocalFsStorageFactory)),
         );
 
-        let namespaces = catalog.list_namespaces(None).await.unwrap();
+        let table = catalog
+            .load_table(&TableIdent::from_strs(["ns1", "test1"]).unwrap())
+            .await
+            .unwrap();
+        assert!(table.scan_planner().is_some());
 
-        let expected_ns = vec![
-            NamespaceIdent::from_vec(vec!["ns1".to_string(), "ns11".to_string()]).unwrap(),
-            NamespaceIdent::from_vec(vec!["ns2".to_string()]).unwrap(),
-            NamespaceIdent::from_vec(vec!["ns3".to_string()]).unwrap(),
-            NamespaceIdent::from_vec(vec!["ns4".to_string(), "ns41".to_string()]).unwrap(),
-        ];
+        let tasks: Vec<_> = table
+            .scan()
+            .select(["id"])
+            .with_filter(Reference::new("id").less_than(Datum::int(10)))
+            .build()
+            .unwrap()
+            .plan_files()
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
 
-        assert_eq!(expected_ns, namespaces);
+        assert_eq!(
+            tasks
+                .iter()
+                .map(|task| (task.data_file_path(), task.record_count, task.deletes.len()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("s3://warehouse/database/table/data/1.parquet", Some(10), 0),
+                ("s3://warehouse/database/table/data/2.parquet", Some(20), 1),
+            ]
+        );
+        assert_eq!(
+            tasks[1].deletes[0].file_path,
+            "s3://warehouse/database/table/data/2-deletes.parquet"
+        );
+        assert!(tasks[0].predicate.is_some());
+        assert_eq!(tasks[0].data_sequence_number, None);
+        assert_eq!(tasks[1].data_sequence_number, Some(3));
 
         config_mock.assert_async().await;
-        list_ns_mock_page1.assert_async().await;
-        list_ns_mock_page2.assert_async().await;
+        load_table_mock.assert_async().await;
+        plan_mock.assert_async().await;
+        fetch_planning_result_mock.assert_async().await;
+        fetch_scan_tasks_mock.assert_async().await;
     }
 
-    #[tokio::test]
-    async fn test_list_namespace_with_multiple_pages() {
-        let mut server = Server::new_async().await;
-
-        let config_mock = create_config_mock(&mut server).await;
-
-        // Page 1
-        let list_ns_mock_page1 = server
-            .mock("GET", "/v1/namespaces")
+    /// Creates the mocks of a server that plans scans asynchronously but never completes the
+    /// plan `p1`, and whose cancel endpoint must be called once.
+    async fn create_unfinished_plan_mocks(server: &mut ServerGuard) -> (Mock, Mock) {
+        server
+            .mock("GET", "/v1/config")
+            .with_status(200)
             .with_body(
                 r#"{
-                "namespaces": [
-                    ["ns1", "ns11"],
-                    ["ns2"]
-                ],
-                "next-page-token": "page2"
+                "overrides": {},
+                "defaults": {},
+                "endpoints": [
+                    "GET /v1/{prefix}/namespaces/{namespace}/tables/{table}",
+                    "POST /v1/{prefix}/namespaces/{namespace}/tables/{table}/plan",
+                    "GET /v1/{prefix}/namespaces/{namespace}/tables/{table}/plan/{plan-id}",
+                    "DELETE /v1/{prefix}/namespaces/{namespace}/tables/{table}/plan/{plan-id}",
+                    "POST /v1/{prefix}/namespaces/{namespace}/tables/{table}/tasks"
+                ]
             }"#,
             )
             .create_async()
             .await;
-
-        // Page 2
-        let list_ns_mock_page2 = server
-            .mock("GET", "/v1/namespaces?pageToken=page2")
-            .with_body(
-                r#"{
-                "namespaces": [
-                    ["ns3"],
-                    ["ns4", "ns41"]
-                ],
-                "next-page-token": "page3"
-            }"#,
-            )
+        server
+            .mock("GET", "/v1/namespaces/ns1/tables/test1")
+            .with_status(200)
+            .with_body_from_file(format!(
+                "{}/testdata/{}",
+                env!("CARGO_MANIFEST_DIR"),
+                "load_table_response.json"
+            ))
             .create_async()
             .await;
-
-        // Page 3
-        let list_ns_mock_page3 = server
-            .mock("GET", "/v1/namespaces?pageToken=page3")
-            .with_body(
-                r#"{
-                "namespaces": [
-                    ["ns5", "ns51", "ns511"]
-                ],
-                "next-page-token": "page4"
-            }"#,
-            )
+        server
+            .mock("POST", "/v1/namespaces/ns1/tables/test1/plan")
+            .with_status(200)
+            .with_body(r#"{"status": "submitted", "plan-id": "p1"}"#)
             .create_async()
             .await;
 
-        // Page 4
-        let list_ns_mock_page4 = server
-            .mock("GET", "/v1/namespaces?pageToken=page4")
-            .with_body(
-                r#"{
-                "namespaces": [
-                    ["ns6"],
-                    ["ns7"]
-                ],
-                "next-page-token": "page5"
-            }"#,
-            )
+        let poll_mock = server
+            .mock("GET", "/v1/namespaces/ns1/tables/test1/plan/p1")
+            .with_status(200)
+            .with_body(r#"{"status": "submitted", "plan-id": "p1"}"#)
+            .expect_at_least(1)
             .create_async()
             .await;
-
-        // Page 5 (final page)
-        let list_ns_mock_page5 = server
-            .mock("GET", "/v1/namespaces?pageToken=page5")
-            .with_body(
-                r#"{
-                "namespaces": [
-                    ["ns8", "ns81"]
-                ]
-            }"#,
-            )
+        let cancel_mock = server
+            .mock("DELETE", "/v1/namespaces/ns1/tables/test1/plan/p1")
+            .with_status(204)
+            .expect(1)
             .create_async()
             .await;
 
+        (poll_mock, cancel_mock)
+    }
+
+    #[tokio::test]
+    async fn test_plan_table_scan_timeout() {
+        let mut server = Server::new_async().await;
+        let (poll_mock, cancel_mock) = create_unfinished_plan_mocks(&mut server).await;
+
         let catalog = RestCatalog::new(
-            RestCatalogConfig::builder().uri(server.url()).build(),
+            RestCatalogConfig::builder()
+                .uri(server.url())
+                .props(HashMap::from([(
+                    REST_CATALOG_PROP_SCAN_PLANNING_TIMEOUT_MS.to_string(),
+                    "300".to_string(),
+                )]))
+                .build(),
             Some(Arc::new(LocalFsStorageFactory)),
         );
+        let table = catalog
+            .load_table(&TableIdent::from_strs(["ns1", "test1"]).unwrap())
+            .await
+            .unwrap();
 
-        let namespaces = catalog.list_namespaces(None).await.unwrap();
+        let err = table
+            .scan()
+            .build()
+            .unwrap()
+            .plan_files()
+            .await
+            .err()
+            .unwrap();
+        assert!(
+            err.to_string()
+                .contains("Scan planning did not complete within 300ms"),
+            "unexpected error: {err}"
+        );
 
-        let expected_ns = vec![
-            NamespaceIdent::from_vec(vec!["ns1".to_string(), "ns11".to_string()]).unwrap(),
-            NamespaceIdent::from_vec(vec!["ns2".to_string()]).unwrap(),
-            NamespaceIdent::from_vec(vec!["ns3".to_string()]).unwrap(),
-            NamespaceIdent::from_vec(vec!["ns4".to_string(), "ns41".to_string()]).unwrap(),
-            NamespaceIdent::from_vec(vec![
-                "ns5".to_string(),
-                "ns51".to_string(),
-                "ns511".to_string(),
-            ])
-            .unwrap(),
-            NamespaceIdent::from_vec(vec!["ns6".to_string()]).unwrap(),
-            NamespaceIdent::from_vec(vec!["ns7".to_string()]).unwrap(),
-            NamespaceIdent::from_vec(vec!["ns8".to_string(), "ns81".to_string()]).unwrap(),
-        ];
+        poll_mock.assert_async().await;
+        cancel_mock.assert_async().await;
+    }
 
-        assert_eq!(expected_ns, namespaces);
+    #[tokio::test]
+    async fn test_cancel_dropped_plan_table_scan() {
+        let mut server = Server::new_async().await;
+        let (poll_mock, cancel_mock) = create_unfinished_plan_mocks(&mut server).await;
 
-        // Verify all page requests were made
-        config_mock.assert_async().await;
-        list_ns_mock_page1.assert_async().await;
-        list_ns_mock_page2.assert_async().await;
-        list_ns_mock_page3.assert_async().await;
-        list_ns_mock_page4.assert_async().await;
-        list_ns_mock_page5.assert_async().await;
+        let catalog = RestCatalog::new(
+            RestCatalogConfig::builder().uri(server.url()).build(),
+            Some(Arc::new(LocalFsStorageFactory)),
+        );
+        let table = catalog
+            .load_table(&TableIdent::from_strs(["ns1", "test1"]).unwrap())
+            .await
+            .unwrap();
+
+        let table_scan = table.scan().build().unwrap();
+        // Planning is abandoned while the plan is still submitted.
+        assert!(
+            tokio::time::timeout(Duration::from_millis(300), table_scan.plan_files())
+                .await
+                .is_err()
+        );
+
+        // The plan is cancelled in the background.
+        for _ in 0..50 {
+            if cancel_mock.matched_async().await {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        poll_mock.assert_async().await;
+        cancel_mock.assert_async().await;
     }
 
     #[tokio::test]
-    async fn test_create_namespace() {
+    async fn test_load_table_without_plan_endpoint() {
         let mut server = Server::new_async().await;
 
         let config_mock = create_config_mock(&mut server).await;
 
-        let create_ns_mock = server
-            .mock("POST", "/v1/namespaces")
-            .with_body(
-                r#"{
-                "namespace": [ "ns1", "ns11"],
-                "properties" : {
-                    "key1": "value1"
-                }
-            }"#,
-            )
+        let load_table_mock = server
+            .mock("GET", "/v1/namespaces/ns1/tables/test1")
+            .with_status(200)
+            .with_body_from_file(format!(
+                "{}/testdata/{}",
+                env!("CARGO_MANIFEST_DIR"),
+                "load_table_response.json"
+            ))
             .create_async()
             .await;
 
@@ -1717,73 +3357,254 @@ mod tests {
             Some(Arc::new(LocalFsStorageFactory)),
         );
 
-        let namespaces = catalog
-            .create_namespace(
-                &NamespaceIdent::from_vec(vec!["ns1".to_string(), "ns11".to_string()]).unwrap(),
-                HashMap::from([("key1".to_string(), "value1".to_string())]),
-            )
+        let table = catalog
+            .load_table(&TableIdent::from_strs(["ns1", "test1"]).unwrap())
             .await
             .unwrap();
+        assert!(table.scan_planner().is_none());
 
-        let expected_ns = Namespace::with_properties(
-            NamespaceIdent::from_vec(vec!["ns1".to_string(), "ns11".to_string()]).unwrap(),
-            HashMap::from([("key1".to_string(), "value1".to_string())]),
-        );
+        config_mock.assert_async().await;
+        load_table_mock.assert_async().await;
+    }
 
-        assert_eq!(expected_ns, namespaces);
+    #[cfg(feature = "sigv4")]
+    fn test_signer() -> SigV4Signer {
+        SigV4Signer::new("us-east-1".to_string(), "glue".to_string(), None)
+    }
 
-        config_mock.assert_async().await;
-        create_ns_mock.assert_async().await;
+    /// Checks that a request sent to the mock server is signed with the test credentials for
+    /// the `glue` service in `us-east-1`, by signing the received request again.
+    #[cfg(feature = "sigv4")]
+    fn is_signed(request: &mockito::Request) -> bool {
+        let header = |name: &str| request.header(name).first()?.to_str().ok();
+        let (Some(authorization), Some(amz_date), Some(host), Ok(body)) = (
+            header("authorization"),
+            header("x-amz-date"),
+            header("host"),
+            request.body(),
+        ) else {
+            return false;
+        };
+        let Some((_, signed_headers)) = authorization.split_once("SignedHeaders=") else {
+            return false;
+        };
+        let Some((signed_headers, _)) = signed_headers.split_once(", ") else {
+            return false;
+        };
+        let signed_headers = signed_headers.split(';').collect::<Vec<_>>();
+        if !signed_headers.contains(&"host") || !signed_headers.contains(&"x-amz-date") {
+            return false;
+        }
+
+        // The signer derives the host from the url and adds the date itself.
+        let Some(headers) = signed_headers
+            .into_iter()
+            .filter(|name| !matches!(*name, "host" | "x-amz-date"))
+            .map(|name| Some((name, header(name)?)))
+            .collect::<Option<Vec<_>>>()
+        else {
+            return false;
+        };
+        test_authorization(
+            &test_signer(),
+            request.method(),
+            &format!("http://{host}{}", request.path_and_query()),
+            &headers,
+            body,
+            amz_date,
+        )
+        .is_some_and(|expected| expected == authorization)
     }
 
+    #[cfg(feature = "sigv4")]
     #[tokio::test]
-    async fn test_get_namespace() {
+    async fn test_sigv4_signed_requests() {
         let mut server = Server::new_async().await;
+        let url = server.url();
 
-        let config_mock = create_config_mock(&mut server).await;
+        let config_mock = server
+            .mock("GET", "/v1/config")
+            .match_request(is_signed)
+            .with_status(200)
+            .with_body(r#"{"overrides": {}, "defaults": {}}"#)
+            .create_async()
+            .await;
 
-        let get_ns_mock = server
-            .mock("GET", "/v1/namespaces/ns1")
-            .with_body(
-                r#"{
-                "namespace": [ "ns1"],
-                "properties" : {
-                    "key1": "value1"
-                }
-            }"#,
-            )
+        let create_namespace_mock = server
+            .mock("POST", "/v1/namespaces")
+            .match_header("original-authorization", "Bearer oauth-token")
+            .match_request(is_signed)
+            .with_status(200)
+            .with_body(r#"{"namespace": ["ns1"], "properties": {}}"#)
             .create_async()
             .await;
 
         let catalog = RestCatalog::new(
-            RestCatalogConfig::builder().uri(server.url()).build(),
-            Some(Arc::new(LocalFsStorageFactory)),
+            RestCatalogConfig::builder()
+                .uri(url)
+                .props(HashMap::from([
+                    (
+                        REST_CATALOG_PROP_SIGV4_ENABLED.to_string(),
+                        "true".to_string(),
+                    ),
+                    (
+                        REST_CATALOG_PROP_SIGNING_REGION.to_string(),
+                        "us-east-1".to_string(),
+                    ),
+                    (
+                        REST_CATALOG_PROP_SIGNING_NAME.to_string(),
+                        "glue".to_string(),
+                    ),
+                    (
+                        REST_CATALOG_PROP_ACCESS_KEY_ID.to_string(),
+                        TEST_ACCESS_KEY_ID.to_string(),
+                    ),
+                    (
+                        REST_CATALOG_PROP_SECRET_ACCESS_KEY.to_string(),
+                        TEST_SECRET_ACCESS_KEY.to_string(),
+                    ),
+                    ("token".to_string(), "oauth-token".to_string()),
+                ]))
+                .build(),
+            None,
         );
 
-        let namespaces = catalog
-            .get_namespace(&NamespaceIdent::new("ns1".to_string()))
+        catalog
+            .create_namespace(&NamespaceIdent::new("ns1".to_string()), HashMap::new())
             .await
             .unwrap();
 
-        let expected_ns = Namespace::with_properties(
-            NamespaceIdent::new("ns1".to_string()),
-            HashMap::from([("key1".to_string(), "value1".to_string())]),
+        config_mock.assert_async().await;
+        create_namespace_mock.assert_async().await;
+    }
+
+    #[cfg(feature = "sigv4")]
+    #[tokio::test]
+    async fn test_sigv4_tampered_requests_are_rejected() {
+        let mut server = Server::new_async().await;
+        let url = format!("{}/v1/namespaces", server.url());
+        let signer = SigV4Signer::new(
+            "us-east-1".to_string(),
+            "glue".to_string(),
+            Some(aws_credential_types::Credentials::new(
+                TEST_ACCESS_KEY_ID,
+                TEST_SECRET_ACCESS_KEY,
+                None,
+                None,
+                "test",
+            )),
         );
+        let signed_request = || async {
+            let mut request = Client::new()
+                .post(&url)
+                .header("x-client-version", "0.14.1")
+                .body(r#"{"namespace": ["ns1"]}"#)
+                .build()
+                .unwrap();
+            signer.sign(&mut request).await.unwrap();
+            request
+        };
 
-        assert_eq!(expected_ns, namespaces);
+        let mock = server
+            .mock("POST", "/v1/namespaces")
+            .match_request(is_signed)
+            .with_status(200)
+            .expect(1)
+            .create_async()
+            .await;
 
-        config_mock.assert_async().await;
-        get_ns_mock.assert_async().await;
+        let request = signed_request().await;
+        let response = Client::new().execute(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let mut request = signed_request().await;
+        *request.body_mut() = Some(r#"{"namespace": ["ns2"]}"#.into());
+        let response = Client::new().execute(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_IMPLEMENTED);
+
+        let mut request = signed_request().await;
+        request
+            .headers_mut()
+            .insert("x-client-version", HeaderValue::from_static("0.14.2"));
+        let response = Client::new().execute(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_IMPLEMENTED);
+
+        mock.assert_async().await;
+    }
+
+    #[cfg(not(feature = "sigv4"))]
+    #[test]
+    fn test_sigv4_requires_feature() {
+        let config = RestCatalogConfig::builder()
+            .uri("http://localhost:8181".to_string())
+            .props(HashMap::from([(
+                REST_CATALOG_PROP_SIGV4_ENABLED.to_string(),
+                "true".to_string(),
+            )]))
+            .build();
+
+        let err = HttpClient::new(&config).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::FeatureUnsupported);
+    }
+
+    #[cfg(feature = "sigv4")]
+    #[test]
+    fn test_sigv4_signer_requires_both_keys() {
+        let config = RestCatalogConfig::builder()
+            .uri("http://localhost:8181".to_string())
+            .props(HashMap::from([
+                (
+                    REST_CATALOG_PROP_SIGV4_ENABLED.to_string(),
+                    "true".to_string(),
+                ),
+                (
+                    REST_CATALOG_PROP_SIGNING_REGION.to_string(),
+                    "us-east-1".to_string(),
+                ),
+                (
+                    REST_CATALOG_PROP_ACCESS_KEY_ID.to_string(),
+                    "key".to_string(),
+                ),
+            ]))
+            .build();
+        assert!(config.sigv4_signer().is_err());
+
+        let config = RestCatalogConfig::builder()
+            .uri("http://localhost:8181".to_string())
+            .build();
+        assert!(config.sigv4_signer().unwrap().is_none());
     }
 
     #[tokio::test]
-    async fn check_namespace_exists() {
+    async fn test_report_metrics() {
         let mut server = Server::new_async().await;
 
         let config_mock = create_config_mock(&mut server).await;
 
-        let get_ns_mock = server
-            .mock("HEAD", "/v1/namespaces/ns1")
+        let load_table_mock = server
+            .mock("GET", "/v1/namespaces/ns1/tables/test1")
+            .with_status(200)
+            .with_body_from_file(format!(
+                "{}/testdata/{}",
+                env!("CARGO_MANIFEST_DIR"),
+                "load_table_response.json"
+            ))
+            .create_async()
+            .await;
+
+        let metrics_mock = server
+            .mock("POST", "/v1/namespaces/ns1/tables/test1/metrics")
+            .match_body(mockito::Matcher::PartialJson(json!({
+                "report-type": "commit-report",
+                "table-name": "ns1.test1",
+                "snapshot-id": 2,
+                "sequence-number": 3,
+                "operation": "append",
+                "metrics": {
+                    "attempts": {"unit": "count", "value": 1},
+                    "added-records": {"unit": "count", "value": 10}
+                }
+            })))
             .with_status(204)
             .create_async()
             .await;
@@ -1793,26 +3614,90 @@ mod tests {
             Some(Arc::new(LocalFsStorageFactory)),
         );
 
-        assert!(
-            catalog
-                .namespace_exists(&NamespaceIdent::new("ns1".to_string()))
-                .await
-                .unwrap()
+        let table_ident = TableIdent::from_strs(["ns1", "test1"]).unwrap();
+        let table = catalog.load_table(&table_ident).await.unwrap();
+        table
+            .metrics_reporter()
+            .expect("Tables of servers accepting metrics report them")
+            .report(MetricsReport::Commit(CommitReport {
+                table: table_ident,
+                snapshot_id: 2,
+                sequence_number: 3,
+                operation: Operation::Append,
+                metrics: CommitMetricsResult {
+                    attempts: 1,
+                    added_records: Some(10),
+                    ..Default::default()
+                },
+            }))
+            .await;
+
+        config_mock.assert_async().await;
+        load_table_mock.assert_async().await;
+        metrics_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_load_table_with_metrics_reporting_disabled() {
+        let mut server = Server::new_async().await;
+
+        let config_mock = create_config_mock(&mut server).await;
+
+        let load_table_mock = server
+            .mock("GET", "/v1/namespaces/ns1/tables/test1")
+            .with_status(200)
+            .with_body_from_file(format!(
+                "{}/testdata/{}",
+                env!("CARGO_MANIFEST_DIR"),
+                "load_table_response.json"
+            ))
+            .create_async()
+            .await;
+
+        let catalog = RestCatalog::new(
+            RestCatalogConfig::builder()
+                .uri(server.url())
+                .props(HashMap::from([(
+                    REST_CATALOG_PROP_METRICS_REPORTING_ENABLED.to_string(),
+                    "false".to_string(),
+                )]))
+                .build(),
+            Some(Arc::new(LocalFsStorageFactory)),
         );
 
+        let table = catalog
+            .load_table(&TableIdent::from_strs(["ns1", "test1"]).unwrap())
+            .await
+            .unwrap();
+        assert!(table.metrics_reporter().is_none());
+
         config_mock.assert_async().await;
-        get_ns_mock.assert_async().await;
+        load_table_mock.assert_async().await;
     }
 
     #[tokio::test]
-    async fn test_drop_namespace() {
+    async fn test_load_table_not_modified() {
         let mut server = Server::new_async().await;
 
         let config_mock = create_config_mock(&mut server).await;
 
-        let drop_ns_mock = server
-            .mock("DELETE", "/v1/namespaces/ns1")
-            .with_status(204)
+        let load_table_mock = server
+            .mock("GET", "/v1/namespaces/ns1/tables/test1")
+            .match_header("if-none-match", mockito::Matcher::Missing)
+            .with_status(200)
+            .with_header("etag", "\"v1\"")
+            .with_body_from_file(format!(
+                "{}/testdata/{}",
+                env!("CARGO_MANIFEST_DIR"),
+                "load_table_response.json"
+            ))
+            .create_async()
+            .await;
+
+        let not_modified_mock = server
+            .mock("GET", "/v1/namespaces/ns1/tables/test1")
+            .match_header("if-none-match", "\"v1\"")
+            .with_status(304)
             .create_async()
             .await;
 
@@ -1821,237 +3706,125 @@ mod tests {
             Some(Arc::new(LocalFsStorageFactory)),
         );
 
-        catalog
-            .drop_namespace(&NamespaceIdent::new("ns1".to_string()))
-            .await
-            .unwrap();
+        let table_ident = TableIdent::from_strs(["ns1", "test1"]).unwrap();
+        let table = catalog.load_table(&table_ident).await.unwrap();
+        let cached_table = catalog.load_table(&table_ident).await.unwrap();
+
+        assert_eq!(table.metadata_location(), cached_table.metadata_location());
+        assert_eq!(table.metadata(), cached_table.metadata());
 
         config_mock.assert_async().await;
-        drop_ns_mock.assert_async().await;
+        load_table_mock.assert_async().await;
+        not_modified_mock.assert_async().await;
     }
 
     #[tokio::test]
-    async fn test_list_tables() {
+    async fn test_load_table_with_referenced_snapshots() {
         let mut server = Server::new_async().await;
 
         let config_mock = create_config_mock(&mut server).await;
 
-        let list_tables_mock = server
-            .mock("GET", "/v1/namespaces/ns1/tables")
+        let load_table_mock = server
+            .mock("GET", "/v1/namespaces/ns1/tables/test1")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "snapshots".to_string(),
+                "refs".to_string(),
+            ))
             .with_status(200)
-            .with_body(
-                r#"{
-                "identifiers": [
-                    {
-                        "namespace": ["ns1"],
-                        "name": "table1"
-                    },
-                    {
-                        "namespace": ["ns1"],
-                        "name": "table2"
-                    }
-                ]
-            }"#,
-            )
+            .with_body_from_file(format!(
+                "{}/testdata/{}",
+                env!("CARGO_MANIFEST_DIR"),
+                "load_table_response.json"
+            ))
             .create_async()
             .await;
 
         let catalog = RestCatalog::new(
-            RestCatalogConfig::builder().uri(server.url()).build(),
+            RestCatalogConfig::builder()
+                .uri(server.url())
+                .props(HashMap::from([(
+                    REST_CATALOG_PROP_SNAPSHOT_LOADING_MODE.to_string(),
+                    "refs".to_string(),
+                )]))
+                .build(),
             Some(Arc::new(LocalFsStorageFactory)),
         );
 
-        let tables = catalog
-            .list_tables(&NamespaceIdent::new("ns1".to_string()))
+        catalog
+            .load_table(&TableIdent::from_strs(["ns1", "test1"]).unwrap())
             .await
             .unwrap();
 
-        let expected_tables = vec![
-            TableIdent::new(NamespaceIdent::new("ns1".to_string()), "table1".to_string()),
-            TableIdent::new(NamespaceIdent::new("ns1".to_string()), "table2".to_string()),
-        ];
-
-        assert_eq!(tables, expected_tables);
-
         config_mock.assert_async().await;
-        list_tables_mock.assert_async().await;
+        load_table_mock.assert_async().await;
     }
 
     #[tokio::test]
-    async fn test_list_tables_with_pagination() {
+    async fn test_load_table_for_commit_loads_all_snapshots() {
         let mut server = Server::new_async().await;
 
         let config_mock = create_config_mock(&mut server).await;
 
-        let list_tables_mock_page1 = server
-            .mock("GET", "/v1/namespaces/ns1/tables")
-            .with_status(200)
-            .with_body(
-                r#"{
-                "identifiers": [
-                    {
-                        "namespace": ["ns1"],
-                        "name": "table1"
-                    },
-                    {
-                        "namespace": ["ns1"],
-                        "name": "table2"
-                    }
-                ],
-                "next-page-token": "token456"
-            }"#,
-            )
-            .create_async()
-            .await;
-
-        let list_tables_mock_page2 = server
-            .mock("GET", "/v1/namespaces/ns1/tables?pageToken=token456")
+        let load_table_mock = server
+            .mock("GET", "/v1/namespaces/ns1/tables/test1")
+            .match_query(mockito::Matcher::Missing)
             .with_status(200)
-            .with_body(
-                r#"{
-                "identifiers": [
-                    {
-                        "namespace": ["ns1"],
-                        "name": "table3"
-                    },
-                    {
-                        "namespace": ["ns1"],
-                        "name": "table4"
-                    }
-                ]
-            }"#,
-            )
+            .with_body_from_file(format!(
+                "{}/testdata/{}",
+                env!("CARGO_MANIFEST_DIR"),
+                "load_table_response.json"
+            ))
             .create_async()
             .await;
 
         let catalog = RestCatalog::new(
-            RestCatalogConfig::builder().uri(server.url()).build(),
+            RestCatalogConfig::builder()
+                .uri(server.url())
+                .props(HashMap::from([(
+                    REST_CATALOG_PROP_SNAPSHOT_LOADING_MODE.to_string(),
+                    "refs".to_string(),
+                )]))
+                .build(),
             Some(Arc::new(LocalFsStorageFactory)),
         );
 
-        let tables = catalog
-            .list_tables(&NamespaceIdent::new("ns1".to_string()))
+        catalog
+            .load_table_for_commit(&TableIdent::from_strs(["ns1", "test1"]).unwrap())
             .await
             .unwrap();
 
-        let expected_tables = vec![
-            TableIdent::new(NamespaceIdent::new("ns1".to_string()), "table1".to_string()),
-            TableIdent::new(NamespaceIdent::new("ns1".to_string()), "table2".to_string()),
-            TableIdent::new(NamespaceIdent::new("ns1".to_string()), "table3".to_string()),
-            TableIdent::new(NamespaceIdent::new("ns1".to_string()), "table4".to_string()),
-        ];
-
-        assert_eq!(tables, expected_tables);
-
         config_mock.assert_async().await;
-        list_tables_mock_page1.assert_async().await;
-        list_tables_mock_page2.assert_async().await;
+        load_table_mock.assert_async().await;
     }
 
     #[tokio::test]
-    async fn test_list_tables_with_multiple_pages() {
+    async fn test_load_table_with_expiring_credentials_is_not_reused() {
         let mut server = Server::new_async().await;
 
         let config_mock = create_config_mock(&mut server).await;
 
-        // Page 1
-        let list_tables_mock_page1 = server
-            .mock("GET", "/v1/namespaces/ns1/tables")
-            .with_status(200)
-            .with_body(
-                r#"{
-                "identifiers": [
-                    {
-                        "namespace": ["ns1"],
-                        "name": "table1"
-                    },
-                    {
-                        "namespace": ["ns1"],
-                        "name": "table2"
-                    }
-                ],
-                "next-page-token": "page2"
-            }"#,
-            )
-            .create_async()
-            .await;
-
-        // Page 2
-        let list_tables_mock_page2 = server
-            .mock("GET", "/v1/namespaces/ns1/tables?pageToken=page2")
-            .with_status(200)
-            .with_body(
-                r#"{
-                "identifiers": [
-                    {
-                        "namespace": ["ns1"],
-                        "name": "table3"
-                    },
-                    {
-                        "namespace": ["ns1"],
-                        "name": "table4"
-                    }
-                ],
-                "next-page-token": "page3"
-            }"#,
-            )
-            .create_async()
-            .await;
-
-        // Page 3
-        let list_tables_mock_page3 = server
-            .mock("GET", "/v1/namespaces/ns1/tables?pageToken=page3")
-            .with_status(200)
-            .with_body(
-                r#"{
-                "identifiers": [
-                    {
-                        "namespace": ["ns1"],
-                        "name": "table5"
-                    }
-                ],
-                "next-page-token": "page4"
-            }"#,
-            )
-            .create_async()
-            .await;
-
-        // Page 4
-        let list_tables_mock_page4 = server
-            .mock("GET", "/v1/namespaces/ns1/tables?pageToken=page4")
-            .with_status(200)
-            .with_body(
-                r#"{
-                "identifiers": [
-                    {
-                        "namespace": ["ns1"],
-                        "name": "table6"
-                    },
-                    {
-                        "namespace": ["ns1"],
-                        "name": "table7"
-                    }
-                ],
-                "next-page-token": "page5"
-            }"#,
-            )
-            .create_async()
-            .await;
+        let expires_soon = Utc::now().timestamp_millis() + 1000;
+        let mut load_table_response: serde_json::Value = serde_json::from_reader(
+            File::open(format!(
+                "{}/testdata/{}",
+                env!("CARGO_MANIFEST_DIR"),
+                "load_table_response.json"
+            ))
+            .unwrap(),
+        )
+        .unwrap();
+        load_table_response["config"] = serde_json::json!({
+            "s3.session-token-expires-at-ms": expires_soon.to_string()
+        });
 
-        // Page 5 (final page)
-        let list_tables_mock_page5 = server
-            .mock("GET", "/v1/namespaces/ns1/tables?pageToken=page5")
+        // The table is reloaded without `If-None-Match`, to get fresh credentials
+        let load_table_mock = server
+            .mock("GET", "/v1/namespaces/ns1/tables/test1")
+            .match_header("if-none-match", mockito::Matcher::Missing)
             .with_status(200)
-            .with_body(
-                r#"{
-                "identifiers": [
-                    {
-                        "namespace": ["ns1"],
-                        "name": "table8"
-                    }
-                ]
-            }"#,
-            )
+            .with_header("etag", "\"v1\"")
+            .with_body(load_table_response.to_string())
+            .expect(2)
             .create_async()
             .await;
 
@@ -2060,42 +3833,68 @@ mod tests {
             Some(Arc::new(LocalFsStorageFactory)),
         );
 
-        let tables = catalog
-            .list_tables(&NamespaceIdent::new("ns1".to_string()))
-            .await
-            .unwrap();
-
-        let expected_tables = vec![
-            TableIdent::new(NamespaceIdent::new("ns1".to_string()), "table1".to_string()),
-            TableIdent::new(NamespaceIdent::new("ns1".to_string()), "table2".to_string()),
-            TableIdent::new(NamespaceIdent::new("ns1".to_string()), "table3".to_string()),
-            TableIdent::new(NamespaceIdent::new("ns1".to_string()), "table4".to_string()),
-            TableIdent::new(NamespaceIdent::new("ns1".to_string()), "table5".to_string()),
-            TableIdent::new(NamespaceIdent::new("ns1".to_string()), "table6".to_string()),
-            TableIdent::new(NamespaceIdent::new("ns1".to_string()), "table7".to_string()),
-            TableIdent::new(NamespaceIdent::new("ns1".to_string()), "table8".to_string()),
-        ];
-
-        assert_eq!(tables, expected_tables);
+        let table_ident = TableIdent::from_strs(["ns1", "test1"]).unwrap();
+        catalog.load_table(&table_ident).await.unwrap();
+        catalog.load_table(&table_ident).await.unwrap();
 
-        // Verify all page requests were made
         config_mock.assert_async().await;
-        list_tables_mock_page1.assert_async().await;
-        list_tables_mock_page2.assert_async().await;
-        list_tables_mock_page3.assert_async().await;
-        list_tables_mock_page4.assert_async().await;
-        list_tables_mock_page5.assert_async().await;
+        load_table_mock.assert_async().await;
     }
 
-    #[tokio::test]
-    async fn test_drop_tables() {
-        let mut server = Server::new_async().await;
+    #[tokio::test]
+    async fn test_load_table_with_vended_credentials() {
+        let mut server = Server::new_async().await;
+
+        let config_mock = server
+            .mock("GET", "/v1/config")
+            .with_status(200)
+            .with_body(
+                r#"{
+                "overrides": {},
+                "defaults": {},
+                "endpoints": [
+                    "GET /v1/{prefix}/namespaces/{namespace}/tables/{table}",
+                    "GET /v1/{prefix}/namespaces/{namespace}/tables/{table}/credentials"
+                ]
+            }"#,
+            )
+            .create_async()
+            .await;
+
+        let prefix = "file:///tmp/iceberg-rest-vended-credentials/";
+        let expires_soon = Utc::now().timestamp_millis() + 1000;
+        let mut load_table_response: serde_json::Value = serde_json::from_reader(
+            File::open(format!(
+                "{}/testdata/{}",
+                env!("CARGO_MANIFEST_DIR"),
+                "load_table_response.json"
+            ))
+            .unwrap(),
+        )
+        .unwrap();
+        load_table_response["storage-credentials"] = json!([
+            {"prefix": "file:///tmp/", "config": {"token": "warehouse"}},
+            {"prefix": prefix, "config": {
+                "token": "table",
+                "s3.session-token-expires-at-ms": expires_soon.to_string()
+            }}
+        ]);
 
-        let config_mock = create_config_mock(&mut server).await;
+        let load_table_mock = server
+            .mock("GET", "/v1/namespaces/ns1/tables/test1")
+            .with_status(200)
+            .with_body(load_table_response.to_string())
+            .create_async()
+            .await;
 
-        let delete_table_mock = server
-            .mock("DELETE", "/v1/namespaces/ns1/tables/table1")
-            .with_status(204)
+        let credentials_mock = server
+            .mock("GET", "/v1/namespaces/ns1/tables/test1/credentials")
+            .with_status(200)
+            .with_body(
+                json!({"storage-credentials": [{"prefix": prefix, "config": {"token": "refreshed"}}]})
+                    .to_string(),
+            )
+            .expect(1)
             .create_async()
             .await;
 
@@ -2104,27 +3903,40 @@ mod tests {
             Some(Arc::new(LocalFsStorageFactory)),
         );
 
-        catalog
-            .drop_table(&TableIdent::new(
-                NamespaceIdent::new("ns1".to_string()),
-                "table1".to_string(),
-            ))
+        let table = catalog
+            .load_table(&TableIdent::from_strs(["ns1", "test1"]).unwrap())
             .await
             .unwrap();
 
+        let credentials = table.file_io().storage_credentials();
+        assert_eq!(credentials.len(), 2);
+        assert_eq!(credentials[1].prefix, prefix);
+        assert_eq!(credentials[1].expires_at_ms(), Some(expires_soon));
+
+        // The expiring credential of the table is refreshed before it's used, once.
+        let file_io = table.file_io();
+        assert!(!file_io.exists(format!("{prefix}1.parquet")).await.unwrap());
+        assert!(!file_io.exists(format!("{prefix}2.parquet")).await.unwrap());
+
         config_mock.assert_async().await;
-        delete_table_mock.assert_async().await;
+        load_table_mock.assert_async().await;
+        credentials_mock.assert_async().await;
     }
 
     #[tokio::test]
-    async fn test_check_table_exists() {
+    async fn test_load_table() {
         let mut server = Server::new_async().await;
 
         let config_mock = create_config_mock(&mut server).await;
 
-        let check_table_exists_mock = server
-            .mock("HEAD", "/v1/namespaces/ns1/tables/table1")
-            .with_status(204)
+        let rename_table_mock = server
+            .mock("GET", "/v1/namespaces/ns1/tables/test1")
+            .with_status(200)
+            .with_body_from_file(format!(
+                "{}/testdata/{}",
+                env!("CARGO_MANIFEST_DIR"),
+                "load_table_response.json"
+            ))
             .create_async()
             .await;
 
@@ -2133,29 +3945,115 @@ mod tests {
             Some(Arc::new(LocalFsStorageFactory)),
         );
 
-        assert!(
-            catalog
-                .table_exists(&TableIdent::new(
-                    NamespaceIdent::new("ns1".to_string()),
-                    "table1".to_string(),
-                ))
-                .await
-                .unwrap()
+        let table = catalog
+            .load_table(&TableIdent::new(
+                NamespaceIdent::new("ns1".to_string()),
+                "test1".to_string(),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            &TableIdent::from_strs(vec!["ns1", "test1"]).unwrap(),
+            table.identifier()
+        );
+        assert_eq!(
+            "s3://warehouse/database/table/metadata/00001-5f2f8166-244c-4eae-ac36-384ecdec81fc.gz.metadata.json",
+            table.metadata_location().unwrap()
+        );
+        assert_eq!(FormatVersion::V1, table.metadata().format_version());
+        assert_eq!("s3://warehouse/database/table", table.metadata().location());
+        assert_eq!(
+            uuid!("b55d9dda-6561-423a-8bfc-787980ce421f"),
+            table.metadata().uuid()
+        );
+        assert_eq!(
+            Utc.timestamp_millis_opt(1646787054459).unwrap(),
+            table.metadata().last_updated_timestamp().unwrap()
+        );
+        assert_eq!(
+            vec![&Arc::new(
+                Schema::builder()
+                    .with_fields(vec![
+                        NestedField::optional(1, "id", Type::Primitive(PrimitiveType::Int)).into(),
+                        NestedField::optional(2, "data", Type::Primitive(PrimitiveType::String))
+                            .into(),
+                    ])
+                    .build()
+                    .unwrap()
+            )],
+            table.metadata().schemas_iter().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            &HashMap::from([
+                ("owner".to_string(), "bryan".to_string()),
+                (
+                    "write.metadata.compression-codec".to_string(),
+                    "gzip".to_string()
+                )
+            ]),
+            table.metadata().properties()
+        );
+        assert_eq!(vec![&Arc::new(Snapshot::builder()
+            .with_snapshot_id(3497810964824022504)
+            .with_timestamp_ms(1646787054459)
+            .with_manifest_list("s3://warehouse/database/table/metadata/snap-3497810964824022504-1-c4f68204-666b-4e50-a9df-b10c34bf6b82.avro")
+            .with_sequence_number(0)
+            .with_schema_id(0)
+            .with_summary(Summary {
+                operation: Operation::Append,
+                additional_properties: HashMap::from_iter([
+                    ("spark.app.id", "local-1646787004168"),
+                    ("added-data-files", "1"),
+                    ("added-records", "1"),
+                    ("added-files-size", "697"),
+                    ("changed-partition-count", "1"),
+                    ("total-records", "1"),
+                    ("total-files-size", "697"),
+                    ("total-data-files", "1"),
+                    ("total-delete-files", "0"),
+                    ("total-position-deletes", "0"),
+                    ("total-equality-deletes", "0")
+                ].iter().map(|p| (p.0.to_string(), p.1.to_string()))),
+            }).build()
+        )], table.metadata().snapshots().collect::<Vec<_>>());
+        assert_eq!(
+            &[SnapshotLog {
+                timestamp_ms: 1646787054459,
+                snapshot_id: 3497810964824022504,
+            }],
+            table.metadata().history()
+        );
+        assert_eq!(
+            vec![&Arc::new(SortOrder {
+                order_id: 0,
+                fields: vec![],
+            })],
+            table.metadata().sort_orders_iter().collect::<Vec<_>>()
         );
 
         config_mock.assert_async().await;
-        check_table_exists_mock.assert_async().await;
+        rename_table_mock.assert_async().await;
     }
 
     #[tokio::test]
-    async fn test_rename_table() {
+    async fn test_load_table_404() {
         let mut server = Server::new_async().await;
 
         let config_mock = create_config_mock(&mut server).await;
 
         let rename_table_mock = server
-            .mock("POST", "/v1/tables/rename")
-            .with_status(204)
+            .mock("GET", "/v1/namespaces/ns1/tables/test1")
+            .with_status(404)
+            .with_body(r#"
+{
+    "error": {
+        "message": "Table does not exist: ns1.test1 in warehouse 8bcb0838-50fc-472d-9ddb-8feb89ef5f1e",
+        "type": "NoSuchNamespaceErrorException",
+        "code": 404
+    }
+}
+            "#)
             .create_async()
             .await;
 
@@ -2164,31 +4062,33 @@ mod tests {
             Some(Arc::new(LocalFsStorageFactory)),
         );
 
-        catalog
-            .rename_table(
-                &TableIdent::new(NamespaceIdent::new("ns1".to_string()), "table1".to_string()),
-                &TableIdent::new(NamespaceIdent::new("ns1".to_string()), "table2".to_string()),
-            )
-            .await
-            .unwrap();
+        let table = catalog
+            .load_table(&TableIdent::new(
+                NamespaceIdent::new("ns1".to_string()),
+                "test1".to_string(),
+            ))
+            .await;
+
+        assert!(table.is_err());
+        assert!(table.err().unwrap().message().contains("does not exist"));
 
         config_mock.assert_async().await;
         rename_table_mock.assert_async().await;
     }
 
     #[tokio::test]
-    async fn test_load_table() {
+    async fn test_create_table() {
         let mut server = Server::new_async().await;
 
         let config_mock = create_config_mock(&mut server).await;
 
-        let rename_table_mock = server
-            .mock("GET", "/v1/namespaces/ns1/tables/test1")
+        let create_table_mock = server
+            .mock("POST", "/v1/namespaces/ns1/tables")
             .with_status(200)
             .with_body_from_file(format!(
                 "{}/testdata/{}",
                 env!("CARGO_MANIFEST_DIR"),
-                "load_table_response.json"
+                "create_table_response.json"
             ))
             .create_async()
             .await;
@@ -2198,11 +4098,52 @@ mod tests {
             Some(Arc::new(LocalFsStorageFactory)),
         );
 
+        let table_creation = TableCreation::builder()
+            .name("test1".to_string())
+            .schema(
+                Schema::builder()
+                    .with_fields(vec![
+                        NestedField::optional(1, "foo", Type::Primitive(PrimitiveType::String))
+                            .into(),
+                        NestedField::required(2, "bar", Type::Primitive(PrimitiveType::Int)).into(),
+                        NestedField::optional(3, "baz", Type::Primitive(PrimitiveType::Boolean))
+                            .into(),
+                    ])
+                    .with_schema_id(1)
+                    .with_identifier_field_ids(vec![2])
+                    .build()
+                    .unwrap(),
+            )
+            .properties(HashMap::from([("owner".to_string(), "testx".to_string())]))
+            .partition_spec(
+                UnboundPartitionSpec::builder()
+                    .add_partition_fields(vec![
+                        UnboundPartitionField::builder()
+                            .source_id(1)
+                            .transform(Transform::Truncate(3))
+                            .name("id".to_string())
+                            .build(),
+                    ])
+                    .unwrap()
+                    .build(),
+            )
+            .sort_order(
+                SortOrder::builder()
+                    .with_sort_field(
+                        SortField::builder()
+                            .source_id(2)
+                            .transform(Transform::Identity)
+                            .direction(SortDirection::Ascending)
+                            .null_order(NullOrder::First)
+                            .build(),
+                    )
+                    .build_unbound()
+                    .unwrap(),
+            )
+            .build();
+
         let table = catalog
-            .load_table(&TableIdent::new(
-                NamespaceIdent::new("ns1".to_string()),
-                "test1".to_string(),
-            ))
+            .create_table(&NamespaceIdent::from_strs(["ns1"]).unwrap(), table_creation)
             .await
             .unwrap();
 
@@ -2211,27 +4152,35 @@ mod tests {
             table.identifier()
         );
         assert_eq!(
-            "s3://warehouse/database/table/metadata/00001-5f2f8166-244c-4eae-ac36-384ecdec81fc.gz.metadata.json",
+            "s3://warehouse/database/table/metadata.json",
             table.metadata_location().unwrap()
         );
         assert_eq!(FormatVersion::V1, table.metadata().format_version());
         assert_eq!("s3://warehouse/database/table", table.metadata().location());
         assert_eq!(
-            uuid!("b55d9dda-6561-423a-8bfc-787980ce421f"),
+            uuid!("bf289591-dcc0-4234-ad4f-5c3eed811a29"),
             table.metadata().uuid()
         );
         assert_eq!(
-            Utc.timestamp_millis_opt(1646787054459).unwrap(),
-            table.metadata().last_updated_timestamp().unwrap()
+            1657810967051,
+            table
+                .metadata()
+                .last_updated_timestamp()
+                .unwrap()
+                .timestamp_millis()
         );
         assert_eq!(
             vec![&Arc::new(
                 Schema::builder()
                     .with_fields(vec![
-                        NestedField::optional(1, "id", Type::Primitive(PrimitiveType::Int)).into(),
-                        NestedField::optional(2, "data", Type::Primitive(PrimitiveType::String))
+                        NestedField::optional(1, "foo", Type::Primitive(PrimitiveType::String))
+                            .into(),
+                        NestedField::required(2, "bar", Type::Primitive(PrimitiveType::Int)).into(),
+                        NestedField::optional(3, "baz", Type::Primitive(PrimitiveType::Boolean))
                             .into(),
                     ])
+                    .with_schema_id(0)
+                    .with_identifier_field_ids(vec![2])
                     .build()
                     .unwrap()
             )],
@@ -2239,44 +4188,27 @@ mod tests {
         );
         assert_eq!(
             &HashMap::from([
-                ("owner".to_string(), "bryan".to_string()),
+                (
+                    "write.delete.parquet.compression-codec".to_string(),
+                    "zstd".to_string()
+                ),
                 (
                     "write.metadata.compression-codec".to_string(),
                     "gzip".to_string()
-                )
+                ),
+                (
+                    "write.summary.partition-limit".to_string(),
+                    "100".to_string()
+                ),
+                (
+                    "write.parquet.compression-codec".to_string(),
+                    "zstd".to_string()
+                ),
             ]),
             table.metadata().properties()
         );
-        assert_eq!(vec![&Arc::new(Snapshot::builder()
-            .with_snapshot_id(3497810964824022504)
-            .with_timestamp_ms(1646787054459)
-            .with_manifest_list("s3://warehouse/database/table/metadata/snap-3497810964824022504-1-c4f68204-666b-4e50-a9df-b10c34bf6b82.avro")
-            .with_sequence_number(0)
-            .with_schema_id(0)
-            .with_summary(Summary {
-                operation: Operation::Append,
-                additional_properties: HashMap::from_iter([
-                    ("spark.app.id", "local-1646787004168"),
-                    ("added-data-files", "1"),
-                    ("added-records", "1"),
-                    ("added-files-size", "697"),
-                    ("changed-partition-count", "1"),
-                    ("total-records", "1"),
-                    ("total-files-size", "697"),
-                    ("total-data-files", "1"),
-                    ("total-delete-files", "0"),
-                    ("total-position-deletes", "0"),
-                    ("total-equality-deletes", "0")
-                ].iter().map(|p| (p.0.to_string(), p.1.to_string()))),
-            }).build()
-        )], table.metadata().snapshots().collect::<Vec<_>>());
-        assert_eq!(
-            &[SnapshotLog {
-                timestamp_ms: 1646787054459,
-                snapshot_id: 3497810964824022504,
-            }],
-            table.metadata().history()
-        );
+        assert!(table.metadata().current_snapshot().is_none());
+        assert!(table.metadata().history().is_empty());
         assert_eq!(
             vec![&Arc::new(SortOrder {
                 order_id: 0,
@@ -2286,27 +4218,61 @@ mod tests {
         );
 
         config_mock.assert_async().await;
-        rename_table_mock.assert_async().await;
+        create_table_mock.assert_async().await;
     }
 
     #[tokio::test]
-    async fn test_load_table_404() {
+    async fn test_stage_create_table_and_commit() {
         let mut server = Server::new_async().await;
 
         let config_mock = create_config_mock(&mut server).await;
 
-        let rename_table_mock = server
-            .mock("GET", "/v1/namespaces/ns1/tables/test1")
-            .with_status(404)
-            .with_body(r#"
-{
-    "error": {
-        "message": "Table does not exist: ns1.test1 in warehouse 8bcb0838-50fc-472d-9ddb-8feb89ef5f1e",
-        "type": "NoSuchNamespaceErrorException",
-        "code": 404
-    }
-}
-            "#)
+        // A staged table has no metadata location yet.
+        let mut staged_response: serde_json::Value = serde_json::from_reader(
+            File::open(format!(
+                "{}/testdata/{}",
+                env!("CARGO_MANIFEST_DIR"),
+                "create_table_response.json"
+            ))
+            .unwrap(),
+        )
+        .unwrap();
+        staged_response
+            .as_object_mut()
+            .unwrap()
+            .remove("metadata-location");
+
+        let stage_create_mock = server
+            .mock("POST", "/v1/namespaces/ns1/tables")
+            .match_body(mockito::Matcher::PartialJson(json!({
+                "name": "test1",
+                "stage-create": true
+            })))
+            .with_status(200)
+            .with_body(staged_response.to_string())
+            .create_async()
+            .await;
+
+        let commit_mock = server
+            .mock("POST", "/v1/namespaces/ns1/tables/test1")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::Regex(r#""requirements":\[\{"type":"assert-create"\}\]"#.to_string()),
+                mockito::Matcher::Regex(
+                    r#""updates":\[\{"action":"assign-uuid","uuid":"bf289591-dcc0-4234-ad4f-5c3eed811a29"\}"#
+                        .to_string(),
+                ),
+                mockito::Matcher::Regex(
+                    r#""action":"set-location","location":"s3://warehouse/database/table""#
+                        .to_string(),
+                ),
+                mockito::Matcher::Regex(r#""action":"set-properties","updates":\{"k":"v"\}"#.to_string()),
+            ]))
+            .with_status(200)
+            .with_body_from_file(format!(
+                "{}/testdata/{}",
+                env!("CARGO_MANIFEST_DIR"),
+                "update_table_response.json"
+            ))
             .create_async()
             .await;
 
@@ -2315,34 +4281,65 @@ mod tests {
             Some(Arc::new(LocalFsStorageFactory)),
         );
 
-        let table = catalog
-            .load_table(&TableIdent::new(
-                NamespaceIdent::new("ns1".to_string()),
-                "test1".to_string(),
-            ))
-            .await;
+        let table_creation = TableCreation::builder()
+            .name("test1".to_string())
+            .schema(
+                Schema::builder()
+                    .with_fields(vec![
+                        NestedField::optional(1, "foo", Type::Primitive(PrimitiveType::String))
+                            .into(),
+                    ])
+                    .build()
+                    .unwrap(),
+            )
+            .build();
 
-        assert!(table.is_err());
-        assert!(table.err().unwrap().message().contains("does not exist"));
+        let staged = catalog
+            .stage_create_table(&NamespaceIdent::from_strs(["ns1"]).unwrap(), table_creation)
+            .await
+            .unwrap();
+        assert_eq!(staged.metadata_location(), None);
+        assert_eq!(
+            &TableIdent::from_strs(["ns1", "test1"]).unwrap(),
+            staged.identifier()
+        );
+
+        let tx = Transaction::create_table(&staged);
+        let tx = tx
+            .update_table_properties()
+            .set("k".to_string(), "v".to_string())
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+
+        assert_eq!(
+            Some("s3://warehouse/database/table/metadata.json"),
+            table.metadata_location()
+        );
 
         config_mock.assert_async().await;
-        rename_table_mock.assert_async().await;
+        stage_create_mock.assert_async().await;
+        commit_mock.assert_async().await;
     }
 
     #[tokio::test]
-    async fn test_create_table() {
+    async fn test_create_table_409() {
         let mut server = Server::new_async().await;
 
         let config_mock = create_config_mock(&mut server).await;
 
         let create_table_mock = server
             .mock("POST", "/v1/namespaces/ns1/tables")
-            .with_status(200)
-            .with_body_from_file(format!(
-                "{}/testdata/{}",
-                env!("CARGO_MANIFEST_DIR"),
-                "create_table_response.json"
-            ))
+            .with_status(409)
+            .with_body(r#"
+{
+    "error": {
+        "message": "Table already exists: ns1.test1 in warehouse 8bcb0838-50fc-472d-9ddb-8feb89ef5f1e",
+        "type": "AlreadyExistsException",
+        "code": 409
+    }
+}
+            "#)
             .create_async()
             .await;
 
@@ -2368,35 +4365,84 @@ mod tests {
                     .unwrap(),
             )
             .properties(HashMap::from([("owner".to_string(), "testx".to_string())]))
-            .partition_spec(
-                UnboundPartitionSpec::builder()
-                    .add_partition_fields(vec![
-                        UnboundPartitionField::builder()
-                            .source_id(1)
-                            .transform(Transform::Truncate(3))
-                            .name("id".to_string())
-                            .build(),
-                    ])
-                    .unwrap()
-                    .build(),
-            )
-            .sort_order(
-                SortOrder::builder()
-                    .with_sort_field(
-                        SortField::builder()
-                            .source_id(2)
-                            .transform(Transform::Identity)
-                            .direction(SortDirection::Ascending)
-                            .null_order(NullOrder::First)
-                            .build(),
-                    )
-                    .build_unbound()
-                    .unwrap(),
-            )
             .build();
 
-        let table = catalog
+        let table_result = catalog
             .create_table(&NamespaceIdent::from_strs(["ns1"]).unwrap(), table_creation)
+            .await;
+
+        assert!(table_result.is_err());
+        assert!(
+            table_result
+                .err()
+                .unwrap()
+                .message()
+                .contains("already exists")
+        );
+
+        config_mock.assert_async().await;
+        create_table_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_update_table() {
+        let mut server = Server::new_async().await;
+
+        let config_mock = create_config_mock(&mut server).await;
+
+        let load_table_mock = server
+            .mock("GET", "/v1/namespaces/ns1/tables/test1")
+            .with_status(200)
+            .with_body_from_file(format!(
+                "{}/testdata/{}",
+                env!("CARGO_MANIFEST_DIR"),
+                "load_table_response.json"
+            ))
+            .create_async()
+            .await;
+
+        let update_table_mock = server
+            .mock("POST", "/v1/namespaces/ns1/tables/test1")
+            .with_status(200)
+            .with_body_from_file(format!(
+                "{}/testdata/{}",
+                env!("CARGO_MANIFEST_DIR"),
+                "update_table_response.json"
+            ))
+            .create_async()
+            .await;
+
+        let catalog = RestCatalog::new(
+            RestCatalogConfig::builder().uri(server.url()).build(),
+            Some(Arc::new(LocalFsStorageFactory)),
+        );
+
+        let table1 = {
+            let file = File::open(format!(
+                "{}/testdata/{}",
+                env!("CARGO_MANIFEST_DIR"),
+                "create_table_response.json"
+            ))
+            .unwrap();
+            let reader = BufReader::new(file);
+            let resp = serde_json::from_reader::<_, LoadTableResult>(reader).unwrap();
+
+            Table::builder()
+                .metadata(resp.metadata)
+                .metadata_location(resp.metadata_location.unwrap())
+                .identifier(TableIdent::from_strs(["ns1", "test1"]).unwrap())
+                .file_io(FileIO::new_with_fs())
+                .build()
+                .unwrap()
+        };
+
+        let tx = Transaction::new(&table1);
+        let table = tx
+            .upgrade_table_version()
+            .set_format_version(FormatVersion::V2)
+            .apply(tx)
+            .unwrap()
+            .commit(&catalog)
             .await
             .unwrap();
 
@@ -2408,7 +4454,7 @@ mod tests {
             "s3://warehouse/database/table/metadata.json",
             table.metadata_location().unwrap()
         );
-        assert_eq!(FormatVersion::V1, table.metadata().format_version());
+        assert_eq!(FormatVersion::V2, table.metadata().format_version());
         assert_eq!("s3://warehouse/database/table", table.metadata().location());
         assert_eq!(
             uuid!("bf289591-dcc0-4234-ad4f-5c3eed811a29"),
@@ -2471,27 +4517,117 @@ mod tests {
         );
 
         config_mock.assert_async().await;
-        create_table_mock.assert_async().await;
+        update_table_mock.assert_async().await;
+        load_table_mock.assert_async().await
+    }
+
+    fn load_test_table(name: &str) -> Table {
+        let file = File::open(format!(
+            "{}/testdata/{}",
+            env!("CARGO_MANIFEST_DIR"),
+            "create_table_response.json"
+        ))
+        .unwrap();
+        let reader = BufReader::new(file);
+        let resp = serde_json::from_reader::<_, LoadTableResult>(reader).unwrap();
+
+        Table::builder()
+            .metadata(resp.metadata)
+            .metadata_location(resp.metadata_location.unwrap())
+            .identifier(TableIdent::from_strs(["ns1", name]).unwrap())
+            .file_io(FileIO::new_with_fs())
+            .build()
+            .unwrap()
+    }
+
+    async fn create_load_table_mock(server: &mut ServerGuard, name: &str) -> Mock {
+        server
+            .mock("GET", format!("/v1/namespaces/ns1/tables/{name}").as_str())
+            .with_status(200)
+            .with_body_from_file(format!(
+                "{}/testdata/{}",
+                env!("CARGO_MANIFEST_DIR"),
+                "load_table_response.json"
+            ))
+            .create_async()
+            .await
+    }
+
+    fn set_property_transaction(table: &Table, value: &str) -> Transaction {
+        let tx = Transaction::new(table);
+        tx.update_table_properties()
+            .set("refreshed".to_string(), value.to_string())
+            .apply(tx)
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_commit_transaction() {
+        let mut server = Server::new_async().await;
+
+        let config_mock = create_config_mock(&mut server).await;
+        let load_table1_mock = create_load_table_mock(&mut server, "test1").await;
+        let load_table2_mock = create_load_table_mock(&mut server, "test2").await;
+
+        let commit_transaction_mock = server
+            .mock("POST", "/v1/transactions/commit")
+            .match_body(mockito::Matcher::PartialJson(json!({
+                "table-changes": [
+                    {
+                        "identifier": {"namespace": ["ns1"], "name": "test1"},
+                        "updates": [{"action": "set-properties", "updates": {"refreshed": "one"}}]
+                    },
+                    {
+                        "identifier": {"namespace": ["ns1"], "name": "test2"},
+                        "updates": [{"action": "set-properties", "updates": {"refreshed": "two"}}]
+                    }
+                ]
+            })))
+            .with_status(204)
+            .create_async()
+            .await;
+
+        let catalog = RestCatalog::new(
+            RestCatalogConfig::builder().uri(server.url()).build(),
+            Some(Arc::new(LocalFsStorageFactory)),
+        );
+
+        MultiTableTransaction::new()
+            .add_transaction(set_property_transaction(&load_test_table("test1"), "one"))
+            .unwrap()
+            .add_transaction(set_property_transaction(&load_test_table("test2"), "two"))
+            .unwrap()
+            .commit(&catalog)
+            .await
+            .unwrap();
+
+        config_mock.assert_async().await;
+        load_table1_mock.assert_async().await;
+        load_table2_mock.assert_async().await;
+        commit_transaction_mock.assert_async().await;
     }
 
     #[tokio::test]
-    async fn test_create_table_409() {
+    async fn test_commit_transaction_not_found() {
         let mut server = Server::new_async().await;
 
         let config_mock = create_config_mock(&mut server).await;
+        let load_table_mock = create_load_table_mock(&mut server, "test1").await;
 
-        let create_table_mock = server
-            .mock("POST", "/v1/namespaces/ns1/tables")
-            .with_status(409)
-            .with_body(r#"
+        let commit_transaction_mock = server
+            .mock("POST", "/v1/transactions/commit")
+            .with_status(404)
+            .with_body(
+                r#"
 {
     "error": {
-        "message": "Table already exists: ns1.test1 in warehouse 8bcb0838-50fc-472d-9ddb-8feb89ef5f1e",
-        "type": "AlreadyExistsException",
-        "code": 409
+        "message": "Table does not exist: ns1.test1",
+        "type": "NoSuchTableException",
+        "code": 404
     }
 }
-            "#)
+            "#,
+            )
             .create_async()
             .await;
 
@@ -2500,44 +4636,21 @@ mod tests {
             Some(Arc::new(LocalFsStorageFactory)),
         );
 
-        let table_creation = TableCreation::builder()
-            .name("test1".to_string())
-            .schema(
-                Schema::builder()
-                    .with_fields(vec![
-                        NestedField::optional(1, "foo", Type::Primitive(PrimitiveType::String))
-                            .into(),
-                        NestedField::required(2, "bar", Type::Primitive(PrimitiveType::Int)).into(),
-                        NestedField::optional(3, "baz", Type::Primitive(PrimitiveType::Boolean))
-                            .into(),
-                    ])
-                    .with_schema_id(1)
-                    .with_identifier_field_ids(vec![2])
-                    .build()
-                    .unwrap(),
-            )
-            .properties(HashMap::from([("owner".to_string(), "testx".to_string())]))
-            .build();
-
-        let table_result = catalog
-            .create_table(&NamespaceIdent::from_strs(["ns1"]).unwrap(), table_creation)
+        let result = MultiTableTransaction::new()
+            .add_transaction(set_property_transaction(&load_test_table("test1"), "one"))
+            .unwrap()
+            .commit(&catalog)
             .await;
 
-        assert!(table_result.is_err());
-        assert!(
-            table_result
-                .err()
-                .unwrap()
-                .message()
-                .contains("already exists")
-        );
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::TableNotFound);
 
         config_mock.assert_async().await;
-        create_table_mock.assert_async().await;
+        load_table_mock.assert_async().await;
+        commit_transaction_mock.assert_async().await;
     }
 
     #[tokio::test]
-    async fn test_update_table() {
+    async fn test_update_table_404() {
         let mut server = Server::new_async().await;
 
         let config_mock = create_config_mock(&mut server).await;
@@ -2555,12 +4668,18 @@ mod tests {
 
         let update_table_mock = server
             .mock("POST", "/v1/namespaces/ns1/tables/test1")
-            .with_status(200)
-            .with_body_from_file(format!(
-                "{}/testdata/{}",
-                env!("CARGO_MANIFEST_DIR"),
-                "update_table_response.json"
-            ))
+            .with_status(404)
+            .with_body(
+                r#"
+{
+    "error": {
+        "message": "The given table does not exist",
+        "type": "NoSuchTableException",
+        "code": 404
+    }
+}
+            "#,
+            )
             .create_async()
             .await;
 
@@ -2589,12 +4708,57 @@ mod tests {
         };
 
         let tx = Transaction::new(&table1);
-        let table = tx
+        let table_result = tx
             .upgrade_table_version()
             .set_format_version(FormatVersion::V2)
             .apply(tx)
             .unwrap()
             .commit(&catalog)
+            .await;
+
+        assert!(table_result.is_err());
+        assert!(
+            table_result
+                .err()
+                .unwrap()
+                .message()
+                .contains("does not exist")
+        );
+
+        config_mock.assert_async().await;
+        update_table_mock.assert_async().await;
+        load_table_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_register_table() {
+        let mut server = Server::new_async().await;
+
+        let config_mock = create_config_mock(&mut server).await;
+
+        let register_table_mock = server
+            .mock("POST", "/v1/namespaces/ns1/register")
+            .with_status(200)
+            .with_body_from_file(format!(
+                "{}/testdata/{}",
+                env!("CARGO_MANIFEST_DIR"),
+                "load_table_response.json"
+            ))
+            .create_async()
+            .await;
+
+        let catalog = RestCatalog::new(
+            RestCatalogConfig::builder().uri(server.url()).build(),
+            Some(Arc::new(LocalFsStorageFactory)),
+        );
+        let table_ident =
+            TableIdent::new(NamespaceIdent::new("ns1".to_string()), "test1".to_string());
+        let metadata_location = String::from(
+            "s3://warehouse/database/table/metadata/00001-5f2f8166-244c-4eae-ac36-384ecdec81fc.gz.metadata.json",
+        );
+
+        let table = catalog
+            .register_table(&table_ident, metadata_location)
             .await
             .unwrap();
 
@@ -2603,103 +4767,209 @@ mod tests {
             table.identifier()
         );
         assert_eq!(
-            "s3://warehouse/database/table/metadata.json",
+            "s3://warehouse/database/table/metadata/00001-5f2f8166-244c-4eae-ac36-384ecdec81fc.gz.metadata.json",
             table.metadata_location().unwrap()
         );
-        assert_eq!(FormatVersion::V2, table.metadata().format_version());
-        assert_eq!("s3://warehouse/database/table", table.metadata().location());
-        assert_eq!(
-            uuid!("bf289591-dcc0-4234-ad4f-5c3eed811a29"),
-            table.metadata().uuid()
+
+        config_mock.assert_async().await;
+        register_table_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_register_table_404() {
+        let mut server = Server::new_async().await;
+
+        let config_mock = create_config_mock(&mut server).await;
+
+        let register_table_mock = server
+            .mock("POST", "/v1/namespaces/ns1/register")
+            .with_status(404)
+            .with_body(
+                r#"
+{
+    "error": {
+        "message": "The namespace specified does not exist",
+        "type": "NoSuchNamespaceErrorException",
+        "code": 404
+    }
+}
+            "#,
+            )
+            .create_async()
+            .await;
+
+        let catalog = RestCatalog::new(
+            RestCatalogConfig::builder().uri(server.url()).build(),
+            Some(Arc::new(LocalFsStorageFactory)),
         );
-        assert_eq!(
-            1657810967051,
-            table
-                .metadata()
-                .last_updated_timestamp()
-                .unwrap()
-                .timestamp_millis()
+
+        let table_ident =
+            TableIdent::new(NamespaceIdent::new("ns1".to_string()), "test1".to_string());
+        let metadata_location = String::from(
+            "s3://warehouse/database/table/metadata/00001-5f2f8166-244c-4eae-ac36-384ecdec81fc.gz.metadata.json",
+        );
+        let table = catalog
+            .register_table(&table_ident, metadata_location)
+            .await;
+
+        assert!(table.is_err());
+        assert!(table.err().unwrap().message().contains("does not exist"));
+
+        config_mock.assert_async().await;
+        register_table_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_list_views() {
+        let mut server = Server::new_async().await;
+
+        let config_mock = create_config_mock(&mut server).await;
+
+        let list_views_mock = server
+            .mock("GET", "/v1/namespaces/ns1/views")
+            .with_status(200)
+            .with_body(
+                r#"{
+                "identifiers": [
+                    {
+                        "namespace": ["ns1"],
+                        "name": "view1"
+                    },
+                    {
+                        "namespace": ["ns1"],
+                        "name": "view2"
+                    }
+                ]
+            }"#,
+            )
+            .create_async()
+            .await;
+
+        let catalog = RestCatalog::new(
+            RestCatalogConfig::builder().uri(server.url()).build(),
+            Some(Arc::new(LocalFsStorageFactory)),
+        );
+
+        let views = catalog
+            .list_views(&NamespaceIdent::new("ns1".to_string()))
+            .await
+            .unwrap();
+
+        let expected_views = vec![
+            TableIdent::new(NamespaceIdent::new("ns1".to_string()), "view1".to_string()),
+            TableIdent::new(NamespaceIdent::new("ns1".to_string()), "view2".to_string()),
+        ];
+
+        assert_eq!(views, expected_views);
+
+        config_mock.assert_async().await;
+        list_views_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_create_view() {
+        let mut server = Server::new_async().await;
+
+        let config_mock = create_config_mock(&mut server).await;
+
+        let create_view_mock = server
+            .mock("POST", "/v1/namespaces/ns1/views")
+            .match_body(mockito::Matcher::PartialJson(json!({
+                "name": "event_agg",
+                "location": "s3://bucket/warehouse/default.db/event_agg",
+                "view-version": {
+                    "version-id": 1,
+                    "schema-id": 1,
+                    "default-namespace": ["default"],
+                    "representations": [{
+                        "type": "sql",
+                        "sql": "SELECT COUNT(1) FROM events",
+                        "dialect": "spark"
+                    }]
+                },
+                "properties": {"comment": "Daily event counts"}
+            })))
+            .with_status(200)
+            .with_body_from_file(format!(
+                "{}/testdata/{}",
+                env!("CARGO_MANIFEST_DIR"),
+                "load_view_response.json"
+            ))
+            .create_async()
+            .await;
+
+        let catalog = RestCatalog::new(
+            RestCatalogConfig::builder().uri(server.url()).build(),
+            Some(Arc::new(LocalFsStorageFactory)),
         );
-        assert_eq!(
-            vec![&Arc::new(
+
+        let view_creation = ViewCreation::builder()
+            .name("event_agg".to_string())
+            .location("s3://bucket/warehouse/default.db/event_agg".to_string())
+            .schema(
                 Schema::builder()
+                    .with_schema_id(1)
                     .with_fields(vec![
-                        NestedField::optional(1, "foo", Type::Primitive(PrimitiveType::String))
-                            .into(),
-                        NestedField::required(2, "bar", Type::Primitive(PrimitiveType::Int)).into(),
-                        NestedField::optional(3, "baz", Type::Primitive(PrimitiveType::Boolean))
-                            .into(),
+                        NestedField::optional(
+                            1,
+                            "event_count",
+                            Type::Primitive(PrimitiveType::Int),
+                        )
+                        .into(),
                     ])
-                    .with_schema_id(0)
-                    .with_identifier_field_ids(vec![2])
                     .build()
-                    .unwrap()
-            )],
-            table.metadata().schemas_iter().collect::<Vec<_>>()
+                    .unwrap(),
+            )
+            .representations(ViewRepresentations::from_iter([SqlViewRepresentation {
+                sql: "SELECT COUNT(1) FROM events".to_string(),
+                dialect: "spark".to_string(),
+            }
+            .into()]))
+            .default_namespace(NamespaceIdent::new("default".to_string()))
+            .properties(HashMap::from([(
+                "comment".to_string(),
+                "Daily event counts".to_string(),
+            )]))
+            .build();
+
+        let view = catalog
+            .create_view(&NamespaceIdent::new("ns1".to_string()), view_creation)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            &TableIdent::from_strs(vec!["ns1", "event_agg"]).unwrap(),
+            view.identifier()
         );
         assert_eq!(
-            &HashMap::from([
-                (
-                    "write.delete.parquet.compression-codec".to_string(),
-                    "zstd".to_string()
-                ),
-                (
-                    "write.metadata.compression-codec".to_string(),
-                    "gzip".to_string()
-                ),
-                (
-                    "write.summary.partition-limit".to_string(),
-                    "100".to_string()
-                ),
-                (
-                    "write.parquet.compression-codec".to_string(),
-                    "zstd".to_string()
-                ),
-            ]),
-            table.metadata().properties()
+            "s3://bucket/warehouse/default.db/event_agg/metadata/00001-fa6506c3-7681-40c8-86dc-e36561f83385.metadata.json",
+            view.metadata_location().unwrap()
         );
-        assert!(table.metadata().current_snapshot().is_none());
-        assert!(table.metadata().history().is_empty());
         assert_eq!(
-            vec![&Arc::new(SortOrder {
-                order_id: 0,
-                fields: vec![],
-            })],
-            table.metadata().sort_orders_iter().collect::<Vec<_>>()
+            uuid!("fa6506c3-7681-40c8-86dc-e36561f83385"),
+            view.metadata().uuid()
         );
 
         config_mock.assert_async().await;
-        update_table_mock.assert_async().await;
-        load_table_mock.assert_async().await
+        create_view_mock.assert_async().await;
     }
 
     #[tokio::test]
-    async fn test_update_table_404() {
+    async fn test_create_view_409() {
         let mut server = Server::new_async().await;
 
         let config_mock = create_config_mock(&mut server).await;
 
-        let load_table_mock = server
-            .mock("GET", "/v1/namespaces/ns1/tables/test1")
-            .with_status(200)
-            .with_body_from_file(format!(
-                "{}/testdata/{}",
-                env!("CARGO_MANIFEST_DIR"),
-                "load_table_response.json"
-            ))
-            .create_async()
-            .await;
-
-        let update_table_mock = server
-            .mock("POST", "/v1/namespaces/ns1/tables/test1")
-            .with_status(404)
+        let create_view_mock = server
+            .mock("POST", "/v1/namespaces/ns1/views")
+            .with_status(409)
             .with_body(
                 r#"
 {
     "error": {
-        "message": "The given table does not exist",
-        "type": "NoSuchTableException",
-        "code": 404
+        "message": "View already exists: ns1.event_agg",
+        "type": "AlreadyExistsException",
+        "code": 409
     }
 }
             "#,
@@ -2712,61 +4982,41 @@ mod tests {
             Some(Arc::new(LocalFsStorageFactory)),
         );
 
-        let table1 = {
-            let file = File::open(format!(
-                "{}/testdata/{}",
-                env!("CARGO_MANIFEST_DIR"),
-                "create_table_response.json"
-            ))
-            .unwrap();
-            let reader = BufReader::new(file);
-            let resp = serde_json::from_reader::<_, LoadTableResult>(reader).unwrap();
-
-            Table::builder()
-                .metadata(resp.metadata)
-                .metadata_location(resp.metadata_location.unwrap())
-                .identifier(TableIdent::from_strs(["ns1", "test1"]).unwrap())
-                .file_io(FileIO::new_with_fs())
-                .build()
-                .unwrap()
-        };
+        let view_creation = ViewCreation::builder()
+            .name("event_agg".to_string())
+            .location("s3://bucket/warehouse/default.db/event_agg".to_string())
+            .schema(Schema::builder().build().unwrap())
+            .representations(ViewRepresentations::from_iter([SqlViewRepresentation {
+                sql: "SELECT 1".to_string(),
+                dialect: "spark".to_string(),
+            }
+            .into()]))
+            .default_namespace(NamespaceIdent::new("default".to_string()))
+            .build();
 
-        let tx = Transaction::new(&table1);
-        let table_result = tx
-            .upgrade_table_version()
-            .set_format_version(FormatVersion::V2)
-            .apply(tx)
-            .unwrap()
-            .commit(&catalog)
+        let result = catalog
+            .create_view(&NamespaceIdent::new("ns1".to_string()), view_creation)
             .await;
 
-        assert!(table_result.is_err());
-        assert!(
-            table_result
-                .err()
-                .unwrap()
-                .message()
-                .contains("does not exist")
-        );
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::ViewAlreadyExists);
 
         config_mock.assert_async().await;
-        update_table_mock.assert_async().await;
-        load_table_mock.assert_async().await;
+        create_view_mock.assert_async().await;
     }
 
     #[tokio::test]
-    async fn test_register_table() {
+    async fn test_load_view() {
         let mut server = Server::new_async().await;
 
         let config_mock = create_config_mock(&mut server).await;
 
-        let register_table_mock = server
-            .mock("POST", "/v1/namespaces/ns1/register")
+        let load_view_mock = server
+            .mock("GET", "/v1/namespaces/ns1/views/event_agg")
             .with_status(200)
             .with_body_from_file(format!(
                 "{}/testdata/{}",
                 env!("CARGO_MANIFEST_DIR"),
-                "load_table_response.json"
+                "load_view_response.json"
             ))
             .create_async()
             .await;
@@ -2775,45 +5025,45 @@ mod tests {
             RestCatalogConfig::builder().uri(server.url()).build(),
             Some(Arc::new(LocalFsStorageFactory)),
         );
-        let table_ident =
-            TableIdent::new(NamespaceIdent::new("ns1".to_string()), "test1".to_string());
-        let metadata_location = String::from(
-            "s3://warehouse/database/table/metadata/00001-5f2f8166-244c-4eae-ac36-384ecdec81fc.gz.metadata.json",
-        );
 
-        let table = catalog
-            .register_table(&table_ident, metadata_location)
+        let view = catalog
+            .load_view(&TableIdent::from_strs(vec!["ns1", "event_agg"]).unwrap())
             .await
             .unwrap();
 
         assert_eq!(
-            &TableIdent::from_strs(vec!["ns1", "test1"]).unwrap(),
-            table.identifier()
+            &TableIdent::from_strs(vec!["ns1", "event_agg"]).unwrap(),
+            view.identifier()
         );
         assert_eq!(
-            "s3://warehouse/database/table/metadata/00001-5f2f8166-244c-4eae-ac36-384ecdec81fc.gz.metadata.json",
-            table.metadata_location().unwrap()
+            "s3://bucket/warehouse/default.db/event_agg",
+            view.metadata().location()
+        );
+        assert_eq!(1, view.metadata().current_version_id());
+        assert_eq!(
+            Some(&"Daily event counts".to_string()),
+            view.metadata().properties().get("comment")
         );
 
         config_mock.assert_async().await;
-        register_table_mock.assert_async().await;
+        load_view_mock.assert_async().await;
     }
 
     #[tokio::test]
-    async fn test_register_table_404() {
+    async fn test_load_view_404() {
         let mut server = Server::new_async().await;
 
         let config_mock = create_config_mock(&mut server).await;
 
-        let register_table_mock = server
-            .mock("POST", "/v1/namespaces/ns1/register")
+        let load_view_mock = server
+            .mock("GET", "/v1/namespaces/ns1/views/event_agg")
             .with_status(404)
             .with_body(
                 r#"
 {
     "error": {
-        "message": "The namespace specified does not exist",
-        "type": "NoSuchNamespaceErrorException",
+        "message": "View does not exist: ns1.event_agg",
+        "type": "NoSuchViewException",
         "code": 404
     }
 }
@@ -2827,20 +5077,171 @@ mod tests {
             Some(Arc::new(LocalFsStorageFactory)),
         );
 
-        let table_ident =
-            TableIdent::new(NamespaceIdent::new("ns1".to_string()), "test1".to_string());
-        let metadata_location = String::from(
-            "s3://warehouse/database/table/metadata/00001-5f2f8166-244c-4eae-ac36-384ecdec81fc.gz.metadata.json",
+        let result = catalog
+            .load_view(&TableIdent::from_strs(vec!["ns1", "event_agg"]).unwrap())
+            .await;
+
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::ViewNotFound);
+
+        config_mock.assert_async().await;
+        load_view_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_drop_view() {
+        let mut server = Server::new_async().await;
+
+        let config_mock = create_config_mock(&mut server).await;
+
+        let drop_view_mock = server
+            .mock("DELETE", "/v1/namespaces/ns1/views/view1")
+            .with_status(204)
+            .create_async()
+            .await;
+
+        let catalog = RestCatalog::new(
+            RestCatalogConfig::builder().uri(server.url()).build(),
+            Some(Arc::new(LocalFsStorageFactory)),
         );
-        let table = catalog
-            .register_table(&table_ident, metadata_location)
+
+        catalog
+            .drop_view(&TableIdent::new(
+                NamespaceIdent::new("ns1".to_string()),
+                "view1".to_string(),
+            ))
+            .await
+            .unwrap();
+
+        config_mock.assert_async().await;
+        drop_view_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_check_view_exists() {
+        let mut server = Server::new_async().await;
+
+        let config_mock = create_config_mock(&mut server).await;
+
+        let check_view_exists_mock = server
+            .mock("HEAD", "/v1/namespaces/ns1/views/view1")
+            .with_status(204)
+            .create_async()
             .await;
 
-        assert!(table.is_err());
-        assert!(table.err().unwrap().message().contains("does not exist"));
+        let catalog = RestCatalog::new(
+            RestCatalogConfig::builder().uri(server.url()).build(),
+            Some(Arc::new(LocalFsStorageFactory)),
+        );
+
+        assert!(
+            catalog
+                .view_exists(&TableIdent::new(
+                    NamespaceIdent::new("ns1".to_string()),
+                    "view1".to_string(),
+                ))
+                .await
+                .unwrap()
+        );
 
         config_mock.assert_async().await;
-        register_table_mock.assert_async().await;
+        check_view_exists_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_rename_view() {
+        let mut server = Server::new_async().await;
+
+        let config_mock = create_config_mock(&mut server).await;
+
+        let rename_view_mock = server
+            .mock("POST", "/v1/views/rename")
+            .match_body(mockito::Matcher::Json(json!({
+                "source": {"namespace": ["ns1"], "name": "view1"},
+                "destination": {"namespace": ["ns1"], "name": "view2"}
+            })))
+            .with_status(204)
+            .create_async()
+            .await;
+
+        let catalog = RestCatalog::new(
+            RestCatalogConfig::builder().uri(server.url()).build(),
+            Some(Arc::new(LocalFsStorageFactory)),
+        );
+
+        catalog
+            .rename_view(
+                &TableIdent::new(NamespaceIdent::new("ns1".to_string()), "view1".to_string()),
+                &TableIdent::new(NamespaceIdent::new("ns1".to_string()), "view2".to_string()),
+            )
+            .await
+            .unwrap();
+
+        config_mock.assert_async().await;
+        rename_view_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_update_view() {
+        let mut server = Server::new_async().await;
+
+        let config_mock = create_config_mock(&mut server).await;
+
+        let load_view_mock = server
+            .mock("GET", "/v1/namespaces/ns1/views/event_agg")
+            .with_status(200)
+            .with_body_from_file(format!(
+                "{}/testdata/{}",
+                env!("CARGO_MANIFEST_DIR"),
+                "load_view_response.json"
+            ))
+            .create_async()
+            .await;
+
+        let update_view_mock = server
+            .mock("POST", "/v1/namespaces/ns1/views/event_agg")
+            .match_body(mockito::Matcher::Json(json!({
+                "identifier": {"namespace": ["ns1"], "name": "event_agg"},
+                "requirements": [
+                    {"type": "assert-view-uuid", "uuid": "fa6506c3-7681-40c8-86dc-e36561f83385"}
+                ],
+                "updates": [
+                    {"action": "set-properties", "updates": {"owner": "bi"}}
+                ]
+            })))
+            .with_status(200)
+            .with_body_from_file(format!(
+                "{}/testdata/{}",
+                env!("CARGO_MANIFEST_DIR"),
+                "load_view_response.json"
+            ))
+            .create_async()
+            .await;
+
+        let catalog = RestCatalog::new(
+            RestCatalogConfig::builder().uri(server.url()).build(),
+            Some(Arc::new(LocalFsStorageFactory)),
+        );
+
+        let view = catalog
+            .load_view(&TableIdent::from_strs(vec!["ns1", "event_agg"]).unwrap())
+            .await
+            .unwrap();
+
+        let view = ViewTransaction::new(&view)
+            .set_properties(HashMap::from([("owner".to_string(), "bi".to_string())]))
+            .unwrap()
+            .commit(&catalog)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            &TableIdent::from_strs(vec!["ns1", "event_agg"]).unwrap(),
+            view.identifier()
+        );
+
+        config_mock.assert_async().await;
+        load_view_mock.assert_async().await;
+        update_view_mock.assert_async().await;
     }
 
     #[tokio::test]
@@ -26,6 +26,8 @@ use serde::de::DeserializeOwned;
 use tokio::sync::Mutex;
 
 use crate::RestCatalogConfig;
+#[cfg(feature = "sigv4")]
+use crate::sigv4::SigV4Signer;
 use crate::types::{ErrorResponse, TokenResponse};
 
 pub(crate) struct HttpClient {
@@ -45,20 +47,28 @@ pub(crate) struct HttpClient {
     extra_oauth_params: HashMap<String, String>,
     /// Whether to disable header redaction in error logs (defaults to false for security).
     disable_header_redaction: bool,
+    /// The signer of requests, if they are signed with AWS SigV4.
+    #[cfg(feature = "sigv4")]
+    signer: Option<SigV4Signer>,
 }
 
 impl Debug for HttpClient {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("HttpClient")
+        let mut debug = f.debug_struct("HttpClient");
+        debug
             .field("client", &self.client)
-            .field("extra_headers", &self.extra_headers)
-            .finish_non_exhaustive()
+            .field("extra_headers", &self.extra_headers);
+        #[cfg(feature = "sigv4")]
+        debug.field("signer", &self.signer);
+        debug.finish_non_exhaustive()
     }
 }
 
 impl HttpClient {
     /// Create a new http client.
     pub fn new(cfg: &RestCatalogConfig) -> Result<Self> {
+        #[cfg(not(feature = "sigv4"))]
+        cfg.check_sigv4_disabled()?;
         let extra_headers = cfg.extra_headers()?;
         Ok(HttpClient {
             client: cfg.client().unwrap_or_default(),
@@ -68,6 +78,8 @@ impl HttpClient {
             extra_headers,
             extra_oauth_params: cfg.extra_oauth_params(),
             disable_header_redaction: cfg.disable_header_redaction(),
+            #[cfg(feature = "sigv4")]
+            signer: cfg.sigv4_signer()?,
         })
     }
 
@@ -76,6 +88,8 @@ impl HttpClient {
     /// If cfg carries new value, we will use cfg instead.
     /// Otherwise, we will keep the old value.
     pub fn update_with(self, cfg: &RestCatalogConfig) -> Result<Self> {
+        #[cfg(not(feature = "sigv4"))]
+        cfg.check_sigv4_disabled()?;
         let extra_headers = (!cfg.extra_headers()?.is_empty())
             .then(|| cfg.extra_headers())
             .transpose()?
@@ -96,6 +110,8 @@ impl HttpClient {
                 self.extra_oauth_params
             },
             disable_header_redaction: cfg.disable_header_redaction(),
+            #[cfg(feature = "sigv4")]
+            signer: cfg.sigv4_signer()?.or(self.signer),
         })
     }
 
@@ -251,8 +267,14 @@ impl HttpClient {
     }
 
     /// Executes the given `Request` and returns a `Response`.
+    ///
+    /// The request is signed if the client signs requests with AWS SigV4.
     pub async fn execute(&self, mut request: Request) -> Result<Response> {
         request.headers_mut().extend(self.extra_headers.clone());
+        #[cfg(feature = "sigv4")]
+        if let Some(signer) = &self.signer {
+            signer.sign(&mut request).await?;
+        }
         Ok(self.client.execute(request).await?)
     }
 
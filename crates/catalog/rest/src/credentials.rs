@@ -0,0 +1,92 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Refreshing of the storage credentials vended by the REST catalog server.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use iceberg::io::{StorageCredential, StorageCredentialsLoader};
+use iceberg::{Result, TableIdent};
+use reqwest::{Method, StatusCode};
+
+use crate::RestCatalogConfig;
+use crate::client::{
+    HttpClient, deserialize_catalog_response, deserialize_unexpected_catalog_error,
+};
+use crate::types::{self, LoadCredentialsResponse};
+
+/// Endpoint advertised in the config response by servers that refresh vended credentials.
+pub(crate) const LOAD_CREDENTIALS_ENDPOINT: &str =
+    "GET /v1/{prefix}/namespaces/{namespace}/tables/{table}/credentials";
+
+/// A [`StorageCredentialsLoader`] loading the storage credentials of a table from the REST
+/// catalog server.
+#[derive(Debug, Clone)]
+pub(crate) struct RestCredentialsLoader {
+    client: Arc<HttpClient>,
+    config: Arc<RestCatalogConfig>,
+    table: TableIdent,
+}
+
+impl RestCredentialsLoader {
+    pub(crate) fn new(
+        client: Arc<HttpClient>,
+        config: Arc<RestCatalogConfig>,
+        table: TableIdent,
+    ) -> Self {
+        Self {
+            client,
+            config,
+            table,
+        }
+    }
+}
+
+#[async_trait]
+impl StorageCredentialsLoader for RestCredentialsLoader {
+    async fn load_credentials(&self) -> Result<Vec<StorageCredential>> {
+        let request = self
+            .client
+            .request(Method::GET, self.config.credentials_endpoint(&self.table))
+            .build()?;
+        let http_response = self.client.query_catalog(request).await?;
+
+        match http_response.status() {
+            StatusCode::OK => {
+                let response: LoadCredentialsResponse =
+                    deserialize_catalog_response(http_response).await?;
+                Ok(to_storage_credentials(response.storage_credentials))
+            }
+            _ => Err(deserialize_unexpected_catalog_error(
+                http_response,
+                self.client.disable_header_redaction(),
+            )
+            .await),
+        }
+    }
+}
+
+/// Converts the storage credentials of a REST response.
+pub(crate) fn to_storage_credentials(
+    credentials: Vec<types::StorageCredential>,
+) -> Vec<StorageCredential> {
+    credentials
+        .into_iter()
+        .map(|credential| StorageCredential::new(credential.prefix, credential.config))
+        .collect()
+}
@@ -53,6 +53,11 @@
 
 mod catalog;
 mod client;
+mod credentials;
+mod metrics;
+mod scan;
+#[cfg(feature = "sigv4")]
+mod sigv4;
 mod types;
 
 pub use catalog::*;
@@ -0,0 +1,286 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Reporting of table metrics to the REST catalog server.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use iceberg::metrics::{
+    CommitMetricsResult, CommitReport, MetricsReport, MetricsReporter, ScanMetricsResult,
+    ScanReport,
+};
+use iceberg::{Result, TableIdent};
+use reqwest::{Method, StatusCode};
+use serde_json::Value;
+
+use crate::RestCatalogConfig;
+use crate::client::{HttpClient, deserialize_unexpected_catalog_error};
+use crate::scan::expression_to_json;
+use crate::types::{
+    CommitReportModel, CounterResult, MetricResult, ReportMetricsRequest, ScanReportModel,
+    TimerResult,
+};
+
+/// Endpoint advertised in the config response by servers that accept metrics reports.
+pub(crate) const REPORT_METRICS_ENDPOINT: &str =
+    "POST /v1/{prefix}/namespaces/{namespace}/tables/{table}/metrics";
+
+/// A [`MetricsReporter`] posting reports to the REST catalog server.
+///
+/// Failures to report are logged and otherwise ignored.
+#[derive(Debug, Clone)]
+pub(crate) struct RestMetricsReporter {
+    client: Arc<HttpClient>,
+    config: Arc<RestCatalogConfig>,
+}
+
+impl RestMetricsReporter {
+    pub(crate) fn new(client: Arc<HttpClient>, config: Arc<RestCatalogConfig>) -> Self {
+        Self { client, config }
+    }
+
+    async fn send(&self, report: MetricsReport) -> Result<()> {
+        let (table, request) = match report {
+            MetricsReport::Scan(report) => (report.table.clone(), scan_report_request(report)?),
+            MetricsReport::Commit(report) => (report.table.clone(), commit_report_request(report)),
+        };
+
+        let http_request = self
+            .client
+            .request(Method::POST, self.config.metrics_endpoint(&table))
+            .json(&request)
+            .build()?;
+        let http_response = self.client.query_catalog(http_request).await?;
+
+        match http_response.status() {
+            StatusCode::NO_CONTENT | StatusCode::OK => Ok(()),
+            _ => Err(deserialize_unexpected_catalog_error(
+                http_response,
+                self.client.disable_header_redaction(),
+            )
+            .await),
+        }
+    }
+}
+
+#[async_trait]
+impl MetricsReporter for RestMetricsReporter {
+    async fn report(&self, report: MetricsReport) {
+        if let Err(e) = self.send(report).await {
+            tracing::warn!("Failed to report metrics to the REST catalog: {e}");
+        }
+    }
+}
+
+fn scan_report_request(report: ScanReport) -> Result<ReportMetricsRequest> {
+    let ScanReport {
+        table,
+        snapshot_id,
+        filter,
+        schema_id,
+        projected_field_ids,
+        projected_field_names,
+        metrics,
+    } = report;
+
+    Ok(ReportMetricsRequest::ScanReport(ScanReportModel {
+        table_name: table_name(&table),
+        snapshot_id,
+        filter: match filter {
+            Some(filter) => expression_to_json(&filter)?,
+            None => Value::Bool(true),
+        },
+        schema_id,
+        projected_field_ids,
+        projected_field_names,
+        metrics: scan_metrics(metrics),
+        metadata: HashMap::new(),
+    }))
+}
+
+fn commit_report_request(report: CommitReport) -> ReportMetricsRequest {
+    let CommitReport {
+        table,
+        snapshot_id,
+        sequence_number,
+        operation,
+        metrics,
+    } = report;
+
+    ReportMetricsRequest::CommitReport(CommitReportModel {
+        table_name: table_name(&table),
+        snapshot_id,
+        sequence_number,
+        operation,
+        metrics: commit_metrics(metrics),
+        metadata: HashMap::new(),
+    })
+}
+
+fn table_name(table: &TableIdent) -> String {
+    format!("{}.{}", table.namespace.join("."), table.name)
+}
+
+fn scan_metrics(metrics: ScanMetricsResult) -> HashMap<String, MetricResult> {
+    HashMap::from([
+        timer("total-planning-duration", metrics.total_planning_duration),
+        counter("result-data-files", metrics.result_data_files),
+        counter("result-delete-files", metrics.result_delete_files),
+        counter("total-data-manifests", metrics.total_data_manifests),
+        counter("total-delete-manifests", metrics.total_delete_manifests),
+        counter("scanned-data-manifests", metrics.scanned_data_manifests),
+        counter("skipped-data-manifests", metrics.skipped_data_manifests),
+        counter("scanned-delete-manifests", metrics.scanned_delete_manifests),
+        counter("skipped-delete-manifests", metrics.skipped_delete_manifests),
+        counter("skipped-data-files", metrics.skipped_data_files),
+        counter("skipped-delete-files", metrics.skipped_delete_files),
+        bytes("total-file-size-in-bytes", metrics.total_file_size_in_bytes),
+        bytes(
+            "total-delete-file-size-in-bytes",
+            metrics.total_delete_file_size_in_bytes,
+        ),
+    ])
+}
+
+fn commit_metrics(metrics: CommitMetricsResult) -> HashMap<String, MetricResult> {
+    let counters = [
+        ("added-data-files", metrics.added_data_files),
+        ("removed-data-files", metrics.removed_data_files),
+        ("total-data-files", metrics.total_data_files),
+        ("added-delete-files", metrics.added_delete_files),
+        ("removed-delete-files", metrics.removed_delete_files),
+        ("total-delete-files", metrics.total_delete_files),
+        ("added-records", metrics.added_records),
+        ("removed-records", metrics.removed_records),
+        ("total-records", metrics.total_records),
+    ];
+    let sizes = [
+        (
+            "added-files-size-in-bytes",
+            metrics.added_files_size_in_bytes,
+        ),
+        (
+            "removed-files-size-in-bytes",
+            metrics.removed_files_size_in_bytes,
+        ),
+        (
+            "total-files-size-in-bytes",
+            metrics.total_files_size_in_bytes,
+        ),
+    ];
+
+    [
+        timer("total-duration", metrics.total_duration),
+        counter("attempts", metrics.attempts),
+    ]
+    .into_iter()
+    .chain(
+        counters
+            .into_iter()
+            .filter_map(|(name, value)| Some(counter(name, value?))),
+    )
+    .chain(
+        sizes
+            .into_iter()
+            .filter_map(|(name, value)| Some(bytes(name, value?))),
+    )
+    .collect()
+}
+
+fn counter(name: &str, value: u64) -> (String, MetricResult) {
+    (
+        name.to_string(),
+        MetricResult::Counter(CounterResult {
+            unit: "count".to_string(),
+            value,
+        }),
+    )
+}
+
+fn bytes(name: &str, value: u64) -> (String, MetricResult) {
+    (
+        name.to_string(),
+        MetricResult::Counter(CounterResult {
+            unit: "bytes".to_string(),
+            value,
+        }),
+    )
+}
+
+fn timer(name: &str, duration: Duration) -> (String, MetricResult) {
+    (
+        name.to_string(),
+        MetricResult::Timer(TimerResult {
+            count: 1,
+            time_unit: "nanoseconds".to_string(),
+            total_duration: duration.as_nanos() as u64,
+        }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use iceberg::TableIdent;
+    use iceberg::metrics::{CommitMetricsResult, CommitReport};
+    use iceberg::spec::Operation;
+    use serde_json::json;
+
+    use super::commit_report_request;
+
+    #[test]
+    fn test_commit_report_request() {
+        let request = commit_report_request(CommitReport {
+            table: TableIdent::from_strs(["ns", "table"]).unwrap(),
+            snapshot_id: 1,
+            sequence_number: 2,
+            operation: Operation::Append,
+            metrics: CommitMetricsResult {
+                total_duration: Duration::from_millis(3),
+                attempts: 1,
+                added_data_files: Some(4),
+                added_files_size_in_bytes: Some(500),
+                ..Default::default()
+            },
+        });
+
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            json!({
+                "report-type": "commit-report",
+                "table-name": "ns.table",
+                "snapshot-id": 1,
+                "sequence-number": 2,
+                "operation": "append",
+                "metrics": {
+                    "total-duration": {
+                        "count": 1,
+                        "time-unit": "nanoseconds",
+                        "total-duration": 3_000_000
+                    },
+                    "attempts": {"unit": "count", "value": 1},
+                    "added-data-files": {"unit": "count", "value": 4},
+                    "added-files-size-in-bytes": {"unit": "bytes", "value": 500}
+                }
+            })
+        );
+    }
+}
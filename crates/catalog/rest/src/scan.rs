@@ -0,0 +1,506 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Server-side scan planning.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use futures::{StreamExt, TryStreamExt, stream};
+use iceberg::expr::{Predicate, PredicateOperator};
+use iceberg::scan::{PlannedFile, PlannedFileStream, ScanPlanRequest, ScanPlanner};
+use iceberg::spec::{
+    DataContentType, DataFile, DataFileBuilder, Literal, PrimitiveType, Struct, TableMetadata, Type,
+};
+use iceberg::{Error, ErrorKind, Result, TableIdent};
+use reqwest::{Method, Request, StatusCode};
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+
+use crate::RestCatalogConfig;
+use crate::client::{
+    HttpClient, deserialize_catalog_response, deserialize_unexpected_catalog_error,
+};
+use crate::types::{
+    ContentFileModel, FetchScanTasksRequest, FetchScanTasksResult, FileContent, FileScanTaskModel,
+    PlanStatus, PlanTableScanRequest, PlanTableScanResult,
+};
+
+/// Endpoint advertised in the config response by servers that plan table scans.
+pub(crate) const PLAN_TABLE_SCAN_ENDPOINT: &str =
+    "POST /v1/{prefix}/namespaces/{namespace}/tables/{table}/plan";
+
+/// Endpoint advertised in the config response by servers that cancel submitted scan plans.
+pub(crate) const CANCEL_PLANNING_ENDPOINT: &str =
+    "DELETE /v1/{prefix}/namespaces/{namespace}/tables/{table}/plan/{plan-id}";
+
+/// Initial interval between two polls of a plan submitted for asynchronous planning.
+const MIN_POLL_INTERVAL: Duration = Duration::from_millis(100);
+/// Maximum interval between two polls of a plan submitted for asynchronous planning.
+const MAX_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// A [`ScanPlanner`] planning scans on the REST catalog server.
+///
+/// Planning starts with a plan request. If the server plans asynchronously, the plan is
+/// polled until it completes or the planning timeout elapses. The file scan tasks of the plan
+/// are then streamed, fetching the file scan tasks of each plan task of the plan when they are
+/// reached.
+///
+/// A submitted plan that is abandoned, because planning timed out or the planning future was
+/// dropped, is cancelled on the server if it supports cancelling plans.
+#[derive(Debug, Clone)]
+pub(crate) struct RestScanPlanner {
+    client: Arc<HttpClient>,
+    config: Arc<RestCatalogConfig>,
+    cancel_supported: bool,
+}
+
+impl RestScanPlanner {
+    pub(crate) fn new(
+        client: Arc<HttpClient>,
+        config: Arc<RestCatalogConfig>,
+        cancel_supported: bool,
+    ) -> Self {
+        Self {
+            client,
+            config,
+            cancel_supported,
+        }
+    }
+
+    async fn query<R: DeserializeOwned>(&self, request: Request) -> Result<R> {
+        let http_response = self.client.query_catalog(request).await?;
+
+        match http_response.status() {
+            StatusCode::OK => deserialize_catalog_response(http_response).await,
+            StatusCode::NOT_FOUND => Err(Error::new(
+                ErrorKind::Unexpected,
+                "Tried to plan a scan of a table or plan that does not exist",
+            )),
+            _ => Err(deserialize_unexpected_catalog_error(
+                http_response,
+                self.client.disable_header_redaction(),
+            )
+            .await),
+        }
+    }
+
+    /// Plans the scan, waiting for the plan to complete if it's submitted.
+    async fn plan(
+        &self,
+        table: &TableIdent,
+        request: &PlanTableScanRequest,
+    ) -> Result<PlanTableScanResult> {
+        let timeout = self.config.scan_planning_timeout()?;
+        let deadline = Instant::now() + timeout;
+
+        let http_request = self
+            .client
+            .request(Method::POST, self.config.plan_table_scan_endpoint(table))
+            .json(request)
+            .build()?;
+        let mut result: PlanTableScanResult = self.query(http_request).await?;
+
+        let mut cancellation = None;
+        let mut poll_interval = MIN_POLL_INTERVAL;
+        while result.status == PlanStatus::Submitted {
+            let plan_id = result.plan_id.as_deref().ok_or_else(|| {
+                Error::new(
+                    ErrorKind::Unexpected,
+                    "Server submitted a scan plan without a plan id",
+                )
+            })?;
+            if self.cancel_supported && cancellation.is_none() {
+                cancellation = Some(PlanCancellation::new(self, table, plan_id));
+            }
+
+            let now = Instant::now();
+            if now >= deadline {
+                if let Some(cancellation) = cancellation.take() {
+                    cancellation.cancel().await;
+                }
+                return Err(Error::new(
+                    ErrorKind::Unexpected,
+                    format!("Scan planning did not complete within {timeout:?}"),
+                )
+                .with_context("plan-id", plan_id));
+            }
+            tokio::time::sleep(poll_interval.min(deadline - now)).await;
+            poll_interval = (poll_interval * 2).min(MAX_POLL_INTERVAL);
+
+            let http_request = self
+                .client
+                .request(Method::GET, self.config.plan_endpoint(table, plan_id))
+                .build()?;
+            result = self.query(http_request).await?;
+        }
+
+        // The plan has ended, there is nothing left to cancel.
+        if let Some(cancellation) = cancellation {
+            cancellation.disarm();
+        }
+
+        match result.status {
+            PlanStatus::Completed => Ok(result),
+            PlanStatus::Failed => Err(match result.error {
+                Some(error) => error.into(),
+                None => Error::new(ErrorKind::Unexpected, "Scan planning failed"),
+            }),
+            PlanStatus::Cancelled => Err(Error::new(
+                ErrorKind::Unexpected,
+                "Scan planning was cancelled",
+            )),
+            PlanStatus::Submitted => unreachable!("submitted plans are polled until they end"),
+        }
+    }
+
+    /// Cancels a submitted plan.
+    async fn cancel_plan(&self, table: &TableIdent, plan_id: &str) -> Result<()> {
+        let http_request = self
+            .client
+            .request(Method::DELETE, self.config.plan_endpoint(table, plan_id))
+            .build()?;
+        let http_response = self.client.query_catalog(http_request).await?;
+
+        match http_response.status() {
+            StatusCode::NO_CONTENT | StatusCode::OK => Ok(()),
+            _ => Err(deserialize_unexpected_catalog_error(
+                http_response,
+                self.client.disable_header_redaction(),
+            )
+            .await),
+        }
+    }
+
+    async fn fetch_scan_tasks(
+        &self,
+        table: &TableIdent,
+        plan_task: String,
+    ) -> Result<FetchScanTasksResult> {
+        let http_request = self
+            .client
+            .request(Method::POST, self.config.fetch_scan_tasks_endpoint(table))
+            .json(&FetchScanTasksRequest { plan_task })
+            .build()?;
+        self.query(http_request).await
+    }
+}
+
+/// Cancels a submitted plan unless it's disarmed once the plan has ended.
+///
+/// If it's dropped while armed, for instance because the planning future was dropped, the
+/// plan is cancelled in the background.
+struct PlanCancellation {
+    plan: Option<(RestScanPlanner, TableIdent, String)>,
+}
+
+impl PlanCancellation {
+    fn new(planner: &RestScanPlanner, table: &TableIdent, plan_id: &str) -> Self {
+        Self {
+            plan: Some((planner.clone(), table.clone(), plan_id.to_string())),
+        }
+    }
+
+    /// Cancels the plan, logging failures since the plan is abandoned either way.
+    async fn cancel(mut self) {
+        if let Some((planner, table, plan_id)) = self.plan.take() {
+            Self::cancel_plan(planner, table, plan_id).await;
+        }
+    }
+
+    fn disarm(mut self) {
+        self.plan = None;
+    }
+
+    async fn cancel_plan(planner: RestScanPlanner, table: TableIdent, plan_id: String) {
+        if let Err(e) = planner.cancel_plan(&table, &plan_id).await {
+            tracing::warn!("Failed to cancel scan plan {plan_id} of table {table}: {e}");
+        }
+    }
+}
+
+impl Drop for PlanCancellation {
+    fn drop(&mut self) {
+        let Some((planner, table, plan_id)) = self.plan.take() else {
+            return;
+        };
+        match tokio::runtime::Handle::try_current() {
+            Ok(handle) => {
+                handle.spawn(Self::cancel_plan(planner, table, plan_id));
+            }
+            Err(_) => tracing::warn!(
+                "Cannot cancel scan plan {plan_id} of table {table} outside of a Tokio runtime"
+            ),
+        }
+    }
+}
+
+#[async_trait]
+impl ScanPlanner for RestScanPlanner {
+    async fn plan_scan(&self, request: ScanPlanRequest) -> Result<PlannedFileStream> {
+        let ScanPlanRequest {
+            table,
+            metadata,
+            snapshot_id,
+            select,
+            filter,
+            case_sensitive,
+        } = request;
+
+        let plan_request = PlanTableScanRequest {
+            snapshot_id: Some(snapshot_id),
+            select,
+            // Filters that can't be sent to the server, such as geospatial ones, are still
+            // applied when reading the planned files.
+            filter: filter
+                .as_ref()
+                .and_then(|filter| expression_to_json(filter).ok()),
+            case_sensitive,
+        };
+        let result = self.plan(&table, &plan_request).await?;
+
+        let files = planned_files(&metadata, result.file_scan_tasks, result.delete_files)?;
+
+        let plan_tasks = VecDeque::from(result.plan_tasks);
+        let planner = self.clone();
+        let fetched_files = stream::try_unfold(plan_tasks, move |mut plan_tasks| {
+            let planner = planner.clone();
+            let table = table.clone();
+            let metadata = metadata.clone();
+            async move {
+                let Some(plan_task) = plan_tasks.pop_front() else {
+                    return Ok::<_, Error>(None);
+                };
+                let result = planner.fetch_scan_tasks(&table, plan_task).await?;
+                plan_tasks.extend(result.plan_tasks);
+
+                let files = planned_files(&metadata, result.file_scan_tasks, result.delete_files)?;
+                Ok(Some((stream::iter(files.into_iter().map(Ok)), plan_tasks)))
+            }
+        })
+        .try_flatten();
+
+        Ok(stream::iter(files.into_iter().map(Ok))
+            .chain(fetched_files)
+            .boxed())
+    }
+}
+
+/// Converts the file scan tasks of a response to [`PlannedFile`]s.
+fn planned_files(
+    metadata: &TableMetadata,
+    file_scan_tasks: Vec<FileScanTaskModel>,
+    delete_files: Vec<ContentFileModel>,
+) -> Result<Vec<PlannedFile>> {
+    let delete_files = delete_files
+        .into_iter()
+        .map(|delete_file| data_file(metadata, delete_file))
+        .collect::<Result<Vec<_>>>()?;
+
+    file_scan_tasks
+        .into_iter()
+        .map(|task| {
+            let delete_files = task
+                .delete_file_references
+                .iter()
+                .map(|&reference| {
+                    delete_files.get(reference).cloned().ok_or_else(|| {
+                        Error::new(
+                            ErrorKind::Unexpected,
+                            format!("File scan task references unknown delete file {reference}"),
+                        )
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            let data_sequence_number = task.data_file.data_sequence_number;
+            Ok(PlannedFile {
+                data_file: data_file(metadata, task.data_file)?,
+                delete_files,
+                data_sequence_number,
+            })
+        })
+        .collect()
+}
+
+fn data_file(metadata: &TableMetadata, content_file: ContentFileModel) -> Result<DataFile> {
+    let partition_spec = metadata
+        .partition_spec_by_id(content_file.spec_id)
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::Unexpected,
+                format!(
+                    "Partition spec {} of file {} not found",
+                    content_file.spec_id, content_file.file_path
+                ),
+            )
+        })?;
+    let partition_type = partition_spec.partition_type(metadata.current_schema())?;
+    if partition_type.fields().len() != content_file.partition.len() {
+        return Err(Error::new(
+            ErrorKind::Unexpected,
+            format!(
+                "File {} has {} partition values, but its partition spec has {} fields",
+                content_file.file_path,
+                content_file.partition.len(),
+                partition_type.fields().len()
+            ),
+        ));
+    }
+    let partition = partition_type
+        .fields()
+        .iter()
+        .zip(content_file.partition)
+        .map(|(field, value)| Literal::try_from_json(value, &field.field_type))
+        .collect::<Result<Vec<_>>>()?;
+
+    DataFileBuilder::default()
+        .content(match content_file.content {
+            FileContent::Data => DataContentType::Data,
+            FileContent::PositionDeletes => DataContentType::PositionDeletes,
+            FileContent::EqualityDeletes => DataContentType::EqualityDeletes,
+        })
+        .file_path(content_file.file_path)
+        .file_format(content_file.file_format)
+        .partition(Struct::from_iter(partition))
+        .partition_spec_id(content_file.spec_id)
+        .file_size_in_bytes(content_file.file_size_in_bytes)
+        .record_count(content_file.record_count)
+        .equality_ids(content_file.equality_ids)
+        .first_row_id(content_file.first_row_id)
+        .referenced_data_file(content_file.referenced_data_file)
+        .content_offset(content_file.content_offset)
+        .content_size_in_bytes(content_file.content_size_in_bytes)
+        .build()
+        .map_err(|e| {
+            Error::new(
+                ErrorKind::Unexpected,
+                format!("Failed to build data file: {e}"),
+            )
+        })
+}
+
+/// Serializes a predicate in the JSON expression format of the REST API.
+pub(crate) fn expression_to_json(predicate: &Predicate) -> Result<Value> {
+    Ok(match predicate {
+        Predicate::AlwaysTrue => Value::Bool(true),
+        Predicate::AlwaysFalse => Value::Bool(false),
+        Predicate::And(expr) => {
+            let [left, right] = expr.inputs();
+            json!({
+                "type": "and",
+                "left": expression_to_json(left)?,
+                "right": expression_to_json(right)?,
+            })
+        }
+        Predicate::Or(expr) => {
+            let [left, right] = expr.inputs();
+            json!({
+                "type": "or",
+                "left": expression_to_json(left)?,
+                "right": expression_to_json(right)?,
+            })
+        }
+        Predicate::Not(expr) => {
+            let [child] = expr.inputs();
+            json!({
+                "type": "not",
+                "child": expression_to_json(child)?,
+            })
+        }
+        Predicate::Unary(expr) => json!({
+            "type": operator_name(expr.op())?,
+            "term": expr.term().name(),
+        }),
+        Predicate::Binary(expr) => json!({
+            "type": operator_name(expr.op())?,
+            "term": expr.term().name(),
+            "value": datum_to_json(expr.literal())?,
+        }),
+        Predicate::Set(expr) => json!({
+            "type": operator_name(expr.op())?,
+            "term": expr.term().name(),
+            "values": expr
+                .literals()
+                .iter()
+                .map(datum_to_json)
+                .collect::<Result<Vec<_>>>()?,
+        }),
+    })
+}
+
+fn operator_name(op: PredicateOperator) -> Result<&'static str> {
+    Ok(match op {
+        PredicateOperator::IsNull => "is-null",
+        PredicateOperator::NotNull => "not-null",
+        PredicateOperator::IsNan => "is-nan",
+        PredicateOperator::NotNan => "not-nan",
+        PredicateOperator::LessThan => "lt",
+        PredicateOperator::LessThanOrEq => "lt-eq",
+        PredicateOperator::GreaterThan => "gt",
+        PredicateOperator::GreaterThanOrEq => "gt-eq",
+        PredicateOperator::Eq => "eq",
+        PredicateOperator::NotEq => "not-eq",
+        PredicateOperator::StartsWith => "starts-with",
+        PredicateOperator::NotStartsWith => "not-starts-with",
+        PredicateOperator::In => "in",
+        PredicateOperator::NotIn => "not-in",
+        _ => {
+            return Err(Error::new(
+                ErrorKind::FeatureUnsupported,
+                format!("Operator {op} is not supported by the REST scan planning API"),
+            ));
+        }
+    })
+}
+
+fn datum_to_json(datum: &iceberg::spec::Datum) -> Result<Value> {
+    let r#type = Type::Primitive(PrimitiveType::clone(datum.data_type()));
+    Literal::from(datum.clone()).try_into_json(&r#type)
+}
+
+#[cfg(test)]
+mod tests {
+    use iceberg::expr::Reference;
+    use iceberg::spec::Datum;
+    use serde_json::json;
+
+    use super::expression_to_json;
+
+    #[test]
+    fn test_expression_to_json() {
+        let predicate = Reference::new("id")
+            .greater_than_or_equal_to(Datum::long(10))
+            .and(!Reference::new("name").is_null())
+            .or(Reference::new("day").is_in([Datum::date_from_str("2024-01-02").unwrap()]));
+
+        assert_eq!(
+            expression_to_json(&predicate).unwrap(),
+            json!({
+                "type": "or",
+                "left": {
+                    "type": "and",
+                    "left": {"type": "gt-eq", "term": "id", "value": 10},
+                    "right": {"type": "not", "child": {"type": "is-null", "term": "name"}}
+                },
+                "right": {"type": "in", "term": "day", "values": ["2024-01-02"]}
+            })
+        );
+    }
+}
@@ -0,0 +1,350 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Signing of requests with AWS Signature Version 4.
+
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use aws_config::default_provider::credentials::DefaultCredentialsChain;
+use aws_credential_types::Credentials;
+use aws_credential_types::provider::{ProvideCredentials, SharedCredentialsProvider};
+use aws_sigv4::http_request::{SignableBody, SignableRequest, SigningSettings, sign};
+use aws_sigv4::sign::v4;
+use http::HeaderValue;
+use http::header::AUTHORIZATION;
+use iceberg::{Error, ErrorKind, Result};
+use reqwest::Request;
+use tokio::sync::{Mutex, OnceCell};
+
+/// Header the authorization of the catalog, such as an OAuth2 token, is moved to when
+/// signing, as the signature replaces the `Authorization` header.
+const ORIGINAL_AUTHORIZATION_HEADER: &str = "original-authorization";
+/// Cached credentials are reloaded this long before they expire.
+const CREDENTIALS_EXPIRY_BUFFER: Duration = Duration::from_secs(5 * 60);
+
+/// Signs requests with AWS Signature Version 4.
+#[derive(Clone)]
+pub(crate) struct SigV4Signer {
+    region: String,
+    service: String,
+    credentials: Arc<CachedCredentials>,
+}
+
+impl Debug for SigV4Signer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SigV4Signer")
+            .field("region", &self.region)
+            .field("service", &self.service)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SigV4Signer {
+    /// Creates a signer using the given static credentials, or else the credentials of the
+    /// default AWS credentials provider chain.
+    pub(crate) fn new(region: String, service: String, credentials: Option<Credentials>) -> Self {
+        Self {
+            region,
+            service,
+            credentials: Arc::new(CachedCredentials {
+                provider: OnceCell::new_with(credentials.map(SharedCredentialsProvider::new)),
+                credentials: Mutex::new(None),
+            }),
+        }
+    }
+
+    /// Signs the request, moving its `Authorization` header, if any, to the
+    /// `Original-Authorization` header.
+    pub(crate) async fn sign(&self, request: &mut Request) -> Result<()> {
+        let credentials = self.credentials.get().await?;
+        self.sign_at(request, &credentials, SystemTime::now())
+    }
+
+    fn sign_at(
+        &self,
+        request: &mut Request,
+        credentials: &Credentials,
+        time: SystemTime,
+    ) -> Result<()> {
+        let headers = request.headers_mut();
+        if let Some(authorization) = headers.remove(AUTHORIZATION) {
+            headers.insert(ORIGINAL_AUTHORIZATION_HEADER, authorization);
+        }
+
+        let body = match request.body() {
+            Some(body) => body.as_bytes().ok_or_else(|| {
+                Error::new(
+                    ErrorKind::FeatureUnsupported,
+                    "Cannot sign request with a streaming body",
+                )
+            })?,
+            None => &[],
+        };
+        let headers = request
+            .headers()
+            .iter()
+            .map(|(name, value)| {
+                let value = value.to_str().map_err(|e| {
+                    Error::new(
+                        ErrorKind::DataInvalid,
+                        format!("Cannot sign request with non-ASCII header {name}"),
+                    )
+                    .with_source(e)
+                })?;
+                Ok((name.as_str(), value))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let identity = credentials.clone().into();
+        let params = v4::SigningParams::builder()
+            .identity(&identity)
+            .region(&self.region)
+            .name(&self.service)
+            .time(time)
+            .settings(SigningSettings::default())
+            .build()
+            .map_err(signing_error)?
+            .into();
+        let signable_request = SignableRequest::new(
+            request.method().as_str(),
+            request.url().as_str(),
+            headers.into_iter(),
+            SignableBody::Bytes(body),
+        )
+        .map_err(signing_error)?;
+        let (instructions, _) = sign(signable_request, &params)
+            .map_err(signing_error)?
+            .into_parts();
+
+        let (headers, _) = instructions.into_parts();
+        for header in headers {
+            request
+                .headers_mut()
+                .insert(header.name(), header_value(header.value())?);
+        }
+
+        Ok(())
+    }
+}
+
+/// Credentials loaded from a provider, cached until they are about to expire.
+struct CachedCredentials {
+    /// The provider of the credentials, initialized with the default chain on first use
+    /// unless static credentials are configured.
+    provider: OnceCell<SharedCredentialsProvider>,
+    credentials: Mutex<Option<Credentials>>,
+}
+
+impl CachedCredentials {
+    async fn get(&self) -> Result<Credentials> {
+        let mut cached = self.credentials.lock().await;
+        if let Some(credentials) = cached.as_ref()
+            && credentials
+                .expiry()
+                .is_none_or(|expiry| SystemTime::now() + CREDENTIALS_EXPIRY_BUFFER < expiry)
+        {
+            return Ok(credentials.clone());
+        }
+
+        let provider = self
+            .provider
+            .get_or_init(|| async {
+                SharedCredentialsProvider::new(DefaultCredentialsChain::builder().build().await)
+            })
+            .await;
+        let credentials = provider.provide_credentials().await.map_err(|e| {
+            Error::new(
+                ErrorKind::DataInvalid,
+                "Failed to load AWS credentials to sign requests with",
+            )
+            .with_source(e)
+        })?;
+        *cached = Some(credentials.clone());
+        Ok(credentials)
+    }
+}
+
+fn signing_error(e: impl std::error::Error + Send + Sync + 'static) -> Error {
+    Error::new(
+        ErrorKind::Unexpected,
+        "Failed to sign request with AWS SigV4",
+    )
+    .with_source(e)
+}
+
+fn header_value(value: &str) -> Result<HeaderValue> {
+    value.parse().map_err(|e| {
+        Error::new(
+            ErrorKind::DataInvalid,
+            "Invalid header value for a signed request",
+        )
+        .with_source(e)
+    })
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use aws_credential_types::Credentials;
+    use chrono::NaiveDateTime;
+    use reqwest::{Client, Method, Request};
+
+    use super::SigV4Signer;
+
+    /// Access key id of the AWS Signature Version 4 test suite.
+    pub(crate) const TEST_ACCESS_KEY_ID: &str = "AKIDEXAMPLE";
+    /// Secret access key of the AWS Signature Version 4 test suite.
+    pub(crate) const TEST_SECRET_ACCESS_KEY: &str = "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY";
+
+    fn test_credentials(session_token: Option<&str>) -> Credentials {
+        Credentials::new(
+            TEST_ACCESS_KEY_ID,
+            TEST_SECRET_ACCESS_KEY,
+            session_token.map(str::to_string),
+            None,
+            "test",
+        )
+    }
+
+    /// 2015-08-30T12:36:00Z, the signing time of the test suite.
+    fn test_time() -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(1440938160)
+    }
+
+    /// Returns the `Authorization` header of the given request signed with the test
+    /// credentials at `amz_date`, the time of the `x-amz-date` header.
+    ///
+    /// This lets a test server check the signature of the requests it receives.
+    pub(crate) fn test_authorization(
+        signer: &SigV4Signer,
+        method: &str,
+        url: &str,
+        headers: &[(&str, &str)],
+        body: &[u8],
+        amz_date: &str,
+    ) -> Option<String> {
+        let time = NaiveDateTime::parse_from_str(amz_date, "%Y%m%dT%H%M%SZ").ok()?;
+        let time = UNIX_EPOCH + Duration::from_secs(time.and_utc().timestamp().try_into().ok()?);
+        let mut request = Client::new()
+            .request(Method::from_bytes(method.as_bytes()).ok()?, url)
+            .body(body.to_vec());
+        for (name, value) in headers {
+            request = request.header(*name, *value);
+        }
+        let mut request = request.build().ok()?;
+
+        signer
+            .sign_at(&mut request, &test_credentials(None), time)
+            .ok()?;
+        Some(
+            request.headers()["authorization"]
+                .to_str()
+                .ok()?
+                .to_string(),
+        )
+    }
+
+    fn sign(signer: &SigV4Signer, request: &mut Request, credentials: &Credentials) -> String {
+        signer.sign_at(request, credentials, test_time()).unwrap();
+        request.headers()["authorization"]
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn test_sign_test_suite_requests() {
+        let signer = SigV4Signer::new("us-east-1".to_string(), "service".to_string(), None);
+        let credentials = test_credentials(None);
+
+        for (method, url, signature) in [
+            (
+                Method::GET,
+                "https://example.amazonaws.com/",
+                "5fa00fa31553b73ebf1942676e86291e8372ff2a2260956d9b8aae1d763fbf31",
+            ),
+            (
+                Method::POST,
+                "https://example.amazonaws.com/",
+                "5da7c1a2acd57cee7505fc6676e4e544621c30862966e37dddb68e92efbe5d6b",
+            ),
+            (
+                Method::GET,
+                "https://example.amazonaws.com/?Param2=value2&Param1=value1",
+                "b97d918cfa904a5beff61c982a1b6f458b799221646efd99d3219ec94cdf2500",
+            ),
+        ] {
+            let mut request = Client::new().request(method, url).build().unwrap();
+            assert_eq!(
+                sign(&signer, &mut request, &credentials),
+                format!(
+                    "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/service/\
+                     aws4_request, SignedHeaders=host;x-amz-date, Signature={signature}"
+                ),
+                "{url}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_sign_catalog_request() {
+        let signer = SigV4Signer::new("us-east-1".to_string(), "glue".to_string(), None);
+        let mut request = Client::new()
+            .post("http://localhost:8181/v1/namespaces/a%1Fb")
+            .bearer_auth("oauth")
+            .body("{}")
+            .build()
+            .unwrap();
+
+        let authorization = sign(&signer, &mut request, &test_credentials(Some("token")));
+
+        assert_eq!(
+            authorization,
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/glue/aws4_request, \
+             SignedHeaders=host;original-authorization;x-amz-date;x-amz-security-token, \
+             Signature=0f4e0b6e0a5e5a6e629b3cd5255327cf62b150aed1734de15f84aa48ae9dc1dc"
+        );
+        assert_eq!(request.headers()["original-authorization"], "Bearer oauth");
+        assert_eq!(request.headers()["x-amz-date"], "20150830T123600Z");
+        assert_eq!(request.headers()["x-amz-security-token"], "token");
+    }
+
+    #[tokio::test]
+    async fn test_sign_with_static_credentials() {
+        let signer = SigV4Signer::new(
+            "us-east-1".to_string(),
+            "glue".to_string(),
+            Some(test_credentials(None)),
+        );
+        let mut request = Client::new()
+            .get("http://localhost:8181/v1/config")
+            .build()
+            .unwrap();
+
+        signer.sign(&mut request).await.unwrap();
+
+        assert!(
+            request.headers()["authorization"]
+                .to_str()
+                .unwrap()
+                .starts_with("AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/")
+        );
+    }
+}
@@ -19,22 +19,33 @@
 
 use std::collections::HashMap;
 
-use iceberg::spec::{Schema, SortOrder, TableMetadata, UnboundPartitionSpec};
+use iceberg::spec::{
+    DataFileFormat, Operation, Schema, SortOrder, TableMetadata, UnboundPartitionSpec,
+    ViewMetadata, ViewVersion,
+};
 use iceberg::{
     Error, ErrorKind, Namespace, NamespaceIdent, TableIdent, TableRequirement, TableUpdate,
+    ViewRequirement, ViewUpdate,
 };
 use serde_derive::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
-pub(super) struct CatalogConfig {
-    pub(super) overrides: HashMap<String, String>,
-    pub(super) defaults: HashMap<String, String>,
+/// Configuration returned by the server, merged into the configuration of clients
+pub struct CatalogConfig {
+    /// Properties overriding the configuration of clients
+    pub overrides: HashMap<String, String>,
+    /// Default properties, overridden by the configuration of clients
+    pub defaults: HashMap<String, String>,
+    /// Endpoints supported by the server, in the form `<METHOD> <path>`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub endpoints: Option<Vec<String>>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 /// Wrapper for all non-2xx error responses from the REST API
 pub struct ErrorResponse {
-    error: ErrorModel,
+    /// Details of the error
+    pub error: ErrorModel,
 }
 
 impl From<ErrorResponse> for Error {
@@ -239,6 +250,14 @@ pub struct StorageCredential {
     pub config: HashMap<String, String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+/// Response of the endpoint loading the vended storage credentials of a table.
+pub struct LoadCredentialsResponse {
+    /// Storage credentials for accessing table data.
+    pub storage_credentials: Vec<StorageCredential>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "kebab-case")]
 /// Request to create a new table in a namespace.
@@ -285,6 +304,14 @@ pub struct CommitTableRequest {
     pub updates: Vec<TableUpdate>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+/// Request to commit updates to multiple tables atomically.
+pub struct CommitTransactionRequest {
+    /// Changes of each table, which must all have an identifier
+    pub table_changes: Vec<CommitTableRequest>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "kebab-case")]
 /// Response returned when a table is successfully updated.
@@ -311,6 +338,283 @@ pub struct RegisterTableRequest {
     pub overwrite: Option<bool>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+/// Request to create a new view in a namespace.
+pub struct CreateViewRequest {
+    /// Name of the view to create
+    pub name: String,
+    /// Optional view location. If not provided, the server will choose a location.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    /// View schema
+    pub schema: Schema,
+    /// The initial version of the view
+    pub view_version: ViewVersion,
+    /// Properties to set on the view
+    #[serde(default)]
+    pub properties: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+/// Result returned when a view is successfully loaded, created or updated.
+///
+/// The view metadata JSON is returned in the `metadata` field. The corresponding file location
+/// of view metadata is returned in the `metadata_location` field.
+pub struct LoadViewResult {
+    /// Location of the view metadata file
+    pub metadata_location: String,
+    /// The view's full metadata
+    pub metadata: ViewMetadata,
+    /// View-specific configuration overriding catalog configuration
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub config: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+/// Request to commit updates to a view.
+///
+/// Like table commits, view commits consist of requirements, which are validated before
+/// making any change, and updates to the view metadata.
+pub struct CommitViewRequest {
+    /// View identifier to update
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identifier: Option<TableIdent>,
+    /// List of requirements that must be satisfied before committing changes
+    pub requirements: Vec<ViewRequirement>,
+    /// List of updates to apply to the view metadata
+    pub updates: Vec<ViewUpdate>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+/// Request to plan a table scan on the server.
+pub struct PlanTableScanRequest {
+    /// The snapshot to scan. The current snapshot is scanned if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snapshot_id: Option<i64>,
+    /// The columns to read. All columns are read if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub select: Option<Vec<String>>,
+    /// Filter expression of the scan, in the JSON expression format of the REST API.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<serde_json::Value>,
+    /// Whether column names are case-sensitive.
+    pub case_sensitive: bool,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+/// Status of a scan plan.
+pub enum PlanStatus {
+    /// Planning is complete.
+    Completed,
+    /// Planning is running asynchronously; its result must be fetched later.
+    Submitted,
+    /// Planning was cancelled.
+    Cancelled,
+    /// Planning failed.
+    Failed,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+/// Result of planning a table scan, also returned when fetching the result of a scan plan
+/// submitted for asynchronous planning.
+pub struct PlanTableScanResult {
+    /// Status of the plan
+    pub status: PlanStatus,
+    /// Id of the plan, set when the plan is submitted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plan_id: Option<String>,
+    /// Opaque tasks to fetch more file scan tasks with
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub plan_tasks: Vec<String>,
+    /// Files to scan
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub file_scan_tasks: Vec<FileScanTaskModel>,
+    /// Delete files referenced by the file scan tasks
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub delete_files: Vec<ContentFileModel>,
+    /// Error of a failed plan
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<ErrorModel>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+/// Request to fetch the file scan tasks of a plan task.
+pub struct FetchScanTasksRequest {
+    /// The plan task, as returned by the server
+    pub plan_task: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+/// Result of fetching the file scan tasks of a plan task.
+pub struct FetchScanTasksResult {
+    /// More plan tasks to fetch file scan tasks with
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub plan_tasks: Vec<String>,
+    /// Files to scan
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub file_scan_tasks: Vec<FileScanTaskModel>,
+    /// Delete files referenced by the file scan tasks
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub delete_files: Vec<ContentFileModel>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+/// A data file to scan, with the delete files that apply to it.
+pub struct FileScanTaskModel {
+    /// The data file
+    pub data_file: ContentFileModel,
+    /// Positions of the delete files that apply to the data file in the `delete-files` of
+    /// the response
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub delete_file_references: Vec<usize>,
+    /// Filter expression that remains to be applied to the rows of the data file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub residual_filter: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+/// Type of content of a file.
+pub enum FileContent {
+    /// Data file
+    Data,
+    /// Position delete file
+    PositionDeletes,
+    /// Equality delete file
+    EqualityDeletes,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+/// A data or delete file.
+pub struct ContentFileModel {
+    /// Type of content of the file
+    pub content: FileContent,
+    /// Location of the file
+    pub file_path: String,
+    /// Format of the file
+    pub file_format: DataFileFormat,
+    /// Id of the partition spec of the file
+    pub spec_id: i32,
+    /// Partition values, in the JSON single-value format, in the order of the spec fields
+    #[serde(default)]
+    pub partition: Vec<serde_json::Value>,
+    /// Size of the file in bytes
+    pub file_size_in_bytes: u64,
+    /// Number of records in the file
+    pub record_count: u64,
+    /// Field ids used to determine row equality of an equality delete file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub equality_ids: Option<Vec<i32>>,
+    /// Id of the first row of a data file, for row lineage
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub first_row_id: Option<i64>,
+    /// Data sequence number of the file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data_sequence_number: Option<i64>,
+    /// Data file that all deletes of a delete file reference
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub referenced_data_file: Option<String>,
+    /// Offset of the deletion vector in the file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_offset: Option<i64>,
+    /// Length of the deletion vector in the file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_size_in_bytes: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "report-type", rename_all = "kebab-case")]
+/// Request to report the metrics of a table scan or commit.
+pub enum ReportMetricsRequest {
+    /// Metrics of a table scan
+    ScanReport(ScanReportModel),
+    /// Metrics of a commit
+    CommitReport(CommitReportModel),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+/// Metrics of planning a table scan.
+pub struct ScanReportModel {
+    /// Name of the scanned table
+    pub table_name: String,
+    /// Id of the scanned snapshot
+    pub snapshot_id: i64,
+    /// Filter expression of the scan, in the JSON expression format of the REST API
+    pub filter: serde_json::Value,
+    /// Id of the schema the scan is projected from
+    pub schema_id: i32,
+    /// Ids of the projected fields
+    pub projected_field_ids: Vec<i32>,
+    /// Names of the projected fields
+    pub projected_field_names: Vec<String>,
+    /// Metrics by name
+    pub metrics: HashMap<String, MetricResult>,
+    /// Additional information about the scan
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub metadata: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+/// Metrics of a commit creating a snapshot.
+pub struct CommitReportModel {
+    /// Name of the committed table
+    pub table_name: String,
+    /// Id of the snapshot created by the commit
+    pub snapshot_id: i64,
+    /// Sequence number of the snapshot
+    pub sequence_number: i64,
+    /// Operation of the snapshot
+    pub operation: Operation,
+    /// Metrics by name
+    pub metrics: HashMap<String, MetricResult>,
+    /// Additional information about the commit
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub metadata: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(untagged)]
+/// Value of a reported metric.
+pub enum MetricResult {
+    /// Value of a counter
+    Counter(CounterResult),
+    /// Value of a timer
+    Timer(TimerResult),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+/// Value of a counter.
+pub struct CounterResult {
+    /// Unit of the counter, such as `count` or `bytes`
+    pub unit: String,
+    /// Value of the counter
+    pub value: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+/// Value of a timer.
+pub struct TimerResult {
+    /// Number of timed events
+    pub count: u64,
+    /// Unit of the total duration, such as `nanoseconds`
+    pub time_unit: String,
+    /// Total duration of the timed events
+    pub total_duration: u64,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -355,4 +659,38 @@ mod tests {
             json_no_props
         );
     }
+
+    #[test]
+    fn test_commit_view_request_serde() {
+        let json = serde_json::json!({
+            "identifier": {"namespace": ["ns1"], "name": "view1"},
+            "requirements": [
+                {"type": "assert-view-uuid", "uuid": "fa6506c3-7681-40c8-86dc-e36561f83385"}
+            ],
+            "updates": [
+                {"action": "set-properties", "updates": {"comment": "replaced"}},
+                {"action": "set-current-view-version", "view-version-id": -1}
+            ]
+        });
+        let request: CommitViewRequest =
+            serde_json::from_value(json.clone()).expect("Deserialization failed");
+        assert_eq!(request, CommitViewRequest {
+            identifier: Some(TableIdent::from_strs(["ns1", "view1"]).unwrap()),
+            requirements: vec![ViewRequirement::UuidMatch {
+                uuid: uuid::uuid!("fa6506c3-7681-40c8-86dc-e36561f83385"),
+            }],
+            updates: vec![
+                ViewUpdate::SetProperties {
+                    updates: HashMap::from([("comment".to_string(), "replaced".to_string())]),
+                },
+                ViewUpdate::SetCurrentViewVersion {
+                    view_version_id: -1,
+                },
+            ],
+        });
+        assert_eq!(
+            serde_json::to_value(&request).expect("Serialization failed"),
+            json
+        );
+    }
 }
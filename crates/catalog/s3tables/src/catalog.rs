@@ -26,7 +26,9 @@ use aws_sdk_s3tables::operation::get_table::GetTableOutput;
 use aws_sdk_s3tables::operation::list_tables::ListTablesOutput;
 use aws_sdk_s3tables::operation::update_table_metadata_location::UpdateTableMetadataLocationError;
 use aws_sdk_s3tables::types::OpenTableFormat;
+use iceberg::encryption::KeyManagementClient;
 use iceberg::io::{FileIO, FileIOBuilder, StorageFactory};
+use iceberg::metrics::MetricsReporter;
 use iceberg::spec::{TableMetadata, TableMetadataBuilder};
 use iceberg::table::Table;
 use iceberg::{
@@ -70,6 +72,8 @@ struct S3TablesCatalogConfig {
 pub struct S3TablesCatalogBuilder {
     config: S3TablesCatalogConfig,
     storage_factory: Option<Arc<dyn StorageFactory>>,
+    key_management_client: Option<Arc<dyn KeyManagementClient>>,
+    metrics_reporter: Option<Arc<dyn MetricsReporter>>,
 }
 
 /// Default builder for [`S3TablesCatalog`].
@@ -84,6 +88,8 @@ impl Default for S3TablesCatalogBuilder {
                 props: HashMap::new(),
             },
             storage_factory: None,
+            key_management_client: None,
+            metrics_reporter: None,
         }
     }
 }
@@ -121,6 +127,18 @@ impl S3TablesCatalogBuilder {
         self.config.table_bucket_arn = table_bucket_arn.into();
         self
     }
+
+    /// Set the client of the KMS holding the master keys of encrypted tables.
+    pub fn with_key_management_client(mut self, client: Arc<dyn KeyManagementClient>) -> Self {
+        self.key_management_client = Some(client);
+        self
+    }
+
+    /// Set the [`MetricsReporter`] the scans and commits of the catalog's tables report to.
+    pub fn with_metrics_reporter(mut self, metrics_reporter: Arc<dyn MetricsReporter>) -> Self {
+        self.metrics_reporter = Some(metrics_reporter);
+        self
+    }
 }
 
 impl CatalogBuilder for S3TablesCatalogBuilder {
@@ -171,7 +189,13 @@ impl CatalogBuilder for S3TablesCatalogBuilder {
                     "Table bucket ARN is required",
                 ))
             } else {
-                S3TablesCatalog::new(self.config, self.storage_factory).await
+                S3TablesCatalog::new(
+                    self.config,
+                    self.storage_factory,
+                    self.key_management_client,
+                    self.metrics_reporter,
+                )
+                .await
             }
         }
     }
@@ -183,6 +207,7 @@ pub struct S3TablesCatalog {
     config: S3TablesCatalogConfig,
     s3tables_client: aws_sdk_s3tables::Client,
     file_io: FileIO,
+    metrics_reporter: Option<Arc<dyn MetricsReporter>>,
 }
 
 impl S3TablesCatalog {
@@ -190,6 +215,8 @@ impl S3TablesCatalog {
     async fn new(
         config: S3TablesCatalogConfig,
         storage_factory: Option<Arc<dyn StorageFactory>>,
+        key_management_client: Option<Arc<dyn KeyManagementClient>>,
+        metrics_reporter: Option<Arc<dyn MetricsReporter>>,
     ) -> Result<Self> {
         let s3tables_client = if let Some(client) = config.client.clone() {
             client
@@ -205,14 +232,16 @@ impl S3TablesCatalog {
                 customized_credential_load: None,
             })
         });
-        let file_io = FileIOBuilder::new(factory)
-            .with_props(&config.props)
-            .build();
+        let mut file_io = FileIOBuilder::new(factory).with_props(&config.props);
+        if let Some(client) = key_management_client {
+            file_io = file_io.with_key_management_client(client);
+        }
 
         Ok(Self {
             config,
             s3tables_client,
-            file_io,
+            file_io: file_io.build(),
+            metrics_reporter,
         })
     }
 
@@ -245,6 +274,7 @@ impl S3TablesCatalog {
             .metadata(metadata)
             .metadata_location(metadata_location)
             .file_io(self.file_io.clone())
+            .metrics_reporter(self.metrics_reporter.clone())
             .build()?;
         Ok((table, resp.version_token))
     }
@@ -522,6 +552,7 @@ impl Catalog for S3TablesCatalog {
             .metadata_location(metadata_location)
             .metadata(metadata)
             .file_io(self.file_io.clone())
+            .metrics_reporter(self.metrics_reporter.clone())
             .build()?;
         Ok(table)
     }
@@ -700,7 +731,7 @@ mod tests {
             props: HashMap::new(),
         };
 
-        Ok(Some(S3TablesCatalog::new(config, None).await?))
+        Ok(Some(S3TablesCatalog::new(config, None, None, None).await?))
     }
 
     #[tokio::test]
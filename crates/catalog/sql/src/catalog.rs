@@ -21,18 +21,22 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use async_trait::async_trait;
+use iceberg::encryption::KeyManagementClient;
 use iceberg::io::{FileIO, FileIOBuilder, StorageFactory};
-use iceberg::spec::{TableMetadata, TableMetadataBuilder};
+use iceberg::metrics::MetricsReporter;
+use iceberg::spec::{TableMetadata, TableMetadataBuilder, ViewMetadata, ViewMetadataBuilder};
 use iceberg::table::Table;
+use iceberg::view::View;
 use iceberg::{
     Catalog, CatalogBuilder, Error, ErrorKind, MetadataLocation, Namespace, NamespaceIdent, Result,
-    TableCommit, TableCreation, TableIdent,
+    TableCommit, TableCreation, TableIdent, ViewCommit, ViewCreation,
 };
 use sqlx::any::{AnyPoolOptions, AnyQueryResult, AnyRow, install_default_drivers};
 use sqlx::{Any, AnyPool, Row, Transaction};
 
 use crate::error::{
-    from_sqlx_error, no_such_namespace_err, no_such_table_err, table_already_exists_err,
+    from_sqlx_error, no_such_namespace_err, no_such_table_err, no_such_view_err,
+    table_already_exists_err, view_already_exists_err,
 };
 
 /// catalog URI
@@ -50,6 +54,7 @@ static CATALOG_FIELD_METADATA_LOCATION_PROP: &str = "metadata_location";
 static CATALOG_FIELD_PREVIOUS_METADATA_LOCATION_PROP: &str = "previous_metadata_location";
 static CATALOG_FIELD_RECORD_TYPE: &str = "iceberg_type";
 static CATALOG_FIELD_TABLE_RECORD_TYPE: &str = "TABLE";
+static CATALOG_FIELD_VIEW_RECORD_TYPE: &str = "VIEW";
 
 static NAMESPACE_TABLE_NAME: &str = "iceberg_namespace_properties";
 static NAMESPACE_FIELD_NAME: &str = "namespace";
@@ -67,6 +72,8 @@ static TEST_BEFORE_ACQUIRE: bool = true; // Default the health-check of each con
 pub struct SqlCatalogBuilder {
     config: SqlCatalogConfig,
     storage_factory: Option<Arc<dyn StorageFactory>>,
+    key_management_client: Option<Arc<dyn KeyManagementClient>>,
+    metrics_reporter: Option<Arc<dyn MetricsReporter>>,
 }
 
 impl Default for SqlCatalogBuilder {
@@ -80,6 +87,8 @@ impl Default for SqlCatalogBuilder {
                 props: HashMap::new(),
             },
             storage_factory: None,
+            key_management_client: None,
+            metrics_reporter: None,
         }
     }
 }
@@ -133,6 +142,18 @@ impl SqlCatalogBuilder {
         self.config.props.insert(key.into(), value.into());
         self
     }
+
+    /// Set the client of the KMS holding the master keys of encrypted tables.
+    pub fn with_key_management_client(mut self, client: Arc<dyn KeyManagementClient>) -> Self {
+        self.key_management_client = Some(client);
+        self
+    }
+
+    /// Set the [`MetricsReporter`] the scans and commits of the catalog's tables report to.
+    pub fn with_metrics_reporter(mut self, metrics_reporter: Arc<dyn MetricsReporter>) -> Self {
+        self.metrics_reporter = Some(metrics_reporter);
+        self
+    }
 }
 
 impl CatalogBuilder for SqlCatalogBuilder {
@@ -190,7 +211,13 @@ impl CatalogBuilder for SqlCatalogBuilder {
                 ))
             } else {
                 self.config.name = name;
-                SqlCatalog::new(self.config, self.storage_factory).await
+                SqlCatalog::new(
+                    self.config,
+                    self.storage_factory,
+                    self.key_management_client,
+                    self.metrics_reporter,
+                )
+                .await
             }
         }
     }
@@ -221,6 +248,7 @@ pub struct SqlCatalog {
     warehouse_location: String,
     fileio: FileIO,
     sql_bind_style: SqlBindStyle,
+    metrics_reporter: Option<Arc<dyn MetricsReporter>>,
 }
 
 #[derive(Debug, PartialEq, strum::EnumString, strum::Display)]
@@ -237,6 +265,8 @@ impl SqlCatalog {
     async fn new(
         config: SqlCatalogConfig,
         storage_factory: Option<Arc<dyn StorageFactory>>,
+        key_management_client: Option<Arc<dyn KeyManagementClient>>,
+        metrics_reporter: Option<Arc<dyn MetricsReporter>>,
     ) -> Result<Self> {
         let factory = storage_factory.ok_or_else(|| {
             Error::new(
@@ -244,7 +274,10 @@ impl SqlCatalog {
                 "StorageFactory must be provided for SqlCatalog. Use `with_storage_factory` to configure it.",
             )
         })?;
-        let fileio = FileIOBuilder::new(factory).build();
+        let mut fileio = FileIOBuilder::new(factory);
+        if let Some(client) = key_management_client {
+            fileio = fileio.with_key_management_client(client);
+        }
 
         install_default_drivers();
         let max_connections: u32 = config
@@ -301,8 +334,9 @@ impl SqlCatalog {
             name: config.name.to_owned(),
             connection: pool,
             warehouse_location: config.warehouse_location,
-            fileio,
+            fileio: fileio.build(),
             sql_bind_style: config.sql_bind_style,
+            metrics_reporter,
         })
     }
 
@@ -367,6 +401,51 @@ impl SqlCatalog {
             }
         }
     }
+
+    /// Points the table to its new metadata file if it still points to `current_metadata_location`.
+    async fn swap_metadata_location(
+        &self,
+        table_ident: &TableIdent,
+        current_metadata_location: &str,
+        staged_metadata_location: &str,
+        transaction: Option<&mut Transaction<'_, Any>>,
+    ) -> Result<()> {
+        let update_result = self
+            .execute(
+                &format!(
+                    "UPDATE {CATALOG_TABLE_NAME}
+                     SET {CATALOG_FIELD_METADATA_LOCATION_PROP} = ?, {CATALOG_FIELD_PREVIOUS_METADATA_LOCATION_PROP} = ?
+                     WHERE {CATALOG_FIELD_CATALOG_NAME} = ?
+                      AND {CATALOG_FIELD_TABLE_NAME} = ?
+                      AND {CATALOG_FIELD_TABLE_NAMESPACE} = ?
+                      AND (
+                        {CATALOG_FIELD_RECORD_TYPE} = '{CATALOG_FIELD_TABLE_RECORD_TYPE}'
+                        OR {CATALOG_FIELD_RECORD_TYPE} IS NULL
+                      )
+                      AND {CATALOG_FIELD_METADATA_LOCATION_PROP} = ?"
+                ),
+                vec![
+                    Some(staged_metadata_location),
+                    Some(current_metadata_location),
+                    Some(&self.name),
+                    Some(table_ident.name()),
+                    Some(&table_ident.namespace().join(".")),
+                    Some(current_metadata_location),
+                ],
+                transaction,
+            )
+            .await?;
+
+        if update_result.rows_affected() == 0 {
+            return Err(Error::new(
+                ErrorKind::CatalogCommitConflicts,
+                format!("Commit conflicted for table: {table_ident}"),
+            )
+            .with_retryable(true));
+        }
+
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -646,6 +725,19 @@ impl Catalog for SqlCatalog {
                 ));
             }
 
+            // Views are stored in the same table as the tables, and would be left behind too.
+            let views = self.list_views(namespace).await?;
+            if !views.is_empty() {
+                return Err(Error::new(
+                    iceberg::ErrorKind::Unexpected,
+                    format!(
+                        "Namespace {:?} is not empty. {} views exist.",
+                        namespace,
+                        views.len()
+                    ),
+                ));
+            }
+
             self.execute(
                 &format!(
                     "DELETE FROM {NAMESPACE_TABLE_NAME}
@@ -799,6 +891,7 @@ impl Catalog for SqlCatalog {
             .identifier(identifier.clone())
             .metadata_location(tbl_metadata_location)
             .metadata(metadata)
+            .metrics_reporter(self.metrics_reporter.clone())
             .build()?)
     }
 
@@ -818,6 +911,10 @@ impl Catalog for SqlCatalog {
             return table_already_exists_err(&tbl_ident);
         }
 
+        if self.view_exists(&tbl_ident).await? {
+            return view_already_exists_err(&tbl_ident);
+        }
+
         let (tbl_creation, location) = match creation.location.clone() {
             Some(location) => (creation, location),
             None => {
@@ -868,6 +965,7 @@ impl Catalog for SqlCatalog {
             .metadata_location(tbl_metadata_location)
             .identifier(tbl_ident)
             .metadata(tbl_metadata)
+            .metrics_reporter(self.metrics_reporter.clone())
             .build()?)
     }
 
@@ -888,6 +986,10 @@ impl Catalog for SqlCatalog {
             return table_already_exists_err(dest);
         }
 
+        if self.view_exists(dest).await? {
+            return view_already_exists_err(dest);
+        }
+
         self.execute(
             &format!(
                 "UPDATE {CATALOG_TABLE_NAME}
@@ -939,6 +1041,7 @@ impl Catalog for SqlCatalog {
             .metadata_location(metadata_location)
             .metadata(metadata)
             .file_io(self.fileio.clone())
+            .metrics_reporter(self.metrics_reporter.clone())
             .build()?)
     }
 
@@ -956,6 +1059,270 @@ impl Catalog for SqlCatalog {
             .write_to(staged_table.file_io(), &staged_metadata_location)
             .await?;
 
+        self.swap_metadata_location(
+            &table_ident,
+            &current_metadata_location,
+            staged_metadata_location,
+            None,
+        )
+        .await?;
+
+        Ok(staged_table)
+    }
+
+    async fn commit_transaction(&self, commits: Vec<TableCommit>) -> Result<()> {
+        // Stage all tables before writing anything, so that a failed requirement
+        // leaves every table unchanged.
+        let mut staged_tables: Vec<(String, Table)> = Vec::with_capacity(commits.len());
+        for commit in commits {
+            let table_ident = commit.identifier().clone();
+            if staged_tables
+                .iter()
+                .any(|(_, table)| table.identifier() == &table_ident)
+            {
+                return Err(Error::new(
+                    ErrorKind::DataInvalid,
+                    format!("Table {table_ident} is updated more than once in the transaction"),
+                ));
+            }
+
+            let current_table = self.load_table(&table_ident).await?;
+            let current_metadata_location = current_table.metadata_location_result()?.to_string();
+            staged_tables.push((current_metadata_location, commit.apply(current_table)?));
+        }
+
+        for (_, staged_table) in &staged_tables {
+            staged_table
+                .metadata()
+                .write_to(
+                    staged_table.file_io(),
+                    staged_table.metadata_location_result()?,
+                )
+                .await?;
+        }
+
+        // All pointers are swapped in one database transaction, which is rolled back
+        // when dropped if any of them conflicts.
+        let mut tx = self.connection.begin().await.map_err(from_sqlx_error)?;
+        for (current_metadata_location, staged_table) in &staged_tables {
+            self.swap_metadata_location(
+                staged_table.identifier(),
+                current_metadata_location,
+                staged_table.metadata_location_result()?,
+                Some(&mut tx),
+            )
+            .await?;
+        }
+        tx.commit().await.map_err(from_sqlx_error)
+    }
+
+    async fn list_views(&self, namespace: &NamespaceIdent) -> Result<Vec<TableIdent>> {
+        if !self.namespace_exists(namespace).await? {
+            return no_such_namespace_err(namespace);
+        }
+
+        let rows = self
+            .fetch_rows(
+                &format!(
+                    "SELECT {CATALOG_FIELD_TABLE_NAME}
+                     FROM {CATALOG_TABLE_NAME}
+                     WHERE {CATALOG_FIELD_TABLE_NAMESPACE} = ?
+                      AND {CATALOG_FIELD_CATALOG_NAME} = ?
+                      AND {CATALOG_FIELD_RECORD_TYPE} = '{CATALOG_FIELD_VIEW_RECORD_TYPE}'"
+                ),
+                vec![Some(&namespace.join(".")), Some(&self.name)],
+            )
+            .await?;
+
+        rows.iter()
+            .map(|row| {
+                let view_name = row
+                    .try_get::<String, _>(CATALOG_FIELD_TABLE_NAME)
+                    .map_err(from_sqlx_error)?;
+                Ok(TableIdent::new(namespace.clone(), view_name))
+            })
+            .collect()
+    }
+
+    async fn view_exists(&self, identifier: &TableIdent) -> Result<bool> {
+        let view_counts = self
+            .fetch_rows(
+                &format!(
+                    "SELECT 1
+                     FROM {CATALOG_TABLE_NAME}
+                     WHERE {CATALOG_FIELD_TABLE_NAMESPACE} = ?
+                      AND {CATALOG_FIELD_CATALOG_NAME} = ?
+                      AND {CATALOG_FIELD_TABLE_NAME} = ?
+                      AND {CATALOG_FIELD_RECORD_TYPE} = '{CATALOG_FIELD_VIEW_RECORD_TYPE}'"
+                ),
+                vec![
+                    Some(&identifier.namespace().join(".")),
+                    Some(&self.name),
+                    Some(identifier.name()),
+                ],
+            )
+            .await?;
+
+        Ok(!view_counts.is_empty())
+    }
+
+    async fn drop_view(&self, identifier: &TableIdent) -> Result<()> {
+        if !self.view_exists(identifier).await? {
+            return no_such_view_err(identifier);
+        }
+
+        self.execute(
+            &format!(
+                "DELETE FROM {CATALOG_TABLE_NAME}
+                 WHERE {CATALOG_FIELD_CATALOG_NAME} = ?
+                  AND {CATALOG_FIELD_TABLE_NAME} = ?
+                  AND {CATALOG_FIELD_TABLE_NAMESPACE} = ?
+                  AND {CATALOG_FIELD_RECORD_TYPE} = '{CATALOG_FIELD_VIEW_RECORD_TYPE}'"
+            ),
+            vec![
+                Some(&self.name),
+                Some(identifier.name()),
+                Some(&identifier.namespace().join(".")),
+            ],
+            None,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn load_view(&self, identifier: &TableIdent) -> Result<View> {
+        let rows = self
+            .fetch_rows(
+                &format!(
+                    "SELECT {CATALOG_FIELD_METADATA_LOCATION_PROP}
+                     FROM {CATALOG_TABLE_NAME}
+                     WHERE {CATALOG_FIELD_CATALOG_NAME} = ?
+                      AND {CATALOG_FIELD_TABLE_NAME} = ?
+                      AND {CATALOG_FIELD_TABLE_NAMESPACE} = ?
+                      AND {CATALOG_FIELD_RECORD_TYPE} = '{CATALOG_FIELD_VIEW_RECORD_TYPE}'"
+                ),
+                vec![
+                    Some(&self.name),
+                    Some(identifier.name()),
+                    Some(&identifier.namespace().join(".")),
+                ],
+            )
+            .await?;
+
+        let Some(row) = rows.first() else {
+            return no_such_view_err(identifier);
+        };
+        let view_metadata_location = row
+            .try_get::<String, _>(CATALOG_FIELD_METADATA_LOCATION_PROP)
+            .map_err(from_sqlx_error)?;
+
+        let metadata = ViewMetadata::read_from(&self.fileio, &view_metadata_location).await?;
+
+        View::builder()
+            .identifier(identifier.clone())
+            .metadata_location(view_metadata_location)
+            .metadata(metadata)
+            .build()
+    }
+
+    async fn create_view(
+        &self,
+        namespace: &NamespaceIdent,
+        creation: ViewCreation,
+    ) -> Result<View> {
+        if !self.namespace_exists(namespace).await? {
+            return no_such_namespace_err(namespace);
+        }
+
+        let view_ident = TableIdent::new(namespace.clone(), creation.name.clone());
+        if self.view_exists(&view_ident).await? {
+            return view_already_exists_err(&view_ident);
+        }
+        if self.table_exists(&view_ident).await? {
+            return table_already_exists_err(&view_ident);
+        }
+
+        let view_metadata_location =
+            MetadataLocation::new_with_table_location(creation.location.clone()).to_string();
+        let view_metadata = ViewMetadataBuilder::from_view_creation(creation)?
+            .build()?
+            .metadata;
+
+        view_metadata
+            .write_to(&self.fileio, &view_metadata_location)
+            .await?;
+
+        self.execute(&format!(
+            "INSERT INTO {CATALOG_TABLE_NAME}
+             ({CATALOG_FIELD_CATALOG_NAME}, {CATALOG_FIELD_TABLE_NAMESPACE}, {CATALOG_FIELD_TABLE_NAME}, {CATALOG_FIELD_METADATA_LOCATION_PROP}, {CATALOG_FIELD_RECORD_TYPE})
+             VALUES (?, ?, ?, ?, ?)
+            "), vec![Some(&self.name), Some(&namespace.join(".")), Some(view_ident.name()), Some(&view_metadata_location), Some(CATALOG_FIELD_VIEW_RECORD_TYPE)], None).await?;
+
+        View::builder()
+            .metadata_location(view_metadata_location)
+            .identifier(view_ident)
+            .metadata(view_metadata)
+            .build()
+    }
+
+    async fn rename_view(&self, src: &TableIdent, dest: &TableIdent) -> Result<()> {
+        if src == dest {
+            return Ok(());
+        }
+
+        if !self.view_exists(src).await? {
+            return no_such_view_err(src);
+        }
+
+        if !self.namespace_exists(dest.namespace()).await? {
+            return no_such_namespace_err(dest.namespace());
+        }
+
+        if self.view_exists(dest).await? {
+            return view_already_exists_err(dest);
+        }
+
+        if self.table_exists(dest).await? {
+            return table_already_exists_err(dest);
+        }
+
+        self.execute(
+            &format!(
+                "UPDATE {CATALOG_TABLE_NAME}
+                 SET {CATALOG_FIELD_TABLE_NAME} = ?, {CATALOG_FIELD_TABLE_NAMESPACE} = ?
+                 WHERE {CATALOG_FIELD_CATALOG_NAME} = ?
+                  AND {CATALOG_FIELD_TABLE_NAME} = ?
+                  AND {CATALOG_FIELD_TABLE_NAMESPACE} = ?
+                  AND {CATALOG_FIELD_RECORD_TYPE} = '{CATALOG_FIELD_VIEW_RECORD_TYPE}'"
+            ),
+            vec![
+                Some(dest.name()),
+                Some(&dest.namespace().join(".")),
+                Some(&self.name),
+                Some(src.name()),
+                Some(&src.namespace().join(".")),
+            ],
+            None,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn update_view(&self, commit: ViewCommit) -> Result<View> {
+        let view_ident = commit.identifier().clone();
+        let current_view = self.load_view(&view_ident).await?;
+        let current_metadata_location = current_view.metadata_location_result()?.to_string();
+
+        let staged_view = commit.apply(current_view)?;
+        let staged_metadata_location = staged_view.metadata_location_result()?;
+
+        staged_view
+            .metadata()
+            .write_to(&self.fileio, staged_metadata_location)
+            .await?;
+
         let update_result = self
             .execute(
                 &format!(
@@ -964,19 +1331,16 @@ impl Catalog for SqlCatalog {
                      WHERE {CATALOG_FIELD_CATALOG_NAME} = ?
                       AND {CATALOG_FIELD_TABLE_NAME} = ?
                       AND {CATALOG_FIELD_TABLE_NAMESPACE} = ?
-                      AND (
-                        {CATALOG_FIELD_RECORD_TYPE} = '{CATALOG_FIELD_TABLE_RECORD_TYPE}'
-                        OR {CATALOG_FIELD_RECORD_TYPE} IS NULL
-                      )
+                      AND {CATALOG_FIELD_RECORD_TYPE} = '{CATALOG_FIELD_VIEW_RECORD_TYPE}'
                       AND {CATALOG_FIELD_METADATA_LOCATION_PROP} = ?"
                 ),
                 vec![
                     Some(staged_metadata_location),
-                    Some(current_metadata_location.as_str()),
+                    Some(&current_metadata_location),
                     Some(&self.name),
-                    Some(table_ident.name()),
-                    Some(&table_ident.namespace().join(".")),
-                    Some(current_metadata_location.as_str()),
+                    Some(view_ident.name()),
+                    Some(&view_ident.namespace().join(".")),
+                    Some(&current_metadata_location),
                 ],
                 None,
             )
@@ -985,12 +1349,12 @@ impl Catalog for SqlCatalog {
         if update_result.rows_affected() == 0 {
             return Err(Error::new(
                 ErrorKind::CatalogCommitConflicts,
-                format!("Commit conflicted for table: {table_ident}"),
+                format!("Commit conflicted for view: {view_ident}"),
             )
             .with_retryable(true));
         }
 
-        Ok(staged_table)
+        Ok(staged_view)
     }
 }
 
@@ -1000,11 +1364,19 @@ mod tests {
     use std::hash::Hash;
     use std::sync::Arc;
 
+    use iceberg::encryption::InMemoryKeyManagementClient;
     use iceberg::io::LocalFsStorageFactory;
-    use iceberg::spec::{NestedField, PartitionSpec, PrimitiveType, Schema, SortOrder, Type};
+    use iceberg::spec::{
+        DataContentType, DataFileBuilder, DataFileFormat, FormatVersion, NestedField,
+        PartitionSpec, PrimitiveType, Schema, SortOrder, SqlViewRepresentation, Struct,
+        TableProperties, Type, ViewRepresentations,
+    };
     use iceberg::table::Table;
-    use iceberg::transaction::{ApplyTransactionAction, Transaction};
-    use iceberg::{Catalog, CatalogBuilder, Namespace, NamespaceIdent, TableCreation, TableIdent};
+    use iceberg::transaction::{ApplyTransactionAction, MultiTableTransaction, Transaction};
+    use iceberg::{
+        Catalog, CatalogBuilder, ErrorKind, Namespace, NamespaceIdent, TableCommit, TableCreation,
+        TableIdent, TableRequirement, TableUpdate, ViewCreation,
+    };
     use itertools::Itertools;
     use regex::Regex;
     use sqlx::migrate::MigrateDatabase;
@@ -1751,6 +2123,34 @@ mod tests {
         assert!(!catalog.namespace_exists(&namespace_ident).await.unwrap())
     }
 
+    #[tokio::test]
+    async fn test_drop_namespace_throws_error_if_namespace_has_views() {
+        let warehouse_loc = temp_path();
+        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
+        let namespace_ident = NamespaceIdent::new("abc".into());
+        create_namespace(&catalog, &namespace_ident).await;
+        let view_ident = TableIdent::new(namespace_ident.clone(), "v1".into());
+        catalog
+            .create_view(&namespace_ident, simple_view_creation(&view_ident))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            catalog
+                .drop_namespace(&namespace_ident)
+                .await
+                .unwrap_err()
+                .to_string(),
+            format!("Unexpected => Namespace {namespace_ident:?} is not empty. 1 views exist.")
+        );
+        assert!(catalog.namespace_exists(&namespace_ident).await.unwrap());
+        assert!(catalog.view_exists(&view_ident).await.unwrap());
+
+        catalog.drop_view(&view_ident).await.unwrap();
+        catalog.drop_namespace(&namespace_ident).await.unwrap();
+        assert!(!catalog.namespace_exists(&namespace_ident).await.unwrap());
+    }
+
     #[tokio::test]
     async fn test_drop_nested_namespace() {
         let warehouse_loc = temp_path();
@@ -2450,4 +2850,307 @@ mod tests {
             updated_table.metadata_location()
         );
     }
+
+    #[tokio::test]
+    async fn test_commit_transaction() {
+        let warehouse_loc = temp_path();
+        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
+
+        let namespace_ident = NamespaceIdent::new("ns1".into());
+        create_namespace(&catalog, &namespace_ident).await;
+        let fact_ident = TableIdent::new(namespace_ident.clone(), "fact".into());
+        let agg_ident = TableIdent::new(namespace_ident, "agg".into());
+        create_table(&catalog, &fact_ident).await;
+        create_table(&catalog, &agg_ident).await;
+
+        let mut multi_tx = MultiTableTransaction::new();
+        let mut original_metadata_locations = vec![];
+        for table_ident in [&fact_ident, &agg_ident] {
+            let table = catalog.load_table(table_ident).await.unwrap();
+            original_metadata_locations.push(table.metadata_location().unwrap().to_string());
+
+            let tx = Transaction::new(&table);
+            let tx = tx
+                .update_table_properties()
+                .set("source".to_string(), table_ident.name().to_string())
+                .apply(tx)
+                .unwrap();
+            multi_tx = multi_tx.add_transaction(tx).unwrap();
+        }
+
+        multi_tx.commit(&catalog).await.unwrap();
+
+        for (table_ident, original_metadata_location) in [&fact_ident, &agg_ident]
+            .into_iter()
+            .zip(original_metadata_locations)
+        {
+            let reloaded = catalog.load_table(table_ident).await.unwrap();
+            assert_eq!(
+                reloaded.metadata().properties().get("source"),
+                Some(&table_ident.name().to_string())
+            );
+            assert_ne!(
+                reloaded.metadata_location().unwrap(),
+                original_metadata_location.as_str()
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_commit_transaction_rolls_back_on_failed_requirement() {
+        let warehouse_loc = temp_path();
+        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
+
+        let namespace_ident = NamespaceIdent::new("ns1".into());
+        create_namespace(&catalog, &namespace_ident).await;
+        let fact_ident = TableIdent::new(namespace_ident.clone(), "fact".into());
+        let agg_ident = TableIdent::new(namespace_ident, "agg".into());
+        create_table(&catalog, &fact_ident).await;
+        create_table(&catalog, &agg_ident).await;
+        let fact = catalog.load_table(&fact_ident).await.unwrap();
+        let agg = catalog.load_table(&agg_ident).await.unwrap();
+
+        let set_source = |table_ident: &TableIdent| TableUpdate::SetProperties {
+            updates: HashMap::from([("source".to_string(), table_ident.name().to_string())]),
+        };
+        let commits = vec![
+            TableCommit::new(
+                fact_ident.clone(),
+                vec![TableRequirement::CurrentSchemaIdMatch {
+                    current_schema_id: fact.metadata().current_schema_id(),
+                }],
+                vec![set_source(&fact_ident)],
+            ),
+            TableCommit::new(
+                agg_ident.clone(),
+                vec![TableRequirement::CurrentSchemaIdMatch {
+                    current_schema_id: agg.metadata().current_schema_id() + 1,
+                }],
+                vec![set_source(&agg_ident)],
+            ),
+        ];
+
+        let err = catalog.commit_transaction(commits).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::CatalogCommitConflicts);
+
+        for table in [fact, agg] {
+            let reloaded = catalog.load_table(table.identifier()).await.unwrap();
+            assert_eq!(reloaded.metadata_location(), table.metadata_location());
+            assert!(reloaded.metadata().properties().get("source").is_none());
+        }
+    }
+
+    fn simple_view_creation(view_ident: &TableIdent) -> ViewCreation {
+        ViewCreation::builder()
+            .name(view_ident.name().to_string())
+            .location(temp_path())
+            .schema(simple_table_schema())
+            .representations(ViewRepresentations::from_iter([SqlViewRepresentation {
+                sql: "SELECT foo FROM tbl".to_string(),
+                dialect: "spark".to_string(),
+            }
+            .into()]))
+            .default_namespace(view_ident.namespace().clone())
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_create_and_load_view() {
+        let warehouse_loc = temp_path();
+        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
+        let namespace_ident = NamespaceIdent::new("ns1".into());
+        create_namespace(&catalog, &namespace_ident).await;
+        let view_ident = TableIdent::new(namespace_ident.clone(), "v1".into());
+
+        let view = catalog
+            .create_view(&namespace_ident, simple_view_creation(&view_ident))
+            .await
+            .unwrap();
+        assert_eq!(view.identifier(), &view_ident);
+
+        let loaded_view = catalog.load_view(&view_ident).await.unwrap();
+        assert_eq!(loaded_view.metadata(), view.metadata());
+        assert_eq!(loaded_view.metadata_location(), view.metadata_location());
+
+        assert!(catalog.view_exists(&view_ident).await.unwrap());
+        assert!(!catalog.table_exists(&view_ident).await.unwrap());
+        assert_eq!(catalog.list_views(&namespace_ident).await.unwrap(), vec![
+            view_ident.clone()
+        ]);
+        assert!(
+            catalog
+                .list_tables(&namespace_ident)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        let err = catalog
+            .create_view(&namespace_ident, simple_view_creation(&view_ident))
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ViewAlreadyExists);
+
+        let err = catalog
+            .create_table(
+                &namespace_ident,
+                TableCreation::builder()
+                    .name(view_ident.name().to_string())
+                    .schema(simple_table_schema())
+                    .build(),
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ViewAlreadyExists);
+    }
+
+    #[tokio::test]
+    async fn test_drop_view() {
+        let warehouse_loc = temp_path();
+        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
+        let namespace_ident = NamespaceIdent::new("ns1".into());
+        create_namespace(&catalog, &namespace_ident).await;
+        let view_ident = TableIdent::new(namespace_ident.clone(), "v1".into());
+        catalog
+            .create_view(&namespace_ident, simple_view_creation(&view_ident))
+            .await
+            .unwrap();
+
+        catalog.drop_view(&view_ident).await.unwrap();
+
+        assert!(!catalog.view_exists(&view_ident).await.unwrap());
+        let err = catalog.load_view(&view_ident).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ViewNotFound);
+    }
+
+    #[tokio::test]
+    async fn test_rename_view() {
+        let warehouse_loc = temp_path();
+        let catalog = new_sql_catalog(warehouse_loc, Some("iceberg")).await;
+        let src_namespace_ident = NamespaceIdent::new("ns1".into());
+        let dst_namespace_ident = NamespaceIdent::new("ns2".into());
+        create_namespaces(&catalog, &vec![&src_namespace_ident, &dst_namespace_ident]).await;
+        let src_view_ident = TableIdent::new(src_namespace_ident.clone(), "v1".into());
+        let dst_view_ident = TableIdent::new(dst_namespace_ident.clone(), "v2".into());
+        let table_ident = TableIdent::new(dst_namespace_ident.clone(), "tbl1".into());
+        create_table(&catalog, &table_ident).await;
+        let view = catalog
+            .create_view(&src_namespace_ident, simple_view_creation(&src_view_ident))
+            .await
+            .unwrap();
+
+        let err = catalog
+            .rename_view(&src_view_ident, &table_ident)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("already exists"));
+
+        catalog
+            .rename_view(&src_view_ident, &dst_view_ident)
+            .await
+            .unwrap();
+
+        assert!(!catalog.view_exists(&src_view_ident).await.unwrap());
+        let renamed_view = catalog.load_view(&dst_view_ident).await.unwrap();
+        assert_eq!(renamed_view.metadata(), view.metadata());
+        assert_eq!(
+            catalog.list_views(&dst_namespace_ident).await.unwrap(),
+            vec![dst_view_ident]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_encrypted_table_round_trip() {
+        let sql_lite_uri = format!("sqlite:{}", temp_path());
+        sqlx::Sqlite::create_database(&sql_lite_uri).await.unwrap();
+        let props = HashMap::from_iter([
+            (SQL_CATALOG_PROP_URI.to_string(), sql_lite_uri),
+            (SQL_CATALOG_PROP_WAREHOUSE.to_string(), temp_path()),
+            (
+                SQL_CATALOG_PROP_BIND_STYLE.to_string(),
+                SqlBindStyle::DollarNumeric.to_string(),
+            ),
+        ]);
+        let kms =
+            Arc::new(InMemoryKeyManagementClient::new().with_master_key("master", vec![3; 32]));
+        let catalog = SqlCatalogBuilder::default()
+            .with_storage_factory(Arc::new(LocalFsStorageFactory))
+            .with_key_management_client(kms)
+            .load("iceberg", props.clone())
+            .await
+            .unwrap();
+
+        let namespace_ident = NamespaceIdent::new("a".into());
+        create_namespace(&catalog, &namespace_ident).await;
+        let table_ident = TableIdent::new(namespace_ident.clone(), "tbl".into());
+        let table = catalog
+            .create_table(
+                &namespace_ident,
+                TableCreation::builder()
+                    .name(table_ident.name().into())
+                    .schema(simple_table_schema())
+                    .format_version(FormatVersion::V3)
+                    .properties(HashMap::from([(
+                        TableProperties::PROPERTY_ENCRYPTION_TABLE_KEY.to_string(),
+                        "master".to_string(),
+                    )]))
+                    .build(),
+            )
+            .await
+            .unwrap();
+
+        let data_file = DataFileBuilder::default()
+            .content(DataContentType::Data)
+            .file_path(format!("{}/data/1.parquet", table.metadata().location()))
+            .file_format(DataFileFormat::Parquet)
+            .file_size_in_bytes(100)
+            .record_count(1)
+            .partition_spec_id(0)
+            .partition(Struct::empty())
+            .build()
+            .unwrap();
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .fast_append()
+            .add_data_files(vec![data_file.clone()])
+            .apply(tx)
+            .unwrap();
+        tx.commit(&catalog).await.unwrap();
+
+        // The encrypted manifest list and manifests can be read back through the catalog.
+        let table = catalog.load_table(&table_ident).await.unwrap();
+        let snapshot = table.metadata().current_snapshot().unwrap();
+        assert!(snapshot.encryption_key_id().is_some());
+        let manifest_list = snapshot
+            .load_manifest_list(table.file_io(), table.metadata())
+            .await
+            .unwrap();
+        assert_eq!(manifest_list.entries().len(), 1);
+        let manifest = manifest_list.entries()[0]
+            .load_manifest(table.file_io())
+            .await
+            .unwrap();
+        assert_eq!(manifest.entries().len(), 1);
+        assert_eq!(
+            manifest.entries()[0].data_file().file_path(),
+            data_file.file_path()
+        );
+
+        // A catalog over the same database without the key management client can't read them.
+        let catalog = SqlCatalogBuilder::default()
+            .with_storage_factory(Arc::new(LocalFsStorageFactory))
+            .load("iceberg", props)
+            .await
+            .unwrap();
+        let table = catalog.load_table(&table_ident).await.unwrap();
+        assert!(
+            table
+                .metadata()
+                .current_snapshot()
+                .unwrap()
+                .load_manifest_list(table.file_io(), table.metadata())
+                .await
+                .is_err()
+        );
+    }
 }
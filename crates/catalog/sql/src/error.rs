@@ -46,3 +46,17 @@ pub fn table_already_exists_err<T>(table_ident: &TableIdent) -> Result<T> {
         format!("Table {table_ident:?} already exists."),
     ))
 }
+
+pub fn no_such_view_err<T>(view_ident: &TableIdent) -> Result<T> {
+    Err(Error::new(
+        ErrorKind::ViewNotFound,
+        format!("No such view: {view_ident:?}"),
+    ))
+}
+
+pub fn view_already_exists_err<T>(view_ident: &TableIdent) -> Result<T> {
+    Err(Error::new(
+        ErrorKind::ViewAlreadyExists,
+        format!("View {view_ident:?} already exists."),
+    ))
+}
@@ -0,0 +1,1267 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! This module contains the action rewriting the absolute paths of a table, to copy it to
+//! another location.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use arrow_array::{Array, ArrayRef, RecordBatch, StringArray};
+use arrow_schema::{Schema as ArrowSchema, SchemaRef as ArrowSchemaRef};
+use futures::TryStreamExt;
+use parquet::arrow::ARROW_SCHEMA_META_KEY;
+use parquet::file::metadata::ParquetMetaData;
+use parquet::file::properties::{WriterProperties, WriterVersion};
+use uuid::Uuid;
+
+use crate::arrow::{
+    ArrowReader, ParquetReadOptions, arrow_schema_to_schema, schema_to_arrow_schema,
+};
+use crate::metadata_columns::{
+    RESERVED_FIELD_ID_DELETE_FILE_PATH, delete_file_path_field, delete_file_pos_field,
+};
+use crate::puffin::{PuffinReader, PuffinWriter};
+use crate::spec::{
+    DataContentType, DataFile, DataFileFormat, Datum, FormatVersion, Manifest, ManifestFile,
+    ManifestListWriter, ManifestWriter, PrimitiveLiteral, Schema, Snapshot, TableMetadata,
+};
+use crate::table::Table;
+use crate::writer::file_writer::{FileWriter, FileWriterBuilder, ParquetWriterBuilder};
+use crate::{Error, ErrorKind, Result};
+
+/// The property of a deletion vector blob holding the path of the data file it applies to.
+const REFERENCED_DATA_FILE_PROPERTY: &str = "referenced-data-file";
+
+/// Table properties holding absolute paths.
+const PATH_PROPERTIES: [&str; 3] = [
+    "write.data.path",
+    "write.metadata.path",
+    "write.object-storage.path",
+];
+
+/// Rewrites the absolute paths of a table from a source prefix to a target prefix, e.g. to
+/// replicate a warehouse to another bucket.
+///
+/// Every file holding absolute paths — table metadata files, manifest lists, manifests,
+/// position delete files and deletion vector files — is rewritten to a staging location. The
+/// rewritten files, and the data files which are copied unchanged, are listed in the
+/// [`RewriteTablePathResult`]. No file is copied to the target location: the files are
+/// expected to be copied by an external tool, after which the table can be registered with
+/// the [`RewriteTablePathResult::latest_version`] metadata file.
+///
+/// The current metadata file and the previous versions of its metadata log are rewritten,
+/// along with the files reachable from the snapshots of the current metadata. Previous
+/// versions that no longer exist, e.g. deleted after commits with
+/// `write.metadata.delete-after-commit.enabled`, are skipped with a warning.
+pub struct RewriteTablePath<'a> {
+    table: &'a Table,
+    source_prefix: String,
+    target_prefix: String,
+    staging_location: Option<String>,
+    snapshot_ids: Option<HashSet<i64>>,
+}
+
+impl<'a> RewriteTablePath<'a> {
+    pub(crate) fn new(table: &'a Table, source_prefix: String, target_prefix: String) -> Self {
+        Self {
+            table,
+            source_prefix,
+            target_prefix,
+            staging_location: None,
+            snapshot_ids: None,
+        }
+    }
+
+    /// Sets the location the rewritten files are written to.
+    ///
+    /// Defaults to a `copy-table-staging-<uuid>` directory in the metadata directory of the
+    /// table.
+    pub fn with_staging_location(mut self, staging_location: impl ToString) -> Self {
+        self.staging_location = Some(staging_location.to_string());
+        self
+    }
+
+    /// Only rewrites the manifest lists, manifests and position delete files of the given
+    /// snapshots, and only lists their data files.
+    ///
+    /// This allows to incrementally copy the snapshots added since a previous copy. Defaults
+    /// to all snapshots of the current metadata.
+    pub fn with_snapshot_ids(mut self, snapshot_ids: impl IntoIterator<Item = i64>) -> Self {
+        self.snapshot_ids = Some(snapshot_ids.into_iter().collect());
+        self
+    }
+
+    /// Rewrites the files of the table to the staging location.
+    pub async fn execute(self) -> Result<RewriteTablePathResult> {
+        let metadata = self.table.metadata();
+        let metadata_location = self.table.metadata_location_result()?;
+
+        if metadata
+            .table_properties()?
+            .encryption_table_key_id
+            .is_some()
+        {
+            return Err(Error::new(
+                ErrorKind::FeatureUnsupported,
+                "Rewriting the paths of an encrypted table is not supported",
+            ));
+        }
+
+        if let Some(snapshot_ids) = &self.snapshot_ids
+            && let Some(missing) = snapshot_ids
+                .iter()
+                .find(|id| metadata.snapshot_by_id(**id).is_none())
+        {
+            return Err(Error::new(
+                ErrorKind::DataInvalid,
+                format!("Snapshot {missing} not found in table metadata"),
+            ));
+        }
+
+        let staging_location = self
+            .staging_location
+            .clone()
+            .unwrap_or_else(|| {
+                format!(
+                    "{}/metadata/copy-table-staging-{}",
+                    metadata.location(),
+                    Uuid::new_v4()
+                )
+            })
+            .trim_end_matches('/')
+            .to_string();
+        let mut rewriter = PathRewriter {
+            table: self.table,
+            source_prefix: &self.source_prefix,
+            target_prefix: &self.target_prefix,
+            staging_location: &staging_location,
+            rewritten_files: Vec::new(),
+            data_files: Vec::new(),
+            listed_files: HashSet::new(),
+            rewritten_manifests: HashMap::new(),
+            rewritten_delete_files: HashMap::new(),
+        };
+
+        let latest_version = rewriter
+            .rewrite_metadata_file(metadata.clone(), metadata_location)
+            .await?;
+        for log in metadata.metadata_log() {
+            if log.metadata_file == metadata_location {
+                continue;
+            }
+            if !self.table.file_io().exists(&log.metadata_file).await? {
+                tracing::warn!(
+                    "Skipping previous metadata file {} which no longer exists",
+                    log.metadata_file
+                );
+                continue;
+            }
+            let previous =
+                TableMetadata::read_from(self.table.file_io(), &log.metadata_file).await?;
+            rewriter
+                .rewrite_metadata_file(previous, &log.metadata_file)
+                .await?;
+        }
+
+        let is_selected = |snapshot_id: i64| {
+            self.snapshot_ids
+                .as_ref()
+                .is_none_or(|ids| ids.contains(&snapshot_id))
+        };
+        for snapshot in metadata.snapshots() {
+            if is_selected(snapshot.snapshot_id()) {
+                rewriter.rewrite_manifest_list(snapshot).await?;
+            }
+        }
+        for statistics in metadata.statistics_iter() {
+            if is_selected(statistics.snapshot_id) {
+                rewriter.list_data_file(&statistics.statistics_path)?;
+            }
+        }
+        for statistics in metadata.partition_statistics_iter() {
+            if is_selected(statistics.snapshot_id) {
+                rewriter.list_data_file(&statistics.statistics_path)?;
+            }
+        }
+
+        Ok(RewriteTablePathResult {
+            latest_version,
+            rewritten_files: rewriter.rewritten_files,
+            data_files: rewriter.data_files,
+            staging_location,
+        })
+    }
+}
+
+/// The outcome of a [`RewriteTablePath`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RewriteTablePathResult {
+    /// The location the rewritten files are written to.
+    pub staging_location: String,
+    /// The target location of the rewritten current metadata file.
+    pub latest_version: String,
+    /// The rewritten metadata files, manifest lists, manifests, position delete files and
+    /// deletion vector files, to be copied from the staging location to their target location.
+    pub rewritten_files: Vec<FileCopy>,
+    /// The data files, equality delete files and statistics files, which hold no absolute
+    /// paths, to be copied unchanged to their target location.
+    pub data_files: Vec<FileCopy>,
+}
+
+/// A file to copy from a source location to a target location.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileCopy {
+    /// The location to copy the file from.
+    pub source: String,
+    /// The location to copy the file to.
+    pub target: String,
+}
+
+struct PathRewriter<'a> {
+    table: &'a Table,
+    source_prefix: &'a str,
+    target_prefix: &'a str,
+    staging_location: &'a str,
+    rewritten_files: Vec<FileCopy>,
+    data_files: Vec<FileCopy>,
+    listed_files: HashSet<String>,
+    /// Manifests shared by several snapshots are only rewritten once.
+    rewritten_manifests: HashMap<String, ManifestFile>,
+    /// The rewritten position delete files and deletion vector files.
+    rewritten_delete_files: HashMap<String, RewrittenDeleteFile>,
+}
+
+/// A rewritten position delete file or deletion vector file.
+struct RewrittenDeleteFile {
+    size: u64,
+    /// The offsets and lengths of the rewritten deletion vectors, by their original offset.
+    blobs: HashMap<i64, (i64, i64)>,
+}
+
+impl PathRewriter<'_> {
+    fn relative_path<'p>(&self, path: &'p str) -> Result<&'p str> {
+        path.strip_prefix(self.source_prefix).ok_or_else(|| {
+            Error::new(
+                ErrorKind::DataInvalid,
+                format!(
+                    "Path {path} does not start with the source prefix {}",
+                    self.source_prefix
+                ),
+            )
+        })
+    }
+
+    fn target_path(&self, path: &str) -> Result<String> {
+        Ok(format!(
+            "{}{}",
+            self.target_prefix,
+            self.relative_path(path)?
+        ))
+    }
+
+    fn staging_path(&self, path: &str) -> Result<String> {
+        Ok(format!(
+            "{}/{}",
+            self.staging_location,
+            self.relative_path(path)?.trim_start_matches('/')
+        ))
+    }
+
+    /// Records a file written to the staging location.
+    fn add_rewritten_file(&mut self, path: &str) -> Result<()> {
+        let copy = FileCopy {
+            source: self.staging_path(path)?,
+            target: self.target_path(path)?,
+        };
+        self.rewritten_files.push(copy);
+        Ok(())
+    }
+
+    /// Records a file to copy unchanged.
+    fn list_data_file(&mut self, path: &str) -> Result<()> {
+        if self.listed_files.insert(path.to_string()) {
+            let copy = FileCopy {
+                source: path.to_string(),
+                target: self.target_path(path)?,
+            };
+            self.data_files.push(copy);
+        }
+        Ok(())
+    }
+
+    /// Rewrites a table metadata file, returning its target location.
+    async fn rewrite_metadata_file(
+        &mut self,
+        mut metadata: TableMetadata,
+        location: &str,
+    ) -> Result<String> {
+        metadata.location = self.target_path(&metadata.location)?;
+        for key in PATH_PROPERTIES {
+            if let Some(path) = metadata.properties.get(key) {
+                let path = self.target_path(path)?;
+                metadata.properties.insert(key.to_string(), path);
+            }
+        }
+        for snapshot in metadata.snapshots.values_mut() {
+            let mut rewritten = snapshot.as_ref().clone();
+            rewritten.manifest_list = self.target_path(&rewritten.manifest_list)?;
+            *snapshot = Arc::new(rewritten);
+        }
+        for log in &mut metadata.metadata_log {
+            log.metadata_file = self.target_path(&log.metadata_file)?;
+        }
+        for statistics in metadata.statistics.values_mut() {
+            statistics.statistics_path = self.target_path(&statistics.statistics_path)?;
+        }
+        for statistics in metadata.partition_statistics.values_mut() {
+            statistics.statistics_path = self.target_path(&statistics.statistics_path)?;
+        }
+
+        metadata
+            .write_to(self.table.file_io(), self.staging_path(location)?)
+            .await?;
+        self.add_rewritten_file(location)?;
+        self.target_path(location)
+    }
+
+    async fn rewrite_manifest_list(&mut self, snapshot: &Snapshot) -> Result<()> {
+        let metadata = self.table.metadata();
+        let manifest_list = snapshot
+            .load_manifest_list(self.table.file_io(), metadata)
+            .await?;
+
+        let mut manifests = Vec::with_capacity(manifest_list.entries().len());
+        for manifest in manifest_list.consume_entries() {
+            let rewritten = match self.rewritten_manifests.get(&manifest.manifest_path) {
+                Some(rewritten) => rewritten.clone(),
+                None => {
+                    let rewritten = self.rewrite_manifest(&manifest).await?;
+                    self.rewritten_manifests
+                        .insert(manifest.manifest_path, rewritten.clone());
+                    rewritten
+                }
+            };
+            manifests.push(rewritten);
+        }
+
+        let output = self
+            .table
+            .file_io()
+            .new_output(self.staging_path(snapshot.manifest_list())?)?;
+        let mut writer = match metadata.format_version() {
+            FormatVersion::V1 => ManifestListWriter::v1(
+                output,
+                snapshot.snapshot_id(),
+                snapshot.parent_snapshot_id(),
+            ),
+            FormatVersion::V2 => ManifestListWriter::v2(
+                output,
+                snapshot.snapshot_id(),
+                snapshot.parent_snapshot_id(),
+                snapshot.sequence_number(),
+            ),
+            FormatVersion::V3 => ManifestListWriter::v3(
+                output,
+                snapshot.snapshot_id(),
+                snapshot.parent_snapshot_id(),
+                snapshot.sequence_number(),
+                snapshot.first_row_id(),
+            ),
+        };
+        writer.add_manifests(manifests.into_iter())?;
+        writer.close().await?;
+
+        self.add_rewritten_file(snapshot.manifest_list())
+    }
+
+    /// Rewrites a manifest, returning the manifest list entry of the rewritten manifest.
+    async fn rewrite_manifest(&mut self, manifest: &ManifestFile) -> Result<ManifestFile> {
+        let file_io = self.table.file_io();
+        let content = file_io.new_input(&manifest.manifest_path)?.read().await?;
+        // The entries are read without inheriting values from the manifest list, so that
+        // they are written back unchanged.
+        let (manifest_metadata, entries) = Manifest::try_from_avro_bytes(&content)?;
+
+        let mut writer = ManifestWriter::new(
+            file_io.new_output(self.staging_path(&manifest.manifest_path)?)?,
+            Some(manifest.added_snapshot_id),
+            manifest.key_metadata.clone(),
+            manifest_metadata,
+            None,
+        );
+        for mut entry in entries {
+            let alive = entry.is_alive();
+            self.rewrite_data_file(&mut entry.data_file, alive).await?;
+            writer.add_entry_unchanged(entry)?;
+        }
+        let written = writer.write_manifest_file().await?;
+        self.add_rewritten_file(&manifest.manifest_path)?;
+
+        Ok(ManifestFile {
+            manifest_path: self.target_path(&manifest.manifest_path)?,
+            manifest_length: written.manifest_length,
+            ..manifest.clone()
+        })
+    }
+
+    /// Rewrites the paths of a manifest entry's file. The position delete files of alive
+    /// entries are rewritten, and the other files of alive entries are listed to be copied.
+    async fn rewrite_data_file(&mut self, data_file: &mut DataFile, alive: bool) -> Result<()> {
+        let path = data_file.file_path.clone();
+        if let Some(referenced) = &data_file.referenced_data_file {
+            data_file.referenced_data_file = Some(self.target_path(referenced)?);
+        }
+
+        if data_file.content == DataContentType::PositionDeletes {
+            for bounds in [&mut data_file.lower_bounds, &mut data_file.upper_bounds] {
+                if let Some(bound) = bounds.get(&RESERVED_FIELD_ID_DELETE_FILE_PATH)
+                    && let PrimitiveLiteral::String(bound_path) = bound.literal()
+                {
+                    let rewritten = Datum::string(self.target_path(bound_path)?);
+                    bounds.insert(RESERVED_FIELD_ID_DELETE_FILE_PATH, rewritten);
+                }
+            }
+
+            if alive {
+                if !self.rewritten_delete_files.contains_key(&path) {
+                    let rewritten = self.rewrite_delete_file(data_file).await?;
+                    self.rewritten_delete_files.insert(path.clone(), rewritten);
+                }
+                let rewritten = &self.rewritten_delete_files[&path];
+                data_file.file_size_in_bytes = rewritten.size;
+                if let Some(offset) = data_file.content_offset {
+                    let (offset, length) = rewritten.blobs.get(&offset).ok_or_else(|| {
+                        Error::new(
+                            ErrorKind::DataInvalid,
+                            format!("Deletion vector file has no blob at offset {offset}"),
+                        )
+                        .with_context("file_path", &path)
+                    })?;
+                    data_file.content_offset = Some(*offset);
+                    data_file.content_size_in_bytes = Some(*length);
+                }
+            }
+        } else if alive {
+            self.list_data_file(&path)?;
+        }
+
+        data_file.file_path = self.target_path(&path)?;
+        Ok(())
+    }
+
+    async fn rewrite_delete_file(&mut self, data_file: &DataFile) -> Result<RewrittenDeleteFile> {
+        match data_file.file_format {
+            DataFileFormat::Parquet => self.rewrite_position_delete_file(data_file).await,
+            DataFileFormat::Puffin => self.rewrite_deletion_vector_file(data_file).await,
+            file_format => Err(Error::new(
+                ErrorKind::FeatureUnsupported,
+                format!("Rewriting {file_format} position delete files is not supported"),
+            )
+            .with_context("file_path", &data_file.file_path)),
+        }
+    }
+
+    /// Rewrites the data file paths of a position delete file, keeping the writer properties
+    /// of the original file.
+    async fn rewrite_position_delete_file(
+        &mut self,
+        data_file: &DataFile,
+    ) -> Result<RewrittenDeleteFile> {
+        let file_io = self.table.file_io();
+        let builder = ArrowReader::create_parquet_record_batch_stream_builder(
+            &data_file.file_path,
+            file_io.clone(),
+            None,
+            data_file.file_size_in_bytes,
+            data_file.key_metadata(),
+            ParquetReadOptions::builder().build(),
+        )
+        .await?;
+        let writer_properties = writer_properties_of(builder.metadata());
+        let schema = Arc::new(position_delete_schema(builder.schema())?);
+        let arrow_schema = Arc::new(schema_to_arrow_schema(&schema)?);
+        let mut batches = builder.build()?.map_err(|e| {
+            Error::new(ErrorKind::Unexpected, "Failed to read position delete file").with_source(e)
+        });
+
+        let staging_path = self.staging_path(&data_file.file_path)?;
+        let mut writer = ParquetWriterBuilder::new(writer_properties, schema)
+            .build(file_io.new_output(&staging_path)?)
+            .await?;
+        while let Some(batch) = batches.try_next().await? {
+            let batch = self.rewrite_position_delete_batch(batch, arrow_schema.clone())?;
+            writer.write(&batch).await?;
+        }
+        if writer.close().await?.is_empty() {
+            return Err(
+                Error::new(ErrorKind::DataInvalid, "Position delete file has no rows")
+                    .with_context("file_path", &data_file.file_path),
+            );
+        }
+        self.add_rewritten_file(&data_file.file_path)?;
+
+        Ok(RewrittenDeleteFile {
+            size: file_io.new_input(&staging_path)?.metadata().await?.size,
+            blobs: HashMap::new(),
+        })
+    }
+
+    fn rewrite_position_delete_batch(
+        &self,
+        batch: RecordBatch,
+        schema: ArrowSchemaRef,
+    ) -> Result<RecordBatch> {
+        let mut columns = batch.columns().to_vec();
+        let Some(paths) = columns
+            .first()
+            .and_then(|column| column.as_any().downcast_ref::<StringArray>())
+        else {
+            return Err(Error::new(
+                ErrorKind::DataInvalid,
+                "Could not downcast file paths array to StringArray",
+            ));
+        };
+
+        let rewritten = paths
+            .iter()
+            .map(|path| path.map(|path| self.target_path(path)).transpose())
+            .collect::<Result<StringArray>>()?;
+        columns[0] = Arc::new(rewritten) as ArrayRef;
+
+        RecordBatch::try_new(schema, columns).map_err(|e| {
+            Error::new(
+                ErrorKind::Unexpected,
+                "Failed to rewrite position delete records",
+            )
+            .with_source(e)
+        })
+    }
+
+    /// Rewrites the referenced data files of the deletion vectors of a Puffin file, keeping
+    /// the other blobs unchanged.
+    async fn rewrite_deletion_vector_file(
+        &mut self,
+        data_file: &DataFile,
+    ) -> Result<RewrittenDeleteFile> {
+        let file_io = self.table.file_io();
+        let reader = PuffinReader::new(file_io.new_input(&data_file.file_path)?);
+        let file_metadata = reader.file_metadata().await?.clone();
+
+        let staging_path = self.staging_path(&data_file.file_path)?;
+        let output = file_io.new_output(&staging_path)?;
+        let mut writer =
+            PuffinWriter::new(&output, file_metadata.properties().clone(), false).await?;
+        for blob_metadata in file_metadata.blobs() {
+            let mut blob = reader.blob(blob_metadata).await?;
+            if let Some(referenced) = blob.properties.get(REFERENCED_DATA_FILE_PROPERTY) {
+                let referenced = self.target_path(referenced)?;
+                blob.properties
+                    .insert(REFERENCED_DATA_FILE_PROPERTY.to_string(), referenced);
+            }
+            writer.add(blob, blob_metadata.compression_codec()).await?;
+        }
+        writer.close().await?;
+        self.add_rewritten_file(&data_file.file_path)?;
+
+        let rewritten_input = file_io.new_input(&staging_path)?;
+        let size = rewritten_input.metadata().await?.size;
+        let rewritten_metadata = PuffinReader::new(rewritten_input)
+            .file_metadata()
+            .await?
+            .clone();
+        let blobs = file_metadata
+            .blobs()
+            .iter()
+            .zip(rewritten_metadata.blobs())
+            .map(|(original, rewritten)| {
+                Ok((
+                    original.offset().try_into()?,
+                    (
+                        rewritten.offset().try_into()?,
+                        rewritten.length().try_into()?,
+                    ),
+                ))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(RewrittenDeleteFile { size, blobs })
+    }
+}
+
+/// Returns the writer properties reproducing the format version, compression and key-value
+/// metadata of a Parquet file.
+fn writer_properties_of(metadata: &ParquetMetaData) -> WriterProperties {
+    let file_metadata = metadata.file_metadata();
+    let writer_version = if file_metadata.version() >= 2 {
+        WriterVersion::PARQUET_2_0
+    } else {
+        WriterVersion::PARQUET_1_0
+    };
+    // The Arrow schema is written again by the writer.
+    let key_value_metadata = file_metadata.key_value_metadata().map(|key_values| {
+        key_values
+            .iter()
+            .filter(|key_value| key_value.key != ARROW_SCHEMA_META_KEY)
+            .cloned()
+            .collect()
+    });
+
+    let mut builder = WriterProperties::builder()
+        .set_writer_version(writer_version)
+        .set_key_value_metadata(key_value_metadata);
+    if let Some(row_group) = metadata.row_groups().first() {
+        for column in row_group.columns() {
+            builder =
+                builder.set_column_compression(column.column_path().clone(), column.compression());
+        }
+    }
+    builder.build()
+}
+
+/// Returns the spec schema of a position delete file, with the deprecated `row` column of
+/// the original file if it has one.
+fn position_delete_schema(file_schema: &ArrowSchema) -> Result<Schema> {
+    let mut fields = vec![
+        delete_file_path_field().clone(),
+        delete_file_pos_field().clone(),
+    ];
+    fields.extend(
+        arrow_schema_to_schema(file_schema)?
+            .as_struct()
+            .fields()
+            .iter()
+            .skip(fields.len())
+            .cloned(),
+    );
+    Schema::builder().with_fields(fields).build()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::fs::File;
+    use std::sync::Arc;
+
+    use arrow_array::{Int64Array, RecordBatch, StringArray};
+    use parquet::arrow::ArrowWriter;
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use parquet::basic::Compression;
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::arrow::delete_filter::tests::create_pos_del_schema;
+    use crate::compression::CompressionCodec;
+    use crate::puffin::{Blob, DELETION_VECTOR_V1};
+    use crate::scan::tests::TableTestFixture;
+    use crate::spec::{
+        DataFileBuilder, ManifestEntry, ManifestList, ManifestStatus, ManifestWriterBuilder,
+        MetadataLog, Struct,
+    };
+
+    const TARGET_PREFIX: &str = "s3://bucket/table1";
+
+    /// Writes the current metadata of the fixture table, an empty manifest list for the
+    /// parent snapshot, and for the current snapshot a data manifest and a delete manifest
+    /// with a position delete file.
+    async fn setup_table() -> TableTestFixture {
+        let fixture = TableTestFixture::new();
+        let table = &fixture.table;
+        let location = &fixture.table_location;
+        let file_io = table.file_io();
+        let metadata = table.metadata();
+        let current_snapshot = metadata.current_snapshot().unwrap();
+        let parent_snapshot = current_snapshot.parent_snapshot(metadata).unwrap();
+        let schema = current_snapshot.schema(metadata).unwrap();
+        let partition_spec = metadata.default_partition_spec().as_ref().clone();
+
+        metadata
+            .write_to(file_io, table.metadata_location().unwrap())
+            .await
+            .unwrap();
+
+        std::fs::create_dir_all(format!("{location}/data")).unwrap();
+        let position_deletes = RecordBatch::try_new(create_pos_del_schema(), vec![
+            Arc::new(StringArray::from(vec![
+                format!("{location}/data/1.parquet"),
+                format!("{location}/data/2.parquet"),
+            ])),
+            Arc::new(Int64Array::from(vec![0, 5])),
+        ])
+        .unwrap();
+        let delete_file_path = format!("{location}/data/pos-del-1.parquet");
+        let mut writer = ArrowWriter::try_new(
+            File::create(&delete_file_path).unwrap(),
+            position_deletes.schema(),
+            Some(
+                WriterProperties::builder()
+                    .set_compression(Compression::SNAPPY)
+                    .build(),
+            ),
+        )
+        .unwrap();
+        writer.write(&position_deletes).unwrap();
+        writer.close().unwrap();
+        let delete_file_size = std::fs::metadata(&delete_file_path).unwrap().len();
+
+        let data_file = |name: &str| {
+            DataFileBuilder::default()
+                .partition_spec_id(0)
+                .content(DataContentType::Data)
+                .file_path(format!("{location}/data/{name}"))
+                .file_format(DataFileFormat::Parquet)
+                .file_size_in_bytes(100)
+                .record_count(10)
+                .partition(Struct::from_iter([Some(crate::spec::Literal::long(1))]))
+                .build()
+                .unwrap()
+        };
+        let mut writer = ManifestWriterBuilder::new(
+            file_io
+                .new_output(format!("{location}/metadata/data-manifest.avro"))
+                .unwrap(),
+            Some(current_snapshot.snapshot_id()),
+            None,
+            schema.clone(),
+            partition_spec.clone(),
+        )
+        .build_v2_data();
+        writer.add_file(data_file("1.parquet"), -1).unwrap();
+        writer
+            .add_existing_file(
+                data_file("2.parquet"),
+                parent_snapshot.snapshot_id(),
+                parent_snapshot.sequence_number(),
+                Some(parent_snapshot.sequence_number()),
+            )
+            .unwrap();
+        writer
+            .add_delete_file(
+                data_file("3.parquet"),
+                parent_snapshot.sequence_number(),
+                Some(parent_snapshot.sequence_number()),
+            )
+            .unwrap();
+        let data_manifest = writer.write_manifest_file().await.unwrap();
+
+        let mut writer = ManifestWriterBuilder::new(
+            file_io
+                .new_output(format!("{location}/metadata/delete-manifest.avro"))
+                .unwrap(),
+            Some(current_snapshot.snapshot_id()),
+            None,
+            schema,
+            partition_spec,
+        )
+        .build_v2_deletes();
+        writer
+            .add_entry(
+                ManifestEntry::builder()
+                    .status(ManifestStatus::Added)
+                    .data_file(
+                        DataFileBuilder::default()
+                            .partition_spec_id(0)
+                            .content(DataContentType::PositionDeletes)
+                            .file_path(delete_file_path)
+                            .file_format(DataFileFormat::Parquet)
+                            .file_size_in_bytes(delete_file_size)
+                            .record_count(2)
+                            .partition(Struct::from_iter([Some(crate::spec::Literal::long(1))]))
+                            .lower_bounds(HashMap::from([(
+                                RESERVED_FIELD_ID_DELETE_FILE_PATH,
+                                Datum::string(format!("{location}/data/1.parquet")),
+                            )]))
+                            .upper_bounds(HashMap::from([(
+                                RESERVED_FIELD_ID_DELETE_FILE_PATH,
+                                Datum::string(format!("{location}/data/2.parquet")),
+                            )]))
+                            .build()
+                            .unwrap(),
+                    )
+                    .build(),
+            )
+            .unwrap();
+        let delete_manifest = writer.write_manifest_file().await.unwrap();
+
+        let mut writer = ManifestListWriter::v2(
+            file_io
+                .new_output(current_snapshot.manifest_list())
+                .unwrap(),
+            current_snapshot.snapshot_id(),
+            current_snapshot.parent_snapshot_id(),
+            current_snapshot.sequence_number(),
+        );
+        writer
+            .add_manifests(vec![data_manifest, delete_manifest].into_iter())
+            .unwrap();
+        writer.close().await.unwrap();
+
+        ManifestListWriter::v2(
+            file_io.new_output(parent_snapshot.manifest_list()).unwrap(),
+            parent_snapshot.snapshot_id(),
+            None,
+            parent_snapshot.sequence_number(),
+        )
+        .close()
+        .await
+        .unwrap();
+
+        fixture
+    }
+
+    fn target(fixture: &TableTestFixture, path: &str) -> String {
+        path.replacen(&fixture.table_location, TARGET_PREFIX, 1)
+    }
+
+    #[tokio::test]
+    async fn test_rewrite_table_path() {
+        let fixture = setup_table().await;
+        let table = &fixture.table;
+        let file_io = table.file_io();
+        let location = &fixture.table_location;
+        let staging = TempDir::new().unwrap();
+        let staging_location = staging.path().to_str().unwrap();
+
+        let result = table
+            .rewrite_table_path(location, TARGET_PREFIX)
+            .with_staging_location(staging_location)
+            .execute()
+            .await
+            .unwrap();
+
+        assert_eq!(result.staging_location, staging_location);
+        assert_eq!(
+            result.latest_version,
+            target(&fixture, table.metadata_location().unwrap())
+        );
+        let current_snapshot = table.metadata().current_snapshot().unwrap();
+        let parent_snapshot = current_snapshot.parent_snapshot(table.metadata()).unwrap();
+        let rewritten_targets = result
+            .rewritten_files
+            .iter()
+            .map(|copy| copy.target.clone())
+            .collect::<HashSet<_>>();
+        assert_eq!(
+            rewritten_targets,
+            HashSet::from([
+                result.latest_version.clone(),
+                target(&fixture, current_snapshot.manifest_list()),
+                target(&fixture, parent_snapshot.manifest_list()),
+                format!("{TARGET_PREFIX}/metadata/data-manifest.avro"),
+                format!("{TARGET_PREFIX}/metadata/delete-manifest.avro"),
+                format!("{TARGET_PREFIX}/data/pos-del-1.parquet"),
+            ])
+        );
+        for copy in &result.rewritten_files {
+            assert_eq!(
+                copy.source,
+                copy.target.replacen(TARGET_PREFIX, staging_location, 1)
+            );
+        }
+        assert_eq!(result.data_files, vec![
+            FileCopy {
+                source: format!("{location}/data/1.parquet"),
+                target: format!("{TARGET_PREFIX}/data/1.parquet"),
+            },
+            FileCopy {
+                source: format!("{location}/data/2.parquet"),
+                target: format!("{TARGET_PREFIX}/data/2.parquet"),
+            },
+        ]);
+
+        let staged = |path: &str| format!("{staging_location}{}", &path[location.len()..]);
+
+        // The metadata points to the target locations.
+        let metadata =
+            TableMetadata::read_from(file_io, staged(table.metadata_location().unwrap()))
+                .await
+                .unwrap();
+        assert_eq!(metadata.location(), TARGET_PREFIX);
+        assert_eq!(metadata.uuid(), table.metadata().uuid());
+        assert_eq!(
+            metadata.current_snapshot().unwrap().manifest_list(),
+            target(&fixture, current_snapshot.manifest_list())
+        );
+
+        // The manifest list points to the rewritten manifests.
+        let original_list = current_snapshot
+            .load_manifest_list(file_io, table.metadata())
+            .await
+            .unwrap();
+        let manifest_list = ManifestList::parse_with_version(
+            &file_io
+                .new_input(staged(current_snapshot.manifest_list()))
+                .unwrap()
+                .read()
+                .await
+                .unwrap(),
+            FormatVersion::V2,
+        )
+        .unwrap();
+        assert_eq!(manifest_list.entries().len(), 2);
+        for (original, rewritten) in original_list.entries().iter().zip(manifest_list.entries()) {
+            assert_eq!(
+                rewritten.manifest_path,
+                target(&fixture, &original.manifest_path)
+            );
+            assert_eq!(rewritten.sequence_number, original.sequence_number);
+            assert_eq!(rewritten.added_files_count, original.added_files_count);
+        }
+
+        // The manifest entries keep their status and point to the target locations.
+        let manifest = Manifest::parse_avro(
+            &file_io
+                .new_input(staged(&format!("{location}/metadata/data-manifest.avro")))
+                .unwrap()
+                .read()
+                .await
+                .unwrap(),
+        )
+        .unwrap();
+        let entries = manifest
+            .entries()
+            .iter()
+            .map(|entry| (entry.status(), entry.file_path().to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(entries, vec![
+            (
+                ManifestStatus::Added,
+                format!("{TARGET_PREFIX}/data/1.parquet")
+            ),
+            (
+                ManifestStatus::Existing,
+                format!("{TARGET_PREFIX}/data/2.parquet")
+            ),
+            (
+                ManifestStatus::Deleted,
+                format!("{TARGET_PREFIX}/data/3.parquet")
+            ),
+        ]);
+
+        // The position delete file refers to the target data file locations.
+        let staged_delete_file = staged(&format!("{location}/data/pos-del-1.parquet"));
+        let manifest = Manifest::parse_avro(
+            &file_io
+                .new_input(staged(&format!("{location}/metadata/delete-manifest.avro")))
+                .unwrap()
+                .read()
+                .await
+                .unwrap(),
+        )
+        .unwrap();
+        let delete_file = manifest.entries()[0].data_file();
+        assert_eq!(
+            delete_file.file_size_in_bytes(),
+            std::fs::metadata(&staged_delete_file).unwrap().len()
+        );
+        assert_eq!(
+            delete_file.lower_bounds()[&RESERVED_FIELD_ID_DELETE_FILE_PATH],
+            Datum::string(format!("{TARGET_PREFIX}/data/1.parquet"))
+        );
+        assert_eq!(
+            delete_file.upper_bounds()[&RESERVED_FIELD_ID_DELETE_FILE_PATH],
+            Datum::string(format!("{TARGET_PREFIX}/data/2.parquet"))
+        );
+
+        // The rewritten file keeps the compression of the original and has the spec schema.
+        let reader =
+            ParquetRecordBatchReaderBuilder::try_new(File::open(&staged_delete_file).unwrap())
+                .unwrap();
+        assert_eq!(
+            reader.metadata().row_group(0).column(0).compression(),
+            Compression::SNAPPY
+        );
+        assert_eq!(
+            arrow_schema_to_schema(reader.schema()).unwrap().as_struct(),
+            position_delete_schema(&create_pos_del_schema())
+                .unwrap()
+                .as_struct()
+        );
+        let batches = reader
+            .build()
+            .unwrap()
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .unwrap();
+        let paths = batches[0]
+            .column(0)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(
+            paths,
+            &StringArray::from(vec![
+                format!("{TARGET_PREFIX}/data/1.parquet"),
+                format!("{TARGET_PREFIX}/data/2.parquet"),
+            ])
+        );
+        assert_eq!(
+            batches[0].column(1).as_ref(),
+            &Int64Array::from(vec![0, 5]) as &dyn Array
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rewrite_table_path_deletion_vectors() {
+        let fixture = setup_table().await;
+        let table = &fixture.table;
+        let file_io = table.file_io();
+        let location = &fixture.table_location;
+        let metadata = table.metadata();
+        let current_snapshot = metadata.current_snapshot().unwrap();
+
+        // A Puffin file with the deletion vectors of two data files.
+        let dv_path = format!("{location}/data/dv-1.puffin");
+        let mut writer = PuffinWriter::new(
+            &file_io.new_output(&dv_path).unwrap(),
+            HashMap::new(),
+            false,
+        )
+        .await
+        .unwrap();
+        for (name, data) in [("1.parquet", vec![1, 2, 3]), ("2.parquet", vec![4, 5])] {
+            let blob = Blob::builder()
+                .r#type(DELETION_VECTOR_V1.to_string())
+                .fields(vec![])
+                .snapshot_id(current_snapshot.snapshot_id())
+                .sequence_number(current_snapshot.sequence_number())
+                .data(data)
+                .properties(HashMap::from([(
+                    REFERENCED_DATA_FILE_PROPERTY.to_string(),
+                    format!("{location}/data/{name}"),
+                )]))
+                .build();
+            writer.add(blob, CompressionCodec::None).await.unwrap();
+        }
+        writer.close().await.unwrap();
+        let dv_size = file_io
+            .new_input(&dv_path)
+            .unwrap()
+            .metadata()
+            .await
+            .unwrap()
+            .size;
+        let blob = PuffinReader::new(file_io.new_input(&dv_path).unwrap())
+            .file_metadata()
+            .await
+            .unwrap()
+            .blobs()[1]
+            .clone();
+
+        let mut writer = ManifestWriterBuilder::new(
+            file_io
+                .new_output(format!("{location}/metadata/dv-manifest.avro"))
+                .unwrap(),
+            Some(current_snapshot.snapshot_id()),
+            None,
+            current_snapshot.schema(metadata).unwrap(),
+            metadata.default_partition_spec().as_ref().clone(),
+        )
+        .build_v3_deletes();
+        writer
+            .add_file(
+                DataFileBuilder::default()
+                    .partition_spec_id(0)
+                    .content(DataContentType::PositionDeletes)
+                    .file_path(dv_path.clone())
+                    .file_format(DataFileFormat::Puffin)
+                    .file_size_in_bytes(dv_size)
+                    .record_count(2)
+                    .partition(Struct::from_iter([Some(crate::spec::Literal::long(1))]))
+                    .referenced_data_file(Some(format!("{location}/data/2.parquet")))
+                    .content_offset(Some(blob.offset() as i64))
+                    .content_size_in_bytes(Some(blob.length() as i64))
+                    .build()
+                    .unwrap(),
+                current_snapshot.sequence_number(),
+            )
+            .unwrap();
+        let dv_manifest = writer.write_manifest_file().await.unwrap();
+        let mut writer = ManifestListWriter::v2(
+            file_io
+                .new_output(current_snapshot.manifest_list())
+                .unwrap(),
+            current_snapshot.snapshot_id(),
+            current_snapshot.parent_snapshot_id(),
+            current_snapshot.sequence_number(),
+        );
+        writer.add_manifests(vec![dv_manifest].into_iter()).unwrap();
+        writer.close().await.unwrap();
+
+        let staging = TempDir::new().unwrap();
+        let staging_location = staging.path().to_str().unwrap();
+        let result = table
+            .rewrite_table_path(location, TARGET_PREFIX)
+            .with_staging_location(staging_location)
+            .execute()
+            .await
+            .unwrap();
+        let staged = |path: &str| format!("{staging_location}{}", &path[location.len()..]);
+        assert!(
+            result
+                .rewritten_files
+                .iter()
+                .any(|copy| copy.target == format!("{TARGET_PREFIX}/data/dv-1.puffin"))
+        );
+
+        // The deletion vectors refer to the target data file locations.
+        let staged_dv = file_io.new_input(staged(&dv_path)).unwrap();
+        let staged_dv_size = staged_dv.metadata().await.unwrap().size;
+        let reader = PuffinReader::new(staged_dv);
+        let blobs = reader.file_metadata().await.unwrap().blobs().to_vec();
+        let referenced = blobs
+            .iter()
+            .map(|blob| blob.properties()[REFERENCED_DATA_FILE_PROPERTY].clone())
+            .collect::<Vec<_>>();
+        assert_eq!(referenced, vec![
+            format!("{TARGET_PREFIX}/data/1.parquet"),
+            format!("{TARGET_PREFIX}/data/2.parquet"),
+        ]);
+        assert_eq!(reader.blob(&blobs[1]).await.unwrap().data(), &[4, 5]);
+
+        // The manifest entry points to the rewritten blob.
+        let manifest = Manifest::parse_avro(
+            &file_io
+                .new_input(staged(&format!("{location}/metadata/dv-manifest.avro")))
+                .unwrap()
+                .read()
+                .await
+                .unwrap(),
+        )
+        .unwrap();
+        let dv = manifest.entries()[0].data_file();
+        assert_eq!(dv.file_path(), format!("{TARGET_PREFIX}/data/dv-1.puffin"));
+        assert_eq!(dv.file_size_in_bytes(), staged_dv_size);
+        assert_eq!(
+            dv.referenced_data_file(),
+            Some(format!("{TARGET_PREFIX}/data/2.parquet"))
+        );
+        assert_eq!(dv.content_offset(), Some(blobs[1].offset() as i64));
+        assert_eq!(dv.content_size_in_bytes(), Some(blobs[1].length() as i64));
+    }
+
+    #[tokio::test]
+    async fn test_rewrite_table_path_selected_snapshots() {
+        let fixture = setup_table().await;
+        let table = &fixture.table;
+        let parent_snapshot = table
+            .metadata()
+            .current_snapshot()
+            .unwrap()
+            .parent_snapshot(table.metadata())
+            .unwrap();
+        let staging = TempDir::new().unwrap();
+
+        let result = table
+            .rewrite_table_path(&fixture.table_location, TARGET_PREFIX)
+            .with_staging_location(staging.path().to_str().unwrap())
+            .with_snapshot_ids([parent_snapshot.snapshot_id()])
+            .execute()
+            .await
+            .unwrap();
+
+        let rewritten_targets = result
+            .rewritten_files
+            .iter()
+            .map(|copy| copy.target.clone())
+            .collect::<HashSet<_>>();
+        assert_eq!(
+            rewritten_targets,
+            HashSet::from([
+                result.latest_version.clone(),
+                target(&fixture, parent_snapshot.manifest_list()),
+            ])
+        );
+        assert!(result.data_files.is_empty());
+
+        let err = table
+            .rewrite_table_path(&fixture.table_location, TARGET_PREFIX)
+            .with_snapshot_ids([1])
+            .execute()
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::DataInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_rewrite_table_path_skips_deleted_previous_versions() {
+        let fixture = setup_table().await;
+        let location = &fixture.table_location;
+        let file_io = fixture.table.file_io();
+        let previous_location = format!("{location}/metadata/v1.metadata.json");
+        let deleted_location = format!("{location}/metadata/v0.metadata.json");
+        fixture
+            .table
+            .metadata()
+            .write_to(file_io, &previous_location)
+            .await
+            .unwrap();
+        let mut metadata = fixture.table.metadata().clone();
+        let last_updated_ms = metadata.last_updated_ms();
+        metadata.metadata_log = vec![
+            MetadataLog {
+                metadata_file: deleted_location.clone(),
+                timestamp_ms: last_updated_ms - 2,
+            },
+            MetadataLog {
+                metadata_file: previous_location.clone(),
+                timestamp_ms: last_updated_ms - 1,
+            },
+        ];
+        let metadata_location = fixture.table.metadata_location().unwrap().to_string();
+        metadata
+            .write_to(file_io, &metadata_location)
+            .await
+            .unwrap();
+        let table = fixture.table.clone().with_metadata(Arc::new(metadata));
+        let staging = TempDir::new().unwrap();
+
+        let result = table
+            .rewrite_table_path(location, TARGET_PREFIX)
+            .with_staging_location(staging.path().to_str().unwrap())
+            .execute()
+            .await
+            .unwrap();
+
+        let rewritten_targets = result
+            .rewritten_files
+            .iter()
+            .map(|copy| copy.target.clone())
+            .collect::<HashSet<_>>();
+        assert!(rewritten_targets.contains(&target(&fixture, &metadata_location)));
+        assert!(rewritten_targets.contains(&target(&fixture, &previous_location)));
+        assert!(!rewritten_targets.contains(&target(&fixture, &deleted_location)));
+    }
+
+    #[tokio::test]
+    async fn test_rewrite_table_path_outside_of_source_prefix() {
+        let fixture = setup_table().await;
+        let staging = TempDir::new().unwrap();
+
+        let err = fixture
+            .table
+            .rewrite_table_path("s3://other-bucket", TARGET_PREFIX)
+            .with_staging_location(staging.path().to_str().unwrap())
+            .execute()
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::DataInvalid);
+        assert!(
+            err.message()
+                .contains("does not start with the source prefix")
+        );
+    }
+}
@@ -56,6 +56,7 @@ impl BasicDeleteFileLoader {
         &self,
         data_file_path: &str,
         file_size_in_bytes: u64,
+        key_metadata: Option<&[u8]>,
     ) -> Result<ArrowRecordBatchStream> {
         /*
            Essentially a super-cut-down ArrowReader. We can't use ArrowReader directly
@@ -66,6 +67,7 @@ impl BasicDeleteFileLoader {
             self.file_io.clone(),
             None,
             file_size_in_bytes,
+            key_metadata,
             ParquetReadOptions::builder().build(),
         )
         .await?
@@ -105,7 +107,11 @@ impl DeleteFileLoader for BasicDeleteFileLoader {
         schema: SchemaRef,
     ) -> Result<ArrowRecordBatchStream> {
         let raw_batch_stream = self
-            .parquet_to_batch_stream(&task.file_path, task.file_size_in_bytes)
+            .parquet_to_batch_stream(
+                &task.file_path,
+                task.file_size_in_bytes,
+                task.key_metadata.as_deref(),
+            )
             .await?;
 
         // For equality deletes, only evolve the equality_ids columns.
@@ -384,6 +384,7 @@ pub(crate) mod tests {
             file_type: DataContentType::PositionDeletes,
             partition_spec_id: 0,
             equality_ids: None,
+            key_metadata: None,
         };
 
         let pos_del_2 = FileScanTaskDeleteFile {
@@ -397,6 +398,7 @@ pub(crate) mod tests {
             file_type: DataContentType::PositionDeletes,
             partition_spec_id: 0,
             equality_ids: None,
+            key_metadata: None,
         };
 
         let pos_del_3 = FileScanTaskDeleteFile {
@@ -410,6 +412,7 @@ pub(crate) mod tests {
             file_type: DataContentType::PositionDeletes,
             partition_spec_id: 0,
             equality_ids: None,
+            key_metadata: None,
         };
 
         let file_scan_tasks = vec![
@@ -427,6 +430,9 @@ pub(crate) mod tests {
                 partition: None,
                 partition_spec: None,
                 name_mapping: None,
+                first_row_id: None,
+                data_sequence_number: None,
+                key_metadata: None,
                 case_sensitive: false,
             },
             FileScanTask {
@@ -443,6 +449,9 @@ pub(crate) mod tests {
                 partition: None,
                 partition_spec: None,
                 name_mapping: None,
+                first_row_id: None,
+                data_sequence_number: None,
+                key_metadata: None,
                 case_sensitive: false,
             },
         ];
@@ -496,10 +505,14 @@ pub(crate) mod tests {
                 file_type: DataContentType::EqualityDeletes,
                 partition_spec_id: 0,
                 equality_ids: None,
+                key_metadata: None,
             }],
             partition: None,
             partition_spec: None,
             name_mapping: None,
+            first_row_id: None,
+            data_sequence_number: None,
+            key_metadata: None,
             case_sensitive: true,
         };
 
@@ -0,0 +1,181 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! The module contains the visitor for calculating bounding boxes of geometry columns in
+//! given arrow record batch.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::{Array, ArrayRef, RecordBatch, StructArray};
+use arrow_schema::DataType;
+
+use crate::Result;
+use crate::arrow::{ArrowArrayAccessor, FieldMatchMode};
+use crate::spec::{
+    BoundingBox, ListType, MapType, NestedFieldRef, PrimitiveType, Schema, SchemaRef,
+    SchemaWithPartnerVisitor, StructType, Type, visit_struct_with_partner,
+};
+
+/// Visitor which computes the bounding boxes of the WKB values of geometry columns in
+/// given record batch(s).
+///
+/// Geography columns are skipped: the bounding box of their vertices does not cover
+/// edges interpolated on the ellipsoid, so it can't be used as bounds.
+pub(crate) struct GeospatialBoundsVisitor {
+    bounds: HashMap<i32, BoundingBox>,
+    /// Columns containing values that are not valid WKB, which therefore have no bounds.
+    invalid_field_ids: HashSet<i32>,
+    match_mode: FieldMatchMode,
+}
+
+impl GeospatialBoundsVisitor {
+    /// Creates new instance of GeospatialBoundsVisitor with explicit match mode
+    pub(crate) fn new_with_match_mode(match_mode: FieldMatchMode) -> Self {
+        Self {
+            bounds: HashMap::new(),
+            invalid_field_ids: HashSet::new(),
+            match_mode,
+        }
+    }
+
+    /// Compute bounding boxes in given schema and record batch
+    pub(crate) fn compute(&mut self, schema: SchemaRef, batch: RecordBatch) -> Result<()> {
+        let arrow_arr_partner_accessor = ArrowArrayAccessor::new_with_match_mode(self.match_mode);
+
+        let struct_arr = Arc::new(StructArray::from(batch)) as ArrayRef;
+        visit_struct_with_partner(
+            schema.as_struct(),
+            &struct_arr,
+            self,
+            &arrow_arr_partner_accessor,
+        )?;
+
+        Ok(())
+    }
+
+    /// Returns the bounding box of each geometry column with at least one non-empty value.
+    pub(crate) fn bounds(&self) -> HashMap<i32, BoundingBox> {
+        self.bounds
+            .iter()
+            .filter(|(field_id, _)| !self.invalid_field_ids.contains(field_id))
+            .map(|(field_id, bbox)| (*field_id, *bbox))
+            .collect()
+    }
+
+    fn update(&mut self, field: &NestedFieldRef, col: &ArrayRef) {
+        if !matches!(
+            field.field_type.as_ref(),
+            Type::Primitive(PrimitiveType::Geometry { .. })
+        ) || self.invalid_field_ids.contains(&field.id)
+        {
+            return;
+        }
+
+        let values: Box<dyn Iterator<Item = Option<&[u8]>>> = match col.data_type() {
+            DataType::Binary => Box::new(col.as_binary::<i32>().iter()),
+            DataType::LargeBinary => Box::new(col.as_binary::<i64>().iter()),
+            DataType::BinaryView => Box::new(col.as_binary_view().iter()),
+            _ => return,
+        };
+
+        for wkb in values.flatten() {
+            match BoundingBox::try_from_wkb(wkb) {
+                Ok(Some(bbox)) => {
+                    self.bounds
+                        .entry(field.id)
+                        .and_modify(|bounds| *bounds = bounds.merge(&bbox))
+                        .or_insert(bbox);
+                }
+                Ok(None) => {}
+                Err(_) => {
+                    self.invalid_field_ids.insert(field.id);
+                    return;
+                }
+            }
+        }
+    }
+}
+
+impl SchemaWithPartnerVisitor<ArrayRef> for GeospatialBoundsVisitor {
+    type T = ();
+
+    fn schema(
+        &mut self,
+        _schema: &Schema,
+        _partner: &ArrayRef,
+        _value: Self::T,
+    ) -> Result<Self::T> {
+        Ok(())
+    }
+
+    fn field(
+        &mut self,
+        _field: &NestedFieldRef,
+        _partner: &ArrayRef,
+        _value: Self::T,
+    ) -> Result<Self::T> {
+        Ok(())
+    }
+
+    fn r#struct(
+        &mut self,
+        _struct: &StructType,
+        _partner: &ArrayRef,
+        _results: Vec<Self::T>,
+    ) -> Result<Self::T> {
+        Ok(())
+    }
+
+    fn list(&mut self, _list: &ListType, _list_arr: &ArrayRef, _value: Self::T) -> Result<Self::T> {
+        Ok(())
+    }
+
+    fn map(
+        &mut self,
+        _map: &MapType,
+        _partner: &ArrayRef,
+        _key_value: Self::T,
+        _value: Self::T,
+    ) -> Result<Self::T> {
+        Ok(())
+    }
+
+    fn primitive(&mut self, _p: &PrimitiveType, _col: &ArrayRef) -> Result<Self::T> {
+        Ok(())
+    }
+
+    fn variant(&mut self, _col: &ArrayRef) -> Result<Self::T> {
+        Ok(())
+    }
+
+    fn after_struct_field(&mut self, field: &NestedFieldRef, partner: &ArrayRef) -> Result<()> {
+        self.update(field, partner);
+        Ok(())
+    }
+
+    fn after_list_element(&mut self, field: &NestedFieldRef, partner: &ArrayRef) -> Result<()> {
+        self.update(field, partner);
+        Ok(())
+    }
+
+    fn after_map_value(&mut self, field: &NestedFieldRef, partner: &ArrayRef) -> Result<()> {
+        self.update(field, partner);
+        Ok(())
+    }
+}
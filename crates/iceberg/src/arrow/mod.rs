@@ -22,6 +22,8 @@ pub use schema::*;
 
 mod nan_val_cnt_visitor;
 pub(crate) use nan_val_cnt_visitor::*;
+mod geospatial_bounds_visitor;
+pub(crate) use geospatial_bounds_visitor::*;
 pub(crate) mod caching_delete_file_loader;
 /// Delete File loader
 pub mod delete_file_loader;
@@ -31,6 +33,7 @@ mod reader;
 /// RecordBatch projection utilities
 pub mod record_batch_projector;
 pub(crate) mod record_batch_transformer;
+pub mod row_lineage;
 mod value;
 
 pub use reader::*;
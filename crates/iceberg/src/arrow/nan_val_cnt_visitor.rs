@@ -122,6 +122,10 @@ impl SchemaWithPartnerVisitor<ArrayRef> for NanValueCountVisitor {
         Ok(())
     }
 
+    fn variant(&mut self, _col: &ArrayRef) -> Result<Self::T> {
+        Ok(())
+    }
+
     fn after_struct_field(&mut self, field: &NestedFieldRef, partner: &ArrayRef) -> Result<()> {
         let field_id = field.id;
         count_float_nans!(partner, self, field_id);
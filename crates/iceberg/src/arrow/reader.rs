@@ -23,11 +23,12 @@ use std::str::FromStr;
 use std::sync::Arc;
 
 use arrow_arith::boolean::{and, and_kleene, is_not_null, is_null, not, or, or_kleene};
+use arrow_array::cast::AsArray;
 use arrow_array::{Array, ArrayRef, BooleanArray, Datum as ArrowDatum, RecordBatch, Scalar};
 use arrow_cast::cast::cast;
 use arrow_ord::cmp::{eq, gt, gt_eq, lt, lt_eq, neq};
 use arrow_schema::{
-    ArrowError, DataType, FieldRef, Schema as ArrowSchema, SchemaRef as ArrowSchemaRef,
+    ArrowError, DataType, Field, FieldRef, Schema as ArrowSchema, SchemaRef as ArrowSchemaRef,
 };
 use arrow_string::like::starts_with;
 use bytes::Bytes;
@@ -38,7 +39,9 @@ use parquet::arrow::arrow_reader::{
     ArrowPredicateFn, ArrowReaderOptions, RowFilter, RowSelection, RowSelector,
 };
 use parquet::arrow::async_reader::AsyncFileReader;
-use parquet::arrow::{PARQUET_FIELD_ID_META_KEY, ParquetRecordBatchStreamBuilder, ProjectionMask};
+use parquet::arrow::{
+    PARQUET_FIELD_ID_META_KEY, ParquetRecordBatchStreamBuilder, ProjectionMask, RowNumber,
+};
 use parquet::file::metadata::{
     PageIndexPolicy, ParquetMetaData, ParquetMetaDataReader, RowGroupMetaData,
 };
@@ -49,15 +52,21 @@ use crate::arrow::caching_delete_file_loader::CachingDeleteFileLoader;
 use crate::arrow::record_batch_transformer::RecordBatchTransformerBuilder;
 use crate::arrow::{arrow_schema_to_schema, get_arrow_datum};
 use crate::delete_vector::DeleteVector;
+use crate::encryption::StandardKeyMetadata;
 use crate::error::Result;
 use crate::expr::visitors::bound_predicate_visitor::{BoundPredicateVisitor, visit};
 use crate::expr::visitors::page_index_evaluator::PageIndexEvaluator;
 use crate::expr::visitors::row_group_metrics_evaluator::RowGroupMetricsEvaluator;
 use crate::expr::{BoundPredicate, BoundReference};
 use crate::io::{FileIO, FileMetadata, FileRead};
-use crate::metadata_columns::{RESERVED_FIELD_ID_FILE, is_metadata_field};
+use crate::metadata_columns::{
+    RESERVED_FIELD_ID_FILE, RESERVED_FIELD_ID_ROW_ID, is_metadata_field, is_row_lineage_field,
+    schema_with_row_lineage,
+};
 use crate::scan::{ArrowRecordBatchStream, FileScanTask, FileScanTaskStream};
-use crate::spec::{Datum, NameMapping, NestedField, PrimitiveType, Schema, Type};
+use crate::spec::{
+    BoundingBox, Datum, NameMapping, NestedField, PrimitiveLiteral, PrimitiveType, Schema, Type,
+};
 use crate::utils::available_parallelism;
 use crate::{Error, ErrorKind};
 
@@ -69,6 +78,10 @@ const DEFAULT_RANGE_COALESCE_BYTES: u64 = 1024 * 1024;
 /// Matches object_store's `OBJECT_STORE_COALESCE_PARALLEL`.
 const DEFAULT_RANGE_FETCH_CONCURRENCY: usize = 10;
 
+/// Name of the virtual column holding each row's position in the Parquet file,
+/// used to inherit `_row_id` for v3 row lineage.
+const ROW_NUMBER_COLUMN_NAME: &str = "_row_number";
+
 /// Default number of bytes to prefetch when parsing Parquet footer metadata.
 /// Matches DataFusion's default `ParquetOptions::metadata_size_hint`.
 const DEFAULT_METADATA_SIZE_HINT: usize = 512 * 1024;
@@ -320,13 +333,32 @@ impl ArrowReader {
         let delete_filter_rx =
             delete_file_loader.load_deletes(&task.deletes, Arc::clone(&task.schema));
 
+        // Inheriting `_row_id` requires each row's position in the file, which the
+        // Parquet reader provides as a virtual row number column.
+        let row_number_columns: Vec<FieldRef> = if task.first_row_id.is_some()
+            && task.project_field_ids.contains(&RESERVED_FIELD_ID_ROW_ID)
+        {
+            vec![Arc::new(
+                Field::new(ROW_NUMBER_COLUMN_NAME, DataType::Int64, false)
+                    .with_extension_type(RowNumber),
+            )]
+        } else {
+            vec![]
+        };
+        let initial_options = if row_number_columns.is_empty() {
+            None
+        } else {
+            Some(ArrowReaderOptions::new().with_virtual_columns(row_number_columns.clone())?)
+        };
+
         // Migrated tables lack field IDs, requiring us to inspect the schema to choose
         // between field-ID-based or position-based projection
         let initial_stream_builder = Self::create_parquet_record_batch_stream_builder(
             &task.data_file_path,
             file_io.clone(),
-            None,
+            initial_options,
             task.file_size_in_bytes,
+            task.key_metadata.as_deref(),
             parquet_read_options,
         )
         .await?;
@@ -357,29 +389,37 @@ impl ArrowReader {
         // - Branch 2: nameMapping present → applyNameMapping(), then pruneColumns()
         // - Branch 3: fallback → addFallbackIds(), then pruneColumnsFallback()
         let mut record_batch_stream_builder = if missing_field_ids {
-            // Parquet file lacks field IDs - must assign them before reading
+            // Parquet file lacks field IDs - must assign them before reading. The row number
+            // columns come last and are not part of the supplied schema.
+            let file_schema = initial_stream_builder.schema();
+            let file_fields =
+                &file_schema.fields()[..file_schema.fields().len() - row_number_columns.len()];
+            let file_schema = Arc::new(ArrowSchema::new_with_metadata(
+                file_fields.to_vec(),
+                file_schema.metadata().clone(),
+            ));
             let arrow_schema = if let Some(name_mapping) = &task.name_mapping {
                 // Branch 2: Apply name mapping to assign correct Iceberg field IDs
                 // Per spec rule #2: "Use schema.name-mapping.default metadata to map field id
                 // to columns without field id"
                 // Corresponds to Java's ParquetSchemaUtil.applyNameMapping()
-                apply_name_mapping_to_arrow_schema(
-                    Arc::clone(initial_stream_builder.schema()),
-                    name_mapping,
-                )?
+                apply_name_mapping_to_arrow_schema(Arc::clone(&file_schema), name_mapping)?
             } else {
                 // Branch 3: No name mapping - use position-based fallback IDs
                 // Corresponds to Java's ParquetSchemaUtil.addFallbackIds()
-                add_fallback_field_ids_to_arrow_schema(initial_stream_builder.schema())
+                add_fallback_field_ids_to_arrow_schema(&file_schema)
             };
 
-            let options = ArrowReaderOptions::new().with_schema(arrow_schema);
+            let options = ArrowReaderOptions::new()
+                .with_schema(arrow_schema)
+                .with_virtual_columns(row_number_columns)?;
 
             Self::create_parquet_record_batch_stream_builder(
                 &task.data_file_path,
                 file_io.clone(),
                 Some(options),
                 task.file_size_in_bytes,
+                task.key_metadata.as_deref(),
                 parquet_read_options,
             )
             .await?
@@ -388,11 +428,24 @@ impl ArrowReader {
             initial_stream_builder
         };
 
-        // Filter out metadata fields for Parquet projection (they don't exist in files)
+        // Row lineage fields are persisted in data files when rows are rewritten, so
+        // they are resolved against the table schema extended with those fields.
+        let projected_schema = if task
+            .project_field_ids
+            .iter()
+            .any(|&id| is_row_lineage_field(id))
+        {
+            Arc::new(schema_with_row_lineage(&task.schema)?)
+        } else {
+            task.schema_ref()
+        };
+
+        // Filter out metadata fields for Parquet projection (they don't exist in files,
+        // except for row lineage fields)
         let project_field_ids_without_metadata: Vec<i32> = task
             .project_field_ids
             .iter()
-            .filter(|&&id| !is_metadata_field(id))
+            .filter(|&&id| !is_metadata_field(id) || is_row_lineage_field(id))
             .copied()
             .collect();
 
@@ -402,7 +455,7 @@ impl ArrowReader {
         // - If fallback IDs: position-based projection (missing_field_ids=true)
         let projection_mask = Self::get_arrow_projection_mask(
             &project_field_ids_without_metadata,
-            &task.schema,
+            &projected_schema,
             record_batch_stream_builder.parquet_schema(),
             record_batch_stream_builder.schema(),
             missing_field_ids, // Whether to use position-based (true) or field-ID-based (false) projection
@@ -415,7 +468,7 @@ impl ArrowReader {
         // that come back from the file, such as type promotion, default column insertion,
         // column re-ordering, partition constants, and virtual field addition (like _file)
         let mut record_batch_transformer_builder =
-            RecordBatchTransformerBuilder::new(task.schema_ref(), task.project_field_ids());
+            RecordBatchTransformerBuilder::new(projected_schema, task.project_field_ids());
 
         // Add the _file metadata column if it's in the projected fields
         if task.project_field_ids().contains(&RESERVED_FIELD_ID_FILE) {
@@ -424,6 +477,12 @@ impl ArrowReader {
                 record_batch_transformer_builder.with_constant(RESERVED_FIELD_ID_FILE, file_datum);
         }
 
+        // Inherit _row_id and _last_updated_sequence_number from the data file's metadata
+        if let Some(first_row_id) = task.first_row_id {
+            record_batch_transformer_builder = record_batch_transformer_builder
+                .with_row_lineage(first_row_id, task.data_sequence_number);
+        }
+
         if let (Some(partition_spec), Some(partition_data)) =
             (task.partition_spec.clone(), task.partition.clone())
         {
@@ -485,6 +544,7 @@ impl ArrowReader {
         if let Some(predicate) = final_predicate {
             let (iceberg_field_ids, field_id_map) = Self::build_field_id_set_and_map(
                 record_batch_stream_builder.parquet_schema(),
+                &task.schema,
                 &predicate,
             )?;
 
@@ -584,6 +644,7 @@ impl ArrowReader {
         file_io: FileIO,
         arrow_reader_options: Option<ArrowReaderOptions>,
         file_size_in_bytes: u64,
+        key_metadata: Option<&[u8]>,
         parquet_read_options: ParquetReadOptions,
     ) -> Result<ParquetRecordBatchStreamBuilder<ArrowFileReader>> {
         // Get the metadata for the Parquet file we need to read and build
@@ -599,7 +660,13 @@ impl ArrowReader {
         .with_parquet_read_options(parquet_read_options);
 
         // Create the record batch stream builder, which wraps the parquet file reader
-        let options = arrow_reader_options.unwrap_or_default();
+        let mut options = arrow_reader_options.unwrap_or_default();
+        // Files of encrypted tables are decrypted with the key of their key metadata.
+        if let Some(key_metadata) = key_metadata.filter(|k| !k.is_empty()) {
+            options = options.with_file_decryption_properties(
+                StandardKeyMetadata::decode(key_metadata)?.parquet_decryption_properties()?,
+            );
+        }
         let record_batch_stream_builder =
             ParquetRecordBatchStreamBuilder::new_with_options(parquet_file_reader, options).await?;
         Ok(record_batch_stream_builder)
@@ -726,6 +793,7 @@ impl ArrowReader {
 
     fn build_field_id_set_and_map(
         parquet_schema: &SchemaDescriptor,
+        iceberg_schema: &Schema,
         predicate: &BoundPredicate,
     ) -> Result<(HashSet<i32>, HashMap<i32, usize>)> {
         // Collects all Iceberg field IDs referenced in the filter predicate
@@ -737,7 +805,7 @@ impl ArrowReader {
         let iceberg_field_ids = collector.field_ids();
 
         // Without embedded field IDs, we fall back to position-based mapping for compatibility
-        let field_id_map = match build_field_id_map(parquet_schema)? {
+        let field_id_map = match build_field_id_map(parquet_schema, iceberg_schema)? {
             Some(map) => map,
             None => build_fallback_field_id_map(parquet_schema),
         };
@@ -747,9 +815,12 @@ impl ArrowReader {
 
     /// Recursively extract leaf field IDs because Parquet projection works at the leaf column level.
     /// Nested types (struct/list/map) are flattened in Parquet's columnar format.
+    ///
+    /// Variants are stored as a group of `metadata` and `value` columns without field IDs of
+    /// their own, so the ID of the variant group is included instead.
     fn include_leaf_field_id(field: &NestedField, field_ids: &mut Vec<i32>) {
         match field.field_type.as_ref() {
-            Type::Primitive(_) => {
+            Type::Primitive(_) | Type::Variant => {
                 field_ids.push(field.id);
             }
             Type::Struct(struct_type) => {
@@ -804,7 +875,12 @@ impl ArrowReader {
 
         if use_fallback {
             // Position-based projection necessary because file lacks embedded field IDs
-            Self::get_arrow_projection_mask_fallback(field_ids, parquet_schema)
+            // Fallback IDs are only assigned to top-level columns, so nested fields are read
+            // with their top-level fields and pruned by RecordBatchTransformer.
+            Self::get_arrow_projection_mask_fallback(
+                &iceberg_schema_of_task.top_level_field_ids(field_ids)?,
+                parquet_schema,
+            )
         } else {
             // Field-ID-based projection using embedded field IDs from Parquet metadata
 
@@ -816,6 +892,11 @@ impl ArrowReader {
                     Self::include_leaf_field_id(field, &mut leaf_field_ids);
                 }
             }
+            for key_field in iceberg_schema_of_task.enclosing_map_key_fields(field_ids)? {
+                if !field_ids.contains(&key_field.id) {
+                    Self::include_leaf_field_id(&key_field, &mut leaf_field_ids);
+                }
+            }
 
             Self::get_arrow_projection_mask_with_field_ids(
                 &leaf_field_ids,
@@ -891,6 +972,9 @@ impl ArrowReader {
             }
         }
 
+        // Variant groups are selected by their own ID, which projects all of their leaves.
+        indices.extend(Self::get_group_leaf_indices(parquet_schema, leaf_field_ids));
+
         if indices.is_empty() {
             // Edge case: All requested columns are new (don't exist in file).
             // Project all columns so RecordBatchTransformer has a batch to transform.
@@ -900,6 +984,40 @@ impl ArrowReader {
         }
     }
 
+    /// Returns the indices of all leaf columns below the Parquet groups whose field ID is in
+    /// `field_ids`.
+    fn get_group_leaf_indices(parquet_schema: &SchemaDescriptor, field_ids: &[i32]) -> Vec<usize> {
+        fn visit(
+            parquet_type: &ParquetType,
+            selected: bool,
+            field_ids: &[i32],
+            next_leaf_idx: &mut usize,
+            indices: &mut Vec<usize>,
+        ) {
+            if parquet_type.is_primitive() {
+                if selected {
+                    indices.push(*next_leaf_idx);
+                }
+                *next_leaf_idx += 1;
+                return;
+            }
+
+            let basic_info = parquet_type.get_basic_info();
+            let selected =
+                selected || (basic_info.has_id() && field_ids.contains(&basic_info.id()));
+            for child in parquet_type.get_fields() {
+                visit(child, selected, field_ids, next_leaf_idx, indices);
+            }
+        }
+
+        let mut indices = vec![];
+        let mut next_leaf_idx = 0;
+        for field in parquet_schema.root_schema().get_fields() {
+            visit(field, false, field_ids, &mut next_leaf_idx, &mut indices);
+        }
+        indices
+    }
+
     /// Fallback projection for Parquet files without field IDs.
     /// Uses position-based matching: field ID N → column position N-1.
     /// Projects entire top-level columns (including nested content) for iceberg-java compatibility.
@@ -1080,7 +1198,14 @@ impl ArrowReader {
 
 /// Build the map of parquet field id to Parquet column index in the schema.
 /// Returns None if the Parquet file doesn't have field IDs embedded (e.g., migrated tables).
-fn build_field_id_map(parquet_schema: &SchemaDescriptor) -> Result<Option<HashMap<i32, usize>>> {
+///
+/// The leaves of a variant column have no field IDs of their own, so they are skipped when
+/// the group they belong to is a variant field of `iceberg_schema`.
+fn build_field_id_map(
+    parquet_schema: &SchemaDescriptor,
+    iceberg_schema: &Schema,
+) -> Result<Option<HashMap<i32, usize>>> {
+    let variant_leaves = variant_leaf_indices(parquet_schema, iceberg_schema);
     let mut column_map = HashMap::new();
 
     for (idx, field) in parquet_schema.columns().iter().enumerate() {
@@ -1088,6 +1213,9 @@ fn build_field_id_map(parquet_schema: &SchemaDescriptor) -> Result<Option<HashMa
         match field_type {
             ParquetType::PrimitiveType { basic_info, .. } => {
                 if !basic_info.has_id() {
+                    if variant_leaves.contains(&idx) {
+                        continue;
+                    }
                     return Ok(None);
                 }
                 column_map.insert(basic_info.id(), idx);
@@ -1103,9 +1231,39 @@ fn build_field_id_map(parquet_schema: &SchemaDescriptor) -> Result<Option<HashMa
         };
     }
 
+    if column_map.is_empty() {
+        return Ok(None);
+    }
+
     Ok(Some(column_map))
 }
 
+/// Returns the indices of the leaf columns stored below the Parquet groups of variant fields.
+///
+/// Variants only exist in format v3 schemas, so this is empty for older tables.
+fn variant_leaf_indices(
+    parquet_schema: &SchemaDescriptor,
+    iceberg_schema: &Schema,
+) -> HashSet<usize> {
+    let variant_field_ids: Vec<i32> = iceberg_schema
+        .field_id_to_name_map()
+        .keys()
+        .filter(|field_id| {
+            iceberg_schema
+                .field_by_id(**field_id)
+                .is_some_and(|field| matches!(field.field_type.as_ref(), Type::Variant))
+        })
+        .copied()
+        .collect();
+    if variant_field_ids.is_empty() {
+        return HashSet::new();
+    }
+
+    ArrowReader::get_group_leaf_indices(parquet_schema, &variant_field_ids)
+        .into_iter()
+        .collect()
+}
+
 /// Build a fallback field ID map for Parquet files without embedded field IDs.
 /// Position-based (1, 2, 3, ...) for compatibility with iceberg-java migrations.
 fn build_fallback_field_id_map(parquet_schema: &SchemaDescriptor) -> HashMap<i32, usize> {
@@ -1362,6 +1520,26 @@ impl BoundPredicateVisitor for CollectFieldIdVisitor {
         Ok(())
     }
 
+    fn intersects(
+        &mut self,
+        reference: &BoundReference,
+        _literal: &Datum,
+        _predicate: &BoundPredicate,
+    ) -> Result<()> {
+        self.field_ids.insert(reference.field().id);
+        Ok(())
+    }
+
+    fn disjoint(
+        &mut self,
+        reference: &BoundReference,
+        _literal: &Datum,
+        _predicate: &BoundPredicate,
+    ) -> Result<()> {
+        self.field_ids.insert(reference.field().id);
+        Ok(())
+    }
+
     fn r#in(
         &mut self,
         reference: &BoundReference,
@@ -1461,6 +1639,42 @@ fn project_column(
     }
 }
 
+/// Conservatively filters rows of a WKB column which might intersect a geometry
+/// with bounding box `query`.
+///
+/// Only rows whose bounding box is disjoint from `query`, or which are empty, are
+/// filtered out. `None` keeps all non-null rows; exact evaluation is left to the engine.
+fn might_intersect(
+    column: &ArrayRef,
+    query: Option<Option<BoundingBox>>,
+) -> std::result::Result<BooleanArray, ArrowError> {
+    let values: Box<dyn Iterator<Item = Option<&[u8]>>> = match column.data_type() {
+        DataType::Binary => Box::new(column.as_binary::<i32>().iter()),
+        DataType::LargeBinary => Box::new(column.as_binary::<i64>().iter()),
+        DataType::BinaryView => Box::new(column.as_binary_view().iter()),
+        data_type => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Spatial predicate requires a binary column, but got {data_type}"
+            )));
+        }
+    };
+
+    Ok(values
+        .map(|value| {
+            value.map(|wkb| match query {
+                None => true,
+                // empty geometries don't intersect anything
+                Some(None) => false,
+                Some(Some(query)) => match BoundingBox::try_from_wkb(wkb) {
+                    Ok(Some(bbox)) => bbox.intersects(&query),
+                    Ok(None) => false,
+                    Err(_) => true,
+                },
+            })
+        })
+        .collect())
+}
+
 type PredicateResult =
     dyn FnMut(RecordBatch) -> std::result::Result<BooleanArray, ArrowError> + Send + 'static;
 
@@ -1725,6 +1939,53 @@ impl BoundPredicateVisitor for PredicateConverter<'_> {
         }
     }
 
+    fn intersects(
+        &mut self,
+        reference: &BoundReference,
+        literal: &Datum,
+        _predicate: &BoundPredicate,
+    ) -> Result<Box<PredicateResult>> {
+        if let Some(idx) = self.bound_reference(reference)? {
+            let PrimitiveLiteral::Binary(wkb) = literal.literal() else {
+                return Err(Error::new(
+                    ErrorKind::DataInvalid,
+                    format!("Spatial predicate requires a WKB literal, but got {literal}"),
+                ));
+            };
+            let query = BoundingBox::try_from_wkb(wkb)?;
+            // Edges of geographies are interpolated, so their vertices can't be used for filtering.
+            let compare_bounds = matches!(
+                reference.field().field_type.as_primitive_type(),
+                Some(PrimitiveType::Geometry { .. })
+            );
+
+            Ok(Box::new(move |batch| {
+                let left = project_column(&batch, idx)?;
+                might_intersect(&left, compare_bounds.then_some(query))
+            }))
+        } else {
+            // A missing column, treating it as null.
+            self.build_always_false()
+        }
+    }
+
+    fn disjoint(
+        &mut self,
+        reference: &BoundReference,
+        _literal: &Datum,
+        _predicate: &BoundPredicate,
+    ) -> Result<Box<PredicateResult>> {
+        if let Some(idx) = self.bound_reference(reference)? {
+            Ok(Box::new(move |batch| {
+                let left = project_column(&batch, idx)?;
+                is_not_null(&left)
+            }))
+        } else {
+            // A missing column, treating it as null.
+            self.build_always_true()
+        }
+    }
+
     fn r#in(
         &mut self,
         reference: &BoundReference,
@@ -1861,14 +2122,15 @@ impl AsyncFileReader for ArrowFileReader {
         .boxed()
     }
 
-    // TODO: currently we don't respect `ArrowReaderOptions` cause it don't expose any method to access the option field
-    // we will fix it after `v55.1.0` is released in https://github.com/apache/arrow-rs/issues/7393
-    fn get_metadata(
-        &mut self,
-        _options: Option<&'_ ArrowReaderOptions>,
-    ) -> BoxFuture<'_, parquet::errors::Result<Arc<ParquetMetaData>>> {
+    fn get_metadata<'a>(
+        &'a mut self,
+        options: Option<&'a ArrowReaderOptions>,
+    ) -> BoxFuture<'a, parquet::errors::Result<Arc<ParquetMetaData>>> {
         async move {
             let reader = ParquetMetaDataReader::new()
+                .with_decryption_properties(
+                    options.and_then(|options| options.file_decryption_properties().cloned()),
+                )
                 .with_prefetch_hint(self.parquet_read_options.metadata_size_hint())
                 // Set the page policy first because it updates both column and offset policies.
                 .with_page_index_policy(PageIndexPolicy::from(
@@ -1968,7 +2230,9 @@ mod tests {
     use tempfile::TempDir;
 
     use crate::ErrorKind;
-    use crate::arrow::reader::{CollectFieldIdVisitor, PARQUET_FIELD_ID_META_KEY};
+    use crate::arrow::reader::{
+        CollectFieldIdVisitor, PARQUET_FIELD_ID_META_KEY, build_field_id_map,
+    };
     use crate::arrow::{ArrowReader, ArrowReaderBuilder};
     use crate::delete_vector::DeleteVector;
     use crate::expr::visitors::bound_predicate_visitor::visit;
@@ -1976,7 +2240,8 @@ mod tests {
     use crate::io::FileIO;
     use crate::scan::{FileScanTask, FileScanTaskDeleteFile, FileScanTaskStream};
     use crate::spec::{
-        DataContentType, DataFileFormat, Datum, NestedField, PrimitiveType, Schema, SchemaRef, Type,
+        DataContentType, DataFileFormat, Datum, NestedField, PrimitiveType, Schema, SchemaRef,
+        StructType, Type,
     };
 
     fn table_schema_simple() -> SchemaRef {
@@ -2144,6 +2409,85 @@ message schema {
         assert_eq!(mask, ProjectionMask::leaves(&parquet_schema, vec![0]));
     }
 
+    #[test]
+    fn test_build_field_id_map_skips_only_variant_leaves() {
+        let message_type = "
+message schema {
+  required int64 id = 1;
+  optional group v = 2 {
+    required binary metadata;
+    required binary value;
+  }
+  optional group s = 3 {
+    optional int64 a = 4;
+    optional int64 b;
+  }
+}
+    ";
+        let parquet_type = parse_message_type(message_type).expect("should parse schema");
+        let parquet_schema = SchemaDescriptor::new(Arc::new(parquet_type));
+        let struct_field = |b_id| {
+            NestedField::optional(
+                3,
+                "s",
+                Type::Struct(StructType::new(vec![
+                    NestedField::optional(4, "a", Type::Primitive(PrimitiveType::Long)).into(),
+                    NestedField::optional(b_id, "b", Type::Primitive(PrimitiveType::Long)).into(),
+                ])),
+            )
+        };
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+                NestedField::optional(2, "v", Type::Variant).into(),
+                struct_field(5).into(),
+            ])
+            .build()
+            .unwrap();
+
+        // The leaf `s.b` has no field ID and is not part of a variant, so the file is
+        // treated as lacking field IDs.
+        assert!(
+            build_field_id_map(&parquet_schema, &schema)
+                .unwrap()
+                .is_none()
+        );
+
+        let message_type = "
+message schema {
+  required int64 id = 1;
+  optional group v = 2 {
+    required binary metadata;
+    required binary value;
+  }
+  optional group s = 3 {
+    optional int64 a = 4;
+    optional int64 b = 5;
+  }
+}
+    ";
+        let parquet_type = parse_message_type(message_type).expect("should parse schema");
+        let parquet_schema = SchemaDescriptor::new(Arc::new(parquet_type));
+        let field_id_map = build_field_id_map(&parquet_schema, &schema)
+            .unwrap()
+            .unwrap();
+        assert_eq!(field_id_map, HashMap::from([(1, 0), (4, 3), (5, 4)]));
+
+        // Without a variant field in the table schema, the variant leaves are not skipped
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+                struct_field(5).into(),
+            ])
+            .build()
+            .unwrap();
+        assert!(
+            build_field_id_map(&parquet_schema, &schema)
+                .unwrap()
+                .is_none()
+        );
+    }
+
     #[tokio::test]
     async fn test_kleene_logic_or_behaviour() {
         // a IS NULL OR a = 'foo'
@@ -2284,6 +2628,9 @@ message schema {
                 partition: None,
                 partition_spec: None,
                 name_mapping: None,
+                first_row_id: None,
+                data_sequence_number: None,
+                key_metadata: None,
                 case_sensitive: false,
             })]
             .into_iter(),
@@ -2607,6 +2954,9 @@ message schema {
             partition: None,
             partition_spec: None,
             name_mapping: None,
+            first_row_id: None,
+            data_sequence_number: None,
+            key_metadata: None,
             case_sensitive: false,
         };
 
@@ -2625,6 +2975,9 @@ message schema {
             partition: None,
             partition_spec: None,
             name_mapping: None,
+            first_row_id: None,
+            data_sequence_number: None,
+            key_metadata: None,
             case_sensitive: false,
         };
 
@@ -2756,6 +3109,9 @@ message schema {
                 partition: None,
                 partition_spec: None,
                 name_mapping: None,
+                first_row_id: None,
+                data_sequence_number: None,
+                key_metadata: None,
                 case_sensitive: false,
             })]
             .into_iter(),
@@ -2926,10 +3282,14 @@ message schema {
                 file_type: DataContentType::PositionDeletes,
                 partition_spec_id: 0,
                 equality_ids: None,
+                key_metadata: None,
             }],
             partition: None,
             partition_spec: None,
             name_mapping: None,
+            first_row_id: None,
+            data_sequence_number: None,
+            key_metadata: None,
             case_sensitive: false,
         };
 
@@ -3146,10 +3506,14 @@ message schema {
                 file_type: DataContentType::PositionDeletes,
                 partition_spec_id: 0,
                 equality_ids: None,
+                key_metadata: None,
             }],
             partition: None,
             partition_spec: None,
             name_mapping: None,
+            first_row_id: None,
+            data_sequence_number: None,
+            key_metadata: None,
             case_sensitive: false,
         };
 
@@ -3359,10 +3723,14 @@ message schema {
                 file_type: DataContentType::PositionDeletes,
                 partition_spec_id: 0,
                 equality_ids: None,
+                key_metadata: None,
             }],
             partition: None,
             partition_spec: None,
             name_mapping: None,
+            first_row_id: None,
+            data_sequence_number: None,
+            key_metadata: None,
             case_sensitive: false,
         };
 
@@ -3470,6 +3838,9 @@ message schema {
                 partition: None,
                 partition_spec: None,
                 name_mapping: None,
+                first_row_id: None,
+                data_sequence_number: None,
+                key_metadata: None,
                 case_sensitive: false,
             })]
             .into_iter(),
@@ -3571,6 +3942,9 @@ message schema {
                 partition: None,
                 partition_spec: None,
                 name_mapping: None,
+                first_row_id: None,
+                data_sequence_number: None,
+                key_metadata: None,
                 case_sensitive: false,
             })]
             .into_iter(),
@@ -3661,6 +4035,9 @@ message schema {
                 partition: None,
                 partition_spec: None,
                 name_mapping: None,
+                first_row_id: None,
+                data_sequence_number: None,
+                key_metadata: None,
                 case_sensitive: false,
             })]
             .into_iter(),
@@ -3765,6 +4142,9 @@ message schema {
                 partition: None,
                 partition_spec: None,
                 name_mapping: None,
+                first_row_id: None,
+                data_sequence_number: None,
+                key_metadata: None,
                 case_sensitive: false,
             })]
             .into_iter(),
@@ -3898,6 +4278,9 @@ message schema {
                 partition: None,
                 partition_spec: None,
                 name_mapping: None,
+                first_row_id: None,
+                data_sequence_number: None,
+                key_metadata: None,
                 case_sensitive: false,
             })]
             .into_iter(),
@@ -3935,6 +4318,170 @@ message schema {
         assert_eq!(age_array.value(1), 25);
     }
 
+    /// Test projecting nested fields, including fields of structs inside lists, which only reads
+    /// the selected leaves and prunes their parents to the selection.
+    #[tokio::test]
+    async fn test_read_nested_field_projection() {
+        use arrow_array::{Array, Int32Array, Int64Array, ListArray, StructArray};
+        use arrow_buffer::OffsetBuffer;
+
+        use crate::arrow::schema_to_arrow_schema;
+        use crate::spec::{ListType, StructType};
+
+        let schema = Arc::new(
+            Schema::builder()
+                .with_schema_id(1)
+                .with_fields(vec![
+                    NestedField::required(1, "id", Type::Primitive(PrimitiveType::Int)).into(),
+                    NestedField::required(
+                        2,
+                        "person",
+                        Type::Struct(StructType::new(vec![
+                            NestedField::required(
+                                3,
+                                "name",
+                                Type::Primitive(PrimitiveType::String),
+                            )
+                            .into(),
+                            NestedField::required(4, "age", Type::Primitive(PrimitiveType::Int))
+                                .into(),
+                        ])),
+                    )
+                    .into(),
+                    NestedField::required(
+                        5,
+                        "events",
+                        Type::List(ListType::new(
+                            NestedField::list_element(
+                                6,
+                                Type::Struct(StructType::new(vec![
+                                    NestedField::required(
+                                        7,
+                                        "kind",
+                                        Type::Primitive(PrimitiveType::String),
+                                    )
+                                    .into(),
+                                    NestedField::required(
+                                        8,
+                                        "ts",
+                                        Type::Primitive(PrimitiveType::Long),
+                                    )
+                                    .into(),
+                                ])),
+                                true,
+                            )
+                            .into(),
+                        )),
+                    )
+                    .into(),
+                ])
+                .build()
+                .unwrap(),
+        );
+        let arrow_schema = Arc::new(schema_to_arrow_schema(&schema).unwrap());
+
+        let DataType::Struct(person_fields) = arrow_schema.field(1).data_type() else {
+            unreachable!("person is a struct");
+        };
+        let person = StructArray::new(
+            person_fields.clone(),
+            vec![
+                Arc::new(StringArray::from(vec!["Alice", "Bob"])) as ArrayRef,
+                Arc::new(Int32Array::from(vec![30, 25])) as ArrayRef,
+            ],
+            None,
+        );
+        let DataType::List(event_field) = arrow_schema.field(2).data_type() else {
+            unreachable!("events is a list");
+        };
+        let DataType::Struct(event_fields) = event_field.data_type() else {
+            unreachable!("events element is a struct");
+        };
+        let events = ListArray::new(
+            event_field.clone(),
+            OffsetBuffer::from_lengths([2, 1]),
+            Arc::new(StructArray::new(
+                event_fields.clone(),
+                vec![
+                    Arc::new(StringArray::from(vec!["click", "view", "click"])) as ArrayRef,
+                    Arc::new(Int64Array::from(vec![10, 20, 30])) as ArrayRef,
+                ],
+                None,
+            )),
+            None,
+        );
+        let to_write = RecordBatch::try_new(arrow_schema.clone(), vec![
+            Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef,
+            Arc::new(person) as ArrayRef,
+            Arc::new(events) as ArrayRef,
+        ])
+        .unwrap();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let table_location = tmp_dir.path().to_str().unwrap().to_string();
+        let file_io = FileIO::new_with_fs();
+
+        let file = File::create(format!("{table_location}/1.parquet")).unwrap();
+        let mut writer = ArrowWriter::try_new(file, to_write.schema(), None).unwrap();
+        writer.write(&to_write).expect("Writing batch");
+        writer.close().unwrap();
+
+        let reader = ArrowReaderBuilder::new(file_io).build();
+        let tasks = Box::pin(futures::stream::iter(
+            vec![Ok(FileScanTask {
+                file_size_in_bytes: std::fs::metadata(format!("{table_location}/1.parquet"))
+                    .unwrap()
+                    .len(),
+                start: 0,
+                length: 0,
+                record_count: None,
+                data_file_path: format!("{table_location}/1.parquet"),
+                data_file_format: DataFileFormat::Parquet,
+                schema: schema.clone(),
+                project_field_ids: vec![8, 4],
+                predicate: None,
+                deletes: vec![],
+                partition: None,
+                partition_spec: None,
+                name_mapping: None,
+                first_row_id: None,
+                data_sequence_number: None,
+                key_metadata: None,
+                case_sensitive: false,
+            })]
+            .into_iter(),
+        )) as FileScanTaskStream;
+
+        let result = reader
+            .read(tasks)
+            .unwrap()
+            .try_collect::<Vec<RecordBatch>>()
+            .await
+            .unwrap();
+
+        assert_eq!(result.len(), 1);
+        let batch = &result[0];
+        assert_eq!(batch.num_columns(), 2);
+        assert_eq!(batch.schema().field(0).name(), "events");
+        assert_eq!(batch.schema().field(1).name(), "person");
+
+        let events = batch.column(0).as_list::<i32>();
+        let event_values = events.values().as_struct();
+        assert_eq!(event_values.column_names(), vec!["ts"]);
+        assert_eq!(
+            event_values.column(0).as_ref(),
+            &Int64Array::from(vec![10, 20, 30]) as &dyn Array
+        );
+        assert_eq!(events.value_offsets(), &[0, 2, 3]);
+
+        let person = batch.column(1).as_struct();
+        assert_eq!(person.column_names(), vec!["age"]);
+        assert_eq!(
+            person.column(0).as_ref(),
+            &Int32Array::from(vec![30, 25]) as &dyn Array
+        );
+    }
+
     /// Test reading Parquet files without field IDs with schema evolution - column added in the middle.
     /// When a new column is inserted between existing columns in the schema order,
     /// the fallback projection must correctly map field IDs to output positions.
@@ -3998,6 +4545,9 @@ message schema {
                 partition: None,
                 partition_spec: None,
                 name_mapping: None,
+                first_row_id: None,
+                data_sequence_number: None,
+                key_metadata: None,
                 case_sensitive: false,
             })]
             .into_iter(),
@@ -4111,6 +4661,9 @@ message schema {
                 partition: None,
                 partition_spec: None,
                 name_mapping: None,
+                first_row_id: None,
+                data_sequence_number: None,
+                key_metadata: None,
                 case_sensitive: false,
             })]
             .into_iter(),
@@ -4205,6 +4758,9 @@ message schema {
                 partition: None,
                 partition_spec: None,
                 name_mapping: None,
+                first_row_id: None,
+                data_sequence_number: None,
+                key_metadata: None,
                 case_sensitive: false,
             }),
             Ok(FileScanTask {
@@ -4223,6 +4779,9 @@ message schema {
                 partition: None,
                 partition_spec: None,
                 name_mapping: None,
+                first_row_id: None,
+                data_sequence_number: None,
+                key_metadata: None,
                 case_sensitive: false,
             }),
             Ok(FileScanTask {
@@ -4241,6 +4800,9 @@ message schema {
                 partition: None,
                 partition_spec: None,
                 name_mapping: None,
+                first_row_id: None,
+                data_sequence_number: None,
+                key_metadata: None,
                 case_sensitive: false,
             }),
         ];
@@ -4423,6 +4985,9 @@ message schema {
                 partition: Some(partition_data),
                 partition_spec: Some(partition_spec),
                 name_mapping: None,
+                first_row_id: None,
+                data_sequence_number: None,
+                key_metadata: None,
                 case_sensitive: false,
             })]
             .into_iter(),
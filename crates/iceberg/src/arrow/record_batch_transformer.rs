@@ -18,20 +18,29 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use arrow_arith::arity::unary;
+use arrow_array::cast::AsArray;
+use arrow_array::types::Int64Type;
 use arrow_array::{
-    Array as ArrowArray, ArrayRef, Int32Array, RecordBatch, RecordBatchOptions, RunArray,
+    Array as ArrowArray, ArrayRef, Int32Array, Int64Array, RecordBatch, RecordBatchOptions,
+    RunArray,
 };
 use arrow_cast::cast;
+use arrow_schema::extension::ExtensionType;
 use arrow_schema::{
     DataType, Field, FieldRef, Schema as ArrowSchema, SchemaRef as ArrowSchemaRef, SchemaRef,
 };
-use parquet::arrow::PARQUET_FIELD_ID_META_KEY;
+use parquet::arrow::{PARQUET_FIELD_ID_META_KEY, RowNumber};
 
 use crate::arrow::value::{create_primitive_array_repeated, create_primitive_array_single_element};
 use crate::arrow::{datum_to_arrow_type_with_ree, schema_to_arrow_schema};
-use crate::metadata_columns::get_metadata_field;
+use crate::metadata_columns::{
+    RESERVED_FIELD_ID_LAST_UPDATED_SEQUENCE_NUMBER, RESERVED_FIELD_ID_ROW_ID, get_metadata_field,
+    is_row_lineage_field,
+};
 use crate::spec::{
     Datum, Literal, PartitionSpec, PrimitiveLiteral, Schema as IcebergSchema, Struct, Transform,
+    Type, prune_columns,
 };
 use crate::{Error, ErrorKind, Result};
 
@@ -140,6 +149,24 @@ pub(crate) enum ColumnSource {
         target_type: DataType,
         value: Option<PrimitiveLiteral>,
     },
+
+    // Signifies the `_row_id` row lineage column. Rows whose id is null in the
+    // file (or all rows, if the file has no `_row_id` column) inherit the data
+    // file's `first_row_id` plus their position in the file, which is read from
+    // the row number column at `row_number_index`.
+    InheritRowId {
+        source_index: Option<usize>,
+        row_number_index: usize,
+        first_row_id: i64,
+    },
+
+    // Signifies the `_last_updated_sequence_number` row lineage column. Rows whose
+    // value is null in the file (or all rows, if the file has no such column) inherit
+    // the data sequence number of the file's manifest entry.
+    InheritSequenceNumber {
+        source_index: Option<usize>,
+        sequence_number: Option<i64>,
+    },
     // The iceberg spec refers to other permissible schema evolution actions
     // (see https://iceberg.apache.org/spec/#schema-evolution):
     // renaming fields, deleting fields and reordering fields.
@@ -184,6 +211,14 @@ enum SchemaComparison {
     Different,
 }
 
+/// Values from the data file's metadata that row lineage columns inherit
+/// when they are null or missing in the file.
+#[derive(Debug, Clone, Copy)]
+struct RowLineage {
+    first_row_id: i64,
+    data_sequence_number: Option<i64>,
+}
+
 /// Builder for RecordBatchTransformer to improve ergonomics when constructing with optional parameters.
 ///
 /// Constant fields are pre-computed for both virtual/metadata fields (like _file) and
@@ -193,6 +228,7 @@ pub(crate) struct RecordBatchTransformerBuilder {
     snapshot_schema: Arc<IcebergSchema>,
     projected_iceberg_field_ids: Vec<i32>,
     constant_fields: HashMap<i32, Datum>,
+    row_lineage: Option<RowLineage>,
 }
 
 impl RecordBatchTransformerBuilder {
@@ -204,6 +240,7 @@ impl RecordBatchTransformerBuilder {
             snapshot_schema,
             projected_iceberg_field_ids: projected_iceberg_field_ids.to_vec(),
             constant_fields: HashMap::new(),
+            row_lineage: None,
         }
     }
 
@@ -240,11 +277,32 @@ impl RecordBatchTransformerBuilder {
         Ok(self)
     }
 
+    /// Set the row lineage values inherited by `_row_id` and `_last_updated_sequence_number`.
+    ///
+    /// When `_row_id` is projected, the incoming RecordBatches must contain a
+    /// [`RowNumber`] virtual column holding each row's position in the file.
+    ///
+    /// # Arguments
+    /// * `first_row_id` - The `first_row_id` of the data file
+    /// * `data_sequence_number` - The data sequence number of the file's manifest entry
+    pub(crate) fn with_row_lineage(
+        mut self,
+        first_row_id: i64,
+        data_sequence_number: Option<i64>,
+    ) -> Self {
+        self.row_lineage = Some(RowLineage {
+            first_row_id,
+            data_sequence_number,
+        });
+        self
+    }
+
     pub(crate) fn build(self) -> RecordBatchTransformer {
         RecordBatchTransformer {
             snapshot_schema: self.snapshot_schema,
             projected_iceberg_field_ids: self.projected_iceberg_field_ids,
             constant_fields: self.constant_fields,
+            row_lineage: self.row_lineage,
             batch_transform: None,
         }
     }
@@ -288,6 +346,8 @@ pub(crate) struct RecordBatchTransformer {
     // Includes both virtual/metadata fields (like _file) and identity-partitioned fields
     // Datum holds both the Iceberg type and the value
     constant_fields: HashMap<i32, Datum>,
+    // Values inherited by row lineage columns, if the data file has a first row id
+    row_lineage: Option<RowLineage>,
 
     // BatchTransform gets lazily constructed based on the schema of
     // the first RecordBatch we receive from the file
@@ -330,6 +390,7 @@ impl RecordBatchTransformer {
                     self.snapshot_schema.as_ref(),
                     &self.projected_iceberg_field_ids,
                     &self.constant_fields,
+                    self.row_lineage,
                 )?);
 
                 self.process_record_batch(record_batch)?
@@ -349,7 +410,15 @@ impl RecordBatchTransformer {
         snapshot_schema: &IcebergSchema,
         projected_iceberg_field_ids: &[i32],
         constant_fields: &HashMap<i32, Datum>,
+        row_lineage: Option<RowLineage>,
     ) -> Result<BatchTransform> {
+        // Selected nested fields are read as their top-level fields, pruned to the selection.
+        let pruned_schema =
+            Self::prune_nested_fields(snapshot_schema, projected_iceberg_field_ids)?;
+        let snapshot_schema = pruned_schema.as_ref().unwrap_or(snapshot_schema);
+        let projected_iceberg_field_ids =
+            &snapshot_schema.top_level_field_ids(projected_iceberg_field_ids)?;
+
         let mapped_unprojected_arrow_schema = Arc::new(schema_to_arrow_schema(snapshot_schema)?);
         let field_id_to_mapped_schema_map =
             Self::build_field_id_to_arrow_schema_map(&mapped_unprojected_arrow_schema)?;
@@ -407,7 +476,20 @@ impl RecordBatchTransformer {
 
         let target_schema = Arc::new(ArrowSchema::new(fields?));
 
-        match Self::compare_schemas(source_schema, &target_schema) {
+        // Row lineage values stored in the file may be null and need to be inherited,
+        // so the columns must always be rebuilt.
+        let inherits_row_lineage = row_lineage.is_some()
+            && projected_iceberg_field_ids
+                .iter()
+                .any(|field_id| is_row_lineage_field(*field_id));
+
+        let schema_comparison = if inherits_row_lineage {
+            SchemaComparison::Different
+        } else {
+            Self::compare_schemas(source_schema, &target_schema)
+        };
+
+        match schema_comparison {
             SchemaComparison::Equivalent => Ok(BatchTransform::PassThrough),
             SchemaComparison::NameChangesOnly => Ok(BatchTransform::ModifySchema { target_schema }),
             SchemaComparison::Different => Ok(BatchTransform::Modify {
@@ -417,12 +499,46 @@ impl RecordBatchTransformer {
                     projected_iceberg_field_ids,
                     field_id_to_mapped_schema_map,
                     constant_fields,
+                    row_lineage,
                 )?,
                 target_schema,
             }),
         }
     }
 
+    /// Prunes the snapshot schema to the projected fields if any of them is nested, so that
+    /// their top-level fields only contain the selected nested fields.
+    fn prune_nested_fields(
+        snapshot_schema: &IcebergSchema,
+        projected_iceberg_field_ids: &[i32],
+    ) -> Result<Option<IcebergSchema>> {
+        let selects_nested_field = projected_iceberg_field_ids.iter().any(|field_id| {
+            snapshot_schema.field_by_id(*field_id).is_some()
+                && snapshot_schema.as_struct().field_by_id(*field_id).is_none()
+        });
+        if !selects_nested_field {
+            return Ok(None);
+        }
+
+        let Type::Struct(pruned_struct) = prune_columns(
+            snapshot_schema,
+            projected_iceberg_field_ids.iter().copied(),
+            true,
+        )?
+        else {
+            return Err(Error::new(
+                ErrorKind::Unexpected,
+                "Pruned schema must be a struct",
+            ));
+        };
+
+        IcebergSchema::builder()
+            .with_schema_id(snapshot_schema.schema_id())
+            .with_fields(pruned_struct.fields().iter().cloned())
+            .build()
+            .map(Some)
+    }
+
     /// Compares the source and target schemas
     /// Determines if they have changed in any meaningful way:
     ///  * If they have different numbers of fields, then we need to modify
@@ -474,6 +590,7 @@ impl RecordBatchTransformer {
         projected_iceberg_field_ids: &[i32],
         field_id_to_mapped_schema_map: HashMap<i32, (FieldRef, usize)>,
         constant_fields: &HashMap<i32, Datum>,
+        row_lineage: Option<RowLineage>,
     ) -> Result<Vec<ColumnSource>> {
         let field_id_to_source_schema_map =
             Self::build_field_id_to_arrow_schema_map(source_schema)?;
@@ -493,6 +610,43 @@ impl RecordBatchTransformer {
                     });
                 }
 
+                // Row lineage columns inherit values from the data file's metadata
+                // when they are null or missing in the file.
+                if let Some(row_lineage) = row_lineage {
+                    let source_index = field_id_to_source_schema_map
+                        .get(field_id)
+                        .map(|(_, source_index)| *source_index);
+
+                    match *field_id {
+                        RESERVED_FIELD_ID_ROW_ID => {
+                            let row_number_index = source_schema
+                                .fields()
+                                .iter()
+                                .position(|field| {
+                                    field.extension_type_name() == Some(RowNumber::NAME)
+                                })
+                                .ok_or_else(|| {
+                                    Error::new(
+                                        ErrorKind::Unexpected,
+                                        "Row number column is required to inherit _row_id",
+                                    )
+                                })?;
+                            return Ok(ColumnSource::InheritRowId {
+                                source_index,
+                                row_number_index,
+                                first_row_id: row_lineage.first_row_id,
+                            });
+                        }
+                        RESERVED_FIELD_ID_LAST_UPDATED_SEQUENCE_NUMBER => {
+                            return Ok(ColumnSource::InheritSequenceNumber {
+                                source_index,
+                                sequence_number: row_lineage.data_sequence_number,
+                            });
+                        }
+                        _ => {}
+                    }
+                }
+
                 let (target_field, _) =
                     field_id_to_mapped_schema_map
                         .get(field_id)
@@ -615,11 +769,59 @@ impl RecordBatchTransformer {
                     ColumnSource::Add { target_type, value } => {
                         Self::create_column(target_type, value, num_rows)?
                     }
+
+                    ColumnSource::InheritRowId {
+                        source_index,
+                        row_number_index,
+                        first_row_id,
+                    } => {
+                        let row_numbers = columns[*row_number_index].as_primitive::<Int64Type>();
+                        let inherited: Int64Array = unary(row_numbers, |pos| first_row_id + pos);
+                        match source_index {
+                            Some(source_index) => Self::coalesce_long_column(
+                                &columns[*source_index],
+                                inherited.iter(),
+                            )?,
+                            None => Arc::new(inherited),
+                        }
+                    }
+
+                    ColumnSource::InheritSequenceNumber {
+                        source_index,
+                        sequence_number,
+                    } => match (source_index, sequence_number) {
+                        (Some(source_index), Some(sequence_number)) => Self::coalesce_long_column(
+                            &columns[*source_index],
+                            std::iter::repeat(Some(*sequence_number)),
+                        )?,
+                        (Some(source_index), None) => {
+                            cast(&*columns[*source_index], &DataType::Int64)?
+                        }
+                        (None, sequence_number) => {
+                            Arc::new(Int64Array::from(vec![*sequence_number; num_rows]))
+                        }
+                    },
                 })
             })
             .collect()
     }
 
+    /// Replaces the null values of a long column read from the file with the
+    /// corresponding inherited values.
+    fn coalesce_long_column(
+        source: &ArrayRef,
+        inherited: impl Iterator<Item = Option<i64>>,
+    ) -> Result<ArrayRef> {
+        let source = cast(source, &DataType::Int64)?;
+        let coalesced: Int64Array = source
+            .as_primitive::<Int64Type>()
+            .iter()
+            .zip(inherited)
+            .map(|(value, inherited)| value.or(inherited))
+            .collect();
+        Ok(Arc::new(coalesced))
+    }
+
     fn create_column(
         target_type: &DataType,
         prim_lit: &Option<PrimitiveLiteral>,
@@ -921,6 +1123,81 @@ mod test {
         assert!(struct_column.is_null(2));
     }
 
+    #[test]
+    fn nested_field_projection_prunes_struct_column() {
+        // Files without field ids are read by top-level column, so the transformer
+        // prunes the struct to the selected nested field.
+        let snapshot_schema = Arc::new(
+            Schema::builder()
+                .with_schema_id(1)
+                .with_fields(vec![
+                    NestedField::required(1, "id", Type::Primitive(PrimitiveType::Int)).into(),
+                    NestedField::optional(
+                        2,
+                        "person",
+                        Type::Struct(crate::spec::StructType::new(vec![
+                            NestedField::optional(
+                                3,
+                                "name",
+                                Type::Primitive(PrimitiveType::String),
+                            )
+                            .into(),
+                            NestedField::optional(4, "age", Type::Primitive(PrimitiveType::Int))
+                                .into(),
+                        ])),
+                    )
+                    .into(),
+                ])
+                .build()
+                .unwrap(),
+        );
+        let projected_iceberg_field_ids = [4, 1];
+
+        let mut transformer =
+            RecordBatchTransformerBuilder::new(snapshot_schema, &projected_iceberg_field_ids)
+                .build();
+
+        let person_fields = arrow_schema::Fields::from(vec![
+            Field::new("name", DataType::Utf8, true),
+            Field::new("age", DataType::Int32, true),
+        ]);
+        let file_schema = Arc::new(ArrowSchema::new(vec![
+            simple_field("id", DataType::Int32, false, "1"),
+            simple_field("person", DataType::Struct(person_fields.clone()), true, "2"),
+        ]));
+        let file_batch = RecordBatch::try_new(file_schema, vec![
+            Arc::new(Int32Array::from(vec![1, 2])),
+            Arc::new(arrow_array::StructArray::new(
+                person_fields,
+                vec![
+                    Arc::new(StringArray::from(vec!["Alice", "Bob"])),
+                    Arc::new(Int32Array::from(vec![30, 25])),
+                ],
+                None,
+            )),
+        ])
+        .unwrap();
+
+        let result = transformer.process_record_batch(file_batch).unwrap();
+
+        assert_eq!(result.num_columns(), 2);
+        assert_eq!(result.schema().field(0).name(), "person");
+        assert_eq!(result.schema().field(1).name(), "id");
+
+        let person_column = result
+            .column(0)
+            .as_any()
+            .downcast_ref::<arrow_array::StructArray>()
+            .unwrap();
+        assert_eq!(person_column.column_names(), vec!["age"]);
+        let age_column = person_column
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap();
+        assert_eq!(age_column.values(), &[30, 25]);
+    }
+
     pub fn source_record_batch() -> RecordBatch {
         RecordBatch::try_new(
             arrow_schema_promotion_addition_and_renaming_required(),
@@ -0,0 +1,241 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Helpers for writers that rewrite rows of v3 tables while preserving their row lineage.
+//!
+//! Rows copied unchanged into a new data file (e.g. by compaction) must keep both their
+//! `_row_id` and `_last_updated_sequence_number`. To do so, read them with both row lineage
+//! columns selected and write the batches as read: the
+//! [`ParquetWriter`](crate::writer::file_writer::ParquetWriter) persists the row lineage
+//! columns of the batches it writes, even if its schema doesn't have them.
+//!
+//! Rows modified by a row-level operation keep their `_row_id`, but their
+//! `_last_updated_sequence_number` must be inherited from the commit that rewrites them.
+//! [`mark_rows_updated`] clears it for those rows before they are written.
+
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::Int64Type;
+use arrow_array::{BooleanArray, Int64Array, RecordBatch};
+use arrow_schema::{Field, Schema as ArrowSchema};
+use parquet::arrow::PARQUET_FIELD_ID_META_KEY;
+
+use crate::metadata_columns::{
+    RESERVED_COL_NAME_LAST_UPDATED_SEQUENCE_NUMBER, RESERVED_FIELD_ID_LAST_UPDATED_SEQUENCE_NUMBER,
+    last_updated_sequence_number_field, row_id_field,
+};
+use crate::spec::Schema;
+use crate::{Error, ErrorKind, Result};
+
+/// Returns whether the arrow field is the metadata column with the given id and name.
+fn is_metadata_column(field: &Field, field_id: i32, name: &str) -> bool {
+    match field.metadata().get(PARQUET_FIELD_ID_META_KEY) {
+        Some(id) => *id == field_id.to_string(),
+        None => field.name() == name,
+    }
+}
+
+/// Returns `schema` extended with the row lineage fields of `batch_schema` it doesn't have,
+/// in the order of `batch_schema`, or `None` if there are none.
+pub(crate) fn schema_with_batch_row_lineage(
+    schema: &Schema,
+    batch_schema: &ArrowSchema,
+) -> Result<Option<Schema>> {
+    let lineage_fields: Vec<_> = batch_schema
+        .fields()
+        .iter()
+        .filter_map(|field| {
+            [row_id_field(), last_updated_sequence_number_field()]
+                .into_iter()
+                .find(|lineage_field| {
+                    is_metadata_column(field, lineage_field.id, &lineage_field.name)
+                })
+        })
+        .filter(|lineage_field| schema.field_by_id(lineage_field.id).is_none())
+        .cloned()
+        .collect();
+    if lineage_fields.is_empty() {
+        return Ok(None);
+    }
+
+    let mut fields = schema.as_struct().fields().to_vec();
+    fields.extend(lineage_fields);
+    Schema::builder()
+        .with_schema_id(schema.schema_id())
+        .with_identifier_field_ids(schema.identifier_field_ids())
+        .with_fields(fields)
+        .build()
+        .map(Some)
+}
+
+/// Clears the `_last_updated_sequence_number` of the rows selected by `updated`, so that
+/// they inherit the sequence number of the snapshot that commits them.
+///
+/// `_row_id` is left untouched, which keeps the identity of updated rows.
+///
+/// # Arguments
+/// * `batch` - Rows read with the `_last_updated_sequence_number` column selected
+/// * `updated` - For each row, whether it was modified
+pub fn mark_rows_updated(batch: &RecordBatch, updated: &BooleanArray) -> Result<RecordBatch> {
+    if updated.len() != batch.num_rows() {
+        return Err(Error::new(
+            ErrorKind::DataInvalid,
+            format!(
+                "Updated row mask has {} values, but the batch has {} rows",
+                updated.len(),
+                batch.num_rows()
+            ),
+        ));
+    }
+
+    let index = batch
+        .schema()
+        .fields()
+        .iter()
+        .position(|field| {
+            is_metadata_column(
+                field,
+                RESERVED_FIELD_ID_LAST_UPDATED_SEQUENCE_NUMBER,
+                RESERVED_COL_NAME_LAST_UPDATED_SEQUENCE_NUMBER,
+            )
+        })
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::DataInvalid,
+                format!(
+                    "Batch has no {RESERVED_COL_NAME_LAST_UPDATED_SEQUENCE_NUMBER} column to update"
+                ),
+            )
+        })?;
+
+    let sequence_numbers = batch
+        .column(index)
+        .as_primitive_opt::<Int64Type>()
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::DataInvalid,
+                format!(
+                    "{RESERVED_COL_NAME_LAST_UPDATED_SEQUENCE_NUMBER} column must be of type long"
+                ),
+            )
+        })?;
+    let cleared: Int64Array = sequence_numbers
+        .iter()
+        .zip(updated.iter())
+        .map(|(sequence_number, updated)| {
+            if updated.unwrap_or(false) {
+                None
+            } else {
+                sequence_number
+            }
+        })
+        .collect();
+
+    let mut columns = batch.columns().to_vec();
+    columns[index] = Arc::new(cleared);
+    RecordBatch::try_new(batch.schema(), columns).map_err(|err| {
+        Error::new(
+            ErrorKind::Unexpected,
+            "Failed to clear the last updated sequence number of updated rows",
+        )
+        .with_source(err)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use arrow_array::Array;
+    use arrow_schema::{DataType, Field, Schema};
+
+    use super::*;
+    use crate::metadata_columns::RESERVED_COL_NAME_ROW_ID;
+    use crate::spec::{NestedField, PrimitiveType, Type};
+
+    #[test]
+    fn test_mark_rows_updated() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new(RESERVED_COL_NAME_ROW_ID, DataType::Int64, true),
+            Field::new("seq", DataType::Int64, true).with_metadata(HashMap::from([(
+                PARQUET_FIELD_ID_META_KEY.to_string(),
+                RESERVED_FIELD_ID_LAST_UPDATED_SEQUENCE_NUMBER.to_string(),
+            )])),
+        ]));
+        let batch = RecordBatch::try_new(schema, vec![
+            Arc::new(Int64Array::from(vec![0, 1, 2])),
+            Arc::new(Int64Array::from(vec![Some(3), Some(3), None])),
+        ])
+        .unwrap();
+
+        let updated = mark_rows_updated(
+            &batch,
+            &BooleanArray::from(vec![Some(false), Some(true), None]),
+        )
+        .unwrap();
+
+        assert_eq!(updated.column(0), batch.column(0));
+        let sequence_numbers = updated.column(1).as_primitive::<Int64Type>();
+        assert_eq!(sequence_numbers.value(0), 3);
+        assert!(sequence_numbers.is_null(1));
+        assert!(sequence_numbers.is_null(2));
+
+        let err = mark_rows_updated(&batch, &BooleanArray::from(vec![true])).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::DataInvalid);
+        let err = mark_rows_updated(
+            &batch.project(&[0]).unwrap(),
+            &BooleanArray::from(vec![true, true, true]),
+        )
+        .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::DataInvalid);
+    }
+
+    #[test]
+    fn test_schema_with_batch_row_lineage() {
+        let schema = crate::spec::Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "x", Type::Primitive(PrimitiveType::Long)).into(),
+            ])
+            .build()
+            .unwrap();
+        let batch_schema = Schema::new(vec![
+            Field::new("x", DataType::Int64, false),
+            Field::new(RESERVED_COL_NAME_ROW_ID, DataType::Int64, true),
+        ]);
+
+        let extended = schema_with_batch_row_lineage(&schema, &batch_schema)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            extended.as_struct().fields().last().unwrap(),
+            row_id_field()
+        );
+        assert!(
+            extended
+                .field_by_id(RESERVED_FIELD_ID_LAST_UPDATED_SEQUENCE_NUMBER)
+                .is_none()
+        );
+
+        // Nothing is added to a schema that has the lineage fields already
+        assert!(
+            schema_with_batch_row_lineage(&extended, &batch_schema)
+                .unwrap()
+                .is_none()
+        );
+    }
+}
@@ -26,6 +26,7 @@ use arrow_array::{
     FixedSizeBinaryArray, Float32Array, Float64Array, Int32Array, Int64Array, Scalar, StringArray,
     TimestampMicrosecondArray, TimestampNanosecondArray,
 };
+use arrow_schema::extension::{EXTENSION_TYPE_METADATA_KEY, EXTENSION_TYPE_NAME_KEY};
 use arrow_schema::{DataType, Field, Fields, Schema as ArrowSchema, TimeUnit};
 use parquet::arrow::PARQUET_FIELD_ID_META_KEY;
 use parquet::file::statistics::Statistics;
@@ -34,8 +35,8 @@ use uuid::Uuid;
 use crate::error::Result;
 use crate::spec::decimal_utils::i128_from_be_bytes;
 use crate::spec::{
-    Datum, FIRST_FIELD_ID, ListType, MapType, NestedField, NestedFieldRef, PrimitiveLiteral,
-    PrimitiveType, Schema, SchemaVisitor, StructType, Type,
+    DEFAULT_CRS, Datum, FIRST_FIELD_ID, ListType, MapType, NestedField, NestedFieldRef,
+    PrimitiveLiteral, PrimitiveType, Schema, SchemaVisitor, StructType, Type,
 };
 use crate::{Error, ErrorKind};
 
@@ -43,6 +44,101 @@ use crate::{Error, ErrorKind};
 pub const DEFAULT_MAP_FIELD_NAME: &str = "key_value";
 /// UTC time zone for Arrow timestamp type.
 pub const UTC_TIME_ZONE: &str = "+00:00";
+/// Arrow extension type name of the canonical Parquet variant type, which iceberg variant
+/// type converts to.
+pub const VARIANT_EXTENSION_NAME: &str = "arrow.parquet.variant";
+/// Field name of the variant metadata in the arrow struct of a variant.
+pub const VARIANT_METADATA_FIELD_NAME: &str = "metadata";
+/// Field name of the variant value in the arrow struct of a variant.
+pub const VARIANT_VALUE_FIELD_NAME: &str = "value";
+
+/// Returns the fields of the arrow struct storing a variant: the binary encoded
+/// variant metadata and value.
+pub(crate) fn variant_struct_fields() -> Fields {
+    Fields::from(vec![
+        Field::new(VARIANT_METADATA_FIELD_NAME, DataType::Binary, false),
+        Field::new(VARIANT_VALUE_FIELD_NAME, DataType::Binary, true),
+    ])
+}
+
+/// Arrow extension type name of GeoArrow WKB arrays, which iceberg geometry and
+/// geography types convert to.
+pub const GEOARROW_WKB_EXTENSION_NAME: &str = "geoarrow.wkb";
+
+/// Whether the arrow field is annotated with the canonical Parquet variant extension type.
+pub(crate) fn is_variant_field(field: &Field) -> bool {
+    field.extension_type_name() == Some(VARIANT_EXTENSION_NAME)
+        && matches!(field.data_type(), DataType::Struct(_))
+}
+
+/// Whether the arrow field is a binary annotated with the GeoArrow WKB extension type.
+pub(crate) fn is_geospatial_field(field: &Field) -> bool {
+    field.extension_type_name() == Some(GEOARROW_WKB_EXTENSION_NAME)
+        && matches!(
+            field.data_type(),
+            DataType::Binary | DataType::LargeBinary | DataType::BinaryView
+        )
+}
+
+/// Returns the extension type metadata of the arrow field storing an iceberg type, if any.
+fn extension_type_metadata(ty: &Type) -> Vec<(String, String)> {
+    let geoarrow_metadata = |metadata: serde_json::Value| {
+        vec![
+            (
+                EXTENSION_TYPE_NAME_KEY.to_string(),
+                GEOARROW_WKB_EXTENSION_NAME.to_string(),
+            ),
+            (
+                EXTENSION_TYPE_METADATA_KEY.to_string(),
+                metadata.to_string(),
+            ),
+        ]
+    };
+    match ty {
+        Type::Variant => vec![(
+            EXTENSION_TYPE_NAME_KEY.to_string(),
+            VARIANT_EXTENSION_NAME.to_string(),
+        )],
+        Type::Primitive(PrimitiveType::Geometry { crs }) => {
+            geoarrow_metadata(serde_json::json!({ "crs": crs }))
+        }
+        Type::Primitive(PrimitiveType::Geography { crs, algorithm }) => {
+            geoarrow_metadata(serde_json::json!({ "crs": crs, "edges": algorithm.to_string() }))
+        }
+        _ => vec![],
+    }
+}
+
+/// Parses the iceberg type of an arrow field annotated with the GeoArrow WKB extension type.
+///
+/// Fields with an edge interpolation algorithm are geographies, others geometries. A missing
+/// CRS defaults to [`DEFAULT_CRS`].
+fn geospatial_type_from_field(field: &Field) -> Result<Type> {
+    let metadata = match field.metadata().get(EXTENSION_TYPE_METADATA_KEY) {
+        Some(metadata) if !metadata.is_empty() => {
+            serde_json::from_str::<serde_json::Value>(metadata).map_err(|e| {
+                Error::new(
+                    ErrorKind::DataInvalid,
+                    format!("Invalid GeoArrow metadata of field {}", field.name()),
+                )
+                .with_source(e)
+            })?
+        }
+        _ => serde_json::Value::Null,
+    };
+    let crs = match metadata.get("crs") {
+        Some(serde_json::Value::String(crs)) => crs.clone(),
+        Some(serde_json::Value::Null) | None => DEFAULT_CRS.to_string(),
+        Some(crs) => crs.to_string(),
+    };
+    match metadata.get("edges").and_then(|edges| edges.as_str()) {
+        None | Some("planar") => Ok(Type::Primitive(PrimitiveType::Geometry { crs })),
+        Some(edges) => Ok(Type::Primitive(PrimitiveType::Geography {
+            crs,
+            algorithm: edges.parse()?,
+        })),
+    }
+}
 
 /// A post order arrow schema visitor.
 ///
@@ -108,10 +204,35 @@ pub trait ArrowSchemaVisitor {
 
     /// Called when see a primitive type.
     fn primitive(&mut self, p: &DataType) -> Result<Self::T>;
+
+    /// Called when see a field annotated with the variant extension type.
+    ///
+    /// Visits the underlying struct type by default.
+    fn variant(&mut self, field: &Field) -> Result<Self::T> {
+        visit_type(field.data_type(), self)
+    }
+
+    /// Called when see a field annotated with the GeoArrow WKB extension type.
+    ///
+    /// Visits the underlying binary type by default.
+    fn geospatial(&mut self, field: &Field) -> Result<Self::T> {
+        visit_type(field.data_type(), self)
+    }
+}
+
+/// Visiting the type of a field in post order.
+fn visit_field<V: ArrowSchemaVisitor + ?Sized>(field: &Field, visitor: &mut V) -> Result<V::T> {
+    if is_variant_field(field) {
+        visitor.variant(field)
+    } else if is_geospatial_field(field) {
+        visitor.geospatial(field)
+    } else {
+        visit_type(field.data_type(), visitor)
+    }
 }
 
 /// Visiting a type in post order.
-fn visit_type<V: ArrowSchemaVisitor>(r#type: &DataType, visitor: &mut V) -> Result<V::T> {
+fn visit_type<V: ArrowSchemaVisitor + ?Sized>(r#type: &DataType, visitor: &mut V) -> Result<V::T> {
     match r#type {
         p if p.is_primitive()
             || matches!(
@@ -145,14 +266,14 @@ fn visit_type<V: ArrowSchemaVisitor>(r#type: &DataType, visitor: &mut V) -> Resu
 
                 let key_result = {
                     visitor.before_map_key(key_field)?;
-                    let ret = visit_type(key_field.data_type(), visitor)?;
+                    let ret = visit_field(key_field, visitor)?;
                     visitor.after_map_key(key_field)?;
                     ret
                 };
 
                 let value_result = {
                     visitor.before_map_value(value_field)?;
-                    let ret = visit_type(value_field.data_type(), visitor)?;
+                    let ret = visit_field(value_field, visitor)?;
                     visitor.after_map_value(value_field)?;
                     ret
                 };
@@ -174,23 +295,23 @@ fn visit_type<V: ArrowSchemaVisitor>(r#type: &DataType, visitor: &mut V) -> Resu
 }
 
 /// Visit list types in post order.
-fn visit_list<V: ArrowSchemaVisitor>(
+fn visit_list<V: ArrowSchemaVisitor + ?Sized>(
     data_type: &DataType,
     element_field: &Field,
     visitor: &mut V,
 ) -> Result<V::T> {
     visitor.before_list_element(element_field)?;
-    let value = visit_type(element_field.data_type(), visitor)?;
+    let value = visit_field(element_field, visitor)?;
     visitor.after_list_element(element_field)?;
     visitor.list(data_type, value)
 }
 
 /// Visit struct type in post order.
-fn visit_struct<V: ArrowSchemaVisitor>(fields: &Fields, visitor: &mut V) -> Result<V::T> {
+fn visit_struct<V: ArrowSchemaVisitor + ?Sized>(fields: &Fields, visitor: &mut V) -> Result<V::T> {
     let mut results = Vec::with_capacity(fields.len());
     for field in fields {
         visitor.before_field(field)?;
-        let result = visit_type(field.data_type(), visitor)?;
+        let result = visit_field(field, visitor)?;
         visitor.after_field(field)?;
         results.push(result);
     }
@@ -203,7 +324,7 @@ fn visit_schema<V: ArrowSchemaVisitor>(schema: &ArrowSchema, visitor: &mut V) ->
     let mut results = Vec::with_capacity(schema.fields().len());
     for field in schema.fields() {
         visitor.before_field(field)?;
-        let result = visit_type(field.data_type(), visitor)?;
+        let result = visit_field(field, visitor)?;
         visitor.after_field(field)?;
         results.push(result);
     }
@@ -485,6 +606,14 @@ impl ArrowSchemaVisitor for ArrowSchemaConverter {
             )),
         }
     }
+
+    fn variant(&mut self, _field: &Field) -> Result<Self::T> {
+        Ok(Type::Variant)
+    }
+
+    fn geospatial(&mut self, field: &Field) -> Result<Self::T> {
+        geospatial_type_from_field(field)
+    }
 }
 
 struct ToArrowSchemaConverter;
@@ -521,7 +650,7 @@ impl SchemaVisitor for ToArrowSchemaConverter {
             ArrowSchemaOrFieldOrType::Type(ty) => ty,
             _ => unreachable!(),
         };
-        let metadata = if let Some(doc) = &field.doc {
+        let mut metadata = if let Some(doc) = &field.doc {
             HashMap::from([
                 (PARQUET_FIELD_ID_META_KEY.to_string(), field.id.to_string()),
                 (ARROW_FIELD_DOC_KEY.to_string(), doc.clone()),
@@ -529,6 +658,7 @@ impl SchemaVisitor for ToArrowSchemaConverter {
         } else {
             HashMap::from([(PARQUET_FIELD_ID_META_KEY.to_string(), field.id.to_string())])
         };
+        metadata.extend(extension_type_metadata(&field.field_type));
         Ok(ArrowSchemaOrFieldOrType::Field(
             Field::new(field.name.clone(), ty, !field.required).with_metadata(metadata),
         ))
@@ -558,7 +688,7 @@ impl SchemaVisitor for ToArrowSchemaConverter {
             ArrowSchemaOrFieldOrType::Field(field) => field,
             _ => unreachable!(),
         };
-        let meta = if let Some(doc) = &list.element_field.doc {
+        let mut meta = if let Some(doc) = &list.element_field.doc {
             HashMap::from([
                 (
                     PARQUET_FIELD_ID_META_KEY.to_string(),
@@ -572,6 +702,7 @@ impl SchemaVisitor for ToArrowSchemaConverter {
                 list.element_field.id.to_string(),
             )])
         };
+        meta.extend(extension_type_metadata(&list.element_field.field_type));
         let field = field.with_metadata(meta);
         Ok(ArrowSchemaOrFieldOrType::Type(DataType::List(Arc::new(
             field,
@@ -687,8 +818,18 @@ impl SchemaVisitor for ToArrowSchemaConverter {
             crate::spec::PrimitiveType::Binary => {
                 Ok(ArrowSchemaOrFieldOrType::Type(DataType::LargeBinary))
             }
+            crate::spec::PrimitiveType::Geometry { .. }
+            | crate::spec::PrimitiveType::Geography { .. } => {
+                Ok(ArrowSchemaOrFieldOrType::Type(DataType::Binary))
+            }
         }
     }
+
+    fn variant(&mut self) -> crate::Result<ArrowSchemaOrFieldOrType> {
+        Ok(ArrowSchemaOrFieldOrType::Type(DataType::Struct(
+            variant_struct_fields(),
+        )))
+    }
 }
 
 /// Convert iceberg schema to an arrow schema.
@@ -1123,6 +1264,9 @@ pub fn datum_to_arrow_type_with_ree(datum: &Datum) -> DataType {
         PrimitiveType::Uuid => make_ree(DataType::Binary),
         PrimitiveType::Fixed(_) => make_ree(DataType::Binary),
         PrimitiveType::Binary => make_ree(DataType::Binary),
+        PrimitiveType::Geometry { .. } | PrimitiveType::Geography { .. } => {
+            make_ree(DataType::Binary)
+        }
         PrimitiveType::Decimal { precision, scale } => {
             make_ree(DataType::Decimal128(*precision as u8, *scale as i8))
         }
@@ -1302,7 +1446,7 @@ mod tests {
 
     use super::*;
     use crate::spec::decimal_utils::decimal_new;
-    use crate::spec::{Literal, Schema};
+    use crate::spec::{EdgeAlgorithm, Literal, Schema};
 
     /// Create a simple field with metadata.
     fn simple_field(name: &str, ty: DataType, nullable: bool, value: &str) -> Field {
@@ -1897,6 +2041,66 @@ mod tests {
         assert_eq!(converted_arrow_schema, arrow_schema);
     }
 
+    #[test]
+    fn test_variant_schema_conversion() {
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+                NestedField::optional(2, "payload", Type::Variant).into(),
+            ])
+            .build()
+            .unwrap();
+
+        let arrow_schema = schema_to_arrow_schema(&schema).unwrap();
+        let variant_field = arrow_schema.field(1);
+        assert!(is_variant_field(variant_field));
+        assert_eq!(
+            variant_field.data_type(),
+            &DataType::Struct(variant_struct_fields())
+        );
+        assert_eq!(
+            variant_field.metadata().get(PARQUET_FIELD_ID_META_KEY),
+            Some(&"2".to_string())
+        );
+
+        assert_eq!(arrow_schema_to_schema(&arrow_schema).unwrap(), schema);
+    }
+
+    #[test]
+    fn test_geospatial_schema_conversion() {
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::optional(1, "point", Type::Primitive(PrimitiveType::geometry()))
+                    .into(),
+                NestedField::optional(
+                    2,
+                    "area",
+                    Type::Primitive(PrimitiveType::Geography {
+                        crs: "srid:4326".to_string(),
+                        algorithm: EdgeAlgorithm::Karney,
+                    }),
+                )
+                .into(),
+            ])
+            .build()
+            .unwrap();
+
+        let arrow_schema = schema_to_arrow_schema(&schema).unwrap();
+        for field in arrow_schema.fields() {
+            assert!(is_geospatial_field(field));
+            assert_eq!(field.data_type(), &DataType::Binary);
+        }
+        assert_eq!(
+            arrow_schema
+                .field(1)
+                .metadata()
+                .get(EXTENSION_TYPE_NAME_KEY),
+            Some(&GEOARROW_WKB_EXTENSION_NAME.to_string())
+        );
+
+        assert_eq!(arrow_schema_to_schema(&arrow_schema).unwrap(), schema);
+    }
+
     #[test]
     fn test_type_conversion() {
         // test primitive type
@@ -37,6 +37,9 @@ const FIELD_ID_PROP: &str = "field-id";
 const KEY_ID: &str = "key-id";
 const VALUE_ID: &str = "value-id";
 const MAP_LOGICAL_TYPE: &str = "map";
+const VARIANT_LOGICAL_TYPE: &str = "variant";
+const VARIANT_METADATA_FIELD: &str = "metadata";
+const VARIANT_VALUE_FIELD: &str = "value";
 // This const may better to maintain in avro-rs.
 const LOGICAL_TYPE: &str = "logicalType";
 
@@ -237,12 +240,40 @@ impl SchemaVisitor for SchemaToAvroSchema {
             PrimitiveType::Uuid => AvroSchema::Uuid,
             PrimitiveType::Fixed(len) => avro_fixed_schema((*len) as usize)?,
             PrimitiveType::Binary => AvroSchema::Bytes,
+            // Geospatial values are stored as WKB.
+            PrimitiveType::Geometry { .. } | PrimitiveType::Geography { .. } => AvroSchema::Bytes,
             PrimitiveType::Decimal { precision, scale } => {
                 avro_decimal_schema(*precision as usize, *scale as usize)?
             }
         };
         Ok(Either::Left(avro_schema))
     }
+
+    fn variant(&mut self) -> Result<AvroSchemaOrField> {
+        // A variant is a record of the binary encoded metadata and value, annotated with
+        // the variant logical type. The record name is determined later, by the field id.
+        let binary_field = |name: &str| AvroRecordField {
+            name: name.to_string(),
+            doc: None,
+            aliases: None,
+            default: None,
+            schema: AvroSchema::Bytes,
+            order: RecordFieldOrder::Ignore,
+            position: 0,
+            custom_attributes: Default::default(),
+        };
+        let mut avro_schema = avro_record_schema("null", vec![
+            binary_field(VARIANT_METADATA_FIELD),
+            binary_field(VARIANT_VALUE_FIELD),
+        ])?;
+        if let AvroSchema::Record(record) = &mut avro_schema {
+            record.attributes.insert(
+                LOGICAL_TYPE.to_string(),
+                Value::String(VARIANT_LOGICAL_TYPE.to_string()),
+            );
+        }
+        Ok(Either::Left(avro_schema))
+    }
 }
 
 /// Converting iceberg schema to avro schema.
@@ -332,11 +363,22 @@ pub(crate) trait AvroSchemaVisitor {
     fn map_array(&mut self, array: &RecordSchema, key: Self::T, value: Self::T) -> Result<Self::T>;
 
     fn primitive(&mut self, schema: &AvroSchema) -> Result<Self::T>;
+
+    fn variant(&mut self, record: &RecordSchema) -> Result<Self::T>;
 }
 
 /// Visit avro schema in post order visitor.
 pub(crate) fn visit<V: AvroSchemaVisitor>(schema: &AvroSchema, visitor: &mut V) -> Result<V::T> {
     match schema {
+        AvroSchema::Record(record)
+            if record
+                .attributes
+                .get(LOGICAL_TYPE)
+                .and_then(|v| Value::as_str(v))
+                == Some(VARIANT_LOGICAL_TYPE) =>
+        {
+            visitor.variant(record)
+        }
         AvroSchema::Record(record) => {
             let field_results = record
                 .fields
@@ -538,6 +580,10 @@ impl AvroSchemaVisitor for AvroSchemaToSchema {
         Ok(Some(schema_type))
     }
 
+    fn variant(&mut self, _record: &RecordSchema) -> Result<Option<Type>> {
+        Ok(Some(Type::Variant))
+    }
+
     fn map_array(
         &mut self,
         array: &RecordSchema,
@@ -1182,6 +1228,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_variant_type() {
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", PrimitiveType::Long.into()).into(),
+                NestedField::optional(2, "payload", Type::Variant).into(),
+            ])
+            .build()
+            .unwrap();
+
+        let avro_schema = schema_to_avro_schema("test", &schema).unwrap();
+        let AvroSchema::Record(record) = &avro_schema else {
+            panic!("expected record schema");
+        };
+        let AvroSchema::Union(union) = &record.fields[1].schema else {
+            panic!("expected optional variant");
+        };
+        let AvroSchema::Record(variant) = &union.variants()[1] else {
+            panic!("expected variant record");
+        };
+        assert_eq!(
+            variant.attributes.get(LOGICAL_TYPE),
+            Some(&Value::String(VARIANT_LOGICAL_TYPE.to_string()))
+        );
+        assert_eq!(variant.fields.len(), 2);
+        assert_eq!(variant.fields[0].schema, AvroSchema::Bytes);
+        assert_eq!(variant.fields[1].schema, AvroSchema::Bytes);
+
+        assert_eq!(schema, avro_schema_to_schema(&avro_schema).unwrap());
+    }
+
     #[test]
     fn test_date_type() {
         let mut converter = AvroSchemaToSchema;
@@ -20,6 +20,8 @@
 use std::sync::Arc;
 
 use crate::spec::{Manifest, ManifestList};
+use crate::table::Table;
+use crate::{NamespaceIdent, TableIdent};
 
 /// A trait for caching in-memory objects of given type.
 ///
@@ -34,6 +36,13 @@ pub trait ObjectCache<K, V>: Send + Sync {
     fn get(&self, key: &K) -> Option<V>;
     /// Sets an object in the cache with the given key and value.
     fn set(&self, key: K, value: V);
+    /// Removes an object from the cache by its key.
+    ///
+    /// [`CachingCatalog`](crate::CachingCatalog) relies on this to drop tables and
+    /// namespace listings that changed, so it must take effect immediately.
+    fn invalidate(&self, key: &K);
+    /// Removes the objects whose keys match `predicate` from the cache.
+    fn invalidate_if(&self, predicate: &dyn Fn(&K) -> bool);
 }
 
 /// A trait for caching different in-memory objects used by iceberg.
@@ -49,6 +58,18 @@ pub trait ObjectCacheProvide: Send + Sync {
     fn manifest_cache(&self) -> &dyn ObjectCache<String, Arc<Manifest>>;
     /// Gets a cache for manifest lists.
     fn manifest_list_cache(&self) -> &dyn ObjectCache<String, Arc<ManifestList>>;
+    /// Gets a cache for the tables loaded by a [`CachingCatalog`](crate::CachingCatalog),
+    /// or `None` if tables are not cached.
+    fn table_cache(&self) -> Option<&dyn ObjectCache<TableIdent, Table>> {
+        None
+    }
+    /// Gets a cache for the namespaces listed by a [`CachingCatalog`](crate::CachingCatalog),
+    /// keyed by parent namespace, or `None` if namespace listings are not cached.
+    fn namespace_list_cache(
+        &self,
+    ) -> Option<&dyn ObjectCache<Option<NamespaceIdent>, Vec<NamespaceIdent>>> {
+        None
+    }
 }
 
 /// CacheProvider is a type alias for a thread-safe reference-counted pointer to a CacheProvide trait object.
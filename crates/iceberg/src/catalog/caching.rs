@@ -0,0 +1,582 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! This module contains a catalog caching the tables and namespaces loaded from another catalog.
+
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+use std::hash::Hash;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use crate::cache::{ObjectCache, ObjectCacheProvider};
+use crate::table::Table;
+use crate::view::View;
+use crate::{
+    Catalog, Namespace, NamespaceIdent, Result, TableCommit, TableCreation, TableIdent, ViewCommit,
+    ViewCreation,
+};
+
+/// Default time after which cached tables and namespace listings expire.
+pub const DEFAULT_CATALOG_CACHE_EXPIRATION: Duration = Duration::from_secs(30);
+/// Default maximum number of cached tables, and of cached namespace listings.
+pub const DEFAULT_CATALOG_CACHE_MAX_ENTRIES: u64 = 1000;
+
+/// The in-memory cache backing a [`CachingCatalog`] without a cache provider.
+struct InMemoryCache<K, V>(moka::sync::Cache<K, V>);
+
+impl<K, V> InMemoryCache<K, V>
+where
+    K: Hash + Eq + Send + Sync + 'static,
+    V: Clone + Send + Sync + 'static,
+{
+    fn new(expiration: Duration, max_entries: u64) -> Self {
+        Self(
+            moka::sync::Cache::builder()
+                .time_to_live(expiration)
+                .max_capacity(max_entries)
+                .build(),
+        )
+    }
+}
+
+impl<K, V> ObjectCache<K, V> for InMemoryCache<K, V>
+where
+    K: Hash + Eq + Send + Sync + 'static,
+    V: Clone + Send + Sync + 'static,
+{
+    fn get(&self, key: &K) -> Option<V> {
+        self.0.get(key)
+    }
+
+    fn set(&self, key: K, value: V) {
+        self.0.insert(key, value);
+    }
+
+    fn invalidate(&self, key: &K) {
+        self.0.invalidate(key);
+    }
+
+    fn invalidate_if(&self, predicate: &dyn Fn(&K) -> bool) {
+        for (key, _) in self.0.iter() {
+            if predicate(&key) {
+                self.0.invalidate(key.as_ref());
+            }
+        }
+    }
+}
+
+enum CatalogCache {
+    InMemory {
+        tables: InMemoryCache<TableIdent, Table>,
+        namespace_lists: InMemoryCache<Option<NamespaceIdent>, Vec<NamespaceIdent>>,
+    },
+    Provider(ObjectCacheProvider),
+}
+
+impl CatalogCache {
+    fn tables(&self) -> Option<&dyn ObjectCache<TableIdent, Table>> {
+        match self {
+            Self::InMemory { tables, .. } => Some(tables),
+            Self::Provider(provider) => provider.table_cache(),
+        }
+    }
+
+    fn namespace_lists(
+        &self,
+    ) -> Option<&dyn ObjectCache<Option<NamespaceIdent>, Vec<NamespaceIdent>>> {
+        match self {
+            Self::InMemory {
+                namespace_lists, ..
+            } => Some(namespace_lists),
+            Self::Provider(provider) => provider.namespace_list_cache(),
+        }
+    }
+}
+
+/// A catalog caching the tables loaded from, and the namespaces listed by, another catalog.
+///
+/// Cached entries expire after a while, so changes made through other catalogs are
+/// eventually visible. Changes made through this catalog invalidate the entries they affect:
+/// tables are invalidated when they are updated, dropped or renamed, or when their namespace
+/// is dropped, and namespace listings when namespaces are created or dropped.
+pub struct CachingCatalog {
+    catalog: Arc<dyn Catalog>,
+    cache: CatalogCache,
+}
+
+impl Debug for CachingCatalog {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CachingCatalog")
+            .field("catalog", &self.catalog)
+            .finish_non_exhaustive()
+    }
+}
+
+impl CachingCatalog {
+    /// Wraps a catalog, caching entries in memory for [`DEFAULT_CATALOG_CACHE_EXPIRATION`].
+    pub fn new(catalog: Arc<dyn Catalog>) -> Self {
+        Self::with_expiration(
+            catalog,
+            DEFAULT_CATALOG_CACHE_EXPIRATION,
+            DEFAULT_CATALOG_CACHE_MAX_ENTRIES,
+        )
+    }
+
+    /// Wraps a catalog, caching up to `max_entries` tables and namespace listings in memory,
+    /// each for `expiration`.
+    pub fn with_expiration(
+        catalog: Arc<dyn Catalog>,
+        expiration: Duration,
+        max_entries: u64,
+    ) -> Self {
+        Self {
+            catalog,
+            cache: CatalogCache::InMemory {
+                tables: InMemoryCache::new(expiration, max_entries),
+                namespace_lists: InMemoryCache::new(expiration, max_entries),
+            },
+        }
+    }
+
+    /// Wraps a catalog, caching entries in the table and namespace list caches of `provider`.
+    ///
+    /// The provider controls expiration and size. Entries the provider has no cache for are
+    /// always loaded from the wrapped catalog.
+    pub fn with_cache_provider(catalog: Arc<dyn Catalog>, provider: ObjectCacheProvider) -> Self {
+        Self {
+            catalog,
+            cache: CatalogCache::Provider(provider),
+        }
+    }
+
+    /// The wrapped catalog.
+    pub fn inner(&self) -> &Arc<dyn Catalog> {
+        &self.catalog
+    }
+
+    /// Removes a table from the cache, so that it's loaded again from the wrapped catalog.
+    pub fn invalidate_table(&self, table: &TableIdent) {
+        if let Some(tables) = self.cache.tables() {
+            tables.invalidate(table);
+        }
+    }
+
+    /// Removes the listing of the namespaces under `parent` from the cache.
+    fn invalidate_namespace_list(&self, parent: Option<NamespaceIdent>) {
+        if let Some(namespace_lists) = self.cache.namespace_lists() {
+            namespace_lists.invalidate(&parent);
+        }
+    }
+
+    fn cache_table(&self, table: &Table) {
+        if let Some(tables) = self.cache.tables() {
+            tables.set(table.identifier().clone(), table.clone());
+        }
+    }
+}
+
+#[async_trait]
+impl Catalog for CachingCatalog {
+    async fn list_namespaces(
+        &self,
+        parent: Option<&NamespaceIdent>,
+    ) -> Result<Vec<NamespaceIdent>> {
+        let key = parent.cloned();
+        if let Some(namespaces) = self
+            .cache
+            .namespace_lists()
+            .and_then(|namespace_lists| namespace_lists.get(&key))
+        {
+            return Ok(namespaces);
+        }
+
+        let namespaces = self.catalog.list_namespaces(parent).await?;
+        if let Some(namespace_lists) = self.cache.namespace_lists() {
+            namespace_lists.set(key, namespaces.clone());
+        }
+        Ok(namespaces)
+    }
+
+    async fn create_namespace(
+        &self,
+        namespace: &NamespaceIdent,
+        properties: HashMap<String, String>,
+    ) -> Result<Namespace> {
+        let result = self.catalog.create_namespace(namespace, properties).await;
+        self.invalidate_namespace_list(namespace.parent());
+        result
+    }
+
+    async fn get_namespace(&self, namespace: &NamespaceIdent) -> Result<Namespace> {
+        self.catalog.get_namespace(namespace).await
+    }
+
+    async fn namespace_exists(&self, namespace: &NamespaceIdent) -> Result<bool> {
+        self.catalog.namespace_exists(namespace).await
+    }
+
+    async fn update_namespace(
+        &self,
+        namespace: &NamespaceIdent,
+        properties: HashMap<String, String>,
+    ) -> Result<()> {
+        self.catalog.update_namespace(namespace, properties).await
+    }
+
+    async fn drop_namespace(&self, namespace: &NamespaceIdent) -> Result<()> {
+        let result = self.catalog.drop_namespace(namespace).await;
+        self.invalidate_namespace_list(namespace.parent());
+        self.invalidate_namespace_list(Some(namespace.clone()));
+        // Tables dropped through other catalogs may still be cached under the namespace.
+        if let Some(tables) = self.cache.tables() {
+            tables.invalidate_if(&|table: &TableIdent| {
+                table.namespace().starts_with(namespace.as_ref())
+            });
+        }
+        result
+    }
+
+    async fn list_tables(&self, namespace: &NamespaceIdent) -> Result<Vec<TableIdent>> {
+        self.catalog.list_tables(namespace).await
+    }
+
+    async fn create_table(
+        &self,
+        namespace: &NamespaceIdent,
+        creation: TableCreation,
+    ) -> Result<Table> {
+        let table = self.catalog.create_table(namespace, creation).await?;
+        self.cache_table(&table);
+        Ok(table)
+    }
+
+    async fn stage_create_table(
+        &self,
+        namespace: &NamespaceIdent,
+        creation: TableCreation,
+    ) -> Result<Table> {
+        self.catalog.stage_create_table(namespace, creation).await
+    }
+
+    async fn load_table(&self, table: &TableIdent) -> Result<Table> {
+        if let Some(cached) = self.cache.tables().and_then(|tables| tables.get(table)) {
+            return Ok(cached);
+        }
+
+        let loaded = self.catalog.load_table(table).await?;
+        self.cache_table(&loaded);
+        Ok(loaded)
+    }
+
+    async fn load_table_for_commit(&self, table: &TableIdent) -> Result<Table> {
+        let loaded = self.catalog.load_table_for_commit(table).await?;
+        self.cache_table(&loaded);
+        Ok(loaded)
+    }
+
+    async fn drop_table(&self, table: &TableIdent) -> Result<()> {
+        let result = self.catalog.drop_table(table).await;
+        self.invalidate_table(table);
+        result
+    }
+
+    async fn purge_table(&self, table: &TableIdent) -> Result<()> {
+        let result = self.catalog.purge_table(table).await;
+        self.invalidate_table(table);
+        result
+    }
+
+    async fn table_exists(&self, table: &TableIdent) -> Result<bool> {
+        self.catalog.table_exists(table).await
+    }
+
+    async fn rename_table(&self, src: &TableIdent, dest: &TableIdent) -> Result<()> {
+        let result = self.catalog.rename_table(src, dest).await;
+        self.invalidate_table(src);
+        self.invalidate_table(dest);
+        result
+    }
+
+    async fn register_table(&self, table: &TableIdent, metadata_location: String) -> Result<Table> {
+        self.invalidate_table(table);
+        let registered = self
+            .catalog
+            .register_table(table, metadata_location)
+            .await?;
+        self.cache_table(&registered);
+        Ok(registered)
+    }
+
+    async fn update_table(&self, commit: TableCommit) -> Result<Table> {
+        let table_ident = commit.identifier().clone();
+        // The table is invalidated even if the commit fails, as its state is then unknown.
+        self.invalidate_table(&table_ident);
+        let updated = self.catalog.update_table(commit).await?;
+        self.cache_table(&updated);
+        Ok(updated)
+    }
+
+    async fn commit_transaction(&self, commits: Vec<TableCommit>) -> Result<()> {
+        for commit in &commits {
+            self.invalidate_table(commit.identifier());
+        }
+        self.catalog.commit_transaction(commits).await
+    }
+
+    async fn list_views(&self, namespace: &NamespaceIdent) -> Result<Vec<TableIdent>> {
+        self.catalog.list_views(namespace).await
+    }
+
+    async fn create_view(
+        &self,
+        namespace: &NamespaceIdent,
+        creation: ViewCreation,
+    ) -> Result<View> {
+        self.catalog.create_view(namespace, creation).await
+    }
+
+    async fn load_view(&self, view: &TableIdent) -> Result<View> {
+        self.catalog.load_view(view).await
+    }
+
+    async fn drop_view(&self, view: &TableIdent) -> Result<()> {
+        self.catalog.drop_view(view).await
+    }
+
+    async fn view_exists(&self, view: &TableIdent) -> Result<bool> {
+        self.catalog.view_exists(view).await
+    }
+
+    async fn rename_view(&self, src: &TableIdent, dest: &TableIdent) -> Result<()> {
+        self.catalog.rename_view(src, dest).await
+    }
+
+    async fn update_view(&self, commit: ViewCommit) -> Result<View> {
+        self.catalog.update_view(commit).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::catalog::MockCatalog;
+    use crate::memory::tests::new_memory_catalog;
+    use crate::spec::{NestedField, PrimitiveType, Schema, Type};
+    use crate::transaction::tests::make_v2_table;
+    use crate::transaction::{ApplyTransactionAction, Transaction};
+
+    fn table_creation(name: &str) -> TableCreation {
+        TableCreation::builder()
+            .name(name.to_string())
+            .schema(
+                Schema::builder()
+                    .with_fields(vec![
+                        NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+                    ])
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_load_table_is_cached() {
+        let table = make_v2_table();
+        let mut mock = MockCatalog::new();
+        mock.expect_load_table()
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(make_v2_table()) }));
+        let catalog = CachingCatalog::new(Arc::new(mock));
+
+        for _ in 0..2 {
+            let result = catalog.load_table(table.identifier()).await.unwrap();
+            assert_eq!(result.metadata(), table.metadata());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_failed_load_table_is_not_cached() {
+        let table = make_v2_table();
+        let mut mock = MockCatalog::new();
+        mock.expect_load_table().times(2).returning(|_| {
+            Box::pin(async {
+                Err(crate::Error::new(
+                    crate::ErrorKind::TableNotFound,
+                    "missing",
+                ))
+            })
+        });
+        let catalog = CachingCatalog::new(Arc::new(mock));
+
+        for _ in 0..2 {
+            assert!(catalog.load_table(table.identifier()).await.is_err());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_namespaces_is_cached_until_namespaces_change() {
+        let mut mock = MockCatalog::new();
+        mock.expect_list_namespaces()
+            .times(2)
+            .returning(|_| Box::pin(async { Ok(vec![NamespaceIdent::new("ns".to_string())]) }));
+        mock.expect_create_namespace()
+            .times(1)
+            .returning(|namespace, _| {
+                let namespace = Namespace::new(namespace.clone());
+                Box::pin(async { Ok(namespace) })
+            });
+        let catalog = CachingCatalog::new(Arc::new(mock));
+
+        catalog.list_namespaces(None).await.unwrap();
+        catalog.list_namespaces(None).await.unwrap();
+        catalog
+            .create_namespace(&NamespaceIdent::new("other".to_string()), HashMap::new())
+            .await
+            .unwrap();
+        catalog.list_namespaces(None).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_update_table_invalidates_cached_table() {
+        let inner: Arc<dyn Catalog> = Arc::new(new_memory_catalog().await);
+        let catalog = CachingCatalog::new(inner.clone());
+        let namespace = NamespaceIdent::new("ns".to_string());
+        catalog
+            .create_namespace(&namespace, HashMap::new())
+            .await
+            .unwrap();
+        let table = catalog
+            .create_table(&namespace, table_creation("tbl"))
+            .await
+            .unwrap();
+
+        let tx = Transaction::new(&table);
+        let updated = tx
+            .update_table_properties()
+            .set("key".to_string(), "value".to_string())
+            .apply(tx)
+            .unwrap()
+            .commit(&catalog)
+            .await
+            .unwrap();
+
+        let loaded = catalog.load_table(table.identifier()).await.unwrap();
+        assert_eq!(loaded.metadata_location(), updated.metadata_location());
+    }
+
+    #[tokio::test]
+    async fn test_changes_to_wrapped_catalog_are_visible_after_invalidation() {
+        let inner: Arc<dyn Catalog> = Arc::new(new_memory_catalog().await);
+        let catalog = CachingCatalog::new(inner.clone());
+        let namespace = NamespaceIdent::new("ns".to_string());
+        inner
+            .create_namespace(&namespace, HashMap::new())
+            .await
+            .unwrap();
+        let table = inner
+            .create_table(&namespace, table_creation("tbl"))
+            .await
+            .unwrap();
+        catalog.load_table(table.identifier()).await.unwrap();
+
+        let tx = Transaction::new(&table);
+        let updated = tx
+            .update_table_properties()
+            .set("key".to_string(), "value".to_string())
+            .apply(tx)
+            .unwrap()
+            .commit(inner.as_ref())
+            .await
+            .unwrap();
+
+        let cached = catalog.load_table(table.identifier()).await.unwrap();
+        assert_eq!(cached.metadata_location(), table.metadata_location());
+
+        catalog.invalidate_table(table.identifier());
+        let loaded = catalog.load_table(table.identifier()).await.unwrap();
+        assert_eq!(loaded.metadata_location(), updated.metadata_location());
+    }
+
+    #[tokio::test]
+    async fn test_drop_and_rename_table_invalidate_cached_tables() {
+        let inner: Arc<dyn Catalog> = Arc::new(new_memory_catalog().await);
+        let catalog = CachingCatalog::new(inner);
+        let namespace = NamespaceIdent::new("ns".to_string());
+        catalog
+            .create_namespace(&namespace, HashMap::new())
+            .await
+            .unwrap();
+        let table = catalog
+            .create_table(&namespace, table_creation("tbl"))
+            .await
+            .unwrap();
+        let renamed = TableIdent::new(namespace.clone(), "renamed".to_string());
+
+        catalog
+            .rename_table(table.identifier(), &renamed)
+            .await
+            .unwrap();
+        assert!(catalog.load_table(table.identifier()).await.is_err());
+        catalog.load_table(&renamed).await.unwrap();
+
+        catalog.drop_table(&renamed).await.unwrap();
+        assert!(catalog.load_table(&renamed).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_drop_namespace_invalidates_cached_tables() {
+        let inner: Arc<dyn Catalog> = Arc::new(new_memory_catalog().await);
+        let catalog = CachingCatalog::new(inner.clone());
+        let namespace = NamespaceIdent::new("ns".to_string());
+        catalog
+            .create_namespace(&namespace, HashMap::new())
+            .await
+            .unwrap();
+        let table = catalog
+            .create_table(&namespace, table_creation("tbl"))
+            .await
+            .unwrap();
+
+        // The table is dropped through the wrapped catalog, so it's still cached.
+        inner.drop_table(table.identifier()).await.unwrap();
+        catalog.load_table(table.identifier()).await.unwrap();
+
+        catalog.drop_namespace(&namespace).await.unwrap();
+        assert!(catalog.load_table(table.identifier()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_cache_expiration() {
+        let table = make_v2_table();
+        let mut mock = MockCatalog::new();
+        mock.expect_load_table()
+            .times(2)
+            .returning(|_| Box::pin(async { Ok(make_v2_table()) }));
+        let catalog =
+            CachingCatalog::with_expiration(Arc::new(mock), Duration::from_millis(10), 10);
+
+        catalog.load_table(table.identifier()).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        catalog.load_table(table.identifier()).await.unwrap();
+    }
+}
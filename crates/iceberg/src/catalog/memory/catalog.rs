@@ -25,12 +25,15 @@ use futures::lock::{Mutex, MutexGuard};
 use itertools::Itertools;
 
 use super::namespace_state::NamespaceState;
+use crate::encryption::KeyManagementClient;
 use crate::io::{FileIO, FileIOBuilder, MemoryStorageFactory, StorageFactory};
-use crate::spec::{TableMetadata, TableMetadataBuilder};
+use crate::metrics::MetricsReporter;
+use crate::spec::{TableMetadata, TableMetadataBuilder, ViewMetadata, ViewMetadataBuilder};
 use crate::table::Table;
+use crate::view::View;
 use crate::{
     Catalog, CatalogBuilder, Error, ErrorKind, MetadataLocation, Namespace, NamespaceIdent, Result,
-    TableCommit, TableCreation, TableIdent,
+    TableCommit, TableCreation, TableIdent, ViewCommit, ViewCreation,
 };
 
 /// Memory catalog warehouse location
@@ -44,6 +47,8 @@ const LOCATION: &str = "location";
 pub struct MemoryCatalogBuilder {
     config: MemoryCatalogConfig,
     storage_factory: Option<Arc<dyn StorageFactory>>,
+    key_management_client: Option<Arc<dyn KeyManagementClient>>,
+    metrics_reporter: Option<Arc<dyn MetricsReporter>>,
 }
 
 impl Default for MemoryCatalogBuilder {
@@ -55,10 +60,26 @@ impl Default for MemoryCatalogBuilder {
                 props: HashMap::new(),
             },
             storage_factory: None,
+            key_management_client: None,
+            metrics_reporter: None,
         }
     }
 }
 
+impl MemoryCatalogBuilder {
+    /// Set the client of the KMS holding the master keys of encrypted tables.
+    pub fn with_key_management_client(mut self, client: Arc<dyn KeyManagementClient>) -> Self {
+        self.key_management_client = Some(client);
+        self
+    }
+
+    /// Set the [`MetricsReporter`] the scans and commits of the catalog's tables report to.
+    pub fn with_metrics_reporter(mut self, metrics_reporter: Arc<dyn MetricsReporter>) -> Self {
+        self.metrics_reporter = Some(metrics_reporter);
+        self
+    }
+}
+
 impl CatalogBuilder for MemoryCatalogBuilder {
     type C = MemoryCatalog;
 
@@ -99,7 +120,12 @@ impl CatalogBuilder for MemoryCatalogBuilder {
                     "Catalog warehouse is required",
                 ))
             } else {
-                MemoryCatalog::new(self.config, self.storage_factory)
+                MemoryCatalog::new(
+                    self.config,
+                    self.storage_factory,
+                    self.key_management_client,
+                    self.metrics_reporter,
+                )
             }
         };
 
@@ -120,6 +146,7 @@ pub struct MemoryCatalog {
     root_namespace_state: Mutex<NamespaceState>,
     file_io: FileIO,
     warehouse_location: String,
+    metrics_reporter: Option<Arc<dyn MetricsReporter>>,
 }
 
 impl MemoryCatalog {
@@ -127,14 +154,21 @@ impl MemoryCatalog {
     fn new(
         config: MemoryCatalogConfig,
         storage_factory: Option<Arc<dyn StorageFactory>>,
+        key_management_client: Option<Arc<dyn KeyManagementClient>>,
+        metrics_reporter: Option<Arc<dyn MetricsReporter>>,
     ) -> Result<Self> {
         // Use provided factory or default to MemoryStorageFactory
         let factory = storage_factory.unwrap_or_else(|| Arc::new(MemoryStorageFactory));
+        let mut file_io = FileIOBuilder::new(factory).with_props(config.props);
+        if let Some(client) = key_management_client {
+            file_io = file_io.with_key_management_client(client);
+        }
 
         Ok(Self {
             root_namespace_state: Mutex::new(NamespaceState::default()),
-            file_io: FileIOBuilder::new(factory).with_props(config.props).build(),
+            file_io: file_io.build(),
             warehouse_location: config.warehouse,
+            metrics_reporter,
         })
     }
 
@@ -152,6 +186,23 @@ impl MemoryCatalog {
             .metadata(metadata)
             .metadata_location(metadata_location.to_string())
             .file_io(self.file_io.clone())
+            .metrics_reporter(self.metrics_reporter.clone())
+            .build()
+    }
+
+    /// Loads a view from the locked namespace state.
+    async fn load_view_from_locked_state(
+        &self,
+        view_ident: &TableIdent,
+        root_namespace_state: &MutexGuard<'_, NamespaceState>,
+    ) -> Result<View> {
+        let metadata_location = root_namespace_state.get_existing_view_location(view_ident)?;
+        let metadata = ViewMetadata::read_from(&self.file_io, metadata_location).await?;
+
+        View::builder()
+            .identifier(view_ident.clone())
+            .metadata(metadata)
+            .metadata_location(metadata_location.to_string())
             .build()
     }
 }
@@ -306,6 +357,7 @@ impl Catalog for MemoryCatalog {
             .metadata_location(metadata_location)
             .metadata(metadata)
             .identifier(table_ident)
+            .metrics_reporter(self.metrics_reporter.clone())
             .build()
     }
 
@@ -366,6 +418,7 @@ impl Catalog for MemoryCatalog {
             .metadata_location(metadata_location)
             .metadata(metadata)
             .identifier(table_ident.clone())
+            .metrics_reporter(self.metrics_reporter.clone())
             .build()
     }
 
@@ -394,6 +447,153 @@ impl Catalog for MemoryCatalog {
 
         Ok(updated_table)
     }
+
+    async fn commit_transaction(&self, commits: Vec<TableCommit>) -> Result<()> {
+        let mut root_namespace_state = self.root_namespace_state.lock().await;
+
+        // Stage all tables before writing anything, so that a failed requirement
+        // leaves every table unchanged.
+        let mut staged_tables: Vec<Table> = Vec::with_capacity(commits.len());
+        for commit in commits {
+            if staged_tables
+                .iter()
+                .any(|table| table.identifier() == commit.identifier())
+            {
+                return Err(Error::new(
+                    ErrorKind::DataInvalid,
+                    format!(
+                        "Table {} is updated more than once in the transaction",
+                        commit.identifier()
+                    ),
+                ));
+            }
+
+            let current_table = self
+                .load_table_from_locked_state(commit.identifier(), &root_namespace_state)
+                .await?;
+            staged_tables.push(commit.apply(current_table)?);
+        }
+
+        for staged_table in &staged_tables {
+            staged_table
+                .metadata()
+                .write_to(
+                    staged_table.file_io(),
+                    staged_table.metadata_location_result()?,
+                )
+                .await?;
+        }
+
+        // The state is locked, so all pointers are flipped at once.
+        for staged_table in staged_tables {
+            root_namespace_state.commit_table_update(staged_table)?;
+        }
+
+        Ok(())
+    }
+
+    /// List views from namespace.
+    async fn list_views(&self, namespace_ident: &NamespaceIdent) -> Result<Vec<TableIdent>> {
+        let root_namespace_state = self.root_namespace_state.lock().await;
+
+        let view_names = root_namespace_state.list_views(namespace_ident)?;
+        let view_idents = view_names
+            .into_iter()
+            .map(|view_name| TableIdent::new(namespace_ident.clone(), view_name.clone()))
+            .collect_vec();
+
+        Ok(view_idents)
+    }
+
+    /// Create a new view inside the namespace.
+    async fn create_view(
+        &self,
+        namespace_ident: &NamespaceIdent,
+        view_creation: ViewCreation,
+    ) -> Result<View> {
+        let mut root_namespace_state = self.root_namespace_state.lock().await;
+
+        let view_ident = TableIdent::new(namespace_ident.clone(), view_creation.name.clone());
+        let location = view_creation.location.clone();
+
+        let metadata = ViewMetadataBuilder::from_view_creation(view_creation)?
+            .build()?
+            .metadata;
+        let metadata_location = MetadataLocation::new_with_table_location(location).to_string();
+
+        metadata.write_to(&self.file_io, &metadata_location).await?;
+
+        root_namespace_state.insert_new_view(&view_ident, metadata_location.clone())?;
+
+        View::builder()
+            .metadata_location(metadata_location)
+            .metadata(metadata)
+            .identifier(view_ident)
+            .build()
+    }
+
+    /// Load view from the catalog.
+    async fn load_view(&self, view_ident: &TableIdent) -> Result<View> {
+        let root_namespace_state = self.root_namespace_state.lock().await;
+
+        self.load_view_from_locked_state(view_ident, &root_namespace_state)
+            .await
+    }
+
+    /// Drop a view from the catalog.
+    async fn drop_view(&self, view_ident: &TableIdent) -> Result<()> {
+        let mut root_namespace_state = self.root_namespace_state.lock().await;
+
+        let metadata_location = root_namespace_state.remove_existing_view(view_ident)?;
+        self.file_io.delete(&metadata_location).await
+    }
+
+    /// Check if a view exists in the catalog.
+    async fn view_exists(&self, view_ident: &TableIdent) -> Result<bool> {
+        let root_namespace_state = self.root_namespace_state.lock().await;
+
+        root_namespace_state.view_exists(view_ident)
+    }
+
+    /// Rename a view in the catalog.
+    async fn rename_view(
+        &self,
+        src_view_ident: &TableIdent,
+        dst_view_ident: &TableIdent,
+    ) -> Result<()> {
+        let mut root_namespace_state = self.root_namespace_state.lock().await;
+
+        let mut new_root_namespace_state = root_namespace_state.clone();
+        let metadata_location = new_root_namespace_state
+            .get_existing_view_location(src_view_ident)?
+            .clone();
+        new_root_namespace_state.remove_existing_view(src_view_ident)?;
+        new_root_namespace_state.insert_new_view(dst_view_ident, metadata_location)?;
+        *root_namespace_state = new_root_namespace_state;
+
+        Ok(())
+    }
+
+    /// Update a view in the catalog.
+    async fn update_view(&self, commit: ViewCommit) -> Result<View> {
+        let mut root_namespace_state = self.root_namespace_state.lock().await;
+
+        let current_view = self
+            .load_view_from_locked_state(commit.identifier(), &root_namespace_state)
+            .await?;
+
+        // Apply ViewCommit to get staged view
+        let staged_view = commit.apply(current_view)?;
+
+        // Write view metadata to the new location
+        staged_view
+            .metadata()
+            .write_to(&self.file_io, staged_view.metadata_location_result()?)
+            .await?;
+
+        // Flip the pointer to reference the new metadata file.
+        root_namespace_state.commit_view_update(staged_view)
+    }
 }
 
 #[cfg(test)]
@@ -405,13 +605,18 @@ pub(crate) mod tests {
 
     use regex::Regex;
     use tempfile::TempDir;
+    use uuid::Uuid;
 
     use super::*;
     use crate::io::FileIO;
-    use crate::spec::{NestedField, PartitionSpec, PrimitiveType, Schema, SortOrder, Type};
+    use crate::spec::{
+        NestedField, PartitionSpec, PrimitiveType, Schema, SortOrder, SqlViewRepresentation, Type,
+        ViewRepresentations, ViewVersion,
+    };
     use crate::transaction::{ApplyTransactionAction, Transaction};
+    use crate::{TableRequirement, TableUpdate, ViewRequirement, ViewUpdate};
 
-    fn temp_path() -> String {
+    pub(crate) fn temp_path() -> String {
         let temp_dir = TempDir::new().unwrap();
         temp_dir.path().to_str().unwrap().to_string()
     }
@@ -1908,6 +2113,252 @@ pub(crate) mod tests {
         assert_eq!(err.kind(), ErrorKind::TableNotFound);
     }
 
+    #[tokio::test]
+    async fn test_commit_transaction_is_atomic() {
+        let catalog = new_memory_catalog().await;
+
+        let namespace_ident = NamespaceIdent::new("a".into());
+        create_namespace(&catalog, &namespace_ident).await;
+        let table1 = create_table(
+            &catalog,
+            &TableIdent::new(namespace_ident.clone(), "t1".into()),
+        )
+        .await;
+        let table2 = create_table(&catalog, &TableIdent::new(namespace_ident, "t2".into())).await;
+
+        let set_key = |table: &Table, uuid: Uuid| {
+            TableCommit::builder()
+                .ident(table.identifier().clone())
+                .requirements(vec![TableRequirement::UuidMatch { uuid }])
+                .updates(vec![TableUpdate::SetProperties {
+                    updates: HashMap::from([("key".to_string(), "value".to_string())]),
+                }])
+                .build()
+        };
+
+        // The second commit fails its requirement, so neither table is updated.
+        let err = catalog
+            .commit_transaction(vec![
+                set_key(&table1, table1.metadata().uuid()),
+                set_key(&table2, Uuid::new_v4()),
+            ])
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::CatalogCommitConflicts);
+        for table in [&table1, &table2] {
+            let loaded_table = catalog.load_table(table.identifier()).await.unwrap();
+            assert_eq!(loaded_table.metadata_location(), table.metadata_location());
+        }
+
+        catalog
+            .commit_transaction(vec![
+                set_key(&table1, table1.metadata().uuid()),
+                set_key(&table2, table2.metadata().uuid()),
+            ])
+            .await
+            .unwrap();
+        for table in [&table1, &table2] {
+            let loaded_table = catalog.load_table(table.identifier()).await.unwrap();
+            assert_eq!(
+                loaded_table.metadata().properties().get("key").unwrap(),
+                "value"
+            );
+        }
+    }
+
+    fn simple_view_creation(view_ident: &TableIdent) -> ViewCreation {
+        ViewCreation::builder()
+            .name(view_ident.name().to_string())
+            .location(temp_path())
+            .schema(simple_table_schema())
+            .representations(ViewRepresentations::from_iter([SqlViewRepresentation {
+                sql: "SELECT foo FROM tbl".to_string(),
+                dialect: "spark".to_string(),
+            }
+            .into()]))
+            .default_namespace(view_ident.namespace().clone())
+            .build()
+    }
+
+    async fn create_view<C: Catalog>(catalog: &C, view_ident: &TableIdent) -> View {
+        catalog
+            .create_view(view_ident.namespace(), simple_view_creation(view_ident))
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_create_and_load_view() {
+        let catalog = new_memory_catalog().await;
+        let namespace_ident = NamespaceIdent::new("a".into());
+        create_namespace(&catalog, &namespace_ident).await;
+        let view_ident = TableIdent::new(namespace_ident.clone(), "v".into());
+
+        let view = create_view(&catalog, &view_ident).await;
+        assert_eq!(view.identifier(), &view_ident);
+        assert_eq!(view.metadata().current_version_id(), 1);
+
+        let loaded_view = catalog.load_view(&view_ident).await.unwrap();
+        assert_eq!(loaded_view.metadata(), view.metadata());
+        assert_eq!(loaded_view.metadata_location(), view.metadata_location());
+
+        assert!(catalog.view_exists(&view_ident).await.unwrap());
+        assert!(!catalog.table_exists(&view_ident).await.unwrap());
+        assert_eq!(catalog.list_views(&namespace_ident).await.unwrap(), vec![
+            view_ident.clone()
+        ]);
+        assert!(
+            catalog
+                .list_tables(&namespace_ident)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        let err = catalog
+            .create_view(&namespace_ident, simple_view_creation(&view_ident))
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ViewAlreadyExists);
+    }
+
+    #[tokio::test]
+    async fn test_view_and_table_share_names() {
+        let catalog = new_memory_catalog().await;
+        let namespace_ident = NamespaceIdent::new("a".into());
+        create_namespace(&catalog, &namespace_ident).await;
+        let table_ident = TableIdent::new(namespace_ident.clone(), "t".into());
+        let view_ident = TableIdent::new(namespace_ident.clone(), "v".into());
+        create_table(&catalog, &table_ident).await;
+        create_view(&catalog, &view_ident).await;
+
+        let err = catalog
+            .create_view(&namespace_ident, simple_view_creation(&table_ident))
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::TableAlreadyExists);
+
+        let err = catalog
+            .rename_table(&table_ident, &view_ident)
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ViewAlreadyExists);
+    }
+
+    #[tokio::test]
+    async fn test_load_view_throws_error_if_view_doesnt_exist() {
+        let catalog = new_memory_catalog().await;
+        let namespace_ident = NamespaceIdent::new("a".into());
+        create_namespace(&catalog, &namespace_ident).await;
+        let view_ident = TableIdent::new(namespace_ident, "v".into());
+
+        let err = catalog.load_view(&view_ident).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ViewNotFound);
+        assert!(!catalog.view_exists(&view_ident).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_drop_view() {
+        let catalog = new_memory_catalog().await;
+        let namespace_ident = NamespaceIdent::new("a".into());
+        create_namespace(&catalog, &namespace_ident).await;
+        let view_ident = TableIdent::new(namespace_ident, "v".into());
+        create_view(&catalog, &view_ident).await;
+
+        catalog.drop_view(&view_ident).await.unwrap();
+
+        assert!(!catalog.view_exists(&view_ident).await.unwrap());
+        let err = catalog.drop_view(&view_ident).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ViewNotFound);
+    }
+
+    #[tokio::test]
+    async fn test_rename_view() {
+        let catalog = new_memory_catalog().await;
+        let src_namespace_ident = NamespaceIdent::new("a".into());
+        let dst_namespace_ident = NamespaceIdent::new("b".into());
+        create_namespaces(&catalog, &vec![&src_namespace_ident, &dst_namespace_ident]).await;
+        let src_view_ident = TableIdent::new(src_namespace_ident.clone(), "v1".into());
+        let dst_view_ident = TableIdent::new(dst_namespace_ident.clone(), "v2".into());
+        let view = create_view(&catalog, &src_view_ident).await;
+
+        catalog
+            .rename_view(&src_view_ident, &dst_view_ident)
+            .await
+            .unwrap();
+
+        assert!(!catalog.view_exists(&src_view_ident).await.unwrap());
+        let renamed_view = catalog.load_view(&dst_view_ident).await.unwrap();
+        assert_eq!(renamed_view.metadata(), view.metadata());
+        assert_eq!(
+            catalog.list_views(&dst_namespace_ident).await.unwrap(),
+            vec![dst_view_ident]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_view() {
+        let catalog = new_memory_catalog().await;
+        let namespace_ident = NamespaceIdent::new("a".into());
+        create_namespace(&catalog, &namespace_ident).await;
+        let view_ident = TableIdent::new(namespace_ident.clone(), "v".into());
+        let view = create_view(&catalog, &view_ident).await;
+
+        let new_version = ViewVersion::builder()
+            .with_version_id(2)
+            .with_schema_id(0)
+            .with_timestamp_ms(view.metadata().current_version().timestamp_ms() + 1)
+            .with_representations(ViewRepresentations::from_iter([SqlViewRepresentation {
+                sql: "SELECT foo + 1 AS foo FROM tbl".to_string(),
+                dialect: "spark".to_string(),
+            }
+            .into()]))
+            .with_default_namespace(namespace_ident)
+            .build();
+        let commit = ViewCommit::builder()
+            .ident(view_ident.clone())
+            .requirements(vec![ViewRequirement::UuidMatch {
+                uuid: view.metadata().uuid(),
+            }])
+            .updates(vec![
+                ViewUpdate::AddViewVersion {
+                    view_version: new_version,
+                },
+                ViewUpdate::SetCurrentViewVersion {
+                    view_version_id: -1,
+                },
+                ViewUpdate::SetProperties {
+                    updates: HashMap::from([("comment".to_string(), "replaced".to_string())]),
+                },
+            ])
+            .build();
+
+        let updated_view = catalog.update_view(commit).await.unwrap();
+
+        assert_eq!(updated_view.metadata().current_version_id(), 2);
+        assert_eq!(
+            updated_view.metadata().properties().get("comment").unwrap(),
+            "replaced"
+        );
+        assert_ne!(updated_view.metadata_location(), view.metadata_location());
+        let loaded_view = catalog.load_view(&view_ident).await.unwrap();
+        assert_eq!(loaded_view.metadata(), updated_view.metadata());
+        assert_eq!(
+            loaded_view.metadata_location(),
+            updated_view.metadata_location()
+        );
+
+        let stale_commit = ViewCommit::builder()
+            .ident(view_ident)
+            .requirements(vec![ViewRequirement::UuidMatch {
+                uuid: Uuid::new_v4(),
+            }])
+            .updates(vec![])
+            .build();
+        let err = catalog.update_view(stale_commit).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::CatalogCommitConflicts);
+    }
+
     fn build_table(ident: TableIdent) -> Table {
         let file_io = FileIO::new_with_fs();
 
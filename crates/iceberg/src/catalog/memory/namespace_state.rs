@@ -20,6 +20,7 @@ use std::collections::{HashMap, hash_map};
 use itertools::Itertools;
 
 use crate::table::Table;
+use crate::view::View;
 use crate::{Error, ErrorKind, NamespaceIdent, Result, TableIdent};
 
 // Represents the state of a namespace
@@ -31,6 +32,8 @@ pub(crate) struct NamespaceState {
     namespaces: HashMap<String, NamespaceState>,
     // Mapping of tables to metadata locations in this namespace
     table_metadata_locations: HashMap<String, String>,
+    // Mapping of views to metadata locations in this namespace
+    view_metadata_locations: HashMap<String, String>,
 }
 
 fn no_such_namespace_err<T>(namespace_ident: &NamespaceIdent) -> Result<T> {
@@ -47,6 +50,13 @@ fn no_such_table_err<T>(table_ident: &TableIdent) -> Result<T> {
     ))
 }
 
+fn no_such_view_err<T>(view_ident: &TableIdent) -> Result<T> {
+    Err(Error::new(
+        ErrorKind::ViewNotFound,
+        format!("No such view: {view_ident:?}"),
+    ))
+}
+
 fn namespace_already_exists_err<T>(namespace_ident: &NamespaceIdent) -> Result<T> {
     Err(Error::new(
         ErrorKind::NamespaceAlreadyExists,
@@ -61,6 +71,13 @@ fn table_already_exists_err<T>(table_ident: &TableIdent) -> Result<T> {
     ))
 }
 
+fn view_already_exists_err<T>(view_ident: &TableIdent) -> Result<T> {
+    Err(Error::new(
+        ErrorKind::ViewAlreadyExists,
+        format!("Cannot create view {view_ident:?}. View already exists."),
+    ))
+}
+
 impl NamespaceState {
     // Returns the state of the given namespace or an error if doesn't exist
     fn get_namespace(&self, namespace_ident: &NamespaceIdent) -> Result<&NamespaceState> {
@@ -171,6 +188,7 @@ impl NamespaceState {
                     properties,
                     namespaces: HashMap::new(),
                     table_metadata_locations: HashMap::new(),
+                    view_metadata_locations: HashMap::new(),
                 });
 
                 Ok(())
@@ -266,6 +284,13 @@ impl NamespaceState {
     ) -> Result<()> {
         let namespace = self.get_mut_namespace(table_ident.namespace())?;
 
+        if namespace
+            .view_metadata_locations
+            .contains_key(table_ident.name())
+        {
+            return view_already_exists_err(table_ident);
+        }
+
         match namespace
             .table_metadata_locations
             .entry(table_ident.name().to_string())
@@ -309,4 +334,91 @@ impl NamespaceState {
 
         Ok(staged_table)
     }
+
+    // Returns the list of view names under the given namespace
+    pub(crate) fn list_views(&self, namespace_ident: &NamespaceIdent) -> Result<Vec<&String>> {
+        let view_names = self
+            .get_namespace(namespace_ident)?
+            .view_metadata_locations
+            .keys()
+            .collect_vec();
+
+        Ok(view_names)
+    }
+
+    // Returns true if the given view exists, otherwise false
+    pub(crate) fn view_exists(&self, view_ident: &TableIdent) -> Result<bool> {
+        let namespace_state = self.get_namespace(view_ident.namespace())?;
+        let view_exists = namespace_state
+            .view_metadata_locations
+            .contains_key(&view_ident.name);
+
+        Ok(view_exists)
+    }
+
+    // Returns the metadata location of the given view or an error if doesn't exist
+    pub(crate) fn get_existing_view_location(&self, view_ident: &TableIdent) -> Result<&String> {
+        let namespace = self.get_namespace(view_ident.namespace())?;
+
+        match namespace.view_metadata_locations.get(view_ident.name()) {
+            None => no_such_view_err(view_ident),
+            Some(view_metadata_location) => Ok(view_metadata_location),
+        }
+    }
+
+    // Inserts the given view or returns an error if it or a table with the same name already exists
+    pub(crate) fn insert_new_view(
+        &mut self,
+        view_ident: &TableIdent,
+        metadata_location: String,
+    ) -> Result<()> {
+        let namespace = self.get_mut_namespace(view_ident.namespace())?;
+
+        if namespace
+            .table_metadata_locations
+            .contains_key(view_ident.name())
+        {
+            return table_already_exists_err(view_ident);
+        }
+
+        match namespace
+            .view_metadata_locations
+            .entry(view_ident.name().to_string())
+        {
+            hash_map::Entry::Occupied(_) => view_already_exists_err(view_ident),
+            hash_map::Entry::Vacant(entry) => {
+                let _ = entry.insert(metadata_location);
+
+                Ok(())
+            }
+        }
+    }
+
+    // Removes the given view or returns an error if doesn't exist
+    pub(crate) fn remove_existing_view(&mut self, view_ident: &TableIdent) -> Result<String> {
+        let namespace = self.get_mut_namespace(view_ident.namespace())?;
+
+        match namespace.view_metadata_locations.remove(view_ident.name()) {
+            None => no_such_view_err(view_ident),
+            Some(metadata_location) => Ok(metadata_location),
+        }
+    }
+
+    /// Updates the metadata location of the given view or returns an error if it doesn't exist
+    pub(crate) fn commit_view_update(&mut self, staged_view: View) -> Result<View> {
+        let namespace = self.get_mut_namespace(staged_view.identifier().namespace())?;
+
+        let _ = namespace
+            .view_metadata_locations
+            .insert(
+                staged_view.identifier().name().to_string(),
+                staged_view.metadata_location_result()?.to_string(),
+            )
+            .ok_or(Error::new(
+                ErrorKind::ViewNotFound,
+                format!("No such view: {:?}", staged_view.identifier()),
+            ))?;
+
+        Ok(staged_view)
+    }
 }
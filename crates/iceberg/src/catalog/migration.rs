@@ -0,0 +1,609 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! This module contains a utility migrating the tables of a catalog to another catalog.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::spec::TableMetadata;
+use crate::table::Table;
+use crate::{Catalog, Error, ErrorKind, MetadataLocation, NamespaceIdent, Result, TableIdent};
+
+/// Migrates the namespaces and tables of a source catalog to a target catalog.
+///
+/// Every namespace of the source catalog is created in the target catalog with its
+/// properties, and every table is registered in the target catalog with
+/// [`Catalog::register_table`], pointing at the current metadata file of the source table.
+/// Data and metadata files are neither copied nor moved, so both catalogs must be able to
+/// read them.
+///
+/// The migration stops at the first error. As registering tables is not transactional, the
+/// namespaces and tables migrated before the error are left in the target catalog, and
+/// running the migration again with [`CatalogMigration::with_skip_existing`] resumes it.
+pub struct CatalogMigration<'a> {
+    source: &'a dyn Catalog,
+    target: &'a dyn Catalog,
+    dry_run: bool,
+    skip_existing: bool,
+    namespace_mapping: HashMap<NamespaceIdent, NamespaceIdent>,
+    table_mapping: HashMap<TableIdent, TableIdent>,
+    table_location_prefix: Option<(String, String)>,
+}
+
+/// The target namespaces a migration has created or found.
+#[derive(Default)]
+struct KnownNamespaces {
+    known: HashSet<NamespaceIdent>,
+    /// The namespaces created before their source namespace was migrated.
+    without_properties: HashSet<NamespaceIdent>,
+}
+
+impl<'a> CatalogMigration<'a> {
+    /// Creates a migration of all namespaces and tables of `source` to `target`.
+    pub fn new(source: &'a dyn Catalog, target: &'a dyn Catalog) -> Self {
+        Self {
+            source,
+            target,
+            dry_run: false,
+            skip_existing: false,
+            namespace_mapping: HashMap::new(),
+            table_mapping: HashMap::new(),
+            table_location_prefix: None,
+        }
+    }
+
+    /// Only reports what the migration would do, without changing the target catalog or
+    /// writing metadata files.
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Skips the tables which already exist in the target catalog, instead of failing.
+    pub fn with_skip_existing(mut self, skip_existing: bool) -> Self {
+        self.skip_existing = skip_existing;
+        self
+    }
+
+    /// Migrates the source namespace `source` to the target namespace `target`.
+    ///
+    /// The tables of `source` are registered in `target`. The mapping only applies to
+    /// `source` itself, not to its child namespaces.
+    pub fn with_namespace_mapping(
+        mut self,
+        source: NamespaceIdent,
+        target: NamespaceIdent,
+    ) -> Self {
+        self.namespace_mapping.insert(source, target);
+        self
+    }
+
+    /// Registers the source table `source` as `target`, taking precedence over the namespace
+    /// mappings.
+    pub fn with_table_mapping(mut self, source: TableIdent, target: TableIdent) -> Self {
+        self.table_mapping.insert(source, target);
+        self
+    }
+
+    /// Rewrites the table location of the migrated tables from `source_prefix` to
+    /// `target_prefix`.
+    ///
+    /// The location of each table must start with `source_prefix`. A copy of its current
+    /// metadata, with the location rewritten to start with `target_prefix`, is written to the
+    /// metadata directory of the new location, and the table is registered with that copy, so
+    /// new files of the target table are written under `target_prefix`.
+    ///
+    /// This doesn't relocate the table: the paths of snapshots, manifest lists, manifests and
+    /// data files are kept, so the existing files must stay readable at their original
+    /// location. To move the files of a table, use [`Table::rewrite_table_path`] and register
+    /// the table with the rewritten metadata once the files are copied.
+    pub fn with_table_location_prefix(
+        mut self,
+        source_prefix: impl ToString,
+        target_prefix: impl ToString,
+    ) -> Self {
+        self.table_location_prefix = Some((source_prefix.to_string(), target_prefix.to_string()));
+        self
+    }
+
+    /// Runs the migration.
+    ///
+    /// For a dry run, the returned report lists what the migration would do.
+    pub async fn execute(&self) -> Result<CatalogMigrationReport> {
+        let mut report = CatalogMigrationReport::default();
+        let mut known_namespaces = KnownNamespaces::default();
+
+        let mut pending = self.source.list_namespaces(None).await?;
+        pending.reverse();
+        while let Some(namespace) = pending.pop() {
+            let mut children = self.source.list_namespaces(Some(&namespace)).await?;
+            children.reverse();
+            pending.extend(children);
+
+            let target_namespace = self
+                .namespace_mapping
+                .get(&namespace)
+                .unwrap_or(&namespace)
+                .clone();
+            let properties = self
+                .source
+                .get_namespace(&namespace)
+                .await?
+                .properties()
+                .clone();
+            self.ensure_namespace(
+                &target_namespace,
+                Some(properties),
+                &mut known_namespaces,
+                &mut report,
+            )
+            .await?;
+
+            for table_ident in self.source.list_tables(&namespace).await? {
+                let target_ident = match self.table_mapping.get(&table_ident) {
+                    Some(target_ident) => {
+                        self.ensure_namespace(
+                            &target_ident.namespace,
+                            None,
+                            &mut known_namespaces,
+                            &mut report,
+                        )
+                        .await?;
+                        target_ident.clone()
+                    }
+                    None => TableIdent::new(target_namespace.clone(), table_ident.name.clone()),
+                };
+                self.migrate_table(table_ident, target_ident, &mut report)
+                    .await?;
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Creates `namespace` and its missing parents in the target catalog.
+    ///
+    /// `properties` are the properties of the source namespace migrated to `namespace`, or
+    /// `None` if the namespace is only needed as a parent or for a table mapping. A namespace
+    /// created without them gets them once its source namespace is migrated.
+    async fn ensure_namespace(
+        &self,
+        namespace: &NamespaceIdent,
+        properties: Option<HashMap<String, String>>,
+        known_namespaces: &mut KnownNamespaces,
+        report: &mut CatalogMigrationReport,
+    ) -> Result<()> {
+        if known_namespaces.known.contains(namespace) {
+            if let Some(properties) = properties
+                && known_namespaces.without_properties.remove(namespace)
+                && !properties.is_empty()
+                && !self.dry_run
+            {
+                self.target.update_namespace(namespace, properties).await?;
+            }
+            return Ok(());
+        }
+
+        if let Some(parent) = namespace.parent() {
+            Box::pin(self.ensure_namespace(&parent, None, known_namespaces, report)).await?;
+        }
+
+        if !self.target.namespace_exists(namespace).await? {
+            if properties.is_none() {
+                known_namespaces
+                    .without_properties
+                    .insert(namespace.clone());
+            }
+            if !self.dry_run {
+                self.target
+                    .create_namespace(namespace, properties.unwrap_or_default())
+                    .await?;
+            }
+            report.created_namespaces.push(namespace.clone());
+        }
+        known_namespaces.known.insert(namespace.clone());
+
+        Ok(())
+    }
+
+    async fn migrate_table(
+        &self,
+        source: TableIdent,
+        target: TableIdent,
+        report: &mut CatalogMigrationReport,
+    ) -> Result<()> {
+        if self.skip_existing && self.target.table_exists(&target).await? {
+            report.skipped_tables.push(target);
+            return Ok(());
+        }
+
+        let table = self.source.load_table(&source).await?;
+        let metadata_location = match &self.table_location_prefix {
+            Some((source_prefix, target_prefix)) => {
+                self.rewrite_table_location(&table, source_prefix, target_prefix)
+                    .await?
+            }
+            None => table
+                .metadata_location_result()
+                .map_err(|e| e.with_context("table", source.to_string()))?
+                .to_string(),
+        };
+
+        if !self.dry_run {
+            self.target
+                .register_table(&target, metadata_location.clone())
+                .await?;
+        }
+        report.registered_tables.push(MigratedTable {
+            source,
+            target,
+            metadata_location,
+        });
+
+        Ok(())
+    }
+
+    /// Writes a copy of the current metadata of `table` with a rewritten table location,
+    /// returning the location of the copy.
+    async fn rewrite_table_location(
+        &self,
+        table: &Table,
+        source_prefix: &str,
+        target_prefix: &str,
+    ) -> Result<String> {
+        let location = table.metadata().location();
+        let Some(suffix) = location.strip_prefix(source_prefix) else {
+            return Err(Error::new(
+                ErrorKind::DataInvalid,
+                format!("Table location {location} does not start with {source_prefix}"),
+            )
+            .with_context("table", table.identifier().to_string()));
+        };
+        let new_location = format!("{target_prefix}{suffix}");
+        let metadata_location =
+            MetadataLocation::new_with_table_location(&new_location).to_string();
+
+        if !self.dry_run {
+            let metadata: TableMetadata = table
+                .metadata()
+                .clone()
+                .into_builder(table.metadata_location().map(ToString::to_string))
+                .set_location(new_location)
+                .build()?
+                .metadata;
+            metadata
+                .write_to(table.file_io(), &metadata_location)
+                .await?;
+        }
+
+        Ok(metadata_location)
+    }
+}
+
+/// The outcome of a [`CatalogMigration`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CatalogMigrationReport {
+    /// The namespaces created in the target catalog.
+    pub created_namespaces: Vec<NamespaceIdent>,
+    /// The tables registered in the target catalog.
+    pub registered_tables: Vec<MigratedTable>,
+    /// The tables skipped because they already exist in the target catalog.
+    pub skipped_tables: Vec<TableIdent>,
+}
+
+/// A table registered in the target catalog of a [`CatalogMigration`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigratedTable {
+    /// The identifier of the table in the source catalog.
+    pub source: TableIdent,
+    /// The identifier of the table in the target catalog.
+    pub target: TableIdent,
+    /// The metadata location the table is registered with.
+    pub metadata_location: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+    use std::sync::Arc;
+
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::io::LocalFsStorageFactory;
+    use crate::memory::{MEMORY_CATALOG_WAREHOUSE, MemoryCatalogBuilder};
+    use crate::spec::{NestedField, PrimitiveType, Schema, Type};
+    use crate::{CatalogBuilder, MemoryCatalog, TableCreation};
+
+    async fn new_catalog(warehouse: &TempDir) -> MemoryCatalog {
+        MemoryCatalogBuilder::default()
+            .with_storage_factory(Arc::new(LocalFsStorageFactory))
+            .load(
+                "memory",
+                HashMap::from([(
+                    MEMORY_CATALOG_WAREHOUSE.to_string(),
+                    warehouse.path().to_str().unwrap().to_string(),
+                )]),
+            )
+            .await
+            .unwrap()
+    }
+
+    async fn create_table(catalog: &impl Catalog, namespace: &NamespaceIdent, name: &str) -> Table {
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+            ])
+            .build()
+            .unwrap();
+        catalog
+            .create_table(
+                namespace,
+                TableCreation::builder()
+                    .name(name.to_string())
+                    .schema(schema)
+                    .build(),
+            )
+            .await
+            .unwrap()
+    }
+
+    /// Creates a source catalog with the tables `db.t1`, `db.t2` and `db.nested.t3`.
+    async fn new_source_catalog(warehouse: &TempDir) -> MemoryCatalog {
+        let catalog = new_catalog(warehouse).await;
+        let db = NamespaceIdent::new("db".to_string());
+        let nested = NamespaceIdent::from_strs(["db", "nested"]).unwrap();
+        catalog
+            .create_namespace(
+                &db,
+                HashMap::from([("owner".to_string(), "alice".to_string())]),
+            )
+            .await
+            .unwrap();
+        catalog
+            .create_namespace(&nested, HashMap::new())
+            .await
+            .unwrap();
+        create_table(&catalog, &db, "t1").await;
+        create_table(&catalog, &db, "t2").await;
+        create_table(&catalog, &nested, "t3").await;
+        catalog
+    }
+
+    #[tokio::test]
+    async fn test_migrate() {
+        let source_warehouse = TempDir::new().unwrap();
+        let target_warehouse = TempDir::new().unwrap();
+        let source = new_source_catalog(&source_warehouse).await;
+        let target = new_catalog(&target_warehouse).await;
+
+        let report = CatalogMigration::new(&source, &target)
+            .execute()
+            .await
+            .unwrap();
+
+        let db = NamespaceIdent::new("db".to_string());
+        let nested = NamespaceIdent::from_strs(["db", "nested"]).unwrap();
+        assert_eq!(report.created_namespaces, vec![db.clone(), nested.clone()]);
+        assert_eq!(report.registered_tables.len(), 3);
+        assert!(report.skipped_tables.is_empty());
+        assert_eq!(
+            target.get_namespace(&db).await.unwrap().properties(),
+            &HashMap::from([("owner".to_string(), "alice".to_string())])
+        );
+
+        for migrated in &report.registered_tables {
+            assert_eq!(migrated.source, migrated.target);
+            let source_table = source.load_table(&migrated.source).await.unwrap();
+            let target_table = target.load_table(&migrated.target).await.unwrap();
+            assert_eq!(target_table.metadata(), source_table.metadata());
+            assert_eq!(
+                target_table.metadata_location(),
+                source_table.metadata_location()
+            );
+        }
+
+        // Migrating again fails unless existing tables are skipped.
+        assert!(
+            CatalogMigration::new(&source, &target)
+                .execute()
+                .await
+                .is_err()
+        );
+        let report = CatalogMigration::new(&source, &target)
+            .with_skip_existing(true)
+            .execute()
+            .await
+            .unwrap();
+        assert!(report.created_namespaces.is_empty());
+        assert!(report.registered_tables.is_empty());
+        assert_eq!(
+            report.skipped_tables.into_iter().collect::<HashSet<_>>(),
+            HashSet::from([
+                TableIdent::from_strs(["db", "t1"]).unwrap(),
+                TableIdent::from_strs(["db", "t2"]).unwrap(),
+                TableIdent::from_strs(["db", "nested", "t3"]).unwrap(),
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_migrate_dry_run() {
+        let source_warehouse = TempDir::new().unwrap();
+        let target_warehouse = TempDir::new().unwrap();
+        let source = new_source_catalog(&source_warehouse).await;
+        let target = new_catalog(&target_warehouse).await;
+
+        let report = CatalogMigration::new(&source, &target)
+            .with_dry_run(true)
+            .with_table_location_prefix(source_warehouse.path().to_str().unwrap(), "/elsewhere")
+            .execute()
+            .await
+            .unwrap();
+
+        assert_eq!(report.created_namespaces.len(), 2);
+        assert_eq!(report.registered_tables.len(), 3);
+        assert!(
+            report
+                .registered_tables
+                .iter()
+                .all(|table| table.metadata_location.starts_with("/elsewhere/"))
+        );
+        assert!(target.list_namespaces(None).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_migrate_with_mappings() {
+        let source_warehouse = TempDir::new().unwrap();
+        let target_warehouse = TempDir::new().unwrap();
+        let source = new_source_catalog(&source_warehouse).await;
+        let target = new_catalog(&target_warehouse).await;
+
+        let db = NamespaceIdent::new("db".to_string());
+        let prod_db = NamespaceIdent::from_strs(["prod", "db"]).unwrap();
+        let renamed = TableIdent::from_strs(["archive", "old_t2"]).unwrap();
+        let report = CatalogMigration::new(&source, &target)
+            .with_namespace_mapping(db.clone(), prod_db.clone())
+            .with_table_mapping(
+                TableIdent::from_strs(["db", "t2"]).unwrap(),
+                renamed.clone(),
+            )
+            .execute()
+            .await
+            .unwrap();
+
+        assert_eq!(report.created_namespaces, vec![
+            NamespaceIdent::new("prod".to_string()),
+            prod_db.clone(),
+            NamespaceIdent::new("archive".to_string()),
+            db.clone(),
+            NamespaceIdent::from_strs(["db", "nested"]).unwrap(),
+        ]);
+        assert_eq!(
+            target
+                .get_namespace(&prod_db)
+                .await
+                .unwrap()
+                .properties()
+                .get("owner"),
+            Some(&"alice".to_string())
+        );
+        assert_eq!(target.list_tables(&prod_db).await.unwrap(), vec![
+            TableIdent::new(prod_db.clone(), "t1".to_string())
+        ]);
+        assert!(target.table_exists(&renamed).await.unwrap());
+        assert!(
+            target
+                .table_exists(&TableIdent::from_strs(["db", "nested", "t3"]).unwrap())
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_migrate_namespace_created_for_mapping_gets_source_properties() {
+        let source_warehouse = TempDir::new().unwrap();
+        let target_warehouse = TempDir::new().unwrap();
+        let source = new_source_catalog(&source_warehouse).await;
+        let target = new_catalog(&target_warehouse).await;
+
+        // Both `prod` and `zoo` are created for the mappings of `db`, before they're migrated.
+        let prod = NamespaceIdent::new("prod".to_string());
+        let zoo = NamespaceIdent::new("zoo".to_string());
+        for namespace in [&prod, &zoo] {
+            source
+                .create_namespace(
+                    namespace,
+                    HashMap::from([("owner".to_string(), namespace.to_url_string())]),
+                )
+                .await
+                .unwrap();
+        }
+        CatalogMigration::new(&source, &target)
+            .with_namespace_mapping(
+                NamespaceIdent::new("db".to_string()),
+                NamespaceIdent::from_strs(["prod", "db"]).unwrap(),
+            )
+            .with_table_mapping(
+                TableIdent::from_strs(["db", "t2"]).unwrap(),
+                TableIdent::from_strs(["zoo", "t2"]).unwrap(),
+            )
+            .execute()
+            .await
+            .unwrap();
+
+        for namespace in [&prod, &zoo] {
+            assert_eq!(
+                target
+                    .get_namespace(namespace)
+                    .await
+                    .unwrap()
+                    .properties()
+                    .get("owner"),
+                Some(&namespace.to_url_string())
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_migrate_with_table_location_prefix() {
+        let source_warehouse = TempDir::new().unwrap();
+        let target_warehouse = TempDir::new().unwrap();
+        let new_location = TempDir::new().unwrap();
+        let source = new_source_catalog(&source_warehouse).await;
+        let target = new_catalog(&target_warehouse).await;
+
+        let source_prefix = source_warehouse.path().to_str().unwrap();
+        let target_prefix = new_location.path().to_str().unwrap();
+        CatalogMigration::new(&source, &target)
+            .with_table_location_prefix(source_prefix, target_prefix)
+            .execute()
+            .await
+            .unwrap();
+
+        let ident = TableIdent::from_strs(["db", "t1"]).unwrap();
+        let source_table = source.load_table(&ident).await.unwrap();
+        let target_table = target.load_table(&ident).await.unwrap();
+        assert_eq!(
+            target_table.metadata().location(),
+            source_table
+                .metadata()
+                .location()
+                .replacen(source_prefix, target_prefix, 1)
+        );
+        assert!(
+            target_table
+                .metadata_location()
+                .unwrap()
+                .starts_with(target_prefix)
+        );
+        assert_eq!(
+            target_table.metadata().uuid(),
+            source_table.metadata().uuid()
+        );
+
+        // Tables outside of the source prefix can't be migrated with a new location.
+        let other_target_warehouse = TempDir::new().unwrap();
+        let other_target = new_catalog(&other_target_warehouse).await;
+        let err = CatalogMigration::new(&source, &other_target)
+            .with_table_location_prefix("s3://bucket", target_prefix)
+            .execute()
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::DataInvalid);
+    }
+}
@@ -17,8 +17,11 @@
 
 //! Catalog API for Apache Iceberg
 
+mod caching;
 pub mod memory;
 mod metadata_location;
+mod migration;
+mod purge;
 
 use std::collections::HashMap;
 use std::fmt::{Debug, Display};
@@ -30,8 +33,10 @@ use std::sync::Arc;
 
 use _serde::{deserialize_snapshot, serialize_snapshot};
 use async_trait::async_trait;
+pub use caching::*;
 pub use memory::MemoryCatalog;
 pub use metadata_location::*;
+pub use migration::*;
 #[cfg(test)]
 use mockall::automock;
 use serde_derive::{Deserialize, Serialize};
@@ -42,9 +47,11 @@ use crate::io::StorageFactory;
 use crate::spec::{
     EncryptedKey, FormatVersion, PartitionStatisticsFile, Schema, SchemaId, Snapshot,
     SnapshotReference, SortOrder, StatisticsFile, TableMetadata, TableMetadataBuilder,
-    UnboundPartitionSpec, ViewFormatVersion, ViewRepresentations, ViewVersion,
+    UnboundPartitionSpec, ViewFormatVersion, ViewMetadata, ViewMetadataBuilder,
+    ViewRepresentations, ViewVersion,
 };
 use crate::table::Table;
+use crate::view::View;
 use crate::{Error, ErrorKind, Result};
 
 /// The catalog API for Iceberg Rust.
@@ -92,12 +99,57 @@ pub trait Catalog: Debug + Sync + Send {
         creation: TableCreation,
     ) -> Result<Table>;
 
+    /// Stage the creation of a table inside the namespace, without creating it.
+    ///
+    /// The returned table has no metadata location. It's created, together with the
+    /// changes made to it, by committing a
+    /// [`Transaction::create_table`](crate::transaction::Transaction::create_table).
+    ///
+    /// Catalogs that can't stage tables return [`ErrorKind::FeatureUnsupported`].
+    async fn stage_create_table(
+        &self,
+        namespace: &NamespaceIdent,
+        creation: TableCreation,
+    ) -> Result<Table> {
+        let _ = (namespace, creation);
+        Err(Error::new(
+            ErrorKind::FeatureUnsupported,
+            "Staged table creation is not supported by this catalog",
+        ))
+    }
+
     /// Load table from the catalog.
     async fn load_table(&self, table: &TableIdent) -> Result<Table>;
 
+    /// Load table from the catalog with its complete metadata, to be used as the base of a
+    /// commit.
+    ///
+    /// The default implementation calls [`Catalog::load_table`]. Catalogs whose tables may
+    /// be loaded with partial metadata, e.g. with only the snapshots referenced by branches
+    /// and tags, or from a cache, load the complete and latest metadata instead.
+    async fn load_table_for_commit(&self, table: &TableIdent) -> Result<Table> {
+        self.load_table(table).await
+    }
+
     /// Drop a table from the catalog, or returns error if it doesn't exist.
     async fn drop_table(&self, table: &TableIdent) -> Result<()>;
 
+    /// Drop a table from the catalog and delete its files, or returns error if it doesn't
+    /// exist.
+    ///
+    /// The default implementation loads the table, drops it with [`Catalog::drop_table`], then
+    /// deletes the data, delete, manifest, manifest list, statistics and metadata files
+    /// reachable from its metadata. Files are deleted on a best-effort basis: failures are
+    /// logged, and leave the files behind without failing the purge. Files which are no longer
+    /// referenced by the table metadata, such as the files of expired snapshots or orphan
+    /// files, are not deleted.
+    async fn purge_table(&self, table: &TableIdent) -> Result<()> {
+        let table = self.load_table(table).await?;
+        self.drop_table(table.identifier()).await?;
+        purge::delete_table_files(&table).await;
+        Ok(())
+    }
+
     /// Check if a table exists in the catalog.
     async fn table_exists(&self, table: &TableIdent) -> Result<bool>;
 
@@ -109,6 +161,74 @@ pub trait Catalog: Debug + Sync + Send {
 
     /// Update a table to the catalog.
     async fn update_table(&self, commit: TableCommit) -> Result<Table>;
+
+    /// Update multiple tables atomically: either all commits are applied, or none of them.
+    ///
+    /// Catalogs that can't commit multiple tables atomically return
+    /// [`ErrorKind::FeatureUnsupported`].
+    async fn commit_transaction(&self, commits: Vec<TableCommit>) -> Result<()> {
+        let _ = commits;
+        Err(Error::new(
+            ErrorKind::FeatureUnsupported,
+            "Multi-table transactions are not supported by this catalog",
+        ))
+    }
+
+    /// List views from namespace.
+    async fn list_views(&self, namespace: &NamespaceIdent) -> Result<Vec<TableIdent>> {
+        let _ = namespace;
+        Err(views_unsupported_err())
+    }
+
+    /// Create a new view inside the namespace.
+    async fn create_view(
+        &self,
+        namespace: &NamespaceIdent,
+        creation: ViewCreation,
+    ) -> Result<View> {
+        let _ = (namespace, creation);
+        Err(views_unsupported_err())
+    }
+
+    /// Load view from the catalog.
+    async fn load_view(&self, view: &TableIdent) -> Result<View> {
+        let _ = view;
+        Err(views_unsupported_err())
+    }
+
+    /// Drop a view from the catalog, or returns error if it doesn't exist.
+    async fn drop_view(&self, view: &TableIdent) -> Result<()> {
+        let _ = view;
+        Err(views_unsupported_err())
+    }
+
+    /// Check if a view exists in the catalog.
+    async fn view_exists(&self, view: &TableIdent) -> Result<bool> {
+        let _ = view;
+        Err(views_unsupported_err())
+    }
+
+    /// Rename a view in the catalog.
+    async fn rename_view(&self, src: &TableIdent, dest: &TableIdent) -> Result<()> {
+        let _ = (src, dest);
+        Err(views_unsupported_err())
+    }
+
+    /// Update a view to the catalog.
+    ///
+    /// Replacing the definition of a view is an update adding a new view version and
+    /// setting it as current.
+    async fn update_view(&self, commit: ViewCommit) -> Result<View> {
+        let _ = commit;
+        Err(views_unsupported_err())
+    }
+}
+
+fn views_unsupported_err() -> Error {
+    Error::new(
+        ErrorKind::FeatureUnsupported,
+        "Views are not supported by this catalog",
+    )
 }
 
 /// Common interface for all catalog builders.
@@ -300,7 +420,7 @@ impl Display for TableIdent {
 }
 
 /// TableCreation represents the creation of a table in the catalog.
-#[derive(Debug, TypedBuilder)]
+#[derive(Debug, Clone, TypedBuilder)]
 pub struct TableCreation {
     /// The name of the table.
     pub name: String,
@@ -344,6 +464,24 @@ pub struct TableCommit {
 }
 
 impl TableCommit {
+    /// Creates a [`TableCommit`] from the requirements and updates received from a client.
+    ///
+    /// This is meant for catalog servers forwarding commits to a [`Catalog`], and is only
+    /// available with the `server` feature. Applications should update tables with
+    /// [`crate::transaction::Transaction`] instead.
+    #[cfg(feature = "server")]
+    pub fn new(
+        ident: TableIdent,
+        requirements: Vec<TableRequirement>,
+        updates: Vec<TableUpdate>,
+    ) -> Self {
+        Self {
+            ident,
+            requirements,
+            updates,
+        }
+    }
+
     /// Return the table identifier.
     pub fn identifier(&self) -> &TableIdent {
         &self.ident
@@ -995,6 +1133,141 @@ pub enum ViewUpdate {
     },
 }
 
+impl ViewUpdate {
+    /// Applies the update to the view metadata builder.
+    pub fn apply(self, builder: ViewMetadataBuilder) -> Result<ViewMetadataBuilder> {
+        match self {
+            ViewUpdate::AssignUuid { uuid } => Ok(builder.assign_uuid(uuid)),
+            ViewUpdate::UpgradeFormatVersion { format_version } => {
+                builder.upgrade_format_version(format_version)
+            }
+            ViewUpdate::AddSchema { schema, .. } => Ok(builder.add_schema(schema)),
+            ViewUpdate::SetLocation { location } => Ok(builder.set_location(location)),
+            ViewUpdate::SetProperties { updates } => builder.set_properties(updates),
+            ViewUpdate::RemoveProperties { removals } => Ok(builder.remove_properties(&removals)),
+            ViewUpdate::AddViewVersion { view_version } => builder.add_version(view_version),
+            ViewUpdate::SetCurrentViewVersion { view_version_id } => {
+                builder.set_current_version_id(view_version_id)
+            }
+        }
+    }
+}
+
+/// ViewRequirement represents a requirement for a view in the catalog.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type")]
+pub enum ViewRequirement {
+    /// The view UUID must match the requirement.
+    #[serde(rename = "assert-view-uuid")]
+    UuidMatch {
+        /// Uuid of original view.
+        uuid: Uuid,
+    },
+}
+
+impl ViewRequirement {
+    /// Check that the requirement is met by the view metadata.
+    /// If the requirement is not met, an appropriate error is returned.
+    pub fn check(&self, metadata: &ViewMetadata) -> Result<()> {
+        match self {
+            ViewRequirement::UuidMatch { uuid } => {
+                if &metadata.uuid() != uuid {
+                    return Err(Error::new(
+                        ErrorKind::CatalogCommitConflicts,
+                        "Requirement failed: View UUID does not match",
+                    )
+                    .with_context("expected", *uuid)
+                    .with_context("found", metadata.uuid())
+                    .with_retryable(true));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// ViewCommit represents the commit of a view in the catalog.
+///
+/// The builder is marked as private since it's dangerous and error-prone to construct
+/// [`ViewCommit`] directly.
+#[derive(Debug, TypedBuilder)]
+#[builder(build_method(vis = "pub(crate)"))]
+pub struct ViewCommit {
+    /// The view ident.
+    ident: TableIdent,
+    /// The requirements of the view.
+    ///
+    /// Commit will fail if the requirements are not met.
+    requirements: Vec<ViewRequirement>,
+    /// The updates of the view.
+    updates: Vec<ViewUpdate>,
+}
+
+impl ViewCommit {
+    /// Creates a [`ViewCommit`] from the requirements and updates received from a client.
+    ///
+    /// This is meant for catalog servers forwarding commits to a [`Catalog`], and is only
+    /// available with the `server` feature.
+    #[cfg(feature = "server")]
+    pub fn new(
+        ident: TableIdent,
+        requirements: Vec<ViewRequirement>,
+        updates: Vec<ViewUpdate>,
+    ) -> Self {
+        Self {
+            ident,
+            requirements,
+            updates,
+        }
+    }
+
+    /// Return the view identifier.
+    pub fn identifier(&self) -> &TableIdent {
+        &self.ident
+    }
+
+    /// Take all requirements.
+    pub fn take_requirements(&mut self) -> Vec<ViewRequirement> {
+        take(&mut self.requirements)
+    }
+
+    /// Take all updates.
+    pub fn take_updates(&mut self) -> Vec<ViewUpdate> {
+        take(&mut self.updates)
+    }
+
+    /// Applies this [`ViewCommit`] to the given [`View`] as part of a catalog update.
+    /// Typically used by [`Catalog::update_view`] to validate requirements and apply metadata updates.
+    ///
+    /// Returns a new [`View`] with updated metadata,
+    /// or an error if validation or application fails.
+    pub fn apply(self, view: View) -> Result<View> {
+        // check requirements
+        for requirement in self.requirements {
+            requirement.check(view.metadata())?;
+        }
+
+        // get current metadata location
+        let current_metadata_location = view.metadata_location_result()?;
+
+        // apply updates to metadata builder
+        let mut metadata_builder = view.metadata().clone().into_builder();
+        for update in self.updates {
+            metadata_builder = update.apply(metadata_builder)?;
+        }
+
+        // Bump the version of metadata
+        let new_metadata_location = MetadataLocation::from_str(current_metadata_location)?
+            .with_next_version()
+            .to_string();
+
+        Ok(view
+            .with_metadata(Arc::new(metadata_builder.build()?.metadata))
+            .with_metadata_location(new_metadata_location))
+    }
+}
+
 mod _serde_set_statistics {
     // The rest spec requires an additional field `snapshot-id`
     // that is redundant with the `snapshot_id` field in the statistics file.
@@ -0,0 +1,285 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! This module contains the deletion of the files of a purged table.
+
+use std::collections::HashSet;
+
+use futures::{StreamExt, stream};
+
+use crate::table::Table;
+use crate::utils::available_parallelism;
+
+/// Deletes the files reachable from the metadata of `table`: the data and delete files, the
+/// manifests and manifest lists of all snapshots, the statistics files, and the current and
+/// previous metadata files.
+///
+/// Deletion is best-effort, like Java's `CatalogUtil.dropTableData`: all files are attempted
+/// to be deleted, and the manifest lists and manifests which can't be read, and the files
+/// which can't be deleted, are logged and skipped. Files which don't exist are ignored.
+pub(crate) async fn delete_table_files(table: &Table) {
+    let file_io = table.file_io();
+    let metadata = table.metadata();
+    let mut files = HashSet::new();
+    let mut manifests = HashSet::new();
+
+    for snapshot in metadata.snapshots() {
+        files.insert(snapshot.manifest_list().to_string());
+        let manifest_list = match snapshot.load_manifest_list(file_io, metadata).await {
+            Ok(manifest_list) => manifest_list,
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to read manifest list {} of table {}: {e}",
+                    snapshot.manifest_list(),
+                    table.identifier()
+                );
+                continue;
+            }
+        };
+        for manifest_file in manifest_list.entries() {
+            if !manifests.insert(manifest_file.manifest_path.clone()) {
+                continue;
+            }
+            match manifest_file.load_manifest(file_io).await {
+                Ok(manifest) => files.extend(
+                    manifest
+                        .entries()
+                        .iter()
+                        .map(|entry| entry.file_path().to_string()),
+                ),
+                Err(e) => tracing::warn!(
+                    "Failed to read manifest {} of table {}: {e}",
+                    manifest_file.manifest_path,
+                    table.identifier()
+                ),
+            }
+        }
+    }
+    files.extend(manifests);
+    files.extend(
+        metadata
+            .statistics_iter()
+            .map(|statistics| statistics.statistics_path.clone()),
+    );
+    files.extend(
+        metadata
+            .partition_statistics_iter()
+            .map(|statistics| statistics.statistics_path.clone()),
+    );
+    files.extend(
+        metadata
+            .metadata_log()
+            .iter()
+            .map(|log| log.metadata_file.clone()),
+    );
+    if let Some(metadata_location) = table.metadata_location() {
+        files.insert(metadata_location.to_string());
+    }
+
+    let failures = stream::iter(files)
+        .map(|path| async move {
+            let result = file_io.delete(&path).await;
+            (path, result)
+        })
+        .buffer_unordered(available_parallelism().get())
+        .filter_map(|(path, result)| async move { result.err().map(|e| (path, e)) })
+        .collect::<Vec<_>>()
+        .await;
+
+    for (path, e) in failures {
+        tracing::warn!(
+            "Failed to delete file {path} of table {}: {e}",
+            table.identifier()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::path::Path;
+    use std::sync::Arc;
+
+    use tempfile::TempDir;
+
+    use crate::io::LocalFsStorageFactory;
+    use crate::memory::{MEMORY_CATALOG_WAREHOUSE, MemoryCatalogBuilder};
+    use crate::spec::{
+        DataContentType, DataFileBuilder, DataFileFormat, NestedField, PrimitiveType, Schema,
+        Struct, Type,
+    };
+    use crate::transaction::{ApplyTransactionAction, Transaction};
+    use crate::{Catalog, CatalogBuilder, NamespaceIdent, TableCreation};
+
+    fn list_files(dir: &Path) -> Vec<String> {
+        let mut files = vec![];
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                files.extend(list_files(&path));
+            } else {
+                files.push(path.to_str().unwrap().to_string());
+            }
+        }
+        files
+    }
+
+    #[tokio::test]
+    async fn test_purge_table() {
+        let warehouse = TempDir::new().unwrap();
+        let catalog = MemoryCatalogBuilder::default()
+            .with_storage_factory(Arc::new(LocalFsStorageFactory))
+            .load(
+                "memory",
+                HashMap::from([(
+                    MEMORY_CATALOG_WAREHOUSE.to_string(),
+                    warehouse.path().to_str().unwrap().to_string(),
+                )]),
+            )
+            .await
+            .unwrap();
+        let namespace = NamespaceIdent::new("ns".to_string());
+        catalog
+            .create_namespace(&namespace, HashMap::new())
+            .await
+            .unwrap();
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+            ])
+            .build()
+            .unwrap();
+        let mut table = catalog
+            .create_table(
+                &namespace,
+                TableCreation::builder()
+                    .name("t1".to_string())
+                    .schema(schema)
+                    .build(),
+            )
+            .await
+            .unwrap();
+        let table_location = table.metadata().location().to_string();
+        std::fs::create_dir_all(format!("{table_location}/data")).unwrap();
+
+        // Two appends, so that the table has several snapshots and metadata files.
+        for n in 0..2 {
+            let path = format!("{table_location}/data/{n}.parquet");
+            std::fs::write(&path, b"data").unwrap();
+            let data_file = DataFileBuilder::default()
+                .content(DataContentType::Data)
+                .file_path(path)
+                .file_format(DataFileFormat::Parquet)
+                .file_size_in_bytes(4)
+                .record_count(1)
+                .partition(Struct::empty())
+                .partition_spec_id(0)
+                .build()
+                .unwrap();
+            let tx = Transaction::new(&table);
+            let tx = tx
+                .fast_append()
+                .add_data_files(vec![data_file])
+                .apply(tx)
+                .unwrap();
+            table = tx.commit(&catalog).await.unwrap();
+        }
+        let orphan = format!("{table_location}/data/orphan.parquet");
+        std::fs::write(&orphan, b"orphan").unwrap();
+        assert_eq!(list_files(Path::new(&table_location)).len(), 10);
+
+        catalog.purge_table(table.identifier()).await.unwrap();
+
+        assert!(!catalog.table_exists(table.identifier()).await.unwrap());
+        assert_eq!(list_files(Path::new(&table_location)), vec![orphan]);
+    }
+
+    #[tokio::test]
+    async fn test_purge_table_with_unreadable_manifest_list() {
+        let warehouse = TempDir::new().unwrap();
+        let catalog = MemoryCatalogBuilder::default()
+            .with_storage_factory(Arc::new(LocalFsStorageFactory))
+            .load(
+                "memory",
+                HashMap::from([(
+                    MEMORY_CATALOG_WAREHOUSE.to_string(),
+                    warehouse.path().to_str().unwrap().to_string(),
+                )]),
+            )
+            .await
+            .unwrap();
+        let namespace = NamespaceIdent::new("ns".to_string());
+        catalog
+            .create_namespace(&namespace, HashMap::new())
+            .await
+            .unwrap();
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+            ])
+            .build()
+            .unwrap();
+        let table = catalog
+            .create_table(
+                &namespace,
+                TableCreation::builder()
+                    .name("t1".to_string())
+                    .schema(schema)
+                    .build(),
+            )
+            .await
+            .unwrap();
+        let table_location = table.metadata().location().to_string();
+        std::fs::create_dir_all(format!("{table_location}/data")).unwrap();
+        let path = format!("{table_location}/data/0.parquet");
+        std::fs::write(&path, b"data").unwrap();
+        let data_file = DataFileBuilder::default()
+            .content(DataContentType::Data)
+            .file_path(path)
+            .file_format(DataFileFormat::Parquet)
+            .file_size_in_bytes(4)
+            .record_count(1)
+            .partition(Struct::empty())
+            .partition_spec_id(0)
+            .build()
+            .unwrap();
+        let tx = Transaction::new(&table);
+        let table = tx
+            .fast_append()
+            .add_data_files(vec![data_file])
+            .apply(tx)
+            .unwrap()
+            .commit(&catalog)
+            .await
+            .unwrap();
+        let manifest_list = table.metadata().current_snapshot().unwrap().manifest_list();
+        std::fs::write(manifest_list, b"corrupt").unwrap();
+
+        // The table is dropped and the metadata files are deleted, even though the manifest
+        // list can't be read to find the manifests and data files.
+        catalog.purge_table(table.identifier()).await.unwrap();
+
+        assert!(!catalog.table_exists(table.identifier()).await.unwrap());
+        let remaining = list_files(Path::new(&table_location));
+        assert!(
+            remaining
+                .iter()
+                .all(|path| !path.ends_with(".metadata.json"))
+        );
+        assert!(!remaining.iter().any(|path| path == manifest_list));
+    }
+}
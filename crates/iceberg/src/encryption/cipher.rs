@@ -0,0 +1,125 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! AES-GCM encryption of single blocks.
+
+use rand::RngCore;
+use ring::aead::{AES_128_GCM, AES_256_GCM, Aad, LessSafeKey, Nonce, UnboundKey};
+
+use crate::{Error, ErrorKind, Result};
+
+/// Length of the nonce prepended to each encrypted block.
+pub(crate) const NONCE_LENGTH: usize = 12;
+/// Length of the authentication tag appended to each encrypted block.
+pub(crate) const GCM_TAG_LENGTH: usize = 16;
+
+/// An AES-GCM key, encrypting blocks as `nonce | ciphertext | tag` with a random nonce.
+pub(crate) struct AesGcmCipher {
+    key: LessSafeKey,
+}
+
+impl AesGcmCipher {
+    /// Creates a cipher from an AES-128 or AES-256 key.
+    pub(crate) fn new(key: &[u8]) -> Result<Self> {
+        let algorithm = match key.len() {
+            16 => &AES_128_GCM,
+            32 => &AES_256_GCM,
+            length => {
+                return Err(Error::new(
+                    ErrorKind::DataInvalid,
+                    format!("Invalid AES key length {length}, expected 16 or 32 bytes"),
+                ));
+            }
+        };
+        let key = UnboundKey::new(algorithm, key)
+            .map_err(|_| Error::new(ErrorKind::DataInvalid, "Invalid AES key"))?;
+
+        Ok(Self {
+            key: LessSafeKey::new(key),
+        })
+    }
+
+    /// Encrypts a block, authenticating it with the additional data `aad`.
+    pub(crate) fn encrypt(&self, plaintext: &[u8], aad: &[u8]) -> Result<Vec<u8>> {
+        let mut nonce = [0u8; NONCE_LENGTH];
+        rand::thread_rng().fill_bytes(&mut nonce);
+
+        let mut block = Vec::with_capacity(NONCE_LENGTH + plaintext.len() + GCM_TAG_LENGTH);
+        block.extend_from_slice(&nonce);
+        block.extend_from_slice(plaintext);
+        let tag = self
+            .key
+            .seal_in_place_separate_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::from(aad),
+                &mut block[NONCE_LENGTH..],
+            )
+            .map_err(|_| Error::new(ErrorKind::Unexpected, "Failed to encrypt a block"))?;
+        block.extend_from_slice(tag.as_ref());
+
+        Ok(block)
+    }
+
+    /// Decrypts a block, checking that it was encrypted with the additional data `aad`.
+    pub(crate) fn decrypt(&self, block: &[u8], aad: &[u8]) -> Result<Vec<u8>> {
+        if block.len() < NONCE_LENGTH + GCM_TAG_LENGTH {
+            return Err(Error::new(
+                ErrorKind::DataInvalid,
+                format!("Encrypted block of {} bytes is too short", block.len()),
+            ));
+        }
+
+        let nonce = Nonce::try_assume_unique_for_key(&block[..NONCE_LENGTH])
+            .map_err(|_| Error::new(ErrorKind::DataInvalid, "Invalid nonce"))?;
+        let mut plaintext = block[NONCE_LENGTH..].to_vec();
+        let length = self
+            .key
+            .open_in_place(nonce, Aad::from(aad), &mut plaintext)
+            .map_err(|_| {
+                Error::new(
+                    ErrorKind::DataInvalid,
+                    "Failed to decrypt a block: wrong key or corrupted data",
+                )
+            })?
+            .len();
+        plaintext.truncate(length);
+
+        Ok(plaintext)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encrypt_decrypt() {
+        let cipher = AesGcmCipher::new(&[1; 16]).unwrap();
+        let block = cipher.encrypt(b"iceberg", b"aad").unwrap();
+        assert_eq!(block.len(), NONCE_LENGTH + 7 + GCM_TAG_LENGTH);
+        assert_eq!(cipher.decrypt(&block, b"aad").unwrap(), b"iceberg");
+
+        assert!(cipher.decrypt(&block, b"other aad").is_err());
+        assert!(
+            AesGcmCipher::new(&[2; 32])
+                .unwrap()
+                .decrypt(&block, b"aad")
+                .is_err()
+        );
+        assert!(AesGcmCipher::new(&[1; 24]).is_err());
+    }
+}
@@ -0,0 +1,219 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Key metadata of encrypted files.
+
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use apache_avro::Schema as AvroSchema;
+use apache_avro::types::Value;
+use once_cell::sync::Lazy;
+use parquet::encryption::decrypt::FileDecryptionProperties;
+use parquet::encryption::encrypt::FileEncryptionProperties;
+
+use crate::{Error, ErrorKind, Result};
+
+const KEY_METADATA_V1: u8 = 1;
+
+static KEY_METADATA_SCHEMA_V1: Lazy<AvroSchema> = Lazy::new(|| {
+    AvroSchema::parse_str(
+        r#"{
+            "type": "record",
+            "name": "key_metadata",
+            "fields": [
+                {"name": "encryption_key", "type": "bytes", "field-id": 0},
+                {"name": "aad_prefix", "type": ["null", "bytes"], "default": null, "field-id": 1},
+                {"name": "file_length", "type": ["null", "long"], "default": null, "field-id": 2}
+            ]
+        }"#,
+    )
+    .expect("key metadata schema is valid")
+});
+
+/// The key metadata of an encrypted file: its data encryption key and AAD prefix.
+///
+/// It is serialized as a version byte followed by an Avro record, the format used by other
+/// Iceberg implementations.
+#[derive(Clone, PartialEq, Eq)]
+pub struct StandardKeyMetadata {
+    encryption_key: Vec<u8>,
+    aad_prefix: Vec<u8>,
+    file_length: Option<u64>,
+}
+
+impl StandardKeyMetadata {
+    /// Creates the key metadata of a file.
+    pub fn new(encryption_key: Vec<u8>, aad_prefix: Vec<u8>) -> Self {
+        Self {
+            encryption_key,
+            aad_prefix,
+            file_length: None,
+        }
+    }
+
+    /// Sets the length of the encrypted file.
+    pub fn with_file_length(mut self, file_length: u64) -> Self {
+        self.file_length = Some(file_length);
+        self
+    }
+
+    /// Returns the data encryption key.
+    pub fn encryption_key(&self) -> &[u8] {
+        &self.encryption_key
+    }
+
+    /// Returns the prefix of the additional authenticated data of the file.
+    pub fn aad_prefix(&self) -> &[u8] {
+        &self.aad_prefix
+    }
+
+    /// Returns the length of the encrypted file, if known.
+    pub fn file_length(&self) -> Option<u64> {
+        self.file_length
+    }
+
+    /// Serializes the key metadata.
+    pub fn encode(&self) -> Result<Vec<u8>> {
+        let file_length = match self.file_length {
+            Some(length) => Value::Union(1, Box::new(Value::Long(i64::try_from(length)?))),
+            None => Value::Union(0, Box::new(Value::Null)),
+        };
+        let record = Value::Record(vec![
+            (
+                "encryption_key".to_string(),
+                Value::Bytes(self.encryption_key.clone()),
+            ),
+            (
+                "aad_prefix".to_string(),
+                Value::Union(1, Box::new(Value::Bytes(self.aad_prefix.clone()))),
+            ),
+            ("file_length".to_string(), file_length),
+        ]);
+
+        let mut bytes = vec![KEY_METADATA_V1];
+        bytes.extend(apache_avro::to_avro_datum(&KEY_METADATA_SCHEMA_V1, record)?);
+        Ok(bytes)
+    }
+
+    /// Deserializes key metadata.
+    pub fn decode(bytes: &[u8]) -> Result<Self> {
+        let Some((&version, mut datum)) = bytes.split_first() else {
+            return Err(Error::new(ErrorKind::DataInvalid, "Empty key metadata"));
+        };
+        if version != KEY_METADATA_V1 {
+            return Err(Error::new(
+                ErrorKind::FeatureUnsupported,
+                format!("Unsupported key metadata version {version}"),
+            ));
+        }
+
+        let Value::Record(fields) =
+            apache_avro::from_avro_datum(&KEY_METADATA_SCHEMA_V1, &mut datum, None)?
+        else {
+            return Err(invalid_key_metadata());
+        };
+        let mut key_metadata = Self::new(vec![], vec![]);
+        for (name, value) in fields {
+            let value = match value {
+                Value::Union(_, value) => *value,
+                value => value,
+            };
+            match (name.as_str(), value) {
+                ("encryption_key", Value::Bytes(key)) => key_metadata.encryption_key = key,
+                ("aad_prefix", Value::Bytes(prefix)) => key_metadata.aad_prefix = prefix,
+                ("file_length", Value::Long(length)) => {
+                    key_metadata.file_length = Some(u64::try_from(length)?)
+                }
+                (_, Value::Null) => {}
+                _ => return Err(invalid_key_metadata()),
+            }
+        }
+
+        Ok(key_metadata)
+    }
+
+    /// Returns the properties encrypting a Parquet file with this key.
+    ///
+    /// The AAD prefix isn't stored in the file, as it's known from the key metadata.
+    pub(crate) fn parquet_encryption_properties(&self) -> Result<Arc<FileEncryptionProperties>> {
+        Ok(
+            FileEncryptionProperties::builder(self.encryption_key.clone())
+                .with_aad_prefix(self.aad_prefix.clone())
+                .with_aad_prefix_storage(false)
+                .build()?,
+        )
+    }
+
+    /// Returns the properties decrypting a Parquet file encrypted with this key.
+    pub(crate) fn parquet_decryption_properties(&self) -> Result<Arc<FileDecryptionProperties>> {
+        Ok(
+            FileDecryptionProperties::builder(self.encryption_key.clone())
+                .with_aad_prefix(self.aad_prefix.clone())
+                .build()?,
+        )
+    }
+}
+
+fn invalid_key_metadata() -> Error {
+    Error::new(ErrorKind::DataInvalid, "Invalid key metadata")
+}
+
+impl Debug for StandardKeyMetadata {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StandardKeyMetadata")
+            .field("encryption_key", &"<redacted>")
+            .field("aad_prefix", &self.aad_prefix)
+            .field("file_length", &self.file_length)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_metadata_round_trip() {
+        let key_metadata = StandardKeyMetadata::new(vec![1; 16], vec![2; 16]);
+        let bytes = key_metadata.encode().unwrap();
+        assert_eq!(bytes[0], 1);
+        assert_eq!(StandardKeyMetadata::decode(&bytes).unwrap(), key_metadata);
+
+        let key_metadata = key_metadata.with_file_length(1024);
+        let bytes = key_metadata.encode().unwrap();
+        assert_eq!(StandardKeyMetadata::decode(&bytes).unwrap(), key_metadata);
+    }
+
+    #[test]
+    fn test_key_metadata_encoding() {
+        let key_metadata = StandardKeyMetadata::new(vec![0xAA; 2], vec![0xBB; 1]);
+        // Version, then the zigzag-encoded lengths and union branches of the Avro record.
+        assert_eq!(key_metadata.encode().unwrap(), vec![
+            1, 4, 0xAA, 0xAA, 2, 2, 0xBB, 0
+        ]);
+
+        assert!(StandardKeyMetadata::decode(&[]).is_err());
+        assert!(StandardKeyMetadata::decode(&[2, 4, 0xAA, 0xAA, 2, 2, 0xBB, 0]).is_err());
+    }
+
+    #[test]
+    fn test_key_metadata_debug_redacts_key() {
+        let key_metadata = StandardKeyMetadata::new(vec![42; 16], vec![]);
+        assert!(!format!("{key_metadata:?}").contains("42"));
+    }
+}
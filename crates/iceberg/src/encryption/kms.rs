@@ -0,0 +1,111 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Clients of key management services.
+
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+
+use async_trait::async_trait;
+
+use super::cipher::AesGcmCipher;
+use crate::{Error, ErrorKind, Result};
+
+/// A client of a key management service (KMS), wrapping keys with the master keys it holds.
+///
+/// Master keys don't leave the KMS: the keys encrypting a table are stored wrapped by the
+/// master key of the table, and are unwrapped by the KMS when the table is read.
+#[async_trait]
+pub trait KeyManagementClient: Debug + Send + Sync {
+    /// Wraps (encrypts) `key` with the master key `wrapping_key_id`.
+    async fn wrap_key(&self, key: &[u8], wrapping_key_id: &str) -> Result<Vec<u8>>;
+
+    /// Unwraps (decrypts) a key wrapped with the master key `wrapping_key_id`.
+    async fn unwrap_key(&self, wrapped_key: &[u8], wrapping_key_id: &str) -> Result<Vec<u8>>;
+}
+
+/// A [`KeyManagementClient`] holding AES master keys in memory, wrapping keys with AES-GCM.
+///
+/// This is meant for tests, as the master keys are only known to the process.
+#[derive(Default)]
+pub struct InMemoryKeyManagementClient {
+    master_keys: HashMap<String, Vec<u8>>,
+}
+
+impl InMemoryKeyManagementClient {
+    /// Creates a client without master keys.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an AES-128 or AES-256 master key.
+    pub fn with_master_key(mut self, key_id: impl Into<String>, key: Vec<u8>) -> Self {
+        self.master_keys.insert(key_id.into(), key);
+        self
+    }
+
+    fn cipher(&self, key_id: &str) -> Result<AesGcmCipher> {
+        let key = self.master_keys.get(key_id).ok_or_else(|| {
+            Error::new(
+                ErrorKind::DataInvalid,
+                format!("Master key {key_id} doesn't exist"),
+            )
+        })?;
+        AesGcmCipher::new(key)
+    }
+}
+
+impl Debug for InMemoryKeyManagementClient {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InMemoryKeyManagementClient")
+            .field("master_key_ids", &self.master_keys.keys())
+            .finish()
+    }
+}
+
+#[async_trait]
+impl KeyManagementClient for InMemoryKeyManagementClient {
+    async fn wrap_key(&self, key: &[u8], wrapping_key_id: &str) -> Result<Vec<u8>> {
+        self.cipher(wrapping_key_id)?
+            .encrypt(key, wrapping_key_id.as_bytes())
+    }
+
+    async fn unwrap_key(&self, wrapped_key: &[u8], wrapping_key_id: &str) -> Result<Vec<u8>> {
+        self.cipher(wrapping_key_id)?
+            .decrypt(wrapped_key, wrapping_key_id.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_in_memory_kms() {
+        let kms = InMemoryKeyManagementClient::new()
+            .with_master_key("k1", vec![1; 16])
+            .with_master_key("k2", vec![2; 32]);
+
+        let wrapped = kms.wrap_key(b"data key", "k1").await.unwrap();
+        assert_ne!(wrapped, b"data key");
+        assert_eq!(kms.unwrap_key(&wrapped, "k1").await.unwrap(), b"data key");
+
+        assert!(kms.unwrap_key(&wrapped, "k2").await.is_err());
+        assert!(kms.wrap_key(b"data key", "k3").await.is_err());
+        assert!(!format!("{kms:?}").contains("[1, 1"));
+    }
+}
@@ -0,0 +1,181 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Management of the keys of encrypted tables.
+
+use std::sync::Arc;
+
+use rand::RngCore;
+use uuid::Uuid;
+
+use super::{KeyManagementClient, StandardKeyMetadata};
+use crate::io::FileIO;
+use crate::spec::{EncryptedKey, FormatVersion, TableMetadata};
+use crate::{Error, ErrorKind, Result};
+
+/// Length of the AAD prefix generated for each file.
+const AAD_PREFIX_LENGTH: usize = 16;
+
+/// Manages the keys of an encrypted table.
+///
+/// Each new file gets its own randomly generated data encryption key. The keys of manifest
+/// lists are wrapped by the table master key in the KMS, as they are stored in the table
+/// metadata.
+#[derive(Debug, Clone)]
+pub struct EncryptionManager {
+    kms: Arc<dyn KeyManagementClient>,
+    table_key_id: String,
+    data_key_length: usize,
+}
+
+impl EncryptionManager {
+    /// Creates a manager generating data encryption keys of `data_key_length` bytes, and
+    /// wrapping them with the master key `table_key_id`.
+    pub fn new(
+        kms: Arc<dyn KeyManagementClient>,
+        table_key_id: impl Into<String>,
+        data_key_length: usize,
+    ) -> Result<Self> {
+        if data_key_length != 16 && data_key_length != 32 {
+            return Err(Error::new(
+                ErrorKind::DataInvalid,
+                format!("Invalid data key length {data_key_length}, expected 16 or 32 bytes"),
+            ));
+        }
+
+        Ok(Self {
+            kms,
+            table_key_id: table_key_id.into(),
+            data_key_length,
+        })
+    }
+
+    /// Returns the manager of a table, or `None` if the table isn't encrypted.
+    ///
+    /// The KMS of an encrypted table is the one of its [`FileIO`]. Encryption requires format
+    /// version 3, as older metadata can't store the wrapped keys of the table.
+    pub fn try_from_table(metadata: &TableMetadata, file_io: &FileIO) -> Result<Option<Self>> {
+        let properties = metadata.table_properties()?;
+        let Some(table_key_id) = properties.encryption_table_key_id else {
+            return Ok(None);
+        };
+        if metadata.format_version() < FormatVersion::V3 {
+            return Err(Error::new(
+                ErrorKind::FeatureUnsupported,
+                "Table encryption requires format version 3",
+            )
+            .with_context("format_version", metadata.format_version().to_string()));
+        }
+        let kms = file_io.key_management_client().ok_or_else(|| {
+            Error::new(
+                ErrorKind::DataInvalid,
+                "Table is encrypted, but no key management client is configured",
+            )
+            .with_context("table_key_id", &table_key_id)
+        })?;
+
+        Self::new(
+            kms.clone(),
+            table_key_id,
+            properties.encryption_data_key_length,
+        )
+        .map(Some)
+    }
+
+    /// Returns the id of the master key of the table.
+    pub fn table_key_id(&self) -> &str {
+        &self.table_key_id
+    }
+
+    /// Generates the key metadata of a new file.
+    pub fn generate_key_metadata(&self) -> StandardKeyMetadata {
+        let mut rng = rand::thread_rng();
+        let mut key = vec![0; self.data_key_length];
+        rng.fill_bytes(&mut key);
+        let mut aad_prefix = vec![0; AAD_PREFIX_LENGTH];
+        rng.fill_bytes(&mut aad_prefix);
+
+        StandardKeyMetadata::new(key, aad_prefix)
+    }
+
+    /// Wraps key metadata with the table master key, to store it in the table metadata.
+    pub async fn wrap_key_metadata(
+        &self,
+        key_metadata: &StandardKeyMetadata,
+    ) -> Result<EncryptedKey> {
+        let wrapped = self
+            .kms
+            .wrap_key(&key_metadata.encode()?, &self.table_key_id)
+            .await?;
+
+        Ok(EncryptedKey::builder()
+            .key_id(Uuid::new_v4().to_string())
+            .encrypted_key_metadata(wrapped)
+            .encrypted_by_id(self.table_key_id.clone())
+            .build())
+    }
+}
+
+/// Unwraps key metadata stored in the table metadata with the KMS.
+pub(crate) async fn unwrap_key_metadata(
+    kms: &dyn KeyManagementClient,
+    encrypted_key: &EncryptedKey,
+) -> Result<StandardKeyMetadata> {
+    let wrapping_key_id = encrypted_key.encrypted_by_id().ok_or_else(|| {
+        Error::new(
+            ErrorKind::DataInvalid,
+            format!(
+                "Encryption key {} doesn't name the key it is wrapped by",
+                encrypted_key.key_id()
+            ),
+        )
+    })?;
+    let key_metadata = kms
+        .unwrap_key(encrypted_key.encrypted_key_metadata(), wrapping_key_id)
+        .await?;
+
+    StandardKeyMetadata::decode(&key_metadata)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::InMemoryKeyManagementClient;
+
+    #[tokio::test]
+    async fn test_wrap_key_metadata() {
+        let kms =
+            Arc::new(InMemoryKeyManagementClient::new().with_master_key("master", vec![1; 16]));
+        let manager = EncryptionManager::new(kms.clone(), "master", 32).unwrap();
+
+        let key_metadata = manager.generate_key_metadata();
+        assert_eq!(key_metadata.encryption_key().len(), 32);
+        assert_eq!(key_metadata.aad_prefix().len(), AAD_PREFIX_LENGTH);
+        assert_ne!(manager.generate_key_metadata(), key_metadata);
+
+        let encrypted_key = manager.wrap_key_metadata(&key_metadata).await.unwrap();
+        assert_eq!(encrypted_key.encrypted_by_id(), Some("master"));
+        assert_eq!(
+            unwrap_key_metadata(kms.as_ref(), &encrypted_key)
+                .await
+                .unwrap(),
+            key_metadata
+        );
+
+        assert!(EncryptionManager::new(kms, "master", 24).is_err());
+    }
+}
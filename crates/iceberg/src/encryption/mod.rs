@@ -0,0 +1,262 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Table encryption.
+//!
+//! Encrypted tables have the `encryption.key-id` table property, naming the master key of the
+//! table in a [`KeyManagementClient`]. Each encrypted file has its own data encryption key,
+//! kept with an AAD prefix in its [`StandardKeyMetadata`]:
+//!
+//! - Data and delete files are written with Parquet modular encryption, and their key metadata
+//!   is stored in the `key_metadata` of their [`DataFile`](crate::spec::DataFile).
+//! - Manifests are encrypted as `AGS1` streams, and their key metadata is stored in their entry
+//!   of the manifest list.
+//! - Manifest lists are encrypted as `AGS1` streams. Their key metadata is wrapped by the table
+//!   master key and stored as an [`EncryptedKey`](crate::spec::EncryptedKey) in the table
+//!   metadata, referenced by the `key-id` of the snapshot.
+//!
+//! The [`FileIO`](crate::io::FileIO) of an encrypted table must be built with
+//! [`FileIOBuilder::with_key_management_client`](crate::io::FileIOBuilder::with_key_management_client).
+
+mod cipher;
+mod key_metadata;
+mod kms;
+mod manager;
+mod stream;
+
+pub use key_metadata::*;
+pub use kms::*;
+pub use manager::EncryptionManager;
+pub(crate) use manager::unwrap_key_metadata;
+pub(crate) use stream::{decrypt_file, encrypt_file};
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use arrow_array::{Int64Array, RecordBatch};
+    use futures::TryStreamExt;
+    use parquet::file::properties::WriterProperties;
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::arrow::schema_to_arrow_schema;
+    use crate::io::LocalFsStorageFactory;
+    use crate::memory::{MEMORY_CATALOG_WAREHOUSE, MemoryCatalogBuilder};
+    use crate::spec::{
+        DataFileFormat, FormatVersion, NestedField, PrimitiveType, Schema, TableProperties, Type,
+    };
+    use crate::table::Table;
+    use crate::transaction::{ApplyTransactionAction, Transaction};
+    use crate::writer::base_writer::data_file_writer::DataFileWriterBuilder;
+    use crate::writer::file_writer::ParquetWriterBuilder;
+    use crate::writer::file_writer::location_generator::{
+        DefaultFileNameGenerator, DefaultLocationGenerator,
+    };
+    use crate::writer::file_writer::rolling_writer::RollingFileWriterBuilder;
+    use crate::writer::{IcebergWriter, IcebergWriterBuilder};
+    use crate::{Catalog, CatalogBuilder, ErrorKind, NamespaceIdent, TableCreation};
+
+    async fn read_ids(table: &Table) -> crate::Result<Vec<i64>> {
+        let batches: Vec<RecordBatch> = table
+            .scan()
+            .build()?
+            .to_arrow()
+            .await?
+            .try_collect()
+            .await?;
+        Ok(batches
+            .iter()
+            .flat_map(|batch| {
+                let ids = batch
+                    .column(0)
+                    .as_any()
+                    .downcast_ref::<Int64Array>()
+                    .unwrap();
+                ids.values().to_vec()
+            })
+            .collect())
+    }
+
+    #[tokio::test]
+    async fn test_encrypted_table_round_trip() {
+        let warehouse = TempDir::new().unwrap();
+        let props = HashMap::from([(
+            MEMORY_CATALOG_WAREHOUSE.to_string(),
+            warehouse.path().to_str().unwrap().to_string(),
+        )]);
+        let kms =
+            Arc::new(InMemoryKeyManagementClient::new().with_master_key("master", vec![3; 32]));
+        let catalog = MemoryCatalogBuilder::default()
+            .with_storage_factory(Arc::new(LocalFsStorageFactory))
+            .with_key_management_client(kms.clone())
+            .load("memory", props.clone())
+            .await
+            .unwrap();
+
+        let namespace = NamespaceIdent::new("ns".to_string());
+        catalog
+            .create_namespace(&namespace, HashMap::new())
+            .await
+            .unwrap();
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+            ])
+            .build()
+            .unwrap();
+        let table = catalog
+            .create_table(
+                &namespace,
+                TableCreation::builder()
+                    .name("t1".to_string())
+                    .schema(schema.clone())
+                    .format_version(FormatVersion::V3)
+                    .properties(HashMap::from([(
+                        TableProperties::PROPERTY_ENCRYPTION_TABLE_KEY.to_string(),
+                        "master".to_string(),
+                    )]))
+                    .build(),
+            )
+            .await
+            .unwrap();
+
+        // Format version 2 metadata can't store the table keys.
+        let v2_table = catalog
+            .create_table(
+                &namespace,
+                TableCreation::builder()
+                    .name("v2".to_string())
+                    .schema(schema.clone())
+                    .properties(HashMap::from([(
+                        TableProperties::PROPERTY_ENCRYPTION_TABLE_KEY.to_string(),
+                        "master".to_string(),
+                    )]))
+                    .build(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            EncryptionManager::try_from_table(v2_table.metadata(), v2_table.file_io())
+                .unwrap_err()
+                .kind(),
+            ErrorKind::FeatureUnsupported
+        );
+
+        // Write an encrypted data file and commit it.
+        let encryption_manager =
+            EncryptionManager::try_from_table(table.metadata(), table.file_io())
+                .unwrap()
+                .unwrap();
+        let parquet_writer_builder =
+            ParquetWriterBuilder::new(WriterProperties::default(), Arc::new(schema.clone()))
+                .with_encryption_manager(encryption_manager);
+        let mut writer =
+            DataFileWriterBuilder::new(RollingFileWriterBuilder::new_with_default_file_size(
+                parquet_writer_builder,
+                table.file_io().clone(),
+                DefaultLocationGenerator::new(table.metadata().clone()).unwrap(),
+                DefaultFileNameGenerator::new("test".to_string(), None, DataFileFormat::Parquet),
+            ))
+            .build(None)
+            .await
+            .unwrap();
+        let batch = RecordBatch::try_new(Arc::new(schema_to_arrow_schema(&schema).unwrap()), vec![
+            Arc::new(Int64Array::from(vec![1, 2, 3])),
+        ])
+        .unwrap();
+        writer.write(batch).await.unwrap();
+        let data_files = writer.close().await.unwrap();
+        let key_metadata = data_files[0].key_metadata().unwrap().to_vec();
+
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .fast_append()
+            .add_data_files(data_files.clone())
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+
+        // All files are encrypted, and the manifest list key is stored in the table metadata.
+        let snapshot = table.metadata().current_snapshot().unwrap();
+        let key_id = snapshot.encryption_key_id().unwrap();
+        assert_eq!(
+            table
+                .metadata()
+                .encryption_key(key_id)
+                .unwrap()
+                .encrypted_by_id(),
+            Some("master")
+        );
+        let read = |path: &str| std::fs::read(path).unwrap();
+        assert_eq!(&read(snapshot.manifest_list())[..4], b"AGS1");
+        let manifest_list = snapshot
+            .load_manifest_list(table.file_io(), table.metadata())
+            .await
+            .unwrap();
+        let manifest_file = &manifest_list.entries()[0];
+        assert!(manifest_file.key_metadata.is_some());
+        assert_eq!(&read(&manifest_file.manifest_path)[..4], b"AGS1");
+        // The key metadata of the manifest list and manifest record the length of the files.
+        let file_length = |path: &str| Some(std::fs::metadata(path).unwrap().len());
+        let manifest_list_key_metadata = unwrap_key_metadata(
+            kms.as_ref(),
+            table.metadata().encryption_key(key_id).unwrap(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            manifest_list_key_metadata.file_length(),
+            file_length(snapshot.manifest_list())
+        );
+        let manifest_key_metadata =
+            StandardKeyMetadata::decode(manifest_file.key_metadata.as_deref().unwrap()).unwrap();
+        assert_eq!(
+            manifest_key_metadata.file_length(),
+            file_length(&manifest_file.manifest_path)
+        );
+        let manifest = manifest_file.load_manifest(table.file_io()).await.unwrap();
+        assert_eq!(
+            manifest.entries()[0].data_file().key_metadata(),
+            Some(key_metadata.as_slice())
+        );
+        let data = read(data_files[0].file_path());
+        assert_eq!(&data[data.len() - 4..], b"PARE");
+
+        assert_eq!(read_ids(&table).await.unwrap(), vec![1, 2, 3]);
+
+        // The table can't be read without the KMS.
+        let catalog = MemoryCatalogBuilder::default()
+            .with_storage_factory(Arc::new(LocalFsStorageFactory))
+            .load("memory", props)
+            .await
+            .unwrap();
+        catalog
+            .create_namespace(&namespace, HashMap::new())
+            .await
+            .unwrap();
+        let table = catalog
+            .register_table(
+                &crate::TableIdent::new(namespace, "t2".to_string()),
+                table.metadata_location().unwrap().to_string(),
+            )
+            .await
+            .unwrap();
+        assert!(read_ids(&table).await.is_err());
+    }
+}
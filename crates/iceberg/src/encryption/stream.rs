@@ -0,0 +1,217 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! AES-GCM stream encryption in the `AGS1` format of the Iceberg spec.
+//!
+//! An encrypted stream starts with the magic `AGS1` and the length of the plaintext blocks as a
+//! 4-byte little-endian integer. It is followed by the plaintext split into blocks, each block
+//! encrypted with AES-GCM as `nonce | ciphertext | tag`. The additional authenticated data of a
+//! block is the AAD prefix of the file followed by the block index as a 4-byte little-endian
+//! integer, so that blocks can't be reordered, dropped or moved to another file.
+//!
+//! The format has no marker of the last block, so a stream cut at a block boundary would still
+//! decrypt. The length of the stream is therefore recorded in the key metadata of the file, and
+//! checked before decrypting.
+
+use super::StandardKeyMetadata;
+use super::cipher::{AesGcmCipher, GCM_TAG_LENGTH, NONCE_LENGTH};
+use crate::{Error, ErrorKind, Result};
+
+const MAGIC: &[u8; 4] = b"AGS1";
+const HEADER_LENGTH: usize = MAGIC.len() + 4;
+/// Length of the plaintext blocks of the written streams.
+const PLAIN_BLOCK_LENGTH: usize = 1024 * 1024;
+
+/// Encrypts `plaintext` as an `AGS1` stream with the key of `key_metadata`.
+///
+/// Returns the stream and the key metadata to store for it, recording the length of the stream.
+pub(crate) fn encrypt_file(
+    key_metadata: StandardKeyMetadata,
+    plaintext: &[u8],
+) -> Result<(Vec<u8>, StandardKeyMetadata)> {
+    let stream = encrypt_stream(
+        key_metadata.encryption_key(),
+        key_metadata.aad_prefix(),
+        plaintext,
+    )?;
+    let file_length = stream.len() as u64;
+    Ok((stream, key_metadata.with_file_length(file_length)))
+}
+
+/// Decrypts an `AGS1` stream with the key of `key_metadata`.
+///
+/// Fails if the key metadata records a length other than the length of the stream.
+pub(crate) fn decrypt_file(key_metadata: &StandardKeyMetadata, stream: &[u8]) -> Result<Vec<u8>> {
+    if let Some(file_length) = key_metadata.file_length()
+        && file_length != stream.len() as u64
+    {
+        return Err(Error::new(
+            ErrorKind::DataInvalid,
+            "Invalid encrypted stream: length doesn't match its key metadata",
+        )
+        .with_context("expected", file_length.to_string())
+        .with_context("actual", stream.len().to_string()));
+    }
+
+    decrypt_stream(
+        key_metadata.encryption_key(),
+        key_metadata.aad_prefix(),
+        stream,
+    )
+}
+
+/// Encrypts `plaintext` as an `AGS1` stream.
+fn encrypt_stream(key: &[u8], aad_prefix: &[u8], plaintext: &[u8]) -> Result<Vec<u8>> {
+    encrypt_stream_with_block_length(key, aad_prefix, plaintext, PLAIN_BLOCK_LENGTH)
+}
+
+fn encrypt_stream_with_block_length(
+    key: &[u8],
+    aad_prefix: &[u8],
+    plaintext: &[u8],
+    block_length: usize,
+) -> Result<Vec<u8>> {
+    let cipher = AesGcmCipher::new(key)?;
+    let block_count = plaintext.len().div_ceil(block_length).max(1);
+
+    let mut stream = Vec::with_capacity(
+        HEADER_LENGTH + plaintext.len() + block_count * (NONCE_LENGTH + GCM_TAG_LENGTH),
+    );
+    stream.extend_from_slice(MAGIC);
+    stream.extend_from_slice(&(block_length as u32).to_le_bytes());
+
+    // An empty plaintext is still written as a single, empty block.
+    let blocks = (0..block_count).map(|index| {
+        let start = index * block_length;
+        &plaintext[start..(start + block_length).min(plaintext.len())]
+    });
+    for (index, block) in blocks.enumerate() {
+        stream.extend(cipher.encrypt(block, &block_aad(aad_prefix, index)?)?);
+    }
+
+    Ok(stream)
+}
+
+/// Decrypts an `AGS1` stream.
+fn decrypt_stream(key: &[u8], aad_prefix: &[u8], stream: &[u8]) -> Result<Vec<u8>> {
+    if stream.len() < HEADER_LENGTH || &stream[..MAGIC.len()] != MAGIC {
+        return Err(Error::new(
+            ErrorKind::DataInvalid,
+            "Invalid encrypted stream: missing AGS1 header",
+        ));
+    }
+    let block_length = u32::from_le_bytes(stream[MAGIC.len()..HEADER_LENGTH].try_into()?) as usize;
+    let body = &stream[HEADER_LENGTH..];
+    if block_length == 0 || body.is_empty() {
+        return Err(Error::new(
+            ErrorKind::DataInvalid,
+            "Invalid encrypted stream: no encrypted blocks",
+        ));
+    }
+
+    let cipher = AesGcmCipher::new(key)?;
+    let mut plaintext = Vec::with_capacity(body.len());
+    for (index, block) in body
+        .chunks(block_length + NONCE_LENGTH + GCM_TAG_LENGTH)
+        .enumerate()
+    {
+        plaintext.extend(cipher.decrypt(block, &block_aad(aad_prefix, index)?)?);
+    }
+
+    Ok(plaintext)
+}
+
+fn block_aad(aad_prefix: &[u8], index: usize) -> Result<Vec<u8>> {
+    let index = i32::try_from(index).map_err(|_| {
+        Error::new(
+            ErrorKind::DataInvalid,
+            "Encrypted stream exceeds the maximum number of blocks",
+        )
+    })?;
+
+    let mut aad = Vec::with_capacity(aad_prefix.len() + 4);
+    aad.extend_from_slice(aad_prefix);
+    aad.extend_from_slice(&index.to_le_bytes());
+    Ok(aad)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: [u8; 16] = [7; 16];
+
+    #[test]
+    fn test_stream_round_trip() {
+        for length in [0usize, 1, 9, 10, 11, 35] {
+            let plaintext: Vec<u8> = (0..length).map(|i| i as u8).collect();
+            let stream = encrypt_stream_with_block_length(&KEY, b"prefix", &plaintext, 10).unwrap();
+
+            assert_eq!(&stream[..4], b"AGS1");
+            assert_eq!(&stream[4..8], &10u32.to_le_bytes());
+            let blocks = length.div_ceil(10).max(1);
+            assert_eq!(stream.len(), 8 + length + blocks * 28);
+
+            assert_eq!(decrypt_stream(&KEY, b"prefix", &stream).unwrap(), plaintext);
+        }
+    }
+
+    #[test]
+    fn test_stream_authentication() {
+        let plaintext = [1u8; 25];
+        let stream = encrypt_stream_with_block_length(&KEY, b"prefix", &plaintext, 10).unwrap();
+
+        // The AAD prefix binds the stream to its file.
+        assert!(decrypt_stream(&KEY, b"other", &stream).is_err());
+
+        // Blocks can't be truncated or reordered.
+        let truncated = &stream[..stream.len() - 1];
+        assert!(decrypt_stream(&KEY, b"prefix", truncated).is_err());
+        let block = 10 + 28;
+        let mut reordered = stream[..8].to_vec();
+        reordered.extend_from_slice(&stream[8 + block..8 + 2 * block]);
+        reordered.extend_from_slice(&stream[8..8 + block]);
+        reordered.extend_from_slice(&stream[8 + 2 * block..]);
+        assert!(decrypt_stream(&KEY, b"prefix", &reordered).is_err());
+
+        assert!(decrypt_stream(&KEY, b"prefix", b"AGS1").is_err());
+        assert!(decrypt_stream(&KEY, b"prefix", b"plain avro bytes").is_err());
+    }
+
+    #[test]
+    fn test_file_length_detects_truncation() {
+        let key_metadata = StandardKeyMetadata::new(KEY.to_vec(), b"prefix".to_vec());
+        let plaintext: Vec<u8> = (0..25).collect();
+        let (stream, key_metadata) = encrypt_file(key_metadata, &plaintext).unwrap();
+        assert_eq!(key_metadata.file_length(), Some(stream.len() as u64));
+        assert_eq!(decrypt_file(&key_metadata, &stream).unwrap(), plaintext);
+
+        // A stream cut after a whole block decrypts to a prefix of the plaintext, unless its
+        // length is checked.
+        let stream = encrypt_stream_with_block_length(&KEY, b"prefix", &plaintext, 10).unwrap();
+        let key_metadata = key_metadata.with_file_length(stream.len() as u64);
+        let truncated = &stream[..stream.len() - (5 + 28)];
+        assert_eq!(
+            decrypt_stream(&KEY, b"prefix", truncated).unwrap(),
+            plaintext[..20]
+        );
+        let err = decrypt_file(&key_metadata, truncated).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::DataInvalid);
+        assert!(err.to_string().contains("length"));
+        assert_eq!(decrypt_file(&key_metadata, &stream).unwrap(), plaintext);
+    }
+}
@@ -56,6 +56,12 @@ pub enum ErrorKind {
     /// Iceberg table does not exist.
     TableNotFound,
 
+    /// Iceberg view already exists at creation.
+    ViewAlreadyExists,
+
+    /// Iceberg view does not exist.
+    ViewNotFound,
+
     /// Iceberg feature is not supported.
     ///
     /// This error is returned when given iceberg feature is not supported.
@@ -63,6 +69,13 @@ pub enum ErrorKind {
 
     /// Catalog commit failed due to outdated metadata
     CatalogCommitConflicts,
+
+    /// Commit failed validation because snapshots committed concurrently
+    /// conflict with the pending operation.
+    ///
+    /// Unlike [`ErrorKind::CatalogCommitConflicts`], retrying the commit
+    /// against refreshed metadata will not resolve this error.
+    CommitConflict,
 }
 
 impl ErrorKind {
@@ -80,10 +93,13 @@ impl From<ErrorKind> for &'static str {
             ErrorKind::FeatureUnsupported => "FeatureUnsupported",
             ErrorKind::TableAlreadyExists => "TableAlreadyExists",
             ErrorKind::TableNotFound => "TableNotFound",
+            ErrorKind::ViewAlreadyExists => "ViewAlreadyExists",
+            ErrorKind::ViewNotFound => "ViewNotFound",
             ErrorKind::NamespaceAlreadyExists => "NamespaceAlreadyExists",
             ErrorKind::NamespaceNotFound => "NamespaceNotFound",
             ErrorKind::PreconditionFailed => "PreconditionFailed",
             ErrorKind::CatalogCommitConflicts => "CatalogCommitConflicts",
+            ErrorKind::CommitConflict => "CommitConflict",
         }
     }
 }
@@ -53,6 +53,8 @@ pub enum PredicateOperator {
     NotEq = 206,
     StartsWith = 207,
     NotStartsWith = 208,
+    Intersects = 209,
+    Disjoint = 210,
 
     // Set operators
     In = 301,
@@ -76,6 +78,8 @@ impl Display for PredicateOperator {
             PredicateOperator::NotIn => write!(f, "NOT IN"),
             PredicateOperator::StartsWith => write!(f, "STARTS WITH"),
             PredicateOperator::NotStartsWith => write!(f, "NOT STARTS WITH"),
+            PredicateOperator::Intersects => write!(f, "ST_INTERSECTS"),
+            PredicateOperator::Disjoint => write!(f, "ST_DISJOINT"),
         }
     }
 }
@@ -115,7 +119,22 @@ impl PredicateOperator {
     /// assert!(PredicateOperator::In.is_set());
     /// ```
     pub fn is_set(self) -> bool {
-        (self as u16) > (PredicateOperator::NotStartsWith as u16)
+        (self as u16) >= (PredicateOperator::In as u16)
+    }
+
+    /// Check if this operator is a spatial operator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iceberg::expr::PredicateOperator;
+    /// assert!(PredicateOperator::Intersects.is_spatial());
+    /// ```
+    pub fn is_spatial(self) -> bool {
+        matches!(
+            self,
+            PredicateOperator::Intersects | PredicateOperator::Disjoint
+        )
     }
 
     /// Returns the predicate that is the inverse of self
@@ -143,6 +162,10 @@ impl PredicateOperator {
     ///     PredicateOperator::StartsWith.negate(),
     ///     PredicateOperator::NotStartsWith
     /// );
+    /// assert_eq!(
+    ///     PredicateOperator::Intersects.negate(),
+    ///     PredicateOperator::Disjoint
+    /// );
     /// ```
     pub fn negate(self) -> PredicateOperator {
         match self {
@@ -160,6 +183,8 @@ impl PredicateOperator {
             PredicateOperator::NotIn => PredicateOperator::In,
             PredicateOperator::StartsWith => PredicateOperator::NotStartsWith,
             PredicateOperator::NotStartsWith => PredicateOperator::StartsWith,
+            PredicateOperator::Intersects => PredicateOperator::Disjoint,
+            PredicateOperator::Disjoint => PredicateOperator::Intersects,
         }
     }
 }
@@ -194,6 +219,9 @@ mod tests {
         assert!(PredicateOperator::NotEq.is_binary());
         assert!(PredicateOperator::StartsWith.is_binary());
         assert!(PredicateOperator::NotStartsWith.is_binary());
+        assert!(PredicateOperator::Intersects.is_binary());
+        assert!(PredicateOperator::Disjoint.is_binary());
+        assert!(!PredicateOperator::Intersects.is_set());
     }
 
     #[test]
@@ -32,7 +32,7 @@ use crate::expr::visitors::bound_predicate_visitor::visit as visit_bound;
 use crate::expr::visitors::predicate_visitor::visit;
 use crate::expr::visitors::rewrite_not::RewriteNotVisitor;
 use crate::expr::{Bind, BoundReference, PredicateOperator, Reference};
-use crate::spec::{Datum, PrimitiveLiteral, SchemaRef};
+use crate::spec::{Datum, PrimitiveLiteral, SchemaRef, Type};
 use crate::{Error, ErrorKind};
 
 /// Logical expression, such as `AND`, `OR`, `NOT`.
@@ -419,6 +419,22 @@ impl Bind for Predicate {
             }
             Predicate::Binary(expr) => {
                 let bound_expr = expr.bind(schema, case_sensitive)?;
+                if bound_expr.op.is_spatial()
+                    && !matches!(
+                        bound_expr.term.field().field_type.as_ref(),
+                        Type::Primitive(p) if p.is_geospatial()
+                    )
+                {
+                    return Err(Error::new(
+                        ErrorKind::DataInvalid,
+                        format!(
+                            "Spatial predicate {} requires a geometry or geography field, but {} is {}",
+                            bound_expr.op,
+                            bound_expr.term.field().name,
+                            bound_expr.term.field().field_type
+                        ),
+                    ));
+                }
                 let bound_literal = bound_expr.literal.to(&bound_expr.term.field().field_type)?;
 
                 match bound_literal.literal() {
@@ -1341,6 +1357,19 @@ mod tests {
         assert!(bound_expr.is_err());
     }
 
+    #[test]
+    fn test_bind_intersects_wrong_type() {
+        let schema = table_schema_simple();
+        let expr = Reference::new("foo").intersects(Datum::geometry(vec![1, 1, 0, 0, 0]));
+        let bound_expr = expr.bind(schema, true);
+        assert!(
+            bound_expr
+                .unwrap_err()
+                .message()
+                .contains("requires a geometry or geography field")
+        );
+    }
+
     #[test]
     fn test_bind_not_starts_with() {
         let schema = table_schema_simple();
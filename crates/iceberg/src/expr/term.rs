@@ -197,6 +197,50 @@ impl Reference {
         ))
     }
 
+    /// Creates a spatial intersects expression against a WKB encoded geometry.
+    /// For example, `a ST_INTERSECTS POINT (1 2)`.
+    ///
+    /// Spatial predicates are evaluated conservatively: scans, including their row filter,
+    /// keep the rows whose bounding box overlaps the geometry, and all non-null rows of
+    /// geography columns. The engine must re-check the predicate on the returned rows.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use iceberg::expr::{PredicateOperator, Reference};
+    /// use iceberg::spec::Datum;
+    ///
+    /// let wkb = [
+    ///     vec![1u8, 1, 0, 0, 0],
+    ///     1f64.to_le_bytes().to_vec(),
+    ///     2f64.to_le_bytes().to_vec(),
+    /// ]
+    /// .concat();
+    /// let expr = Reference::new("a").intersects(Datum::geometry(wkb));
+    ///
+    /// assert!(format!("{expr}").starts_with("a ST_INTERSECTS"));
+    /// ```
+    pub fn intersects(self, datum: Datum) -> Predicate {
+        Predicate::Binary(BinaryExpression::new(
+            PredicateOperator::Intersects,
+            self,
+            datum,
+        ))
+    }
+
+    /// Creates a spatial disjoint expression against a WKB encoded geometry.
+    /// For example, `a ST_DISJOINT POINT (1 2)`.
+    ///
+    /// Spatial predicates are evaluated conservatively: scans, including their row filter,
+    /// keep all non-null rows. The engine must re-check the predicate on the returned rows.
+    pub fn disjoint(self, datum: Datum) -> Predicate {
+        Predicate::Binary(BinaryExpression::new(
+            PredicateOperator::Disjoint,
+            self,
+            datum,
+        ))
+    }
+
     /// Creates an is-nan expression. For example, `a IS NAN`.
     ///
     /// # Example
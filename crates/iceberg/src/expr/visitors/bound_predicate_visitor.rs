@@ -17,9 +17,9 @@
 
 use fnv::FnvHashSet;
 
-use crate::Result;
 use crate::expr::{BoundPredicate, BoundReference, PredicateOperator};
 use crate::spec::Datum;
+use crate::{Error, ErrorKind, Result};
 
 /// A visitor for [`BoundPredicate`]s. Visits in post-order.
 pub trait BoundPredicateVisitor {
@@ -130,6 +130,36 @@ pub trait BoundPredicateVisitor {
         predicate: &BoundPredicate,
     ) -> Result<Self::T>;
 
+    /// Called after a predicate with an `Intersects` operator is visited
+    ///
+    /// Spatial predicates are only supported by the visitors that implement them.
+    fn intersects(
+        &mut self,
+        _reference: &BoundReference,
+        _literal: &Datum,
+        _predicate: &BoundPredicate,
+    ) -> Result<Self::T> {
+        Err(Error::new(
+            ErrorKind::FeatureUnsupported,
+            "Intersects predicates are not supported by this bound predicate visitor",
+        ))
+    }
+
+    /// Called after a predicate with a `Disjoint` operator is visited
+    ///
+    /// Spatial predicates are only supported by the visitors that implement them.
+    fn disjoint(
+        &mut self,
+        _reference: &BoundReference,
+        _literal: &Datum,
+        _predicate: &BoundPredicate,
+    ) -> Result<Self::T> {
+        Err(Error::new(
+            ErrorKind::FeatureUnsupported,
+            "Disjoint predicates are not supported by this bound predicate visitor",
+        ))
+    }
+
     /// Called after a predicate with an `In` operator is visited
     fn r#in(
         &mut self,
@@ -208,6 +238,8 @@ pub(crate) fn visit<V: BoundPredicateVisitor>(
                 PredicateOperator::NotStartsWith => {
                     visitor.not_starts_with(reference, literal, predicate)
                 }
+                PredicateOperator::Intersects => visitor.intersects(reference, literal, predicate),
+                PredicateOperator::Disjoint => visitor.disjoint(reference, literal, predicate),
                 op => {
                     panic!("Unexpected op for binary predicate: {}", &op)
                 }
@@ -234,12 +266,13 @@ mod tests {
 
     use fnv::FnvHashSet;
 
+    use crate::ErrorKind;
     use crate::expr::visitors::bound_predicate_visitor::{BoundPredicateVisitor, visit};
     use crate::expr::{
         BinaryExpression, Bind, BoundPredicate, BoundReference, Predicate, PredicateOperator,
         Reference, SetExpression, UnaryExpression,
     };
-    use crate::spec::{Datum, NestedField, PrimitiveType, Schema, SchemaRef, Type};
+    use crate::spec::{DEFAULT_CRS, Datum, NestedField, PrimitiveType, Schema, SchemaRef, Type};
 
     struct TestEvaluator {}
     impl BoundPredicateVisitor for TestEvaluator {
@@ -707,6 +740,33 @@ mod tests {
         assert!(!result.unwrap());
     }
 
+    #[test]
+    fn test_spatial_predicates_unsupported_by_default() {
+        let schema = Arc::new(
+            Schema::builder()
+                .with_fields(vec![Arc::new(NestedField::required(
+                    1,
+                    "geom",
+                    Type::Primitive(PrimitiveType::Geometry {
+                        crs: DEFAULT_CRS.to_string(),
+                    }),
+                ))])
+                .build()
+                .unwrap(),
+        );
+        let geometry = Datum::geometry(vec![1, 1, 0, 0, 0]);
+
+        let mut test_evaluator = TestEvaluator {};
+        for predicate in [
+            Reference::new("geom").intersects(geometry.clone()),
+            Reference::new("geom").disjoint(geometry),
+        ] {
+            let bound_predicate = predicate.bind(schema.clone(), false).unwrap();
+            let err = visit(&mut test_evaluator, &bound_predicate).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::FeatureUnsupported);
+        }
+    }
+
     #[test]
     fn test_in() {
         let predicate = Predicate::Set(SetExpression::new(
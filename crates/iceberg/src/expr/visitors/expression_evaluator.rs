@@ -221,6 +221,31 @@ impl BoundPredicateVisitor for ExpressionEvaluatorVisitor<'_> {
         Ok(!self.starts_with(reference, literal, _predicate)?)
     }
 
+    fn intersects(
+        &mut self,
+        reference: &BoundReference,
+        _literal: &Datum,
+        _predicate: &BoundPredicate,
+    ) -> Result<bool> {
+        // Geospatial fields can't be partition sources, so there is nothing to evaluate.
+        Err(Error::new(
+            ErrorKind::FeatureUnsupported,
+            format!(
+                "Cannot evaluate spatial predicate on partition field {}",
+                reference.field().name
+            ),
+        ))
+    }
+
+    fn disjoint(
+        &mut self,
+        reference: &BoundReference,
+        literal: &Datum,
+        predicate: &BoundPredicate,
+    ) -> Result<bool> {
+        self.intersects(reference, literal, predicate)
+    }
+
     fn r#in(
         &mut self,
         reference: &BoundReference,
@@ -19,7 +19,7 @@ use fnv::FnvHashSet;
 
 use crate::expr::visitors::bound_predicate_visitor::{BoundPredicateVisitor, visit};
 use crate::expr::{BoundPredicate, BoundReference};
-use crate::spec::{DataFile, Datum, PrimitiveLiteral};
+use crate::spec::{BoundingBox, DataFile, Datum, PrimitiveLiteral, PrimitiveType};
 use crate::{Error, ErrorKind};
 
 const IN_PREDICATE_LIMIT: usize = 200;
@@ -420,6 +420,62 @@ impl BoundPredicateVisitor for InclusiveMetricsEvaluator<'_> {
         ROWS_MIGHT_MATCH
     }
 
+    fn intersects(
+        &mut self,
+        reference: &BoundReference,
+        datum: &Datum,
+        _predicate: &BoundPredicate,
+    ) -> crate::Result<bool> {
+        let field_id = reference.field().id;
+
+        if self.contains_nulls_only(field_id) {
+            return ROWS_CANNOT_MATCH;
+        }
+
+        // The bounding box of the vertices of a geography doesn't cover its edges,
+        // so only geometry bounds can be compared.
+        if !matches!(
+            reference.field().field_type.as_primitive_type(),
+            Some(PrimitiveType::Geometry { .. })
+        ) {
+            return ROWS_MIGHT_MATCH;
+        }
+
+        let (Some(lower_bound), Some(upper_bound)) =
+            (self.lower_bound(field_id), self.upper_bound(field_id))
+        else {
+            return ROWS_MIGHT_MATCH;
+        };
+
+        let PrimitiveLiteral::Binary(wkb) = datum.literal() else {
+            return Err(Error::new(
+                ErrorKind::Unexpected,
+                "Cannot use Intersects operator on non-geospatial values",
+            ));
+        };
+
+        let Some(query) = BoundingBox::try_from_wkb(wkb)? else {
+            // empty geometries don't intersect anything
+            return ROWS_CANNOT_MATCH;
+        };
+
+        if BoundingBox::try_from_bounds(lower_bound, upper_bound)?.intersects(&query) {
+            ROWS_MIGHT_MATCH
+        } else {
+            ROWS_CANNOT_MATCH
+        }
+    }
+
+    fn disjoint(
+        &mut self,
+        _reference: &BoundReference,
+        _datum: &Datum,
+        _predicate: &BoundPredicate,
+    ) -> crate::Result<bool> {
+        // Overlapping bounding boxes don't imply the values intersect.
+        ROWS_MIGHT_MATCH
+    }
+
     fn r#in(
         &mut self,
         reference: &BoundReference,
@@ -495,8 +551,8 @@ mod test {
         UnaryExpression,
     };
     use crate::spec::{
-        DataContentType, DataFile, DataFileFormat, Datum, NestedField, PartitionSpec,
-        PartitionSpecRef, PrimitiveType, Schema, SchemaRef, Struct, Transform, Type,
+        DataContentType, DataFile, DataFileFormat, Datum, GeospatialBound, NestedField,
+        PartitionSpec, PartitionSpecRef, PrimitiveType, Schema, SchemaRef, Struct, Transform, Type,
         UnboundPartitionField,
     };
 
@@ -1344,6 +1400,60 @@ mod test {
         assert!(!result, "Should skip: range does not match");
     }
 
+    #[test]
+    fn test_geospatial_intersects() {
+        let schema = Arc::new(
+            Schema::builder()
+                .with_fields(vec![
+                    NestedField::optional(1, "geom", Type::Primitive(PrimitiveType::geometry()))
+                        .into(),
+                    NestedField::optional(2, "geog", Type::Primitive(PrimitiveType::geography()))
+                        .into(),
+                ])
+                .build()
+                .unwrap(),
+        );
+        let bound = |x: f64, y: f64| Datum::geometry(GeospatialBound::new(x, y).to_bytes());
+        let data_file = DataFile {
+            value_counts: HashMap::from([(1, 10), (2, 10)]),
+            null_value_counts: HashMap::from([(1, 0), (2, 0)]),
+            lower_bounds: HashMap::from([(1, bound(0.0, 0.0)), (2, bound(0.0, 0.0))]),
+            upper_bounds: HashMap::from([(1, bound(10.0, 10.0)), (2, bound(10.0, 10.0))]),
+            ..get_test_file_2()
+        };
+        let point = |x: f64, y: f64| {
+            let mut wkb = vec![1, 1, 0, 0, 0];
+            wkb.extend_from_slice(&x.to_le_bytes());
+            wkb.extend_from_slice(&y.to_le_bytes());
+            Datum::geometry(wkb)
+        };
+        let eval = |predicate: Predicate| {
+            InclusiveMetricsEvaluator::eval(
+                &predicate.bind(schema.clone(), true).unwrap(),
+                &data_file,
+                true,
+            )
+            .unwrap()
+        };
+
+        assert!(
+            eval(Reference::new("geom").intersects(point(5.0, 5.0))),
+            "Should read: point is within bounds"
+        );
+        assert!(
+            !eval(Reference::new("geom").intersects(point(20.0, 5.0))),
+            "Should skip: point is outside bounds"
+        );
+        assert!(
+            eval(Reference::new("geog").intersects(point(20.0, 5.0))),
+            "Should read: geography bounds don't cover edges"
+        );
+        assert!(
+            eval(Reference::new("geom").disjoint(point(20.0, 5.0))),
+            "Should read: disjoint can't be evaluated with bounds"
+        );
+    }
+
     #[test]
     fn test_string_not_starts_with() {
         let result = InclusiveMetricsEvaluator::eval(
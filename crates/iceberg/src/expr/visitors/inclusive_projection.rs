@@ -212,6 +212,24 @@ impl BoundPredicateVisitor for InclusiveProjection {
         self.get_parts(reference, predicate)
     }
 
+    fn intersects(
+        &mut self,
+        reference: &BoundReference,
+        _literal: &Datum,
+        predicate: &BoundPredicate,
+    ) -> crate::Result<Self::T> {
+        self.get_parts(reference, predicate)
+    }
+
+    fn disjoint(
+        &mut self,
+        reference: &BoundReference,
+        _literal: &Datum,
+        predicate: &BoundPredicate,
+    ) -> crate::Result<Self::T> {
+        self.get_parts(reference, predicate)
+    }
+
     fn r#in(
         &mut self,
         reference: &BoundReference,
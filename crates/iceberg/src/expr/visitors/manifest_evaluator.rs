@@ -406,6 +406,25 @@ impl BoundPredicateVisitor for ManifestFilterVisitor<'_> {
         ROWS_MIGHT_MATCH
     }
 
+    fn intersects(
+        &mut self,
+        _reference: &BoundReference,
+        _datum: &Datum,
+        _predicate: &BoundPredicate,
+    ) -> crate::Result<bool> {
+        // Geospatial fields can't be partition sources.
+        ROWS_MIGHT_MATCH
+    }
+
+    fn disjoint(
+        &mut self,
+        _reference: &BoundReference,
+        _datum: &Datum,
+        _predicate: &BoundPredicate,
+    ) -> crate::Result<bool> {
+        ROWS_MIGHT_MATCH
+    }
+
     fn r#in(
         &mut self,
         reference: &BoundReference,
@@ -716,6 +716,25 @@ impl BoundPredicateVisitor for PageIndexEvaluator<'_> {
         )
     }
 
+    fn intersects(
+        &mut self,
+        _reference: &BoundReference,
+        _datum: &Datum,
+        _predicate: &BoundPredicate,
+    ) -> Result<RowSelection> {
+        // Parquet page indexes don't contain bounding boxes.
+        self.select_all_rows()
+    }
+
+    fn disjoint(
+        &mut self,
+        _reference: &BoundReference,
+        _datum: &Datum,
+        _predicate: &BoundPredicate,
+    ) -> Result<RowSelection> {
+        self.select_all_rows()
+    }
+
     fn r#in(
         &mut self,
         reference: &BoundReference,
@@ -17,9 +17,9 @@
 
 use fnv::FnvHashSet;
 
-use crate::Result;
 use crate::expr::{Predicate, PredicateOperator, Reference};
 use crate::spec::Datum;
+use crate::{Error, ErrorKind, Result};
 
 /// A visitor for [`Predicate`]s. Visits in post-order.
 pub trait PredicateVisitor {
@@ -117,6 +117,36 @@ pub trait PredicateVisitor {
         predicate: &Predicate,
     ) -> Result<Self::T>;
 
+    /// Called after a predicate with an `Intersects` operator is visited
+    ///
+    /// Spatial predicates are only supported by the visitors that implement them.
+    fn intersects(
+        &mut self,
+        _reference: &Reference,
+        _literal: &Datum,
+        _predicate: &Predicate,
+    ) -> Result<Self::T> {
+        Err(Error::new(
+            ErrorKind::FeatureUnsupported,
+            "Intersects predicates are not supported by this predicate visitor",
+        ))
+    }
+
+    /// Called after a predicate with a `Disjoint` operator is visited
+    ///
+    /// Spatial predicates are only supported by the visitors that implement them.
+    fn disjoint(
+        &mut self,
+        _reference: &Reference,
+        _literal: &Datum,
+        _predicate: &Predicate,
+    ) -> Result<Self::T> {
+        Err(Error::new(
+            ErrorKind::FeatureUnsupported,
+            "Disjoint predicates are not supported by this predicate visitor",
+        ))
+    }
+
     /// Called after a predicate with an `In` operator is visited
     fn r#in(
         &mut self,
@@ -192,6 +222,8 @@ pub(crate) fn visit<V: PredicateVisitor>(visitor: &mut V, predicate: &Predicate)
                 PredicateOperator::NotStartsWith => {
                     visitor.not_starts_with(reference, literal, predicate)
                 }
+                PredicateOperator::Intersects => visitor.intersects(reference, literal, predicate),
+                PredicateOperator::Disjoint => visitor.disjoint(reference, literal, predicate),
                 op => {
                     panic!("Unexpected op for binary predicate: {}", &op)
                 }
@@ -351,6 +383,24 @@ mod tests {
             Ok(false)
         }
 
+        fn intersects(
+            &mut self,
+            _reference: &Reference,
+            _literal: &Datum,
+            _predicate: &Predicate,
+        ) -> crate::Result<bool> {
+            Ok(false)
+        }
+
+        fn disjoint(
+            &mut self,
+            _reference: &Reference,
+            _literal: &Datum,
+            _predicate: &Predicate,
+        ) -> crate::Result<bool> {
+            Ok(false)
+        }
+
         fn r#in(
             &mut self,
             _reference: &Reference,
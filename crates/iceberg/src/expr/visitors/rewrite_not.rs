@@ -149,6 +149,24 @@ impl PredicateVisitor for RewriteNotVisitor {
         Ok(predicate.clone())
     }
 
+    fn intersects(
+        &mut self,
+        _reference: &Reference,
+        _literal: &Datum,
+        predicate: &Predicate,
+    ) -> Result<Self::T> {
+        Ok(predicate.clone())
+    }
+
+    fn disjoint(
+        &mut self,
+        _reference: &Reference,
+        _literal: &Datum,
+        predicate: &Predicate,
+    ) -> Result<Self::T> {
+        Ok(predicate.clone())
+    }
+
     fn r#in(
         &mut self,
         _reference: &Reference,
@@ -297,6 +315,24 @@ impl BoundPredicateVisitor for RewriteNotVisitor {
         Ok(predicate.clone())
     }
 
+    fn intersects(
+        &mut self,
+        _reference: &BoundReference,
+        _literal: &Datum,
+        predicate: &BoundPredicate,
+    ) -> Result<Self::T> {
+        Ok(predicate.clone())
+    }
+
+    fn disjoint(
+        &mut self,
+        _reference: &BoundReference,
+        _literal: &Datum,
+        predicate: &BoundPredicate,
+    ) -> Result<Self::T> {
+        Ok(predicate.clone())
+    }
+
     fn r#in(
         &mut self,
         _reference: &BoundReference,
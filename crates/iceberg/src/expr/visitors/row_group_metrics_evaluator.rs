@@ -459,6 +459,25 @@ impl BoundPredicateVisitor for RowGroupMetricsEvaluator<'_> {
         ROW_GROUP_MIGHT_MATCH
     }
 
+    fn intersects(
+        &mut self,
+        _reference: &BoundReference,
+        _datum: &Datum,
+        _predicate: &BoundPredicate,
+    ) -> Result<bool> {
+        // Parquet column statistics don't contain bounding boxes.
+        ROW_GROUP_MIGHT_MATCH
+    }
+
+    fn disjoint(
+        &mut self,
+        _reference: &BoundReference,
+        _datum: &Datum,
+        _predicate: &BoundPredicate,
+    ) -> Result<bool> {
+        ROW_GROUP_MIGHT_MATCH
+    }
+
     fn r#in(
         &mut self,
         reference: &BoundReference,
@@ -19,7 +19,7 @@ use fnv::FnvHashSet;
 
 use crate::expr::visitors::bound_predicate_visitor::{BoundPredicateVisitor, visit};
 use crate::expr::{BoundPredicate, BoundReference};
-use crate::spec::{DataFile, Datum};
+use crate::spec::{BoundingBox, DataFile, Datum, PrimitiveLiteral, PrimitiveType};
 use crate::{Error, ErrorKind, Result};
 
 #[allow(dead_code)]
@@ -353,6 +353,54 @@ impl BoundPredicateVisitor for StrictMetricsEvaluator<'_> {
         ROWS_MIGHT_NOT_MATCH
     }
 
+    fn intersects(
+        &mut self,
+        _reference: &BoundReference,
+        _datum: &Datum,
+        _predicate: &BoundPredicate,
+    ) -> crate::Result<bool> {
+        // Overlapping bounding boxes don't imply the values intersect.
+        ROWS_MIGHT_NOT_MATCH
+    }
+
+    fn disjoint(
+        &mut self,
+        reference: &BoundReference,
+        datum: &Datum,
+        _predicate: &BoundPredicate,
+    ) -> crate::Result<bool> {
+        let field_id = reference.field().id;
+
+        if self.may_contain_null(field_id)
+            || !matches!(
+                reference.field().field_type.as_primitive_type(),
+                Some(PrimitiveType::Geometry { .. })
+            )
+        {
+            return ROWS_MIGHT_NOT_MATCH;
+        }
+
+        let (Some(lower_bound), Some(upper_bound)) =
+            (self.lower_bound(field_id), self.upper_bound(field_id))
+        else {
+            return ROWS_MIGHT_NOT_MATCH;
+        };
+
+        let PrimitiveLiteral::Binary(wkb) = datum.literal() else {
+            return ROWS_MIGHT_NOT_MATCH;
+        };
+
+        // All values are disjoint from the geometry if their bounding boxes are.
+        match BoundingBox::try_from_wkb(wkb)? {
+            Some(query)
+                if BoundingBox::try_from_bounds(lower_bound, upper_bound)?.intersects(&query) =>
+            {
+                ROWS_MIGHT_NOT_MATCH
+            }
+            _ => ROWS_MUST_MATCH,
+        }
+    }
+
     fn r#in(
         &mut self,
         reference: &BoundReference,
@@ -227,6 +227,24 @@ impl BoundPredicateVisitor for StrictProjection {
         self.get_parts(reference, predicate)
     }
 
+    fn intersects(
+        &mut self,
+        reference: &BoundReference,
+        _literal: &Datum,
+        predicate: &BoundPredicate,
+    ) -> crate::Result<Self::T> {
+        self.get_parts(reference, predicate)
+    }
+
+    fn disjoint(
+        &mut self,
+        reference: &BoundReference,
+        _literal: &Datum,
+        predicate: &BoundPredicate,
+    ) -> crate::Result<Self::T> {
+        self.get_parts(reference, predicate)
+    }
+
     fn r#in(
         &mut self,
         reference: &BoundReference,
@@ -21,15 +21,22 @@ use std::sync::{Arc, OnceLock};
 use bytes::Bytes;
 
 use super::storage::{
-    LocalFsStorageFactory, MemoryStorageFactory, Storage, StorageConfig, StorageFactory,
+    CredentialScopedStorage, LocalFsStorageFactory, MemoryStorageFactory, Storage, StorageConfig,
+    StorageCredential, StorageCredentialsLoader, StorageFactory,
 };
 use crate::Result;
+use crate::encryption::KeyManagementClient;
 
 /// FileIO implementation, used to manipulate files in underlying storage.
 ///
 /// FileIO wraps a `dyn Storage` with lazy initialization via `StorageFactory`.
 /// The storage is created on first use and cached for subsequent operations.
 ///
+/// A FileIO built with [`StorageCredential`]s uses, for each file, the properties of the
+/// credential with the longest prefix matching its location, and refreshes expiring
+/// credentials with its [`StorageCredentialsLoader`], if any. The loader is not serialized
+/// with the storage, so a deserialized storage fails once its credentials expire.
+///
 /// # Note
 ///
 /// All paths passed to `FileIO` must be absolute paths starting with the scheme string
@@ -70,6 +77,12 @@ pub struct FileIO {
     config: StorageConfig,
     /// Factory for creating storage instances
     factory: Arc<dyn StorageFactory>,
+    /// Storage credentials scoped to location prefixes
+    storage_credentials: Vec<StorageCredential>,
+    /// Loader refreshing the storage credentials
+    credentials_loader: Option<Arc<dyn StorageCredentialsLoader>>,
+    /// Client of the KMS holding the master keys of encrypted tables
+    key_management_client: Option<Arc<dyn KeyManagementClient>>,
     /// Cached storage instance (lazily initialized)
     storage: Arc<OnceLock<Arc<dyn Storage>>>,
 }
@@ -82,6 +95,9 @@ impl FileIO {
         Self {
             config: StorageConfig::new(),
             factory: Arc::new(MemoryStorageFactory),
+            storage_credentials: vec![],
+            credentials_loader: None,
+            key_management_client: None,
             storage: Arc::new(OnceLock::new()),
         }
     }
@@ -93,6 +109,9 @@ impl FileIO {
         Self {
             config: StorageConfig::new(),
             factory: Arc::new(LocalFsStorageFactory),
+            storage_credentials: vec![],
+            credentials_loader: None,
+            key_management_client: None,
             storage: Arc::new(OnceLock::new()),
         }
     }
@@ -102,6 +121,16 @@ impl FileIO {
         &self.config
     }
 
+    /// Get the storage credentials the FileIO was built with.
+    pub fn storage_credentials(&self) -> &[StorageCredential] {
+        &self.storage_credentials
+    }
+
+    /// Get the client of the KMS holding the master keys of encrypted tables.
+    pub fn key_management_client(&self) -> Option<&Arc<dyn KeyManagementClient>> {
+        self.key_management_client.as_ref()
+    }
+
     /// Get or create the storage instance.
     ///
     /// The factory is invoked on first access and the result is cached
@@ -113,7 +142,17 @@ impl FileIO {
         }
 
         // Build the storage
-        let storage = self.factory.build(&self.config)?;
+        let storage: Arc<dyn Storage> =
+            if self.storage_credentials.is_empty() && self.credentials_loader.is_none() {
+                self.factory.build(&self.config)?
+            } else {
+                Arc::new(CredentialScopedStorage::new(
+                    self.factory.clone(),
+                    self.config.clone(),
+                    self.storage_credentials.clone(),
+                    self.credentials_loader.clone(),
+                ))
+            };
 
         // Try to set it (another thread might have set it first)
         let _ = self.storage.set(storage.clone());
@@ -146,6 +185,29 @@ impl FileIO {
         self.get_storage()?.delete_prefix(path.as_ref()).await
     }
 
+    /// Lists the files and directories directly under a directory.
+    ///
+    /// # Arguments
+    ///
+    /// * path: It should be *absolute* path starting with scheme string used to construct [`FileIO`].
+    ///
+    /// # Behavior
+    ///
+    /// - The paths of the entries are `path` joined with their names.
+    /// - If the directory does not exist, no entries are returned.
+    pub async fn list(&self, path: impl AsRef<str>) -> Result<Vec<FileEntry>> {
+        self.get_storage()?.list(path.as_ref()).await
+    }
+
+    /// Creates a directory and its missing parents.
+    ///
+    /// # Arguments
+    ///
+    /// * path: It should be *absolute* path starting with scheme string used to construct [`FileIO`].
+    pub async fn create_dir(&self, path: impl AsRef<str>) -> Result<()> {
+        self.get_storage()?.create_dir(path.as_ref()).await
+    }
+
     /// Check file exists.
     ///
     /// # Arguments
@@ -184,6 +246,12 @@ pub struct FileIOBuilder {
     factory: Arc<dyn StorageFactory>,
     /// Storage configuration
     config: StorageConfig,
+    /// Storage credentials scoped to location prefixes
+    storage_credentials: Vec<StorageCredential>,
+    /// Loader refreshing the storage credentials
+    credentials_loader: Option<Arc<dyn StorageCredentialsLoader>>,
+    /// Client of the KMS holding the master keys of encrypted tables
+    key_management_client: Option<Arc<dyn KeyManagementClient>>,
 }
 
 impl FileIOBuilder {
@@ -192,6 +260,9 @@ impl FileIOBuilder {
         Self {
             factory,
             config: StorageConfig::new(),
+            storage_credentials: vec![],
+            credentials_loader: None,
+            key_management_client: None,
         }
     }
 
@@ -212,6 +283,32 @@ impl FileIOBuilder {
         self
     }
 
+    /// Add storage credentials, used for the locations starting with their prefix.
+    pub fn with_storage_credentials(
+        mut self,
+        credentials: impl IntoIterator<Item = StorageCredential>,
+    ) -> Self {
+        self.storage_credentials.extend(credentials);
+        self
+    }
+
+    /// Set the loader refreshing the storage credentials before they expire.
+    ///
+    /// The loader is not serialized with the storage of the FileIO.
+    pub fn with_storage_credentials_loader(
+        mut self,
+        loader: Arc<dyn StorageCredentialsLoader>,
+    ) -> Self {
+        self.credentials_loader = Some(loader);
+        self
+    }
+
+    /// Set the client of the KMS holding the master keys of encrypted tables.
+    pub fn with_key_management_client(mut self, client: Arc<dyn KeyManagementClient>) -> Self {
+        self.key_management_client = Some(client);
+        self
+    }
+
     /// Get the storage configuration.
     pub fn config(&self) -> &StorageConfig {
         &self.config
@@ -222,6 +319,9 @@ impl FileIOBuilder {
         FileIO {
             config: self.config,
             factory: self.factory,
+            storage_credentials: self.storage_credentials,
+            credentials_loader: self.credentials_loader,
+            key_management_client: self.key_management_client,
             storage: Arc::new(OnceLock::new()),
         }
     }
@@ -235,6 +335,30 @@ pub struct FileMetadata {
     pub size: u64,
 }
 
+/// A file or directory listed by [`FileIO::list`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileEntry {
+    /// The path of the entry, without trailing slash for directories.
+    pub path: String,
+    /// Whether the entry is a directory.
+    pub is_dir: bool,
+}
+
+impl FileEntry {
+    /// Creates an entry named `name` under the directory at `dir`.
+    pub fn new(dir: &str, name: &str, is_dir: bool) -> Self {
+        Self {
+            path: format!("{}/{}", dir.trim_end_matches('/'), name.trim_matches('/')),
+            is_dir,
+        }
+    }
+
+    /// The name of the entry, which is the last segment of its path.
+    pub fn name(&self) -> &str {
+        self.path.rsplit('/').next().unwrap_or(&self.path)
+    }
+}
+
 /// Trait for reading file.
 ///
 /// # TODO
@@ -360,6 +484,17 @@ impl OutputFile {
         self.storage.write(&self.path, bs).await
     }
 
+    /// Create a new output file with given bytes, unless the file already exists.
+    ///
+    /// # Notes
+    ///
+    /// Returns an error of kind [`ErrorKind::PreconditionFailed`](crate::ErrorKind::PreconditionFailed)
+    /// if the file exists, or of kind [`ErrorKind::FeatureUnsupported`](crate::ErrorKind::FeatureUnsupported)
+    /// if the storage can't create files atomically.
+    pub async fn write_if_not_exists(&self, bs: Bytes) -> crate::Result<()> {
+        self.storage.write_if_not_exists(&self.path, bs).await
+    }
+
     /// Creates output file for continuous writing.
     ///
     /// # Notes
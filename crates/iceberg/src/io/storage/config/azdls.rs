@@ -36,6 +36,9 @@ pub const ADLS_ACCOUNT_NAME: &str = "adls.account-name";
 pub const ADLS_ACCOUNT_KEY: &str = "adls.account-key";
 /// The shared access signature.
 pub const ADLS_SAS_TOKEN: &str = "adls.sas-token";
+/// Prefix of the properties holding when the shared access signature of an account
+/// expires, in milliseconds since the epoch, e.g. `adls.sas-token-expires-at-ms.<account>`.
+pub const ADLS_SAS_TOKEN_EXPIRES_AT_MS_PREFIX: &str = "adls.sas-token-expires-at-ms.";
 /// The tenant-id.
 pub const ADLS_TENANT_ID: &str = "adls.tenant-id";
 /// The client-id.
@@ -0,0 +1,560 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Storage credentials scoped to location prefixes, such as the credentials vended by a
+//! catalog for the files of a table.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    ADLS_SAS_TOKEN_EXPIRES_AT_MS_PREFIX, GCS_TOKEN_EXPIRES_AT, S3_SESSION_TOKEN_EXPIRES_AT_MS,
+    Storage, StorageConfig, StorageFactory,
+};
+use crate::io::{FileEntry, FileMetadata, FileRead, FileWrite, InputFile, OutputFile};
+use crate::{Error, ErrorKind, Result};
+
+/// Credentials are refreshed when they expire within this duration.
+const CREDENTIALS_REFRESH_WINDOW: Duration = Duration::from_secs(5 * 60);
+/// Minimum duration between two attempts to refresh the credentials, doubled after each
+/// failed attempt.
+const MIN_REFRESH_INTERVAL: Duration = Duration::from_secs(10);
+/// Maximum duration between two attempts to refresh the credentials.
+const MAX_REFRESH_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+/// Storage configuration, usually holding credentials, for the locations starting with a
+/// prefix.
+///
+/// When several credentials apply to a location, the one with the longest prefix is used.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageCredential {
+    /// The location prefix the credential applies to.
+    pub prefix: String,
+    /// The storage properties of the credential, added to the properties of the
+    /// [`FileIO`](crate::io::FileIO).
+    pub config: HashMap<String, String>,
+}
+
+impl StorageCredential {
+    /// Creates a storage credential for the locations starting with `prefix`.
+    pub fn new(prefix: impl ToString, config: HashMap<String, String>) -> Self {
+        Self {
+            prefix: prefix.to_string(),
+            config,
+        }
+    }
+
+    /// Returns when the credential expires, in milliseconds since the epoch.
+    ///
+    /// The expiration is read from the `s3.session-token-expires-at-ms`,
+    /// `adls.sas-token-expires-at-ms.<account>` and `gcs.oauth2.token-expires-at`
+    /// properties. The earliest one is used.
+    pub fn expires_at_ms(&self) -> Option<i64> {
+        self.config
+            .iter()
+            .filter(|(key, _)| {
+                key.as_str() == S3_SESSION_TOKEN_EXPIRES_AT_MS
+                    || key.as_str() == GCS_TOKEN_EXPIRES_AT
+                    || key.starts_with(ADLS_SAS_TOKEN_EXPIRES_AT_MS_PREFIX)
+            })
+            .filter_map(|(_, value)| value.parse().ok())
+            .min()
+    }
+}
+
+/// Loads fresh storage credentials, typically from the catalog that vended them.
+///
+/// A [`FileIO`](crate::io::FileIO) with a loader refreshes its credentials shortly before
+/// they expire.
+///
+/// The loader is not serialized with the storage of the `FileIO`, e.g. when it's shipped to
+/// workers. A deserialized storage can't refresh its credentials, and fails the operations on
+/// files whose credential has expired.
+#[async_trait]
+pub trait StorageCredentialsLoader: Debug + Send + Sync {
+    /// Loads the current storage credentials.
+    async fn load_credentials(&self) -> Result<Vec<StorageCredential>>;
+}
+
+type StoragesByPrefix = HashMap<Option<String>, Arc<dyn Storage>>;
+
+/// A [`Storage`] choosing the [`StorageCredential`] to use by the location of each file.
+///
+/// A storage is built by the factory for each credential, on first use.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CredentialScopedStorage {
+    factory: Arc<dyn StorageFactory>,
+    config: StorageConfig,
+    credentials: Arc<RwLock<Vec<StorageCredential>>>,
+    /// Storages built for each credential prefix, or for `None` when no credential applies.
+    #[serde(skip)]
+    storages: Arc<RwLock<StoragesByPrefix>>,
+    #[serde(skip)]
+    loader: Option<Arc<dyn StorageCredentialsLoader>>,
+    /// Whether the credentials were refreshed by a loader, which is lost when the storage is
+    /// serialized.
+    #[serde(default)]
+    refreshable: bool,
+    /// The last attempt to refresh the credentials, held while refreshing them so that
+    /// concurrent operations refresh them once.
+    #[serde(skip)]
+    last_refresh: Arc<tokio::sync::Mutex<Option<RefreshAttempt>>>,
+}
+
+/// An attempt to refresh the credentials, used to space out the following attempts when
+/// refreshing fails or doesn't extend the credentials beyond the refresh window.
+#[derive(Debug, Clone, Copy)]
+struct RefreshAttempt {
+    at: Instant,
+    consecutive_failures: u32,
+}
+
+impl RefreshAttempt {
+    /// Whether the credentials may be refreshed again, backing off exponentially after
+    /// failed attempts.
+    fn allows_refresh(&self) -> bool {
+        let interval = MIN_REFRESH_INTERVAL
+            .saturating_mul(2u32.saturating_pow(self.consecutive_failures))
+            .min(MAX_REFRESH_INTERVAL);
+        self.at.elapsed() >= interval
+    }
+}
+
+impl CredentialScopedStorage {
+    pub(crate) fn new(
+        factory: Arc<dyn StorageFactory>,
+        config: StorageConfig,
+        credentials: Vec<StorageCredential>,
+        loader: Option<Arc<dyn StorageCredentialsLoader>>,
+    ) -> Self {
+        Self {
+            factory,
+            config,
+            credentials: Arc::new(RwLock::new(credentials)),
+            storages: Arc::default(),
+            refreshable: loader.is_some(),
+            loader,
+            last_refresh: Arc::default(),
+        }
+    }
+
+    /// Returns the credential with the longest prefix matching `path`.
+    fn credential_for(&self, path: &str) -> Result<Option<StorageCredential>> {
+        let credentials = self.credentials.read().map_err(lock_error)?;
+        Ok(credentials
+            .iter()
+            .filter(|credential| path.starts_with(&credential.prefix))
+            .max_by_key(|credential| credential.prefix.len())
+            .cloned())
+    }
+
+    /// Returns the storage to use for `path`, refreshing its credential first if it's about
+    /// to expire.
+    async fn storage_for(&self, path: &str) -> Result<Arc<dyn Storage>> {
+        self.refresh_expiring_credential(path).await?;
+
+        let credential = self.credential_for(path)?;
+        let key = credential.as_ref().map(|c| c.prefix.clone());
+        if let Some(storage) = self.storages.read().map_err(lock_error)?.get(&key) {
+            return Ok(storage.clone());
+        }
+
+        let mut config = self.config.clone();
+        if let Some(credential) = credential {
+            config = config.with_props(credential.config);
+        }
+        let storage = self.factory.build(&config)?;
+
+        Ok(self
+            .storages
+            .write()
+            .map_err(lock_error)?
+            .entry(key)
+            .or_insert(storage)
+            .clone())
+    }
+
+    async fn refresh_expiring_credential(&self, path: &str) -> Result<()> {
+        let Some(loader) = &self.loader else {
+            if self.refreshable && self.is_expired(path)? {
+                return Err(Error::new(
+                    ErrorKind::Unexpected,
+                    format!(
+                        "Storage credential for {path} has expired and can't be refreshed, \
+                         since the loader refreshing it is not kept when the storage is serialized"
+                    ),
+                ));
+            }
+            return Ok(());
+        };
+        if !self.is_expiring(path)? {
+            return Ok(());
+        }
+
+        let mut last_refresh = self.last_refresh.lock().await;
+        // Another operation may have refreshed the credentials while waiting for the lock.
+        if !self.is_expiring(path)? || last_refresh.is_some_and(|attempt| !attempt.allows_refresh())
+        {
+            return Ok(());
+        }
+
+        let consecutive_failures = match loader.load_credentials().await {
+            Ok(credentials) => {
+                *self.credentials.write().map_err(lock_error)? = credentials;
+                self.storages.write().map_err(lock_error)?.clear();
+                0
+            }
+            // The current credentials may still be valid for a while.
+            Err(e) => {
+                tracing::warn!("Failed to refresh storage credentials: {e}");
+                last_refresh.map_or(1, |attempt| attempt.consecutive_failures + 1)
+            }
+        };
+        *last_refresh = Some(RefreshAttempt {
+            at: Instant::now(),
+            consecutive_failures,
+        });
+
+        Ok(())
+    }
+
+    fn is_expiring(&self, path: &str) -> Result<bool> {
+        self.expires_within(path, CREDENTIALS_REFRESH_WINDOW)
+    }
+
+    fn is_expired(&self, path: &str) -> Result<bool> {
+        self.expires_within(path, Duration::ZERO)
+    }
+
+    fn expires_within(&self, path: &str, duration: Duration) -> Result<bool> {
+        let expires_at_ms = self
+            .credential_for(path)?
+            .and_then(|credential| credential.expires_at_ms());
+
+        Ok(expires_at_ms.is_some_and(|expires_at_ms| {
+            expires_at_ms - chrono::Utc::now().timestamp_millis() <= duration.as_millis() as i64
+        }))
+    }
+}
+
+fn lock_error(e: impl std::fmt::Display) -> Error {
+    Error::new(
+        ErrorKind::Unexpected,
+        format!("Failed to acquire storage credentials lock: {e}"),
+    )
+}
+
+#[async_trait]
+#[typetag::serde]
+impl Storage for CredentialScopedStorage {
+    async fn exists(&self, path: &str) -> Result<bool> {
+        self.storage_for(path).await?.exists(path).await
+    }
+
+    async fn metadata(&self, path: &str) -> Result<FileMetadata> {
+        self.storage_for(path).await?.metadata(path).await
+    }
+
+    async fn read(&self, path: &str) -> Result<Bytes> {
+        self.storage_for(path).await?.read(path).await
+    }
+
+    async fn reader(&self, path: &str) -> Result<Box<dyn FileRead>> {
+        self.storage_for(path).await?.reader(path).await
+    }
+
+    async fn write(&self, path: &str, bs: Bytes) -> Result<()> {
+        self.storage_for(path).await?.write(path, bs).await
+    }
+
+    async fn writer(&self, path: &str) -> Result<Box<dyn FileWrite>> {
+        self.storage_for(path).await?.writer(path).await
+    }
+
+    async fn delete(&self, path: &str) -> Result<()> {
+        self.storage_for(path).await?.delete(path).await
+    }
+
+    async fn delete_prefix(&self, path: &str) -> Result<()> {
+        self.storage_for(path).await?.delete_prefix(path).await
+    }
+
+    async fn list(&self, path: &str) -> Result<Vec<FileEntry>> {
+        self.storage_for(path).await?.list(path).await
+    }
+
+    async fn create_dir(&self, path: &str) -> Result<()> {
+        self.storage_for(path).await?.create_dir(path).await
+    }
+
+    async fn write_if_not_exists(&self, path: &str, bs: Bytes) -> Result<()> {
+        self.storage_for(path)
+            .await?
+            .write_if_not_exists(path, bs)
+            .await
+    }
+
+    fn new_input(&self, path: &str) -> Result<InputFile> {
+        Ok(InputFile::new(Arc::new(self.clone()), path.to_string()))
+    }
+
+    fn new_output(&self, path: &str) -> Result<OutputFile> {
+        Ok(OutputFile::new(Arc::new(self.clone()), path.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    use async_trait::async_trait;
+    use serde::{Deserialize, Serialize};
+
+    use super::{CredentialScopedStorage, StorageCredential, StorageCredentialsLoader};
+    use crate::io::{FileIOBuilder, MemoryStorage, Storage, StorageConfig, StorageFactory};
+    use crate::{Error, ErrorKind, Result};
+
+    /// A factory building memory storages and recording the configs it's called with.
+    #[derive(Debug, Default, Serialize, Deserialize)]
+    struct RecordingStorageFactory {
+        #[serde(skip)]
+        configs: Arc<Mutex<Vec<HashMap<String, String>>>>,
+    }
+
+    #[typetag::serde]
+    impl StorageFactory for RecordingStorageFactory {
+        fn build(&self, config: &StorageConfig) -> Result<Arc<dyn Storage>> {
+            self.configs.lock().unwrap().push(config.props().clone());
+            Ok(Arc::new(MemoryStorage::new()))
+        }
+    }
+
+    /// A loader returning `credentials`, or failing if there are none.
+    #[derive(Debug)]
+    struct TestCredentialsLoader {
+        credentials: Vec<StorageCredential>,
+        calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl StorageCredentialsLoader for TestCredentialsLoader {
+        async fn load_credentials(&self) -> Result<Vec<StorageCredential>> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            if self.credentials.is_empty() {
+                return Err(Error::new(
+                    ErrorKind::Unexpected,
+                    "Failed to load credentials",
+                ));
+            }
+            Ok(self.credentials.clone())
+        }
+    }
+
+    fn credential(prefix: &str, props: &[(&str, &str)]) -> StorageCredential {
+        StorageCredential::new(
+            prefix,
+            props
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_expires_at_ms() {
+        let adls_credential = credential("abfss://container@account/", &[
+            ("adls.sas-token-expires-at-ms.account", "1500"),
+            ("custom.expires-at", "1000"),
+        ]);
+        assert_eq!(adls_credential.expires_at_ms(), Some(1500));
+
+        let credential = credential("s3://bucket/", &[
+            ("s3.access-key-id", "key"),
+            ("s3.session-token-expires-at-ms", "2000"),
+            ("gcs.oauth2.token-expires-at", "1000"),
+            ("adls.sas-token-expires-at-ms.account", "1500"),
+        ]);
+        assert_eq!(credential.expires_at_ms(), Some(1000));
+
+        let credential = StorageCredential::new("s3://bucket/", HashMap::new());
+        assert_eq!(credential.expires_at_ms(), None);
+    }
+
+    #[tokio::test]
+    async fn test_longest_prefix_credential_is_used() {
+        let factory = Arc::new(RecordingStorageFactory::default());
+        let configs = factory.configs.clone();
+        let file_io = FileIOBuilder::new(factory)
+            .with_prop("region", "us-east-1")
+            .with_storage_credentials([
+                credential("s3://bucket/", &[("token", "bucket")]),
+                credential("s3://bucket/table/", &[("token", "table")]),
+            ])
+            .build();
+
+        file_io
+            .new_output("s3://bucket/table/data/1.parquet")
+            .unwrap()
+            .write("data".into())
+            .await
+            .unwrap();
+        file_io
+            .new_output("s3://bucket/table/data/2.parquet")
+            .unwrap()
+            .write("data".into())
+            .await
+            .unwrap();
+        file_io.exists("s3://bucket/other/1.parquet").await.unwrap();
+        file_io.exists("s3://other/1.parquet").await.unwrap();
+
+        let token = |config: &HashMap<String, String>| config.get("token").cloned();
+        let configs = configs.lock().unwrap();
+        // A storage is built once for each credential.
+        assert_eq!(configs.iter().map(token).collect::<Vec<_>>(), vec![
+            Some("table".to_string()),
+            Some("bucket".to_string()),
+            None,
+        ]);
+        assert!(
+            configs
+                .iter()
+                .all(|config| config.get("region") == Some(&"us-east-1".to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_expiring_credentials_are_refreshed() {
+        let now = chrono::Utc::now().timestamp_millis();
+        let expires_soon = (now + 1000).to_string();
+        let expires_later = (now + 3_600_000).to_string();
+
+        let factory = Arc::new(RecordingStorageFactory::default());
+        let configs = factory.configs.clone();
+        let loader = Arc::new(TestCredentialsLoader {
+            credentials: vec![credential("s3://bucket/", &[
+                ("token", "refreshed"),
+                ("s3.session-token-expires-at-ms", &expires_later),
+            ])],
+            calls: AtomicUsize::new(0),
+        });
+        let file_io = FileIOBuilder::new(factory)
+            .with_storage_credentials([credential("s3://bucket/", &[
+                ("token", "expiring"),
+                ("s3.session-token-expires-at-ms", &expires_soon),
+            ])])
+            .with_storage_credentials_loader(loader.clone())
+            .build();
+
+        file_io.exists("s3://bucket/1.parquet").await.unwrap();
+        file_io.exists("s3://bucket/2.parquet").await.unwrap();
+
+        assert_eq!(loader.calls.load(Ordering::SeqCst), 1);
+        let configs = configs.lock().unwrap();
+        assert_eq!(configs.len(), 1);
+        assert_eq!(configs[0].get("token"), Some(&"refreshed".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_failed_refresh_is_not_retried_immediately() {
+        let expires_soon = (chrono::Utc::now().timestamp_millis() + 1000).to_string();
+
+        let loader = Arc::new(TestCredentialsLoader {
+            credentials: vec![],
+            calls: AtomicUsize::new(0),
+        });
+        let file_io = FileIOBuilder::new(Arc::new(RecordingStorageFactory::default()))
+            .with_storage_credentials([credential("s3://bucket/", &[(
+                "s3.session-token-expires-at-ms",
+                &expires_soon,
+            )])])
+            .with_storage_credentials_loader(loader.clone())
+            .build();
+
+        file_io.exists("s3://bucket/1.parquet").await.unwrap();
+        file_io.exists("s3://bucket/2.parquet").await.unwrap();
+
+        assert_eq!(loader.calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_short_lived_credentials_are_not_refreshed_immediately() {
+        let expires_soon = (chrono::Utc::now().timestamp_millis() + 1000).to_string();
+
+        // The refreshed credentials expire within the refresh window too
+        let loader = Arc::new(TestCredentialsLoader {
+            credentials: vec![credential("s3://bucket/", &[(
+                "s3.session-token-expires-at-ms",
+                &expires_soon,
+            )])],
+            calls: AtomicUsize::new(0),
+        });
+        let file_io = FileIOBuilder::new(Arc::new(RecordingStorageFactory::default()))
+            .with_storage_credentials(loader.credentials.clone())
+            .with_storage_credentials_loader(loader.clone())
+            .build();
+
+        file_io.exists("s3://bucket/1.parquet").await.unwrap();
+        file_io.exists("s3://bucket/2.parquet").await.unwrap();
+
+        assert_eq!(loader.calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_deserialized_storage_fails_on_expired_credentials() {
+        let now = chrono::Utc::now().timestamp_millis();
+        let expired = (now - 1000).to_string();
+        let expires_later = (now + 3_600_000).to_string();
+
+        let loader = Arc::new(TestCredentialsLoader {
+            credentials: vec![],
+            calls: AtomicUsize::new(0),
+        });
+        let storage: Box<dyn Storage> = Box::new(CredentialScopedStorage::new(
+            Arc::new(RecordingStorageFactory::default()),
+            StorageConfig::new(),
+            vec![
+                credential("s3://expired/", &[(
+                    "s3.session-token-expires-at-ms",
+                    &expired,
+                )]),
+                credential("s3://valid/", &[(
+                    "s3.session-token-expires-at-ms",
+                    &expires_later,
+                )]),
+            ],
+            Some(loader),
+        ));
+
+        let serialized = serde_json::to_string(&storage).unwrap();
+        let storage: Box<dyn Storage> = serde_json::from_str(&serialized).unwrap();
+
+        storage.exists("s3://valid/1.parquet").await.unwrap();
+        let err = storage.exists("s3://expired/1.parquet").await.unwrap_err();
+        assert!(
+            err.message().contains("has expired and can't be refreshed"),
+            "unexpected error: {err}"
+        );
+    }
+}
@@ -22,17 +22,19 @@
 //! scenarios where tests need to read/write files on the local filesystem.
 
 use std::fs;
-use std::io::{Read, Seek, SeekFrom, Write};
+use std::io::{ErrorKind as IoErrorKind, Read, Seek, SeekFrom, Write};
 use std::ops::Range;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use async_trait::async_trait;
 use bytes::Bytes;
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
+use super::file_exists_error;
 use crate::io::{
-    FileMetadata, FileRead, FileWrite, InputFile, OutputFile, Storage, StorageConfig,
+    FileEntry, FileMetadata, FileRead, FileWrite, InputFile, OutputFile, Storage, StorageConfig,
     StorageFactory,
 };
 use crate::{Error, ErrorKind, Result};
@@ -200,6 +202,88 @@ impl Storage for LocalFsStorage {
         Ok(())
     }
 
+    async fn list(&self, path: &str) -> Result<Vec<FileEntry>> {
+        let dir = Self::normalize_path(path);
+        let read_dir = match fs::read_dir(&dir) {
+            Ok(read_dir) => read_dir,
+            Err(e) if e.kind() == IoErrorKind::NotFound => return Ok(vec![]),
+            Err(e) => {
+                return Err(Error::new(
+                    ErrorKind::Unexpected,
+                    format!("Failed to list directory {}: {}", dir.display(), e),
+                ));
+            }
+        };
+
+        let mut entries = read_dir
+            .map(|entry| {
+                let entry = entry.map_err(|e| {
+                    Error::new(
+                        ErrorKind::Unexpected,
+                        format!("Failed to list directory {}: {}", dir.display(), e),
+                    )
+                })?;
+                let is_dir = entry.file_type().is_ok_and(|file_type| file_type.is_dir());
+                Ok(FileEntry::new(
+                    path,
+                    &entry.file_name().to_string_lossy(),
+                    is_dir,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        entries.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok(entries)
+    }
+
+    async fn create_dir(&self, path: &str) -> Result<()> {
+        let path = Self::normalize_path(path);
+        fs::create_dir_all(&path).map_err(|e| {
+            Error::new(
+                ErrorKind::Unexpected,
+                format!("Failed to create directory {}: {}", path.display(), e),
+            )
+        })
+    }
+
+    async fn write_if_not_exists(&self, path: &str, bs: Bytes) -> Result<()> {
+        let normalized = Self::normalize_path(path);
+
+        // Create parent directories if they don't exist
+        let parent = normalized.parent().unwrap_or_else(|| Path::new("."));
+        fs::create_dir_all(parent).map_err(|e| {
+            Error::new(
+                ErrorKind::Unexpected,
+                format!("Failed to create directory {}: {}", parent.display(), e),
+            )
+        })?;
+
+        // The content is written to a temporary file first, so that readers never see a
+        // partially written file.
+        let file_name = normalized
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let temp_path = parent.join(format!(".{file_name}.{}.tmp", Uuid::new_v4()));
+        fs::write(&temp_path, &bs).map_err(|e| {
+            Error::new(
+                ErrorKind::Unexpected,
+                format!("Failed to write file {}: {}", temp_path.display(), e),
+            )
+        })?;
+
+        // Linking fails atomically if the file exists, unlike renaming which replaces it.
+        let result = match fs::hard_link(&temp_path, &normalized) {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == IoErrorKind::AlreadyExists => Err(file_exists_error(path)),
+            Err(e) => Err(Error::new(
+                ErrorKind::Unexpected,
+                format!("Failed to create file {}: {}", normalized.display(), e),
+            )),
+        };
+        let _ = fs::remove_file(&temp_path);
+        result
+    }
+
     fn new_input(&self, path: &str) -> Result<InputFile> {
         Ok(InputFile::new(Arc::new(self.clone()), path.to_string()))
     }
@@ -446,6 +530,55 @@ mod tests {
         assert!(!dir_path.exists());
     }
 
+    #[tokio::test]
+    async fn test_local_fs_storage_list() {
+        let tmp_dir = TempDir::new().unwrap();
+        let storage = LocalFsStorage::new();
+        let dir = format!("file://{}", tmp_dir.path().to_str().unwrap());
+
+        storage
+            .write(&format!("{dir}/file.txt"), Bytes::from("1"))
+            .await
+            .unwrap();
+        storage.create_dir(&format!("{dir}/sub")).await.unwrap();
+
+        assert_eq!(storage.list(&dir).await.unwrap(), vec![
+            FileEntry::new(&dir, "file.txt", false),
+            FileEntry::new(&dir, "sub", true),
+        ]);
+        assert!(
+            storage
+                .list(&format!("{dir}/missing"))
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_local_fs_storage_write_if_not_exists() {
+        let tmp_dir = TempDir::new().unwrap();
+        let storage = LocalFsStorage::new();
+        let path = tmp_dir.path().join("dir").join("test.txt");
+        let path_str = path.to_str().unwrap();
+
+        storage
+            .write_if_not_exists(path_str, Bytes::from("1"))
+            .await
+            .unwrap();
+
+        let err = storage
+            .write_if_not_exists(path_str, Bytes::from("2"))
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::PreconditionFailed);
+        assert_eq!(storage.read(path_str).await.unwrap(), Bytes::from("1"));
+
+        // No temporary files are left behind.
+        let entries = fs::read_dir(tmp_dir.path().join("dir")).unwrap().count();
+        assert_eq!(entries, 1);
+    }
+
     #[tokio::test]
     async fn test_local_fs_storage_reader() {
         let tmp_dir = TempDir::new().unwrap();
@@ -22,7 +22,7 @@
 //! It is primarily intended for unit testing and scenarios where persistent
 //! storage is not needed.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::ops::Range;
 use std::sync::{Arc, RwLock};
 
@@ -30,8 +30,9 @@ use async_trait::async_trait;
 use bytes::Bytes;
 use serde::{Deserialize, Serialize};
 
+use super::file_exists_error;
 use crate::io::{
-    FileMetadata, FileRead, FileWrite, InputFile, OutputFile, Storage, StorageConfig,
+    FileEntry, FileMetadata, FileRead, FileWrite, InputFile, OutputFile, Storage, StorageConfig,
     StorageFactory,
 };
 use crate::{Error, ErrorKind, Result};
@@ -220,6 +221,68 @@ impl Storage for MemoryStorage {
         Ok(())
     }
 
+    async fn list(&self, path: &str) -> Result<Vec<FileEntry>> {
+        let normalized = Self::normalize_path(path);
+        let prefix = if normalized.is_empty() || normalized.ends_with('/') {
+            normalized
+        } else {
+            format!("{normalized}/")
+        };
+
+        let data = self.data.read().map_err(|e| {
+            Error::new(
+                ErrorKind::Unexpected,
+                format!("Failed to acquire read lock: {e}"),
+            )
+        })?;
+
+        // Keys ending with a slash are markers of directories created with `create_dir`.
+        let mut entries = BTreeMap::new();
+        for key in data.keys() {
+            let Some(rest) = key.strip_prefix(&prefix) else {
+                continue;
+            };
+            match rest.split_once('/') {
+                Some((name, _)) => entries.insert(name.to_string(), true),
+                None if !rest.is_empty() => entries.insert(rest.to_string(), false),
+                None => None,
+            };
+        }
+
+        Ok(entries
+            .into_iter()
+            .map(|(name, is_dir)| FileEntry::new(path, &name, is_dir))
+            .collect())
+    }
+
+    async fn create_dir(&self, path: &str) -> Result<()> {
+        let normalized = Self::normalize_path(path);
+        let marker = format!("{}/", normalized.trim_end_matches('/'));
+        let mut data = self.data.write().map_err(|e| {
+            Error::new(
+                ErrorKind::Unexpected,
+                format!("Failed to acquire write lock: {e}"),
+            )
+        })?;
+        data.entry(marker).or_default();
+        Ok(())
+    }
+
+    async fn write_if_not_exists(&self, path: &str, bs: Bytes) -> Result<()> {
+        let normalized = Self::normalize_path(path);
+        let mut data = self.data.write().map_err(|e| {
+            Error::new(
+                ErrorKind::Unexpected,
+                format!("Failed to acquire write lock: {e}"),
+            )
+        })?;
+        if data.contains_key(&normalized) {
+            return Err(file_exists_error(path));
+        }
+        data.insert(normalized, bs);
+        Ok(())
+    }
+
     fn new_input(&self, path: &str) -> Result<InputFile> {
         Ok(InputFile::new(Arc::new(self.clone()), path.to_string()))
     }
@@ -463,6 +526,47 @@ mod tests {
         assert!(storage.exists("memory://other/file.txt").await.unwrap());
     }
 
+    #[tokio::test]
+    async fn test_memory_storage_list() {
+        let storage = MemoryStorage::new();
+        storage
+            .write("memory://dir/file.txt", Bytes::from("1"))
+            .await
+            .unwrap();
+        storage
+            .write("memory://dir/sub/file.txt", Bytes::from("2"))
+            .await
+            .unwrap();
+        storage.create_dir("memory://dir/empty").await.unwrap();
+
+        assert_eq!(storage.list("memory://dir").await.unwrap(), vec![
+            FileEntry::new("memory://dir", "empty", true),
+            FileEntry::new("memory://dir", "file.txt", false),
+            FileEntry::new("memory://dir", "sub", true),
+        ]);
+        assert!(storage.list("memory://dir/empty").await.unwrap().is_empty());
+        assert!(storage.list("memory://missing").await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_memory_storage_write_if_not_exists() {
+        let storage = MemoryStorage::new();
+        storage
+            .write_if_not_exists("memory://file.txt", Bytes::from("1"))
+            .await
+            .unwrap();
+
+        let err = storage
+            .write_if_not_exists("memory://file.txt", Bytes::from("2"))
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::PreconditionFailed);
+        assert_eq!(
+            storage.read("memory://file.txt").await.unwrap(),
+            Bytes::from("1")
+        );
+    }
+
     #[tokio::test]
     async fn test_memory_storage_reader() {
         let storage = MemoryStorage::new();
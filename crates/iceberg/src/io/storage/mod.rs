@@ -18,6 +18,7 @@
 //! Storage interfaces for Iceberg.
 
 mod config;
+mod credentials;
 mod local_fs;
 mod memory;
 
@@ -27,11 +28,13 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use bytes::Bytes;
 pub use config::*;
+pub(crate) use credentials::CredentialScopedStorage;
+pub use credentials::{StorageCredential, StorageCredentialsLoader};
 pub use local_fs::{LocalFsStorage, LocalFsStorageFactory};
 pub use memory::{MemoryStorage, MemoryStorageFactory};
 
-use super::{FileMetadata, FileRead, FileWrite, InputFile, OutputFile};
-use crate::Result;
+use super::{FileEntry, FileMetadata, FileRead, FileWrite, InputFile, OutputFile};
+use crate::{Error, ErrorKind, Result};
 
 /// Trait for storage operations in Iceberg.
 ///
@@ -93,6 +96,40 @@ pub trait Storage: Debug + Send + Sync {
     /// Delete all files with the given prefix
     async fn delete_prefix(&self, path: &str) -> Result<()>;
 
+    /// List the files and directories directly under the directory at the given path.
+    ///
+    /// Listing a directory which doesn't exist returns no entries. Storages without
+    /// directories report the common prefixes of the objects under the path as directories.
+    ///
+    /// The default implementation returns [`ErrorKind::FeatureUnsupported`].
+    async fn list(&self, path: &str) -> Result<Vec<FileEntry>> {
+        Err(Error::new(
+            ErrorKind::FeatureUnsupported,
+            format!("Listing is not supported by this storage: {path}"),
+        ))
+    }
+
+    /// Create a directory and its missing parents at the given path.
+    ///
+    /// The default implementation does nothing, which suits storages creating
+    /// directories implicitly when files are written.
+    async fn create_dir(&self, _path: &str) -> Result<()> {
+        Ok(())
+    }
+
+    /// Atomically write bytes to an output path unless a file already exists there, in which
+    /// case [`ErrorKind::PreconditionFailed`] is returned.
+    ///
+    /// The default implementation returns [`ErrorKind::FeatureUnsupported`], as checking
+    /// whether the file exists before writing it is not atomic. Storages supporting
+    /// conditional writes should override it.
+    async fn write_if_not_exists(&self, path: &str, _bs: Bytes) -> Result<()> {
+        Err(Error::new(
+            ErrorKind::FeatureUnsupported,
+            format!("Conditional writes are not supported by this storage: {path}"),
+        ))
+    }
+
     /// Create a new input file for reading
     fn new_input(&self, path: &str) -> Result<InputFile>;
 
@@ -100,6 +137,14 @@ pub trait Storage: Debug + Send + Sync {
     fn new_output(&self, path: &str) -> Result<OutputFile>;
 }
 
+/// The error returned by [`Storage::write_if_not_exists`] when the file already exists.
+pub(crate) fn file_exists_error(path: &str) -> Error {
+    Error::new(
+        ErrorKind::PreconditionFailed,
+        format!("File already exists: {path}"),
+    )
+}
+
 /// Factory for creating Storage instances from configuration.
 ///
 /// Implement this trait to provide custom storage backends. The factory pattern
@@ -73,15 +73,19 @@ mod catalog;
 
 pub use catalog::*;
 
+pub mod actions;
 pub mod table;
+pub mod view;
 
 mod avro;
 pub mod cache;
 pub mod compression;
+pub mod encryption;
 pub mod io;
 pub mod spec;
 
 pub mod inspect;
+pub mod metrics;
 pub mod scan;
 
 pub mod expr;
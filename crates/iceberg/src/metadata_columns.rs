@@ -26,7 +26,7 @@ use std::sync::Arc;
 
 use once_cell::sync::Lazy;
 
-use crate::spec::{NestedField, NestedFieldRef, PrimitiveType, Type};
+use crate::spec::{NestedField, NestedFieldRef, PrimitiveType, Schema, Type};
 use crate::{Error, ErrorKind, Result};
 
 /// Reserved field ID for the file path (_file) column per Iceberg spec
@@ -221,10 +221,11 @@ static COMMIT_SNAPSHOT_ID_FIELD: Lazy<NestedFieldRef> = Lazy::new(|| {
 });
 
 /// Lazy-initialized Iceberg field definition for the _row_id metadata column.
-/// This field represents a unique long assigned for row lineage.
+/// This field represents a unique long assigned for row lineage. It is optional
+/// because rows written before row lineage was enabled have no row id.
 static ROW_ID_FIELD: Lazy<NestedFieldRef> = Lazy::new(|| {
     Arc::new(
-        NestedField::required(
+        NestedField::optional(
             RESERVED_FIELD_ID_ROW_ID,
             RESERVED_COL_NAME_ROW_ID,
             Type::Primitive(PrimitiveType::Long),
@@ -237,7 +238,7 @@ static ROW_ID_FIELD: Lazy<NestedFieldRef> = Lazy::new(|| {
 /// This field represents the sequence number which last updated this row.
 static LAST_UPDATED_SEQUENCE_NUMBER_FIELD: Lazy<NestedFieldRef> = Lazy::new(|| {
     Arc::new(
-        NestedField::required(
+        NestedField::optional(
             RESERVED_FIELD_ID_LAST_UPDATED_SEQUENCE_NUMBER,
             RESERVED_COL_NAME_LAST_UPDATED_SEQUENCE_NUMBER,
             Type::Primitive(PrimitiveType::Long),
@@ -478,6 +479,47 @@ pub fn is_metadata_field(field_id: i32) -> bool {
     )
 }
 
+/// Checks if a field ID is one of the v3 row lineage fields
+/// (`_row_id` or `_last_updated_sequence_number`).
+///
+/// Unlike the other metadata columns, row lineage fields may be stored in data files.
+pub fn is_row_lineage_field(field_id: i32) -> bool {
+    matches!(
+        field_id,
+        RESERVED_FIELD_ID_ROW_ID | RESERVED_FIELD_ID_LAST_UPDATED_SEQUENCE_NUMBER
+    )
+}
+
+/// Returns a copy of `schema` with the row lineage fields appended.
+///
+/// This is the schema of the files written by writers that rewrite existing rows (e.g.
+/// compaction or row-level updates), which persist the `_row_id` and
+/// `_last_updated_sequence_number` read from the source files instead of reassigning them.
+/// The [`ParquetWriter`](crate::writer::file_writer::ParquetWriter) extends its schema with
+/// these fields when the batches it writes carry them. Rows changed by a row-level update
+/// should first go through
+/// [`mark_rows_updated`](crate::arrow::row_lineage::mark_rows_updated).
+///
+/// # Arguments
+/// * `schema` - The table schema
+///
+/// # Returns
+/// The table schema extended with the `_row_id` and `_last_updated_sequence_number` fields
+pub fn schema_with_row_lineage(schema: &Schema) -> Result<Schema> {
+    let mut fields = schema.as_struct().fields().to_vec();
+    for lineage_field in [row_id_field(), last_updated_sequence_number_field()] {
+        if schema.field_by_id(lineage_field.id).is_none() {
+            fields.push(lineage_field.clone());
+        }
+    }
+
+    Schema::builder()
+        .with_schema_id(schema.schema_id())
+        .with_identifier_field_ids(schema.identifier_field_ids())
+        .with_fields(fields)
+        .build()
+}
+
 /// Checks if a column name is a metadata column.
 ///
 /// # Arguments
@@ -569,4 +611,36 @@ mod tests {
         assert!(get_metadata_field(RESERVED_FIELD_ID_ROW_ID).is_ok());
         assert!(get_metadata_field(RESERVED_FIELD_ID_LAST_UPDATED_SEQUENCE_NUMBER).is_ok());
     }
+
+    #[test]
+    fn test_schema_with_row_lineage() {
+        let schema = Schema::builder()
+            .with_schema_id(3)
+            .with_fields(vec![Arc::new(NestedField::required(
+                1,
+                "id",
+                Type::Primitive(PrimitiveType::Long),
+            ))])
+            .build()
+            .unwrap();
+
+        let lineage_schema = schema_with_row_lineage(&schema).unwrap();
+        assert_eq!(lineage_schema.schema_id(), 3);
+        assert_eq!(lineage_schema.as_struct().fields().len(), 3);
+        let row_id = lineage_schema
+            .field_by_id(RESERVED_FIELD_ID_ROW_ID)
+            .unwrap();
+        assert!(!row_id.required);
+        assert!(
+            lineage_schema
+                .field_by_id(RESERVED_FIELD_ID_LAST_UPDATED_SEQUENCE_NUMBER)
+                .is_some()
+        );
+
+        // Applying it twice does not duplicate the fields
+        let lineage_schema = schema_with_row_lineage(&lineage_schema).unwrap();
+        assert_eq!(lineage_schema.as_struct().fields().len(), 3);
+        assert!(is_row_lineage_field(RESERVED_FIELD_ID_ROW_ID));
+        assert!(!is_row_lineage_field(RESERVED_FIELD_ID_FILE));
+    }
 }
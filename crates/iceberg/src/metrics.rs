@@ -0,0 +1,299 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Metrics reported by table scans and commits.
+//!
+//! A [`Table`](crate::table::Table) with a [`MetricsReporter`] sends a [`ScanReport`] once
+//! the file scan tasks planned by [`TableScan::plan_files`](crate::scan::TableScan::plan_files)
+//! have been consumed, and a [`CommitReport`] when
+//! [`Transaction::commit`](crate::transaction::Transaction::commit) creates a snapshot.
+
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use crate::TableIdent;
+use crate::expr::Predicate;
+use crate::spec::{
+    ADDED_DATA_FILES, ADDED_DELETE_FILES, ADDED_FILE_SIZE, ADDED_RECORDS, DELETED_DATA_FILES,
+    DELETED_RECORDS, Operation, REMOVED_DELETE_FILES, REMOVED_FILE_SIZE, Summary, TOTAL_DATA_FILES,
+    TOTAL_DELETE_FILES, TOTAL_FILE_SIZE, TOTAL_RECORDS,
+};
+
+/// Receives the metrics reports of tables.
+///
+/// Reporting must not fail the scan or commit being reported, so implementations handle
+/// their own errors.
+#[async_trait]
+pub trait MetricsReporter: Debug + Send + Sync {
+    /// Reports the metrics of a scan or a commit.
+    async fn report(&self, report: MetricsReport);
+}
+
+/// A [`MetricsReporter`] that logs reports.
+#[derive(Debug, Clone, Default)]
+pub struct LoggingMetricsReporter;
+
+#[async_trait]
+impl MetricsReporter for LoggingMetricsReporter {
+    async fn report(&self, report: MetricsReport) {
+        tracing::info!("Received metrics report: {report:?}");
+    }
+}
+
+/// The metrics of a scan or a commit.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MetricsReport {
+    /// The metrics of a table scan.
+    Scan(ScanReport),
+    /// The metrics of a table commit.
+    Commit(CommitReport),
+}
+
+/// The metrics of planning a table scan.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScanReport {
+    /// The scanned table.
+    pub table: TableIdent,
+    /// The scanned snapshot.
+    pub snapshot_id: i64,
+    /// The filter of the scan, or `None` if all rows are scanned.
+    pub filter: Option<Predicate>,
+    /// The id of the schema the scan is projected from.
+    pub schema_id: i32,
+    /// The ids of the projected fields.
+    pub projected_field_ids: Vec<i32>,
+    /// The names of the projected fields.
+    pub projected_field_names: Vec<String>,
+    /// The metrics collected while planning the scan.
+    pub metrics: ScanMetricsResult,
+}
+
+/// The metrics collected while planning a table scan.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScanMetricsResult {
+    /// The time from the start of planning until the last file scan task was consumed.
+    pub total_planning_duration: Duration,
+    /// The number of data files to read.
+    pub result_data_files: u64,
+    /// The number of delete files whose partition values match the filter of the scan.
+    pub result_delete_files: u64,
+    /// The number of data manifests of the snapshot.
+    pub total_data_manifests: u64,
+    /// The number of delete manifests of the snapshot.
+    pub total_delete_manifests: u64,
+    /// The number of data manifests read.
+    pub scanned_data_manifests: u64,
+    /// The number of data manifests pruned by the partition filter.
+    pub skipped_data_manifests: u64,
+    /// The number of delete manifests read.
+    pub scanned_delete_manifests: u64,
+    /// The number of delete manifests pruned by the partition filter.
+    pub skipped_delete_manifests: u64,
+    /// The number of live data files pruned by partition values or column metrics.
+    pub skipped_data_files: u64,
+    /// The number of live delete files pruned by partition values.
+    pub skipped_delete_files: u64,
+    /// The total size in bytes of the data files to read.
+    pub total_file_size_in_bytes: u64,
+    /// The total size in bytes of the delete files whose partition values match the filter
+    /// of the scan.
+    pub total_delete_file_size_in_bytes: u64,
+}
+
+/// Counters of a table scan being planned, shared by the planning tasks.
+#[derive(Debug, Default)]
+pub(crate) struct ScanMetrics {
+    pub result_data_files: AtomicU64,
+    pub result_delete_files: AtomicU64,
+    pub total_data_manifests: AtomicU64,
+    pub total_delete_manifests: AtomicU64,
+    pub skipped_data_manifests: AtomicU64,
+    pub skipped_delete_manifests: AtomicU64,
+    pub skipped_data_files: AtomicU64,
+    pub skipped_delete_files: AtomicU64,
+    pub total_file_size_in_bytes: AtomicU64,
+    pub total_delete_file_size_in_bytes: AtomicU64,
+}
+
+impl ScanMetrics {
+    /// Adds `value` to `counter`.
+    pub(crate) fn add(counter: &AtomicU64, value: u64) {
+        counter.fetch_add(value, Ordering::Relaxed);
+    }
+
+    /// Returns the values of the counters.
+    pub(crate) fn result(&self, total_planning_duration: Duration) -> ScanMetricsResult {
+        let get = |counter: &AtomicU64| counter.load(Ordering::Relaxed);
+        let total_data_manifests = get(&self.total_data_manifests);
+        let total_delete_manifests = get(&self.total_delete_manifests);
+        let skipped_data_manifests = get(&self.skipped_data_manifests);
+        let skipped_delete_manifests = get(&self.skipped_delete_manifests);
+
+        ScanMetricsResult {
+            total_planning_duration,
+            result_data_files: get(&self.result_data_files),
+            result_delete_files: get(&self.result_delete_files),
+            total_data_manifests,
+            total_delete_manifests,
+            scanned_data_manifests: total_data_manifests - skipped_data_manifests,
+            skipped_data_manifests,
+            scanned_delete_manifests: total_delete_manifests - skipped_delete_manifests,
+            skipped_delete_manifests,
+            skipped_data_files: get(&self.skipped_data_files),
+            skipped_delete_files: get(&self.skipped_delete_files),
+            total_file_size_in_bytes: get(&self.total_file_size_in_bytes),
+            total_delete_file_size_in_bytes: get(&self.total_delete_file_size_in_bytes),
+        }
+    }
+}
+
+/// The metrics of a commit creating a snapshot.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommitReport {
+    /// The committed table.
+    pub table: TableIdent,
+    /// The snapshot created by the commit.
+    pub snapshot_id: i64,
+    /// The sequence number of the snapshot.
+    pub sequence_number: i64,
+    /// The operation of the snapshot.
+    pub operation: Operation,
+    /// The metrics of the commit.
+    pub metrics: CommitMetricsResult,
+}
+
+/// The metrics of a commit.
+///
+/// File and record counts are read from the summary of the created snapshot and are `None`
+/// when the summary does not include them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommitMetricsResult {
+    /// The time spent committing, including retries.
+    pub total_duration: Duration,
+    /// The number of attempts made to commit.
+    pub attempts: u64,
+    /// The number of data files added.
+    pub added_data_files: Option<u64>,
+    /// The number of data files removed.
+    pub removed_data_files: Option<u64>,
+    /// The number of data files in the snapshot.
+    pub total_data_files: Option<u64>,
+    /// The number of delete files added.
+    pub added_delete_files: Option<u64>,
+    /// The number of delete files removed.
+    pub removed_delete_files: Option<u64>,
+    /// The number of delete files in the snapshot.
+    pub total_delete_files: Option<u64>,
+    /// The number of records added.
+    pub added_records: Option<u64>,
+    /// The number of records removed.
+    pub removed_records: Option<u64>,
+    /// The number of records in the snapshot.
+    pub total_records: Option<u64>,
+    /// The total size in bytes of the files added.
+    pub added_files_size_in_bytes: Option<u64>,
+    /// The total size in bytes of the files removed.
+    pub removed_files_size_in_bytes: Option<u64>,
+    /// The total size in bytes of the files in the snapshot.
+    pub total_files_size_in_bytes: Option<u64>,
+}
+
+impl CommitMetricsResult {
+    /// Builds the metrics of a commit from the summary of the snapshot it created.
+    pub(crate) fn from_summary(total_duration: Duration, attempts: u64, summary: &Summary) -> Self {
+        let get = |key: &str| {
+            summary
+                .additional_properties
+                .get(key)
+                .and_then(|value| value.parse().ok())
+        };
+
+        Self {
+            total_duration,
+            attempts,
+            added_data_files: get(ADDED_DATA_FILES),
+            removed_data_files: get(DELETED_DATA_FILES),
+            total_data_files: get(TOTAL_DATA_FILES),
+            added_delete_files: get(ADDED_DELETE_FILES),
+            removed_delete_files: get(REMOVED_DELETE_FILES),
+            total_delete_files: get(TOTAL_DELETE_FILES),
+            added_records: get(ADDED_RECORDS),
+            removed_records: get(DELETED_RECORDS),
+            total_records: get(TOTAL_RECORDS),
+            added_files_size_in_bytes: get(ADDED_FILE_SIZE),
+            removed_files_size_in_bytes: get(REMOVED_FILE_SIZE),
+            total_files_size_in_bytes: get(TOTAL_FILE_SIZE),
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    use async_trait::async_trait;
+
+    use super::{CommitMetricsResult, MetricsReport, MetricsReporter};
+    use crate::spec::{Operation, Summary};
+
+    /// A [`MetricsReporter`] keeping the reports it receives.
+    #[derive(Debug, Default)]
+    pub(crate) struct RecordingMetricsReporter {
+        reports: Mutex<Vec<MetricsReport>>,
+    }
+
+    impl RecordingMetricsReporter {
+        pub(crate) fn reports(&self) -> Vec<MetricsReport> {
+            self.reports.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl MetricsReporter for RecordingMetricsReporter {
+        async fn report(&self, report: MetricsReport) {
+            self.reports.lock().unwrap().push(report);
+        }
+    }
+
+    #[test]
+    fn test_commit_metrics_from_summary() {
+        let summary = Summary {
+            operation: Operation::Overwrite,
+            additional_properties: HashMap::from([
+                ("added-data-files".to_string(), "2".to_string()),
+                ("deleted-data-files".to_string(), "1".to_string()),
+                ("added-records".to_string(), "20".to_string()),
+                ("total-records".to_string(), "not a number".to_string()),
+            ]),
+        };
+
+        let metrics = CommitMetricsResult::from_summary(Duration::from_millis(5), 2, &summary);
+        assert_eq!(metrics, CommitMetricsResult {
+            total_duration: Duration::from_millis(5),
+            attempts: 2,
+            added_data_files: Some(2),
+            removed_data_files: Some(1),
+            added_records: Some(20),
+            ..Default::default()
+        });
+    }
+}
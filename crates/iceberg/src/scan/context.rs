@@ -23,6 +23,7 @@ use futures::{SinkExt, TryFutureExt};
 use crate::delete_file_index::DeleteFileIndex;
 use crate::expr::{Bind, BoundPredicate, Predicate};
 use crate::io::object_cache::ObjectCache;
+use crate::metrics::ScanMetrics;
 use crate::scan::{
     BoundPredicates, ExpressionEvaluatorCache, FileScanTask, ManifestEvaluatorCache,
     PartitionFilterCache,
@@ -47,6 +48,7 @@ pub(crate) struct ManifestFileContext {
     expression_evaluator_cache: Arc<ExpressionEvaluatorCache>,
     delete_file_index: DeleteFileIndex,
     case_sensitive: bool,
+    metrics: Arc<ScanMetrics>,
 }
 
 /// Wraps a [`ManifestEntryRef`] alongside the objects that are needed
@@ -61,6 +63,7 @@ pub(crate) struct ManifestEntryContext {
     pub snapshot_schema: SchemaRef,
     pub delete_file_index: DeleteFileIndex,
     pub case_sensitive: bool,
+    pub metrics: Arc<ScanMetrics>,
 }
 
 impl ManifestFileContext {
@@ -76,6 +79,7 @@ impl ManifestFileContext {
             mut sender,
             expression_evaluator_cache,
             delete_file_index,
+            metrics,
             ..
         } = self;
 
@@ -92,6 +96,7 @@ impl ManifestFileContext {
                 snapshot_schema: snapshot_schema.clone(),
                 delete_file_index: delete_file_index.clone(),
                 case_sensitive: self.case_sensitive,
+                metrics: metrics.clone(),
             };
 
             sender
@@ -139,6 +144,9 @@ impl ManifestEntryContext {
             partition_spec: None,
             // TODO: Extract name_mapping from table metadata property "schema.name-mapping.default"
             name_mapping: None,
+            first_row_id: self.manifest_entry.data_file().first_row_id(),
+            data_sequence_number: self.manifest_entry.sequence_number(),
+            key_metadata: self.manifest_entry.data_file.key_metadata.clone(),
             case_sensitive: self.case_sensitive,
         })
     }
@@ -198,6 +206,7 @@ impl PlanContext {
         tx_data: Sender<ManifestEntryContext>,
         delete_file_idx: DeleteFileIndex,
         delete_file_tx: Sender<ManifestEntryContext>,
+        metrics: Arc<ScanMetrics>,
     ) -> Result<Box<impl Iterator<Item = Result<ManifestFileContext>> + 'static>> {
         let mut manifest_files = manifest_list.entries().iter().collect::<Vec<_>>();
         // Sort manifest files to process delete manifests first.
@@ -214,11 +223,21 @@ impl PlanContext {
         // TODO: Ideally we could ditch this intermediate Vec as we return an iterator.
         let mut filtered_mfcs = vec![];
         for manifest_file in manifest_files {
-            let tx = if manifest_file.content == ManifestContentType::Deletes {
-                delete_file_tx.clone()
-            } else {
-                tx_data.clone()
-            };
+            let (tx, total_manifests, skipped_manifests) =
+                if manifest_file.content == ManifestContentType::Deletes {
+                    (
+                        delete_file_tx.clone(),
+                        &metrics.total_delete_manifests,
+                        &metrics.skipped_delete_manifests,
+                    )
+                } else {
+                    (
+                        tx_data.clone(),
+                        &metrics.total_data_manifests,
+                        &metrics.skipped_data_manifests,
+                    )
+                };
+            ScanMetrics::add(total_manifests, 1);
 
             let partition_bound_predicate = if self.predicate.is_some() {
                 let partition_bound_predicate = self.get_partition_filter(manifest_file)?;
@@ -233,6 +252,7 @@ impl PlanContext {
                     )
                     .eval(manifest_file)?
                 {
+                    ScanMetrics::add(skipped_manifests, 1);
                     continue;
                 }
 
@@ -246,6 +266,7 @@ impl PlanContext {
                 partition_bound_predicate,
                 tx,
                 delete_file_idx.clone(),
+                metrics.clone(),
             );
 
             filtered_mfcs.push(Ok(mfc));
@@ -260,6 +281,7 @@ impl PlanContext {
         partition_filter: Option<Arc<BoundPredicate>>,
         sender: Sender<ManifestEntryContext>,
         delete_file_index: DeleteFileIndex,
+        metrics: Arc<ScanMetrics>,
     ) -> ManifestFileContext {
         let bound_predicates =
             if let (Some(ref partition_bound_predicate), Some(snapshot_bound_predicate)) =
@@ -283,6 +305,7 @@ impl PlanContext {
             expression_evaluator_cache: self.expression_evaluator_cache.clone(),
             delete_file_index,
             case_sensitive: self.case_sensitive,
+            metrics,
         }
     }
 }
@@ -21,14 +21,17 @@ mod cache;
 use cache::*;
 mod context;
 use context::*;
+mod planner;
 mod task;
 
 use std::sync::Arc;
+use std::time::Instant;
 
 use arrow_array::RecordBatch;
 use futures::channel::mpsc::{Sender, channel};
 use futures::stream::BoxStream;
 use futures::{SinkExt, StreamExt, TryStreamExt};
+pub use planner::*;
 pub use task::*;
 
 use crate::arrow::ArrowReaderBuilder;
@@ -37,11 +40,12 @@ use crate::expr::visitors::inclusive_metrics_evaluator::InclusiveMetricsEvaluato
 use crate::expr::{Bind, BoundPredicate, Predicate};
 use crate::io::FileIO;
 use crate::metadata_columns::{get_metadata_field_id, is_metadata_column_name};
+use crate::metrics::{MetricsReport, MetricsReporter, ScanMetrics, ScanMetricsResult, ScanReport};
 use crate::runtime::spawn;
 use crate::spec::{DataContentType, SnapshotRef};
 use crate::table::Table;
 use crate::utils::available_parallelism;
-use crate::{Error, ErrorKind, Result};
+use crate::{Error, ErrorKind, Result, TableIdent};
 
 /// A stream of arrow [`RecordBatch`]es.
 pub type ArrowRecordBatchStream = BoxStream<'static, Result<RecordBatch>>;
@@ -60,6 +64,7 @@ pub struct TableScanBuilder<'a> {
     concurrency_limit_manifest_files: usize,
     row_group_filtering_enabled: bool,
     row_selection_enabled: bool,
+    scan_planner_enabled: bool,
 }
 
 impl<'a> TableScanBuilder<'a> {
@@ -78,6 +83,7 @@ impl<'a> TableScanBuilder<'a> {
             concurrency_limit_manifest_files: num_cpus,
             row_group_filtering_enabled: true,
             row_selection_enabled: false,
+            scan_planner_enabled: true,
         }
     }
 
@@ -95,6 +101,10 @@ impl<'a> TableScanBuilder<'a> {
     }
 
     /// Specifies a predicate to use as a filter
+    ///
+    /// Spatial predicates, such as [`Reference::intersects`](crate::expr::Reference::intersects),
+    /// are evaluated conservatively: the scan may return rows which don't match them, so the
+    /// engine must re-check them.
     pub fn with_filter(mut self, predicate: Predicate) -> Self {
         // calls rewrite_not to remove Not nodes, which must be absent
         // when applying the manifest evaluator
@@ -115,6 +125,9 @@ impl<'a> TableScanBuilder<'a> {
     }
 
     /// Select some columns of the table.
+    ///
+    /// Nested fields are selected by their full name, such as `a.b`, in which case the
+    /// top-level column `a` only contains the selected nested fields.
     pub fn select(mut self, column_names: impl IntoIterator<Item = impl ToString>) -> Self {
         self.column_names = Some(
             column_names
@@ -184,8 +197,24 @@ impl<'a> TableScanBuilder<'a> {
         self
     }
 
+    /// Determines whether planning is delegated to the table's [`ScanPlanner`], such as
+    /// a REST catalog server planning scans, when the table has one.
+    ///
+    /// Defaults to enabled. When disabled, manifests are always read by the client.
+    pub fn with_scan_planner_enabled(mut self, scan_planner_enabled: bool) -> Self {
+        self.scan_planner_enabled = scan_planner_enabled;
+        self
+    }
+
     /// Build the table scan.
     pub fn build(self) -> Result<TableScan> {
+        let scan_planner = self
+            .table
+            .scan_planner()
+            .filter(|_| self.scan_planner_enabled)
+            .cloned();
+        let metrics_reporter = self.table.metrics_reporter().cloned();
+
         let snapshot = match self.snapshot_id {
             Some(snapshot_id) => self
                 .table
@@ -205,6 +234,9 @@ impl<'a> TableScanBuilder<'a> {
                         column_names: self.column_names,
                         file_io: self.table.file_io().clone(),
                         plan_context: None,
+                        table_ident: self.table.identifier().clone(),
+                        scan_planner,
+                        metrics_reporter,
                         concurrency_limit_data_files: self.concurrency_limit_data_files,
                         concurrency_limit_manifest_entries: self.concurrency_limit_manifest_entries,
                         concurrency_limit_manifest_files: self.concurrency_limit_manifest_files,
@@ -258,18 +290,6 @@ impl<'a> TableScanBuilder<'a> {
                 )
             })?;
 
-            schema
-                .as_struct()
-                .field_by_id(field_id)
-                .ok_or_else(|| {
-                    Error::new(
-                        ErrorKind::FeatureUnsupported,
-                        format!(
-                        "Column {column_name} is not a direct child of schema but a nested field, which is not supported now. Schema: {schema}"
-                    ),
-                )
-            })?;
-
             field_ids.push(field_id);
         }
 
@@ -298,6 +318,9 @@ impl<'a> TableScanBuilder<'a> {
             column_names: self.column_names,
             file_io: self.table.file_io().clone(),
             plan_context: Some(plan_context),
+            table_ident: self.table.identifier().clone(),
+            scan_planner,
+            metrics_reporter,
             concurrency_limit_data_files: self.concurrency_limit_data_files,
             concurrency_limit_manifest_entries: self.concurrency_limit_manifest_entries,
             concurrency_limit_manifest_files: self.concurrency_limit_manifest_files,
@@ -314,6 +337,11 @@ pub struct TableScan {
     ///
     /// If this is None, then the scan contains no rows.
     plan_context: Option<PlanContext>,
+    table_ident: TableIdent,
+    /// The planner the planning of files is delegated to, if any.
+    scan_planner: Option<Arc<dyn ScanPlanner>>,
+    /// The reporter of the metrics of planning files, if any.
+    metrics_reporter: Option<Arc<dyn MetricsReporter>>,
     batch_size: Option<usize>,
     file_io: FileIO,
     column_names: Option<Vec<String>>,
@@ -335,11 +363,21 @@ pub struct TableScan {
 
 impl TableScan {
     /// Returns a stream of [`FileScanTask`]s.
+    ///
+    /// If the table has a [`MetricsReporter`], a [`ScanReport`] is sent once the stream has
+    /// been consumed to the end.
     pub async fn plan_files(&self) -> Result<FileScanTaskStream> {
         let Some(plan_context) = self.plan_context.as_ref() else {
             return Ok(Box::pin(futures::stream::empty()));
         };
 
+        if let Some(scan_planner) = self.scan_planner.as_ref() {
+            return self.plan_files_with(scan_planner, plan_context).await;
+        }
+
+        let start = Instant::now();
+        let metrics = Arc::new(ScanMetrics::default());
+
         let concurrency_limit_manifest_files = self.concurrency_limit_manifest_files;
         let concurrency_limit_manifest_entries = self.concurrency_limit_manifest_entries;
 
@@ -364,6 +402,7 @@ impl TableScan {
             manifest_entry_data_ctx_tx,
             delete_file_idx.clone(),
             manifest_entry_delete_ctx_tx,
+            metrics.clone(),
         )?;
 
         let mut channel_for_manifest_error = file_scan_task_tx.clone();
@@ -427,7 +466,120 @@ impl TableScan {
             }
         });
 
-        Ok(file_scan_task_rx.boxed())
+        Ok(self.report_on_completion(plan_context, file_scan_task_rx.boxed(), metrics, start))
+    }
+
+    /// Sends a [`ScanReport`] to the metrics reporter after the last task of `tasks`.
+    fn report_on_completion(
+        &self,
+        plan_context: &PlanContext,
+        tasks: FileScanTaskStream,
+        metrics: Arc<ScanMetrics>,
+        start: Instant,
+    ) -> FileScanTaskStream {
+        let Some(metrics_reporter) = self.metrics_reporter.clone() else {
+            return tasks;
+        };
+
+        let schema = &plan_context.snapshot_schema;
+        let mut report = ScanReport {
+            table: self.table_ident.clone(),
+            snapshot_id: plan_context.snapshot.snapshot_id(),
+            filter: plan_context.predicate.as_deref().cloned(),
+            schema_id: schema.schema_id(),
+            projected_field_ids: plan_context.field_ids.to_vec(),
+            projected_field_names: self.column_names.clone().unwrap_or_else(|| {
+                schema
+                    .as_struct()
+                    .fields()
+                    .iter()
+                    .map(|f| f.name.clone())
+                    .collect()
+            }),
+            metrics: ScanMetricsResult::default(),
+        };
+
+        let report = futures::stream::once(async move {
+            report.metrics = metrics.result(start.elapsed());
+            metrics_reporter.report(MetricsReport::Scan(report)).await;
+        })
+        .filter_map(|_| async { None });
+
+        tasks.chain(report).boxed()
+    }
+
+    /// Plans the files to scan with a [`ScanPlanner`] instead of reading manifests.
+    async fn plan_files_with(
+        &self,
+        scan_planner: &Arc<dyn ScanPlanner>,
+        plan_context: &PlanContext,
+    ) -> Result<FileScanTaskStream> {
+        let request = ScanPlanRequest {
+            table: self.table_ident.clone(),
+            metadata: plan_context.table_metadata.clone(),
+            snapshot_id: plan_context.snapshot.snapshot_id(),
+            // Metadata columns are produced by the reader, not by the planner
+            select: self.column_names.as_ref().map(|column_names| {
+                column_names
+                    .iter()
+                    .filter(|name| !is_metadata_column_name(name))
+                    .cloned()
+                    .collect()
+            }),
+            filter: plan_context.predicate.as_deref().cloned(),
+            case_sensitive: plan_context.case_sensitive,
+        };
+
+        let start = Instant::now();
+        let metrics = Arc::new(ScanMetrics::default());
+
+        let schema = plan_context.snapshot_schema.clone();
+        let field_ids = plan_context.field_ids.clone();
+        let predicate = plan_context.snapshot_bound_predicate.clone();
+        let case_sensitive = plan_context.case_sensitive;
+        let task_metrics = metrics.clone();
+
+        let tasks = scan_planner
+            .plan_scan(request)
+            .await?
+            .map_ok(move |planned_file| {
+                let data_file = planned_file.data_file;
+                ScanMetrics::add(&task_metrics.result_data_files, 1);
+                ScanMetrics::add(
+                    &task_metrics.total_file_size_in_bytes,
+                    data_file.file_size_in_bytes(),
+                );
+                for delete_file in &planned_file.delete_files {
+                    ScanMetrics::add(&task_metrics.result_delete_files, 1);
+                    ScanMetrics::add(
+                        &task_metrics.total_delete_file_size_in_bytes,
+                        delete_file.file_size_in_bytes(),
+                    );
+                }
+
+                FileScanTask {
+                    file_size_in_bytes: data_file.file_size_in_bytes(),
+                    start: 0,
+                    length: data_file.file_size_in_bytes(),
+                    record_count: Some(data_file.record_count()),
+                    data_file_path: data_file.file_path().to_string(),
+                    data_file_format: data_file.file_format(),
+                    schema: schema.clone(),
+                    project_field_ids: field_ids.to_vec(),
+                    predicate: predicate.as_deref().cloned(),
+                    deletes: planned_file.delete_files.iter().map(Into::into).collect(),
+                    partition: Some(data_file.partition().clone()),
+                    partition_spec: None,
+                    name_mapping: None,
+                    first_row_id: data_file.first_row_id(),
+                    data_sequence_number: planned_file.data_sequence_number,
+                    key_metadata: data_file.key_metadata.clone(),
+                    case_sensitive,
+                }
+            })
+            .boxed();
+
+        Ok(self.report_on_completion(plan_context, tasks, metrics, start))
     }
 
     /// Returns an [`ArrowRecordBatchStream`].
@@ -488,6 +640,7 @@ impl TableScan {
             // skip any data file whose partition data indicates that it can't contain
             // any data that matches this scan's filter
             if !expression_evaluator.eval(manifest_entry_context.manifest_entry.data_file())? {
+                ScanMetrics::add(&manifest_entry_context.metrics.skipped_data_files, 1);
                 return Ok(());
             }
 
@@ -497,10 +650,18 @@ impl TableScan {
                 manifest_entry_context.manifest_entry.data_file(),
                 false,
             )? {
+                ScanMetrics::add(&manifest_entry_context.metrics.skipped_data_files, 1);
                 return Ok(());
             }
         }
 
+        let metrics = manifest_entry_context.metrics.clone();
+        ScanMetrics::add(&metrics.result_data_files, 1);
+        ScanMetrics::add(
+            &metrics.total_file_size_in_bytes,
+            manifest_entry_context.manifest_entry.file_size_in_bytes(),
+        );
+
         // congratulations! the manifest entry has made its way through the
         // entire plan without getting filtered out. Create a corresponding
         // FileScanTask and push it to the result stream
@@ -540,10 +701,18 @@ impl TableScan {
             // skip any data file whose partition data indicates that it can't contain
             // any data that matches this scan's filter
             if !expression_evaluator.eval(manifest_entry_context.manifest_entry.data_file())? {
+                ScanMetrics::add(&manifest_entry_context.metrics.skipped_delete_files, 1);
                 return Ok(());
             }
         }
 
+        let metrics = &manifest_entry_context.metrics;
+        ScanMetrics::add(&metrics.result_delete_files, 1);
+        ScanMetrics::add(
+            &metrics.total_delete_file_size_in_bytes,
+            manifest_entry_context.manifest_entry.file_size_in_bytes(),
+        );
+
         delete_file_ctx_tx
             .send(DeleteFileContext {
                 manifest_entry: manifest_entry_context.manifest_entry.clone(),
@@ -584,18 +753,20 @@ pub mod tests {
     use tempfile::TempDir;
     use uuid::Uuid;
 
-    use crate::TableIdent;
     use crate::arrow::ArrowReaderBuilder;
     use crate::expr::{BoundPredicate, Reference};
     use crate::io::{FileIO, OutputFile};
     use crate::metadata_columns::RESERVED_COL_NAME_FILE;
-    use crate::scan::FileScanTask;
+    use crate::metrics::MetricsReport;
+    use crate::metrics::tests::RecordingMetricsReporter;
+    use crate::scan::{FileScanTask, PlannedFile, PlannedFileStream, ScanPlanRequest, ScanPlanner};
     use crate::spec::{
         DataContentType, DataFileBuilder, DataFileFormat, Datum, Literal, ManifestEntry,
         ManifestListWriter, ManifestStatus, ManifestWriterBuilder, NestedField, PartitionSpec,
         PrimitiveType, Schema, Struct, StructType, TableMetadata, Type,
     };
     use crate::table::Table;
+    use crate::{Result, TableIdent};
 
     fn render_template(template: &str, ctx: Value) -> String {
         let mut env = Environment::new();
@@ -1233,6 +1404,131 @@ pub mod tests {
         );
     }
 
+    #[derive(Debug, Default)]
+    struct TestScanPlanner {
+        requests: std::sync::Mutex<Vec<ScanPlanRequest>>,
+        planned_files: Vec<PlannedFile>,
+    }
+
+    #[async_trait::async_trait]
+    impl ScanPlanner for TestScanPlanner {
+        async fn plan_scan(&self, request: ScanPlanRequest) -> Result<PlannedFileStream> {
+            self.requests.lock().unwrap().push(request);
+            Ok(Box::pin(stream::iter(
+                self.planned_files.clone().into_iter().map(Ok),
+            )))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_plan_files_with_scan_planner() {
+        let mut fixture = TableTestFixture::new();
+        fixture.setup_manifest_files().await;
+
+        let data_file_path = format!("{}/1.parquet", &fixture.table_location);
+        let delete_file_path = format!("{}/1-deletes.parquet", &fixture.table_location);
+        let scan_planner = Arc::new(TestScanPlanner {
+            requests: Default::default(),
+            planned_files: vec![PlannedFile {
+                data_file: DataFileBuilder::default()
+                    .content(DataContentType::Data)
+                    .file_path(data_file_path.clone())
+                    .file_format(DataFileFormat::Parquet)
+                    .file_size_in_bytes(100)
+                    .record_count(1)
+                    .partition(Struct::from_iter([Some(Literal::long(100))]))
+                    .partition_spec_id(0)
+                    .build()
+                    .unwrap(),
+                delete_files: vec![
+                    DataFileBuilder::default()
+                        .content(DataContentType::PositionDeletes)
+                        .file_path(delete_file_path.clone())
+                        .file_format(DataFileFormat::Parquet)
+                        .file_size_in_bytes(10)
+                        .record_count(1)
+                        .partition_spec_id(0)
+                        .build()
+                        .unwrap(),
+                ],
+                data_sequence_number: Some(4),
+            }],
+        });
+        let reporter = Arc::new(RecordingMetricsReporter::default());
+        let table = Table::builder()
+            .metadata(fixture.table.metadata_ref())
+            .identifier(fixture.table.identifier().clone())
+            .file_io(fixture.table.file_io().clone())
+            .scan_planner(scan_planner.clone())
+            .build()
+            .unwrap()
+            .with_metrics_reporter(reporter.clone());
+
+        let tasks: Vec<_> = table
+            .scan()
+            .select(["x", RESERVED_COL_NAME_FILE])
+            .with_filter(Reference::new("y").less_than(Datum::long(3)))
+            .build()
+            .unwrap()
+            .plan_files()
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+
+        let requests = scan_planner.requests.lock().unwrap().clone();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(&requests[0].table, table.identifier());
+        assert_eq!(
+            requests[0].snapshot_id,
+            table.metadata().current_snapshot_id().unwrap()
+        );
+        assert_eq!(requests[0].select, Some(vec!["x".to_string()]));
+        assert_eq!(
+            requests[0].filter,
+            Some(Reference::new("y").less_than(Datum::long(3)))
+        );
+
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].data_file_path, data_file_path);
+        assert_eq!(tasks[0].file_size_in_bytes, 100);
+        assert_eq!(tasks[0].record_count, Some(1));
+        assert_eq!(tasks[0].data_sequence_number, Some(4));
+        assert_eq!(tasks[0].project_field_ids.len(), 2);
+        assert!(tasks[0].predicate.is_some());
+        assert_eq!(tasks[0].deletes.len(), 1);
+        assert_eq!(tasks[0].deletes[0].file_path, delete_file_path);
+        assert_eq!(
+            tasks[0].deletes[0].file_type,
+            DataContentType::PositionDeletes
+        );
+
+        let reports = reporter.reports();
+        let [MetricsReport::Scan(report)] = reports.as_slice() else {
+            panic!("Expected a single scan report, got {reports:?}");
+        };
+        assert_eq!(report.metrics.result_data_files, 1);
+        assert_eq!(report.metrics.total_file_size_in_bytes, 100);
+        assert_eq!(report.metrics.result_delete_files, 1);
+        assert_eq!(report.metrics.total_delete_file_size_in_bytes, 10);
+
+        // Planning locally ignores the scan planner
+        let tasks: Vec<_> = table
+            .scan()
+            .with_scan_planner_enabled(false)
+            .build()
+            .unwrap()
+            .plan_files()
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(tasks.len(), 2);
+        assert_eq!(scan_planner.requests.lock().unwrap().len(), 1);
+    }
+
     #[tokio::test]
     async fn test_plan_files_on_table_without_any_snapshots() {
         let table = TableTestFixture::new_empty().table;
@@ -1282,6 +1578,60 @@ pub mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_plan_files_reports_metrics() {
+        let mut fixture = TableTestFixture::new();
+        fixture.setup_manifest_files().await;
+
+        let reporter = Arc::new(RecordingMetricsReporter::default());
+        let table = fixture
+            .table
+            .clone()
+            .with_metrics_reporter(reporter.clone());
+        let filter = Reference::new("x").greater_than_or_equal_to(Datum::long(200));
+        let table_scan = table
+            .scan()
+            .select(["x", "y"])
+            .with_filter(filter.clone())
+            .build()
+            .unwrap();
+
+        let tasks: Vec<_> = table_scan
+            .plan_files()
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(tasks.len(), 1);
+
+        let reports = reporter.reports();
+        let [MetricsReport::Scan(report)] = reports.as_slice() else {
+            panic!("Expected a single scan report, got {reports:?}");
+        };
+        assert_eq!(report.table, *table.identifier());
+        assert_eq!(
+            report.snapshot_id,
+            table.metadata().current_snapshot_id().unwrap()
+        );
+        assert_eq!(report.filter, Some(filter));
+        assert_eq!(report.projected_field_names, vec!["x", "y"]);
+        assert_eq!(report.projected_field_ids, vec![1, 2]);
+
+        let metrics = &report.metrics;
+        assert_eq!(metrics.total_data_manifests, 1);
+        assert_eq!(metrics.scanned_data_manifests, 1);
+        assert_eq!(metrics.skipped_data_manifests, 0);
+        assert_eq!(metrics.total_delete_manifests, 0);
+        assert_eq!(metrics.result_data_files, 1);
+        assert_eq!(metrics.skipped_data_files, 1);
+        assert_eq!(metrics.result_delete_files, 0);
+        assert_eq!(
+            metrics.total_file_size_in_bytes,
+            tasks[0].file_size_in_bytes
+        );
+    }
+
     #[tokio::test]
     async fn test_open_parquet_no_deletions() {
         let mut fixture = TableTestFixture::new();
@@ -1786,6 +2136,9 @@ pub mod tests {
             partition: None,
             partition_spec: None,
             name_mapping: None,
+            first_row_id: None,
+            data_sequence_number: None,
+            key_metadata: None,
             case_sensitive: false,
         };
         test_fn(task);
@@ -1805,6 +2158,9 @@ pub mod tests {
             partition: None,
             partition_spec: None,
             name_mapping: None,
+            first_row_id: None,
+            data_sequence_number: None,
+            key_metadata: None,
             case_sensitive: false,
         };
         test_fn(task);
@@ -2032,6 +2388,267 @@ pub mod tests {
         assert_eq!(schema.field(2).name(), RESERVED_COL_NAME_FILE);
     }
 
+    /// Creates a table with nested columns holding two rows:
+    /// `id`, `s: struct<a, b>`, `l: list<struct<c, d>>` and `m: map<string, struct<e, f>>`.
+    async fn make_nested_table(catalog: &impl crate::Catalog) -> Table {
+        use arrow_array::{ListArray, MapArray, StructArray};
+        use arrow_buffer::OffsetBuffer;
+        use arrow_schema::DataType;
+
+        use crate::arrow::schema_to_arrow_schema;
+        use crate::spec::{ListType, MapType};
+        use crate::transaction::{ApplyTransactionAction, Transaction};
+        use crate::{NamespaceIdent, TableCreation};
+
+        let long = |id: i32, name: &str| {
+            Arc::new(NestedField::required(
+                id,
+                name,
+                Type::Primitive(PrimitiveType::Long),
+            ))
+        };
+        let schema = Schema::builder()
+            .with_fields(vec![
+                long(1, "id"),
+                NestedField::required(
+                    2,
+                    "s",
+                    Type::Struct(StructType::new(vec![
+                        long(3, "a"),
+                        NestedField::required(4, "b", Type::Primitive(PrimitiveType::String))
+                            .into(),
+                    ])),
+                )
+                .into(),
+                NestedField::required(
+                    5,
+                    "l",
+                    Type::List(ListType::new(
+                        NestedField::list_element(
+                            6,
+                            Type::Struct(StructType::new(vec![long(7, "c"), long(8, "d")])),
+                            true,
+                        )
+                        .into(),
+                    )),
+                )
+                .into(),
+                NestedField::required(
+                    9,
+                    "m",
+                    Type::Map(MapType::new(
+                        NestedField::map_key_element(10, Type::Primitive(PrimitiveType::String))
+                            .into(),
+                        NestedField::map_value_element(
+                            11,
+                            Type::Struct(StructType::new(vec![long(12, "e"), long(13, "f")])),
+                            true,
+                        )
+                        .into(),
+                    )),
+                )
+                .into(),
+            ])
+            .build()
+            .unwrap();
+
+        let namespace = NamespaceIdent::new("ns".to_string());
+        catalog
+            .create_namespace(&namespace, HashMap::new())
+            .await
+            .unwrap();
+        let table = catalog
+            .create_table(
+                &namespace,
+                TableCreation::builder()
+                    .name("nested".to_string())
+                    .schema(schema.clone())
+                    .build(),
+            )
+            .await
+            .unwrap();
+
+        // Field ids are reassigned when the table is created.
+        let arrow_schema =
+            Arc::new(schema_to_arrow_schema(table.metadata().current_schema()).unwrap());
+        let struct_fields = |data_type: &DataType| match data_type {
+            DataType::Struct(fields) => fields.clone(),
+            _ => unreachable!("{data_type} is not a struct"),
+        };
+        let s = StructArray::new(
+            struct_fields(arrow_schema.field(1).data_type()),
+            vec![
+                Arc::new(Int64Array::from(vec![10, 20])) as ArrayRef,
+                Arc::new(StringArray::from(vec!["x", "y"])) as ArrayRef,
+            ],
+            None,
+        );
+        let DataType::List(l_field) = arrow_schema.field(2).data_type() else {
+            unreachable!("l is a list");
+        };
+        let l = ListArray::new(
+            l_field.clone(),
+            OffsetBuffer::from_lengths([2, 1]),
+            Arc::new(StructArray::new(
+                struct_fields(l_field.data_type()),
+                vec![
+                    Arc::new(Int64Array::from(vec![1, 3, 5])) as ArrayRef,
+                    Arc::new(Int64Array::from(vec![2, 4, 6])) as ArrayRef,
+                ],
+                None,
+            )),
+            None,
+        );
+        let DataType::Map(m_field, _) = arrow_schema.field(3).data_type() else {
+            unreachable!("m is a map");
+        };
+        let entry_fields = struct_fields(m_field.data_type());
+        let m = MapArray::new(
+            m_field.clone(),
+            OffsetBuffer::from_lengths([1, 1]),
+            StructArray::new(
+                entry_fields.clone(),
+                vec![
+                    Arc::new(StringArray::from(vec!["k1", "k2"])) as ArrayRef,
+                    Arc::new(StructArray::new(
+                        struct_fields(entry_fields[1].data_type()),
+                        vec![
+                            Arc::new(Int64Array::from(vec![7, 9])) as ArrayRef,
+                            Arc::new(Int64Array::from(vec![8, 10])) as ArrayRef,
+                        ],
+                        None,
+                    )) as ArrayRef,
+                ],
+                None,
+            ),
+            None,
+            false,
+        );
+        let batch = RecordBatch::try_new(arrow_schema.clone(), vec![
+            Arc::new(Int64Array::from(vec![1, 2])) as ArrayRef,
+            Arc::new(s) as ArrayRef,
+            Arc::new(l) as ArrayRef,
+            Arc::new(m) as ArrayRef,
+        ])
+        .unwrap();
+
+        let path = format!("{}/data/nested.parquet", table.metadata().location());
+        let mut buffer = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buffer, arrow_schema, None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let file_size = buffer.len() as u64;
+        table
+            .file_io()
+            .new_output(&path)
+            .unwrap()
+            .write(buffer.into())
+            .await
+            .unwrap();
+        let data_file = DataFileBuilder::default()
+            .content(DataContentType::Data)
+            .file_path(path)
+            .file_format(DataFileFormat::Parquet)
+            .file_size_in_bytes(file_size)
+            .record_count(2)
+            .partition_spec_id(table.metadata().default_partition_spec_id())
+            .partition(Struct::empty())
+            .build()
+            .unwrap();
+
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .fast_append()
+            .add_data_files(vec![data_file])
+            .apply(tx)
+            .unwrap();
+        tx.commit(catalog).await.unwrap()
+    }
+
+    /// Plans and reads a scan of `columns`, checking that the projected field ids are the
+    /// ones of the columns, and returns the rows.
+    async fn scan_nested_columns(table: &Table, columns: &[&str]) -> RecordBatch {
+        let schema = table.metadata().current_schema();
+        let field_ids: Vec<_> = columns
+            .iter()
+            .map(|column| schema.field_id_by_name(column).unwrap())
+            .collect();
+
+        let scan = table
+            .scan()
+            .select(columns.iter().copied())
+            .build()
+            .unwrap();
+        let tasks: Vec<_> = scan
+            .plan_files()
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].project_field_ids, field_ids);
+        let batches: Vec<_> = scan.to_arrow().await.unwrap().try_collect().await.unwrap();
+        assert_eq!(batches.len(), 1);
+        batches[0].clone()
+    }
+
+    #[tokio::test]
+    async fn test_select_nested_struct_field() {
+        let catalog = crate::memory::tests::new_memory_catalog().await;
+        let table = make_nested_table(&catalog).await;
+
+        let batch = scan_nested_columns(&table, &["id", "s.a"]).await;
+        assert_eq!(batch.num_columns(), 2);
+        assert_eq!(batch.schema().field(1).name(), "s");
+        let s = batch.column(1).as_struct();
+        assert_eq!(s.column_names(), vec!["a"]);
+        assert_eq!(
+            s.column(0).as_ref(),
+            &Int64Array::from(vec![10, 20]) as &dyn Array
+        );
+    }
+
+    #[tokio::test]
+    async fn test_select_nested_list_element_field() {
+        let catalog = crate::memory::tests::new_memory_catalog().await;
+        let table = make_nested_table(&catalog).await;
+
+        let batch = scan_nested_columns(&table, &["l.element.d"]).await;
+        assert_eq!(batch.num_columns(), 1);
+        assert_eq!(batch.schema().field(0).name(), "l");
+        let l = batch.column(0).as_list::<i32>();
+        assert_eq!(l.value_offsets(), &[0, 2, 3]);
+        let elements = l.values().as_struct();
+        assert_eq!(elements.column_names(), vec!["d"]);
+        assert_eq!(
+            elements.column(0).as_ref(),
+            &Int64Array::from(vec![2, 4, 6]) as &dyn Array
+        );
+    }
+
+    #[tokio::test]
+    async fn test_select_nested_map_value_field() {
+        let catalog = crate::memory::tests::new_memory_catalog().await;
+        let table = make_nested_table(&catalog).await;
+
+        let batch = scan_nested_columns(&table, &["m.value.e"]).await;
+        assert_eq!(batch.num_columns(), 1);
+        assert_eq!(batch.schema().field(0).name(), "m");
+        let m = batch.column(0).as_map();
+        assert_eq!(m.value_offsets(), &[0, 1, 2]);
+        assert_eq!(
+            m.keys().as_ref(),
+            &StringArray::from(vec!["k1", "k2"]) as &dyn Array
+        );
+        let values = m.values().as_struct();
+        assert_eq!(values.column_names(), vec!["e"]);
+        assert_eq!(
+            values.column(0).as_ref(),
+            &Int64Array::from(vec![7, 9]) as &dyn Array
+        );
+    }
+
     #[tokio::test]
     async fn test_select_with_repeated_column_names() {
         let mut fixture = TableTestFixture::new();
@@ -2156,4 +2773,292 @@ pub mod tests {
         // Assert it finished (didn't timeout)
         assert!(result.is_ok(), "Scan timed out - deadlock detected");
     }
+
+    #[tokio::test]
+    async fn test_select_row_lineage_columns() {
+        use crate::arrow::schema_to_arrow_schema;
+        use crate::memory::tests::new_memory_catalog;
+        use crate::metadata_columns::{
+            RESERVED_COL_NAME_LAST_UPDATED_SEQUENCE_NUMBER, RESERVED_COL_NAME_ROW_ID,
+            schema_with_row_lineage,
+        };
+        use crate::transaction::tests::make_v3_minimal_table_in_catalog;
+        use crate::transaction::{ApplyTransactionAction, Transaction};
+
+        let catalog = new_memory_catalog().await;
+        let table = make_v3_minimal_table_in_catalog(&catalog).await;
+        let schema = table.metadata().current_schema().clone();
+
+        // Writes a data file into partition x=1 and returns its DataFile
+        let write_data_file = |name: &str, schema: Schema, columns: Vec<ArrayRef>| {
+            let table = table.clone();
+            let path = format!("{}/data/{name}.parquet", table.metadata().location());
+            async move {
+                let arrow_schema = Arc::new(schema_to_arrow_schema(&schema).unwrap());
+                let batch = RecordBatch::try_new(arrow_schema.clone(), columns).unwrap();
+                let mut buffer = Vec::new();
+                let mut writer = ArrowWriter::try_new(&mut buffer, arrow_schema, None).unwrap();
+                writer.write(&batch).unwrap();
+                writer.close().unwrap();
+
+                let file_size = buffer.len() as u64;
+                table
+                    .file_io()
+                    .new_output(&path)
+                    .unwrap()
+                    .write(buffer.into())
+                    .await
+                    .unwrap();
+
+                DataFileBuilder::default()
+                    .content(DataContentType::Data)
+                    .file_path(path)
+                    .file_format(DataFileFormat::Parquet)
+                    .file_size_in_bytes(file_size)
+                    .record_count(batch.num_rows() as u64)
+                    .partition_spec_id(table.metadata().default_partition_spec_id())
+                    .partition(Struct::from_iter([Some(Literal::long(1))]))
+                    .build()
+                    .unwrap()
+            }
+        };
+
+        // A freshly written file without lineage columns
+        let new_rows = write_data_file("new", (*schema).clone(), vec![
+            Arc::new(Int64Array::from(vec![1, 1, 1])),
+            Arc::new(Int64Array::from(vec![10, 11, 12])),
+            Arc::new(Int64Array::from(vec![0, 0, 0])),
+        ])
+        .await;
+        // A rewritten file that preserved the lineage of its first row
+        let rewritten_rows = write_data_file(
+            "rewritten",
+            schema_with_row_lineage(&schema).unwrap(),
+            vec![
+                Arc::new(Int64Array::from(vec![1, 1])),
+                Arc::new(Int64Array::from(vec![20, 21])),
+                Arc::new(Int64Array::from(vec![0, 0])),
+                Arc::new(Int64Array::from(vec![Some(100), None])),
+                Arc::new(Int64Array::from(vec![Some(7), None])),
+            ],
+        )
+        .await;
+
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .fast_append()
+            .add_data_files(vec![new_rows, rewritten_rows])
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+        let sequence_number = table
+            .metadata()
+            .current_snapshot()
+            .unwrap()
+            .sequence_number();
+
+        let batches: Vec<_> = table
+            .scan()
+            .select([
+                "y",
+                RESERVED_COL_NAME_ROW_ID,
+                RESERVED_COL_NAME_LAST_UPDATED_SEQUENCE_NUMBER,
+            ])
+            .build()
+            .unwrap()
+            .to_arrow()
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+
+        let mut rows = vec![];
+        for batch in &batches {
+            let y = batch
+                .column(0)
+                .as_primitive::<arrow_array::types::Int64Type>();
+            let row_id = batch
+                .column_by_name(RESERVED_COL_NAME_ROW_ID)
+                .unwrap()
+                .as_primitive::<arrow_array::types::Int64Type>();
+            let seq = batch
+                .column_by_name(RESERVED_COL_NAME_LAST_UPDATED_SEQUENCE_NUMBER)
+                .unwrap()
+                .as_primitive::<arrow_array::types::Int64Type>();
+            for i in 0..batch.num_rows() {
+                rows.push((y.value(i), row_id.value(i), seq.value(i)));
+            }
+        }
+        rows.sort();
+
+        // Row ids are inherited from the files' first_row_id (0 and 3) plus the row position
+        assert_eq!(rows, vec![
+            (10, 0, sequence_number),
+            (11, 1, sequence_number),
+            (12, 2, sequence_number),
+            (20, 100, 7),
+            (21, 4, sequence_number),
+        ]);
+    }
+
+    #[tokio::test]
+    async fn test_rewrite_preserves_row_lineage() {
+        use std::collections::HashSet;
+
+        use crate::arrow::row_lineage::mark_rows_updated;
+        use crate::memory::tests::new_memory_catalog;
+        use crate::metadata_columns::{
+            RESERVED_COL_NAME_LAST_UPDATED_SEQUENCE_NUMBER, RESERVED_COL_NAME_ROW_ID,
+        };
+        use crate::spec::PartitionKey;
+        use crate::transaction::tests::make_v3_minimal_table_in_catalog;
+        use crate::transaction::{ApplyTransactionAction, Transaction};
+        use crate::writer::base_writer::data_file_writer::DataFileWriterBuilder;
+        use crate::writer::file_writer::ParquetWriterBuilder;
+        use crate::writer::file_writer::location_generator::{
+            DefaultFileNameGenerator, DefaultLocationGenerator,
+        };
+        use crate::writer::file_writer::rolling_writer::RollingFileWriterBuilder;
+        use crate::writer::{IcebergWriter, IcebergWriterBuilder};
+
+        let catalog = new_memory_catalog().await;
+        let table = make_v3_minimal_table_in_catalog(&catalog).await;
+        let catalog = &catalog;
+
+        // Writes batches into partition x=1 with a writer for `schema`
+        let write = |table: Table, schema: Schema, batches: Vec<RecordBatch>| async move {
+            let schema = Arc::new(schema);
+            let rolling_writer_builder = RollingFileWriterBuilder::new_with_default_file_size(
+                ParquetWriterBuilder::new(WriterProperties::default(), schema.clone()),
+                table.file_io().clone(),
+                DefaultLocationGenerator::new(table.metadata().clone()).unwrap(),
+                DefaultFileNameGenerator::new(
+                    Uuid::new_v4().to_string(),
+                    None,
+                    DataFileFormat::Parquet,
+                ),
+            );
+            let partition_key = PartitionKey::new(
+                table.metadata().default_partition_spec().as_ref().clone(),
+                schema,
+                Struct::from_iter([Some(Literal::long(1))]),
+            );
+            let mut writer = DataFileWriterBuilder::new(rolling_writer_builder)
+                .build(Some(partition_key))
+                .await
+                .unwrap();
+            for batch in batches {
+                writer.write(batch).await.unwrap();
+            }
+            let data_files = writer.close().await.unwrap();
+            let file_paths: Vec<_> = data_files
+                .iter()
+                .map(|data_file| data_file.file_path().to_string())
+                .collect();
+
+            let tx = Transaction::new(&table);
+            let tx = tx
+                .fast_append()
+                .add_data_files(data_files)
+                .apply(tx)
+                .unwrap();
+            (tx.commit(catalog).await.unwrap(), file_paths)
+        };
+        // Scans the given data files of the table only
+        let scan_with_lineage = |table: Table, file_paths: Vec<String>| async move {
+            let tasks: Vec<_> = table
+                .scan()
+                .select([
+                    "x",
+                    "y",
+                    "z",
+                    RESERVED_COL_NAME_ROW_ID,
+                    RESERVED_COL_NAME_LAST_UPDATED_SEQUENCE_NUMBER,
+                ])
+                .build()
+                .unwrap()
+                .plan_files()
+                .await
+                .unwrap()
+                .try_filter(|task| {
+                    futures::future::ready(file_paths.contains(&task.data_file_path))
+                })
+                .try_collect()
+                .await
+                .unwrap();
+            ArrowReaderBuilder::new(table.file_io().clone())
+                .build()
+                .read(Box::pin(stream::iter(tasks.into_iter().map(Ok))))
+                .unwrap()
+                .try_collect::<Vec<_>>()
+                .await
+                .unwrap()
+        };
+
+        let schema = table.metadata().current_schema().as_ref().clone();
+        let arrow_schema = Arc::new(crate::arrow::schema_to_arrow_schema(&schema).unwrap());
+        let batch = RecordBatch::try_new(arrow_schema, vec![
+            Arc::new(Int64Array::from(vec![1, 1, 1])),
+            Arc::new(Int64Array::from(vec![10, 11, 12])),
+            Arc::new(Int64Array::from(vec![0, 0, 0])),
+        ])
+        .unwrap();
+        let (table, original_files) = write(table, schema.clone(), vec![batch]).await;
+        let first_sequence_number = table
+            .metadata()
+            .current_snapshot()
+            .unwrap()
+            .sequence_number();
+
+        // Rewrite the rows, updating y=11, with a writer for the table schema, which persists
+        // the lineage columns of the batches
+        let mut rewritten = vec![];
+        for batch in scan_with_lineage(table.clone(), original_files).await {
+            let y = batch
+                .column(1)
+                .as_primitive::<arrow_array::types::Int64Type>();
+            let updated: BooleanArray = y.iter().map(|y| Some(y == Some(11))).collect();
+            let mut columns = batch.columns().to_vec();
+            columns[1] = Arc::new(arrow_arith::arity::unary::<
+                _,
+                _,
+                arrow_array::types::Int64Type,
+            >(y, |y| if y == 11 { 111 } else { y }));
+            let batch = RecordBatch::try_new(batch.schema(), columns).unwrap();
+            rewritten.push(mark_rows_updated(&batch, &updated).unwrap());
+        }
+        let (table, rewritten_files) = write(table, schema.clone(), rewritten).await;
+        let second_sequence_number = table
+            .metadata()
+            .current_snapshot()
+            .unwrap()
+            .sequence_number();
+
+        // There is no overwrite action to remove the original file, so read the rewritten files
+        // only, which is what an overwrite would leave live.
+        let mut rows = vec![];
+        for batch in scan_with_lineage(table, rewritten_files).await {
+            let column = |index: usize| {
+                batch
+                    .column(index)
+                    .as_primitive::<arrow_array::types::Int64Type>()
+                    .clone()
+            };
+            let (y, row_id, seq) = (column(1), column(3), column(4));
+            for i in 0..batch.num_rows() {
+                rows.push((y.value(i), row_id.value(i), seq.value(i)));
+            }
+        }
+        rows.sort();
+
+        // Copied rows keep both lineage values, the updated row keeps its row id only
+        assert_eq!(rows, vec![
+            (10, 0, first_sequence_number),
+            (12, 2, first_sequence_number),
+            (111, 1, second_sequence_number),
+        ]);
+        let row_ids: HashSet<_> = rows.iter().map(|(_, row_id, _)| *row_id).collect();
+        assert_eq!(row_ids.len(), rows.len());
+    }
 }
@@ -0,0 +1,69 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Delegation of scan planning to a catalog.
+
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+use futures::stream::BoxStream;
+
+use crate::expr::Predicate;
+use crate::spec::{DataFile, TableMetadataRef};
+use crate::{Result, TableIdent};
+
+/// A stream of [`PlannedFile`]s.
+pub type PlannedFileStream = BoxStream<'static, Result<PlannedFile>>;
+
+/// Plans table scans outside of the client, for example on a REST catalog server that
+/// applies access policies.
+///
+/// A [`Table`](crate::table::Table) loaded with a scan planner delegates
+/// [`TableScan::plan_files`](crate::scan::TableScan::plan_files) to it.
+#[async_trait]
+pub trait ScanPlanner: Debug + Send + Sync {
+    /// Returns the data files to read for the scan, with the delete files to apply to them.
+    async fn plan_scan(&self, request: ScanPlanRequest) -> Result<PlannedFileStream>;
+}
+
+/// A table scan to plan with a [`ScanPlanner`].
+#[derive(Debug, Clone)]
+pub struct ScanPlanRequest {
+    /// The table to scan.
+    pub table: TableIdent,
+    /// The metadata of the table, used to interpret the planned files.
+    pub metadata: TableMetadataRef,
+    /// The snapshot to scan.
+    pub snapshot_id: i64,
+    /// The columns to read, or `None` to read all columns.
+    pub select: Option<Vec<String>>,
+    /// The filter of the scan.
+    pub filter: Option<Predicate>,
+    /// Whether column names are case-sensitive.
+    pub case_sensitive: bool,
+}
+
+/// A data file to read, planned by a [`ScanPlanner`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlannedFile {
+    /// The data file.
+    pub data_file: DataFile,
+    /// The delete files that apply to the data file.
+    pub delete_files: Vec<DataFile>,
+    /// The data sequence number of the data file, if known.
+    pub data_sequence_number: Option<i64>,
+}
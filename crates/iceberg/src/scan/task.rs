@@ -23,8 +23,8 @@ use serde::{Deserialize, Serialize, Serializer};
 use crate::Result;
 use crate::expr::BoundPredicate;
 use crate::spec::{
-    DataContentType, DataFileFormat, ManifestEntryRef, NameMapping, PartitionSpec, Schema,
-    SchemaRef, Struct,
+    DataContentType, DataFile, DataFileFormat, ManifestEntryRef, NameMapping, PartitionSpec,
+    Schema, SchemaRef, Struct,
 };
 
 /// A stream of [`FileScanTask`].
@@ -108,6 +108,23 @@ pub struct FileScanTask {
     #[serde(deserialize_with = "deserialize_not_implemented")]
     pub name_mapping: Option<Arc<NameMapping>>,
 
+    /// The first row id of the data file (v3 row lineage), used to inherit `_row_id`
+    /// for rows whose id is not stored in the data file.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_row_id: Option<i64>,
+
+    /// The data sequence number of the data file, used to inherit
+    /// `_last_updated_sequence_number` for rows whose value is not stored in the data file.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data_sequence_number: Option<i64>,
+
+    /// The key metadata of an encrypted data file, used to decrypt it.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_metadata: Option<Vec<u8>>,
+
     /// Whether this scan task should treat column names as case-sensitive when binding predicates.
     pub case_sensitive: bool,
 }
@@ -153,6 +170,20 @@ impl From<&DeleteFileContext> for FileScanTaskDeleteFile {
             file_type: ctx.manifest_entry.content_type(),
             partition_spec_id: ctx.partition_spec_id,
             equality_ids: ctx.manifest_entry.data_file.equality_ids.clone(),
+            key_metadata: ctx.manifest_entry.data_file.key_metadata.clone(),
+        }
+    }
+}
+
+impl From<&DataFile> for FileScanTaskDeleteFile {
+    fn from(delete_file: &DataFile) -> Self {
+        FileScanTaskDeleteFile {
+            file_path: delete_file.file_path().to_string(),
+            file_size_in_bytes: delete_file.file_size_in_bytes(),
+            file_type: delete_file.content_type(),
+            partition_spec_id: delete_file.partition_spec_id,
+            equality_ids: delete_file.equality_ids(),
+            key_metadata: delete_file.key_metadata.clone(),
         }
     }
 }
@@ -174,4 +205,9 @@ pub struct FileScanTaskDeleteFile {
 
     /// equality ids for equality deletes (null for anything other than equality-deletes)
     pub equality_ids: Option<Vec<i32>>,
+
+    /// The key metadata of an encrypted delete file, used to decrypt it.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_metadata: Option<Vec<u8>>,
 }
@@ -22,6 +22,7 @@ use std::collections::HashMap;
 use std::convert::identity;
 use std::fmt;
 use std::ops::Index;
+use std::str::FromStr;
 use std::sync::{Arc, OnceLock};
 
 use ::serde::de::{MapAccess, Visitor};
@@ -90,6 +91,9 @@ pub enum Type {
     List(ListType),
     /// Map type
     Map(MapType),
+    /// Semi-structured variant type (format v3), stored as a binary-encoded
+    /// metadata and value pair.
+    Variant,
 }
 
 impl fmt::Display for Type {
@@ -99,6 +103,7 @@ impl fmt::Display for Type {
             Type::Struct(s) => write!(f, "{s}"),
             Type::List(_) => write!(f, "list"),
             Type::Map(_) => write!(f, "map"),
+            Type::Variant => write!(f, "variant"),
         }
     }
 }
@@ -122,6 +127,12 @@ impl Type {
         matches!(self, Type::Struct(_) | Type::List(_) | Type::Map(_))
     }
 
+    /// Whether the type is variant type.
+    #[inline(always)]
+    pub fn is_variant(&self) -> bool {
+        matches!(self, Type::Variant)
+    }
+
     /// Convert Type to reference of PrimitiveType
     pub fn as_primitive_type(&self) -> Option<&PrimitiveType> {
         if let Type::Primitive(primitive_type) = self {
@@ -247,9 +258,94 @@ pub enum PrimitiveType {
     Fixed(u64),
     /// Arbitrary-length byte array.
     Binary,
+    /// Geospatial features stored as WKB, with linear edges between the vertices (format v3).
+    Geometry {
+        /// Coordinate reference system of the geometries, defaults to [`DEFAULT_CRS`].
+        crs: String,
+    },
+    /// Geospatial features stored as WKB, with edges interpolated on the ellipsoid (format v3).
+    Geography {
+        /// Coordinate reference system of the geographies, defaults to [`DEFAULT_CRS`].
+        crs: String,
+        /// Algorithm used to interpolate the edges between the vertices.
+        algorithm: EdgeAlgorithm,
+    },
+}
+
+/// Default coordinate reference system of geometry and geography types.
+pub const DEFAULT_CRS: &str = "OGC:CRS84";
+
+/// Edge interpolation algorithm of the geography type.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, Hash, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum EdgeAlgorithm {
+    /// Edges are geodesics on a sphere.
+    #[default]
+    Spherical,
+    /// Vincenty's formulae on an ellipsoid.
+    Vincenty,
+    /// Thomas' spheroidal geodesics.
+    Thomas,
+    /// Andoyer's approximation on an ellipsoid.
+    Andoyer,
+    /// Karney's geodesic algorithms.
+    Karney,
+}
+
+impl fmt::Display for EdgeAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EdgeAlgorithm::Spherical => write!(f, "spherical"),
+            EdgeAlgorithm::Vincenty => write!(f, "vincenty"),
+            EdgeAlgorithm::Thomas => write!(f, "thomas"),
+            EdgeAlgorithm::Andoyer => write!(f, "andoyer"),
+            EdgeAlgorithm::Karney => write!(f, "karney"),
+        }
+    }
+}
+
+impl FromStr for EdgeAlgorithm {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "spherical" => Ok(EdgeAlgorithm::Spherical),
+            "vincenty" => Ok(EdgeAlgorithm::Vincenty),
+            "thomas" => Ok(EdgeAlgorithm::Thomas),
+            "andoyer" => Ok(EdgeAlgorithm::Andoyer),
+            "karney" => Ok(EdgeAlgorithm::Karney),
+            _ => Err(crate::Error::new(
+                crate::ErrorKind::DataInvalid,
+                format!("Invalid edge interpolation algorithm: {s}"),
+            )),
+        }
+    }
 }
 
 impl PrimitiveType {
+    /// Geometry type with the default coordinate reference system.
+    pub fn geometry() -> Self {
+        PrimitiveType::Geometry {
+            crs: DEFAULT_CRS.to_string(),
+        }
+    }
+
+    /// Geography type with the default coordinate reference system and edge algorithm.
+    pub fn geography() -> Self {
+        PrimitiveType::Geography {
+            crs: DEFAULT_CRS.to_string(),
+            algorithm: EdgeAlgorithm::default(),
+        }
+    }
+
+    /// Returns true if this is a geometry or geography type.
+    pub fn is_geospatial(&self) -> bool {
+        matches!(
+            self,
+            PrimitiveType::Geometry { .. } | PrimitiveType::Geography { .. }
+        )
+    }
+
     /// Check whether literal is compatible with the type.
     pub fn compatible(&self, literal: &PrimitiveLiteral) -> bool {
         matches!(
@@ -270,6 +366,8 @@ impl PrimitiveType {
                 | (PrimitiveType::Uuid, PrimitiveLiteral::UInt128(_))
                 | (PrimitiveType::Fixed(_), PrimitiveLiteral::Binary(_))
                 | (PrimitiveType::Binary, PrimitiveLiteral::Binary(_))
+                | (PrimitiveType::Geometry { .. }, PrimitiveLiteral::Binary(_))
+                | (PrimitiveType::Geography { .. }, PrimitiveLiteral::Binary(_))
         )
     }
 }
@@ -298,6 +396,8 @@ impl<'de> Deserialize<'de> for PrimitiveType {
             deserialize_decimal(s.into_deserializer())
         } else if s.starts_with("fixed") {
             deserialize_fixed(s.into_deserializer())
+        } else if s.starts_with("geometry") || s.starts_with("geography") {
+            deserialize_geospatial(s.into_deserializer())
         } else {
             PrimitiveType::deserialize(s.into_deserializer())
         }
@@ -312,6 +412,9 @@ impl Serialize for PrimitiveType {
                 serialize_decimal(precision, scale, serializer)
             }
             PrimitiveType::Fixed(l) => serialize_fixed(l, serializer),
+            PrimitiveType::Geometry { .. } | PrimitiveType::Geography { .. } => {
+                serializer.serialize_str(&self.to_string())
+            }
             _ => PrimitiveType::serialize(self, serializer),
         }
     }
@@ -361,6 +464,42 @@ where S: Serializer {
     serializer.serialize_str(&format!("fixed[{value}]"))
 }
 
+fn deserialize_geospatial<'de, D>(deserializer: D) -> std::result::Result<PrimitiveType, D::Error>
+where D: Deserializer<'de> {
+    let s = String::deserialize(deserializer)?;
+    let (name, args) = match s.split_once('(') {
+        Some((name, args)) => {
+            let args = args
+                .strip_suffix(')')
+                .ok_or_else(|| D::Error::custom(format!("Invalid geospatial type: {s}")))?;
+            (name.trim(), Some(args))
+        }
+        None => (s.trim(), None),
+    };
+    // The CRS may contain commas itself (e.g. a PROJJSON reference), so only the last
+    // argument of a geography type is taken as the edge algorithm.
+    match (name, args) {
+        ("geometry", None) => Ok(PrimitiveType::geometry()),
+        ("geometry", Some(crs)) => Ok(PrimitiveType::Geometry {
+            crs: crs.trim().to_string(),
+        }),
+        ("geography", None) => Ok(PrimitiveType::geography()),
+        ("geography", Some(args)) => match args.rsplit_once(',') {
+            Some((crs, algorithm)) if algorithm.trim().parse::<EdgeAlgorithm>().is_ok() => {
+                Ok(PrimitiveType::Geography {
+                    crs: crs.trim().to_string(),
+                    algorithm: algorithm.trim().parse().map_err(D::Error::custom)?,
+                })
+            }
+            _ => Ok(PrimitiveType::Geography {
+                crs: args.trim().to_string(),
+                algorithm: EdgeAlgorithm::default(),
+            }),
+        },
+        _ => Err(D::Error::custom(format!("Invalid geospatial type: {s}"))),
+    }
+}
+
 impl fmt::Display for PrimitiveType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -382,6 +521,21 @@ impl fmt::Display for PrimitiveType {
             PrimitiveType::Uuid => write!(f, "uuid"),
             PrimitiveType::Fixed(size) => write!(f, "fixed({size})"),
             PrimitiveType::Binary => write!(f, "binary"),
+            PrimitiveType::Geometry { crs } if crs == DEFAULT_CRS => write!(f, "geometry"),
+            PrimitiveType::Geometry { crs } => write!(f, "geometry({crs})"),
+            PrimitiveType::Geography { crs, algorithm }
+                if crs == DEFAULT_CRS && *algorithm == EdgeAlgorithm::default() =>
+            {
+                write!(f, "geography")
+            }
+            PrimitiveType::Geography { crs, algorithm }
+                if *algorithm == EdgeAlgorithm::default() =>
+            {
+                write!(f, "geography({crs})")
+            }
+            PrimitiveType::Geography { crs, algorithm } => {
+                write!(f, "geography({crs}, {algorithm})")
+            }
         }
     }
 }
@@ -737,6 +891,14 @@ pub(super) mod _serde {
             value: Cow<'a, Type>,
         },
         Primitive(PrimitiveType),
+        Variant(VariantTypeName),
+    }
+
+    /// Type name of the variant type, which is serialized as a plain string
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename_all = "lowercase")]
+    pub(super) enum VariantTypeName {
+        Variant,
     }
 
     impl From<SerdeType<'_>> for Type {
@@ -775,6 +937,7 @@ pub(super) mod _serde {
                     Self::Struct(StructType::new(fields.into_owned()))
                 }
                 SerdeType::Primitive(p) => Self::Primitive(p),
+                SerdeType::Variant(_) => Self::Variant,
             }
         }
     }
@@ -801,6 +964,7 @@ pub(super) mod _serde {
                     fields: Cow::Borrowed(&s.fields),
                 },
                 Type::Primitive(p) => SerdeType::Primitive(p.clone()),
+                Type::Variant => SerdeType::Variant(VariantTypeName::Variant),
             }
         }
     }
@@ -955,6 +1119,82 @@ mod tests {
         )
     }
 
+    #[test]
+    fn variant_type_serde() {
+        let record = r#"
+        {
+            "type": "struct",
+            "fields": [
+                {"id": 1, "name": "id", "required": true, "type": "long"},
+                {"id": 2, "name": "payload", "required": false, "type": "variant"}
+            ]
+        }
+        "#;
+
+        check_type_serde(
+            record,
+            Type::Struct(StructType::new(vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+                NestedField::optional(2, "payload", Type::Variant).into(),
+            ])),
+        );
+        assert!(Type::Variant.is_variant());
+        assert!(!Type::Variant.is_primitive());
+        assert_eq!(Type::Variant.to_string(), "variant");
+    }
+
+    #[test]
+    fn geospatial_type_serde() {
+        let record = r#"
+        {
+            "type": "struct",
+            "fields": [
+                {"id": 1, "name": "point", "required": false, "type": "geometry"},
+                {"id": 2, "name": "shape", "required": false, "type": "geometry(srid:3857)"},
+                {"id": 3, "name": "area", "required": false, "type": "geography(srid:4326, vincenty)"},
+                {"id": 4, "name": "region", "required": false, "type": "geography(projjson:crs)"}
+            ]
+        }
+        "#;
+
+        check_type_serde(
+            record,
+            Type::Struct(StructType::new(vec![
+                NestedField::optional(1, "point", Type::Primitive(PrimitiveType::geometry()))
+                    .into(),
+                NestedField::optional(
+                    2,
+                    "shape",
+                    Type::Primitive(PrimitiveType::Geometry {
+                        crs: "srid:3857".to_string(),
+                    }),
+                )
+                .into(),
+                NestedField::optional(
+                    3,
+                    "area",
+                    Type::Primitive(PrimitiveType::Geography {
+                        crs: "srid:4326".to_string(),
+                        algorithm: EdgeAlgorithm::Vincenty,
+                    }),
+                )
+                .into(),
+                NestedField::optional(
+                    4,
+                    "region",
+                    Type::Primitive(PrimitiveType::Geography {
+                        crs: "projjson:crs".to_string(),
+                        algorithm: EdgeAlgorithm::Spherical,
+                    }),
+                )
+                .into(),
+            ])),
+        );
+
+        assert!(PrimitiveType::geography().is_geospatial());
+        assert_eq!(PrimitiveType::geography().to_string(), "geography");
+    }
+
     #[test]
     fn struct_type() {
         let record = r#"
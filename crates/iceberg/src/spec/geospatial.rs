@@ -0,0 +1,519 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Bounding boxes of geospatial values, used as lower and upper bounds of
+//! geometry and geography columns.
+
+use crate::error::Result;
+use crate::spec::{Datum, PrimitiveLiteral};
+use crate::{Error, ErrorKind};
+
+/// A corner of a [`BoundingBox`], with optional `z` and `m` dimensions.
+///
+/// Serialized as little-endian doubles `x:y`, `x:y:z` or `x:y:z:m`, where `z` is
+/// written as NaN when only `m` is present.
+/// See [this spec](https://iceberg.apache.org/spec/#bound-serialization) for reference.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeospatialBound {
+    x: f64,
+    y: f64,
+    z: Option<f64>,
+    m: Option<f64>,
+}
+
+impl GeospatialBound {
+    /// Creates a two-dimensional bound.
+    pub fn new(x: f64, y: f64) -> Self {
+        Self {
+            x,
+            y,
+            z: None,
+            m: None,
+        }
+    }
+
+    /// Sets the `z` dimension of the bound.
+    pub fn with_z(mut self, z: f64) -> Self {
+        self.z = Some(z);
+        self
+    }
+
+    /// Sets the `m` dimension of the bound.
+    pub fn with_m(mut self, m: f64) -> Self {
+        self.m = Some(m);
+        self
+    }
+
+    /// The `x` (longitude or easting) value.
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    /// The `y` (latitude or northing) value.
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    /// The `z` (elevation) value, if present.
+    pub fn z(&self) -> Option<f64> {
+        self.z
+    }
+
+    /// The `m` (measure) value, if present.
+    pub fn m(&self) -> Option<f64> {
+        self.m
+    }
+
+    /// Serializes the bound.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(32);
+        bytes.extend_from_slice(&self.x.to_le_bytes());
+        bytes.extend_from_slice(&self.y.to_le_bytes());
+        match (self.z, self.m) {
+            (Some(z), Some(m)) => {
+                bytes.extend_from_slice(&z.to_le_bytes());
+                bytes.extend_from_slice(&m.to_le_bytes());
+            }
+            (Some(z), None) => bytes.extend_from_slice(&z.to_le_bytes()),
+            (None, Some(m)) => {
+                bytes.extend_from_slice(&f64::NAN.to_le_bytes());
+                bytes.extend_from_slice(&m.to_le_bytes());
+            }
+            (None, None) => {}
+        }
+        bytes
+    }
+
+    /// Deserializes a bound.
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Self> {
+        let value =
+            |idx: usize| f64::from_le_bytes(bytes[idx * 8..idx * 8 + 8].try_into().unwrap());
+        match bytes.len() {
+            16 => Ok(Self::new(value(0), value(1))),
+            24 => Ok(Self::new(value(0), value(1)).with_z(value(2))),
+            32 => {
+                let bound = Self::new(value(0), value(1)).with_m(value(3));
+                if value(2).is_nan() {
+                    Ok(bound)
+                } else {
+                    Ok(bound.with_z(value(2)))
+                }
+            }
+            len => Err(Error::new(
+                ErrorKind::DataInvalid,
+                format!("Invalid geospatial bound of {len} bytes, expected 16, 24 or 32"),
+            )),
+        }
+    }
+}
+
+/// The bounding box of a set of geospatial values.
+///
+/// For geography values the `x` range may wrap around the antimeridian, in which
+/// case the minimum `x` is greater than the maximum `x`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox {
+    min: GeospatialBound,
+    max: GeospatialBound,
+}
+
+impl BoundingBox {
+    /// Creates a bounding box from its lower and upper corners.
+    pub fn new(min: GeospatialBound, max: GeospatialBound) -> Self {
+        Self { min, max }
+    }
+
+    /// The lower corner of the box.
+    pub fn min(&self) -> &GeospatialBound {
+        &self.min
+    }
+
+    /// The upper corner of the box.
+    pub fn max(&self) -> &GeospatialBound {
+        &self.max
+    }
+
+    /// Computes the bounding box of the vertices of a WKB encoded geometry.
+    ///
+    /// Both ISO and extended (PostGIS) WKB are accepted. Returns `None` for empty geometries.
+    pub fn try_from_wkb(wkb: &[u8]) -> Result<Option<Self>> {
+        let mut builder = BoundingBoxBuilder::default();
+        let mut reader = WkbReader { wkb, pos: 0 };
+        reader.read_geometry(&mut builder, 0)?;
+        Ok(builder.build())
+    }
+
+    /// Creates the bounding box of a geospatial column from its lower and upper bounds.
+    pub fn try_from_bounds(lower: &Datum, upper: &Datum) -> Result<Self> {
+        match (lower.literal(), upper.literal()) {
+            (PrimitiveLiteral::Binary(lower), PrimitiveLiteral::Binary(upper)) => Ok(Self::new(
+                GeospatialBound::try_from_bytes(lower)?,
+                GeospatialBound::try_from_bytes(upper)?,
+            )),
+            _ => Err(Error::new(
+                ErrorKind::DataInvalid,
+                format!("Invalid geospatial bounds: {lower}, {upper}"),
+            )),
+        }
+    }
+
+    /// Expands this box to also cover `other`, without wrapping around the antimeridian.
+    pub fn merge(&self, other: &BoundingBox) -> BoundingBox {
+        let merge_dim = |a: Option<f64>, b: Option<f64>, f: fn(f64, f64) -> f64| match (a, b) {
+            (Some(a), Some(b)) => Some(f(a, b)),
+            _ => None,
+        };
+        BoundingBox {
+            min: GeospatialBound {
+                x: self.min.x.min(other.min.x),
+                y: self.min.y.min(other.min.y),
+                z: merge_dim(self.min.z, other.min.z, f64::min),
+                m: merge_dim(self.min.m, other.min.m, f64::min),
+            },
+            max: GeospatialBound {
+                x: self.max.x.max(other.max.x),
+                y: self.max.y.max(other.max.y),
+                z: merge_dim(self.max.z, other.max.z, f64::max),
+                m: merge_dim(self.max.m, other.max.m, f64::max),
+            },
+        }
+    }
+
+    /// Returns true if the two boxes overlap. Dimensions missing from either box are
+    /// not compared.
+    pub fn intersects(&self, other: &BoundingBox) -> bool {
+        let overlaps = |min1: f64, max1: f64, min2: f64, max2: f64| min1 <= max2 && min2 <= max1;
+        let overlaps_dim = |min1: Option<f64>, max1: Option<f64>, min2: Option<f64>, max2| match (
+            min1, max1, min2, max2,
+        ) {
+            (Some(min1), Some(max1), Some(min2), Some(max2)) => overlaps(min1, max1, min2, max2),
+            _ => true,
+        };
+
+        self.x_ranges().iter().any(|(min1, max1)| {
+            other
+                .x_ranges()
+                .iter()
+                .any(|(min2, max2)| overlaps(*min1, *max1, *min2, *max2))
+        }) && overlaps(self.min.y, self.max.y, other.min.y, other.max.y)
+            && overlaps_dim(self.min.z, self.max.z, other.min.z, other.max.z)
+            && overlaps_dim(self.min.m, self.max.m, other.min.m, other.max.m)
+    }
+
+    /// The `x` ranges covered by the box, split in two when it wraps around the antimeridian.
+    fn x_ranges(&self) -> Vec<(f64, f64)> {
+        if self.min.x > self.max.x {
+            vec![(self.min.x, f64::INFINITY), (f64::NEG_INFINITY, self.max.x)]
+        } else {
+            vec![(self.min.x, self.max.x)]
+        }
+    }
+}
+
+#[derive(Default)]
+struct BoundingBoxBuilder {
+    min: [f64; 4],
+    max: [f64; 4],
+    has_xy: bool,
+    has_z: Option<bool>,
+    has_m: Option<bool>,
+}
+
+impl BoundingBoxBuilder {
+    fn update(&mut self, coords: [f64; 4], has_z: bool, has_m: bool) {
+        // Empty points are encoded with NaN coordinates.
+        if coords[0].is_nan() || coords[1].is_nan() {
+            return;
+        }
+        if !self.has_xy {
+            self.min = [f64::INFINITY; 4];
+            self.max = [f64::NEG_INFINITY; 4];
+            self.has_xy = true;
+        }
+        // A dimension is only bounded if every vertex has it.
+        self.has_z = Some(self.has_z.unwrap_or(true) && has_z);
+        self.has_m = Some(self.has_m.unwrap_or(true) && has_m);
+        for (idx, value) in coords.iter().enumerate() {
+            if !value.is_nan() {
+                self.min[idx] = self.min[idx].min(*value);
+                self.max[idx] = self.max[idx].max(*value);
+            }
+        }
+    }
+
+    fn build(self) -> Option<BoundingBox> {
+        if !self.has_xy {
+            return None;
+        }
+        let bound = |values: [f64; 4]| {
+            let mut bound = GeospatialBound::new(values[0], values[1]);
+            if self.has_z == Some(true) && values[2].is_finite() {
+                bound = bound.with_z(values[2]);
+            }
+            if self.has_m == Some(true) && values[3].is_finite() {
+                bound = bound.with_m(values[3]);
+            }
+            bound
+        };
+        Some(BoundingBox::new(bound(self.min), bound(self.max)))
+    }
+}
+
+const WKB_POINT: u32 = 1;
+const WKB_LINE_STRING: u32 = 2;
+const WKB_POLYGON: u32 = 3;
+const WKB_MULTI_POINT: u32 = 4;
+const WKB_MULTI_LINE_STRING: u32 = 5;
+const WKB_MULTI_POLYGON: u32 = 6;
+const WKB_GEOMETRY_COLLECTION: u32 = 7;
+
+const EWKB_Z_FLAG: u32 = 0x8000_0000;
+const EWKB_M_FLAG: u32 = 0x4000_0000;
+const EWKB_SRID_FLAG: u32 = 0x2000_0000;
+
+/// Maximum nesting of multi geometries and geometry collections, which bounds the recursion
+/// when reading untrusted WKB.
+const MAX_WKB_NESTING_DEPTH: usize = 64;
+
+struct WkbReader<'a> {
+    wkb: &'a [u8],
+    pos: usize,
+}
+
+impl WkbReader<'_> {
+    fn read_bytes<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let bytes = self
+            .wkb
+            .get(self.pos..self.pos + N)
+            .ok_or_else(|| Error::new(ErrorKind::DataInvalid, "Unexpected end of WKB geometry"))?;
+        self.pos += N;
+        Ok(bytes.try_into().unwrap())
+    }
+
+    fn read_u32(&mut self, little_endian: bool) -> Result<u32> {
+        let bytes = self.read_bytes::<4>()?;
+        Ok(if little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    fn read_f64(&mut self, little_endian: bool) -> Result<f64> {
+        let bytes = self.read_bytes::<8>()?;
+        Ok(if little_endian {
+            f64::from_le_bytes(bytes)
+        } else {
+            f64::from_be_bytes(bytes)
+        })
+    }
+
+    fn read_geometry(&mut self, builder: &mut BoundingBoxBuilder, depth: usize) -> Result<()> {
+        if depth > MAX_WKB_NESTING_DEPTH {
+            return Err(Error::new(
+                ErrorKind::DataInvalid,
+                format!("WKB geometry is nested deeper than {MAX_WKB_NESTING_DEPTH} levels"),
+            ));
+        }
+        let little_endian = match self.read_bytes::<1>()?[0] {
+            0 => false,
+            1 => true,
+            order => {
+                return Err(Error::new(
+                    ErrorKind::DataInvalid,
+                    format!("Invalid WKB byte order: {order}"),
+                ));
+            }
+        };
+        let code = self.read_u32(little_endian)?;
+        if code & EWKB_SRID_FLAG != 0 {
+            self.read_u32(little_endian)?;
+        }
+        let iso_code = code & 0x0FFF_FFFF;
+        let geometry_type = iso_code % 1000;
+        let has_z = code & EWKB_Z_FLAG != 0 || matches!(iso_code / 1000, 1 | 3);
+        let has_m = code & EWKB_M_FLAG != 0 || matches!(iso_code / 1000, 2 | 3);
+
+        match geometry_type {
+            WKB_POINT => self.read_points(1, little_endian, has_z, has_m, builder),
+            WKB_LINE_STRING => {
+                let num_points = self.read_u32(little_endian)?;
+                self.read_points(num_points, little_endian, has_z, has_m, builder)
+            }
+            WKB_POLYGON => {
+                let num_rings = self.read_u32(little_endian)?;
+                for _ in 0..num_rings {
+                    let num_points = self.read_u32(little_endian)?;
+                    self.read_points(num_points, little_endian, has_z, has_m, builder)?;
+                }
+                Ok(())
+            }
+            WKB_MULTI_POINT
+            | WKB_MULTI_LINE_STRING
+            | WKB_MULTI_POLYGON
+            | WKB_GEOMETRY_COLLECTION => {
+                let num_geometries = self.read_u32(little_endian)?;
+                for _ in 0..num_geometries {
+                    self.read_geometry(builder, depth + 1)?;
+                }
+                Ok(())
+            }
+            _ => Err(Error::new(
+                ErrorKind::FeatureUnsupported,
+                format!("Unsupported WKB geometry type: {code}"),
+            )),
+        }
+    }
+
+    fn read_points(
+        &mut self,
+        num_points: u32,
+        little_endian: bool,
+        has_z: bool,
+        has_m: bool,
+        builder: &mut BoundingBoxBuilder,
+    ) -> Result<()> {
+        for _ in 0..num_points {
+            let mut coords = [f64::NAN; 4];
+            coords[0] = self.read_f64(little_endian)?;
+            coords[1] = self.read_f64(little_endian)?;
+            if has_z {
+                coords[2] = self.read_f64(little_endian)?;
+            }
+            if has_m {
+                coords[3] = self.read_f64(little_endian)?;
+            }
+            builder.update(coords, has_z, has_m);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wkb_point(x: f64, y: f64) -> Vec<u8> {
+        let mut wkb = vec![1u8];
+        wkb.extend_from_slice(&WKB_POINT.to_le_bytes());
+        wkb.extend_from_slice(&x.to_le_bytes());
+        wkb.extend_from_slice(&y.to_le_bytes());
+        wkb
+    }
+
+    #[test]
+    fn test_bound_serialization() {
+        let bounds = [
+            GeospatialBound::new(1.0, 2.0),
+            GeospatialBound::new(1.0, 2.0).with_z(3.0),
+            GeospatialBound::new(1.0, 2.0).with_m(4.0),
+            GeospatialBound::new(1.0, 2.0).with_z(3.0).with_m(4.0),
+        ];
+        for (bound, len) in bounds.iter().zip([16, 24, 32, 32]) {
+            let bytes = bound.to_bytes();
+            assert_eq!(bytes.len(), len);
+            assert_eq!(&GeospatialBound::try_from_bytes(&bytes).unwrap(), bound);
+        }
+        assert!(GeospatialBound::try_from_bytes(&[0; 8]).is_err());
+    }
+
+    #[test]
+    fn test_bounding_box_from_wkb() {
+        let point = BoundingBox::try_from_wkb(&wkb_point(1.0, 2.0))
+            .unwrap()
+            .unwrap();
+        assert_eq!(point.min(), &GeospatialBound::new(1.0, 2.0));
+        assert_eq!(point.max(), &GeospatialBound::new(1.0, 2.0));
+
+        // Big endian line string with z, using the ISO type code.
+        let mut line = vec![0u8];
+        line.extend_from_slice(&(1000 + WKB_LINE_STRING).to_be_bytes());
+        line.extend_from_slice(&2u32.to_be_bytes());
+        for value in [3.0f64, -1.0, 5.0, 1.0, 4.0, -2.0] {
+            line.extend_from_slice(&value.to_be_bytes());
+        }
+        let line = BoundingBox::try_from_wkb(&line).unwrap().unwrap();
+        assert_eq!(line.min(), &GeospatialBound::new(1.0, -1.0).with_z(-2.0));
+        assert_eq!(line.max(), &GeospatialBound::new(3.0, 4.0).with_z(5.0));
+
+        // Multi point with an empty point.
+        let mut multi = vec![1u8];
+        multi.extend_from_slice(&WKB_MULTI_POINT.to_le_bytes());
+        multi.extend_from_slice(&2u32.to_le_bytes());
+        multi.extend(wkb_point(f64::NAN, f64::NAN));
+        multi.extend(wkb_point(-5.0, 7.0));
+        let multi = BoundingBox::try_from_wkb(&multi).unwrap().unwrap();
+        assert_eq!(multi.min(), &GeospatialBound::new(-5.0, 7.0));
+
+        assert_eq!(
+            BoundingBox::try_from_wkb(&wkb_point(f64::NAN, f64::NAN)).unwrap(),
+            None
+        );
+        assert!(BoundingBox::try_from_wkb(&[1, 1, 0]).is_err());
+    }
+
+    #[test]
+    fn test_bounding_box_from_nested_wkb() {
+        let nested = |depth: usize| {
+            let mut wkb = vec![];
+            for _ in 0..depth {
+                wkb.push(1u8);
+                wkb.extend_from_slice(&WKB_GEOMETRY_COLLECTION.to_le_bytes());
+                wkb.extend_from_slice(&1u32.to_le_bytes());
+            }
+            wkb.extend(wkb_point(1.0, 2.0));
+            wkb
+        };
+
+        let bbox = BoundingBox::try_from_wkb(&nested(MAX_WKB_NESTING_DEPTH))
+            .unwrap()
+            .unwrap();
+        assert_eq!(bbox.min(), &GeospatialBound::new(1.0, 2.0));
+
+        let err = BoundingBox::try_from_wkb(&nested(MAX_WKB_NESTING_DEPTH + 1)).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::DataInvalid);
+        // A crafted value nesting collections far deeper doesn't overflow the stack.
+        let err = BoundingBox::try_from_wkb(&nested(1_000_000)).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::DataInvalid);
+    }
+
+    #[test]
+    fn test_bounding_box_intersects() {
+        let bbox = |xmin, ymin, xmax, ymax| {
+            BoundingBox::new(
+                GeospatialBound::new(xmin, ymin),
+                GeospatialBound::new(xmax, ymax),
+            )
+        };
+
+        assert!(bbox(0.0, 0.0, 10.0, 10.0).intersects(&bbox(5.0, 5.0, 15.0, 15.0)));
+        assert!(bbox(0.0, 0.0, 10.0, 10.0).intersects(&bbox(10.0, 10.0, 15.0, 15.0)));
+        assert!(!bbox(0.0, 0.0, 10.0, 10.0).intersects(&bbox(11.0, 0.0, 15.0, 10.0)));
+        assert!(!bbox(0.0, 0.0, 10.0, 10.0).intersects(&bbox(0.0, 11.0, 10.0, 15.0)));
+
+        // Wraps around the antimeridian.
+        let wrapping = bbox(170.0, 0.0, -170.0, 10.0);
+        assert!(wrapping.intersects(&bbox(175.0, 5.0, 176.0, 6.0)));
+        assert!(wrapping.intersects(&bbox(-180.0, 5.0, -175.0, 6.0)));
+        assert!(!wrapping.intersects(&bbox(0.0, 5.0, 10.0, 6.0)));
+
+        assert_eq!(
+            bbox(0.0, 0.0, 1.0, 1.0).merge(&bbox(-1.0, 2.0, 0.5, 3.0)),
+            bbox(-1.0, 0.0, 1.0, 3.0)
+        );
+    }
+}
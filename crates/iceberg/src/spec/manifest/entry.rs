@@ -124,6 +124,23 @@ impl ManifestEntry {
         }
     }
 
+    /// Inherit `first_row_id` for a live data file that has none assigned, advancing
+    /// `next_row_id` by the file's record count.
+    ///
+    /// Per the spec, a null `first_row_id` is replaced by the manifest's `first_row_id`
+    /// plus the record counts of all preceding data files that also had a null `first_row_id`.
+    pub(crate) fn inherit_first_row_id(&mut self, next_row_id: &mut i64) {
+        if self.status == ManifestStatus::Deleted
+            || self.data_file.content != DataContentType::Data
+            || self.data_file.first_row_id.is_some()
+        {
+            return;
+        }
+
+        self.data_file.first_row_id = Some(*next_row_id);
+        *next_row_id += self.data_file.record_count as i64;
+    }
+
     /// Snapshot id
     #[inline]
     pub fn snapshot_id(&self) -> Option<i64> {
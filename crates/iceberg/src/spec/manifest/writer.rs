@@ -26,6 +26,7 @@ use super::{
     Datum, FormatVersion, ManifestContentType, PartitionSpec, PrimitiveType,
     UNASSIGNED_SEQUENCE_NUMBER,
 };
+use crate::encryption::{StandardKeyMetadata, encrypt_file};
 use crate::error::Result;
 use crate::io::OutputFile;
 use crate::spec::manifest::_serde::{ManifestEntryV1, ManifestEntryV2};
@@ -47,6 +48,9 @@ pub struct ManifestWriterBuilder {
 
 impl ManifestWriterBuilder {
     /// Create a new builder.
+    ///
+    /// A manifest with non-empty `key_metadata`, serialized [`StandardKeyMetadata`], is
+    /// encrypted with its key.
     pub fn new(
         output: OutputFile,
         snapshot_id: Option<i64>,
@@ -362,6 +366,19 @@ impl ManifestWriter {
         Ok(())
     }
 
+    /// Add a manifest entry without changing its status, snapshot id or sequence numbers, e.g.
+    /// to rewrite an existing manifest.
+    pub(crate) fn add_entry_unchanged(&mut self, entry: ManifestEntry) -> Result<()> {
+        self.check_data_file(&entry.data_file)?;
+        // v1 manifests have no sequence numbers to check.
+        if self.metadata.format_version == FormatVersion::V1 {
+            self.record_entry(entry);
+            Ok(())
+        } else {
+            self.add_entry_inner(entry)
+        }
+    }
+
     fn add_entry_inner(&mut self, entry: ManifestEntry) -> Result<()> {
         // Check if the entry has sequence number
         if (entry.status == ManifestStatus::Deleted || entry.status == ManifestStatus::Existing)
@@ -372,7 +389,11 @@ impl ManifestWriter {
                 "Manifest entry with status Existing or Deleted should have sequence number",
             ));
         }
+        self.record_entry(entry);
+        Ok(())
+    }
 
+    fn record_entry(&mut self, entry: ManifestEntry) {
         // Update the statistics
         match entry.status {
             ManifestStatus::Added => {
@@ -394,7 +415,6 @@ impl ManifestWriter {
             self.min_seq_num = Some(self.min_seq_num.map_or(seq_num, |v| min(v, seq_num)));
         }
         self.manifest_entries.push(entry);
-        Ok(())
     }
 
     /// Write manifest file and return it.
@@ -461,7 +481,13 @@ impl ManifestWriter {
             avro_writer.append(value)?;
         }
 
-        let content = avro_writer.into_inner()?;
+        let mut content = avro_writer.into_inner()?;
+        if let Some(key_metadata) = self.key_metadata.as_deref().filter(|k| !k.is_empty()) {
+            let (stream, key_metadata) =
+                encrypt_file(StandardKeyMetadata::decode(key_metadata)?, &content)?;
+            content = stream;
+            self.key_metadata = Some(key_metadata.encode()?);
+        }
         let length = content.len();
         self.output.write(Bytes::from(content)).await?;
 
@@ -29,6 +29,7 @@ use serde_derive::{Deserialize, Serialize};
 use self::_const_schema::{MANIFEST_LIST_AVRO_SCHEMA_V1, MANIFEST_LIST_AVRO_SCHEMA_V2};
 use self::_serde::{ManifestFileV1, ManifestFileV2};
 use super::{FormatVersion, Manifest};
+use crate::encryption::{StandardKeyMetadata, decrypt_file, encrypt_file};
 use crate::error::Result;
 use crate::io::{FileIO, OutputFile};
 use crate::spec::manifest_list::_const_schema::MANIFEST_LIST_AVRO_SCHEMA_V3;
@@ -98,6 +99,7 @@ pub struct ManifestListWriter {
     sequence_number: i64,
     snapshot_id: i64,
     next_row_id: Option<u64>,
+    key_metadata: Option<StandardKeyMetadata>,
 }
 
 impl std::fmt::Debug for ManifestListWriter {
@@ -227,9 +229,16 @@ impl ManifestListWriter {
             sequence_number,
             snapshot_id,
             next_row_id: first_row_id,
+            key_metadata: None,
         }
     }
 
+    /// Encrypt the manifest list with the key of `key_metadata`.
+    pub fn with_key_metadata(mut self, key_metadata: StandardKeyMetadata) -> Self {
+        self.key_metadata = Some(key_metadata);
+        self
+    }
+
     /// Append manifests to be written.
     ///
     /// If V3 Manifests are added and the `first_row_id` of any data manifest is unassigned,
@@ -262,12 +271,21 @@ impl ManifestListWriter {
     }
 
     /// Write the manifest list to the output file.
-    pub async fn close(self) -> Result<()> {
-        let data = self.avro_writer.into_inner()?;
+    ///
+    /// Returns the key metadata of an encrypted manifest list, recording the length of the
+    /// file, which is the key metadata to store for reading it.
+    pub async fn close(self) -> Result<Option<StandardKeyMetadata>> {
+        let mut data = self.avro_writer.into_inner()?;
+        let mut key_metadata = None;
+        if let Some(file_key_metadata) = self.key_metadata {
+            let (stream, file_key_metadata) = encrypt_file(file_key_metadata, &data)?;
+            data = stream;
+            key_metadata = Some(file_key_metadata);
+        }
         let mut writer = self.output_file.writer().await?;
         writer.write(Bytes::from(data)).await?;
         writer.close().await?;
-        Ok(())
+        Ok(key_metadata)
     }
 
     /// Assign sequence numbers to manifest if they are unassigned
@@ -846,7 +864,10 @@ impl ManifestFile {
     ///
     /// This method will also initialize inherited values of [`ManifestEntry`], such as `sequence_number`.
     pub async fn load_manifest(&self, file_io: &FileIO) -> Result<Manifest> {
-        let avro = file_io.new_input(&self.manifest_path)?.read().await?;
+        let mut avro = file_io.new_input(&self.manifest_path)?.read().await?;
+        if let Some(key_metadata) = self.key_metadata.as_deref().filter(|k| !k.is_empty()) {
+            avro = decrypt_file(&StandardKeyMetadata::decode(key_metadata)?, &avro)?.into();
+        }
 
         let (metadata, mut entries) = Manifest::try_from_avro_bytes(&avro)?;
 
@@ -855,6 +876,16 @@ impl ManifestFile {
             entry.inherit_data(self);
         }
 
+        // Assign row ids to data files written without one (v3 row lineage).
+        if self.content == ManifestContentType::Data
+            && let Some(first_row_id) = self.first_row_id
+        {
+            let mut next_row_id = first_row_id as i64;
+            for entry in &mut entries {
+                entry.inherit_first_row_id(&mut next_row_id);
+            }
+        }
+
         Ok(Manifest::new(metadata, entries))
     }
 }
@@ -19,6 +19,7 @@
 
 mod datatypes;
 mod encrypted_key;
+mod geospatial;
 mod manifest;
 mod manifest_list;
 mod name_mapping;
@@ -39,6 +40,7 @@ mod view_version;
 
 pub use datatypes::*;
 pub use encrypted_key::*;
+pub use geospatial::*;
 pub use manifest::*;
 pub use manifest_list::*;
 pub use name_mapping::*;
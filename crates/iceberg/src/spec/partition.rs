@@ -25,7 +25,7 @@ use serde::{Deserialize, Serialize};
 use typed_builder::TypedBuilder;
 
 use super::transform::Transform;
-use super::{NestedField, Schema, SchemaRef, StructType};
+use super::{NestedField, Schema, SchemaRef, StructType, Type};
 use crate::spec::Struct;
 use crate::{Error, ErrorKind, Result};
 
@@ -638,6 +638,28 @@ impl PartitionSpecBuilder {
             )
         })?;
 
+        if schema_field.field_type.is_variant() {
+            return Err(Error::new(
+                ErrorKind::DataInvalid,
+                format!(
+                    "Cannot partition by variant source field: '{}'.",
+                    schema_field.name
+                ),
+            ));
+        }
+
+        if let Type::Primitive(p) = schema_field.field_type.as_ref()
+            && p.is_geospatial()
+        {
+            return Err(Error::new(
+                ErrorKind::DataInvalid,
+                format!(
+                    "Cannot partition by {p} source field: '{}'.",
+                    schema_field.name
+                ),
+            ));
+        }
+
         if field.transform != Transform::Void {
             if !schema_field.field_type.is_primitive() {
                 return Err(Error::new(
@@ -1434,6 +1456,41 @@ mod tests {
             .unwrap_err();
     }
 
+    #[test]
+    fn test_builder_variant_source_disallowed() {
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::optional(1, "payload", Type::Variant).into(),
+            ])
+            .build()
+            .unwrap();
+
+        let err = PartitionSpec::builder(schema)
+            .with_spec_id(1)
+            .add_unbound_field(UnboundPartitionField {
+                source_id: 1,
+                field_id: None,
+                name: "payload".to_string(),
+                transform: Transform::Identity,
+            })
+            .unwrap_err();
+        assert!(err.message().contains("Cannot partition by variant"));
+    }
+
+    #[test]
+    fn test_builder_geospatial_source_disallowed() {
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::optional(1, "geom", Type::Primitive(PrimitiveType::geometry())).into(),
+            ])
+            .build()
+            .unwrap();
+        let err = PartitionSpec::builder(schema)
+            .add_partition_field("geom", "geom", Transform::Identity)
+            .unwrap_err();
+        assert!(err.message().contains("Cannot partition by geometry"));
+    }
+
     #[test]
     fn test_build_unbound_specs_without_partition_id() {
         let spec = UnboundPartitionSpec::builder()
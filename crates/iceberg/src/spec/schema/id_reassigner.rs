@@ -52,7 +52,7 @@ impl ReassignFieldIds {
         outer_fields
             .into_iter()
             .map(|field| {
-                if field.field_type.is_primitive() {
+                if field.field_type.is_primitive() || field.field_type.is_variant() {
                     Ok(field)
                 } else {
                     let mut new_field = Arc::unwrap_or_clone(field);
@@ -66,6 +66,7 @@ impl ReassignFieldIds {
     fn reassign_ids_visit_type(&mut self, field_type: Type) -> Result<Type> {
         match field_type {
             Type::Primitive(s) => Ok(Type::Primitive(s)),
+            Type::Variant => Ok(Type::Variant),
             Type::Struct(s) => {
                 let new_fields = self.reassign_field_ids(s.fields().to_vec())?;
                 Ok(Type::Struct(StructType::new(new_fields)))
@@ -397,6 +397,63 @@ impl Schema {
         self.field_id_to_accessor.get(&field_id).cloned()
     }
 
+    /// Returns the ids of the top-level fields containing the given fields.
+    ///
+    /// Nested fields are merged into the first occurrence of their top-level field, and a
+    /// top-level field selected after one of its nested fields is merged into it as well.
+    /// Repeated top-level and unknown ids (such as metadata columns) are kept as-is.
+    pub(crate) fn top_level_field_ids(&self, field_ids: &[i32]) -> Result<Vec<i32>> {
+        let id_to_parent = index_parents(&self.r#struct)?;
+
+        // Top-level fields added for a nested field, not yet selected themselves
+        let mut merged = HashSet::new();
+        let mut top_level_field_ids = Vec::with_capacity(field_ids.len());
+        for field_id in field_ids {
+            let mut cur_field_id = *field_id;
+            while let Some(parent) = id_to_parent.get(&cur_field_id) {
+                cur_field_id = *parent;
+            }
+            if cur_field_id == *field_id {
+                if !merged.remove(&cur_field_id) {
+                    top_level_field_ids.push(cur_field_id);
+                }
+            } else if !top_level_field_ids.contains(&cur_field_id) {
+                merged.insert(cur_field_id);
+                top_level_field_ids.push(cur_field_id);
+            }
+        }
+
+        Ok(top_level_field_ids)
+    }
+
+    /// Returns the key fields of the maps containing any of the given fields.
+    ///
+    /// A map can't be read without its keys, so they are projected along with any field
+    /// selected from the map's values.
+    pub(crate) fn enclosing_map_key_fields(
+        &self,
+        field_ids: &[i32],
+    ) -> Result<Vec<NestedFieldRef>> {
+        let id_to_parent = index_parents(&self.r#struct)?;
+
+        let mut key_fields: Vec<NestedFieldRef> = vec![];
+        for field_id in field_ids {
+            let mut cur_field_id = *field_id;
+            while let Some(parent) = id_to_parent.get(&cur_field_id) {
+                cur_field_id = *parent;
+                if let Some(Type::Map(map_type)) = self
+                    .field_by_id(cur_field_id)
+                    .map(|f| f.field_type.as_ref())
+                    && !key_fields.iter().any(|f| f.id == map_type.key_field.id)
+                {
+                    key_fields.push(map_type.key_field.clone());
+                }
+            }
+        }
+
+        Ok(key_fields)
+    }
+
     /// Check if this schema is identical to another schema semantically - excluding schema id.
     pub(crate) fn is_same_schema(&self, other: &SchemaRef) -> bool {
         self.as_struct().eq(other.as_struct())
@@ -445,6 +502,40 @@ mod tests {
     use crate::spec::values::Map as MapValue;
     use crate::spec::{Datum, Literal};
 
+    #[test]
+    fn test_top_level_field_ids() {
+        let schema = table_schema_nested();
+
+        assert_eq!(
+            schema
+                .top_level_field_ids(&[17, 2, 13, 16, 10, 14, 2, 2147483646])
+                .unwrap(),
+            vec![15, 2, 11, 6, 2, 2147483646]
+        );
+        // A nested field selected before its parent
+        assert_eq!(schema.top_level_field_ids(&[17, 15]).unwrap(), vec![15]);
+        assert_eq!(schema.top_level_field_ids(&[17, 15, 15]).unwrap(), vec![
+            15, 15
+        ]);
+    }
+
+    #[test]
+    fn test_enclosing_map_key_fields() {
+        let schema = table_schema_nested();
+        let key_field_ids = |field_ids: &[i32]| {
+            schema
+                .enclosing_map_key_fields(field_ids)
+                .unwrap()
+                .iter()
+                .map(|f| f.id)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(key_field_ids(&[10]), vec![9, 7]);
+        assert_eq!(key_field_ids(&[8, 10]), vec![7, 9]);
+        assert_eq!(key_field_ids(&[6, 13]), Vec::<i32>::new());
+    }
+
     #[test]
     fn test_construct_schema() {
         let field1: NestedFieldRef =
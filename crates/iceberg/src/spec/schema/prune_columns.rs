@@ -178,7 +178,9 @@ impl SchemaVisitor for PruneColumn {
                     list,
                     Type::Struct(projected_struct),
                 )?)))
-            } else if list.element_field.field_type.is_primitive() {
+            } else if list.element_field.field_type.is_primitive()
+                || list.element_field.field_type.is_variant()
+            {
                 Ok(Some(Type::List(list.clone())))
             } else {
                 Err(Error::new(
@@ -212,7 +214,9 @@ impl SchemaVisitor for PruneColumn {
                     map,
                     Type::Struct(projected_struct),
                 )?)))
-            } else if map.value_field.field_type.is_primitive() {
+            } else if map.value_field.field_type.is_primitive()
+                || map.value_field.field_type.is_variant()
+            {
                 Ok(Some(Type::Map(map.clone())))
             } else {
                 Err(Error::new(
@@ -238,6 +242,10 @@ impl SchemaVisitor for PruneColumn {
     fn primitive(&mut self, _p: &PrimitiveType) -> Result<Option<Type>> {
         Ok(None)
     }
+
+    fn variant(&mut self) -> Result<Option<Type>> {
+        Ok(None)
+    }
 }
 
 #[cfg(test)]
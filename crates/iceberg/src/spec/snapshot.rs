@@ -26,6 +26,7 @@ use serde::{Deserialize, Serialize};
 use typed_builder::TypedBuilder;
 
 use super::table_metadata::SnapshotLog;
+use crate::encryption::{StandardKeyMetadata, decrypt_file, unwrap_key_metadata};
 use crate::error::{Result, timestamp_ms_to_utc};
 use crate::io::FileIO;
 use crate::spec::{ManifestList, SchemaId, SchemaRef, TableMetadata};
@@ -202,7 +203,13 @@ impl Snapshot {
         file_io: &FileIO,
         table_metadata: &TableMetadata,
     ) -> Result<ManifestList> {
-        let manifest_list_content = file_io.new_input(&self.manifest_list)?.read().await?;
+        let mut manifest_list_content = file_io.new_input(&self.manifest_list)?.read().await?;
+        if let Some(key_id) = &self.encryption_key_id {
+            let key_metadata = self
+                .manifest_list_key_metadata(key_id, file_io, table_metadata)
+                .await?;
+            manifest_list_content = decrypt_file(&key_metadata, &manifest_list_content)?.into();
+        }
         ManifestList::parse_with_version(
             &manifest_list_content,
             // TODO: You don't really need the version since you could just project any Avro in
@@ -211,6 +218,35 @@ impl Snapshot {
         )
     }
 
+    /// Unwraps the key metadata of an encrypted manifest list with the KMS of `file_io`.
+    async fn manifest_list_key_metadata(
+        &self,
+        key_id: &str,
+        file_io: &FileIO,
+        table_metadata: &TableMetadata,
+    ) -> Result<StandardKeyMetadata> {
+        let encrypted_key = table_metadata.encryption_key(key_id).ok_or_else(|| {
+            Error::new(
+                ErrorKind::DataInvalid,
+                format!(
+                    "Encryption key {key_id} of snapshot {} doesn't exist",
+                    self.snapshot_id
+                ),
+            )
+        })?;
+        let kms = file_io.key_management_client().ok_or_else(|| {
+            Error::new(
+                ErrorKind::DataInvalid,
+                format!(
+                    "Manifest list of snapshot {} is encrypted, but no key management client is configured",
+                    self.snapshot_id
+                ),
+            )
+        })?;
+
+        unwrap_key_metadata(kms.as_ref(), encrypted_key).await
+    }
+
     #[allow(dead_code)]
     pub(crate) fn log(&self) -> SnapshotLog {
         SnapshotLog {
@@ -23,31 +23,38 @@ use super::{DataContentType, DataFile, PartitionSpecRef};
 use crate::spec::{ManifestContentType, ManifestFile, Operation, SchemaRef, Summary};
 use crate::{Error, ErrorKind, Result};
 
-const ADDED_DATA_FILES: &str = "added-data-files";
-const ADDED_DELETE_FILES: &str = "added-delete-files";
+pub(crate) const ADDED_DATA_FILES: &str = "added-data-files";
+pub(crate) const ADDED_DELETE_FILES: &str = "added-delete-files";
 const ADDED_EQUALITY_DELETES: &str = "added-equality-deletes";
-const ADDED_FILE_SIZE: &str = "added-files-size";
+pub(crate) const ADDED_FILE_SIZE: &str = "added-files-size";
 const ADDED_POSITION_DELETES: &str = "added-position-deletes";
 const ADDED_POSITION_DELETE_FILES: &str = "added-position-delete-files";
-const ADDED_RECORDS: &str = "added-records";
-const DELETED_DATA_FILES: &str = "deleted-data-files";
-const DELETED_RECORDS: &str = "deleted-records";
+pub(crate) const ADDED_RECORDS: &str = "added-records";
+pub(crate) const DELETED_DATA_FILES: &str = "deleted-data-files";
+pub(crate) const DELETED_RECORDS: &str = "deleted-records";
 const ADDED_EQUALITY_DELETE_FILES: &str = "added-equality-delete-files";
-const REMOVED_DELETE_FILES: &str = "removed-delete-files";
+pub(crate) const REMOVED_DELETE_FILES: &str = "removed-delete-files";
 const REMOVED_EQUALITY_DELETES: &str = "removed-equality-deletes";
 const REMOVED_EQUALITY_DELETE_FILES: &str = "removed-equality-delete-files";
-const REMOVED_FILE_SIZE: &str = "removed-files-size";
+pub(crate) const REMOVED_FILE_SIZE: &str = "removed-files-size";
 const REMOVED_POSITION_DELETES: &str = "removed-position-deletes";
 const REMOVED_POSITION_DELETE_FILES: &str = "removed-position-delete-files";
 const TOTAL_EQUALITY_DELETES: &str = "total-equality-deletes";
 const TOTAL_POSITION_DELETES: &str = "total-position-deletes";
-const TOTAL_DATA_FILES: &str = "total-data-files";
-const TOTAL_DELETE_FILES: &str = "total-delete-files";
-const TOTAL_RECORDS: &str = "total-records";
-const TOTAL_FILE_SIZE: &str = "total-files-size";
+pub(crate) const TOTAL_DATA_FILES: &str = "total-data-files";
+pub(crate) const TOTAL_DELETE_FILES: &str = "total-delete-files";
+pub(crate) const TOTAL_RECORDS: &str = "total-records";
+pub(crate) const TOTAL_FILE_SIZE: &str = "total-files-size";
 const CHANGED_PARTITION_COUNT_PROP: &str = "changed-partition-count";
 const CHANGED_PARTITION_PREFIX: &str = "partitions.";
 
+/// Summary property holding the write-audit-publish id of a staged snapshot.
+pub const STAGED_WAP_ID_PROP: &str = "wap.id";
+/// Summary property holding the write-audit-publish id published by a cherry-picked snapshot.
+pub const PUBLISHED_WAP_ID_PROP: &str = "published-wap-id";
+/// Summary property holding the id of the snapshot a cherry-picked snapshot was created from.
+pub const SOURCE_SNAPSHOT_ID_PROP: &str = "source-snapshot-id";
+
 /// `SnapshotSummaryCollector` collects and aggregates snapshot update metrics.
 /// It gathers metrics about added or removed data files and manifests, and tracks
 /// partition-specific updates.
@@ -53,6 +53,10 @@ pub(crate) static INITIAL_SEQUENCE_NUMBER: i64 = 0;
 pub const INITIAL_ROW_ID: u64 = 0;
 /// Minimum format version that supports row lineage (v3).
 pub const MIN_FORMAT_VERSION_ROW_LINEAGE: FormatVersion = FormatVersion::V3;
+/// Minimum format version that supports the variant type (v3).
+pub const MIN_FORMAT_VERSION_VARIANT: FormatVersion = FormatVersion::V3;
+/// Minimum format version that supports the geometry and geography types (v3).
+pub const MIN_FORMAT_VERSION_GEOSPATIAL: FormatVersion = FormatVersion::V3;
 /// Reference to [`TableMetadata`].
 pub type TableMetadataRef = Arc<TableMetadata>;
 
@@ -463,6 +467,10 @@ impl TableMetadata {
     }
 
     /// Write table metadata to the given location.
+    ///
+    /// The file is compressed with the codec of the
+    /// [`write.metadata.compression-codec`](TableProperties::PROPERTY_METADATA_COMPRESSION_CODEC)
+    /// property.
     pub async fn write_to(
         &self,
         file_io: &FileIO,
@@ -470,10 +478,59 @@ impl TableMetadata {
     ) -> Result<()> {
         file_io
             .new_output(metadata_location)?
-            .write(serde_json::to_vec(self)?.into())
+            .write(self.encode()?.into())
+            .await
+    }
+
+    /// Write table metadata to the given location unless a file already exists there, like
+    /// [`TableMetadata::write_to`].
+    ///
+    /// Returns an error of kind [`ErrorKind::PreconditionFailed`] if the file exists, or of kind
+    /// [`ErrorKind::FeatureUnsupported`] if the storage can't create files atomically.
+    pub async fn write_to_if_not_exists(
+        &self,
+        file_io: &FileIO,
+        metadata_location: impl AsRef<str>,
+    ) -> Result<()> {
+        file_io
+            .new_output(metadata_location)?
+            .write_if_not_exists(self.encode()?.into())
             .await
     }
 
+    /// Returns the extension of the metadata files of the table, `.gz.metadata.json` if they
+    /// are compressed with gzip and `.metadata.json` otherwise.
+    pub fn metadata_file_extension(&self) -> Result<&'static str> {
+        Ok(match self.metadata_compression_codec()? {
+            CompressionCodec::Gzip => ".gz.metadata.json",
+            _ => ".metadata.json",
+        })
+    }
+
+    fn metadata_compression_codec(&self) -> Result<CompressionCodec> {
+        let codec = self
+            .properties
+            .get(TableProperties::PROPERTY_METADATA_COMPRESSION_CODEC)
+            .map(String::as_str)
+            .unwrap_or(TableProperties::PROPERTY_METADATA_COMPRESSION_CODEC_DEFAULT);
+        match codec.to_ascii_lowercase().as_str() {
+            "none" => Ok(CompressionCodec::None),
+            "gzip" => Ok(CompressionCodec::Gzip),
+            _ => Err(Error::new(
+                ErrorKind::DataInvalid,
+                format!(
+                    "Invalid value for {}: {codec}",
+                    TableProperties::PROPERTY_METADATA_COMPRESSION_CODEC
+                ),
+            )),
+        }
+    }
+
+    fn encode(&self) -> Result<Vec<u8>> {
+        self.metadata_compression_codec()?
+            .compress(serde_json::to_vec(self)?)
+    }
+
     /// Normalize this partition spec.
     ///
     /// This is an internal method
@@ -1574,7 +1631,7 @@ mod tests {
         BlobMetadata, EncryptedKey, INITIAL_ROW_ID, Literal, NestedField, NullOrder, Operation,
         PartitionSpec, PartitionStatisticsFile, PrimitiveLiteral, PrimitiveType, Schema, Snapshot,
         SnapshotReference, SnapshotRetention, SortDirection, SortField, SortOrder, StatisticsFile,
-        Summary, Transform, Type, UnboundPartitionField,
+        Summary, TableProperties, Transform, Type, UnboundPartitionField,
     };
     use crate::{ErrorKind, TableCreation};
 
@@ -3567,6 +3624,52 @@ mod tests {
         assert_eq!(read_metadata, original_metadata);
     }
 
+    #[tokio::test]
+    async fn test_table_metadata_write_compressed() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_io = FileIO::new_with_fs();
+
+        let mut metadata: TableMetadata = get_test_table_metadata("TableMetadataV2Valid.json");
+        assert_eq!(
+            metadata.metadata_file_extension().unwrap(),
+            ".metadata.json"
+        );
+
+        metadata.properties.insert(
+            TableProperties::PROPERTY_METADATA_COMPRESSION_CODEC.to_string(),
+            "gzip".to_string(),
+        );
+        let extension = metadata.metadata_file_extension().unwrap();
+        assert_eq!(extension, ".gz.metadata.json");
+
+        let metadata_location = format!("{}/v1{extension}", temp_dir.path().to_str().unwrap());
+        metadata
+            .write_to_if_not_exists(&file_io, &metadata_location)
+            .await
+            .unwrap();
+
+        let content = std::fs::read(&metadata_location).unwrap();
+        assert_eq!(&content[..2], &[0x1F, 0x8B]);
+        assert_eq!(
+            TableMetadata::read_from(&file_io, &metadata_location)
+                .await
+                .unwrap(),
+            metadata
+        );
+
+        let err = metadata
+            .write_to_if_not_exists(&file_io, &metadata_location)
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::PreconditionFailed);
+
+        metadata.properties.insert(
+            TableProperties::PROPERTY_METADATA_COMPRESSION_CODEC.to_string(),
+            "lz4".to_string(),
+        );
+        assert!(metadata.metadata_file_extension().is_err());
+    }
+
     #[tokio::test]
     async fn test_table_metadata_read_compressed() {
         let temp_dir = TempDir::new().unwrap();
@@ -21,14 +21,17 @@ use std::sync::Arc;
 use uuid::Uuid;
 
 use super::{
-    DEFAULT_PARTITION_SPEC_ID, DEFAULT_SCHEMA_ID, FormatVersion, MAIN_BRANCH, MetadataLog,
-    ONE_MINUTE_MS, PartitionSpec, PartitionSpecBuilder, PartitionStatisticsFile, Schema, SchemaRef,
-    Snapshot, SnapshotLog, SnapshotReference, SnapshotRetention, SortOrder, SortOrderRef,
-    StatisticsFile, StructType, TableMetadata, TableProperties, UNPARTITIONED_LAST_ASSIGNED_ID,
-    UnboundPartitionSpec,
+    DEFAULT_PARTITION_SPEC_ID, DEFAULT_SCHEMA_ID, FormatVersion, ListType, MAIN_BRANCH, MapType,
+    MetadataLog, NestedFieldRef, ONE_MINUTE_MS, PartitionSpec, PartitionSpecBuilder,
+    PartitionStatisticsFile, Schema, SchemaRef, Snapshot, SnapshotLog, SnapshotReference,
+    SnapshotRetention, SortOrder, SortOrderRef, StatisticsFile, StructType, TableMetadata,
+    TableProperties, Type, UNPARTITIONED_LAST_ASSIGNED_ID, UnboundPartitionSpec,
 };
 use crate::error::{Error, ErrorKind, Result};
-use crate::spec::{EncryptedKey, INITIAL_ROW_ID, MIN_FORMAT_VERSION_ROW_LINEAGE};
+use crate::spec::{
+    EncryptedKey, INITIAL_ROW_ID, MIN_FORMAT_VERSION_GEOSPATIAL, MIN_FORMAT_VERSION_ROW_LINEAGE,
+    MIN_FORMAT_VERSION_VARIANT, PrimitiveType,
+};
 use crate::{TableCreation, TableUpdate};
 
 pub(crate) const FIRST_FIELD_ID: i32 = 1;
@@ -196,6 +199,74 @@ impl TableMetadataBuilder {
         )
     }
 
+    /// Creates a builder replacing the definition of a table with the given table creation,
+    /// as done by `CREATE OR REPLACE TABLE`.
+    ///
+    /// The schema, partition spec, sort order and format version of the table creation
+    /// become the current ones, its location and properties are set, and the `main` branch
+    /// is removed. Snapshots and history of `previous` are kept. Fields of the new schema
+    /// keep the id of the field with the same name in the current schema, other fields get
+    /// fresh ids.
+    pub fn replace_from_table_creation(
+        previous: TableMetadata,
+        current_file_location: Option<String>,
+        table_creation: TableCreation,
+    ) -> Result<Self> {
+        let TableCreation {
+            name: _,
+            location,
+            schema,
+            partition_spec,
+            sort_order,
+            properties,
+            format_version,
+        } = table_creation;
+
+        let mut assigner = ReplacementFieldIds::new(&previous);
+        let fields = assigner.assign_fields(schema.as_struct().fields(), None)?;
+        let identifier_field_ids = schema
+            .identifier_field_ids()
+            .map(|id| assigner.new_id(id))
+            .collect::<Result<Vec<_>>>()?;
+        let new_schema = Schema::builder()
+            .with_fields(fields)
+            .with_identifier_field_ids(identifier_field_ids)
+            .build()?;
+
+        let partition_spec = UnboundPartitionSpec {
+            spec_id: None,
+            fields: partition_spec
+                .map(|spec| spec.fields)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|mut field| {
+                    field.source_id = assigner.new_id(field.source_id)?;
+                    field.field_id = None;
+                    Ok(field)
+                })
+                .collect::<Result<_>>()?,
+        };
+
+        let mut sort_order = sort_order.unwrap_or(SortOrder::unsorted_order());
+        for field in &mut sort_order.fields {
+            field.source_id = assigner.new_id(field.source_id)?;
+        }
+
+        let mut builder = Self::new_from_metadata(previous, current_file_location);
+        if format_version > builder.metadata.format_version {
+            builder = builder.upgrade_format_version(format_version)?;
+        }
+        builder = builder
+            .add_current_schema(new_schema)?
+            .add_default_partition_spec(partition_spec)?
+            .add_default_sort_order(sort_order)?;
+        if let Some(location) = location {
+            builder = builder.set_location(location);
+        }
+
+        Ok(builder.set_properties(properties)?.remove_ref(MAIN_BRANCH))
+    }
+
     /// Changes uuid of table metadata.
     pub fn assign_uuid(mut self, uuid: Uuid) -> Self {
         if self.metadata.table_uuid != uuid {
@@ -640,6 +711,7 @@ impl TableMetadataBuilder {
     pub fn add_schema(mut self, schema: Schema) -> Result<Self> {
         // Validate that new schema fields don't conflict with existing partition field names
         self.validate_schema_field_names(&schema)?;
+        self.validate_schema_types(&schema)?;
 
         let new_schema_id = self.reuse_or_create_new_schema_id(&schema);
         let schema_found = self.metadata.schemas.contains_key(&new_schema_id);
@@ -731,6 +803,36 @@ impl TableMetadataBuilder {
             .set_current_schema(Self::LAST_ADDED)
     }
 
+    /// Validate that the schema only uses types supported by the table's format version.
+    fn validate_schema_types(&self, schema: &Schema) -> Result<()> {
+        for (field_id, name) in schema.field_id_to_name_map() {
+            let Some(field) = schema.field_by_id(*field_id) else {
+                continue;
+            };
+            let (type_name, min_format_version) = match field.field_type.as_ref() {
+                Type::Variant => ("variant", MIN_FORMAT_VERSION_VARIANT),
+                Type::Primitive(PrimitiveType::Geometry { .. }) => {
+                    ("geometry", MIN_FORMAT_VERSION_GEOSPATIAL)
+                }
+                Type::Primitive(PrimitiveType::Geography { .. }) => {
+                    ("geography", MIN_FORMAT_VERSION_GEOSPATIAL)
+                }
+                _ => continue,
+            };
+            if self.metadata.format_version < min_format_version {
+                return Err(Error::new(
+                    ErrorKind::DataInvalid,
+                    format!(
+                        "Invalid type for {name}: {type_name} is not supported until {min_format_version} but format version is {}",
+                        self.metadata.format_version
+                    ),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
     /// Validate schema field names against partition field names across all historical schemas.
     ///
     /// Due to Iceberg's multi-version property, this check ignores existing schema fields
@@ -1452,6 +1554,98 @@ impl TableMetadataBuilder {
     }
 }
 
+/// Assigns the field ids of a schema replacing the current schema of a table.
+struct ReplacementFieldIds<'a> {
+    current_schema: &'a Schema,
+    last_column_id: i32,
+    old_to_new_id: HashMap<i32, i32>,
+}
+
+impl<'a> ReplacementFieldIds<'a> {
+    fn new(metadata: &'a TableMetadata) -> Self {
+        Self {
+            current_schema: metadata.current_schema(),
+            last_column_id: metadata.last_column_id,
+            old_to_new_id: HashMap::new(),
+        }
+    }
+
+    fn assign_fields(
+        &mut self,
+        fields: &[NestedFieldRef],
+        parent: Option<&str>,
+    ) -> Result<Vec<NestedFieldRef>> {
+        fields
+            .iter()
+            .map(|field| {
+                let name = match parent {
+                    Some(parent) => format!("{parent}.{}", field.name),
+                    None => field.name.clone(),
+                };
+                let id = self.assign_id(field.id, &name)?;
+                let field_type = self.assign_type(&field.field_type, &name)?;
+
+                let mut field = field.as_ref().clone().with_id(id);
+                *field.field_type = field_type;
+                Ok(Arc::new(field))
+            })
+            .collect()
+    }
+
+    fn assign_type(&mut self, field_type: &Type, name: &str) -> Result<Type> {
+        match field_type {
+            Type::Struct(struct_type) => Ok(Type::Struct(StructType::new(
+                self.assign_fields(struct_type.fields(), Some(name))?,
+            ))),
+            Type::List(list_type) => {
+                let mut fields =
+                    self.assign_fields(std::slice::from_ref(&list_type.element_field), Some(name))?;
+                Ok(Type::List(ListType {
+                    element_field: fields.remove(0),
+                }))
+            }
+            Type::Map(map_type) => {
+                let mut fields = self.assign_fields(
+                    &[map_type.key_field.clone(), map_type.value_field.clone()],
+                    Some(name),
+                )?;
+                let value_field = fields.remove(1);
+                Ok(Type::Map(MapType {
+                    key_field: fields.remove(0),
+                    value_field,
+                }))
+            }
+            Type::Primitive(_) | Type::Variant => Ok(field_type.clone()),
+        }
+    }
+
+    fn assign_id(&mut self, old_id: i32, name: &str) -> Result<i32> {
+        let id = match self.current_schema.field_by_name(name) {
+            Some(field) => field.id,
+            None => {
+                self.last_column_id = self.last_column_id.checked_add(1).ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::DataInvalid,
+                        "Field ID overflowed, cannot add more fields",
+                    )
+                })?;
+                self.last_column_id
+            }
+        };
+        self.old_to_new_id.insert(old_id, id);
+        Ok(id)
+    }
+
+    fn new_id(&self, old_id: i32) -> Result<i32> {
+        self.old_to_new_id.get(&old_id).copied().ok_or_else(|| {
+            Error::new(
+                ErrorKind::DataInvalid,
+                format!("Cannot find field with id {old_id} in the replacement schema"),
+            )
+        })
+    }
+}
+
 impl From<TableMetadataBuildResult> for TableMetadata {
     fn from(result: TableMetadataBuildResult) -> Self {
         result.metadata
@@ -1468,9 +1662,9 @@ mod tests {
     use crate::TableIdent;
     use crate::io::FileIO;
     use crate::spec::{
-        BlobMetadata, NestedField, NullOrder, Operation, PartitionSpec, PrimitiveType, Schema,
-        SnapshotRetention, SortDirection, SortField, StructType, Summary, TableProperties,
-        Transform, Type, UnboundPartitionField,
+        BlobMetadata, DEFAULT_CRS, EdgeAlgorithm, NestedField, NullOrder, Operation, PartitionSpec,
+        PrimitiveType, Schema, SnapshotRetention, SortDirection, SortField, StructType, Summary,
+        TableProperties, Transform, Type, UnboundPartitionField,
     };
     use crate::table::Table;
 
@@ -2048,6 +2242,73 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_add_variant_schema_requires_v3() {
+        let added_schema = Schema::builder()
+            .with_schema_id(1)
+            .with_fields(vec![
+                NestedField::required(1, "x", Type::Primitive(PrimitiveType::Long)).into(),
+                NestedField::required(2, "y", Type::Primitive(PrimitiveType::Long)).into(),
+                NestedField::required(3, "z", Type::Primitive(PrimitiveType::Long)).into(),
+                NestedField::optional(4, "v", Type::Variant).into(),
+            ])
+            .build()
+            .unwrap();
+
+        let err = builder_without_changes(FormatVersion::V2)
+            .add_current_schema(added_schema.clone())
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::DataInvalid);
+        assert!(err.message().contains("variant is not supported until v3"));
+
+        let build_result = builder_without_changes(FormatVersion::V3)
+            .add_current_schema(added_schema)
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(build_result.metadata.current_schema_id, 1);
+    }
+
+    #[test]
+    fn test_add_geospatial_schema_requires_v3() {
+        for (name, field_type) in [
+            ("geometry", PrimitiveType::Geometry {
+                crs: DEFAULT_CRS.to_string(),
+            }),
+            ("geography", PrimitiveType::Geography {
+                crs: DEFAULT_CRS.to_string(),
+                algorithm: EdgeAlgorithm::default(),
+            }),
+        ] {
+            let added_schema = Schema::builder()
+                .with_schema_id(1)
+                .with_fields(vec![
+                    NestedField::required(1, "x", Type::Primitive(PrimitiveType::Long)).into(),
+                    NestedField::required(2, "y", Type::Primitive(PrimitiveType::Long)).into(),
+                    NestedField::required(3, "z", Type::Primitive(PrimitiveType::Long)).into(),
+                    NestedField::optional(4, "geo", Type::Primitive(field_type)).into(),
+                ])
+                .build()
+                .unwrap();
+
+            let err = builder_without_changes(FormatVersion::V2)
+                .add_current_schema(added_schema.clone())
+                .unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::DataInvalid);
+            assert!(
+                err.message()
+                    .contains(&format!("{name} is not supported until v3"))
+            );
+
+            let build_result = builder_without_changes(FormatVersion::V3)
+                .add_current_schema(added_schema)
+                .unwrap()
+                .build()
+                .unwrap();
+            assert_eq!(build_result.metadata.current_schema_id, 1);
+        }
+    }
+
     #[test]
     fn test_set_current_schema_change_is_minus_one_if_schema_was_added_in_this_change() {
         let builder = builder_without_changes(FormatVersion::V2);
@@ -2211,6 +2472,81 @@ mod tests {
         }])
     }
 
+    #[test]
+    fn test_replace_from_table_creation() {
+        let builder = builder_without_changes(FormatVersion::V2);
+        let snapshot = Snapshot::builder()
+            .with_snapshot_id(1)
+            .with_timestamp_ms(builder.metadata.last_updated_ms + 1)
+            .with_sequence_number(1)
+            .with_schema_id(0)
+            .with_manifest_list("/snap-1.avro")
+            .with_summary(Summary {
+                operation: Operation::Append,
+                additional_properties: HashMap::new(),
+            })
+            .build();
+        let base = builder
+            .set_branch_snapshot(snapshot, MAIN_BRANCH)
+            .unwrap()
+            .build()
+            .unwrap()
+            .metadata;
+
+        let creation = TableCreation::builder()
+            .name("table".to_string())
+            .schema(
+                Schema::builder()
+                    .with_fields(vec![
+                        NestedField::required(1, "z", Type::Primitive(PrimitiveType::Long)).into(),
+                        NestedField::optional(2, "w", Type::Primitive(PrimitiveType::String))
+                            .into(),
+                    ])
+                    .build()
+                    .unwrap(),
+            )
+            .partition_spec(
+                UnboundPartitionSpec::builder()
+                    .add_partition_field(2, "w", Transform::Identity)
+                    .unwrap()
+                    .build(),
+            )
+            .properties(HashMap::from([("key".to_string(), "value".to_string())]))
+            .build();
+
+        let build_result =
+            TableMetadataBuilder::replace_from_table_creation(base.clone(), None, creation)
+                .unwrap()
+                .build()
+                .unwrap();
+        let metadata = build_result.metadata;
+
+        // "z" keeps its id, "w" gets a fresh one.
+        let current_schema = metadata.current_schema();
+        assert_eq!(current_schema.schema_id(), 1);
+        assert_eq!(current_schema.field_by_name("z").unwrap().id, 3);
+        assert_eq!(current_schema.field_by_name("w").unwrap().id, 4);
+        assert_eq!(metadata.last_column_id, 4);
+
+        let default_spec = metadata.default_partition_spec();
+        assert_eq!(default_spec.spec_id(), 1);
+        assert_eq!(default_spec.fields()[0].source_id, 4);
+        assert!(metadata.default_sort_order().is_unsorted());
+        assert_eq!(metadata.properties.get("key"), Some(&"value".to_string()));
+
+        // The history is kept, but the table has no current snapshot.
+        assert_eq!(metadata.current_snapshot_id, None);
+        assert!(metadata.snapshot_by_id(1).is_some());
+        assert_eq!(metadata.table_uuid, base.table_uuid);
+        assert!(
+            build_result
+                .changes
+                .contains(&TableUpdate::RemoveSnapshotRef {
+                    ref_name: MAIN_BRANCH.to_string()
+                })
+        );
+    }
+
     #[test]
     fn test_snapshot_log_skips_intermediates() {
         let builder = builder_without_changes(FormatVersion::V2);
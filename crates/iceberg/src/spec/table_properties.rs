@@ -51,6 +51,12 @@ pub struct TableProperties {
     pub write_target_file_size_bytes: usize,
     /// Whether to use `FanoutWriter` for partitioned tables.
     pub write_datafusion_fanout_enabled: bool,
+    /// Whether snapshots produced with a `wap.id` are staged instead of committed to `main`.
+    pub write_wap_enabled: bool,
+    /// The id of the master key encrypting the table, if the table is encrypted.
+    pub encryption_table_key_id: Option<String>,
+    /// The length in bytes of the data encryption keys.
+    pub encryption_data_key_length: usize,
 }
 
 impl TableProperties {
@@ -87,6 +93,11 @@ impl TableProperties {
     /// Default value for max number of previous versions to keep.
     pub const PROPERTY_METADATA_PREVIOUS_VERSIONS_MAX_DEFAULT: usize = 100;
 
+    /// Property key for the codec compressing the metadata files, `none` or `gzip`.
+    pub const PROPERTY_METADATA_COMPRESSION_CODEC: &str = "write.metadata.compression-codec";
+    /// Default value for the codec compressing the metadata files.
+    pub const PROPERTY_METADATA_COMPRESSION_CODEC_DEFAULT: &str = "none";
+
     /// Property key for max number of partitions to keep summary stats for.
     pub const PROPERTY_WRITE_PARTITION_SUMMARY_LIMIT: &str = "write.summary.partition-limit";
     /// Default value for the max number of partitions to keep summary stats for.
@@ -144,6 +155,24 @@ impl TableProperties {
     pub const PROPERTY_DATAFUSION_WRITE_FANOUT_ENABLED: &str = "write.datafusion.fanout.enabled";
     /// Default value for fanout writer enabled
     pub const PROPERTY_DATAFUSION_WRITE_FANOUT_ENABLED_DEFAULT: bool = true;
+
+    /// Property key for enabling write-audit-publish.
+    ///
+    /// When enabled, snapshots whose summary contains a `wap.id` are added to the table
+    /// metadata without becoming current, and can later be published with cherry-pick.
+    pub const PROPERTY_WRITE_WAP_ENABLED: &str = "write.wap.enabled";
+    /// Default value for write-audit-publish enabled
+    pub const PROPERTY_WRITE_WAP_ENABLED_DEFAULT: bool = false;
+
+    /// Property key for the id of the master key encrypting the table.
+    ///
+    /// Tables with this property have their data files, manifests and manifest lists
+    /// encrypted, with keys wrapped by this master key of the key management service.
+    pub const PROPERTY_ENCRYPTION_TABLE_KEY: &str = "encryption.key-id";
+    /// Property key for the length in bytes of the data encryption keys, 16 or 32.
+    pub const PROPERTY_ENCRYPTION_DEK_LENGTH: &str = "encryption.data-key-length";
+    /// Default value for the length of the data encryption keys
+    pub const PROPERTY_ENCRYPTION_DEK_LENGTH_DEFAULT: usize = 16;
 }
 
 impl TryFrom<&HashMap<String, String>> for TableProperties {
@@ -187,6 +216,19 @@ impl TryFrom<&HashMap<String, String>> for TableProperties {
                 TableProperties::PROPERTY_DATAFUSION_WRITE_FANOUT_ENABLED,
                 TableProperties::PROPERTY_DATAFUSION_WRITE_FANOUT_ENABLED_DEFAULT,
             )?,
+            write_wap_enabled: parse_property(
+                props,
+                TableProperties::PROPERTY_WRITE_WAP_ENABLED,
+                TableProperties::PROPERTY_WRITE_WAP_ENABLED_DEFAULT,
+            )?,
+            encryption_table_key_id: props
+                .get(TableProperties::PROPERTY_ENCRYPTION_TABLE_KEY)
+                .cloned(),
+            encryption_data_key_length: parse_property(
+                props,
+                TableProperties::PROPERTY_ENCRYPTION_DEK_LENGTH,
+                TableProperties::PROPERTY_ENCRYPTION_DEK_LENGTH_DEFAULT,
+            )?,
         })
     }
 }
@@ -219,6 +261,10 @@ mod tests {
             table_properties.write_target_file_size_bytes,
             TableProperties::PROPERTY_WRITE_TARGET_FILE_SIZE_BYTES_DEFAULT
         );
+        assert_eq!(
+            table_properties.write_wap_enabled,
+            TableProperties::PROPERTY_WRITE_WAP_ENABLED_DEFAULT
+        );
     }
 
     #[test]
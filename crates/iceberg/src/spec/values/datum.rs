@@ -419,6 +419,9 @@ impl Datum {
             }
             PrimitiveType::Fixed(_) => PrimitiveLiteral::Binary(Vec::from(bytes)),
             PrimitiveType::Binary => PrimitiveLiteral::Binary(Vec::from(bytes)),
+            PrimitiveType::Geometry { .. } | PrimitiveType::Geography { .. } => {
+                PrimitiveLiteral::Binary(Vec::from(bytes))
+            }
             PrimitiveType::Decimal { .. } => {
                 PrimitiveLiteral::Int128(i128_from_be_bytes(bytes).ok_or_else(|| {
                     Error::new(
@@ -1003,6 +1006,32 @@ impl Datum {
         }
     }
 
+    /// Creates a geometry literal from WKB bytes, in the default coordinate reference system.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use iceberg::spec::{Datum, PrimitiveType};
+    /// let t = Datum::geometry(vec![1u8, 100u8]);
+    ///
+    /// assert_eq!(t.data_type(), &PrimitiveType::geometry());
+    /// ```
+    pub fn geometry<I: IntoIterator<Item = u8>>(wkb: I) -> Self {
+        Self {
+            r#type: PrimitiveType::geometry(),
+            literal: PrimitiveLiteral::Binary(wkb.into_iter().collect()),
+        }
+    }
+
+    /// Creates a geography literal from WKB bytes, in the default coordinate reference
+    /// system and edge algorithm.
+    pub fn geography<I: IntoIterator<Item = u8>>(wkb: I) -> Self {
+        Self {
+            r#type: PrimitiveType::geography(),
+            literal: PrimitiveLiteral::Binary(wkb.into_iter().collect()),
+        }
+    }
+
     /// Creates decimal literal from string.
     ///
     /// Example:
@@ -1147,6 +1176,12 @@ impl Datum {
                         Datum::timestamptz_from_str(val)
                     }
 
+                    (PrimitiveLiteral::Binary(_), self_type, target_type)
+                        if self_type.is_geospatial() && target_type.is_geospatial() =>
+                    {
+                        Ok(Datum::new(target_type.clone(), self.literal))
+                    }
+
                     // TODO: implement more type conversions
                     (_, self_type, target_type) if self_type == target_type => Ok(self),
                     _ => Err(Error::new(
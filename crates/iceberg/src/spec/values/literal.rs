@@ -595,6 +595,13 @@ impl Literal {
                     ))
                 }
             }
+            Type::Variant => match value {
+                JsonValue::Null => Ok(None),
+                _ => Err(Error::new(
+                    crate::ErrorKind::DataInvalid,
+                    "Variant type has no json single-value serialization, only null is allowed.",
+                )),
+            },
         }
     }
 
@@ -448,6 +448,10 @@ pub(crate) mod _serde {
                 },
                 RawLiteralEnum::Bytes(v) => match ty {
                     Type::Primitive(PrimitiveType::Binary) => Ok(Some(Literal::binary(v.to_vec()))),
+                    Type::Primitive(PrimitiveType::Geometry { .. })
+                    | Type::Primitive(PrimitiveType::Geography { .. }) => {
+                        Ok(Some(Literal::binary(v.to_vec())))
+                    }
                     Type::Primitive(PrimitiveType::Fixed(expected_len)) => {
                         if v.len() == *expected_len as usize {
                             Ok(Some(Literal::fixed(v.to_vec())))
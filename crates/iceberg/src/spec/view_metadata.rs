@@ -33,6 +33,7 @@ pub use super::view_metadata_builder::ViewMetadataBuilder;
 use super::view_version::{ViewVersionId, ViewVersionRef};
 use super::{SchemaId, SchemaRef};
 use crate::error::{Result, timestamp_ms_to_utc};
+use crate::io::FileIO;
 use crate::{Error, ErrorKind};
 
 /// Reference to [`ViewMetadata`].
@@ -161,6 +162,28 @@ impl ViewMetadata {
         &self.version_log
     }
 
+    /// Read view metadata from the given location.
+    pub async fn read_from(
+        file_io: &FileIO,
+        metadata_location: impl AsRef<str>,
+    ) -> Result<ViewMetadata> {
+        let input_file = file_io.new_input(metadata_location)?;
+        let metadata_content = input_file.read().await?;
+        Ok(serde_json::from_slice(&metadata_content)?)
+    }
+
+    /// Write view metadata to the given location.
+    pub async fn write_to(
+        &self,
+        file_io: &FileIO,
+        metadata_location: impl AsRef<str>,
+    ) -> Result<()> {
+        file_io
+            .new_output(metadata_location)?
+            .write(serde_json::to_vec(self)?.into())
+            .await
+    }
+
     /// Validate the view metadata.
     pub(super) fn validate(&self) -> Result<()> {
         self.validate_current_version_id()?;
@@ -136,6 +136,11 @@ impl ViewMetadataBuilder {
         Self::new(location, schema, version, ViewFormatVersion::V1, properties)
     }
 
+    /// Returns the current version of the view being built.
+    pub(crate) fn current_version(&self) -> &ViewVersionRef {
+        self.metadata.current_version()
+    }
+
     /// Upgrade `FormatVersion`. Downgrades are not allowed.
     ///
     /// # Errors
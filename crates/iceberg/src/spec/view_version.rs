@@ -191,6 +191,12 @@ impl IntoIterator for ViewRepresentations {
     }
 }
 
+impl FromIterator<ViewRepresentation> for ViewRepresentations {
+    fn from_iter<T: IntoIterator<Item = ViewRepresentation>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
 #[serde(tag = "type")]
 /// View definitions can be represented in multiple ways.
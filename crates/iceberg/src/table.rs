@@ -19,11 +19,13 @@
 
 use std::sync::Arc;
 
+use crate::actions::RewriteTablePath;
 use crate::arrow::ArrowReaderBuilder;
 use crate::inspect::MetadataTable;
 use crate::io::FileIO;
 use crate::io::object_cache::ObjectCache;
-use crate::scan::TableScanBuilder;
+use crate::metrics::MetricsReporter;
+use crate::scan::{ScanPlanner, TableScanBuilder};
 use crate::spec::{SchemaRef, TableMetadata, TableMetadataRef};
 use crate::{Error, ErrorKind, Result, TableIdent};
 
@@ -36,6 +38,8 @@ pub struct TableBuilder {
     readonly: bool,
     disable_cache: bool,
     cache_size_bytes: Option<u64>,
+    scan_planner: Option<Arc<dyn ScanPlanner>>,
+    metrics_reporter: Option<Arc<dyn MetricsReporter>>,
 }
 
 impl TableBuilder {
@@ -48,6 +52,8 @@ impl TableBuilder {
             readonly: false,
             disable_cache: false,
             cache_size_bytes: None,
+            scan_planner: None,
+            metrics_reporter: None,
         }
     }
 
@@ -95,6 +101,18 @@ impl TableBuilder {
         self
     }
 
+    /// optional - delegates the planning of the table's scans to a [`ScanPlanner`]
+    pub fn scan_planner(mut self, scan_planner: Arc<dyn ScanPlanner>) -> Self {
+        self.scan_planner = Some(scan_planner);
+        self
+    }
+
+    /// optional - sets the [`MetricsReporter`] the table's scans and commits report to
+    pub fn metrics_reporter(mut self, metrics_reporter: Option<Arc<dyn MetricsReporter>>) -> Self {
+        self.metrics_reporter = metrics_reporter;
+        self
+    }
+
     /// build the Table
     pub fn build(self) -> Result<Table> {
         let Self {
@@ -105,6 +123,8 @@ impl TableBuilder {
             readonly,
             disable_cache,
             cache_size_bytes,
+            scan_planner,
+            metrics_reporter,
         } = self;
 
         let Some(file_io) = file_io else {
@@ -146,6 +166,8 @@ impl TableBuilder {
             identifier,
             readonly,
             object_cache,
+            scan_planner,
+            metrics_reporter,
         })
     }
 }
@@ -159,6 +181,8 @@ pub struct Table {
     identifier: TableIdent,
     readonly: bool,
     object_cache: Arc<ObjectCache>,
+    scan_planner: Option<Arc<dyn ScanPlanner>>,
+    metrics_reporter: Option<Arc<dyn MetricsReporter>>,
 }
 
 impl Table {
@@ -174,6 +198,12 @@ impl Table {
         self
     }
 
+    /// Sets the [`MetricsReporter`] the table's scans and commits report to.
+    pub fn with_metrics_reporter(mut self, metrics_reporter: Arc<dyn MetricsReporter>) -> Self {
+        self.metrics_reporter = Some(metrics_reporter);
+        self
+    }
+
     /// Returns a TableBuilder to build a table
     pub fn builder() -> TableBuilder {
         TableBuilder::new()
@@ -219,6 +249,16 @@ impl Table {
         self.object_cache.clone()
     }
 
+    /// Returns the planner the table's scans are delegated to, if any.
+    pub fn scan_planner(&self) -> Option<&Arc<dyn ScanPlanner>> {
+        self.scan_planner.as_ref()
+    }
+
+    /// Returns the reporter of the table's scan and commit metrics, if any.
+    pub fn metrics_reporter(&self) -> Option<&Arc<dyn MetricsReporter>> {
+        self.metrics_reporter.as_ref()
+    }
+
     /// Creates a table scan.
     pub fn scan(&self) -> TableScanBuilder<'_> {
         TableScanBuilder::new(self)
@@ -230,6 +270,16 @@ impl Table {
         MetadataTable::new(self)
     }
 
+    /// Creates an action rewriting the absolute paths of this table from `source_prefix` to
+    /// `target_prefix`, to copy the table to another location.
+    pub fn rewrite_table_path(
+        &self,
+        source_prefix: impl ToString,
+        target_prefix: impl ToString,
+    ) -> RewriteTablePath<'_> {
+        RewriteTablePath::new(self, source_prefix.to_string(), target_prefix.to_string())
+    }
+
     /// Returns the flag indicating whether the `Table` is readonly or not
     pub fn readonly(&self) -> bool {
         self.readonly
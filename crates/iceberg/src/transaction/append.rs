@@ -22,11 +22,13 @@ use async_trait::async_trait;
 use uuid::Uuid;
 
 use crate::error::Result;
+use crate::expr::Predicate;
 use crate::spec::{DataFile, ManifestEntry, ManifestFile, Operation};
 use crate::table::Table;
 use crate::transaction::snapshot::{
     DefaultManifestProcess, SnapshotProduceOperation, SnapshotProducer,
 };
+use crate::transaction::validate::SnapshotValidator;
 use crate::transaction::{ActionCommit, TransactionAction};
 
 /// FastAppendAction is a transaction action for fast append data files to the table.
@@ -37,16 +39,18 @@ pub struct FastAppendAction {
     key_metadata: Option<Vec<u8>>,
     snapshot_properties: HashMap<String, String>,
     added_data_files: Vec<DataFile>,
+    validator: SnapshotValidator,
 }
 
 impl FastAppendAction {
-    pub(crate) fn new() -> Self {
+    pub(crate) fn new(starting_snapshot_id: Option<i64>) -> Self {
         Self {
             check_duplicate: true,
             commit_uuid: None,
             key_metadata: None,
             snapshot_properties: HashMap::default(),
             added_data_files: vec![],
+            validator: SnapshotValidator::new(starting_snapshot_id),
         }
     }
 
@@ -79,6 +83,44 @@ impl FastAppendAction {
         self.snapshot_properties = snapshot_properties;
         self
     }
+
+    /// Set the snapshot this append was based on.
+    ///
+    /// Conflict validation only considers snapshots committed after it. Defaults to the
+    /// current snapshot of the table the transaction was created from.
+    pub fn validate_from_snapshot(mut self, snapshot_id: i64) -> Self {
+        self.validator.set_from_snapshot(snapshot_id);
+        self
+    }
+
+    /// Set the row filter used to detect conflicting concurrent changes.
+    ///
+    /// Only files that may contain rows matching the filter are treated as conflicts.
+    pub fn conflict_detection_filter(mut self, filter: Predicate) -> Self {
+        self.validator.set_conflict_detection_filter(filter);
+        self
+    }
+
+    /// Fail the commit if data files matching the conflict detection filter were added
+    /// by a concurrent commit.
+    pub fn validate_no_conflicting_data(mut self) -> Self {
+        self.validator.set_no_new_data_files();
+        self
+    }
+
+    /// Fail the commit if delete files matching the conflict detection filter were added
+    /// by a concurrent commit.
+    pub fn validate_no_conflicting_deletes(mut self) -> Self {
+        self.validator.set_no_new_delete_files();
+        self
+    }
+
+    /// Fail the commit if data files matching the conflict detection filter were deleted
+    /// by a concurrent commit.
+    pub fn validate_deleted_files(mut self) -> Self {
+        self.validator.set_no_deleted_data_files();
+        self
+    }
 }
 
 #[async_trait]
@@ -100,13 +142,16 @@ impl TransactionAction for FastAppendAction {
             snapshot_producer.validate_duplicate_files().await?;
         }
 
+        // Checks changes committed concurrently since the starting snapshot
+        self.validator.validate(table).await?;
+
         snapshot_producer
             .commit(FastAppendOperation, DefaultManifestProcess)
             .await
     }
 }
 
-struct FastAppendOperation;
+pub(crate) struct FastAppendOperation;
 
 impl SnapshotProduceOperation for FastAppendOperation {
     fn operation(&self) -> Operation {
@@ -0,0 +1,371 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::spec::{
+    DataFile, MAIN_BRANCH, ManifestContentType, ManifestStatus, Operation, PUBLISHED_WAP_ID_PROP,
+    SOURCE_SNAPSHOT_ID_PROP, STAGED_WAP_ID_PROP, SnapshotRef, SnapshotReference, SnapshotRetention,
+    TableMetadata,
+};
+use crate::table::Table;
+use crate::transaction::append::FastAppendOperation;
+use crate::transaction::snapshot::{DefaultManifestProcess, SnapshotProducer};
+use crate::transaction::{ActionCommit, TransactionAction};
+use crate::{Error, ErrorKind, Result, TableRequirement, TableUpdate};
+
+/// A transaction action that publishes an existing snapshot onto the `main` branch.
+///
+/// This is typically used to publish a snapshot staged by write-audit-publish (WAP).
+/// If the snapshot's parent is the current snapshot of `main`, the branch is fast-forwarded
+/// to it. Otherwise, the files added by the snapshot are appended in a new snapshot that
+/// records the source snapshot id and the published WAP id in its summary.
+///
+/// A snapshot carrying a WAP id that has already been published to `main` is rejected.
+pub struct CherryPickAction {
+    snapshot_id: i64,
+}
+
+impl CherryPickAction {
+    pub(crate) fn new(snapshot_id: i64) -> Self {
+        Self { snapshot_id }
+    }
+
+    fn snapshot<'a>(&self, metadata: &'a TableMetadata) -> Result<&'a SnapshotRef> {
+        metadata.snapshot_by_id(self.snapshot_id).ok_or_else(|| {
+            Error::new(
+                ErrorKind::DataInvalid,
+                format!("Cannot cherry-pick unknown snapshot {}", self.snapshot_id),
+            )
+        })
+    }
+
+    // Rejects snapshots that are already part of the history of `main`, and staged snapshots
+    // whose WAP id has been published before.
+    fn validate(&self, metadata: &TableMetadata) -> Result<()> {
+        let snapshot = self.snapshot(metadata)?;
+        let wap_id = snapshot
+            .summary()
+            .additional_properties
+            .get(STAGED_WAP_ID_PROP);
+
+        let mut current = metadata.snapshot_for_ref(MAIN_BRANCH);
+        while let Some(ancestor) = current {
+            if ancestor.snapshot_id() == self.snapshot_id {
+                return Err(Error::new(
+                    ErrorKind::DataInvalid,
+                    format!(
+                        "Cannot cherry-pick snapshot {}: already an ancestor",
+                        self.snapshot_id
+                    ),
+                ));
+            }
+
+            if let Some(wap_id) = wap_id {
+                let properties = &ancestor.summary().additional_properties;
+                if properties.get(STAGED_WAP_ID_PROP) == Some(wap_id)
+                    || properties.get(PUBLISHED_WAP_ID_PROP) == Some(wap_id)
+                {
+                    return Err(Error::new(
+                        ErrorKind::CommitConflict,
+                        format!(
+                            "Duplicate request to cherry-pick wap id that was published already: {wap_id}"
+                        ),
+                    ));
+                }
+            }
+
+            current = ancestor
+                .parent_snapshot_id()
+                .and_then(|parent_id| metadata.snapshot_by_id(parent_id));
+        }
+
+        Ok(())
+    }
+
+    // Collects the data files added by the cherry-picked snapshot.
+    async fn added_data_files(&self, table: &Table) -> Result<Vec<DataFile>> {
+        let snapshot = self.snapshot(table.metadata())?;
+
+        if snapshot.summary().operation != Operation::Append {
+            return Err(Error::new(
+                ErrorKind::FeatureUnsupported,
+                format!(
+                    "Cannot cherry-pick snapshot {} with operation {}, only append is supported",
+                    self.snapshot_id,
+                    snapshot.summary().operation.as_str()
+                ),
+            ));
+        }
+
+        let manifest_list = snapshot
+            .load_manifest_list(table.file_io(), &table.metadata_ref())
+            .await?;
+
+        let mut data_files = vec![];
+        for manifest_file in manifest_list.entries() {
+            if manifest_file.added_snapshot_id != self.snapshot_id
+                || manifest_file.content != ManifestContentType::Data
+            {
+                continue;
+            }
+
+            let manifest = manifest_file.load_manifest(table.file_io()).await?;
+            data_files.extend(
+                manifest
+                    .entries()
+                    .iter()
+                    .filter(|entry| {
+                        entry.status() == ManifestStatus::Added
+                            && entry.snapshot_id() == Some(self.snapshot_id)
+                    })
+                    .map(|entry| entry.data_file().clone()),
+            );
+        }
+
+        Ok(data_files)
+    }
+}
+
+#[async_trait]
+impl TransactionAction for CherryPickAction {
+    async fn commit(self: Arc<Self>, table: &Table) -> Result<ActionCommit> {
+        let metadata = table.metadata();
+        self.validate(metadata)?;
+
+        let snapshot = self.snapshot(metadata)?;
+        let current_snapshot_id = metadata.current_snapshot_id();
+
+        let requirements = vec![
+            TableRequirement::UuidMatch {
+                uuid: metadata.uuid(),
+            },
+            TableRequirement::RefSnapshotIdMatch {
+                r#ref: MAIN_BRANCH.to_string(),
+                snapshot_id: current_snapshot_id,
+            },
+        ];
+
+        // The staged snapshot was produced on top of the current state, so it can be
+        // published as is.
+        if snapshot.parent_snapshot_id() == current_snapshot_id {
+            let updates = vec![TableUpdate::SetSnapshotRef {
+                ref_name: MAIN_BRANCH.to_string(),
+                reference: SnapshotReference::new(
+                    self.snapshot_id,
+                    SnapshotRetention::branch(None, None, None),
+                ),
+            }];
+            return Ok(ActionCommit::new(updates, requirements));
+        }
+
+        let mut snapshot_properties = HashMap::from([(
+            SOURCE_SNAPSHOT_ID_PROP.to_string(),
+            self.snapshot_id.to_string(),
+        )]);
+        if let Some(wap_id) = snapshot
+            .summary()
+            .additional_properties
+            .get(STAGED_WAP_ID_PROP)
+        {
+            snapshot_properties.insert(PUBLISHED_WAP_ID_PROP.to_string(), wap_id.clone());
+        }
+
+        let added_data_files = self.added_data_files(table).await?;
+        let snapshot_producer = SnapshotProducer::new(
+            table,
+            Uuid::now_v7(),
+            None,
+            snapshot_properties,
+            added_data_files,
+        );
+        snapshot_producer.validate_added_data_files()?;
+        snapshot_producer.validate_duplicate_files().await?;
+
+        snapshot_producer
+            .commit(FastAppendOperation, DefaultManifestProcess)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::memory::tests::new_memory_catalog;
+    use crate::spec::{
+        DataContentType, DataFile, DataFileBuilder, DataFileFormat, Literal, PUBLISHED_WAP_ID_PROP,
+        SOURCE_SNAPSHOT_ID_PROP, STAGED_WAP_ID_PROP, Struct, TableProperties,
+    };
+    use crate::table::Table;
+    use crate::transaction::tests::make_v3_minimal_table_in_catalog;
+    use crate::transaction::{ApplyTransactionAction, Transaction};
+    use crate::{Catalog, ErrorKind};
+
+    fn data_file(path: &str) -> DataFile {
+        DataFileBuilder::default()
+            .content(DataContentType::Data)
+            .file_path(path.to_string())
+            .file_format(DataFileFormat::Parquet)
+            .file_size_in_bytes(100)
+            .record_count(1)
+            .partition(Struct::from_iter([Some(Literal::long(0))]))
+            .partition_spec_id(0)
+            .build()
+            .unwrap()
+    }
+
+    async fn make_wap_table(catalog: &impl Catalog) -> Table {
+        let table = make_v3_minimal_table_in_catalog(catalog).await;
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .update_table_properties()
+            .set(
+                TableProperties::PROPERTY_WRITE_WAP_ENABLED.to_string(),
+                "true".to_string(),
+            )
+            .apply(tx)
+            .unwrap();
+        tx.commit(catalog).await.unwrap()
+    }
+
+    async fn append(
+        catalog: &impl Catalog,
+        table: &Table,
+        path: &str,
+        wap_id: Option<&str>,
+    ) -> Table {
+        let snapshot_properties = wap_id
+            .map(|wap_id| HashMap::from([(STAGED_WAP_ID_PROP.to_string(), wap_id.to_string())]))
+            .unwrap_or_default();
+        let tx = Transaction::new(table);
+        let tx = tx
+            .fast_append()
+            .set_snapshot_properties(snapshot_properties)
+            .add_data_files(vec![data_file(path)])
+            .apply(tx)
+            .unwrap();
+        tx.commit(catalog).await.unwrap()
+    }
+
+    fn staged_snapshot_id(table: &Table, wap_id: &str) -> i64 {
+        table
+            .metadata()
+            .snapshots()
+            .find(|s| {
+                s.summary().additional_properties.get(STAGED_WAP_ID_PROP)
+                    == Some(&wap_id.to_string())
+            })
+            .unwrap()
+            .snapshot_id()
+    }
+
+    #[tokio::test]
+    async fn test_wap_snapshot_is_staged() {
+        let catalog = new_memory_catalog().await;
+        let table = make_wap_table(&catalog).await;
+
+        let table = append(&catalog, &table, "test/staged.parquet", Some("wap-1")).await;
+
+        assert_eq!(table.metadata().snapshots().count(), 1);
+        assert!(table.metadata().current_snapshot().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_cherry_pick_fast_forward() {
+        let catalog = new_memory_catalog().await;
+        let table = make_wap_table(&catalog).await;
+        let table = append(&catalog, &table, "test/staged.parquet", Some("wap-1")).await;
+        let staged_id = staged_snapshot_id(&table, "wap-1");
+
+        let tx = Transaction::new(&table);
+        let tx = tx.cherry_pick(staged_id).apply(tx).unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+
+        assert_eq!(table.metadata().current_snapshot_id(), Some(staged_id));
+        assert_eq!(table.metadata().snapshots().count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_cherry_pick_onto_new_main() {
+        let catalog = new_memory_catalog().await;
+        let table = make_wap_table(&catalog).await;
+        let table = append(&catalog, &table, "test/staged.parquet", Some("wap-1")).await;
+        let staged_id = staged_snapshot_id(&table, "wap-1");
+        // main moves on after the snapshot was staged
+        let table = append(&catalog, &table, "test/main.parquet", None).await;
+
+        let tx = Transaction::new(&table);
+        let tx = tx.cherry_pick(staged_id).apply(tx).unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+
+        let current = table.metadata().current_snapshot().unwrap();
+        assert_ne!(current.snapshot_id(), staged_id);
+        let properties = &current.summary().additional_properties;
+        assert_eq!(properties.get(PUBLISHED_WAP_ID_PROP).unwrap(), "wap-1");
+        assert_eq!(
+            properties.get(SOURCE_SNAPSHOT_ID_PROP).unwrap(),
+            &staged_id.to_string()
+        );
+        assert_eq!(properties.get("added-data-files").unwrap(), "1");
+
+        // Publishing the same WAP id twice is rejected.
+        let table = append(&catalog, &table, "test/staged-2.parquet", Some("wap-1")).await;
+        let staged_id = table
+            .metadata()
+            .snapshots()
+            .filter(|s| {
+                s.summary().additional_properties.get(STAGED_WAP_ID_PROP)
+                    == Some(&"wap-1".to_string())
+            })
+            .max_by_key(|s| s.sequence_number())
+            .unwrap()
+            .snapshot_id();
+        let tx = Transaction::new(&table);
+        let tx = tx.cherry_pick(staged_id).apply(tx).unwrap();
+        let err = tx.commit(&catalog).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::CommitConflict);
+    }
+
+    #[tokio::test]
+    async fn test_cherry_pick_ancestor() {
+        let catalog = new_memory_catalog().await;
+        let table = make_v3_minimal_table_in_catalog(&catalog).await;
+        let table = append(&catalog, &table, "test/main.parquet", None).await;
+        let current_id = table.metadata().current_snapshot_id().unwrap();
+
+        let tx = Transaction::new(&table);
+        let tx = tx.cherry_pick(current_id).apply(tx).unwrap();
+        let err = tx.commit(&catalog).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::DataInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_cherry_pick_unknown_snapshot() {
+        let catalog = new_memory_catalog().await;
+        let table = make_wap_table(&catalog).await;
+
+        let tx = Transaction::new(&table);
+        let tx = tx.cherry_pick(42).apply(tx).unwrap();
+        let err = tx.commit(&catalog).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::DataInvalid);
+    }
+}
@@ -17,7 +17,9 @@
 
 //! This module contains transaction api.
 //!
-//! The transaction API enables changes to be made to an existing table.
+//! The transaction API enables changes to be made to an existing table. A
+//! transaction can also create a staged table, or replace the definition of a
+//! table, together with the changes made by its actions.
 //!
 //! Note that this may also have side effects, such as producing new manifest
 //! files.
@@ -53,43 +55,92 @@
 mod action;
 
 pub use action::*;
+pub use multi_table::MultiTableTransaction;
+pub use view::ViewTransaction;
 mod append;
+mod cherry_pick;
+mod multi_table;
 mod snapshot;
 mod sort_order;
 mod update_location;
 mod update_properties;
 mod update_statistics;
 mod upgrade_format_version;
+mod validate;
+mod view;
 
 use std::sync::Arc;
-use std::time::Duration;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
 
 use backon::{BackoffBuilder, ExponentialBackoff, ExponentialBuilder, RetryableWithContext};
 
 use crate::error::Result;
-use crate::spec::TableProperties;
+use crate::metrics::{CommitMetricsResult, CommitReport, MetricsReport};
+use crate::spec::{TableMetadata, TableMetadataBuilder, TableProperties};
 use crate::table::Table;
 use crate::transaction::action::BoxedTransactionAction;
 use crate::transaction::append::FastAppendAction;
+use crate::transaction::cherry_pick::CherryPickAction;
 use crate::transaction::sort_order::ReplaceSortOrderAction;
 use crate::transaction::update_location::UpdateLocationAction;
 use crate::transaction::update_properties::UpdatePropertiesAction;
 use crate::transaction::update_statistics::UpdateStatisticsAction;
 use crate::transaction::upgrade_format_version::UpgradeFormatVersionAction;
-use crate::{Catalog, TableCommit, TableRequirement, TableUpdate};
+use crate::{Catalog, TableCommit, TableCreation, TableRequirement, TableUpdate};
 
 /// Table transaction.
 #[derive(Clone)]
 pub struct Transaction {
     table: Table,
+    kind: TransactionKind,
     actions: Vec<BoxedTransactionAction>,
 }
 
+/// What a [`Transaction`] does to the table besides applying its actions.
+#[derive(Clone)]
+enum TransactionKind {
+    /// Updates the existing table.
+    Update,
+    /// Creates the staged table.
+    Create,
+    /// Replaces the definition of the existing table.
+    Replace(Box<TableCreation>),
+}
+
 impl Transaction {
     /// Creates a new transaction.
     pub fn new(table: &Table) -> Self {
         Self {
             table: table.clone(),
+            kind: TransactionKind::Update,
+            actions: vec![],
+        }
+    }
+
+    /// Creates a transaction creating a staged table, usually returned by
+    /// [`Catalog::stage_create_table`].
+    ///
+    /// The table is created with the changes of the transaction actions in a single commit,
+    /// which fails if a table with the same identifier already exists.
+    pub fn create_table(table: &Table) -> Self {
+        Self {
+            table: table.clone(),
+            kind: TransactionKind::Create,
+            actions: vec![],
+        }
+    }
+
+    /// Creates a transaction replacing the definition of the table with `creation`, as done
+    /// by `CREATE OR REPLACE TABLE`.
+    ///
+    /// On commit, the schema, partition spec, sort order, location and properties of
+    /// `creation` are applied to the latest metadata of the table, and its `main` branch is
+    /// reset before the actions are applied. Snapshots and history of the table are kept.
+    pub fn replace_table(table: &Table, creation: TableCreation) -> Self {
+        Self {
+            table: table.clone(),
+            kind: TransactionKind::Replace(Box::new(creation)),
             actions: vec![],
         }
     }
@@ -138,7 +189,12 @@ impl Transaction {
 
     /// Creates a fast append action.
     pub fn fast_append(&self) -> FastAppendAction {
-        FastAppendAction::new()
+        FastAppendAction::new(self.table.metadata().current_snapshot_id())
+    }
+
+    /// Creates a cherry-pick action that publishes the given snapshot onto the main branch.
+    pub fn cherry_pick(&self, snapshot_id: i64) -> CherryPickAction {
+        CherryPickAction::new(snapshot_id)
     }
 
     /// Creates replace sort order action.
@@ -157,8 +213,11 @@ impl Transaction {
     }
 
     /// Commit transaction.
+    ///
+    /// If the table has a [`MetricsReporter`](crate::metrics::MetricsReporter) and the
+    /// transaction creates a snapshot, a [`CommitReport`] is sent once the commit succeeds.
     pub async fn commit(self, catalog: &dyn Catalog) -> Result<Table> {
-        if self.actions.is_empty() {
+        if self.actions.is_empty() && matches!(self.kind, TransactionKind::Update) {
             // nothing to commit
             return Ok(self.table);
         }
@@ -166,18 +225,71 @@ impl Transaction {
         let table_props = self.table.metadata().table_properties()?;
 
         let backoff = Self::build_backoff(table_props)?;
+        let metrics_reporter = self.table.metrics_reporter().cloned();
+        let start = Instant::now();
+        let attempts = AtomicU64::new(0);
+        // Creating the table again can't succeed once it has been created concurrently.
+        let retryable = !matches!(self.kind, TransactionKind::Create);
         let tx = self;
 
-        (|mut tx: Transaction| async {
+        let (tx, result) = (|mut tx: Transaction| async {
+            attempts.fetch_add(1, Ordering::Relaxed);
             let result = tx.do_commit(catalog).await;
             (tx, result)
         })
         .retry(backoff)
         .sleep(tokio::time::sleep)
         .context(tx)
-        .when(|e| e.retryable())
-        .await
-        .1
+        .when(|e| retryable && e.retryable())
+        .await;
+        let table = result?;
+
+        if let Some(metrics_reporter) = metrics_reporter
+            && let Some(report) = Self::commit_report(
+                &tx.table,
+                &table,
+                start.elapsed(),
+                attempts.load(Ordering::Relaxed),
+            )
+        {
+            metrics_reporter.report(MetricsReport::Commit(report)).await;
+        }
+
+        Ok(table)
+    }
+
+    /// Returns the report of a commit of `base` that resulted in `committed`, or `None` if
+    /// the commit did not create a snapshot.
+    ///
+    /// The snapshot added by the commit is reported whether or not it became the current
+    /// snapshot, e.g. when it was committed to a branch or staged.
+    fn commit_report(
+        base: &Table,
+        committed: &Table,
+        total_duration: Duration,
+        attempts: u64,
+    ) -> Option<CommitReport> {
+        let snapshot = committed
+            .metadata()
+            .snapshots()
+            .filter(|snapshot| {
+                base.metadata()
+                    .snapshot_by_id(snapshot.snapshot_id())
+                    .is_none()
+            })
+            .max_by_key(|snapshot| snapshot.sequence_number())?;
+
+        Some(CommitReport {
+            table: committed.identifier().clone(),
+            snapshot_id: snapshot.snapshot_id(),
+            sequence_number: snapshot.sequence_number(),
+            operation: snapshot.summary().operation.clone(),
+            metrics: CommitMetricsResult::from_summary(
+                total_duration,
+                attempts,
+                snapshot.summary(),
+            ),
+        })
     }
 
     fn build_backoff(props: TableProperties) -> Result<ExponentialBackoff> {
@@ -193,19 +305,63 @@ impl Transaction {
     }
 
     async fn do_commit(&mut self, catalog: &dyn Catalog) -> Result<Table> {
-        let refreshed = catalog.load_table(self.table.identifier()).await?;
+        let table_commit = self.prepare_commit(catalog).await?;
 
-        if self.table.metadata() != refreshed.metadata()
-            || self.table.metadata_location() != refreshed.metadata_location()
-        {
-            // current base is stale, use refreshed as base and re-apply transaction actions
-            self.table = refreshed.clone();
+        catalog.update_table(table_commit).await
+    }
+
+    /// Re-applies the transaction actions on top of the latest table metadata, returning
+    /// the resulting [`TableCommit`].
+    async fn prepare_commit(&mut self, catalog: &dyn Catalog) -> Result<TableCommit> {
+        if !matches!(self.kind, TransactionKind::Create) {
+            let refreshed = catalog
+                .load_table_for_commit(self.table.identifier())
+                .await?;
+
+            if self.table.metadata() != refreshed.metadata()
+                || self.table.metadata_location() != refreshed.metadata_location()
+            {
+                // current base is stale, use refreshed as base and re-apply transaction actions
+                self.table = refreshed.clone();
+            }
         }
 
         let mut current_table = self.table.clone();
         let mut existing_updates: Vec<TableUpdate> = vec![];
         let mut existing_requirements: Vec<TableRequirement> = vec![];
 
+        // Requirements replacing the ones of the actions, which assume an existing table
+        // whose definition does not change.
+        let base_requirements = match &self.kind {
+            TransactionKind::Update => None,
+            TransactionKind::Create => {
+                existing_updates = Self::creation_updates(current_table.metadata());
+                Some(vec![TableRequirement::NotExist])
+            }
+            TransactionKind::Replace(creation) => {
+                let base = current_table.metadata();
+                let requirements = vec![
+                    TableRequirement::UuidMatch { uuid: base.uuid() },
+                    TableRequirement::LastAssignedFieldIdMatch {
+                        last_assigned_field_id: base.last_column_id(),
+                    },
+                    TableRequirement::LastAssignedPartitionIdMatch {
+                        last_assigned_partition_id: base.last_partition_id(),
+                    },
+                ];
+
+                let replacement = TableMetadataBuilder::replace_from_table_creation(
+                    base.clone(),
+                    None,
+                    creation.as_ref().clone(),
+                )?
+                .build()?;
+                existing_updates = replacement.changes;
+                current_table = current_table.with_metadata(Arc::new(replacement.metadata));
+                Some(requirements)
+            }
+        };
+
         for action in &self.actions {
             let action_commit = Arc::clone(action).commit(&current_table).await?;
             // apply action commit to current_table
@@ -217,18 +373,60 @@ impl Transaction {
             )?;
         }
 
-        let table_commit = TableCommit::builder()
+        if let Some(base_requirements) = base_requirements {
+            existing_requirements = base_requirements;
+        }
+
+        Ok(TableCommit::builder()
             .ident(self.table.identifier().to_owned())
             .updates(existing_updates)
             .requirements(existing_requirements)
-            .build();
+            .build())
+    }
 
-        catalog.update_table(table_commit).await
+    /// Returns the updates creating a table with the given metadata.
+    fn creation_updates(metadata: &TableMetadata) -> Vec<TableUpdate> {
+        vec![
+            TableUpdate::AssignUuid {
+                uuid: metadata.uuid(),
+            },
+            TableUpdate::UpgradeFormatVersion {
+                format_version: metadata.format_version(),
+            },
+            TableUpdate::AddSchema {
+                schema: metadata.current_schema().as_ref().clone(),
+            },
+            TableUpdate::SetCurrentSchema {
+                schema_id: TableMetadataBuilder::LAST_ADDED,
+            },
+            TableUpdate::AddSpec {
+                spec: metadata
+                    .default_partition_spec()
+                    .as_ref()
+                    .clone()
+                    .into_unbound(),
+            },
+            TableUpdate::SetDefaultSpec {
+                spec_id: TableMetadataBuilder::LAST_ADDED,
+            },
+            TableUpdate::AddSortOrder {
+                sort_order: metadata.default_sort_order().as_ref().clone(),
+            },
+            TableUpdate::SetDefaultSortOrder {
+                sort_order_id: TableMetadataBuilder::LAST_ADDED as i64,
+            },
+            TableUpdate::SetLocation {
+                location: metadata.location().to_string(),
+            },
+            TableUpdate::SetProperties {
+                updates: metadata.properties().clone(),
+            },
+        ]
     }
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use std::collections::HashMap;
     use std::fs::File;
     use std::io::BufReader;
@@ -237,10 +435,20 @@ mod tests {
 
     use crate::catalog::MockCatalog;
     use crate::io::FileIO;
-    use crate::spec::TableMetadata;
+    use crate::memory::tests::temp_path;
+    use crate::memory::{MEMORY_CATALOG_WAREHOUSE, MemoryCatalogBuilder};
+    use crate::metrics::tests::RecordingMetricsReporter;
+    use crate::metrics::{CommitReport, MetricsReport};
+    use crate::spec::{
+        DataContentType, DataFileBuilder, DataFileFormat, Literal, NestedField, Operation,
+        PrimitiveType, STAGED_WAP_ID_PROP, Schema, Struct, TableMetadata, TableProperties, Type,
+    };
     use crate::table::Table;
     use crate::transaction::{ApplyTransactionAction, Transaction};
-    use crate::{Catalog, Error, ErrorKind, TableCreation, TableIdent};
+    use crate::{
+        Catalog, CatalogBuilder, Error, ErrorKind, TableCreation, TableIdent, TableRequirement,
+        TableUpdate,
+    };
 
     pub fn make_v1_table() -> Table {
         let file = File::open(format!(
@@ -380,7 +588,7 @@ mod tests {
         let mut mock_catalog = MockCatalog::new();
 
         mock_catalog
-            .expect_load_table()
+            .expect_load_table_for_commit()
             .returning_st(|_| Box::pin(async move { Ok(make_v2_table()) }));
 
         let attempts = AtomicU32::new(0);
@@ -419,7 +627,7 @@ mod tests {
         let mut mock_catalog = MockCatalog::new();
 
         mock_catalog
-            .expect_load_table()
+            .expect_load_table_for_commit()
             .returning_st(|_| Box::pin(async move { Ok(make_v2_table()) }));
 
         mock_catalog
@@ -498,6 +706,199 @@ mod tests {
             assert!(err.retryable(), "Error should be retryable");
         }
     }
+
+    #[tokio::test]
+    async fn test_commit_reports_metrics() {
+        let reporter = Arc::new(RecordingMetricsReporter::default());
+        let catalog = MemoryCatalogBuilder::default()
+            .with_metrics_reporter(reporter.clone())
+            .load(
+                "memory",
+                HashMap::from([(MEMORY_CATALOG_WAREHOUSE.to_string(), temp_path())]),
+            )
+            .await
+            .unwrap();
+        let table = make_v3_minimal_table_in_catalog(&catalog).await;
+
+        let data_file = |path: &str| {
+            DataFileBuilder::default()
+                .content(DataContentType::Data)
+                .file_path(path.to_string())
+                .file_format(DataFileFormat::Parquet)
+                .file_size_in_bytes(100)
+                .record_count(30)
+                .partition(Struct::from_iter([Some(Literal::long(0))]))
+                .partition_spec_id(0)
+                .build()
+                .unwrap()
+        };
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .fast_append()
+            .add_data_files(vec![data_file("test/1.parquet")])
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+
+        // Property updates don't create a snapshot and are not reported
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .update_table_properties()
+            .set(
+                TableProperties::PROPERTY_WRITE_WAP_ENABLED.to_string(),
+                "true".to_string(),
+            )
+            .apply(tx)
+            .unwrap();
+        let wap_table = tx.commit(&catalog).await.unwrap();
+
+        // Staged snapshots are reported although they don't become the current snapshot
+        let tx = Transaction::new(&wap_table);
+        let tx = tx
+            .fast_append()
+            .set_snapshot_properties(HashMap::from([(
+                STAGED_WAP_ID_PROP.to_string(),
+                "wap-1".to_string(),
+            )]))
+            .add_data_files(vec![data_file("test/2.parquet")])
+            .apply(tx)
+            .unwrap();
+        let wap_table = tx.commit(&catalog).await.unwrap();
+
+        let reports = reporter.reports();
+        let [
+            MetricsReport::Commit(report),
+            MetricsReport::Commit(staged_report),
+        ] = reports.as_slice()
+        else {
+            panic!("Expected two commit reports, got {reports:?}");
+        };
+        assert_eq!(
+            wap_table.metadata().current_snapshot_id(),
+            table.metadata().current_snapshot_id()
+        );
+        let staged_snapshot = wap_table
+            .metadata()
+            .snapshots()
+            .max_by_key(|snapshot| snapshot.sequence_number())
+            .unwrap();
+        assert_eq!(staged_report.snapshot_id, staged_snapshot.snapshot_id());
+        assert_eq!(staged_report.metrics.added_data_files, Some(1));
+
+        let snapshot = table.metadata().current_snapshot().unwrap();
+        let CommitReport {
+            table: table_ident,
+            snapshot_id,
+            sequence_number,
+            operation,
+            metrics,
+        } = report;
+        assert_eq!(table_ident, table.identifier());
+        assert_eq!(*snapshot_id, snapshot.snapshot_id());
+        assert_eq!(*sequence_number, snapshot.sequence_number());
+        assert_eq!(*operation, Operation::Append);
+        assert_eq!(metrics.attempts, 1);
+        assert_eq!(metrics.added_data_files, Some(1));
+        assert_eq!(metrics.total_data_files, Some(1));
+        assert_eq!(metrics.added_records, Some(30));
+        assert_eq!(metrics.added_files_size_in_bytes, Some(100));
+        assert_eq!(metrics.removed_data_files, None);
+    }
+
+    #[tokio::test]
+    async fn test_create_table_commit() {
+        let staged = make_v2_minimal_table();
+
+        // A staged table is not loaded from the catalog.
+        let catalog = MockCatalog::new();
+
+        let tx = Transaction::create_table(&staged);
+        let mut tx = tx
+            .update_table_properties()
+            .set("key".to_string(), "value".to_string())
+            .apply(tx)
+            .unwrap();
+        let mut commit = tx.prepare_commit(&catalog).await.unwrap();
+
+        assert_eq!(commit.take_requirements(), vec![TableRequirement::NotExist]);
+        let updates = commit.take_updates();
+        assert_eq!(updates[0], TableUpdate::AssignUuid {
+            uuid: staged.metadata().uuid()
+        });
+        assert!(updates.contains(&TableUpdate::SetLocation {
+            location: staged.metadata().location().to_string()
+        }));
+        assert!(updates.contains(&TableUpdate::SetProperties {
+            updates: HashMap::from([("key".to_string(), "value".to_string())])
+        }));
+    }
+
+    #[tokio::test]
+    async fn test_replace_table_commit() {
+        let table = make_v2_table();
+        let previous_snapshot_id = table.metadata().current_snapshot_id().unwrap();
+
+        let mut catalog = MockCatalog::new();
+        catalog
+            .expect_load_table_for_commit()
+            .returning_st(|_| Box::pin(async move { Ok(make_v2_table()) }));
+
+        let creation = TableCreation::builder()
+            .name(table.identifier().name().to_string())
+            .schema(
+                Schema::builder()
+                    .with_fields(vec![
+                        NestedField::required(1, "x", Type::Primitive(PrimitiveType::Long)).into(),
+                        NestedField::optional(2, "w", Type::Primitive(PrimitiveType::String))
+                            .into(),
+                    ])
+                    .build()
+                    .unwrap(),
+            )
+            .build();
+        let data_file = DataFileBuilder::default()
+            .content(DataContentType::Data)
+            .file_path("test/1.parquet".to_string())
+            .file_format(DataFileFormat::Parquet)
+            .file_size_in_bytes(100)
+            .record_count(30)
+            .partition(Struct::empty())
+            .partition_spec_id(1)
+            .build()
+            .unwrap();
+
+        let tx = Transaction::replace_table(&table, creation);
+        let mut tx = tx
+            .fast_append()
+            .add_data_files(vec![data_file])
+            .apply(tx)
+            .unwrap();
+        let mut commit = tx.prepare_commit(&catalog).await.unwrap();
+
+        assert_eq!(commit.take_requirements(), vec![
+            TableRequirement::UuidMatch {
+                uuid: table.metadata().uuid()
+            },
+            TableRequirement::LastAssignedFieldIdMatch {
+                last_assigned_field_id: 3
+            },
+            TableRequirement::LastAssignedPartitionIdMatch {
+                last_assigned_partition_id: 1000
+            },
+        ]);
+
+        let replaced = Transaction::update_table_metadata(table, &commit.take_updates()).unwrap();
+        let metadata = replaced.metadata();
+        assert_eq!(metadata.current_schema().field_by_name("x").unwrap().id, 1);
+        assert_eq!(metadata.current_schema().field_by_name("w").unwrap().id, 4);
+        assert!(metadata.default_partition_spec().is_unpartitioned());
+
+        // The previous snapshot is kept, but is not the parent of the appended snapshot.
+        assert!(metadata.snapshot_by_id(previous_snapshot_id).is_some());
+        let snapshot = metadata.current_snapshot().unwrap();
+        assert_ne!(snapshot.snapshot_id(), previous_snapshot_id);
+        assert_eq!(snapshot.parent_snapshot_id(), None);
+    }
 }
 
 #[cfg(test)]
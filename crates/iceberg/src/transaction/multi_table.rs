@@ -0,0 +1,260 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use backon::RetryableWithContext;
+
+use crate::error::Result;
+use crate::transaction::{Transaction, TransactionKind};
+use crate::{Catalog, Error, ErrorKind};
+
+/// Transaction committing changes to multiple tables atomically.
+///
+/// Each table is updated through its own [`Transaction`], and all of them are committed
+/// together with [`Catalog::commit_transaction`]: either every table is updated or none is.
+///
+/// ```ignore
+/// use iceberg::transaction::{ApplyTransactionAction, MultiTableTransaction, Transaction};
+///
+/// let fact_tx = Transaction::new(&fact_table);
+/// let fact_tx = fact_tx.fast_append().add_data_files(fact_files).apply(fact_tx)?;
+///
+/// let agg_tx = Transaction::new(&agg_table);
+/// let agg_tx = agg_tx.fast_append().add_data_files(agg_files).apply(agg_tx)?;
+///
+/// MultiTableTransaction::new()
+///     .add_transaction(fact_tx)?
+///     .add_transaction(agg_tx)?
+///     .commit(&catalog)
+///     .await?;
+/// ```
+#[derive(Clone, Default)]
+pub struct MultiTableTransaction {
+    transactions: Vec<Transaction>,
+}
+
+impl MultiTableTransaction {
+    /// Creates a new, empty multi-table transaction.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the transaction of a table.
+    ///
+    /// Returns an error if a transaction for the same table was already added.
+    pub fn add_transaction(mut self, transaction: Transaction) -> Result<Self> {
+        let identifier = transaction.table.identifier();
+        if self
+            .transactions
+            .iter()
+            .any(|tx| tx.table.identifier() == identifier)
+        {
+            return Err(Error::new(
+                ErrorKind::DataInvalid,
+                format!("Table {identifier} is already part of the transaction"),
+            ));
+        }
+
+        self.transactions.push(transaction);
+        Ok(self)
+    }
+
+    /// Commits the changes of all tables atomically.
+    ///
+    /// Retryable failures, such as commit conflicts, refresh all tables and re-apply their
+    /// actions, using the retry properties of the first table. Commits creating a staged
+    /// table are not retried, as creating the table can't succeed once it has been created
+    /// concurrently.
+    pub async fn commit(self, catalog: &dyn Catalog) -> Result<()> {
+        let transactions: Vec<_> = self
+            .transactions
+            .into_iter()
+            .filter(|tx| !(tx.actions.is_empty() && matches!(tx.kind, TransactionKind::Update)))
+            .collect();
+
+        let Some(first) = transactions.first() else {
+            // nothing to commit
+            return Ok(());
+        };
+
+        let backoff = Transaction::build_backoff(first.table.metadata().table_properties()?)?;
+        let retryable = !transactions
+            .iter()
+            .any(|tx| matches!(tx.kind, TransactionKind::Create));
+
+        (|mut transactions: Vec<Transaction>| async {
+            let result = Self::do_commit(&mut transactions, catalog).await;
+            (transactions, result)
+        })
+        .retry(backoff)
+        .sleep(tokio::time::sleep)
+        .context(transactions)
+        .when(|e| retryable && e.retryable())
+        .await
+        .1
+    }
+
+    async fn do_commit(transactions: &mut [Transaction], catalog: &dyn Catalog) -> Result<()> {
+        let mut table_commits = Vec::with_capacity(transactions.len());
+        for tx in transactions.iter_mut() {
+            table_commits.push(tx.prepare_commit(catalog).await?);
+        }
+
+        catalog.commit_transaction(table_commits).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::catalog::MockCatalog;
+    use crate::memory::tests::new_memory_catalog;
+    use crate::transaction::tests::{
+        make_v2_minimal_table, make_v2_table, make_v3_minimal_table_in_catalog,
+    };
+    use crate::transaction::{ApplyTransactionAction, MultiTableTransaction, Transaction};
+    use crate::{Catalog, Error, ErrorKind, TableCreation, TableRequirement};
+
+    #[tokio::test]
+    async fn test_commit_multiple_tables() {
+        let catalog = new_memory_catalog().await;
+        let fact_table = make_v3_minimal_table_in_catalog(&catalog).await;
+        let agg_table = make_v3_minimal_table_in_catalog(&catalog).await;
+
+        let fact_tx = Transaction::new(&fact_table);
+        let fact_tx = fact_tx
+            .update_table_properties()
+            .set("refreshed".to_string(), "fact".to_string())
+            .apply(fact_tx)
+            .unwrap();
+        let agg_tx = Transaction::new(&agg_table);
+        let agg_tx = agg_tx
+            .update_table_properties()
+            .set("refreshed".to_string(), "agg".to_string())
+            .apply(agg_tx)
+            .unwrap();
+
+        MultiTableTransaction::new()
+            .add_transaction(fact_tx)
+            .unwrap()
+            .add_transaction(agg_tx)
+            .unwrap()
+            .commit(&catalog)
+            .await
+            .unwrap();
+
+        for (table, value) in [(&fact_table, "fact"), (&agg_table, "agg")] {
+            let reloaded = catalog.load_table(table.identifier()).await.unwrap();
+            assert_eq!(
+                reloaded.metadata().properties().get("refreshed"),
+                Some(&value.to_string())
+            );
+            assert_ne!(reloaded.metadata_location(), table.metadata_location());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_add_same_table_twice() {
+        let catalog = new_memory_catalog().await;
+        let table = make_v3_minimal_table_in_catalog(&catalog).await;
+
+        let result = MultiTableTransaction::new()
+            .add_transaction(Transaction::new(&table))
+            .unwrap()
+            .add_transaction(Transaction::new(&table));
+
+        assert_eq!(result.err().unwrap().kind(), ErrorKind::DataInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_commit_without_actions() {
+        let catalog = new_memory_catalog().await;
+        let table = make_v3_minimal_table_in_catalog(&catalog).await;
+
+        MultiTableTransaction::new()
+            .add_transaction(Transaction::new(&table))
+            .unwrap()
+            .commit(&catalog)
+            .await
+            .unwrap();
+
+        let reloaded = catalog.load_table(table.identifier()).await.unwrap();
+        assert_eq!(reloaded.metadata_location(), table.metadata_location());
+    }
+
+    #[tokio::test]
+    async fn test_commit_replace_without_actions() {
+        let table = make_v2_table();
+        let creation = TableCreation::builder()
+            .name(table.identifier().name().to_string())
+            .schema(table.metadata().current_schema().as_ref().clone())
+            .build();
+
+        let mut catalog = MockCatalog::new();
+        catalog
+            .expect_load_table_for_commit()
+            .returning_st(|_| Box::pin(async move { Ok(make_v2_table()) }));
+        let uuid = table.metadata().uuid();
+        catalog
+            .expect_commit_transaction()
+            .times(1)
+            .returning_st(move |mut commits| {
+                assert_eq!(commits.len(), 1);
+                assert!(
+                    commits[0]
+                        .take_requirements()
+                        .contains(&TableRequirement::UuidMatch { uuid })
+                );
+                Box::pin(async move { Ok(()) })
+            });
+
+        MultiTableTransaction::new()
+            .add_transaction(Transaction::replace_table(&table, creation))
+            .unwrap()
+            .commit(&catalog)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_commit_create_is_not_retried() {
+        let staged = make_v2_minimal_table();
+
+        let mut catalog = MockCatalog::new();
+        catalog
+            .expect_commit_transaction()
+            .times(1)
+            .returning_st(|mut commits| {
+                assert_eq!(commits[0].take_requirements(), vec![
+                    TableRequirement::NotExist
+                ]);
+                Box::pin(async move {
+                    Err(
+                        Error::new(ErrorKind::CatalogCommitConflicts, "Commit conflict")
+                            .with_retryable(true),
+                    )
+                })
+            });
+
+        let err = MultiTableTransaction::new()
+            .add_transaction(Transaction::create_table(&staged))
+            .unwrap()
+            .commit(&catalog)
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::CatalogCommitConflicts);
+    }
+}
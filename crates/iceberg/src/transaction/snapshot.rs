@@ -21,12 +21,13 @@ use std::ops::RangeFrom;
 
 use uuid::Uuid;
 
+use crate::encryption::EncryptionManager;
 use crate::error::Result;
 use crate::spec::{
     DataFile, DataFileFormat, FormatVersion, MAIN_BRANCH, ManifestContentType, ManifestEntry,
-    ManifestFile, ManifestListWriter, ManifestWriter, ManifestWriterBuilder, Operation, Snapshot,
-    SnapshotReference, SnapshotRetention, SnapshotSummaryCollector, Struct, StructType, Summary,
-    TableProperties, update_snapshot_summaries,
+    ManifestFile, ManifestListWriter, ManifestWriter, ManifestWriterBuilder, Operation,
+    STAGED_WAP_ID_PROP, Snapshot, SnapshotReference, SnapshotRetention, SnapshotSummaryCollector,
+    Struct, StructType, Summary, TableProperties, update_snapshot_summaries,
 };
 use crate::table::Table;
 use crate::transaction::ActionCommit;
@@ -233,10 +234,15 @@ impl<'a> SnapshotProducer<'a> {
             DataFileFormat::Avro
         );
         let output_file = self.table.file_io().new_output(new_manifest_path)?;
+        // Each manifest of an encrypted table is encrypted with its own key.
+        let key_metadata = match self.encryption_manager()? {
+            Some(manager) => Some(manager.generate_key_metadata().encode()?),
+            None => self.key_metadata.clone(),
+        };
         let builder = ManifestWriterBuilder::new(
             output_file,
             Some(self.snapshot_id),
-            self.key_metadata.clone(),
+            key_metadata,
             self.table.metadata().current_schema().clone(),
             self.table
                 .metadata()
@@ -403,6 +409,17 @@ impl<'a> SnapshotProducer<'a> {
         )
     }
 
+    fn encryption_manager(&self) -> Result<Option<EncryptionManager>> {
+        EncryptionManager::try_from_table(self.table.metadata(), self.table.file_io())
+    }
+
+    // Returns whether the snapshot is staged for write-audit-publish, which is the case when
+    // `write.wap.enabled` is set and the snapshot summary carries a `wap.id`.
+    fn is_staged(&self) -> Result<bool> {
+        Ok(self.snapshot_properties.contains_key(STAGED_WAP_ID_PROP)
+            && self.table.metadata().table_properties()?.write_wap_enabled)
+    }
+
     fn generate_manifest_list_file_path(&self, attempt: i64) -> String {
         format!(
             "{}/{}/snap-{}-{}-{}.{}",
@@ -451,6 +468,16 @@ impl<'a> SnapshotProducer<'a> {
             ),
         };
 
+        // The key of an encrypted manifest list is stored in the table metadata, wrapped by
+        // the table master key.
+        let encryption_manager = self.encryption_manager()?;
+        let manifest_list_key_metadata = encryption_manager
+            .as_ref()
+            .map(|manager| manager.generate_key_metadata());
+        if let Some(key_metadata) = &manifest_list_key_metadata {
+            manifest_list_writer = manifest_list_writer.with_key_metadata(key_metadata.clone());
+        }
+
         // Calling self.summary() before self.manifest_file() is important because self.added_data_files
         // will be set to an empty vec after self.manifest_file() returns, resulting in an empty summary
         // being generated.
@@ -464,7 +491,15 @@ impl<'a> SnapshotProducer<'a> {
 
         manifest_list_writer.add_manifests(new_manifests.into_iter())?;
         let writer_next_row_id = manifest_list_writer.next_row_id();
-        manifest_list_writer.close().await?;
+        // The key metadata written records the length of the encrypted manifest list
+        let manifest_list_key_metadata = manifest_list_writer.close().await?;
+
+        let encryption_key = match (&encryption_manager, &manifest_list_key_metadata) {
+            (Some(manager), Some(key_metadata)) => {
+                Some(manager.wrap_key_metadata(key_metadata).await?)
+            }
+            _ => None,
+        };
 
         let commit_ts = chrono::Utc::now().timestamp_millis();
         let new_snapshot = Snapshot::builder()
@@ -474,6 +509,7 @@ impl<'a> SnapshotProducer<'a> {
             .with_sequence_number(next_seq_num)
             .with_summary(summary)
             .with_schema_id(self.table.metadata().current_schema_id())
+            .with_encryption_key_id(encryption_key.as_ref().map(|key| key.key_id().to_string()))
             .with_timestamp_ms(commit_ts);
 
         let new_snapshot = if let Some(writer_next_row_id) = writer_next_row_id {
@@ -485,18 +521,23 @@ impl<'a> SnapshotProducer<'a> {
             new_snapshot.build()
         };
 
-        let updates = vec![
-            TableUpdate::AddSnapshot {
-                snapshot: new_snapshot,
-            },
-            TableUpdate::SetSnapshotRef {
+        let mut updates = vec![];
+        if let Some(encryption_key) = encryption_key {
+            updates.push(TableUpdate::AddEncryptionKey { encryption_key });
+        }
+        updates.push(TableUpdate::AddSnapshot {
+            snapshot: new_snapshot,
+        });
+        // Staged snapshots are added to the metadata without being made current.
+        if !self.is_staged()? {
+            updates.push(TableUpdate::SetSnapshotRef {
                 ref_name: MAIN_BRANCH.to_string(),
                 reference: SnapshotReference::new(
                     self.snapshot_id,
                     SnapshotRetention::branch(None, None, None),
                 ),
-            },
-        ];
+            });
+        }
 
         let requirements = vec![
             TableRequirement::UuidMatch {
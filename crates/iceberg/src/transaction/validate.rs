@@ -0,0 +1,514 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::error::Result;
+use crate::expr::visitors::expression_evaluator::ExpressionEvaluator;
+use crate::expr::visitors::inclusive_metrics_evaluator::InclusiveMetricsEvaluator;
+use crate::expr::visitors::inclusive_projection::InclusiveProjection;
+use crate::expr::visitors::manifest_evaluator::ManifestEvaluator;
+use crate::expr::{Bind, BoundPredicate, Predicate};
+use crate::spec::{
+    DataFile, MAIN_BRANCH, ManifestContentType, ManifestFile, ManifestStatus, Operation, Schema,
+    SchemaRef, SnapshotRef, TableMetadata,
+};
+use crate::table::Table;
+use crate::{Error, ErrorKind};
+
+/// Operations whose snapshots may add data files that conflict with a pending commit.
+const NEW_DATA_FILES_OPERATIONS: [Operation; 2] = [Operation::Append, Operation::Overwrite];
+/// Operations whose snapshots may add delete files that conflict with a pending commit.
+const NEW_DELETE_FILES_OPERATIONS: [Operation; 2] = [Operation::Overwrite, Operation::Delete];
+/// Operations whose snapshots may delete data files that conflict with a pending commit.
+const DELETED_DATA_FILES_OPERATIONS: [Operation; 2] = [Operation::Overwrite, Operation::Delete];
+
+/// Conflict validation against snapshots committed after a starting snapshot.
+///
+/// Snapshot producing actions carry a `SnapshotValidator` and run it against the
+/// refreshed table on every commit attempt, so that changes committed concurrently
+/// by other writers are detected even when the catalog accepts the retried commit.
+/// A detected conflict fails the commit with [`ErrorKind::CommitConflict`].
+///
+/// Fast append is the only action producing snapshots from new files, so it is the only one
+/// carrying a validator. Appends only add files, so concurrent appends don't conflict unless the
+/// writer opts into validation. This crate has no overwrite, row delta or rewrite actions, and
+/// cherry-pick only publishes appends, so validating commits that replace or delete files is not
+/// supported.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct SnapshotValidator {
+    /// Snapshot the pending operation was based on. Only snapshots committed after it
+    /// are validated; when unset, the table had no snapshot and all ancestors of the
+    /// current snapshot are validated.
+    from_snapshot_id: Option<i64>,
+    /// Row filter limiting which concurrently added or removed files are conflicts.
+    conflict_detection_filter: Option<Predicate>,
+    /// Fail if data files matching the filter were added concurrently.
+    no_new_data_files: bool,
+    /// Fail if delete files matching the filter were added concurrently.
+    no_new_delete_files: bool,
+    /// Fail if data files matching the filter were deleted concurrently.
+    no_deleted_data_files: bool,
+}
+
+impl SnapshotValidator {
+    pub(crate) fn new(from_snapshot_id: Option<i64>) -> Self {
+        Self {
+            from_snapshot_id,
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn set_from_snapshot(&mut self, snapshot_id: i64) {
+        self.from_snapshot_id = Some(snapshot_id);
+    }
+
+    pub(crate) fn set_conflict_detection_filter(&mut self, filter: Predicate) {
+        self.conflict_detection_filter = Some(filter);
+    }
+
+    pub(crate) fn set_no_new_data_files(&mut self) {
+        self.no_new_data_files = true;
+    }
+
+    pub(crate) fn set_no_new_delete_files(&mut self) {
+        self.no_new_delete_files = true;
+    }
+
+    pub(crate) fn set_no_deleted_data_files(&mut self) {
+        self.no_deleted_data_files = true;
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.no_new_data_files || self.no_new_delete_files || self.no_deleted_data_files
+    }
+
+    /// Validates the snapshots committed to the main branch of `table` since the
+    /// starting snapshot.
+    pub(crate) async fn validate(&self, table: &Table) -> Result<()> {
+        if !self.is_enabled() {
+            return Ok(());
+        }
+
+        let history = self.validation_history(table.metadata())?;
+        if history.is_empty() {
+            return Ok(());
+        }
+
+        let mut filter = ConflictFilter::try_new(
+            table.metadata().current_schema().clone(),
+            self.conflict_detection_filter.as_ref(),
+        )?;
+
+        let mut new_data_files = vec![];
+        let mut new_delete_files = vec![];
+        let mut deleted_data_files = vec![];
+
+        for snapshot in history {
+            let operation = &snapshot.summary().operation;
+            let check_new_data =
+                self.no_new_data_files && NEW_DATA_FILES_OPERATIONS.contains(operation);
+            let check_new_deletes =
+                self.no_new_delete_files && NEW_DELETE_FILES_OPERATIONS.contains(operation);
+            let check_deleted_data =
+                self.no_deleted_data_files && DELETED_DATA_FILES_OPERATIONS.contains(operation);
+            if !(check_new_data || check_new_deletes || check_deleted_data) {
+                continue;
+            }
+
+            let manifest_list = snapshot
+                .load_manifest_list(table.file_io(), &table.metadata_ref())
+                .await?;
+
+            for manifest_file in manifest_list.entries() {
+                // Only manifests written by this snapshot can contain its changes.
+                if manifest_file.added_snapshot_id != snapshot.snapshot_id() {
+                    continue;
+                }
+
+                let (check_added, check_deleted) = match manifest_file.content {
+                    ManifestContentType::Data => (
+                        check_new_data && manifest_file.has_added_files(),
+                        check_deleted_data && manifest_file.has_deleted_files(),
+                    ),
+                    ManifestContentType::Deletes => {
+                        (check_new_deletes && manifest_file.has_added_files(), false)
+                    }
+                };
+                if !(check_added || check_deleted)
+                    || !filter.may_match_manifest(table.metadata(), manifest_file)?
+                {
+                    continue;
+                }
+
+                let manifest = manifest_file.load_manifest(table.file_io()).await?;
+                for entry in manifest.entries() {
+                    if entry.snapshot_id() != Some(snapshot.snapshot_id()) {
+                        continue;
+                    }
+
+                    let conflicts = match (entry.status(), manifest_file.content) {
+                        (ManifestStatus::Added, ManifestContentType::Data) if check_added => {
+                            &mut new_data_files
+                        }
+                        (ManifestStatus::Added, ManifestContentType::Deletes) if check_added => {
+                            &mut new_delete_files
+                        }
+                        (ManifestStatus::Deleted, ManifestContentType::Data) if check_deleted => {
+                            &mut deleted_data_files
+                        }
+                        _ => continue,
+                    };
+
+                    if filter.may_match_file(table.metadata(), entry.data_file())? {
+                        conflicts.push(entry.file_path().to_string());
+                    }
+                }
+            }
+        }
+
+        let filter_display = self
+            .conflict_detection_filter
+            .as_ref()
+            .map(|filter| filter.to_string())
+            .unwrap_or_else(|| "TRUE".to_string());
+
+        if !new_data_files.is_empty() {
+            return Err(Self::conflict_error(
+                format!(
+                    "Found conflicting files that can contain records matching {filter_display}"
+                ),
+                new_data_files,
+            ));
+        }
+        if !new_delete_files.is_empty() {
+            return Err(Self::conflict_error(
+                format!(
+                    "Found new conflicting delete files that can apply to records matching {filter_display}"
+                ),
+                new_delete_files,
+            ));
+        }
+        if !deleted_data_files.is_empty() {
+            return Err(Self::conflict_error(
+                format!(
+                    "Found conflicting deleted files that can contain records matching {filter_display}"
+                ),
+                deleted_data_files,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the snapshots on the main branch that were committed after the starting
+    /// snapshot, newest first.
+    fn validation_history<'a>(&self, metadata: &'a TableMetadata) -> Result<Vec<&'a SnapshotRef>> {
+        let mut history = vec![];
+        let mut current = metadata.snapshot_for_ref(MAIN_BRANCH);
+
+        while let Some(snapshot) = current {
+            if Some(snapshot.snapshot_id()) == self.from_snapshot_id {
+                return Ok(history);
+            }
+            history.push(snapshot);
+            current = snapshot
+                .parent_snapshot_id()
+                .and_then(|parent_id| metadata.snapshot_by_id(parent_id));
+        }
+
+        match self.from_snapshot_id {
+            Some(from_snapshot_id) => Err(Error::new(
+                ErrorKind::CommitConflict,
+                format!(
+                    "Cannot determine history between starting snapshot {from_snapshot_id} and the current snapshot"
+                ),
+            )
+            .with_context(
+                "current_snapshot_id",
+                format!("{:?}", metadata.current_snapshot_id()),
+            )),
+            None => Ok(history),
+        }
+    }
+
+    fn conflict_error(message: String, file_paths: Vec<String>) -> Error {
+        Error::new(
+            ErrorKind::CommitConflict,
+            format!("{message}: {}", file_paths.join(", ")),
+        )
+    }
+}
+
+/// Evaluates the conflict detection filter against manifests and files.
+struct ConflictFilter {
+    schema: SchemaRef,
+    row_filter: Option<BoundPredicate>,
+    // Projected partition filters keyed by partition spec id.
+    partition_filters: HashMap<i32, Arc<BoundPredicate>>,
+}
+
+impl ConflictFilter {
+    fn try_new(schema: SchemaRef, filter: Option<&Predicate>) -> Result<Self> {
+        let row_filter = filter
+            .map(|filter| filter.clone().rewrite_not().bind(schema.clone(), true))
+            .transpose()?;
+
+        Ok(Self {
+            schema,
+            row_filter,
+            partition_filters: HashMap::new(),
+        })
+    }
+
+    fn partition_filter(
+        &mut self,
+        metadata: &TableMetadata,
+        spec_id: i32,
+    ) -> Result<Option<Arc<BoundPredicate>>> {
+        let Some(row_filter) = &self.row_filter else {
+            return Ok(None);
+        };
+
+        if let Some(partition_filter) = self.partition_filters.get(&spec_id) {
+            return Ok(Some(partition_filter.clone()));
+        }
+
+        let partition_spec = metadata.partition_spec_by_id(spec_id).ok_or_else(|| {
+            Error::new(
+                ErrorKind::Unexpected,
+                format!("Could not find partition spec for id {spec_id}"),
+            )
+        })?;
+        let partition_type = partition_spec.partition_type(&self.schema)?;
+        let partition_schema = Arc::new(
+            Schema::builder()
+                .with_schema_id(partition_spec.spec_id())
+                .with_fields(partition_type.fields().to_owned())
+                .build()?,
+        );
+
+        let partition_filter = Arc::new(
+            InclusiveProjection::new(partition_spec.clone())
+                .project(row_filter)?
+                .rewrite_not()
+                .bind(partition_schema, true)?,
+        );
+        self.partition_filters
+            .insert(spec_id, partition_filter.clone());
+
+        Ok(Some(partition_filter))
+    }
+
+    fn may_match_manifest(
+        &mut self,
+        metadata: &TableMetadata,
+        manifest_file: &ManifestFile,
+    ) -> Result<bool> {
+        match self.partition_filter(metadata, manifest_file.partition_spec_id)? {
+            Some(partition_filter) => ManifestEvaluator::builder(partition_filter.as_ref().clone())
+                .build()
+                .eval(manifest_file),
+            None => Ok(true),
+        }
+    }
+
+    fn may_match_file(&mut self, metadata: &TableMetadata, data_file: &DataFile) -> Result<bool> {
+        let Some(partition_filter) =
+            self.partition_filter(metadata, data_file.partition_spec_id)?
+        else {
+            return Ok(true);
+        };
+
+        if !ExpressionEvaluator::new(partition_filter.as_ref().clone()).eval(data_file)? {
+            return Ok(false);
+        }
+
+        match &self.row_filter {
+            Some(row_filter) => InclusiveMetricsEvaluator::eval(row_filter, data_file, true),
+            None => Ok(true),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::expr::Reference;
+    use crate::memory::tests::new_memory_catalog;
+    use crate::spec::{
+        DataContentType, DataFile, DataFileBuilder, DataFileFormat, Datum, Literal, Struct,
+    };
+    use crate::transaction::tests::make_v3_minimal_table_in_catalog;
+    use crate::transaction::{ApplyTransactionAction, Transaction};
+    use crate::{Catalog, ErrorKind};
+
+    fn data_file(path: &str, partition_value: i64) -> DataFile {
+        DataFileBuilder::default()
+            .content(DataContentType::Data)
+            .file_path(path.to_string())
+            .file_format(DataFileFormat::Parquet)
+            .file_size_in_bytes(100)
+            .record_count(1)
+            .partition(Struct::from_iter([Some(Literal::long(partition_value))]))
+            .partition_spec_id(0)
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_append_conflicts() {
+        let catalog = new_memory_catalog().await;
+        let table = make_v3_minimal_table_in_catalog(&catalog).await;
+
+        // Both transactions start from the same table state.
+        let tx_a = Transaction::new(&table);
+        let tx_a = tx_a
+            .fast_append()
+            .add_data_files(vec![data_file("test/a.parquet", 0)])
+            .apply(tx_a)
+            .unwrap();
+        let tx_b = Transaction::new(&table);
+        let tx_b = tx_b
+            .fast_append()
+            .add_data_files(vec![data_file("test/b.parquet", 0)])
+            .validate_no_conflicting_data()
+            .apply(tx_b)
+            .unwrap();
+
+        tx_a.commit(&catalog).await.unwrap();
+        let err = tx_b.commit(&catalog).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::CommitConflict);
+        assert!(err.message().contains("test/a.parquet"));
+        assert!(!err.retryable());
+    }
+
+    #[tokio::test]
+    async fn test_validated_append_without_concurrent_writer() {
+        let catalog = new_memory_catalog().await;
+        let table = make_v3_minimal_table_in_catalog(&catalog).await;
+
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .fast_append()
+            .add_data_files(vec![data_file("test/a.parquet", 0)])
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+
+        // Snapshots committed before the transaction was created are not conflicts.
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .fast_append()
+            .add_data_files(vec![data_file("test/b.parquet", 0)])
+            .validate_no_conflicting_data()
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+        assert_eq!(table.metadata().snapshots().count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_appends_without_validation() {
+        let catalog = new_memory_catalog().await;
+        let table = make_v3_minimal_table_in_catalog(&catalog).await;
+
+        let tx_a = Transaction::new(&table);
+        let tx_a = tx_a
+            .fast_append()
+            .add_data_files(vec![data_file("test/a.parquet", 0)])
+            .apply(tx_a)
+            .unwrap();
+        let tx_b = Transaction::new(&table);
+        let tx_b = tx_b
+            .fast_append()
+            .add_data_files(vec![data_file("test/b.parquet", 0)])
+            .apply(tx_b)
+            .unwrap();
+
+        // Appends only add files, so the second one is retried on top of the first one.
+        tx_a.commit(&catalog).await.unwrap();
+        let table = tx_b.commit(&catalog).await.unwrap();
+        assert_eq!(table.metadata().snapshots().count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_append_outside_conflict_filter() {
+        let catalog = new_memory_catalog().await;
+        let table = make_v3_minimal_table_in_catalog(&catalog).await;
+
+        let tx_a = Transaction::new(&table);
+        let tx_a = tx_a
+            .fast_append()
+            .add_data_files(vec![data_file("test/a.parquet", 0)])
+            .apply(tx_a)
+            .unwrap();
+        let tx_b = Transaction::new(&table);
+        let tx_b = tx_b
+            .fast_append()
+            .add_data_files(vec![data_file("test/b.parquet", 2)])
+            .conflict_detection_filter(Reference::new("x").equal_to(Datum::long(2)))
+            .validate_no_conflicting_data()
+            .apply(tx_b)
+            .unwrap();
+
+        tx_a.commit(&catalog).await.unwrap();
+        let table = tx_b.commit(&catalog).await.unwrap();
+        assert_eq!(table.metadata().snapshots().count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_validate_from_snapshot() {
+        let catalog = new_memory_catalog().await;
+        let table = make_v3_minimal_table_in_catalog(&catalog).await;
+
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .fast_append()
+            .add_data_files(vec![data_file("test/a.parquet", 0)])
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+        let starting_snapshot_id = table.metadata().current_snapshot_id().unwrap();
+
+        // Snapshots up to and including the starting snapshot are not validated.
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .fast_append()
+            .add_data_files(vec![data_file("test/b.parquet", 0)])
+            .validate_from_snapshot(starting_snapshot_id)
+            .validate_no_conflicting_data()
+            .apply(tx)
+            .unwrap();
+        let table = tx.commit(&catalog).await.unwrap();
+
+        // An unknown starting snapshot cannot be validated.
+        let tx = Transaction::new(&table);
+        let tx = tx
+            .fast_append()
+            .add_data_files(vec![data_file("test/c.parquet", 0)])
+            .validate_from_snapshot(-1)
+            .validate_no_conflicting_data()
+            .apply(tx)
+            .unwrap();
+        let err = tx.commit(&catalog).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::CommitConflict);
+
+        let table = catalog.load_table(table.identifier()).await.unwrap();
+        assert_eq!(table.metadata().snapshots().count(), 2);
+    }
+}
@@ -0,0 +1,408 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+
+use chrono::Utc;
+
+use crate::error::Result;
+use crate::spec::{
+    Schema, SqlViewRepresentation, TableMetadataBuilder, ViewMetadataBuilder, ViewRepresentation,
+    ViewRepresentations, ViewVersion,
+};
+use crate::view::View;
+use crate::{Catalog, Error, ErrorKind, ViewCommit, ViewRequirement};
+
+/// Transaction changing the definition or the properties of a view.
+///
+/// A view version can't contain two queries for the same dialect, and changing the current
+/// version can't drop a dialect unless `replace.drop-dialect.allowed` is set; the latter is
+/// checked on commit, once the final properties are known. Schemas and view versions identical
+/// to existing ones are reused instead of added again.
+///
+/// ```ignore
+/// use iceberg::spec::SqlViewRepresentation;
+/// use iceberg::transaction::ViewTransaction;
+///
+/// let view = ViewTransaction::new(&view)
+///     .add_representation(SqlViewRepresentation {
+///         sql: "SELECT count(*) AS cnt FROM events".to_string(),
+///         dialect: "trino".to_string(),
+///     })?
+///     .commit(&catalog)
+///     .await?;
+/// ```
+pub struct ViewTransaction {
+    view: View,
+    builder: ViewMetadataBuilder,
+}
+
+impl ViewTransaction {
+    /// Creates a new transaction.
+    pub fn new(view: &View) -> Self {
+        Self {
+            view: view.clone(),
+            builder: view.metadata().clone().into_builder(),
+        }
+    }
+
+    /// Replaces the definition of the view with a new version made of the given
+    /// representations and schema, and sets it as current.
+    ///
+    /// The default catalog, default namespace and summary are kept from the current version.
+    pub fn replace_version(
+        mut self,
+        representations: ViewRepresentations,
+        schema: Schema,
+    ) -> Result<Self> {
+        let current_version = self.builder.current_version().clone();
+        let view_version = ViewVersion::builder()
+            .with_schema_id(schema.schema_id())
+            .with_timestamp_ms(Utc::now().timestamp_millis())
+            .with_summary(current_version.summary().clone())
+            .with_representations(representations)
+            .with_default_catalog(current_version.default_catalog().cloned())
+            .with_default_namespace(current_version.default_namespace().clone())
+            .build();
+
+        self.builder = self.builder.set_current_version(view_version, schema)?;
+        Ok(self)
+    }
+
+    /// Adds a query for another dialect to the current definition of the view, creating a
+    /// new current version.
+    ///
+    /// Returns an error if the current version already has a query for the dialect.
+    pub fn add_representation(mut self, representation: SqlViewRepresentation) -> Result<Self> {
+        let current_version = self.builder.current_version().clone();
+        if current_version.representations().iter().any(|repr| {
+            let ViewRepresentation::Sql(sql_repr) = repr;
+            sql_repr
+                .dialect
+                .eq_ignore_ascii_case(&representation.dialect)
+        }) {
+            return Err(Error::new(
+                ErrorKind::DataInvalid,
+                format!(
+                    "Cannot add a representation for dialect {}: the view already has one",
+                    representation.dialect
+                ),
+            ));
+        }
+
+        let view_version = ViewVersion::builder()
+            .with_schema_id(current_version.schema_id())
+            .with_timestamp_ms(Utc::now().timestamp_millis())
+            .with_summary(current_version.summary().clone())
+            .with_representations(
+                current_version
+                    .representations()
+                    .iter()
+                    .cloned()
+                    .chain([representation.into()])
+                    .collect(),
+            )
+            .with_default_catalog(current_version.default_catalog().cloned())
+            .with_default_namespace(current_version.default_namespace().clone())
+            .build();
+
+        self.builder = self
+            .builder
+            .add_version(view_version)?
+            .set_current_version_id(TableMetadataBuilder::LAST_ADDED)?;
+        Ok(self)
+    }
+
+    /// Sets an earlier version of the view as current.
+    pub fn rollback_to(mut self, version_id: i32) -> Result<Self> {
+        self.builder = self.builder.set_current_version_id(version_id)?;
+        Ok(self)
+    }
+
+    /// Sets properties of the view. Existing keys are overwritten.
+    pub fn set_properties(mut self, properties: HashMap<String, String>) -> Result<Self> {
+        self.builder = self.builder.set_properties(properties)?;
+        Ok(self)
+    }
+
+    /// Removes properties of the view.
+    pub fn remove_properties(mut self, keys: &[String]) -> Self {
+        self.builder = self.builder.remove_properties(keys);
+        self
+    }
+
+    /// Commits the changes to the catalog, returning the updated view.
+    pub async fn commit(self, catalog: &dyn Catalog) -> Result<View> {
+        let changes = self.builder.build()?.changes;
+        if changes.is_empty() {
+            // nothing to commit
+            return Ok(self.view);
+        }
+
+        let commit = ViewCommit::builder()
+            .ident(self.view.identifier().clone())
+            .requirements(vec![ViewRequirement::UuidMatch {
+                uuid: self.view.metadata().uuid(),
+            }])
+            .updates(changes)
+            .build();
+
+        catalog.update_view(commit).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use tempfile::TempDir;
+
+    use crate::memory::tests::new_memory_catalog;
+    use crate::spec::{
+        NestedField, PrimitiveType, Schema, SqlViewRepresentation, Type, ViewRepresentation,
+        ViewRepresentations,
+    };
+    use crate::transaction::ViewTransaction;
+    use crate::view::View;
+    use crate::{Catalog, ErrorKind, NamespaceIdent, TableIdent, ViewCreation};
+
+    fn schema(field_names: &[&str]) -> Schema {
+        Schema::builder()
+            .with_fields(field_names.iter().enumerate().map(|(i, name)| {
+                NestedField::optional(i as i32 + 1, *name, Type::Primitive(PrimitiveType::Long))
+                    .into()
+            }))
+            .build()
+            .unwrap()
+    }
+
+    fn sql(dialect: &str, sql: &str) -> SqlViewRepresentation {
+        SqlViewRepresentation {
+            sql: sql.to_string(),
+            dialect: dialect.to_string(),
+        }
+    }
+
+    fn dialects(view: &View) -> Vec<String> {
+        view.metadata()
+            .current_version()
+            .representations()
+            .iter()
+            .map(|repr| {
+                let ViewRepresentation::Sql(sql_repr) = repr;
+                sql_repr.dialect.clone()
+            })
+            .collect()
+    }
+
+    async fn make_view(catalog: &impl Catalog) -> View {
+        let namespace_ident = NamespaceIdent::new("ns".to_string());
+        catalog
+            .create_namespace(&namespace_ident, HashMap::new())
+            .await
+            .unwrap();
+
+        let view_ident = TableIdent::new(namespace_ident.clone(), "v".to_string());
+        catalog
+            .create_view(
+                &namespace_ident,
+                ViewCreation::builder()
+                    .name(view_ident.name().to_string())
+                    .location(TempDir::new().unwrap().path().to_str().unwrap().to_string())
+                    .schema(schema(&["cnt"]))
+                    .representations(ViewRepresentations::from_iter([sql(
+                        "spark",
+                        "SELECT count(*) AS cnt FROM events",
+                    )
+                    .into()]))
+                    .default_namespace(namespace_ident.clone())
+                    .build(),
+            )
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_replace_version() {
+        let catalog = new_memory_catalog().await;
+        let view = make_view(&catalog).await;
+
+        let view = ViewTransaction::new(&view)
+            .replace_version(
+                ViewRepresentations::from_iter([sql(
+                    "spark",
+                    "SELECT count(*) AS cnt, max(ts) AS latest FROM events",
+                )
+                .into()]),
+                schema(&["cnt", "latest"]),
+            )
+            .unwrap()
+            .commit(&catalog)
+            .await
+            .unwrap();
+
+        let current_version = view.metadata().current_version();
+        assert_eq!(current_version.version_id(), 2);
+        assert_eq!(current_version.schema_id(), 1);
+        assert_eq!(view.metadata().schemas_iter().len(), 2);
+        assert_eq!(
+            view.metadata()
+                .history()
+                .iter()
+                .map(|log| log.version_id())
+                .collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+
+        // Replacing with the original schema reuses its id.
+        let view = ViewTransaction::new(&view)
+            .replace_version(
+                ViewRepresentations::from_iter([sql(
+                    "spark",
+                    "SELECT count(1) AS cnt FROM events",
+                )
+                .into()]),
+                schema(&["cnt"]),
+            )
+            .unwrap()
+            .commit(&catalog)
+            .await
+            .unwrap();
+        assert_eq!(view.metadata().current_version().version_id(), 3);
+        assert_eq!(view.metadata().current_version().schema_id(), 0);
+        assert_eq!(view.metadata().schemas_iter().len(), 2);
+
+        let loaded_view = catalog.load_view(view.identifier()).await.unwrap();
+        assert_eq!(loaded_view.metadata(), view.metadata());
+    }
+
+    #[tokio::test]
+    async fn test_replace_version_cannot_drop_dialect() {
+        let catalog = new_memory_catalog().await;
+        let view = make_view(&catalog).await;
+
+        let result = ViewTransaction::new(&view)
+            .replace_version(
+                ViewRepresentations::from_iter([sql(
+                    "trino",
+                    "SELECT count(*) AS cnt FROM events",
+                )
+                .into()]),
+                schema(&["cnt"]),
+            )
+            .unwrap()
+            .commit(&catalog)
+            .await;
+
+        assert_eq!(result.err().unwrap().kind(), ErrorKind::DataInvalid);
+        let loaded_view = catalog.load_view(view.identifier()).await.unwrap();
+        assert_eq!(loaded_view.metadata().current_version_id(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_add_representation() {
+        let catalog = new_memory_catalog().await;
+        let view = make_view(&catalog).await;
+
+        let view = ViewTransaction::new(&view)
+            .add_representation(sql("trino", "SELECT count(*) AS cnt FROM events"))
+            .unwrap()
+            .commit(&catalog)
+            .await
+            .unwrap();
+
+        assert_eq!(view.metadata().current_version().version_id(), 2);
+        assert_eq!(view.metadata().current_version().schema_id(), 0);
+        assert_eq!(dialects(&view), vec!["spark", "trino"]);
+
+        let result = ViewTransaction::new(&view)
+            .add_representation(sql("Trino", "SELECT count(1) AS cnt FROM events"));
+        assert_eq!(result.err().unwrap().kind(), ErrorKind::DataInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_rollback_to() {
+        let catalog = new_memory_catalog().await;
+        let view = make_view(&catalog).await;
+
+        let view = ViewTransaction::new(&view)
+            .add_representation(sql("trino", "SELECT count(*) AS cnt FROM events"))
+            .unwrap()
+            .set_properties(HashMap::from([(
+                "replace.drop-dialect.allowed".to_string(),
+                "true".to_string(),
+            )]))
+            .unwrap()
+            .commit(&catalog)
+            .await
+            .unwrap();
+
+        let view = ViewTransaction::new(&view)
+            .rollback_to(1)
+            .unwrap()
+            .commit(&catalog)
+            .await
+            .unwrap();
+
+        assert_eq!(view.metadata().current_version_id(), 1);
+        assert_eq!(dialects(&view), vec!["spark"]);
+        assert_eq!(
+            view.metadata()
+                .history()
+                .iter()
+                .map(|log| log.version_id())
+                .collect::<Vec<_>>(),
+            vec![1, 2, 1]
+        );
+
+        let result = ViewTransaction::new(&view).rollback_to(5);
+        assert_eq!(result.err().unwrap().kind(), ErrorKind::DataInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_update_properties() {
+        let catalog = new_memory_catalog().await;
+        let view = make_view(&catalog).await;
+
+        let view = ViewTransaction::new(&view)
+            .set_properties(HashMap::from([
+                ("comment".to_string(), "event counts".to_string()),
+                ("owner".to_string(), "bi".to_string()),
+            ]))
+            .unwrap()
+            .remove_properties(&["owner".to_string()])
+            .commit(&catalog)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            view.metadata().properties(),
+            &HashMap::from([("comment".to_string(), "event counts".to_string())])
+        );
+        assert_eq!(view.metadata().current_version_id(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_commit_without_changes() {
+        let catalog = new_memory_catalog().await;
+        let view = make_view(&catalog).await;
+
+        let committed_view = ViewTransaction::new(&view).commit(&catalog).await.unwrap();
+
+        assert_eq!(committed_view.metadata_location(), view.metadata_location());
+    }
+}
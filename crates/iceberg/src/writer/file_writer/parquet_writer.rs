@@ -24,23 +24,29 @@ use arrow_schema::SchemaRef as ArrowSchemaRef;
 use bytes::Bytes;
 use futures::future::BoxFuture;
 use itertools::Itertools;
-use parquet::arrow::AsyncArrowWriter;
+use parquet::arrow::arrow_writer::ArrowWriterOptions;
 use parquet::arrow::async_reader::AsyncFileReader;
 use parquet::arrow::async_writer::AsyncFileWriter as ArrowAsyncFileWriter;
+use parquet::arrow::{ArrowSchemaConverter, AsyncArrowWriter};
+use parquet::basic::{EdgeInterpolationAlgorithm, LogicalType};
 use parquet::file::metadata::ParquetMetaData;
 use parquet::file::properties::WriterProperties;
 use parquet::file::statistics::Statistics;
+use parquet::schema::types::{SchemaDescriptor, Type as ParquetType, TypePtr};
 
 use super::{FileWriter, FileWriterBuilder};
+use crate::arrow::row_lineage::schema_with_batch_row_lineage;
 use crate::arrow::{
-    ArrowFileReader, DEFAULT_MAP_FIELD_NAME, FieldMatchMode, NanValueCountVisitor,
-    get_parquet_stat_max_as_datum, get_parquet_stat_min_as_datum,
+    ArrowFileReader, DEFAULT_MAP_FIELD_NAME, FieldMatchMode, GeospatialBoundsVisitor,
+    NanValueCountVisitor, get_parquet_stat_max_as_datum, get_parquet_stat_min_as_datum,
 };
+use crate::encryption::EncryptionManager;
 use crate::io::{FileIO, FileWrite, OutputFile};
 use crate::spec::{
-    DataContentType, DataFileBuilder, DataFileFormat, Datum, ListType, Literal, MapType,
-    NestedFieldRef, PartitionSpec, PrimitiveType, Schema, SchemaRef, SchemaVisitor, Struct,
-    StructType, TableMetadata, Type, visit_schema,
+    BoundingBox, DEFAULT_CRS, DataContentType, DataFileBuilder, DataFileFormat, Datum,
+    EdgeAlgorithm, ListType, Literal, MapType, NestedFieldRef, PartitionSpec, PrimitiveLiteral,
+    PrimitiveType, Schema, SchemaRef, SchemaVisitor, Struct, StructType, TableMetadata, Type,
+    visit_schema,
 };
 use crate::transform::create_transform_function;
 use crate::writer::{CurrentFileStatus, DataFile};
@@ -52,6 +58,7 @@ pub struct ParquetWriterBuilder {
     props: WriterProperties,
     schema: SchemaRef,
     match_mode: FieldMatchMode,
+    encryption_manager: Option<EncryptionManager>,
 }
 
 impl ParquetWriterBuilder {
@@ -71,21 +78,43 @@ impl ParquetWriterBuilder {
             props,
             schema,
             match_mode,
+            encryption_manager: None,
         }
     }
+
+    /// Encrypt the written files with Parquet modular encryption, each with a new key of
+    /// the encryption manager, recorded in the key metadata of its data file.
+    pub fn with_encryption_manager(mut self, encryption_manager: EncryptionManager) -> Self {
+        self.encryption_manager = Some(encryption_manager);
+        self
+    }
 }
 
 impl FileWriterBuilder for ParquetWriterBuilder {
     type R = ParquetWriter;
 
     async fn build(&self, output_file: OutputFile) -> Result<Self::R> {
+        let mut writer_properties = self.props.clone();
+        let mut key_metadata = None;
+        if let Some(encryption_manager) = &self.encryption_manager {
+            let file_key_metadata = encryption_manager.generate_key_metadata();
+            writer_properties = writer_properties
+                .into_builder()
+                .with_file_encryption_properties(file_key_metadata.parquet_encryption_properties()?)
+                .build();
+            key_metadata = Some(file_key_metadata.encode()?);
+        }
+
         Ok(ParquetWriter {
             schema: self.schema.clone(),
             inner_writer: None,
-            writer_properties: self.props.clone(),
+            writer_properties,
+            key_metadata,
             current_row_num: 0,
             output_file,
             nan_value_count_visitor: NanValueCountVisitor::new_with_match_mode(self.match_mode),
+            geospatial_bounds_visitor: has_geospatial_field(&self.schema)
+                .then(|| GeospatialBoundsVisitor::new_with_match_mode(self.match_mode)),
         })
     }
 }
@@ -206,6 +235,12 @@ impl SchemaVisitor for IndexByParquetPathName {
 
         Ok(())
     }
+
+    fn variant(&mut self) -> Result<Self::T> {
+        // Variant columns are stored as binary `metadata` and `value` leaves
+        // which have no field ids and collect no metrics.
+        Ok(())
+    }
 }
 
 /// `ParquetWriter`` is used to write arrow data into parquet file on storage.
@@ -214,8 +249,81 @@ pub struct ParquetWriter {
     output_file: OutputFile,
     inner_writer: Option<AsyncArrowWriter<AsyncFileWriter>>,
     writer_properties: WriterProperties,
+    /// The serialized key metadata of an encrypted file.
+    key_metadata: Option<Vec<u8>>,
     current_row_num: usize,
     nan_value_count_visitor: NanValueCountVisitor,
+    /// Only set when the schema has geospatial columns.
+    geospatial_bounds_visitor: Option<GeospatialBoundsVisitor>,
+}
+
+fn has_geospatial_field(schema: &Schema) -> bool {
+    schema
+        .field_id_to_fields()
+        .values()
+        .any(|field| matches!(field.field_type.as_ref(), Type::Primitive(p) if p.is_geospatial()))
+}
+
+/// Annotates the leaves of geometry and geography columns with the Parquet geospatial
+/// logical types, which can't be derived from the binary arrow type of these columns.
+fn with_geospatial_logical_types(
+    ty: &TypePtr,
+    schema: &Schema,
+) -> parquet::errors::Result<TypePtr> {
+    let info = ty.get_basic_info();
+    if ty.is_primitive() {
+        let field_type = info
+            .has_id()
+            .then(|| schema.field_by_id(info.id()))
+            .flatten()
+            .map(|field| field.field_type.as_ref());
+        let crs = |crs: &String| (crs != DEFAULT_CRS).then(|| crs.clone());
+        let logical_type = match field_type {
+            Some(Type::Primitive(PrimitiveType::Geometry { crs: field_crs })) => {
+                LogicalType::Geometry {
+                    crs: crs(field_crs),
+                }
+            }
+            Some(Type::Primitive(PrimitiveType::Geography {
+                crs: field_crs,
+                algorithm,
+            })) => LogicalType::Geography {
+                crs: crs(field_crs),
+                algorithm: Some(match algorithm {
+                    EdgeAlgorithm::Spherical => EdgeInterpolationAlgorithm::SPHERICAL,
+                    EdgeAlgorithm::Vincenty => EdgeInterpolationAlgorithm::VINCENTY,
+                    EdgeAlgorithm::Thomas => EdgeInterpolationAlgorithm::THOMAS,
+                    EdgeAlgorithm::Andoyer => EdgeInterpolationAlgorithm::ANDOYER,
+                    EdgeAlgorithm::Karney => EdgeInterpolationAlgorithm::KARNEY,
+                }),
+            },
+            _ => return Ok(ty.clone()),
+        };
+        return Ok(Arc::new(
+            ParquetType::primitive_type_builder(info.name(), ty.get_physical_type())
+                .with_repetition(info.repetition())
+                .with_logical_type(Some(logical_type))
+                .with_id(Some(info.id()))
+                .build()?,
+        ));
+    }
+
+    let fields = ty
+        .get_fields()
+        .iter()
+        .map(|field| with_geospatial_logical_types(field, schema))
+        .collect::<parquet::errors::Result<Vec<_>>>()?;
+    let mut builder = ParquetType::group_type_builder(info.name())
+        .with_fields(fields)
+        .with_logical_type(info.logical_type_ref().cloned())
+        .with_converted_type(info.converted_type());
+    if info.has_repetition() {
+        builder = builder.with_repetition(info.repetition());
+    }
+    if info.has_id() {
+        builder = builder.with_id(Some(info.id()));
+    }
+    Ok(Arc::new(builder.build()?))
 }
 
 /// Used to aggregate min and max value of each column.
@@ -275,6 +383,11 @@ impl MinMaxColAggregator {
             ));
         };
 
+        // Geospatial bounds are bounding boxes rather than the min and max of the WKB bytes.
+        if ty.is_geospatial() {
+            return Ok(());
+        }
+
         if value.min_is_exact() {
             let Some(min_datum) = get_parquet_stat_min_as_datum(&ty, &value)? else {
                 return Err(Error::new(
@@ -337,6 +450,7 @@ impl ParquetWriter {
                 file_path,
                 // TODO: Implement nan_value_counts here
                 HashMap::new(),
+                HashMap::new(),
             )?;
             builder.partition_spec_id(table_metadata.default_partition_spec_id());
             let data_file = builder.build().unwrap();
@@ -353,6 +467,7 @@ impl ParquetWriter {
         written_size: usize,
         file_path: String,
         nan_value_counts: HashMap<i32, u64>,
+        geospatial_bounds: HashMap<i32, BoundingBox>,
     ) -> Result<DataFileBuilder> {
         let index_by_parquet_path = {
             let mut visitor = IndexByParquetPathName::new();
@@ -360,11 +475,11 @@ impl ParquetWriter {
             visitor
         };
 
-        let (column_sizes, value_counts, null_value_counts, (lower_bounds, upper_bounds)) = {
+        let (column_sizes, value_counts, null_value_counts, (mut lower_bounds, mut upper_bounds)) = {
             let mut per_col_size: HashMap<i32, u64> = HashMap::new();
             let mut per_col_val_num: HashMap<i32, u64> = HashMap::new();
             let mut per_col_null_val_num: HashMap<i32, u64> = HashMap::new();
-            let mut min_max_agg = MinMaxColAggregator::new(schema);
+            let mut min_max_agg = MinMaxColAggregator::new(schema.clone());
 
             for row_group in metadata.row_groups() {
                 for column_chunk_metadata in row_group.columns() {
@@ -396,6 +511,23 @@ impl ParquetWriter {
             )
         };
 
+        for (field_id, bbox) in geospatial_bounds {
+            let Some(field) = schema.field_by_id(field_id) else {
+                continue;
+            };
+            let Type::Primitive(ty) = field.field_type.as_ref() else {
+                continue;
+            };
+            lower_bounds.insert(
+                field_id,
+                Datum::new(ty.clone(), PrimitiveLiteral::Binary(bbox.min().to_bytes())),
+            );
+            upper_bounds.insert(
+                field_id,
+                Datum::new(ty.clone(), PrimitiveLiteral::Binary(bbox.max().to_bytes())),
+            );
+        }
+
         let mut builder = DataFileBuilder::default();
         builder
             .content(DataContentType::Data)
@@ -481,9 +613,19 @@ impl FileWriter for ParquetWriter {
 
         self.current_row_num += batch.num_rows();
 
+        // Rows rewritten with their row lineage keep it in the new file
+        if self.inner_writer.is_none()
+            && let Some(schema) = schema_with_batch_row_lineage(&self.schema, batch.schema_ref())?
+        {
+            self.schema = Arc::new(schema);
+        }
+
         let batch_c = batch.clone();
         self.nan_value_count_visitor
             .compute(self.schema.clone(), batch_c)?;
+        if let Some(visitor) = &mut self.geospatial_bounds_visitor {
+            visitor.compute(self.schema.clone(), batch.clone())?;
+        }
 
         // Lazy initialize the writer
         let writer = if let Some(writer) = &mut self.inner_writer {
@@ -492,15 +634,27 @@ impl FileWriter for ParquetWriter {
             let arrow_schema: ArrowSchemaRef = Arc::new(self.schema.as_ref().try_into()?);
             let inner_writer = self.output_file.writer().await?;
             let async_writer = AsyncFileWriter::new(inner_writer);
-            let writer = AsyncArrowWriter::try_new(
-                async_writer,
-                arrow_schema.clone(),
-                Some(self.writer_properties.clone()),
-            )
-            .map_err(|err| {
-                Error::new(ErrorKind::Unexpected, "Failed to build parquet writer.")
-                    .with_source(err)
-            })?;
+            let mut options =
+                ArrowWriterOptions::new().with_properties(self.writer_properties.clone());
+            if self.geospatial_bounds_visitor.is_some() {
+                let parquet_schema = ArrowSchemaConverter::new()
+                    .with_coerce_types(self.writer_properties.coerce_types())
+                    .convert(&arrow_schema)
+                    .and_then(|descr| {
+                        with_geospatial_logical_types(&descr.root_schema_ptr(), &self.schema)
+                    })
+                    .map_err(|err| {
+                        Error::new(ErrorKind::Unexpected, "Failed to build parquet schema.")
+                            .with_source(err)
+                    })?;
+                options = options.with_parquet_schema(SchemaDescriptor::new(parquet_schema));
+            }
+            let writer =
+                AsyncArrowWriter::try_new_with_options(async_writer, arrow_schema.clone(), options)
+                    .map_err(|err| {
+                        Error::new(ErrorKind::Unexpected, "Failed to build parquet writer.")
+                            .with_source(err)
+                    })?;
             self.inner_writer = Some(writer);
             self.inner_writer.as_mut().unwrap()
         };
@@ -540,13 +694,18 @@ impl FileWriter for ParquetWriter {
         } else {
             let parquet_metadata = Arc::new(metadata);
 
-            Ok(vec![Self::parquet_to_data_file_builder(
+            let mut builder = Self::parquet_to_data_file_builder(
                 self.schema,
                 parquet_metadata,
                 written_size,
                 self.output_file.location().to_string(),
                 self.nan_value_count_visitor.nan_value_counts,
-            )?])
+                self.geospatial_bounds_visitor
+                    .map(|visitor| visitor.bounds())
+                    .unwrap_or_default(),
+            )?;
+            builder.key_metadata(self.key_metadata);
+            Ok(vec![builder])
         }
     }
 }
@@ -1620,6 +1779,254 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_variant_write_and_read() -> Result<()> {
+        use arrow_array::BinaryArray;
+        use futures::TryStreamExt;
+
+        use crate::arrow::ArrowReaderBuilder;
+        use crate::scan::{FileScanTask, FileScanTaskStream};
+
+        let temp_dir = TempDir::new().unwrap();
+        let file_io = FileIO::new_with_fs();
+        let location_gen = DefaultLocationGenerator::with_data_location(
+            temp_dir.path().to_str().unwrap().to_string(),
+        );
+        let file_name_gen =
+            DefaultFileNameGenerator::new("test".to_string(), None, DataFileFormat::Parquet);
+
+        let schema = Arc::new(
+            Schema::builder()
+                .with_fields(vec![
+                    NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+                    NestedField::optional(2, "payload", Type::Variant).into(),
+                ])
+                .build()
+                .unwrap(),
+        );
+        let arrow_schema: ArrowSchemaRef = Arc::new(schema_to_arrow_schema(&schema).unwrap());
+        let DataType::Struct(variant_fields) = arrow_schema.field(1).data_type() else {
+            unreachable!("variant is mapped to a struct");
+        };
+
+        // Variant metadata header (version 1, empty dictionary) and primitive values.
+        let metadata = BinaryArray::from(vec![&[0x01u8, 0x00, 0x00][..]; 3]);
+        let value = BinaryArray::from(vec![Some(&[0x0Cu8, 0x2A][..]), Some(&[0x00][..]), None]);
+        let payload = StructArray::new(
+            variant_fields.clone(),
+            vec![Arc::new(metadata) as ArrayRef, Arc::new(value) as ArrayRef],
+            Some(vec![true, true, false].into()),
+        );
+        let to_write = RecordBatch::try_new(arrow_schema.clone(), vec![
+            Arc::new(Int64Array::from(vec![1, 2, 3])) as ArrayRef,
+            Arc::new(payload) as ArrayRef,
+        ])
+        .unwrap();
+
+        let output_file = file_io.new_output(
+            location_gen.generate_location(None, &file_name_gen.generate_file_name()),
+        )?;
+        let mut pw = ParquetWriterBuilder::new(WriterProperties::builder().build(), schema.clone())
+            .build(output_file)
+            .await?;
+        pw.write(&to_write).await?;
+        let res = pw.close().await?;
+        let data_file = res
+            .into_iter()
+            .next()
+            .unwrap()
+            .content(DataContentType::Data)
+            .partition(Struct::empty())
+            .partition_spec_id(0)
+            .build()
+            .unwrap();
+
+        // Variant columns carry no field ids below the group, so only the long column has metrics.
+        assert_eq!(data_file.record_count(), 3);
+        assert_eq!(*data_file.value_counts(), HashMap::from([(1, 3)]));
+
+        let reader = ArrowReaderBuilder::new(file_io).build();
+        let tasks = Box::pin(futures::stream::iter(
+            vec![Ok(FileScanTask {
+                file_size_in_bytes: data_file.file_size_in_bytes(),
+                start: 0,
+                length: 0,
+                record_count: None,
+                data_file_path: data_file.file_path().to_string(),
+                data_file_format: DataFileFormat::Parquet,
+                schema: schema.clone(),
+                project_field_ids: vec![2],
+                predicate: None,
+                deletes: vec![],
+                partition: None,
+                partition_spec: None,
+                name_mapping: None,
+                first_row_id: None,
+                data_sequence_number: None,
+                key_metadata: None,
+                case_sensitive: false,
+            })]
+            .into_iter(),
+        )) as FileScanTaskStream;
+        let batches = reader
+            .read(tasks)
+            .unwrap()
+            .try_collect::<Vec<RecordBatch>>()
+            .await
+            .unwrap();
+
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].num_columns(), 1);
+        assert_eq!(batches[0].column(0), to_write.column(1));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_geometry_write_and_read() -> Result<()> {
+        use arrow_array::BinaryArray;
+        use futures::TryStreamExt;
+        use parquet::file::reader::{FileReader, SerializedFileReader};
+
+        use crate::arrow::ArrowReaderBuilder;
+        use crate::expr::{Bind, Reference};
+        use crate::scan::{FileScanTask, FileScanTaskStream};
+        use crate::spec::GeospatialBound;
+
+        let temp_dir = TempDir::new().unwrap();
+        let file_io = FileIO::new_with_fs();
+        let location_gen = DefaultLocationGenerator::with_data_location(
+            temp_dir.path().to_str().unwrap().to_string(),
+        );
+        let file_name_gen =
+            DefaultFileNameGenerator::new("test".to_string(), None, DataFileFormat::Parquet);
+
+        let schema = Arc::new(
+            Schema::builder()
+                .with_fields(vec![
+                    NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+                    NestedField::optional(2, "geom", Type::Primitive(PrimitiveType::geometry()))
+                        .into(),
+                    NestedField::optional(3, "geog", Type::Primitive(PrimitiveType::geography()))
+                        .into(),
+                ])
+                .build()
+                .unwrap(),
+        );
+        let arrow_schema: ArrowSchemaRef = Arc::new(schema_to_arrow_schema(&schema).unwrap());
+
+        let point = |x: f64, y: f64| {
+            let mut wkb = vec![1u8, 1, 0, 0, 0];
+            wkb.extend_from_slice(&x.to_le_bytes());
+            wkb.extend_from_slice(&y.to_le_bytes());
+            wkb
+        };
+        let points = vec![Some(point(1.0, 2.0)), Some(point(5.0, -3.0)), None];
+        let to_write = RecordBatch::try_new(arrow_schema.clone(), vec![
+            Arc::new(Int64Array::from(vec![1, 2, 3])) as ArrayRef,
+            Arc::new(BinaryArray::from_iter(points.clone())) as ArrayRef,
+            Arc::new(BinaryArray::from_iter(points)) as ArrayRef,
+        ])
+        .unwrap();
+
+        let output_file = file_io.new_output(
+            location_gen.generate_location(None, &file_name_gen.generate_file_name()),
+        )?;
+        let mut pw = ParquetWriterBuilder::new(WriterProperties::builder().build(), schema.clone())
+            .build(output_file)
+            .await?;
+        pw.write(&to_write).await?;
+        let res = pw.close().await?;
+        let data_file = res
+            .into_iter()
+            .next()
+            .unwrap()
+            .content(DataContentType::Data)
+            .partition(Struct::empty())
+            .partition_spec_id(0)
+            .build()
+            .unwrap();
+
+        // Only geometry columns have bounds.
+        assert_eq!(
+            *data_file.lower_bounds(),
+            HashMap::from([
+                (1, Datum::long(1)),
+                (
+                    2,
+                    Datum::geometry(GeospatialBound::new(1.0, -3.0).to_bytes())
+                )
+            ])
+        );
+        assert_eq!(
+            *data_file.upper_bounds(),
+            HashMap::from([
+                (1, Datum::long(3)),
+                (
+                    2,
+                    Datum::geometry(GeospatialBound::new(5.0, 2.0).to_bytes())
+                )
+            ])
+        );
+
+        let parquet_reader =
+            SerializedFileReader::new(std::fs::File::open(data_file.file_path()).unwrap()).unwrap();
+        let parquet_schema = parquet_reader.metadata().file_metadata().schema_descr_ptr();
+        assert_eq!(
+            parquet_schema.column(1).logical_type_ref(),
+            Some(&LogicalType::Geometry { crs: None })
+        );
+        assert_eq!(
+            parquet_schema.column(2).logical_type_ref(),
+            Some(&LogicalType::Geography {
+                crs: None,
+                algorithm: Some(EdgeInterpolationAlgorithm::SPHERICAL)
+            })
+        );
+
+        let predicate = Reference::new("geom")
+            .intersects(Datum::geometry(point(1.0, 2.0)))
+            .bind(schema.clone(), true)
+            .unwrap();
+        let reader = ArrowReaderBuilder::new(file_io).build();
+        let tasks = Box::pin(futures::stream::iter(
+            vec![Ok(FileScanTask {
+                file_size_in_bytes: data_file.file_size_in_bytes(),
+                start: 0,
+                length: 0,
+                record_count: None,
+                data_file_path: data_file.file_path().to_string(),
+                data_file_format: DataFileFormat::Parquet,
+                schema: schema.clone(),
+                project_field_ids: vec![1, 2],
+                predicate: Some(predicate),
+                deletes: vec![],
+                partition: None,
+                partition_spec: None,
+                name_mapping: None,
+                first_row_id: None,
+                data_sequence_number: None,
+                key_metadata: None,
+                case_sensitive: false,
+            })]
+            .into_iter(),
+        )) as FileScanTaskStream;
+        let batches = reader
+            .read(tasks)
+            .unwrap()
+            .try_collect::<Vec<RecordBatch>>()
+            .await
+            .unwrap();
+
+        assert_eq!(batches.len(), 1);
+        assert_eq!(
+            batches[0].column(0).as_ref(),
+            &Int64Array::from(vec![1]) as &dyn Array
+        );
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_nan_val_cnts_primitive_type() -> Result<()> {
         let temp_dir = TempDir::new().unwrap();
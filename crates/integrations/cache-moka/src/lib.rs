@@ -20,6 +20,8 @@ use std::sync::Arc;
 
 use iceberg::cache::{ObjectCache, ObjectCacheProvide};
 use iceberg::spec::{Manifest, ManifestList};
+use iceberg::table::Table;
+use iceberg::{NamespaceIdent, TableIdent};
 
 const DEFAULT_CACHE_SIZE_BYTES: u64 = 32 * 1024 * 1024; // 32MiB
 
@@ -37,12 +39,26 @@ where
     fn set(&self, key: K, value: V) {
         self.0.insert(key, value);
     }
+
+    fn invalidate(&self, key: &K) {
+        self.0.invalidate(key);
+    }
+
+    fn invalidate_if(&self, predicate: &dyn Fn(&K) -> bool) {
+        for (key, _) in self.0.iter() {
+            if predicate(&key) {
+                self.0.invalidate(key.as_ref());
+            }
+        }
+    }
 }
 
 /// A cache provider that uses Moka for caching objects.
 pub struct MokaObjectCacheProvider {
     manifest_cache: MokaObjectCache<String, Arc<Manifest>>,
     manifest_list_cache: MokaObjectCache<String, Arc<ManifestList>>,
+    table_cache: Option<MokaObjectCache<TableIdent, Table>>,
+    namespace_list_cache: Option<MokaObjectCache<Option<NamespaceIdent>, Vec<NamespaceIdent>>>,
 }
 
 impl Default for MokaObjectCacheProvider {
@@ -60,6 +76,8 @@ impl MokaObjectCacheProvider {
         Self {
             manifest_cache,
             manifest_list_cache,
+            table_cache: None,
+            namespace_list_cache: None,
         }
     }
 
@@ -77,6 +95,26 @@ impl MokaObjectCacheProvider {
         self.manifest_list_cache = MokaObjectCache(cache);
         self
     }
+
+    /// Set the cache for the tables loaded by a `CachingCatalog`.
+    ///
+    /// Tables are not cached unless this is set. The expiration of tables is configured on
+    /// the moka cache, for example with `time_to_live`.
+    pub fn with_table_cache(mut self, cache: moka::sync::Cache<TableIdent, Table>) -> Self {
+        self.table_cache = Some(MokaObjectCache(cache));
+        self
+    }
+
+    /// Set the cache for the namespaces listed by a `CachingCatalog`.
+    ///
+    /// Namespace listings are not cached unless this is set.
+    pub fn with_namespace_list_cache(
+        mut self,
+        cache: moka::sync::Cache<Option<NamespaceIdent>, Vec<NamespaceIdent>>,
+    ) -> Self {
+        self.namespace_list_cache = Some(MokaObjectCache(cache));
+        self
+    }
 }
 
 impl ObjectCacheProvide for MokaObjectCacheProvider {
@@ -87,4 +125,18 @@ impl ObjectCacheProvide for MokaObjectCacheProvider {
     fn manifest_list_cache(&self) -> &dyn ObjectCache<String, Arc<ManifestList>> {
         &self.manifest_list_cache
     }
+
+    fn table_cache(&self) -> Option<&dyn ObjectCache<TableIdent, Table>> {
+        self.table_cache
+            .as_ref()
+            .map(|cache| cache as &dyn ObjectCache<TableIdent, Table>)
+    }
+
+    fn namespace_list_cache(
+        &self,
+    ) -> Option<&dyn ObjectCache<Option<NamespaceIdent>, Vec<NamespaceIdent>>> {
+        self.namespace_list_cache
+            .as_ref()
+            .map(|cache| cache as &dyn ObjectCache<Option<NamespaceIdent>, Vec<NamespaceIdent>>)
+    }
 }
@@ -23,8 +23,11 @@ pub use error::*;
 
 pub mod physical_plan;
 mod schema;
+mod sql;
+pub use sql::execute_sql;
 pub mod table;
 pub use table::table_provider_factory::IcebergTableProviderFactory;
 pub use table::*;
 
 pub(crate) mod task_writer;
+mod view;
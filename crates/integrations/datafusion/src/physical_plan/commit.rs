@@ -30,10 +30,10 @@ use datafusion::physical_plan::execution_plan::{Boundedness, EmissionType};
 use datafusion::physical_plan::stream::RecordBatchStreamAdapter;
 use datafusion::physical_plan::{DisplayAs, DisplayFormatType, ExecutionPlan, PlanProperties};
 use futures::StreamExt;
-use iceberg::Catalog;
 use iceberg::spec::{DataFile, deserialize_data_file_from_json};
 use iceberg::table::Table;
 use iceberg::transaction::{ApplyTransactionAction, Transaction};
+use iceberg::{Catalog, TableCreation};
 
 use crate::physical_plan::DATA_FILES_COL_NAME;
 use crate::to_datafusion_error;
@@ -43,6 +43,7 @@ use crate::to_datafusion_error;
 #[derive(Debug)]
 pub(crate) struct IcebergCommitExec {
     table: Table,
+    mode: CommitMode,
     catalog: Arc<dyn Catalog>,
     input: Arc<dyn ExecutionPlan>,
     schema: ArrowSchemaRef,
@@ -50,6 +51,17 @@ pub(crate) struct IcebergCommitExec {
     plan_properties: PlanProperties,
 }
 
+/// How [`IcebergCommitExec`] commits the data files written.
+#[derive(Debug, Clone)]
+enum CommitMode {
+    /// Appends the data files to the existing table.
+    Append,
+    /// Creates the staged table with the data files.
+    Create,
+    /// Replaces the definition of the table, with the data files as its only data.
+    Replace(Box<(Table, TableCreation)>),
+}
+
 impl IcebergCommitExec {
     pub fn new(
         table: Table,
@@ -63,6 +75,7 @@ impl IcebergCommitExec {
 
         Self {
             table,
+            mode: CommitMode::Append,
             catalog,
             input,
             schema,
@@ -71,6 +84,24 @@ impl IcebergCommitExec {
         }
     }
 
+    /// Creates the staged table with the data files written in a single commit, instead of
+    /// appending them to an existing table. The table is created even if no data was
+    /// written.
+    pub fn with_create_table(mut self) -> Self {
+        self.mode = CommitMode::Create;
+        self
+    }
+
+    /// Replaces the definition of `base` with `creation` and sets the data files written as
+    /// its only data, in a single commit keeping the history of `base`.
+    ///
+    /// `table` must have the metadata of `base` after the replacement, so that the data
+    /// files are written with the replaced schema and partition spec.
+    pub fn with_replace_table(mut self, base: Table, creation: TableCreation) -> Self {
+        self.mode = CommitMode::Replace(Box::new((base, creation)));
+        self
+    }
+
     // Compute the plan properties for this execution plan
     fn compute_properties(schema: ArrowSchemaRef) -> PlanProperties {
         PlanProperties::new(
@@ -160,12 +191,15 @@ impl ExecutionPlan for IcebergCommitExec {
             )));
         }
 
-        Ok(Arc::new(IcebergCommitExec::new(
+        let mut commit_exec = IcebergCommitExec::new(
             self.table.clone(),
             self.catalog.clone(),
             children[0].clone(),
             self.schema.clone(),
-        )))
+        );
+        commit_exec.mode = self.mode.clone();
+
+        Ok(Arc::new(commit_exec))
     }
 
     fn execute(
@@ -181,6 +215,7 @@ impl ExecutionPlan for IcebergCommitExec {
         }
 
         let table = self.table.clone();
+        let mode = self.mode.clone();
         let input_plan = self.input.clone();
         let count_schema = Arc::clone(&self.count_schema);
 
@@ -241,18 +276,26 @@ impl ExecutionPlan for IcebergCommitExec {
             }
 
             // If no data files were collected, return an empty result
-            if data_files.is_empty() {
+            if data_files.is_empty() && matches!(mode, CommitMode::Append) {
                 return Ok(RecordBatch::new_empty(count_schema));
             }
 
             // Create a transaction and commit the data files
-            let tx = Transaction::new(&table);
-            let action = tx.fast_append().add_data_files(data_files);
+            let mut tx = match mode {
+                CommitMode::Append => Transaction::new(&table),
+                CommitMode::Create => Transaction::create_table(&table),
+                CommitMode::Replace(replace) => {
+                    let (base, creation) = *replace;
+                    Transaction::replace_table(&base, creation)
+                }
+            };
+            if !data_files.is_empty() {
+                let action = tx.fast_append().add_data_files(data_files);
+                tx = action.apply(tx).map_err(to_datafusion_error)?;
+            }
 
-            // Apply the action and commit the transaction
-            let _updated_table = action
-                .apply(tx)
-                .map_err(to_datafusion_error)?
+            // Commit the transaction
+            let _updated_table = tx
                 .commit(catalog.as_ref())
                 .await
                 .map_err(to_datafusion_error)?;
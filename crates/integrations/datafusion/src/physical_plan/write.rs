@@ -36,6 +36,7 @@ use datafusion::physical_plan::{
 };
 use futures::StreamExt;
 use iceberg::arrow::FieldMatchMode;
+use iceberg::encryption::EncryptionManager;
 use iceberg::spec::{DataFileFormat, serialize_data_file_to_json};
 use iceberg::table::Table;
 use iceberg::writer::base_writer::data_file_writer::DataFileWriterBuilder;
@@ -226,11 +227,19 @@ impl ExecutionPlan for IcebergWriteExec {
         }
 
         // Create data file writer builder
-        let parquet_file_writer_builder = ParquetWriterBuilder::new_with_match_mode(
+        let mut parquet_file_writer_builder = ParquetWriterBuilder::new_with_match_mode(
             WriterProperties::default(),
             self.table.metadata().current_schema().clone(),
             FieldMatchMode::Name,
         );
+        // Data files of encrypted tables are encrypted with keys of the table's KMS.
+        if let Some(encryption_manager) =
+            EncryptionManager::try_from_table(self.table.metadata(), self.table.file_io())
+                .map_err(to_datafusion_error)?
+        {
+            parquet_file_writer_builder =
+                parquet_file_writer_builder.with_encryption_manager(encryption_manager);
+        }
         let target_file_size = table_props.write_target_file_size_bytes;
 
         let file_io = self.table.file_io().clone();
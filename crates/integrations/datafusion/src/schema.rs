@@ -19,20 +19,29 @@ use std::any::Any;
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use dashmap::DashMap;
+use dashmap::{DashMap, DashSet};
 use datafusion::catalog::SchemaProvider;
-use datafusion::datasource::TableProvider;
+use datafusion::common::stats::Precision;
+use datafusion::datasource::{TableProvider, ViewTable};
 use datafusion::error::{DataFusionError, Result as DFResult};
-use datafusion::execution::TaskContext;
+use datafusion::physical_plan::coalesce_partitions::CoalescePartitionsExec;
+use datafusion::physical_plan::{ExecutionPlan, collect};
 use datafusion::prelude::SessionContext;
-use futures::StreamExt;
 use futures::future::try_join_all;
 use iceberg::arrow::arrow_schema_to_schema_auto_assign_ids;
 use iceberg::inspect::MetadataTableType;
+use iceberg::spec::TableMetadataBuilder;
+use iceberg::table::Table;
 use iceberg::{Catalog, Error, ErrorKind, NamespaceIdent, Result, TableCreation, TableIdent};
 
+use crate::physical_plan::commit::IcebergCommitExec;
+use crate::physical_plan::write::IcebergWriteExec;
 use crate::table::IcebergTableProvider;
 use crate::to_datafusion_error;
+use crate::view::{UnresolvedView, plan_view, view_creation};
+
+/// Namespace property holding the location under which views are created.
+const NAMESPACE_LOCATION_PROPERTY: &str = "location";
 
 /// Represents a [`SchemaProvider`] for the Iceberg [`Catalog`], managing
 /// access to table providers within a specific namespace.
@@ -47,6 +56,8 @@ pub(crate) struct IcebergSchemaProvider {
     /// [`TableProvider`] trait.
     /// Wrapped in Arc to allow sharing across async boundaries in register_table.
     tables: Arc<DashMap<String, Arc<IcebergTableProvider>>>,
+    /// Names of the views in the namespace. Views are planned when they are accessed.
+    views: Arc<DashSet<String>>,
 }
 
 impl IcebergSchemaProvider {
@@ -85,12 +96,179 @@ impl IcebergSchemaProvider {
             tables.insert(name, Arc::new(provider));
         }
 
+        let views = match client.list_views(&namespace).await {
+            Ok(views) => views.iter().map(|view| view.name().to_string()).collect(),
+            // Catalogs without view support only expose tables
+            Err(e) if e.kind() == ErrorKind::FeatureUnsupported => DashSet::new(),
+            Err(e) => return Err(e),
+        };
+
         Ok(IcebergSchemaProvider {
             catalog: client,
             namespace,
             tables,
+            views: Arc::new(views),
         })
     }
+
+    /// Whether `name` is a table of the namespace, rather than a view or metadata table.
+    pub(crate) fn is_table(&self, name: &str) -> bool {
+        self.tables.contains_key(name)
+    }
+
+    /// Creates the table `name` with the schema and rows of `input`, in a single commit so that
+    /// no table is left behind if writing fails.
+    ///
+    /// Fails with a planning error, before `input` is executed, if the catalog can't stage
+    /// the creation of tables.
+    pub(crate) async fn create_table_as_select(
+        &self,
+        name: &str,
+        input: Arc<dyn ExecutionPlan>,
+    ) -> DFResult<()> {
+        let iceberg_schema = arrow_schema_to_schema_auto_assign_ids(input.schema().as_ref())
+            .map_err(to_datafusion_error)?;
+        let table_creation = TableCreation::builder()
+            .name(name.to_string())
+            .schema(iceberg_schema)
+            .build();
+
+        let staged = match self
+            .catalog
+            .stage_create_table(&self.namespace, table_creation)
+            .await
+        {
+            Ok(staged) => staged,
+            Err(e) if e.kind() == ErrorKind::FeatureUnsupported => {
+                return Err(DataFusionError::Plan(format!(
+                    "Cannot create table {name} from a query: the catalog does not support \
+                     staged table creation, which CREATE TABLE AS SELECT requires"
+                )));
+            }
+            Err(e) => return Err(to_datafusion_error(e)),
+        };
+        create_table_as_select(self.catalog.clone(), staged, input).await?;
+
+        let table_provider =
+            IcebergTableProvider::try_new(self.catalog.clone(), self.namespace.clone(), name)
+                .await
+                .map_err(to_datafusion_error)?;
+        self.tables
+            .insert(name.to_string(), Arc::new(table_provider));
+        Ok(())
+    }
+
+    /// Replaces the definition of the table `name` with the schema of `input` and its data
+    /// with the rows of `input`, in a single commit keeping the history of the table.
+    pub(crate) async fn replace_table(
+        &self,
+        name: &str,
+        input: Arc<dyn ExecutionPlan>,
+    ) -> DFResult<()> {
+        let base = self
+            .catalog
+            .load_table(&TableIdent::new(self.namespace.clone(), name.to_string()))
+            .await
+            .map_err(to_datafusion_error)?;
+        let iceberg_schema = arrow_schema_to_schema_auto_assign_ids(input.schema().as_ref())
+            .map_err(to_datafusion_error)?;
+        let table_creation = TableCreation::builder()
+            .name(name.to_string())
+            .schema(iceberg_schema)
+            .build();
+
+        replace_table_as_select(self.catalog.clone(), base, table_creation, input).await?;
+
+        let table_provider =
+            IcebergTableProvider::try_new(self.catalog.clone(), self.namespace.clone(), name)
+                .await
+                .map_err(to_datafusion_error)?;
+        self.tables
+            .insert(name.to_string(), Arc::new(table_provider));
+        Ok(())
+    }
+
+    /// Persists a view defined in DataFusion, e.g. by `CREATE VIEW`, as an Iceberg view.
+    ///
+    /// The view is stored under the `location` of the namespace.
+    fn register_view(
+        &self,
+        name: String,
+        table: Arc<dyn TableProvider>,
+    ) -> DFResult<Option<Arc<dyn TableProvider>>> {
+        let catalog = self.catalog.clone();
+        let namespace = self.namespace.clone();
+        let views = self.views.clone();
+
+        // Use tokio's spawn_blocking to handle the async work on a blocking thread pool
+        let result = tokio::task::spawn_blocking(move || {
+            let rt = tokio::runtime::Handle::current();
+            rt.block_on(async move {
+                let view_table = table
+                    .as_any()
+                    .downcast_ref::<ViewTable>()
+                    .expect("Table must be a view");
+
+                let namespace_location = catalog
+                    .get_namespace(&namespace)
+                    .await
+                    .map_err(to_datafusion_error)?
+                    .properties()
+                    .get(NAMESPACE_LOCATION_PROPERTY)
+                    .cloned()
+                    .ok_or_else(|| {
+                        DataFusionError::Plan(format!(
+                            "Cannot create view {name}: namespace {namespace} has no {NAMESPACE_LOCATION_PROPERTY} property"
+                        ))
+                    })?;
+                let location = format!("{}/{}", namespace_location.trim_end_matches('/'), name);
+
+                let view_creation = view_creation(name.clone(), &namespace, location, view_table)
+                    .map_err(to_datafusion_error)?;
+                catalog
+                    .create_view(&namespace, view_creation)
+                    .await
+                    .map_err(to_datafusion_error)?;
+
+                views.insert(name);
+
+                Ok(None)
+            })
+        });
+
+        futures::executor::block_on(result).map_err(|e| {
+            DataFusionError::Execution(format!("Failed to create Iceberg view: {e}"))
+        })?
+    }
+
+    /// Drops an Iceberg view, e.g. for `DROP VIEW`.
+    ///
+    /// Returns `None` rather than the dropped view, which would have to be planned.
+    fn deregister_view(&self, name: &str) -> DFResult<Option<Arc<dyn TableProvider>>> {
+        let catalog = self.catalog.clone();
+        let namespace = self.namespace.clone();
+        let views = self.views.clone();
+        let view_name = name.to_string();
+
+        // Use tokio's spawn_blocking to handle the async work on a blocking thread pool
+        let result = tokio::task::spawn_blocking(move || {
+            let rt = tokio::runtime::Handle::current();
+            rt.block_on(async move {
+                // The view is not planned, so that views whose query no longer resolves
+                // can be dropped
+                catalog
+                    .drop_view(&TableIdent::new(namespace, view_name.clone()))
+                    .await
+                    .map_err(to_datafusion_error)?;
+                views.remove(&view_name);
+
+                Ok(None)
+            })
+        });
+
+        futures::executor::block_on(result)
+            .map_err(|e| DataFusionError::Execution(format!("Failed to drop Iceberg view: {e}")))?
+    }
 }
 
 #[async_trait]
@@ -112,6 +290,7 @@ impl SchemaProvider for IcebergSchemaProvider {
                         }),
                     )
             })
+            .chain(self.views.iter().map(|view| view.key().clone()))
             .collect()
     }
 
@@ -120,7 +299,7 @@ impl SchemaProvider for IcebergSchemaProvider {
             self.tables.contains_key(table_name)
                 && MetadataTableType::try_from(metadata_table_name).is_ok()
         } else {
-            self.tables.contains_key(name)
+            self.tables.contains_key(name) || self.views.contains(name)
         }
     }
 
@@ -139,6 +318,21 @@ impl SchemaProvider for IcebergSchemaProvider {
             }
         }
 
+        if self.views.contains(name) {
+            let view = self
+                .catalog
+                .load_view(&TableIdent::new(self.namespace.clone(), name.to_string()))
+                .await
+                .map_err(to_datafusion_error)?;
+            return match plan_view(self.catalog.clone(), &view).await {
+                Ok(view_table) => Ok(Some(Arc::new(view_table))),
+                // Views that no longer resolve are still exposed, so that they can be dropped
+                Err(e) => Ok(Some(Arc::new(
+                    UnresolvedView::try_new(&view, e).map_err(to_datafusion_error)?,
+                ))),
+            };
+        }
+
         Ok(self
             .tables
             .get(name)
@@ -157,6 +351,10 @@ impl SchemaProvider for IcebergSchemaProvider {
             )));
         }
 
+        if table.as_any().is::<ViewTable>() {
+            return self.register_view(name, table);
+        }
+
         // Convert DataFusion schema to Iceberg schema
         // DataFusion schemas don't have field IDs, so we use the function that assigns them automatically
         let df_schema = table.schema();
@@ -179,15 +377,34 @@ impl SchemaProvider for IcebergSchemaProvider {
             // Create a new runtime handle to execute the async work
             let rt = tokio::runtime::Handle::current();
             rt.block_on(async move {
-                // Verify the input table is empty - CREATE TABLE only accepts schema definition
-                ensure_table_is_empty(&table)
-                    .await
-                    .map_err(to_datafusion_error)?;
-
-                catalog
-                    .create_table(&namespace, table_creation)
-                    .await
-                    .map_err(to_datafusion_error)?;
+                let session_ctx = SessionContext::new();
+                let input = table.scan(&session_ctx.state(), None, &[], None).await?;
+
+                if !produces_no_rows(&input)? {
+                    // CREATE TABLE AS SELECT: stage the table so that it's only created
+                    // once its data has been written.
+                    let staged = catalog
+                        .stage_create_table(&namespace, table_creation)
+                        .await
+                        .map_err(|e| {
+                            if e.kind() == ErrorKind::FeatureUnsupported {
+                                to_datafusion_error(Error::new(
+                                    ErrorKind::FeatureUnsupported,
+                                    "register_table does not support tables with data. \
+                                     Creating a table from a query requires a catalog \
+                                     supporting staged table creation.",
+                                ))
+                            } else {
+                                to_datafusion_error(e)
+                            }
+                        })?;
+                    create_table_as_select(catalog.clone(), staged, input).await?;
+                } else {
+                    catalog
+                        .create_table(&namespace, table_creation)
+                        .await
+                        .map_err(to_datafusion_error)?;
+                }
 
                 // Create a new table provider using the catalog reference
                 let table_provider = IcebergTableProvider::try_new(
@@ -218,6 +435,10 @@ impl SchemaProvider for IcebergSchemaProvider {
             return Ok(None);
         }
 
+        if self.views.contains(name) {
+            return self.deregister_view(name);
+        }
+
         let catalog = self.catalog.clone();
         let namespace = self.namespace.clone();
         let tables = self.tables.clone();
@@ -249,36 +470,71 @@ impl SchemaProvider for IcebergSchemaProvider {
     }
 }
 
-/// Verifies that a table provider contains no data by scanning with LIMIT 1.
-/// Returns an error if the table has any rows.
-async fn ensure_table_is_empty(table: &Arc<dyn TableProvider>) -> Result<()> {
-    let session_ctx = SessionContext::new();
-    let exec_plan = table
-        .scan(&session_ctx.state(), None, &[], Some(1))
-        .await
-        .map_err(|e| Error::new(ErrorKind::Unexpected, format!("Failed to scan table: {e}")))?;
-
-    let task_ctx = Arc::new(TaskContext::default());
-    let stream = exec_plan.execute(0, task_ctx).map_err(|e| {
-        Error::new(
-            ErrorKind::Unexpected,
-            format!("Failed to execute scan: {e}"),
+/// Whether `plan` is known from its statistics to produce no rows, without executing it.
+pub(crate) fn produces_no_rows(plan: &Arc<dyn ExecutionPlan>) -> DFResult<bool> {
+    Ok(plan.partition_statistics(None)?.num_rows == Precision::Exact(0))
+}
+
+/// Plans writing the rows of `input` to `table`, as the input of an [`IcebergCommitExec`].
+fn write_plan(table: Table, input: Arc<dyn ExecutionPlan>) -> Arc<dyn ExecutionPlan> {
+    let schema = input.schema();
+    Arc::new(CoalescePartitionsExec::new(Arc::new(
+        IcebergWriteExec::new(table, input, schema),
+    )))
+}
+
+/// Writes the rows of `input` to the staged table, and creates the table with them in a
+/// single commit, so that no table is left behind if writing fails.
+async fn create_table_as_select(
+    catalog: Arc<dyn Catalog>,
+    staged: Table,
+    input: Arc<dyn ExecutionPlan>,
+) -> DFResult<()> {
+    let schema = input.schema();
+    let commit_plan = Arc::new(
+        IcebergCommitExec::new(staged.clone(), catalog, write_plan(staged, input), schema)
+            .with_create_table(),
+    );
+
+    collect(commit_plan, SessionContext::new().task_ctx()).await?;
+    Ok(())
+}
+
+/// Replaces the definition of `base` with `creation` and its data with the rows of `input`
+/// in a single commit, keeping the history of `base`.
+async fn replace_table_as_select(
+    catalog: Arc<dyn Catalog>,
+    base: Table,
+    creation: TableCreation,
+    input: Arc<dyn ExecutionPlan>,
+) -> DFResult<()> {
+    // The data files are written with the schema and partition spec of the replacement
+    let replacement = TableMetadataBuilder::replace_from_table_creation(
+        base.metadata().clone(),
+        None,
+        creation.clone(),
+    )
+    .and_then(|builder| builder.build())
+    .map_err(to_datafusion_error)?;
+    let replaced = Table::builder()
+        .file_io(base.file_io().clone())
+        .identifier(base.identifier().clone())
+        .metadata(replacement.metadata)
+        .build()
+        .map_err(to_datafusion_error)?;
+
+    let schema = input.schema();
+    let commit_plan = Arc::new(
+        IcebergCommitExec::new(
+            replaced.clone(),
+            catalog,
+            write_plan(replaced, input),
+            schema,
         )
-    })?;
-
-    let batches: Vec<_> = stream.collect().await;
-    let has_data = batches
-        .into_iter()
-        .filter_map(|r| r.ok())
-        .any(|batch| batch.num_rows() > 0);
-
-    if has_data {
-        return Err(Error::new(
-            ErrorKind::Unexpected,
-            "register_table does not support tables with data.",
-        ));
-    }
+        .with_replace_table(base, creation),
+    );
 
+    collect(commit_plan, SessionContext::new().task_ctx()).await?;
     Ok(())
 }
 
@@ -442,4 +698,87 @@ mod tests {
         assert!(result.is_ok());
         assert!(result.unwrap().is_none());
     }
+
+    fn mem_table(ids: Vec<i32>) -> Arc<MemTable> {
+        let arrow_schema = Arc::new(ArrowSchema::new(vec![Field::new(
+            "id",
+            DataType::Int32,
+            false,
+        )]));
+        let batch =
+            RecordBatch::try_new(arrow_schema.clone(), vec![Arc::new(Int32Array::from(ids))])
+                .unwrap();
+
+        Arc::new(MemTable::try_new(arrow_schema, vec![vec![batch]]).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_replace_table_keeps_history() {
+        let (schema_provider, _temp_dir) = create_test_schema_provider().await;
+        let table_ident = TableIdent::new(schema_provider.namespace.clone(), "t".to_string());
+
+        schema_provider
+            .register_table("t".to_string(), mem_table(vec![]))
+            .unwrap();
+        let created = schema_provider
+            .catalog
+            .load_table(&table_ident)
+            .await
+            .unwrap();
+
+        let state = SessionContext::new().state();
+        for ids in [vec![1, 2], vec![3]] {
+            let input = mem_table(ids).scan(&state, None, &[], None).await.unwrap();
+            schema_provider.replace_table("t", input).await.unwrap();
+        }
+
+        let replaced = schema_provider
+            .catalog
+            .load_table(&table_ident)
+            .await
+            .unwrap();
+        assert_eq!(replaced.metadata().uuid(), created.metadata().uuid());
+        assert_eq!(replaced.metadata().snapshots().count(), 2);
+        let current_snapshot = replaced.metadata().current_snapshot().unwrap();
+        assert_eq!(
+            current_snapshot.summary().additional_properties["total-records"],
+            "1"
+        );
+        assert!(schema_provider.is_table("t"));
+    }
+
+    #[tokio::test]
+    async fn test_register_table_after_drop_creates_it() {
+        let (schema_provider, _temp_dir) = create_test_schema_provider().await;
+        let table_ident = TableIdent::new(schema_provider.namespace.clone(), "t".to_string());
+
+        schema_provider
+            .register_table("t".to_string(), mem_table(vec![]))
+            .unwrap();
+        let created = schema_provider
+            .catalog
+            .load_table(&table_ident)
+            .await
+            .unwrap();
+
+        let input = mem_table(vec![1])
+            .scan(&SessionContext::new().state(), None, &[], None)
+            .await
+            .unwrap();
+        schema_provider.replace_table("t", input).await.unwrap();
+
+        // A table registered after the table is dropped doesn't keep its history
+        schema_provider.deregister_table("t").unwrap();
+        schema_provider
+            .register_table("t".to_string(), mem_table(vec![]))
+            .unwrap();
+
+        let recreated = schema_provider
+            .catalog
+            .load_table(&table_ident)
+            .await
+            .unwrap();
+        assert_ne!(recreated.metadata().uuid(), created.metadata().uuid());
+        assert_eq!(recreated.metadata().snapshots().count(), 0);
+    }
 }
@@ -0,0 +1,80 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use datafusion::catalog::SchemaProvider;
+use datafusion::dataframe::DataFrame;
+use datafusion::error::Result as DFResult;
+use datafusion::logical_expr::{CreateMemoryTable, DdlStatement, LogicalPlan, LogicalPlanBuilder};
+use datafusion::prelude::SessionContext;
+
+use crate::schema::{IcebergSchemaProvider, produces_no_rows};
+
+/// Runs a SQL statement like [`SessionContext::sql`], creating Iceberg tables from queries and
+/// replacing them in place.
+///
+/// DataFusion runs `CREATE TABLE ... AS SELECT` by executing the query and then registering a
+/// table holding its rows, and `CREATE OR REPLACE TABLE` by dropping the table and creating a
+/// new one. Neither statement can be hooked into through [`SessionContext::sql`], so Iceberg
+/// tables must be created from queries and replaced with this function instead:
+///
+/// - `CREATE TABLE ... AS SELECT` stages the table before executing the query, failing with a
+///   planning error if the catalog can't stage tables, and creates it with the rows of the
+///   query in a single commit.
+/// - `CREATE OR REPLACE TABLE` of an existing table replaces its schema and data in a single
+///   commit, keeping its snapshots. [`SessionContext::sql`] loses the history of the table.
+///
+/// Other statements, including `CREATE TABLE` statements whose query is known to produce no
+/// rows, are run by DataFusion.
+pub async fn execute_sql(ctx: &SessionContext, sql: &str) -> DFResult<DataFrame> {
+    let plan = ctx.state().create_logical_plan(sql).await?;
+
+    if let LogicalPlan::Ddl(DdlStatement::CreateMemoryTable(CreateMemoryTable {
+        name,
+        input,
+        if_not_exists,
+        or_replace,
+        temporary: false,
+        ..
+    })) = &plan
+    {
+        let schema = ctx.state().schema_for_ref(name.clone())?;
+        if let Some(schema) = schema.as_any().downcast_ref::<IcebergSchemaProvider>() {
+            let exists = schema.table_exist(name.table());
+            if exists && *or_replace && !*if_not_exists && schema.is_table(name.table()) {
+                let input = ctx.state().create_physical_plan(input).await?;
+                schema.replace_table(name.table(), input).await?;
+                return empty_data_frame(ctx);
+            }
+
+            if !exists {
+                let input = ctx.state().create_physical_plan(input).await?;
+                if !produces_no_rows(&input)? {
+                    schema.create_table_as_select(name.table(), input).await?;
+                    return empty_data_frame(ctx);
+                }
+            }
+        }
+    }
+
+    ctx.execute_logical_plan(plan).await
+}
+
+/// Returns a [`DataFrame`] with no rows, the result of DDL statements in DataFusion.
+fn empty_data_frame(ctx: &SessionContext) -> DFResult<DataFrame> {
+    let plan = LogicalPlanBuilder::empty(false).build()?;
+    Ok(DataFrame::new(ctx.state(), plan))
+}
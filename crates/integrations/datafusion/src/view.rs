@@ -0,0 +1,389 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Conversion between Iceberg views and DataFusion [`ViewTable`]s.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use datafusion::arrow::datatypes::SchemaRef as ArrowSchemaRef;
+use datafusion::catalog::{
+    CatalogProvider, MemoryCatalogProvider, MemorySchemaProvider, SchemaProvider, Session,
+};
+use datafusion::common::tree_node::TreeNodeRecursion;
+use datafusion::datasource::{TableProvider, ViewTable};
+use datafusion::error::{DataFusionError, Result as DFResult};
+use datafusion::logical_expr::{Expr, LogicalPlan, TableType};
+use datafusion::physical_plan::ExecutionPlan;
+use datafusion::prelude::{SessionConfig, SessionContext};
+use datafusion::sql::sqlparser::ast::Statement;
+use datafusion::sql::sqlparser::dialect::GenericDialect;
+use datafusion::sql::sqlparser::parser::Parser;
+use iceberg::arrow::{arrow_schema_to_schema_auto_assign_ids, schema_to_arrow_schema};
+use iceberg::inspect::MetadataTableType;
+use iceberg::spec::{SqlViewRepresentation, ViewRepresentation, ViewRepresentations};
+use iceberg::view::View;
+use iceberg::{Catalog, Error, ErrorKind, NamespaceIdent, Result, TableIdent, ViewCreation};
+
+use crate::from_datafusion_error;
+use crate::table::IcebergTableProvider;
+
+/// Dialect of the SQL representations written for views created through DataFusion.
+const DATAFUSION_DIALECT: &str = "datafusion";
+
+/// Dialects DataFusion can plan, in order of preference.
+const SUPPORTED_DIALECTS: [&str; 3] = [DATAFUSION_DIALECT, "spark", "generic"];
+
+/// Plans the current version of an Iceberg view as a DataFusion [`ViewTable`].
+///
+/// The query is planned in a session where the Iceberg catalog is registered under the
+/// view's default catalog, or DataFusion's default catalog name if there is none, and the
+/// view's default namespace is the default schema. Only the tables and views referenced by
+/// the query are loaded from the catalog.
+pub(crate) async fn plan_view(catalog: Arc<dyn Catalog>, view: &View) -> Result<ViewTable> {
+    plan_nested_view(catalog, view, &[]).await
+}
+
+/// Plans a view referenced, directly or through other views, by the views of `planning`.
+///
+/// Fails if the view is one of `planning`, as the views would reference each other forever.
+async fn plan_nested_view(
+    catalog: Arc<dyn Catalog>,
+    view: &View,
+    planning: &[TableIdent],
+) -> Result<ViewTable> {
+    if planning.contains(view.identifier()) {
+        let cycle = planning
+            .iter()
+            .skip_while(|ident| *ident != view.identifier())
+            .chain([view.identifier()])
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        return Err(Error::new(
+            ErrorKind::DataInvalid,
+            format!("Views reference each other: {}", cycle.join(" -> ")),
+        ));
+    }
+    let planning = planning
+        .iter()
+        .chain([view.identifier()])
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let version = view.metadata().current_version();
+    let sql = supported_sql(version.representations()).ok_or_else(|| {
+        Error::new(
+            ErrorKind::FeatureUnsupported,
+            format!(
+                "View {} has no SQL representation in a dialect supported by DataFusion: {}",
+                view.identifier(),
+                SUPPORTED_DIALECTS.join(", ")
+            ),
+        )
+    })?;
+
+    let config = SessionConfig::new().with_create_default_catalog_and_schema(false);
+    let catalog_name = version
+        .default_catalog()
+        .cloned()
+        .unwrap_or_else(|| config.options().catalog.default_catalog.clone());
+    let default_schema = version.default_namespace().join(".");
+    let config = config.with_default_catalog_and_schema(&catalog_name, &default_schema);
+
+    let ctx = SessionContext::new_with_config(config);
+    let state = ctx.state();
+    let dialect = state.config().options().sql_parser.dialect;
+    let statement = state
+        .sql_to_statement(&sql.sql, &dialect)
+        .map_err(from_datafusion_error)?;
+    let references = state
+        .resolve_table_references(&statement)
+        .map_err(from_datafusion_error)?;
+
+    let catalog_provider = MemoryCatalogProvider::new();
+    for reference in references {
+        let reference = reference.resolve(&catalog_name, &default_schema);
+        // Tables of other catalogs are left unresolved, and fail planning
+        if *reference.catalog != catalog_name {
+            continue;
+        }
+
+        let Some(provider) = referenced_table_provider(
+            catalog.clone(),
+            &reference.schema,
+            &reference.table,
+            &planning,
+        )
+        .await?
+        else {
+            continue;
+        };
+
+        let schema = match catalog_provider.schema(&reference.schema) {
+            Some(schema) => schema,
+            None => {
+                let schema = Arc::new(MemorySchemaProvider::new()) as Arc<dyn SchemaProvider>;
+                catalog_provider
+                    .register_schema(&reference.schema, schema.clone())
+                    .map_err(from_datafusion_error)?;
+                schema
+            }
+        };
+        schema
+            .register_table(reference.table.to_string(), provider)
+            .map_err(from_datafusion_error)?;
+    }
+    ctx.register_catalog(catalog_name, Arc::new(catalog_provider));
+
+    let plan = ctx
+        .state()
+        .statement_to_plan(statement)
+        .await
+        .map_err(from_datafusion_error)?;
+
+    Ok(ViewTable::new(plan, Some(sql.sql.clone())))
+}
+
+/// Loads the provider of a table, metadata table or view referenced by a view's query.
+///
+/// Returns `None` if the catalog has no such table or view. `planning` are the views whose
+/// queries reference the table, directly or through other views.
+async fn referenced_table_provider(
+    catalog: Arc<dyn Catalog>,
+    schema: &str,
+    table: &str,
+    planning: &[TableIdent],
+) -> Result<Option<Arc<dyn TableProvider>>> {
+    let namespace = NamespaceIdent::from_strs(schema.split('.'))?;
+
+    if let Some((table_name, metadata_table_name)) = table.split_once('$') {
+        let Ok(metadata_table_type) = MetadataTableType::try_from(metadata_table_name) else {
+            return Ok(None);
+        };
+        let table_ident = TableIdent::new(namespace.clone(), table_name.to_string());
+        if !catalog.table_exists(&table_ident).await? {
+            return Ok(None);
+        }
+        let provider = IcebergTableProvider::try_new(catalog, namespace, table_name).await?;
+        return Ok(Some(Arc::new(
+            provider.metadata_table(metadata_table_type).await?,
+        )));
+    }
+
+    let ident = TableIdent::new(namespace.clone(), table.to_string());
+    if catalog.table_exists(&ident).await? {
+        let provider = IcebergTableProvider::try_new(catalog, namespace, table).await?;
+        return Ok(Some(Arc::new(provider)));
+    }
+
+    let view_exists = match catalog.view_exists(&ident).await {
+        Ok(exists) => exists,
+        // Catalogs without view support only expose tables
+        Err(e) if e.kind() == ErrorKind::FeatureUnsupported => false,
+        Err(e) => return Err(e),
+    };
+    if view_exists {
+        let view = catalog.load_view(&ident).await?;
+        let view_table = Box::pin(plan_nested_view(catalog, &view, planning)).await?;
+        return Ok(Some(Arc::new(view_table)));
+    }
+
+    Ok(None)
+}
+
+/// A view whose query cannot be planned, e.g. because a table it references was dropped.
+///
+/// It exposes the schema stored in the view's metadata, so that the view can still be
+/// listed and dropped, and fails with the planning error when it is scanned.
+#[derive(Debug)]
+pub(crate) struct UnresolvedView {
+    identifier: TableIdent,
+    schema: ArrowSchemaRef,
+    error: String,
+}
+
+impl UnresolvedView {
+    pub(crate) fn try_new(view: &View, error: Error) -> Result<Self> {
+        Ok(UnresolvedView {
+            identifier: view.identifier().clone(),
+            schema: Arc::new(schema_to_arrow_schema(view.metadata().current_schema())?),
+            error: error.to_string(),
+        })
+    }
+}
+
+#[async_trait]
+impl TableProvider for UnresolvedView {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> ArrowSchemaRef {
+        self.schema.clone()
+    }
+
+    fn table_type(&self) -> TableType {
+        TableType::View
+    }
+
+    async fn scan(
+        &self,
+        _state: &dyn Session,
+        _projection: Option<&Vec<usize>>,
+        _filters: &[Expr],
+        _limit: Option<usize>,
+    ) -> DFResult<Arc<dyn ExecutionPlan>> {
+        Err(DataFusionError::Plan(format!(
+            "Failed to plan view {}: {}",
+            self.identifier, self.error
+        )))
+    }
+}
+
+/// Builds the creation of an Iceberg view persisting a view defined in DataFusion.
+///
+/// Table references in the query are resolved against `namespace`, and against the catalog
+/// referenced by the query, if any.
+pub(crate) fn view_creation(
+    name: String,
+    namespace: &NamespaceIdent,
+    location: String,
+    view: &ViewTable,
+) -> Result<ViewCreation> {
+    let definition = view.definition().ok_or_else(|| {
+        Error::new(
+            ErrorKind::DataInvalid,
+            format!("Cannot create view {name} without a SQL definition"),
+        )
+    })?;
+    let sql = view_query(definition)?;
+    let schema = arrow_schema_to_schema_auto_assign_ids(view.schema().as_ref())?;
+
+    Ok(ViewCreation::builder()
+        .name(name)
+        .location(location)
+        .representations(ViewRepresentations::from_iter([SqlViewRepresentation {
+            sql,
+            dialect: DATAFUSION_DIALECT.to_string(),
+        }
+        .into()]))
+        .schema(schema)
+        .default_namespace(namespace.clone())
+        .default_catalog(referenced_catalog(view.logical_plan())?)
+        .summary(HashMap::from([
+            ("engine-name".to_string(), "DataFusion".to_string()),
+            (
+                "engine-version".to_string(),
+                datafusion::DATAFUSION_VERSION.to_string(),
+            ),
+        ]))
+        .build())
+}
+
+/// Returns the representation of the first supported dialect.
+fn supported_sql(representations: &ViewRepresentations) -> Option<&SqlViewRepresentation> {
+    SUPPORTED_DIALECTS.iter().find_map(|dialect| {
+        representations.iter().find_map(|repr| match repr {
+            ViewRepresentation::Sql(sql) if sql.dialect.eq_ignore_ascii_case(dialect) => Some(sql),
+            _ => None,
+        })
+    })
+}
+
+/// Extracts the query from the definition of a [`ViewTable`], which DataFusion sets to the
+/// `CREATE VIEW` statement.
+fn view_query(definition: &str) -> Result<String> {
+    let statements = Parser::parse_sql(&GenericDialect {}, definition).map_err(|e| {
+        Error::new(
+            ErrorKind::DataInvalid,
+            format!("Failed to parse view definition: {definition}"),
+        )
+        .with_source(e)
+    })?;
+
+    match statements.as_slice() {
+        [Statement::CreateView { query, .. }] => Ok(query.to_string()),
+        [Statement::Query(query)] => Ok(query.to_string()),
+        _ => Err(Error::new(
+            ErrorKind::DataInvalid,
+            format!("View definition is not a single query: {definition}"),
+        )),
+    }
+}
+
+/// Returns the catalog of the first table referenced with a catalog-qualified name.
+fn referenced_catalog(plan: &LogicalPlan) -> Result<Option<String>> {
+    let mut catalog = None;
+    plan.apply_with_subqueries(|node| {
+        if let LogicalPlan::TableScan(scan) = node
+            && let Some(name) = scan.table_name.catalog()
+        {
+            catalog = Some(name.to_string());
+            return Ok(TreeNodeRecursion::Stop);
+        }
+        Ok(TreeNodeRecursion::Continue)
+    })
+    .map_err(from_datafusion_error)?;
+
+    Ok(catalog)
+}
+
+#[cfg(test)]
+mod tests {
+    use iceberg::spec::{SqlViewRepresentation, ViewRepresentations};
+
+    use super::{supported_sql, view_query};
+
+    fn representations(dialects: &[&str]) -> ViewRepresentations {
+        ViewRepresentations::from_iter(dialects.iter().map(|dialect| {
+            SqlViewRepresentation {
+                sql: format!("SELECT '{dialect}'"),
+                dialect: dialect.to_string(),
+            }
+            .into()
+        }))
+    }
+
+    #[test]
+    fn test_supported_sql_prefers_datafusion() {
+        let reprs = representations(&["trino", "spark", "DataFusion"]);
+        assert_eq!(supported_sql(&reprs).unwrap().dialect, "DataFusion");
+
+        let reprs = representations(&["trino", "generic", "spark"]);
+        assert_eq!(supported_sql(&reprs).unwrap().dialect, "spark");
+
+        let reprs = representations(&["trino"]);
+        assert!(supported_sql(&reprs).is_none());
+    }
+
+    #[test]
+    fn test_view_query() {
+        assert_eq!(
+            view_query("CREATE VIEW catalog.ns.v AS SELECT a, b FROM catalog.ns.t WHERE a > 1")
+                .unwrap(),
+            "SELECT a, b FROM catalog.ns.t WHERE a > 1"
+        );
+        assert_eq!(
+            view_query("CREATE OR REPLACE VIEW v AS SELECT 1").unwrap(),
+            "SELECT 1"
+        );
+        assert_eq!(view_query("SELECT 1").unwrap(), "SELECT 1");
+        assert!(view_query("DROP VIEW v").is_err());
+    }
+}
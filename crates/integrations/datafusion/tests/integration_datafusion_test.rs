@@ -23,19 +23,25 @@ use std::vec;
 
 use datafusion::arrow::array::{Array, StringArray, UInt64Array};
 use datafusion::arrow::datatypes::{DataType, Field, Schema as ArrowSchema};
+use datafusion::error::DataFusionError;
 use datafusion::execution::context::SessionContext;
 use datafusion::parquet::arrow::PARQUET_FIELD_ID_META_KEY;
 use expect_test::expect;
+use futures::TryStreamExt;
+use iceberg::encryption::InMemoryKeyManagementClient;
 use iceberg::io::LocalFsStorageFactory;
 use iceberg::memory::{MEMORY_CATALOG_WAREHOUSE, MemoryCatalogBuilder};
 use iceberg::spec::{
-    NestedField, PrimitiveType, Schema, StructType, Transform, Type, UnboundPartitionSpec,
+    FormatVersion, NestedField, PrimitiveType, Schema, SqlViewRepresentation, StructType,
+    TableProperties, Transform, Type, UnboundPartitionSpec, ViewRepresentation,
+    ViewRepresentations,
 };
 use iceberg::test_utils::check_record_batches;
 use iceberg::{
     Catalog, CatalogBuilder, MemoryCatalog, NamespaceIdent, Result, TableCreation, TableIdent,
+    ViewCreation,
 };
-use iceberg_datafusion::IcebergCatalogProvider;
+use iceberg_datafusion::{IcebergCatalogProvider, execute_sql};
 use tempfile::TempDir;
 
 fn temp_path() -> String {
@@ -526,6 +532,106 @@ async fn test_insert_into() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_insert_into_encrypted_table() -> Result<()> {
+    let kms = Arc::new(InMemoryKeyManagementClient::new().with_master_key("master", vec![3; 32]));
+    let iceberg_catalog = MemoryCatalogBuilder::default()
+        .with_storage_factory(Arc::new(LocalFsStorageFactory))
+        .with_key_management_client(kms)
+        .load(
+            "memory",
+            HashMap::from([(MEMORY_CATALOG_WAREHOUSE.to_string(), temp_path())]),
+        )
+        .await?;
+    let namespace = NamespaceIdent::new("test_insert_into_encrypted".to_string());
+    set_test_namespace(&iceberg_catalog, &namespace).await?;
+
+    let mut creation = get_table_creation(temp_path(), "my_table", None)?;
+    creation.format_version = FormatVersion::V3;
+    creation.properties = HashMap::from([(
+        TableProperties::PROPERTY_ENCRYPTION_TABLE_KEY.to_string(),
+        "master".to_string(),
+    )]);
+    iceberg_catalog.create_table(&namespace, creation).await?;
+
+    let client = Arc::new(iceberg_catalog);
+    let catalog = Arc::new(IcebergCatalogProvider::try_new(client.clone()).await?);
+    let ctx = SessionContext::new();
+    ctx.register_catalog("catalog", catalog);
+
+    ctx.sql(
+        "INSERT INTO catalog.test_insert_into_encrypted.my_table VALUES (1, 'alan'), (2, 'turing')",
+    )
+    .await
+    .unwrap()
+    .collect()
+    .await
+    .unwrap();
+
+    // The data file is encrypted with a key recorded in its key metadata.
+    let table_ident = TableIdent::new(namespace.clone(), "my_table".to_string());
+    let table = client.load_table(&table_ident).await?;
+    let mut tasks = table
+        .scan()
+        .select_all()
+        .build()?
+        .plan_files()
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert_eq!(tasks.len(), 1);
+    let task = tasks.remove(0);
+    assert!(task.key_metadata.is_some());
+
+    let batches = ctx
+        .sql("SELECT foo2 FROM catalog.test_insert_into_encrypted.my_table ORDER BY foo1")
+        .await
+        .unwrap()
+        .collect()
+        .await
+        .unwrap();
+    let names = batches[0]
+        .column(0)
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .unwrap();
+    assert_eq!(names, &StringArray::from(vec!["alan", "turing"]));
+
+    // Parquet modular encryption with an encrypted footer
+    let data = std::fs::read(&task.data_file_path).unwrap();
+    assert_eq!(&data[data.len() - 4..], b"PARE");
+
+    // The table can't be read without the KMS.
+    let plain_catalog = MemoryCatalogBuilder::default()
+        .with_storage_factory(Arc::new(LocalFsStorageFactory))
+        .load(
+            "memory",
+            HashMap::from([(MEMORY_CATALOG_WAREHOUSE.to_string(), temp_path())]),
+        )
+        .await?;
+    plain_catalog
+        .create_namespace(&namespace, HashMap::new())
+        .await?;
+    plain_catalog
+        .register_table(&table_ident, table.metadata_location().unwrap().to_string())
+        .await?;
+    let ctx = SessionContext::new();
+    ctx.register_catalog(
+        "catalog",
+        Arc::new(IcebergCatalogProvider::try_new(Arc::new(plain_catalog)).await?),
+    );
+    let result = match ctx
+        .sql("SELECT foo2 FROM catalog.test_insert_into_encrypted.my_table")
+        .await
+    {
+        Ok(df) => df.collect().await.map(|_| ()),
+        Err(e) => Err(e),
+    };
+    assert!(result.is_err());
+
+    Ok(())
+}
+
 fn get_nested_struct_type() -> StructType {
     // Create a nested struct type with:
     // - address: STRUCT<street: STRING, city: STRING, zip: INT>
@@ -945,3 +1051,313 @@ async fn test_insert_into_partitioned() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_create_and_drop_view() -> Result<()> {
+    let iceberg_catalog = get_iceberg_catalog().await;
+    let namespace = NamespaceIdent::new("test_view".to_string());
+    iceberg_catalog
+        .create_namespace(
+            &namespace,
+            HashMap::from([("location".to_string(), temp_path())]),
+        )
+        .await?;
+
+    let creation = get_table_creation(temp_path(), "my_table", None)?;
+    iceberg_catalog.create_table(&namespace, creation).await?;
+
+    let client = Arc::new(iceberg_catalog);
+    let catalog = Arc::new(IcebergCatalogProvider::try_new(client.clone()).await?);
+
+    let ctx = SessionContext::new();
+    ctx.register_catalog("catalog", catalog);
+
+    ctx.sql("INSERT INTO catalog.test_view.my_table VALUES (1, 'alan'), (2, 'turing'), (3, 'ada')")
+        .await
+        .unwrap()
+        .collect()
+        .await
+        .unwrap();
+
+    ctx.sql(
+        "CREATE VIEW catalog.test_view.my_view AS \
+         SELECT foo1, foo2 FROM catalog.test_view.my_table WHERE foo1 > 1",
+    )
+    .await
+    .unwrap();
+
+    // The view is persisted in the Iceberg catalog
+    let view_ident = TableIdent::new(namespace.clone(), "my_view".to_string());
+    let view = client.load_view(&view_ident).await?;
+    let version = view.metadata().current_version();
+    assert_eq!(version.default_catalog(), Some(&"catalog".to_string()));
+    assert_eq!(version.default_namespace(), &namespace);
+    let ViewRepresentation::Sql(sql) = version.representations().iter().next().unwrap();
+    assert_eq!(sql.dialect, "datafusion");
+    assert_eq!(
+        sql.sql,
+        "SELECT foo1, foo2 FROM catalog.test_view.my_table WHERE foo1 > 1"
+    );
+
+    // The view is loaded from the catalog by a new provider
+    let ctx = SessionContext::new();
+    ctx.register_catalog(
+        "catalog",
+        Arc::new(IcebergCatalogProvider::try_new(client.clone()).await?),
+    );
+
+    let batches = ctx
+        .sql("SELECT * FROM catalog.test_view.my_view ORDER BY foo1")
+        .await
+        .unwrap()
+        .collect()
+        .await
+        .unwrap();
+    let names = batches[0]
+        .column_by_name("foo2")
+        .unwrap()
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .unwrap();
+    assert_eq!(names.iter().collect::<Vec<_>>(), vec![
+        Some("turing"),
+        Some("ada")
+    ]);
+
+    ctx.sql("DROP VIEW catalog.test_view.my_view")
+        .await
+        .unwrap();
+    assert!(!client.view_exists(&view_ident).await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_drop_view_of_dropped_table() -> Result<()> {
+    let iceberg_catalog = get_iceberg_catalog().await;
+    let namespace = NamespaceIdent::new("test_broken_view".to_string());
+    iceberg_catalog
+        .create_namespace(
+            &namespace,
+            HashMap::from([("location".to_string(), temp_path())]),
+        )
+        .await?;
+
+    let creation = get_table_creation(temp_path(), "my_table", None)?;
+    iceberg_catalog.create_table(&namespace, creation).await?;
+
+    let client = Arc::new(iceberg_catalog);
+    let ctx = SessionContext::new();
+    ctx.register_catalog(
+        "catalog",
+        Arc::new(IcebergCatalogProvider::try_new(client.clone()).await?),
+    );
+    ctx.sql(
+        "CREATE VIEW catalog.test_broken_view.my_view AS \
+         SELECT foo1 FROM catalog.test_broken_view.my_table",
+    )
+    .await
+    .unwrap();
+
+    // The view no longer resolves once its table is dropped
+    let table_ident = TableIdent::new(namespace.clone(), "my_table".to_string());
+    client.drop_table(&table_ident).await?;
+
+    let ctx = SessionContext::new();
+    ctx.register_catalog(
+        "catalog",
+        Arc::new(IcebergCatalogProvider::try_new(client.clone()).await?),
+    );
+    let err = ctx
+        .sql("SELECT * FROM catalog.test_broken_view.my_view")
+        .await
+        .unwrap()
+        .collect()
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("Failed to plan view"));
+
+    ctx.sql("DROP VIEW catalog.test_broken_view.my_view")
+        .await
+        .unwrap();
+    let view_ident = TableIdent::new(namespace, "my_view".to_string());
+    assert!(!client.view_exists(&view_ident).await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_view_cycle_fails_planning() -> Result<()> {
+    let iceberg_catalog = get_iceberg_catalog().await;
+    let namespace = NamespaceIdent::new("test_view_cycle".to_string());
+    iceberg_catalog
+        .create_namespace(
+            &namespace,
+            HashMap::from([("location".to_string(), temp_path())]),
+        )
+        .await?;
+
+    // Views can reference each other when created by other engines
+    let schema = Schema::builder()
+        .with_fields(vec![
+            NestedField::required(1, "foo1", Type::Primitive(PrimitiveType::Int)).into(),
+        ])
+        .build()?;
+    for (name, referenced) in [("v1", "v2"), ("v2", "v1")] {
+        let creation = ViewCreation::builder()
+            .name(name.to_string())
+            .location(temp_path())
+            .schema(schema.clone())
+            .representations(ViewRepresentations::from_iter([SqlViewRepresentation {
+                sql: format!("SELECT foo1 FROM {referenced}"),
+                dialect: "datafusion".to_string(),
+            }
+            .into()]))
+            .default_namespace(namespace.clone())
+            .build();
+        iceberg_catalog.create_view(&namespace, creation).await?;
+    }
+
+    let ctx = SessionContext::new();
+    ctx.register_catalog(
+        "catalog",
+        Arc::new(IcebergCatalogProvider::try_new(Arc::new(iceberg_catalog)).await?),
+    );
+    let err = ctx
+        .sql("SELECT * FROM catalog.test_view_cycle.v1")
+        .await
+        .unwrap()
+        .collect()
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("Failed to plan view"));
+    assert!(err.to_string().contains(
+        "Views reference each other: test_view_cycle.v1 -> test_view_cycle.v2 -> test_view_cycle.v1"
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_table_as_select_requires_staged_creation() -> Result<()> {
+    let iceberg_catalog = get_iceberg_catalog().await;
+    let namespace = NamespaceIdent::new("test_ctas".to_string());
+    set_test_namespace(&iceberg_catalog, &namespace).await?;
+
+    let client = Arc::new(iceberg_catalog);
+    let ctx = SessionContext::new();
+    ctx.register_catalog(
+        "catalog",
+        Arc::new(IcebergCatalogProvider::try_new(client.clone()).await?),
+    );
+
+    // The memory catalog can't stage tables, so the query is rejected before it runs
+    let err = execute_sql(&ctx, "CREATE TABLE catalog.test_ctas.t AS VALUES (1), (2)")
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, DataFusionError::Plan(_)),
+        "Expected a planning error, got: {err}"
+    );
+    assert!(
+        err.to_string()
+            .contains("the catalog does not support staged table creation"),
+        "Expected error about staged table creation, got: {err}"
+    );
+    let table_ident = TableIdent::new(namespace, "t".to_string());
+    assert!(!client.table_exists(&table_ident).await?);
+
+    // Tables created from queries without rows don't need to be staged
+    execute_sql(
+        &ctx,
+        "CREATE TABLE catalog.test_ctas.t AS SELECT 1 AS id WHERE false",
+    )
+    .await
+    .unwrap()
+    .collect()
+    .await
+    .unwrap();
+    assert!(client.table_exists(&table_ident).await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_or_replace_table_keeps_history() -> Result<()> {
+    let iceberg_catalog = get_iceberg_catalog().await;
+    let namespace = NamespaceIdent::new("test_replace_table".to_string());
+    set_test_namespace(&iceberg_catalog, &namespace).await?;
+
+    let client = Arc::new(iceberg_catalog);
+    let ctx = SessionContext::new();
+    ctx.register_catalog(
+        "catalog",
+        Arc::new(IcebergCatalogProvider::try_new(client.clone()).await?),
+    );
+
+    ctx.sql("CREATE TABLE catalog.test_replace_table.t (id INT)")
+        .await
+        .unwrap();
+    let table_ident = TableIdent::new(namespace, "t".to_string());
+    let created = client.load_table(&table_ident).await?;
+
+    for query in [
+        "CREATE OR REPLACE TABLE catalog.test_replace_table.t AS VALUES (1), (2)",
+        "CREATE OR REPLACE TABLE catalog.test_replace_table.t AS SELECT 'a' AS name",
+    ] {
+        execute_sql(&ctx, query)
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+    }
+
+    let replaced = client.load_table(&table_ident).await?;
+    assert_eq!(replaced.metadata().uuid(), created.metadata().uuid());
+    assert_eq!(replaced.metadata().snapshots().count(), 2);
+    assert_eq!(
+        replaced
+            .metadata()
+            .current_schema()
+            .as_struct()
+            .fields()
+            .iter()
+            .map(|field| field.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["name"]
+    );
+
+    let batches = ctx
+        .sql("SELECT * FROM catalog.test_replace_table.t")
+        .await
+        .unwrap()
+        .collect()
+        .await
+        .unwrap();
+    check_record_batches(
+        batches,
+        expect![[r#"
+            Field { "name": Utf8, metadata: {"PARQUET:field_id": "3"} }"#]],
+        expect![[r#"
+            name: StringArray
+            [
+              "a",
+            ]"#]],
+        &[],
+        None,
+    );
+
+    // DataFusion itself drops the table and creates a new one
+    ctx.sql("CREATE OR REPLACE TABLE catalog.test_replace_table.t (id INT)")
+        .await
+        .unwrap()
+        .collect()
+        .await
+        .unwrap();
+    let recreated = client.load_table(&table_ident).await?;
+    assert_ne!(recreated.metadata().uuid(), created.metadata().uuid());
+    assert_eq!(recreated.metadata().snapshots().count(), 0);
+
+    Ok(())
+}
@@ -23,8 +23,8 @@ use std::sync::Arc;
 use anyhow::anyhow;
 use datafusion::catalog::{CatalogProvider, CatalogProviderList};
 use fs_err::read_to_string;
-use iceberg::CatalogBuilder;
 use iceberg::memory::MemoryCatalogBuilder;
+use iceberg::{Catalog, CatalogBuilder};
 use iceberg_catalog_rest::RestCatalogBuilder;
 use iceberg_datafusion::IcebergCatalogProvider;
 use toml::{Table as TomlTable, Value};
@@ -34,6 +34,7 @@ const CONFIG_NAME_CATALOGS: &str = "catalogs";
 #[derive(Debug)]
 pub struct IcebergCatalogList {
     catalogs: HashMap<String, Arc<IcebergCatalogProvider>>,
+    iceberg_catalogs: HashMap<String, Arc<dyn Catalog>>,
 }
 
 impl IcebergCatalogList {
@@ -49,24 +50,34 @@ impl IcebergCatalogList {
             })?
         {
             let mut catalogs = HashMap::with_capacity(catalogs_config.len());
+            let mut iceberg_catalogs = HashMap::with_capacity(catalogs_config.len());
             for config in catalogs_config {
                 if let Value::Table(table_config) = config {
-                    let (name, catalog_provider) =
-                        IcebergCatalogList::parse_one(table_config).await?;
-                    catalogs.insert(name, catalog_provider);
+                    let (name, catalog) = IcebergCatalogList::parse_one(table_config).await?;
+                    catalogs.insert(
+                        name.clone(),
+                        Arc::new(IcebergCatalogProvider::try_new(catalog.clone()).await?),
+                    );
+                    iceberg_catalogs.insert(name, catalog);
                 } else {
                     return Err(anyhow!("{CONFIG_NAME_CATALOGS} entry must be a table"));
                 }
             }
-            Ok(Self { catalogs })
+            Ok(Self {
+                catalogs,
+                iceberg_catalogs,
+            })
         } else {
             Err(anyhow!("{CONFIG_NAME_CATALOGS} must be an array of table!"))
         }
     }
 
-    async fn parse_one(
-        config: &TomlTable,
-    ) -> anyhow::Result<(String, Arc<IcebergCatalogProvider>)> {
+    /// Returns the iceberg catalog configured with `name`.
+    pub fn iceberg_catalog(&self, name: &str) -> Option<Arc<dyn Catalog>> {
+        self.iceberg_catalogs.get(name).cloned()
+    }
+
+    async fn parse_one(config: &TomlTable) -> anyhow::Result<(String, Arc<dyn Catalog>)> {
         let name = config
             .get("name")
             .ok_or_else(|| anyhow::anyhow!("name not found for catalog"))?
@@ -95,7 +106,7 @@ impl IcebergCatalogList {
         }
 
         // Create catalog based on type using the appropriate builder
-        let catalog: Arc<dyn iceberg::Catalog> = match r#type {
+        let catalog: Arc<dyn Catalog> = match r#type {
             "rest" => Arc::new(RestCatalogBuilder::default().load(name, props).await?),
             "memory" => Arc::new(MemoryCatalogBuilder::default().load(name, props).await?),
             _ => {
@@ -105,10 +116,7 @@ impl IcebergCatalogList {
             }
         };
 
-        Ok((
-            name.to_string(),
-            Arc::new(IcebergCatalogProvider::try_new(catalog).await?),
-        ))
+        Ok((name.to_string(), catalog))
     }
 }
 
@@ -160,6 +168,8 @@ mod tests {
                 .contains(&"test_memory".to_string())
         );
         assert!(catalog_list.catalog("test_memory").is_some());
+        assert!(catalog_list.iceberg_catalog("test_memory").is_some());
+        assert!(catalog_list.iceberg_catalog("unknown").is_none());
     }
 
     #[tokio::test]
@@ -20,13 +20,15 @@ use std::process::ExitCode;
 use std::str::FromStr;
 use std::sync::Arc;
 
-use clap::Parser;
+use anyhow::anyhow;
+use clap::{Parser, Subcommand};
 use datafusion::execution::runtime_env::RuntimeEnvBuilder;
 use datafusion::prelude::{SessionConfig, SessionContext};
 use datafusion_cli::exec;
 use datafusion_cli::object_storage::instrumented::InstrumentedObjectStoreRegistry;
 use datafusion_cli::print_format::PrintFormat;
 use datafusion_cli::print_options::{MaxRows, PrintOptions};
+use iceberg::{CatalogMigration, NamespaceIdent, TableIdent};
 use iceberg_playground::{ICEBERG_PLAYGROUND_VERSION, IcebergCatalogList};
 
 #[derive(Debug, Parser, PartialEq)]
@@ -58,6 +60,54 @@ struct Args {
 
     #[clap(long, help = "Enables console syntax highlighting")]
     color: bool,
+
+    #[clap(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand, PartialEq)]
+enum Command {
+    /// Registers the namespaces and tables of a catalog in another catalog
+    Migrate(MigrateArgs),
+}
+
+#[derive(Debug, clap::Args, PartialEq)]
+struct MigrateArgs {
+    #[clap(long, help = "Name of the catalog to migrate tables from")]
+    source: String,
+
+    #[clap(long, help = "Name of the catalog to register tables in")]
+    target: String,
+
+    #[clap(long, help = "Print the migration without changing the target catalog")]
+    dry_run: bool,
+
+    #[clap(
+        long,
+        help = "Skip the tables which already exist in the target catalog"
+    )]
+    skip_existing: bool,
+
+    #[clap(
+        long = "namespace-mapping",
+        value_name = "SOURCE=TARGET",
+        help = "Migrate a namespace to another namespace, e.g. 'db=prod.db'"
+    )]
+    namespace_mappings: Vec<String>,
+
+    #[clap(
+        long = "table-mapping",
+        value_name = "SOURCE=TARGET",
+        help = "Migrate a table to another table, e.g. 'db.t1=prod.db.t2'"
+    )]
+    table_mappings: Vec<String>,
+
+    #[clap(
+        long,
+        value_name = "SOURCE=TARGET",
+        help = "Rewrite the table locations starting with SOURCE to start with TARGET"
+    )]
+    table_location_prefix: Option<String>,
 }
 
 #[tokio::main]
@@ -76,6 +126,18 @@ pub async fn main() -> ExitCode {
 async fn main_inner() -> anyhow::Result<()> {
     let args = Args::parse();
 
+    let rc = match args.rc {
+        Some(file) => PathBuf::from_str(&file)?,
+        None => dirs::home_dir()
+            .map(|h| h.join(".icebergrc"))
+            .ok_or_else(|| anyhow::anyhow!("cannot find home directory"))?,
+    };
+
+    if let Some(Command::Migrate(migrate_args)) = args.command {
+        let catalogs = IcebergCatalogList::parse(&rc).await?;
+        return migrate(&catalogs, migrate_args).await;
+    }
+
     if !args.quiet {
         println!("ICEBERG PLAYGROUND v{ICEBERG_PLAYGROUND_VERSION}");
     }
@@ -98,15 +160,64 @@ async fn main_inner() -> anyhow::Result<()> {
         instrumented_registry: Arc::new(InstrumentedObjectStoreRegistry::new()),
     };
 
-    let rc = match args.rc {
-        Some(file) => PathBuf::from_str(&file)?,
-        None => dirs::home_dir()
-            .map(|h| h.join(".icebergrc"))
-            .ok_or_else(|| anyhow::anyhow!("cannot find home directory"))?,
-    };
-
     let catalogs = Arc::new(IcebergCatalogList::parse(&rc).await?);
     ctx.register_catalog_list(catalogs);
 
     Ok(exec::exec_from_repl(&ctx, &mut print_options).await?)
 }
+
+async fn migrate(catalogs: &IcebergCatalogList, args: MigrateArgs) -> anyhow::Result<()> {
+    let source = catalogs
+        .iceberg_catalog(&args.source)
+        .ok_or_else(|| anyhow!("catalog {} not found", args.source))?;
+    let target = catalogs
+        .iceberg_catalog(&args.target)
+        .ok_or_else(|| anyhow!("catalog {} not found", args.target))?;
+
+    let mut migration = CatalogMigration::new(source.as_ref(), target.as_ref())
+        .with_dry_run(args.dry_run)
+        .with_skip_existing(args.skip_existing);
+    for mapping in &args.namespace_mappings {
+        let (source, target) = split_mapping(mapping)?;
+        migration = migration.with_namespace_mapping(
+            NamespaceIdent::from_strs(source.split('.'))?,
+            NamespaceIdent::from_strs(target.split('.'))?,
+        );
+    }
+    for mapping in &args.table_mappings {
+        let (source, target) = split_mapping(mapping)?;
+        migration = migration.with_table_mapping(
+            TableIdent::from_strs(source.split('.'))?,
+            TableIdent::from_strs(target.split('.'))?,
+        );
+    }
+    if let Some(mapping) = &args.table_location_prefix {
+        let (source, target) = split_mapping(mapping)?;
+        migration = migration.with_table_location_prefix(source, target);
+    }
+
+    let report = migration.execute().await?;
+
+    let prefix = if args.dry_run { "[dry run] " } else { "" };
+    for namespace in &report.created_namespaces {
+        println!("{prefix}Created namespace {}", namespace.join("."));
+    }
+    for table in &report.registered_tables {
+        println!(
+            "{prefix}Registered table {} as {} with {}",
+            table.source, table.target, table.metadata_location
+        );
+    }
+    for table in &report.skipped_tables {
+        println!("{prefix}Skipped existing table {table}");
+    }
+
+    Ok(())
+}
+
+/// Splits a `SOURCE=TARGET` mapping argument.
+fn split_mapping(mapping: &str) -> anyhow::Result<(&str, &str)> {
+    mapping
+        .split_once('=')
+        .ok_or_else(|| anyhow!("invalid mapping '{mapping}', expected SOURCE=TARGET"))
+}
@@ -30,7 +30,7 @@ use async_trait::async_trait;
 use bytes::Bytes;
 use cfg_if::cfg_if;
 use iceberg::io::{
-    FileMetadata, FileRead, FileWrite, InputFile, OutputFile, Storage, StorageConfig,
+    FileEntry, FileMetadata, FileRead, FileWrite, InputFile, OutputFile, Storage, StorageConfig,
     StorageFactory,
 };
 use iceberg::{Error, ErrorKind, Result};
@@ -400,6 +400,57 @@ impl Storage for OpenDalStorage {
         Ok(op.remove_all(&path).await.map_err(from_opendal_error)?)
     }
 
+    async fn list(&self, path: &str) -> Result<Vec<FileEntry>> {
+        let (op, relative_path) = self.create_operator(&path)?;
+        let dir = dir_path(relative_path);
+        let entries = match op.list(&dir).await {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == opendal::ErrorKind::NotFound => return Ok(vec![]),
+            Err(e) => return Err(from_opendal_error(e)),
+        };
+
+        Ok(entries
+            .into_iter()
+            // Some services list the directory itself.
+            .filter(|entry| entry.path() != dir)
+            .map(|entry| FileEntry::new(path, entry.name(), entry.metadata().is_dir()))
+            .collect())
+    }
+
+    async fn create_dir(&self, path: &str) -> Result<()> {
+        let (op, relative_path) = self.create_operator(&path)?;
+        Ok(op
+            .create_dir(&dir_path(relative_path))
+            .await
+            .map_err(from_opendal_error)?)
+    }
+
+    async fn write_if_not_exists(&self, path: &str, bs: Bytes) -> Result<()> {
+        let (op, relative_path) = self.create_operator(&path)?;
+        let file_exists = || {
+            Error::new(
+                ErrorKind::PreconditionFailed,
+                format!("File already exists: {path}"),
+            )
+        };
+
+        if !op.info().full_capability().write_with_if_not_exists {
+            return Err(Error::new(
+                ErrorKind::FeatureUnsupported,
+                format!(
+                    "Conditional writes are not supported by the {} service: {path}",
+                    op.info().scheme()
+                ),
+            ));
+        }
+
+        match op.write_with(relative_path, bs).if_not_exists(true).await {
+            Ok(_) => Ok(()),
+            Err(e) if e.kind() == opendal::ErrorKind::ConditionNotMatch => Err(file_exists()),
+            Err(e) => Err(from_opendal_error(e)),
+        }
+    }
+
     #[allow(unreachable_code, unused_variables)]
     fn new_input(&self, path: &str) -> Result<InputFile> {
         Ok(InputFile::new(Arc::new(self.clone()), path.to_string()))
@@ -411,6 +462,15 @@ impl Storage for OpenDalStorage {
     }
 }
 
+/// Appends the trailing slash opendal expects of directory paths.
+fn dir_path(relative_path: &str) -> String {
+    if relative_path.is_empty() || relative_path.ends_with('/') {
+        relative_path.to_string()
+    } else {
+        format!("{relative_path}/")
+    }
+}
+
 // Newtype wrappers for opendal types to satisfy orphan rules.
 // We can't implement iceberg's FileRead/FileWrite traits directly on opendal's
 // Reader/Writer since neither trait nor type is defined in this crate.